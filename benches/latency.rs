@@ -139,6 +139,7 @@ fn bench_signal_serialization(c: &mut Criterion) {
         worst_case_payout: Decimal::new(98, 2),
         yes_fee_shares: Decimal::ZERO,
         no_fee_shares: Decimal::ZERO,
+        trace_context: None,
     };
 
     group.throughput(Throughput::Elements(1));
@@ -26,13 +26,8 @@ pub struct AccountSnapshotConfig {
 impl AccountSnapshotConfig {
     pub fn from_env() -> Self {
         Self {
-            enabled: std::env::var("ACCOUNT_SNAPSHOT_ENABLED")
-                .map(|v| v == "true")
-                .unwrap_or(true),
-            interval_secs: std::env::var("ACCOUNT_SNAPSHOT_INTERVAL_SECS")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(300),
+            enabled: polymarket_core::settings::get("ACCOUNT_SNAPSHOT_ENABLED"),
+            interval_secs: polymarket_core::settings::get("ACCOUNT_SNAPSHOT_INTERVAL_SECS"),
         }
     }
 }
@@ -233,6 +228,30 @@ pub async fn load_live_account_snapshot(
     }))
 }
 
+/// Latest persisted total equity for the canonical workspace, with its snapshot time.
+pub async fn load_latest_total_equity(
+    pool: &PgPool,
+) -> anyhow::Result<Option<(Decimal, DateTime<Utc>)>> {
+    let Some(workspace_id) = resolve_canonical_workspace_id(pool).await? else {
+        return Ok(None);
+    };
+
+    let row: Option<(Decimal, DateTime<Utc>)> = sqlx::query_as(
+        r#"
+        SELECT total_equity, snapshot_time
+        FROM account_snapshots
+        WHERE workspace_id = $1
+        ORDER BY snapshot_time DESC
+        LIMIT 1
+        "#,
+    )
+    .bind(workspace_id)
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(row)
+}
+
 async fn snapshot_canonical_workspace(state: &Arc<AppState>) -> anyhow::Result<()> {
     if let Some(snapshot) = load_live_account_snapshot(state).await? {
         persist_snapshot(&state.pool, &snapshot).await?;
@@ -8,6 +8,7 @@
 use chrono::Utc;
 use polymarket_core::api::{ClobClient, GammaClient};
 use polymarket_core::db::positions::{PositionRepository, SOURCE_ARBITRAGE};
+use polymarket_core::telemetry;
 use polymarket_core::types::Market;
 use polymarket_core::types::{ArbOpportunity, ExitStrategy, FailureReason, MarketOrder, OrderSide};
 use risk_manager::circuit_breaker::CircuitBreaker;
@@ -17,7 +18,7 @@ use std::collections::{HashMap, HashSet};
 use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
 use std::sync::Arc;
 use tokio::sync::{broadcast, Mutex, RwLock};
-use tracing::{debug, error, info, warn};
+use tracing::{debug, error, info, info_span, warn, Instrument};
 use trading_engine::OrderExecutor;
 use uuid::Uuid;
 
@@ -54,6 +55,10 @@ pub struct ArbExecutorConfig {
     pub fee_rate: Decimal,
     /// Maximum total exposure across all open positions before rejecting new entries.
     pub max_total_exposure: Decimal,
+    /// Legs priced below this are not entered.
+    pub min_entry_price: Decimal,
+    /// Legs priced above this are not entered.
+    pub max_entry_price: Decimal,
 }
 
 impl Default for ArbExecutorConfig {
@@ -71,6 +76,8 @@ impl Default for ArbExecutorConfig {
             min_book_depth: Decimal::new(100, 0),    // $100 minimum depth
             fee_rate: Decimal::new(2, 2),            // 2%
             max_total_exposure: Decimal::new(25000, 0), // $25,000 max total exposure
+            min_entry_price: Decimal::ZERO,
+            max_entry_price: Decimal::ONE,
         }
     }
 }
@@ -79,48 +86,20 @@ impl ArbExecutorConfig {
     /// Create config from environment variables.
     pub fn from_env() -> Self {
         Self {
-            enabled: std::env::var("ARB_AUTO_EXECUTE")
-                .map(|v| v == "true")
-                .unwrap_or(false),
-            allow_live_execution: std::env::var("ARB_ALLOW_LIVE_EXECUTION")
-                .map(|v| v == "true" || v == "1")
-                .unwrap_or(false),
-            position_size: std::env::var("ARB_POSITION_SIZE")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(Decimal::new(50, 0)),
-            min_net_profit: std::env::var("ARB_MIN_NET_PROFIT")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(Decimal::new(1, 3)),
-            max_signal_age_secs: std::env::var("ARB_MAX_SIGNAL_AGE_SECS")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(30),
-            cache_refresh_secs: std::env::var("ARB_CACHE_REFRESH_SECS")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(300),
-            dynamic_sizing: std::env::var("ARB_DYNAMIC_SIZING")
-                .map(|v| v != "false")
-                .unwrap_or(true),
-            min_position_size: std::env::var("ARB_MIN_POSITION_SIZE")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(Decimal::new(5, 0)), // $5 floor (small wallet)
-            max_position_size: std::env::var("ARB_MAX_POSITION_SIZE")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(Decimal::new(25, 0)), // $25 ceiling (small wallet)
-            min_book_depth: std::env::var("ARB_MIN_BOOK_DEPTH")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(Decimal::new(25, 0)), // $25 min depth (small wallet)
+            enabled: polymarket_core::settings::get("ARB_AUTO_EXECUTE"),
+            allow_live_execution: polymarket_core::settings::get("ARB_ALLOW_LIVE_EXECUTION"),
+            position_size: polymarket_core::settings::get("ARB_POSITION_SIZE"),
+            min_net_profit: polymarket_core::settings::get("ARB_MIN_NET_PROFIT"),
+            max_signal_age_secs: polymarket_core::settings::get("ARB_MAX_SIGNAL_AGE_SECS"),
+            cache_refresh_secs: polymarket_core::settings::get("ARB_CACHE_REFRESH_SECS"),
+            dynamic_sizing: polymarket_core::settings::get("ARB_DYNAMIC_SIZING"),
+            min_position_size: polymarket_core::settings::get("ARB_MIN_POSITION_SIZE"), // $5 floor (small wallet)
+            max_position_size: polymarket_core::settings::get("ARB_MAX_POSITION_SIZE"), // $25 ceiling (small wallet)
+            min_book_depth: polymarket_core::settings::get("ARB_MIN_BOOK_DEPTH"), // $25 min depth (small wallet)
             fee_rate: Decimal::new(2, 2), // Always 2% on Polymarket
-            max_total_exposure: std::env::var("ARB_MAX_TOTAL_EXPOSURE")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(Decimal::new(25000, 0)), // $25,000 default
+            max_total_exposure: polymarket_core::settings::get("ARB_MAX_TOTAL_EXPOSURE"), // $25,000 default
+            min_entry_price: polymarket_core::settings::get("RISK_MIN_ENTRY_PRICE"),
+            max_entry_price: polymarket_core::settings::get("RISK_MAX_ENTRY_PRICE"),
         }
     }
 }
@@ -162,10 +141,7 @@ impl OutcomeTokenCache {
     /// Refresh the cache using Gamma as the source of tradable markets.
     pub(crate) async fn refresh(&self) -> anyhow::Result<(usize, usize)> {
         let _guard = self.refresh_lock.lock().await;
-        let gamma_page_size = std::env::var("GAMMA_ARB_MARKET_PAGE_SIZE")
-            .ok()
-            .and_then(|s| s.parse::<u32>().ok())
-            .unwrap_or(200);
+        let gamma_page_size = polymarket_core::settings::get::<u32>("GAMMA_ARB_MARKET_PAGE_SIZE");
         let markets = self
             .gamma_client
             .get_all_tradable_markets(gamma_page_size)
@@ -401,6 +377,8 @@ impl ArbExecutorRuntimeStatus {
 #[derive(Debug, Clone, Default)]
 struct ArbExecutionTelemetry {
     attempt_id: Uuid,
+    /// OpenTelemetry trace of this attempt, when traces are exported.
+    trace_id: Option<String>,
     signal_age_ms: i64,
     token_lookup_ms: Option<i64>,
     depth_check_ms: Option<i64>,
@@ -420,6 +398,7 @@ impl ArbExecutionTelemetry {
     fn new(attempt_id: Uuid, signal_age_ms: i64) -> Self {
         Self {
             attempt_id,
+            trace_id: telemetry::current_trace_id(),
             signal_age_ms,
             ..Self::default()
         }
@@ -433,6 +412,9 @@ impl ArbExecutionTelemetry {
         let mut map = serde_json::Map::new();
         map.insert("telemetry_version".to_string(), serde_json::json!(2));
         map.insert("attempt_id".to_string(), serde_json::json!(self.attempt_id));
+        if let Some(trace_id) = &self.trace_id {
+            map.insert("trace_id".to_string(), serde_json::json!(trace_id));
+        }
         map.insert(
             "signal_age_ms".to_string(),
             serde_json::json!(self.signal_age_ms),
@@ -699,7 +681,14 @@ impl ArbAutoExecutor {
                 result = self.arb_entry_rx.recv() => {
                     match result {
                         Ok(arb) => {
-                            if let Err(e) = self.process_arb_signal(arb).await {
+                            let span = info_span!(
+                                "arb.execute",
+                                market_id = %arb.market_id,
+                                net_profit = %arb.net_profit,
+                                attempt_id = tracing::field::Empty,
+                            );
+                            telemetry::set_parent(&span, arb.trace_context.as_ref());
+                            if let Err(e) = self.process_arb_signal(arb).instrument(span).await {
                                 error!(error = %e, "Failed to process arb signal");
                             }
                         }
@@ -771,6 +760,7 @@ impl ArbAutoExecutor {
             .max(0);
         let age_secs = signal_age_ms / 1000;
         let attempt_id = Uuid::new_v4();
+        tracing::Span::current().record("attempt_id", tracing::field::display(attempt_id));
         let mut telemetry = ArbExecutionTelemetry::new(attempt_id, signal_age_ms);
 
         {
@@ -930,6 +920,24 @@ impl ArbAutoExecutor {
             return Ok(());
         }
 
+        // 2a. Order band — both legs must price inside the configured entry band
+        let out_of_band =
+            |price: Decimal| price < cfg.min_entry_price || price > cfg.max_entry_price;
+        if out_of_band(arb.yes_ask) || out_of_band(arb.no_ask) {
+            let mut runtime = self.runtime_status.write().await;
+            runtime.record_decision(
+                market_id,
+                format!(
+                    "skipped: leg price outside band [{}, {}]",
+                    cfg.min_entry_price, cfg.max_entry_price
+                ),
+            );
+            telemetry.finish_total(&process_started_at);
+            self.record_skip_event(&arb, execution_mode, "price_out_of_band", &telemetry)
+                .await;
+            return Ok(());
+        }
+
         // 3. Dedup — skip if we already have an active position in this market
         {
             let active = self.active_markets.read().await;
@@ -1135,7 +1143,7 @@ impl ArbAutoExecutor {
             );
             sized
         } else {
-            cfg.position_size
+            cfg.position_size.min(cfg.max_position_size)
         };
 
         if rollout_decision.size_multiplier < Decimal::ONE {
@@ -1150,6 +1158,17 @@ impl ArbAutoExecutor {
             );
         }
 
+        // Scale down while the circuit breaker is in recovery or throttled by market conditions.
+        let capacity = self.circuit_breaker.trading_capacity().await;
+        if capacity < Decimal::ONE {
+            position_size *= capacity;
+            let mut runtime = self.runtime_status.write().await;
+            runtime.record_decision(
+                market_id,
+                format!("circuit breaker capacity adjusted size by {capacity}"),
+            );
+        }
+
         if arb.total_cost.is_zero() {
             let mut runtime = self.runtime_status.write().await;
             runtime.zero_cost_skips = runtime.zero_cost_skips.saturating_add(1);
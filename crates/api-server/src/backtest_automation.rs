@@ -22,21 +22,12 @@ pub struct BacktestAutomationConfig {
 impl BacktestAutomationConfig {
     pub fn from_env() -> Self {
         Self {
-            enabled: std::env::var("BACKTEST_AUTOMATION_ENABLED")
-                .map(|v| v == "true")
-                .unwrap_or(true),
-            interval_secs: std::env::var("BACKTEST_AUTOMATION_INTERVAL_SECS")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(300),
-            startup_delay_secs: std::env::var("BACKTEST_AUTOMATION_STARTUP_DELAY_SECS")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(180),
-            claim_limit: std::env::var("BACKTEST_AUTOMATION_CLAIM_LIMIT")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(4),
+            enabled: polymarket_core::settings::get("BACKTEST_AUTOMATION_ENABLED"),
+            interval_secs: polymarket_core::settings::get("BACKTEST_AUTOMATION_INTERVAL_SECS"),
+            startup_delay_secs: polymarket_core::settings::get(
+                "BACKTEST_AUTOMATION_STARTUP_DELAY_SECS",
+            ),
+            claim_limit: polymarket_core::settings::get("BACKTEST_AUTOMATION_CLAIM_LIMIT"),
         }
     }
 }
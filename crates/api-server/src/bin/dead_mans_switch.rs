@@ -0,0 +1,142 @@
+//! Standalone dead-man's switch.
+//!
+//! Watches the api-server heartbeat in Redis and cancels every resting CLOB
+//! order for the trading wallet (`WALLET_PRIVATE_KEY`) once the heartbeat has
+//! not been renewed within `DEAD_MANS_SWITCH_HEARTBEAT_TIMEOUT_SECS`, or when
+//! api-server reports a hard-kill trip. Runs as its own process so it keeps
+//! working when api-server crashes. Mass-cancels are written to the audit log
+//! when `DATABASE_URL` is set.
+
+use api_server::dead_mans_switch::{
+    connect_redis, log_mass_cancel, read_heartbeat, DeadMansSwitchConfig, HardKillLatch,
+    HeartbeatWatch, MassCancelReason,
+};
+use auth::{AuditLogger, AuditStorage, PostgresAuditStorage, TradingWallet};
+use chrono::Utc;
+use polymarket_core::api::clob::AuthenticatedClobClient;
+use polymarket_core::api::ClobClient;
+use polymarket_core::signing::OrderSigner;
+use std::sync::Arc;
+use tracing::{info, warn};
+use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    dotenvy::dotenv().ok();
+
+    tracing_subscriber::registry()
+        .with(EnvFilter::try_from_default_env().unwrap_or_else(|_| {
+            "dead_mans_switch=info,api_server=info,polymarket_core=info".into()
+        }))
+        .with(tracing_subscriber::fmt::layer())
+        .init();
+
+    let config = DeadMansSwitchConfig::from_env();
+    let wallet = TradingWallet::from_env()?;
+    let clob_url = polymarket_core::settings::get_opt::<String>("POLYMARKET_CLOB_URL");
+    let mut client = AuthenticatedClobClient::new(
+        ClobClient::new(clob_url, None),
+        OrderSigner::new(wallet.into_signer()),
+    );
+    if let Err(e) = client.create_or_derive_api_key().await {
+        // Retried right before the first mass-cancel.
+        warn!(error = %e, "Failed to derive CLOB API credentials at startup");
+    }
+
+    let audit_logger = match polymarket_core::settings::get_opt::<String>("DATABASE_URL") {
+        Some(url) => match sqlx::PgPool::connect(&url).await {
+            Ok(pool) => {
+                let storage: Arc<dyn AuditStorage> = Arc::new(PostgresAuditStorage::new(pool));
+                Some(AuditLogger::new(storage))
+            }
+            Err(e) => {
+                warn!(error = %e, "Audit logging unavailable; mass-cancels will only be logged");
+                None
+            }
+        },
+        None => None,
+    };
+
+    info!(
+        wallet = %client.address(),
+        heartbeat_timeout_secs = config.heartbeat_timeout_secs,
+        "Dead-man's switch watching api-server heartbeat"
+    );
+
+    let mut redis = None;
+    let mut watch = HeartbeatWatch::new(config.heartbeat_timeout_secs);
+    let mut hard_kill_latch = HardKillLatch::default();
+    let mut last_live = false;
+
+    let mut ticker = tokio::time::interval(tokio::time::Duration::from_secs(
+        config.heartbeat_interval_secs.max(1),
+    ));
+    loop {
+        ticker.tick().await;
+
+        if redis.is_none() {
+            redis = connect_redis(&config.redis_url).await;
+        }
+        let Some(conn) = redis.as_mut() else {
+            continue;
+        };
+
+        // A Redis outage says nothing about api-server, so skip the tick
+        // rather than treating it as a lost heartbeat.
+        let heartbeat = match read_heartbeat(conn).await {
+            Ok(heartbeat) => heartbeat,
+            Err(e) => {
+                warn!(error = %e, "Failed to read api-server heartbeat");
+                continue;
+            }
+        };
+
+        let mut hard_kill = false;
+        if let Some(heartbeat) = heartbeat {
+            watch.record_beat(heartbeat.sent_at);
+            last_live = heartbeat.live;
+            hard_kill = heartbeat.hard_kill;
+        }
+
+        let reason = if hard_kill_latch.observe(hard_kill) {
+            Some(MassCancelReason::HardKill)
+        } else {
+            watch
+                .due(Utc::now())
+                .map(|age_secs| MassCancelReason::HeartbeatLost { age_secs })
+        };
+        let Some(reason) = reason else {
+            continue;
+        };
+        if !last_live {
+            // api-server was paper trading; nothing of ours rests on the CLOB.
+            mark_handled(&reason, &mut watch, &mut hard_kill_latch);
+            continue;
+        }
+
+        let result = cancel_all(&mut client).await;
+        log_mass_cancel(audit_logger.as_ref(), "watchdog", &reason, &result);
+        if result.is_ok() {
+            mark_handled(&reason, &mut watch, &mut hard_kill_latch);
+        }
+    }
+}
+
+async fn cancel_all(client: &mut AuthenticatedClobClient) -> anyhow::Result<()> {
+    if !client.has_credentials() {
+        client.create_or_derive_api_key().await?;
+    }
+    client.cancel_all_orders().await?;
+    Ok(())
+}
+
+fn mark_handled(
+    reason: &MassCancelReason,
+    watch: &mut HeartbeatWatch,
+    hard_kill_latch: &mut HardKillLatch,
+) {
+    match reason {
+        MassCancelReason::HardKill => hard_kill_latch.mark_cancelled(),
+        _ => watch.mark_cancelled(),
+    }
+}
@@ -1,45 +1,32 @@
-//! Binance WebSocket client for real-time BTC/ETH aggTrade feeds.
+//! Binance combined-stream aggTrade feed.
 
-use futures_util::{SinkExt, StreamExt};
 use serde::Deserialize;
 use std::time;
-use tokio::sync::mpsc;
-use tokio::task::JoinHandle;
-use tokio_tungstenite::tungstenite::Message;
-use tracing::{debug, info, warn};
 
-use super::price_tracker::{CexPriceTick, CexSymbol};
+use super::feed::{parse_price, CexFeed, VenueTick};
+use super::price_tracker::CexSymbol;
 
-/// Configuration for the Binance WebSocket client.
+/// Binance spot aggTrade streams.
 #[derive(Debug, Clone)]
-pub struct BinanceWsConfig {
+pub struct BinanceFeed {
     /// Combined stream URL. Default: wss://stream.binance.com:9443
     pub base_url: String,
-    /// Streams to subscribe to.
-    pub streams: Vec<String>,
-    /// Initial reconnect delay in milliseconds.
-    pub reconnect_delay_ms: u64,
-    /// Maximum reconnect delay in milliseconds.
-    pub max_reconnect_delay_ms: u64,
+    pub symbols: Vec<CexSymbol>,
 }
 
-impl BinanceWsConfig {
-    pub fn from_env() -> Self {
+impl BinanceFeed {
+    pub fn from_env(symbols: Vec<CexSymbol>) -> Self {
         Self {
-            base_url: std::env::var("BINANCE_WS_URL")
-                .unwrap_or_else(|_| "wss://stream.binance.com:9443".to_string()),
-            streams: vec![
-                "btcusdt@aggTrade".to_string(),
-                "ethusdt@aggTrade".to_string(),
-            ],
-            reconnect_delay_ms: 1000,
-            max_reconnect_delay_ms: 30000,
+            base_url: polymarket_core::settings::get("BINANCE_WS_URL"),
+            symbols,
         }
     }
 
-    fn build_url(&self) -> String {
-        let streams = self.streams.join("/");
-        format!("{}/stream?streams={}", self.base_url, streams)
+    fn streams(&self) -> Vec<String> {
+        self.symbols
+            .iter()
+            .map(|symbol| format!("{}@aggTrade", symbol.as_str().to_ascii_lowercase()))
+            .collect()
     }
 }
 
@@ -54,112 +41,53 @@ struct BinanceStreamWrapper {
 struct AggTradeData {
     /// Price as string (Binance sends numbers as strings).
     p: String,
-    /// Aggregate trade ID.
-    #[serde(rename = "a")]
-    _agg_trade_id: u64,
+    /// Trade time in milliseconds.
+    #[serde(rename = "T")]
+    trade_time: Option<i64>,
 }
 
 fn stream_to_symbol(stream: &str) -> Option<CexSymbol> {
-    if stream.starts_with("btcusdt") {
-        Some(CexSymbol::BtcUsdt)
-    } else if stream.starts_with("ethusdt") {
-        Some(CexSymbol::EthUsdt)
-    } else {
-        None
-    }
+    let pair = stream.split('@').next()?;
+    CexSymbol::parse(pair)
 }
 
-/// Spawn a Binance WebSocket client that sends price ticks on the channel.
-pub fn spawn_binance_ws_client(
-    config: BinanceWsConfig,
-    price_tx: mpsc::Sender<CexPriceTick>,
-) -> JoinHandle<()> {
-    tokio::spawn(async move {
-        let mut reconnect_delay = time::Duration::from_millis(config.reconnect_delay_ms);
-        let max_delay = time::Duration::from_millis(config.max_reconnect_delay_ms);
-
-        loop {
-            let url = config.build_url();
-            info!(url = %url, "Connecting to Binance WebSocket");
-
-            match tokio_tungstenite::connect_async(&url).await {
-                Ok((ws_stream, _)) => {
-                    info!("Binance WebSocket connected");
-                    reconnect_delay = time::Duration::from_millis(config.reconnect_delay_ms);
-
-                    let (mut _write, mut read) = ws_stream.split();
+impl CexFeed for BinanceFeed {
+    fn venue(&self) -> &'static str {
+        "binance"
+    }
 
-                    loop {
-                        match tokio::time::timeout(time::Duration::from_secs(30), read.next()).await
-                        {
-                            Ok(Some(Ok(Message::Text(text)))) => {
-                                match serde_json::from_str::<BinanceStreamWrapper>(&text) {
-                                    Ok(wrapper) => {
-                                        let Some(symbol) = stream_to_symbol(&wrapper.stream) else {
-                                            continue;
-                                        };
-                                        let Ok(price) = wrapper.data.p.parse::<f64>() else {
-                                            continue;
-                                        };
-                                        let tick = CexPriceTick {
-                                            symbol,
-                                            price,
-                                            received_at: time::Instant::now(),
-                                        };
-                                        if price_tx.try_send(tick).is_err() {
-                                            // Channel full — drop oldest tick (receiver is slow)
-                                            debug!("Binance price channel full, dropping tick");
-                                        }
-                                    }
-                                    Err(e) => {
-                                        debug!(error = %e, "Failed to parse Binance message");
-                                    }
-                                }
-                            }
-                            Ok(Some(Ok(Message::Ping(data)))) => {
-                                if let Err(e) = _write.send(Message::Pong(data)).await {
-                                    warn!(error = %e, "Failed to send pong");
-                                    break;
-                                }
-                            }
-                            Ok(Some(Ok(Message::Close(_)))) => {
-                                info!("Binance WebSocket closed by server");
-                                break;
-                            }
-                            Ok(Some(Err(e))) => {
-                                warn!(error = %e, "Binance WebSocket error");
-                                break;
-                            }
-                            Ok(None) => {
-                                info!("Binance WebSocket stream ended");
-                                break;
-                            }
-                            Err(_) => {
-                                warn!("Binance WebSocket read timeout (30s)");
-                                break;
-                            }
-                            _ => {}
-                        }
-                    }
-                }
-                Err(e) => {
-                    warn!(error = %e, "Failed to connect to Binance WebSocket");
-                }
-            }
+    fn url(&self) -> String {
+        format!(
+            "{}/stream?streams={}",
+            self.base_url.trim_end_matches('/'),
+            self.streams().join("/")
+        )
+    }
 
-            warn!(
-                delay_ms = reconnect_delay.as_millis(),
-                "Reconnecting to Binance WebSocket"
-            );
-            tokio::time::sleep(reconnect_delay).await;
-            reconnect_delay = (reconnect_delay * 2).min(max_delay);
-        }
-    })
+    fn parse(&self, text: &str) -> Vec<VenueTick> {
+        let Ok(wrapper) = serde_json::from_str::<BinanceStreamWrapper>(text) else {
+            return Vec::new();
+        };
+        let (Some(symbol), Some(price)) = (
+            stream_to_symbol(&wrapper.stream),
+            parse_price(&wrapper.data.p),
+        ) else {
+            return Vec::new();
+        };
+        vec![VenueTick {
+            venue: self.venue(),
+            symbol,
+            price,
+            exchange_ts_ms: wrapper.data.trade_time,
+            received_at: time::Instant::now(),
+        }]
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::cex::feed::test_support::run_against_stub;
 
     #[test]
     fn test_stream_to_symbol() {
@@ -168,26 +96,43 @@ mod tests {
             Some(CexSymbol::BtcUsdt)
         );
         assert_eq!(
-            stream_to_symbol("ethusdt@aggTrade"),
-            Some(CexSymbol::EthUsdt)
+            stream_to_symbol("solusdt@aggTrade"),
+            Some(CexSymbol::SolUsdt)
         );
         assert_eq!(stream_to_symbol("unknown@aggTrade"), None);
     }
 
     #[test]
     fn test_build_url() {
-        let config = BinanceWsConfig {
+        let feed = BinanceFeed {
             base_url: "wss://stream.binance.com:9443".to_string(),
-            streams: vec![
-                "btcusdt@aggTrade".to_string(),
-                "ethusdt@aggTrade".to_string(),
-            ],
-            reconnect_delay_ms: 1000,
-            max_reconnect_delay_ms: 30000,
+            symbols: vec![CexSymbol::BtcUsdt, CexSymbol::EthUsdt],
         };
         assert_eq!(
-            config.build_url(),
+            feed.url(),
             "wss://stream.binance.com:9443/stream?streams=btcusdt@aggTrade/ethusdt@aggTrade"
         );
     }
+
+    #[tokio::test]
+    async fn test_ticks_from_stub() {
+        let frames = vec![
+            r#"{"stream":"btcusdt@aggTrade","data":{"e":"aggTrade","a":1,"p":"70123.45","q":"0.1","T":1700000000123}}"#.to_string(),
+            r#"{"result":null,"id":1}"#.to_string(),
+            r#"{"stream":"xrpusdt@aggTrade","data":{"e":"aggTrade","a":2,"p":"0.6123","q":"10","T":1700000000456}}"#.to_string(),
+        ];
+        let (_, ticks) = run_against_stub(
+            |url| BinanceFeed {
+                base_url: url,
+                symbols: vec![CexSymbol::BtcUsdt, CexSymbol::XrpUsdt],
+            },
+            frames,
+            2,
+        )
+        .await;
+        assert_eq!(ticks[0].symbol, CexSymbol::BtcUsdt);
+        assert_eq!(ticks[0].price, 70123.45);
+        assert_eq!(ticks[0].exchange_ts_ms, Some(1_700_000_000_123));
+        assert_eq!(ticks[1].symbol, CexSymbol::XrpUsdt);
+    }
 }
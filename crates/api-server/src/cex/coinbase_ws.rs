@@ -0,0 +1,111 @@
+//! Coinbase Exchange ticker feed (USD pairs).
+
+use serde::Deserialize;
+use std::time;
+
+use super::feed::{parse_price, parse_rfc3339_ms, CexFeed, VenueTick};
+use super::price_tracker::CexSymbol;
+
+/// Coinbase Exchange `ticker` channel, one print per match.
+#[derive(Debug, Clone)]
+pub struct CoinbaseFeed {
+    /// Default: wss://ws-feed.exchange.coinbase.com
+    pub url: String,
+    pub symbols: Vec<CexSymbol>,
+}
+
+impl CoinbaseFeed {
+    pub fn from_env(symbols: Vec<CexSymbol>) -> Self {
+        Self {
+            url: polymarket_core::settings::get("COINBASE_WS_URL"),
+            symbols,
+        }
+    }
+}
+
+fn product_id(symbol: CexSymbol) -> String {
+    format!("{}-USD", symbol.base())
+}
+
+#[derive(Debug, Deserialize)]
+struct TickerMessage {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    product_id: String,
+    price: Option<String>,
+    time: Option<String>,
+}
+
+impl CexFeed for CoinbaseFeed {
+    fn venue(&self) -> &'static str {
+        "coinbase"
+    }
+
+    fn url(&self) -> String {
+        self.url.clone()
+    }
+
+    fn subscribe_messages(&self) -> Vec<String> {
+        let product_ids: Vec<String> = self.symbols.iter().copied().map(product_id).collect();
+        vec![serde_json::json!({
+            "type": "subscribe",
+            "product_ids": product_ids,
+            "channels": ["ticker"],
+        })
+        .to_string()]
+    }
+
+    fn parse(&self, text: &str) -> Vec<VenueTick> {
+        let Ok(message) = serde_json::from_str::<TickerMessage>(text) else {
+            return Vec::new();
+        };
+        if message.kind != "ticker" {
+            return Vec::new();
+        }
+        let base = message.product_id.split('-').next().unwrap_or_default();
+        let (Some(symbol), Some(price)) = (
+            CexSymbol::parse(base),
+            message.price.as_deref().and_then(parse_price),
+        ) else {
+            return Vec::new();
+        };
+        vec![VenueTick {
+            venue: self.venue(),
+            symbol,
+            price,
+            exchange_ts_ms: message.time.as_deref().and_then(parse_rfc3339_ms),
+            received_at: time::Instant::now(),
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cex::feed::test_support::run_against_stub;
+
+    #[tokio::test]
+    async fn test_subscribes_and_parses_ticker_from_stub() {
+        let frames = vec![
+            r#"{"type":"subscriptions","channels":[{"name":"ticker","product_ids":["BTC-USD","SOL-USD"]}]}"#.to_string(),
+            r#"{"type":"ticker","sequence":1,"product_id":"SOL-USD","price":"145.21","time":"2024-01-01T00:00:00.250Z"}"#.to_string(),
+        ];
+        let (received, ticks) = run_against_stub(
+            |url| CoinbaseFeed {
+                url,
+                symbols: vec![CexSymbol::BtcUsdt, CexSymbol::SolUsdt],
+            },
+            frames,
+            1,
+        )
+        .await;
+
+        let subscription: serde_json::Value = serde_json::from_str(&received[0]).unwrap();
+        assert_eq!(subscription["product_ids"][1], "SOL-USD");
+        assert_eq!(ticks[0].venue, "coinbase");
+        assert_eq!(ticks[0].symbol, CexSymbol::SolUsdt);
+        assert_eq!(ticks[0].price, 145.21);
+        assert_eq!(ticks[0].exchange_ts_ms, Some(1_704_067_200_250));
+    }
+}
@@ -0,0 +1,377 @@
+//! Composite CEX index across venues.
+//!
+//! Runs every configured [`CexFeed`] and emits, per symbol, the median of the
+//! latest price from each venue that has ticked within `stale_after_ms`. A
+//! venue that disconnects or freezes simply ages out of the median, so one
+//! exchange going dark no longer blinds the latency-arb strategy. Per-venue
+//! lag (local receive time minus the venue's event time, smoothed) is logged
+//! and exported as `abbot_cex_venue_lag_ms`; it includes any clock skew
+//! between this host and the venue.
+
+use prometheus::{GaugeVec, IntGaugeVec, Opts};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::{Arc, LazyLock};
+use std::time;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+
+use super::binance_ws::BinanceFeed;
+use super::coinbase_ws::CoinbaseFeed;
+use super::feed::{spawn_feed, CexFeed, FeedConnectionConfig, VenueTick};
+use super::kraken_ws::KrakenFeed;
+use super::okx_ws::OkxFeed;
+use super::price_tracker::{CexPriceTick, CexSymbol};
+
+/// Smoothing for the per-venue lag average.
+const LAG_EWMA_ALPHA: f64 = 0.1;
+
+static VENUE_LAG_MS: LazyLock<GaugeVec> = LazyLock::new(|| {
+    let gauge = GaugeVec::new(
+        Opts::new(
+            "cex_venue_lag_ms",
+            "Smoothed receive lag behind venue event time",
+        )
+        .namespace("abbot"),
+        &["venue"],
+    )
+    .expect("metric definition is valid");
+    register(Box::new(gauge.clone()));
+    gauge
+});
+
+static VENUE_STALE: LazyLock<IntGaugeVec> = LazyLock::new(|| {
+    let gauge = IntGaugeVec::new(
+        Opts::new(
+            "cex_venue_stale",
+            "1 while a venue is excluded from the composite index",
+        )
+        .namespace("abbot"),
+        &["venue"],
+    )
+    .expect("metric definition is valid");
+    register(Box::new(gauge.clone()));
+    gauge
+});
+
+fn register(collector: Box<dyn prometheus::core::Collector>) {
+    if let Err(e) = polymarket_core::metrics::global()
+        .registry
+        .register(collector)
+    {
+        warn!(error = %e, "Failed to register CEX feed metrics");
+    }
+}
+
+/// Configuration for the composite feed.
+#[derive(Debug, Clone)]
+pub struct CompositeFeedConfig {
+    /// Venues to connect to (binance, coinbase, kraken, okx).
+    pub venues: Vec<String>,
+    pub symbols: Vec<CexSymbol>,
+    /// A venue drops out of the median after this long without a tick.
+    pub stale_after_ms: u64,
+    /// Fresh venues required before an index price is emitted.
+    pub min_venues: usize,
+    /// How often per-venue status is logged and exported.
+    pub status_interval_secs: u64,
+}
+
+impl CompositeFeedConfig {
+    pub fn from_env() -> Self {
+        let list = |name: &str| -> Vec<String> {
+            polymarket_core::settings::get::<Vec<String>>(name)
+                .into_iter()
+                .map(|v| v.to_ascii_lowercase())
+                .collect()
+        };
+        let symbols = list("CEX_FEED_SYMBOLS")
+            .iter()
+            .filter_map(|s| {
+                let symbol = CexSymbol::parse(s);
+                if symbol.is_none() {
+                    warn!(symbol = %s, "Ignoring unknown CEX_FEED_SYMBOLS entry");
+                }
+                symbol
+            })
+            .collect();
+        Self {
+            venues: list("CEX_FEED_VENUES"),
+            symbols,
+            stale_after_ms: polymarket_core::settings::get("CEX_FEED_STALE_MS"),
+            min_venues: polymarket_core::settings::get("CEX_FEED_MIN_VENUES"),
+            status_interval_secs: 60,
+        }
+    }
+
+    /// Feeds for the configured venues; unknown names are skipped.
+    pub fn build_feeds(&self) -> Vec<Arc<dyn CexFeed>> {
+        let symbols = self.symbols.clone();
+        self.venues
+            .iter()
+            .filter_map(|venue| -> Option<Arc<dyn CexFeed>> {
+                match venue.as_str() {
+                    "binance" => Some(Arc::new(BinanceFeed::from_env(symbols.clone()))),
+                    "coinbase" => Some(Arc::new(CoinbaseFeed::from_env(symbols.clone()))),
+                    "kraken" => Some(Arc::new(KrakenFeed::from_env(symbols.clone()))),
+                    "okx" => Some(Arc::new(OkxFeed::from_env(symbols.clone()))),
+                    other => {
+                        warn!(venue = %other, "Ignoring unknown CEX_FEED_VENUES entry");
+                        None
+                    }
+                }
+            })
+            .collect()
+    }
+}
+
+/// Health of one venue as seen by the composite.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VenueStatus {
+    pub venue: &'static str,
+    pub ticks: u64,
+    /// Time since the venue's last tick, if it has ticked at all.
+    pub last_tick_age_ms: Option<u64>,
+    /// Smoothed receive lag behind the venue's event timestamps.
+    pub lag_ms: Option<f64>,
+    pub stale: bool,
+}
+
+#[derive(Debug, Default)]
+struct VenueHealth {
+    ticks: u64,
+    last_tick_at: Option<time::Instant>,
+    lag_ms: Option<f64>,
+}
+
+/// Median-of-venues price index.
+pub struct CompositeIndex {
+    stale_after: time::Duration,
+    min_venues: usize,
+    /// Latest (price, received_at) per symbol and venue.
+    quotes: HashMap<CexSymbol, BTreeMap<&'static str, (f64, time::Instant)>>,
+    health: BTreeMap<&'static str, VenueHealth>,
+}
+
+impl CompositeIndex {
+    pub fn new(stale_after_ms: u64, min_venues: usize) -> Self {
+        Self {
+            stale_after: time::Duration::from_millis(stale_after_ms),
+            min_venues: min_venues.max(1),
+            quotes: HashMap::new(),
+            health: BTreeMap::new(),
+        }
+    }
+
+    /// Record a venue tick and return the updated index price for its symbol,
+    /// or `None` while fewer than `min_venues` venues are fresh.
+    pub fn on_tick(&mut self, tick: &VenueTick, now_unix_ms: i64) -> Option<CexPriceTick> {
+        let health = self.health.entry(tick.venue).or_default();
+        health.ticks += 1;
+        health.last_tick_at = Some(tick.received_at);
+        if let Some(exchange_ts_ms) = tick.exchange_ts_ms {
+            let lag = (now_unix_ms - exchange_ts_ms).max(0) as f64;
+            health.lag_ms = Some(match health.lag_ms {
+                Some(avg) => avg + LAG_EWMA_ALPHA * (lag - avg),
+                None => lag,
+            });
+        }
+
+        let venues = self.quotes.entry(tick.symbol).or_default();
+        venues.insert(tick.venue, (tick.price, tick.received_at));
+
+        let mut fresh: Vec<f64> = venues
+            .values()
+            .filter(|(_, at)| tick.received_at.saturating_duration_since(*at) <= self.stale_after)
+            .map(|(price, _)| *price)
+            .collect();
+        if fresh.len() < self.min_venues {
+            return None;
+        }
+        Some(CexPriceTick {
+            symbol: tick.symbol,
+            price: median(&mut fresh),
+            received_at: tick.received_at,
+        })
+    }
+
+    /// Status of every venue seen so far plus any expected venue that never ticked.
+    pub fn venue_status(&self, expected: &[&'static str], now: time::Instant) -> Vec<VenueStatus> {
+        let venues: BTreeSet<&'static str> = self
+            .health
+            .keys()
+            .copied()
+            .chain(expected.iter().copied())
+            .collect();
+        venues
+            .into_iter()
+            .map(|venue| {
+                let health = self.health.get(venue);
+                let age = health
+                    .and_then(|h| h.last_tick_at)
+                    .map(|at| now.saturating_duration_since(at));
+                VenueStatus {
+                    venue,
+                    ticks: health.map_or(0, |h| h.ticks),
+                    last_tick_age_ms: age.map(|a| a.as_millis() as u64),
+                    lag_ms: health.and_then(|h| h.lag_ms),
+                    stale: age.is_none_or(|a| a > self.stale_after),
+                }
+            })
+            .collect()
+    }
+}
+
+fn median(values: &mut [f64]) -> f64 {
+    values.sort_by(|a, b| a.total_cmp(b));
+    let mid = values.len() / 2;
+    if values.len().is_multiple_of(2) {
+        (values[mid - 1] + values[mid]) / 2.0
+    } else {
+        values[mid]
+    }
+}
+
+/// Spawn every configured venue plus the task that merges them into index
+/// ticks on `price_tx`.
+pub fn spawn_composite_feed(
+    config: CompositeFeedConfig,
+    connection: FeedConnectionConfig,
+    price_tx: mpsc::Sender<CexPriceTick>,
+) -> JoinHandle<()> {
+    let feeds = config.build_feeds();
+    let expected: Vec<&'static str> = feeds.iter().map(|feed| feed.venue()).collect();
+    info!(
+        venues = ?expected,
+        symbols = ?config.symbols.iter().map(|s| s.as_str()).collect::<Vec<_>>(),
+        stale_after_ms = config.stale_after_ms,
+        min_venues = config.min_venues,
+        "Spawning composite CEX feed"
+    );
+
+    let (tick_tx, mut tick_rx) = mpsc::channel(4000);
+    for feed in feeds {
+        spawn_feed(feed, connection.clone(), tick_tx.clone());
+    }
+    drop(tick_tx);
+
+    tokio::spawn(async move {
+        let mut index = CompositeIndex::new(config.stale_after_ms, config.min_venues);
+        let mut status_interval = tokio::time::interval(time::Duration::from_secs(
+            config.status_interval_secs.max(1),
+        ));
+        let mut stale_venues: BTreeSet<&'static str> = BTreeSet::new();
+
+        loop {
+            tokio::select! {
+                tick = tick_rx.recv() => {
+                    let Some(tick) = tick else {
+                        warn!("All CEX feeds stopped, composite feed shutting down");
+                        return;
+                    };
+                    let now_ms = chrono::Utc::now().timestamp_millis();
+                    if let Some(index_tick) = index.on_tick(&tick, now_ms) {
+                        if price_tx.try_send(index_tick).is_err() {
+                            // Channel full — drop tick (receiver is slow)
+                            tracing::debug!("Composite price channel full, dropping tick");
+                        }
+                    }
+                }
+                _ = status_interval.tick() => {
+                    for status in index.venue_status(&expected, time::Instant::now()) {
+                        VENUE_STALE
+                            .with_label_values(&[status.venue])
+                            .set(i64::from(status.stale));
+                        if let Some(lag) = status.lag_ms {
+                            VENUE_LAG_MS.with_label_values(&[status.venue]).set(lag);
+                        }
+                        if status.stale && stale_venues.insert(status.venue) {
+                            warn!(
+                                venue = status.venue,
+                                last_tick_age_ms = ?status.last_tick_age_ms,
+                                "CEX venue stale, excluded from composite index"
+                            );
+                        } else if !status.stale && stale_venues.remove(status.venue) {
+                            info!(venue = status.venue, "CEX venue fresh again");
+                        }
+                        info!(
+                            venue = status.venue,
+                            ticks = status.ticks,
+                            lag_ms = ?status.lag_ms.map(|l| l.round()),
+                            stale = status.stale,
+                            "CEX venue status"
+                        );
+                    }
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tick(venue: &'static str, price: f64, at: time::Instant, ts: Option<i64>) -> VenueTick {
+        VenueTick {
+            venue,
+            symbol: CexSymbol::BtcUsdt,
+            price,
+            exchange_ts_ms: ts,
+            received_at: at,
+        }
+    }
+
+    #[test]
+    fn test_median_ignores_an_outlier_venue() {
+        let mut index = CompositeIndex::new(5000, 1);
+        let now = time::Instant::now();
+        index.on_tick(&tick("binance", 70_000.0, now, None), 0);
+        index.on_tick(&tick("coinbase", 70_020.0, now, None), 0);
+        let out = index
+            .on_tick(&tick("kraken", 75_000.0, now, None), 0)
+            .unwrap();
+        assert_eq!(out.price, 70_020.0);
+
+        let out = index.on_tick(&tick("okx", 70_010.0, now, None), 0).unwrap();
+        assert_eq!(out.price, 70_015.0);
+    }
+
+    #[test]
+    fn test_stale_venues_drop_out_and_min_venues_gates_output() {
+        let mut index = CompositeIndex::new(1000, 2);
+        let start = time::Instant::now();
+        assert!(index
+            .on_tick(&tick("binance", 70_000.0, start, None), 0)
+            .is_none());
+        let both = index
+            .on_tick(&tick("coinbase", 70_100.0, start, None), 0)
+            .unwrap();
+        assert_eq!(both.price, 70_050.0);
+
+        // Binance froze; two seconds later coinbase alone is not enough.
+        let later = start + time::Duration::from_secs(2);
+        assert!(index
+            .on_tick(&tick("coinbase", 70_200.0, later, None), 0)
+            .is_none());
+
+        let status = index.venue_status(&["binance", "coinbase", "okx"], later);
+        let stale: Vec<_> = status.iter().filter(|s| s.stale).map(|s| s.venue).collect();
+        assert_eq!(stale, vec!["binance", "okx"]);
+        assert_eq!(status[2].ticks, 0);
+    }
+
+    #[test]
+    fn test_lag_is_smoothed_per_venue() {
+        let mut index = CompositeIndex::new(5000, 1);
+        let now = time::Instant::now();
+        index.on_tick(&tick("okx", 1.0, now, Some(1_000)), 1_100);
+        index.on_tick(&tick("okx", 1.0, now, Some(2_000)), 2_200);
+        index.on_tick(&tick("binance", 1.0, now, None), 2_200);
+
+        let status = index.venue_status(&[], now);
+        assert_eq!(status[0].venue, "binance");
+        assert_eq!(status[0].lag_ms, None);
+        assert!((status[1].lag_ms.unwrap() - 110.0).abs() < 1e-9);
+    }
+}
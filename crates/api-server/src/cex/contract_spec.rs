@@ -0,0 +1,621 @@
+//! Structured payoff definitions for Polymarket crypto price contracts.
+//!
+//! Market titles come in a handful of shapes: a single strike ("above $X",
+//! "less than $X"), a band ("between $X and $Y", one rung of a neg-risk
+//! ladder), or a window return ("Up or Down - 3PM ET"). [`parse_contract_spec`]
+//! turns any of them into a [`ContractSpec`]: YES pays when the reference
+//! price at `reference_time` is at or above `lower` and below `upper`.
+//! Path-dependent contracts ("reach", "dip to") are not mapped, since a
+//! terminal-price model cannot value them.
+
+use chrono::{DateTime, Duration, Utc};
+
+use super::fair_value::{range_fair_value, DigitalQuote};
+use super::price_tracker::CexSymbol;
+
+/// One side of a contract's payoff band.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Bound {
+    /// A fixed strike from the title.
+    Price(f64),
+    /// The reference price at the start of the contract's window ("Up or Down").
+    WindowOpen,
+}
+
+/// Where the contract's resolution price comes from, per its description.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResolutionSource {
+    Binance,
+    Coinbase,
+    Chainlink,
+    Unspecified,
+}
+
+impl ResolutionSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Binance => "binance",
+            Self::Coinbase => "coinbase",
+            Self::Chainlink => "chainlink",
+            Self::Unspecified => "unspecified",
+        }
+    }
+
+    fn from_description(description: &str) -> Self {
+        let lower = description.to_lowercase();
+        if lower.contains("chainlink") {
+            Self::Chainlink
+        } else if lower.contains("binance") {
+            Self::Binance
+        } else if lower.contains("coinbase") {
+            Self::Coinbase
+        } else {
+            Self::Unspecified
+        }
+    }
+}
+
+/// The payoff of a contract's YES outcome.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContractSpec {
+    pub symbol: CexSymbol,
+    /// YES requires the reference price at or above this, when set.
+    pub lower: Option<Bound>,
+    /// YES requires the reference price below this, when set.
+    pub upper: Option<Bound>,
+    /// Start of the observation window, for contracts with a [`Bound::WindowOpen`].
+    pub window_start: Option<DateTime<Utc>>,
+    /// When the reference price is observed.
+    pub reference_time: DateTime<Utc>,
+    pub resolution_source: ResolutionSource,
+}
+
+impl ContractSpec {
+    /// Short payoff label for logs: "above", "below", "range" or "up_down".
+    pub fn kind(&self) -> &'static str {
+        match (self.lower, self.upper) {
+            (Some(Bound::WindowOpen), _) | (_, Some(Bound::WindowOpen)) => "up_down",
+            (Some(_), Some(_)) => "range",
+            (Some(_), None) => "above",
+            _ => "below",
+        }
+    }
+
+    /// Model price of YES given the current `spot`. `window_open` is the
+    /// reference price at `window_start`; it is only needed once the window
+    /// has begun, and without it the contract cannot be valued.
+    pub fn fair_value(
+        &self,
+        spot: f64,
+        window_open: Option<f64>,
+        vol_per_sqrt_sec: f64,
+        now: DateTime<Utc>,
+    ) -> Option<DigitalQuote> {
+        let secs_to_reference = secs_between(now, self.reference_time);
+
+        if let Some(start) = self.window_start.filter(|start| now < *start) {
+            // The open is not set yet, so today's spot carries no information:
+            // only the window's own length matters.
+            let window_secs = secs_between(start, self.reference_time);
+            let quote = range_fair_value(
+                1.0,
+                self.lower.map(|b| self.unit_bound(b)),
+                self.upper.map(|b| self.unit_bound(b)),
+                vol_per_sqrt_sec,
+                window_secs,
+            )?;
+            return Some(DigitalQuote {
+                delta: 0.0,
+                ..quote
+            });
+        }
+
+        let resolve = |bound: Option<Bound>| -> Option<Option<f64>> {
+            match bound {
+                None => Some(None),
+                Some(Bound::Price(price)) => Some(Some(price)),
+                Some(Bound::WindowOpen) => window_open.map(Some),
+            }
+        };
+        range_fair_value(
+            spot,
+            resolve(self.lower)?,
+            resolve(self.upper)?,
+            vol_per_sqrt_sec,
+            secs_to_reference,
+        )
+    }
+
+    /// A bound expressed relative to a window open of 1.0.
+    fn unit_bound(&self, bound: Bound) -> f64 {
+        match bound {
+            Bound::WindowOpen => 1.0,
+            // Fixed strikes never share a spec with a window open.
+            Bound::Price(price) => price,
+        }
+    }
+}
+
+fn secs_between(from: DateTime<Utc>, to: DateTime<Utc>) -> f64 {
+    (to - from).num_milliseconds() as f64 / 1000.0
+}
+
+/// Parse a market title (and its resolution text) into a payoff. `end_date`
+/// is when the market resolves and becomes the reference time.
+pub fn parse_contract_spec(
+    question: &str,
+    description: Option<&str>,
+    end_date: DateTime<Utc>,
+) -> Option<ContractSpec> {
+    let lower_question = question.to_lowercase();
+    let symbol = symbol_from_question(&lower_question)?;
+    let words = normalized_words(&lower_question);
+
+    if PATH_DEPENDENT.iter().any(|w| words.contains(w)) {
+        return None;
+    }
+
+    let mut spec = ContractSpec {
+        symbol,
+        lower: None,
+        upper: None,
+        window_start: None,
+        reference_time: end_date,
+        resolution_source: description
+            .map(ResolutionSource::from_description)
+            .unwrap_or(ResolutionSource::Unspecified),
+    };
+
+    if words.contains(" up or down ") {
+        let window_minutes = window_minutes(&lower_question, &words)?;
+        spec.lower = Some(Bound::WindowOpen);
+        spec.window_start = Some(end_date - Duration::minutes(window_minutes));
+        return Some(spec);
+    }
+
+    let prices = dollar_amounts(&lower_question);
+    match prices.as_slice() {
+        [(_, _, price)] => {
+            let above = ABOVE.iter().any(|w| words.contains(w));
+            let below = BELOW.iter().any(|w| words.contains(w));
+            match (above, below) {
+                (true, false) => spec.lower = Some(Bound::Price(*price)),
+                (false, true) => spec.upper = Some(Bound::Price(*price)),
+                _ => return None,
+            }
+        }
+        [(_, first_end, a), (second_start, _, b)] => {
+            let joiner = lower_question[*first_end..*second_start].trim();
+            let is_band = words.contains(" between ") || joiner == "-" || joiner == "to";
+            if !is_band || a == b {
+                return None;
+            }
+            spec.lower = Some(Bound::Price(a.min(*b)));
+            spec.upper = Some(Bound::Price(a.max(*b)));
+        }
+        _ => return None,
+    }
+    Some(spec)
+}
+
+/// Phrases (space-padded, on normalized words) that put the strike below YES.
+const ABOVE: &[&str] = &[
+    " above ",
+    " greater than ",
+    " higher than ",
+    " over ",
+    " or higher ",
+    " or more ",
+    " or above ",
+    " at least ",
+];
+
+/// Phrases that put the strike above YES.
+const BELOW: &[&str] = &[
+    " below ",
+    " less than ",
+    " lower than ",
+    " under ",
+    " or lower ",
+    " or less ",
+    " or below ",
+];
+
+/// Contracts that pay on touching a level rather than on the closing price.
+const PATH_DEPENDENT: &[&str] = &[
+    " reach ",
+    " reaches ",
+    " hit ",
+    " hits ",
+    " dip ",
+    " dips ",
+    " touch ",
+    " all time high ",
+    " ath ",
+];
+
+/// The tracked asset a question is about, matched on whole words so that
+/// e.g. "resolve" does not read as SOL.
+pub fn symbol_from_question(question_lower: &str) -> Option<CexSymbol> {
+    question_lower
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .find_map(|word| match word {
+            "bitcoin" | "btc" => Some(CexSymbol::BtcUsdt),
+            "ethereum" | "eth" | "ether" => Some(CexSymbol::EthUsdt),
+            "solana" | "sol" => Some(CexSymbol::SolUsdt),
+            "xrp" | "ripple" => Some(CexSymbol::XrpUsdt),
+            _ => None,
+        })
+}
+
+/// Lowercase alphanumeric words joined by single spaces, padded at both ends
+/// so phrases can be matched on word boundaries.
+fn normalized_words(question_lower: &str) -> String {
+    let mut out = String::from(" ");
+    for word in question_lower
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty())
+    {
+        out.push_str(word);
+        out.push(' ');
+    }
+    out
+}
+
+/// Every `$` amount in the title as (start, end, value) byte offsets, e.g.
+/// "$70,000", "$3,500.50", "$0.60", "$110k", "$1.2m".
+fn dollar_amounts(question_lower: &str) -> Vec<(usize, usize, f64)> {
+    let bytes = question_lower.as_bytes();
+    let mut amounts = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] != b'$' {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        i += 1;
+        let mut digits = String::new();
+        while i < bytes.len() && (bytes[i].is_ascii_digit() || bytes[i] == b',' || bytes[i] == b'.')
+        {
+            if bytes[i] != b',' {
+                digits.push(bytes[i] as char);
+            }
+            i += 1;
+        }
+        // A trailing period ends the sentence rather than starting decimals.
+        let digits = digits.trim_end_matches('.');
+        let mut multiplier = 1.0;
+        let suffix = match bytes.get(i) {
+            Some(b'k') => Some(1e3),
+            Some(b'm') => Some(1e6),
+            Some(b'b') => Some(1e9),
+            _ => None,
+        };
+        if let Some(suffix) = suffix {
+            if !bytes.get(i + 1).is_some_and(|c| c.is_ascii_alphabetic()) {
+                multiplier = suffix;
+                i += 1;
+            }
+        }
+        if let Ok(value) = digits.parse::<f64>() {
+            if value > 0.0 {
+                amounts.push((start, i, value * multiplier));
+            }
+        }
+    }
+    amounts
+}
+
+/// Length of an "Up or Down" window from its title: a clock range
+/// ("3:00PM-3:15PM ET"), a single hour ("3PM ET"), a week, or a whole day.
+fn window_minutes(question_lower: &str, words: &str) -> Option<i64> {
+    let times = clock_times(question_lower);
+    match times.as_slice() {
+        [(_, first_end, from), (second_start, _, to)]
+            if question_lower[*first_end..*second_start].trim() == "-" =>
+        {
+            let minutes = (to - from).rem_euclid(24 * 60);
+            (minutes > 0).then_some(minutes)
+        }
+        [_] => Some(60),
+        [] if words.contains(" week ") => Some(7 * 24 * 60),
+        [] => Some(24 * 60),
+        _ => None,
+    }
+}
+
+/// Clock times like "3pm", "3 pm" or "11:45am" as (start, end, minutes past
+/// midnight).
+fn clock_times(question_lower: &str) -> Vec<(usize, usize, i64)> {
+    let bytes = question_lower.as_bytes();
+    let mut times = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let starts_number =
+            bytes[i].is_ascii_digit() && (i == 0 || !bytes[i - 1].is_ascii_alphanumeric());
+        if !starts_number {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        let mut j = i;
+        while j < bytes.len() && bytes[j].is_ascii_digit() {
+            j += 1;
+        }
+        let hour: i64 = question_lower[start..j].parse().unwrap_or(-1);
+        let mut minute = 0;
+        if bytes.get(j) == Some(&b':') {
+            let mut k = j + 1;
+            while k < bytes.len() && bytes[k].is_ascii_digit() {
+                k += 1;
+            }
+            minute = question_lower[j + 1..k].parse().unwrap_or(-1);
+            j = k;
+        }
+        let mut k = j;
+        if bytes.get(k) == Some(&b' ') {
+            k += 1;
+        }
+        let is_word_end = !bytes.get(k + 2).is_some_and(|c| c.is_ascii_alphabetic());
+        match question_lower.get(k..k + 2) {
+            Some(m @ ("am" | "pm"))
+                if is_word_end && (1..=12).contains(&hour) && (0..60).contains(&minute) =>
+            {
+                let hour24 = hour % 12 + if m == "pm" { 12 } else { 0 };
+                times.push((start, k + 2, hour24 * 60 + minute));
+                i = k + 2;
+            }
+            _ => i = j,
+        }
+    }
+    times
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[derive(Debug, PartialEq)]
+    enum Expect {
+        Above(f64),
+        Below(f64),
+        Range(f64, f64),
+        /// Window length in minutes.
+        UpDown(i64),
+        Unmapped,
+    }
+
+    fn end() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 10, 18, 20, 0, 0).unwrap()
+    }
+
+    fn expect_of(spec: Option<ContractSpec>) -> Expect {
+        let Some(spec) = spec else {
+            return Expect::Unmapped;
+        };
+        match (spec.lower, spec.upper) {
+            (Some(Bound::WindowOpen), None) => {
+                Expect::UpDown((spec.reference_time - spec.window_start.unwrap()).num_minutes())
+            }
+            (Some(Bound::Price(a)), Some(Bound::Price(b))) => Expect::Range(a, b),
+            (Some(Bound::Price(a)), None) => Expect::Above(a),
+            (None, Some(Bound::Price(b))) => Expect::Below(b),
+            other => panic!("unexpected bounds {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_title_fixtures() {
+        use Expect::*;
+        let fixtures: &[(&str, Expect)] = &[
+            // Single strike, daily and hourly ladders.
+            (
+                "Will the price of Bitcoin be above $112,000 on October 18?",
+                Above(112_000.0),
+            ),
+            (
+                "Will the price of Ethereum be above $4,000 on October 18?",
+                Above(4_000.0),
+            ),
+            ("Bitcoin above $110k on October 18?", Above(110_000.0)),
+            ("Will BTC be above $70,000?", Above(70_000.0)),
+            ("ETH price higher than $3,500.50?", Above(3_500.5)),
+            ("Bitcoin above $95000 by Friday?", Above(95_000.0)),
+            (
+                "Will Solana close at $200 or higher on October 18?",
+                Above(200.0),
+            ),
+            ("Will XRP be greater than $2.50 at 4PM ET?", Above(2.5)),
+            (
+                "Will Ethereum be at least $4,200 on October 18?",
+                Above(4_200.0),
+            ),
+            (
+                "Will the price of Bitcoin be greater than $120,000 on October 18?",
+                Above(120_000.0),
+            ),
+            (
+                "Will the price of Bitcoin be less than $100,000 on October 18?",
+                Below(100_000.0),
+            ),
+            ("Will Solana be below $150 at 5pm ET?", Below(150.0)),
+            (
+                "Will ETH close under $3,000.00 on October 18?",
+                Below(3_000.0),
+            ),
+            ("Will XRP finish October 18 at $0.60 or lower?", Below(0.6)),
+            (
+                "Bitcoin lower than $1.2m on October 18?",
+                Below(1_200_000.0),
+            ),
+            // Neg-risk bands.
+            (
+                "Will the price of Bitcoin be between $108,000 and $110,000 on October 18?",
+                Range(108_000.0, 110_000.0),
+            ),
+            (
+                "Will the price of Ethereum be between $3,900 and $4,000 on October 18?",
+                Range(3_900.0, 4_000.0),
+            ),
+            (
+                "Will the price of Solana be between $190 and $200 on October 18?",
+                Range(190.0, 200.0),
+            ),
+            (
+                "Will the price of XRP be between $2.40 and $2.50 on October 18?",
+                Range(2.4, 2.5),
+            ),
+            (
+                "Bitcoin price on October 18: $110k-$112k?",
+                Range(110_000.0, 112_000.0),
+            ),
+            (
+                "Ethereum $4,000 to $4,100 on October 18?",
+                Range(4_000.0, 4_100.0),
+            ),
+            // Window returns.
+            ("Bitcoin Up or Down - October 18, 3PM ET", UpDown(60)),
+            ("Ethereum Up or Down - October 18, 11AM ET", UpDown(60)),
+            (
+                "Solana Up or Down - October 18, 3:00PM-3:15PM ET",
+                UpDown(15),
+            ),
+            ("XRP Up or Down - October 18, 3:05PM-3:10PM ET", UpDown(5)),
+            (
+                "Bitcoin Up or Down - October 18, 12:00PM-4:00PM ET",
+                UpDown(240),
+            ),
+            (
+                "Bitcoin Up or Down - October 18, 11:45PM-12:00AM ET",
+                UpDown(15),
+            ),
+            ("Bitcoin Up or Down on October 18?", UpDown(24 * 60)),
+            ("Ethereum Up or Down this week?", UpDown(7 * 24 * 60)),
+            // Path-dependent or unrelated.
+            ("What price will Bitcoin hit in October?", Unmapped),
+            ("Will Bitcoin reach $150,000 by December 31?", Unmapped),
+            ("Will Ethereum dip to $3,000 in October?", Unmapped),
+            ("Will Bitcoin hit a new all time high in 2026?", Unmapped),
+            ("Will the Fed cut rates above 50bps?", Unmapped),
+            ("Will it rain tomorrow?", Unmapped),
+            ("Will MicroStrategy buy more Bitcoin in October?", Unmapped),
+            ("Will BTC be above $100,000 and ETH above $4,000?", Unmapped),
+            (
+                "Will Bitcoin be above or below $110,000 on October 18?",
+                Unmapped,
+            ),
+        ];
+
+        for (title, expected) in fixtures {
+            let actual = expect_of(parse_contract_spec(title, None, end()));
+            assert_eq!(&actual, expected, "{title}");
+        }
+    }
+
+    #[test]
+    fn test_symbol_from_question() {
+        assert_eq!(
+            symbol_from_question("will solana be above $150 at 5pm et?"),
+            Some(CexSymbol::SolUsdt)
+        );
+        assert_eq!(
+            symbol_from_question("xrp above $0.60 on friday?"),
+            Some(CexSymbol::XrpUsdt)
+        );
+        assert_eq!(
+            symbol_from_question("will the senate resolve this by friday?"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_symbol_and_reference_time() {
+        let spec = parse_contract_spec(
+            "Solana Up or Down - October 18, 3:00PM-3:15PM ET",
+            None,
+            end(),
+        )
+        .unwrap();
+        assert_eq!(spec.symbol, CexSymbol::SolUsdt);
+        assert_eq!(spec.reference_time, end());
+        assert_eq!(spec.window_start, Some(end() - Duration::minutes(15)));
+        assert_eq!(spec.kind(), "up_down");
+    }
+
+    #[test]
+    fn test_resolution_source_from_description() {
+        let cases = [
+            (
+                "This market will resolve to \"Up\" if the close price is greater than or equal to the open price for the BTC/USDT 1 hour candle on Binance.",
+                ResolutionSource::Binance,
+            ),
+            (
+                "The resolution source for this market is information from Chainlink, specifically the BTC/USD data stream.",
+                ResolutionSource::Chainlink,
+            ),
+            (
+                "Resolves according to the Coinbase ETH-USD price at 12:00 PM ET.",
+                ResolutionSource::Coinbase,
+            ),
+            ("Resolves per a credible source.", ResolutionSource::Unspecified),
+        ];
+        for (description, expected) in cases {
+            let spec = parse_contract_spec(
+                "Will the price of Bitcoin be above $112,000 on October 18?",
+                Some(description),
+                end(),
+            )
+            .unwrap();
+            assert_eq!(spec.resolution_source, expected);
+        }
+    }
+
+    /// ~60% annualized volatility expressed per √second.
+    const VOL: f64 = 0.60 / 5_615.69;
+
+    #[test]
+    fn test_every_payoff_kind_is_priced() {
+        let now = end() - Duration::minutes(30);
+        let price = |title: &str, open: Option<f64>| {
+            parse_contract_spec(title, None, end())
+                .unwrap()
+                .fair_value(110_000.0, open, VOL, now)
+                .map(|q| q.p_yes)
+        };
+
+        let above = price("Bitcoin above $110k on October 18?", None).unwrap();
+        let below = price("Bitcoin below $110k on October 18?", None).unwrap();
+        assert!((above + below - 1.0).abs() < 1e-9);
+
+        // A ladder of bands partitions the outcome space.
+        let ladder: f64 = [
+            "Will the price of Bitcoin be less than $108,000 on October 18?",
+            "Will the price of Bitcoin be between $108,000 and $110,000 on October 18?",
+            "Will the price of Bitcoin be between $110,000 and $112,000 on October 18?",
+            "Will the price of Bitcoin be greater than $112,000 on October 18?",
+        ]
+        .iter()
+        .map(|title| price(title, None).unwrap())
+        .sum();
+        assert!((ladder - 1.0).abs() < 1e-9);
+
+        // Window already running: priced off the recorded open.
+        let hour = "Bitcoin Up or Down - October 18, 3PM ET";
+        assert!(price(hour, None).is_none());
+        assert!(price(hour, Some(109_500.0)).unwrap() > 0.6);
+        assert!(price(hour, Some(110_500.0)).unwrap() < 0.4);
+    }
+
+    #[test]
+    fn test_up_down_before_window_start_is_a_coin_flip() {
+        let spec =
+            parse_contract_spec("Bitcoin Up or Down - October 18, 3PM ET", None, end()).unwrap();
+        let quote = spec
+            .fair_value(110_000.0, None, VOL, end() - Duration::hours(2))
+            .unwrap();
+        assert!((quote.p_yes - 0.5).abs() < 0.01);
+        assert_eq!(quote.fair_value_move(VOL, 5.0), 0.0);
+    }
+}
@@ -0,0 +1,185 @@
+//! Digital-option fair value for crypto price contracts.
+//!
+//! The CEX price is modelled as driftless geometric Brownian motion over the
+//! contract's remaining life, so YES on "above K" pays like a cash-or-nothing
+//! digital call and is worth `N(d2)`; a band between two strikes is the
+//! difference of two such calls. Volatility is the realized volatility
+//! measured by [`super::PriceTracker`], in log-return units per √second.
+
+/// Model price of a contract's YES outcome.
+#[derive(Debug, Clone, Copy)]
+pub struct DigitalQuote {
+    /// Probability YES resolves true (its fair price).
+    pub p_yes: f64,
+    /// Standard deviation of the log price at expiry (σ√τ).
+    pub total_sd: f64,
+    /// Change in `p_yes` per unit move in log spot.
+    pub delta: f64,
+}
+
+impl DigitalQuote {
+    /// Expected one-sigma move in fair value over `horizon_secs` of price
+    /// noise: how far the quote can drift between the tick we priced off and
+    /// our order reaching the book. Largest at the money and near expiry.
+    pub fn fair_value_move(&self, vol_per_sqrt_sec: f64, horizon_secs: f64) -> f64 {
+        (self.delta.abs() * vol_per_sqrt_sec * horizon_secs.max(0.0).sqrt()).min(1.0)
+    }
+}
+
+/// Fair value of a digital contract on `spot` finishing above (or below)
+/// `strike` in `secs_to_expiry`. `None` for non-positive prices or a non-finite volatility.
+pub fn digital_fair_value(
+    spot: f64,
+    strike: f64,
+    vol_per_sqrt_sec: f64,
+    secs_to_expiry: f64,
+    is_above: bool,
+) -> Option<DigitalQuote> {
+    if is_above {
+        range_fair_value(spot, Some(strike), None, vol_per_sqrt_sec, secs_to_expiry)
+    } else {
+        range_fair_value(spot, None, Some(strike), vol_per_sqrt_sec, secs_to_expiry)
+    }
+}
+
+/// Fair value of a contract paying when `spot` finishes at or above `lower`
+/// and below `upper` in `secs_to_expiry`; a missing bound is unbounded. This
+/// is a long digital call at `lower` and a short one at `upper`, so it covers
+/// single strikes and neg-risk bands alike. `None` for non-positive prices,
+/// an empty band or a non-finite volatility.
+pub fn range_fair_value(
+    spot: f64,
+    lower: Option<f64>,
+    upper: Option<f64>,
+    vol_per_sqrt_sec: f64,
+    secs_to_expiry: f64,
+) -> Option<DigitalQuote> {
+    let positive = |bound: Option<f64>| bound.is_none_or(|b| b > 0.0);
+    if spot <= 0.0 || !positive(lower) || !positive(upper) || !vol_per_sqrt_sec.is_finite() {
+        return None;
+    }
+    if let (Some(lower), Some(upper)) = (lower, upper) {
+        if lower >= upper {
+            return None;
+        }
+    }
+    let total_sd = vol_per_sqrt_sec.max(0.0) * secs_to_expiry.max(0.0).sqrt();
+
+    // At expiry (or with no volatility) the outcome is already decided.
+    if total_sd <= 1e-9 {
+        let inside = lower.is_none_or(|l| spot >= l) && upper.is_none_or(|u| spot < u);
+        return Some(DigitalQuote {
+            p_yes: if inside { 1.0 } else { 0.0 },
+            total_sd,
+            delta: 0.0,
+        });
+    }
+
+    // Probability of finishing above `strike`, and its sensitivity to log spot.
+    let call = |strike: f64| {
+        let d2 = ((spot / strike).ln() - 0.5 * total_sd * total_sd) / total_sd;
+        (normal_cdf(d2), normal_pdf(d2) / total_sd)
+    };
+    let (p_lower, delta_lower) = lower.map_or((1.0, 0.0), call);
+    let (p_upper, delta_upper) = upper.map_or((0.0, 0.0), call);
+
+    Some(DigitalQuote {
+        p_yes: (p_lower - p_upper).clamp(0.0, 1.0),
+        total_sd,
+        delta: delta_lower - delta_upper,
+    })
+}
+
+fn normal_pdf(x: f64) -> f64 {
+    if !x.is_finite() {
+        return 0.0;
+    }
+    (-0.5 * x * x).exp() / (2.0 * std::f64::consts::PI).sqrt()
+}
+
+/// Standard normal CDF (Abramowitz & Stegun 7.1.26, |error| < 1.5e-7).
+fn normal_cdf(x: f64) -> f64 {
+    let z = x.abs() / std::f64::consts::SQRT_2;
+    let t = 1.0 / (1.0 + 0.327_591_1 * z);
+    let poly = t
+        * (0.254_829_592
+            + t * (-0.284_496_736
+                + t * (1.421_413_741 + t * (-1.453_152_027 + t * 1.061_405_429))));
+    let erfc = poly * (-z * z).exp();
+    if x >= 0.0 {
+        1.0 - 0.5 * erfc
+    } else {
+        0.5 * erfc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// ~60% annualized volatility expressed per √second.
+    const VOL: f64 = 0.60 / 5_615.69;
+
+    #[test]
+    fn test_normal_cdf_matches_known_values() {
+        assert!((normal_cdf(0.0) - 0.5).abs() < 1e-7);
+        assert!((normal_cdf(1.96) - 0.975).abs() < 1e-4);
+        assert!((normal_cdf(-1.0) - 0.158_655).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_at_the_money_is_a_coin_flip_and_below_mirrors_above() {
+        let above = digital_fair_value(70_000.0, 70_000.0, VOL, 3_600.0, true).unwrap();
+        let below = digital_fair_value(70_000.0, 70_000.0, VOL, 3_600.0, false).unwrap();
+        assert!((above.p_yes - 0.5).abs() < 0.01);
+        assert!((above.p_yes + below.p_yes - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_distance_to_strike_and_time_to_expiry_both_matter() {
+        // $500 above the strike: near-certain with a minute left, far less so
+        // with a day left.
+        let minute = digital_fair_value(70_500.0, 70_000.0, VOL, 60.0, true).unwrap();
+        let day = digital_fair_value(70_500.0, 70_000.0, VOL, 86_400.0, true).unwrap();
+        assert!(minute.p_yes > 0.99);
+        assert!(day.p_yes > 0.5 && day.p_yes < 0.6);
+
+        let expired = digital_fair_value(69_900.0, 70_000.0, VOL, 0.0, true).unwrap();
+        assert_eq!(expired.p_yes, 0.0);
+        assert_eq!(expired.fair_value_move(VOL, 5.0), 0.0);
+    }
+
+    #[test]
+    fn test_fair_value_move_is_largest_at_the_money() {
+        let atm = digital_fair_value(70_000.0, 70_000.0, VOL, 600.0, true).unwrap();
+        let otm = digital_fair_value(68_000.0, 70_000.0, VOL, 600.0, true).unwrap();
+        assert!(atm.fair_value_move(VOL, 5.0) > otm.fair_value_move(VOL, 5.0));
+        assert!(atm.fair_value_move(VOL, 5.0) > 0.01);
+    }
+
+    #[test]
+    fn test_range_is_difference_of_digitals() {
+        let below = digital_fair_value(70_000.0, 69_000.0, VOL, 3_600.0, false).unwrap();
+        let band =
+            range_fair_value(70_000.0, Some(69_000.0), Some(71_000.0), VOL, 3_600.0).unwrap();
+        let above = digital_fair_value(70_000.0, 71_000.0, VOL, 3_600.0, true).unwrap();
+        assert!((below.p_yes + band.p_yes + above.p_yes - 1.0).abs() < 1e-9);
+
+        // Centred in the band, small moves barely change its value; at the
+        // band's edge they matter.
+        let centred =
+            range_fair_value(70_000.0, Some(69_000.0), Some(71_000.0), VOL, 600.0).unwrap();
+        let edge = range_fair_value(69_000.0, Some(69_000.0), Some(71_000.0), VOL, 600.0).unwrap();
+        assert!(centred.fair_value_move(VOL, 5.0) < edge.fair_value_move(VOL, 5.0));
+
+        let settled = range_fair_value(70_000.0, Some(69_000.0), Some(71_000.0), VOL, 0.0).unwrap();
+        assert_eq!(settled.p_yes, 1.0);
+        assert!(range_fair_value(70_000.0, Some(71_000.0), Some(69_000.0), VOL, 60.0).is_none());
+    }
+
+    #[test]
+    fn test_rejects_bad_inputs() {
+        assert!(digital_fair_value(0.0, 70_000.0, VOL, 60.0, true).is_none());
+        assert!(digital_fair_value(70_000.0, 70_000.0, f64::NAN, 60.0, true).is_none());
+    }
+}
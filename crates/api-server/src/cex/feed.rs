@@ -0,0 +1,257 @@
+//! Common interface for exchange WebSocket price feeds.
+//!
+//! Each venue implements [`CexFeed`]: where to connect, what to subscribe to
+//! and how to parse a text frame. [`spawn_feed`] owns the connection loop
+//! (keepalive, read timeout, reconnect backoff) for every venue, and the
+//! composite feed merges their ticks into one index.
+
+use futures_util::{SinkExt, StreamExt};
+use std::sync::Arc;
+use std::time;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio_tungstenite::tungstenite::Message;
+use tracing::{debug, info, warn};
+
+use super::price_tracker::CexSymbol;
+
+/// One trade or ticker print from a single venue.
+#[derive(Debug, Clone)]
+pub struct VenueTick {
+    pub venue: &'static str,
+    pub symbol: CexSymbol,
+    pub price: f64,
+    /// Venue-side event time in unix milliseconds, when the venue sends one.
+    pub exchange_ts_ms: Option<i64>,
+    pub received_at: time::Instant,
+}
+
+/// An exchange's public price stream.
+pub trait CexFeed: Send + Sync {
+    /// Short venue name used in logs and metrics.
+    fn venue(&self) -> &'static str;
+
+    /// WebSocket URL to connect to.
+    fn url(&self) -> String;
+
+    /// Frames sent right after connecting, typically subscriptions.
+    fn subscribe_messages(&self) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Application-level ping the venue expects, and how often.
+    fn keepalive(&self) -> Option<(time::Duration, String)> {
+        None
+    }
+
+    /// Parse a text frame into ticks; anything else (acks, heartbeats) yields none.
+    fn parse(&self, text: &str) -> Vec<VenueTick>;
+}
+
+/// Connection settings shared by every venue.
+#[derive(Debug, Clone)]
+pub struct FeedConnectionConfig {
+    /// Initial reconnect delay in milliseconds.
+    pub reconnect_delay_ms: u64,
+    /// Maximum reconnect delay in milliseconds.
+    pub max_reconnect_delay_ms: u64,
+    /// Reconnect when nothing arrives for this long.
+    pub read_timeout_secs: u64,
+}
+
+impl Default for FeedConnectionConfig {
+    fn default() -> Self {
+        Self {
+            reconnect_delay_ms: 1000,
+            max_reconnect_delay_ms: 30000,
+            read_timeout_secs: 30,
+        }
+    }
+}
+
+/// Spawn a client for one venue that forwards its ticks on the channel.
+pub fn spawn_feed(
+    feed: Arc<dyn CexFeed>,
+    config: FeedConnectionConfig,
+    tick_tx: mpsc::Sender<VenueTick>,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let venue = feed.venue();
+        let mut reconnect_delay = time::Duration::from_millis(config.reconnect_delay_ms);
+        let max_delay = time::Duration::from_millis(config.max_reconnect_delay_ms);
+
+        loop {
+            let url = feed.url();
+            info!(venue, url = %url, "Connecting to CEX WebSocket");
+
+            match tokio_tungstenite::connect_async(&url).await {
+                Ok((ws_stream, _)) => {
+                    info!(venue, "CEX WebSocket connected");
+                    reconnect_delay = time::Duration::from_millis(config.reconnect_delay_ms);
+                    if !run_connection(feed.as_ref(), ws_stream, &config, &tick_tx).await {
+                        info!(venue, "Tick channel closed, stopping CEX feed");
+                        return;
+                    }
+                }
+                Err(e) => {
+                    warn!(venue, error = %e, "Failed to connect to CEX WebSocket");
+                }
+            }
+
+            warn!(
+                venue,
+                delay_ms = reconnect_delay.as_millis(),
+                "Reconnecting to CEX WebSocket"
+            );
+            tokio::time::sleep(reconnect_delay).await;
+            reconnect_delay = (reconnect_delay * 2).min(max_delay);
+        }
+    })
+}
+
+/// Read one connection until it drops. Returns false once the receiver is gone.
+async fn run_connection<S>(
+    feed: &dyn CexFeed,
+    ws_stream: tokio_tungstenite::WebSocketStream<S>,
+    config: &FeedConnectionConfig,
+    tick_tx: &mpsc::Sender<VenueTick>,
+) -> bool
+where
+    S: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin,
+{
+    let venue = feed.venue();
+    let (mut write, mut read) = ws_stream.split();
+
+    for message in feed.subscribe_messages() {
+        if let Err(e) = write.send(Message::Text(message)).await {
+            warn!(venue, error = %e, "Failed to send CEX subscription");
+            return true;
+        }
+    }
+
+    let (keepalive_every, keepalive_message) = feed
+        .keepalive()
+        .unwrap_or((time::Duration::from_secs(3600), String::new()));
+    let mut keepalive = tokio::time::interval(keepalive_every);
+    keepalive.tick().await;
+    let read_timeout = time::Duration::from_secs(config.read_timeout_secs);
+
+    loop {
+        tokio::select! {
+            _ = keepalive.tick(), if !keepalive_message.is_empty() => {
+                if let Err(e) = write.send(Message::Text(keepalive_message.clone())).await {
+                    warn!(venue, error = %e, "Failed to send CEX keepalive");
+                    return true;
+                }
+            }
+            next = tokio::time::timeout(read_timeout, read.next()) => match next {
+                Ok(Some(Ok(Message::Text(text)))) => {
+                    for tick in feed.parse(&text) {
+                        match tick_tx.try_send(tick) {
+                            Ok(()) => {}
+                            Err(mpsc::error::TrySendError::Full(_)) => {
+                                // Receiver is slow; a newer tick follows shortly.
+                                debug!(venue, "CEX tick channel full, dropping tick");
+                            }
+                            Err(mpsc::error::TrySendError::Closed(_)) => return false,
+                        }
+                    }
+                }
+                Ok(Some(Ok(Message::Ping(data)))) => {
+                    if let Err(e) = write.send(Message::Pong(data)).await {
+                        warn!(venue, error = %e, "Failed to send pong");
+                        return true;
+                    }
+                }
+                Ok(Some(Ok(Message::Close(_)))) => {
+                    info!(venue, "CEX WebSocket closed by server");
+                    return true;
+                }
+                Ok(Some(Err(e))) => {
+                    warn!(venue, error = %e, "CEX WebSocket error");
+                    return true;
+                }
+                Ok(None) => {
+                    info!(venue, "CEX WebSocket stream ended");
+                    return true;
+                }
+                Err(_) => {
+                    warn!(venue, timeout_secs = config.read_timeout_secs, "CEX WebSocket read timeout");
+                    return true;
+                }
+                Ok(Some(Ok(_))) => {}
+            }
+        }
+    }
+}
+
+/// Parse a decimal price sent as a string.
+pub(crate) fn parse_price(value: &str) -> Option<f64> {
+    value
+        .parse::<f64>()
+        .ok()
+        .filter(|p| p.is_finite() && *p > 0.0)
+}
+
+/// Parse an RFC 3339 venue timestamp into unix milliseconds.
+pub(crate) fn parse_rfc3339_ms(value: &str) -> Option<i64> {
+    chrono::DateTime::parse_from_rfc3339(value)
+        .ok()
+        .map(|at| at.timestamp_millis())
+}
+
+/// Local WebSocket stub for exercising a feed end to end.
+#[cfg(test)]
+pub(crate) mod test_support {
+    use super::*;
+    use tokio::net::TcpListener;
+
+    /// Serve one connection: record what the client sends first, reply with
+    /// `frames`, then collect the ticks `feed` produced from them.
+    pub async fn run_against_stub<F>(
+        make_feed: impl FnOnce(String) -> F,
+        frames: Vec<String>,
+        expected_ticks: usize,
+    ) -> (Vec<String>, Vec<VenueTick>)
+    where
+        F: CexFeed + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let feed = make_feed(url);
+        let subscriptions = feed.subscribe_messages().len();
+
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+            let mut received = Vec::new();
+            while received.len() < subscriptions {
+                match ws.next().await {
+                    Some(Ok(Message::Text(text))) => received.push(text),
+                    Some(Ok(_)) => {}
+                    _ => break,
+                }
+            }
+            for frame in frames {
+                ws.send(Message::Text(frame)).await.unwrap();
+            }
+            // Hold the socket open until the client has read everything.
+            let _ = ws.next().await;
+            received
+        });
+
+        let (tick_tx, mut tick_rx) = mpsc::channel(64);
+        let client = spawn_feed(Arc::new(feed), FeedConnectionConfig::default(), tick_tx);
+        let mut ticks = Vec::new();
+        while ticks.len() < expected_ticks {
+            let tick = tokio::time::timeout(time::Duration::from_secs(5), tick_rx.recv())
+                .await
+                .expect("feed produced no tick")
+                .unwrap();
+            ticks.push(tick);
+        }
+        client.abort();
+        let received = server.await.unwrap();
+        (received, ticks)
+    }
+}
@@ -0,0 +1,129 @@
+//! Kraken v2 trade feed (USD pairs).
+
+use serde::Deserialize;
+use std::time;
+
+use super::feed::{parse_rfc3339_ms, CexFeed, VenueTick};
+use super::price_tracker::CexSymbol;
+
+/// Kraken WebSocket v2 `trade` channel.
+#[derive(Debug, Clone)]
+pub struct KrakenFeed {
+    /// Default: wss://ws.kraken.com/v2
+    pub url: String,
+    pub symbols: Vec<CexSymbol>,
+}
+
+impl KrakenFeed {
+    pub fn from_env(symbols: Vec<CexSymbol>) -> Self {
+        Self {
+            url: polymarket_core::settings::get("KRAKEN_WS_URL"),
+            symbols,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TradeMessage {
+    channel: String,
+    #[serde(default)]
+    data: Vec<TradeData>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TradeData {
+    symbol: String,
+    price: f64,
+    timestamp: Option<String>,
+}
+
+impl CexFeed for KrakenFeed {
+    fn venue(&self) -> &'static str {
+        "kraken"
+    }
+
+    fn url(&self) -> String {
+        self.url.clone()
+    }
+
+    fn subscribe_messages(&self) -> Vec<String> {
+        let pairs: Vec<String> = self
+            .symbols
+            .iter()
+            .map(|symbol| format!("{}/USD", symbol.base()))
+            .collect();
+        vec![serde_json::json!({
+            "method": "subscribe",
+            "params": { "channel": "trade", "symbol": pairs },
+        })
+        .to_string()]
+    }
+
+    fn keepalive(&self) -> Option<(time::Duration, String)> {
+        // Trades in quiet pairs can be minutes apart; keep the read loop fed.
+        Some((
+            time::Duration::from_secs(15),
+            r#"{"method":"ping"}"#.to_string(),
+        ))
+    }
+
+    fn parse(&self, text: &str) -> Vec<VenueTick> {
+        let Ok(message) = serde_json::from_str::<TradeMessage>(text) else {
+            return Vec::new();
+        };
+        if message.channel != "trade" {
+            return Vec::new();
+        }
+        let received_at = time::Instant::now();
+        message
+            .data
+            .into_iter()
+            .filter(|trade| trade.price.is_finite() && trade.price > 0.0)
+            .filter_map(|trade| {
+                let base = trade.symbol.split('/').next()?;
+                Some(VenueTick {
+                    venue: self.venue(),
+                    symbol: CexSymbol::parse(base)?,
+                    price: trade.price,
+                    exchange_ts_ms: trade.timestamp.as_deref().and_then(parse_rfc3339_ms),
+                    received_at,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cex::feed::test_support::run_against_stub;
+
+    #[tokio::test]
+    async fn test_subscribes_and_parses_trades_from_stub() {
+        let frames = vec![
+            r#"{"method":"subscribe","result":{"channel":"trade","symbol":"BTC/USD"},"success":true}"#.to_string(),
+            r#"{"channel":"heartbeat"}"#.to_string(),
+            r#"{"channel":"trade","type":"update","data":[
+                {"symbol":"BTC/USD","side":"buy","price":70010.5,"qty":0.01,"ord_type":"market","trade_id":1,"timestamp":"2024-01-01T00:00:01.000000Z"},
+                {"symbol":"XRP/USD","side":"sell","price":0.61,"qty":100,"ord_type":"limit","trade_id":2,"timestamp":"2024-01-01T00:00:01.500000Z"}
+            ]}"#
+            .to_string(),
+        ];
+        let (received, ticks) = run_against_stub(
+            |url| KrakenFeed {
+                url,
+                symbols: vec![CexSymbol::BtcUsdt, CexSymbol::XrpUsdt],
+            },
+            frames,
+            2,
+        )
+        .await;
+
+        let subscription: serde_json::Value = serde_json::from_str(&received[0]).unwrap();
+        assert_eq!(subscription["params"]["symbol"][0], "BTC/USD");
+        assert_eq!(ticks[0].symbol, CexSymbol::BtcUsdt);
+        assert_eq!(ticks[0].price, 70010.5);
+        assert_eq!(ticks[1].symbol, CexSymbol::XrpUsdt);
+        assert_eq!(ticks[1].exchange_ts_ms, Some(1_704_067_201_500));
+    }
+}
@@ -1,9 +1,13 @@
 //! Dedicated hot-path executor for CEX latency arbitrage signals.
 //!
-//! Consumes `PriceMovement` from the Binance WebSocket feed, maps them
-//! to Polymarket contracts, computes Kelly-sized positions, and executes
-//! FOK orders via the existing `OrderExecutor`.
-
+//! Consumes `PriceMovement` from the composite CEX feed, maps them to
+//! Polymarket contracts, prices each contract's payoff (single strike, band or
+//! up/down window) off the CEX spot and realized volatility, sizes them jointly through the portfolio
+//! Kelly sizer, and executes FOK orders via the existing `OrderExecutor`.
+
+use chrono::Utc;
+use polymarket_core::api::ClobClient;
+use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
 use sqlx::PgPool;
 use std::collections::HashMap;
@@ -14,25 +18,34 @@ use tokio::task::JoinHandle;
 use tracing::{debug, info, warn};
 use uuid::Uuid;
 
+use polymarket_core::sizing::PortfolioBet;
 use risk_manager::circuit_breaker::CircuitBreaker;
 use trading_engine::OrderExecutor;
 
+use super::fair_value::DigitalQuote;
 use super::market_mapper::{MappedMarket, MarketMapper};
 use super::price_tracker::{
-    CexPriceTick, CexSymbol, PriceDirection, PriceMovement, PriceTracker, PriceTrackerConfig,
+    CexPriceTick, CexSymbol, PriceDirection, PriceTracker, PriceTrackerConfig,
 };
+use crate::portfolio_sizing::{KellyLimits, PortfolioSizer};
 
 /// Configuration for the latency arb executor.
 #[derive(Debug, Clone)]
 pub struct LatencyArbExecutorConfig {
     pub enabled: bool,
-    /// Minimum edge on Polymarket side (e.g. 0.10 = 10 cents).
+    /// Edge still required after fees and the volatility buffer (e.g. 0.02 = 2 cents).
     pub min_edge: f64,
+    /// Taker fee as a fraction of the ask.
+    pub fee_rate: f64,
+    /// Multiple of the expected fair-value move added to the required edge.
+    pub vol_buffer: f64,
+    /// Horizon over which that fair-value move is measured (signal to fill).
+    pub vol_buffer_horizon_secs: f64,
     /// Maximum signal age before discarding.
     pub max_signal_age_ms: u64,
     /// Fractional Kelly multiplier (e.g. 0.10 = 10% of full Kelly).
     pub kelly_fraction: f64,
-    /// Bankroll for Kelly sizing.
+    /// Bankroll for standalone Kelly sizing when portfolio sizing is unavailable.
     pub kelly_bankroll: Decimal,
     /// Hard cap on position size.
     pub max_position_size: Decimal,
@@ -49,50 +62,35 @@ pub struct LatencyArbExecutorConfig {
 impl LatencyArbExecutorConfig {
     pub fn from_env() -> Self {
         Self {
-            enabled: std::env::var("LATENCY_ARB_ENABLED")
-                .map(|v| v == "true")
-                .unwrap_or(false),
-            min_edge: std::env::var("LATENCY_ARB_MIN_EDGE")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(0.10),
-            max_signal_age_ms: std::env::var("LATENCY_ARB_MAX_SIGNAL_AGE_MS")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(1500),
-            kelly_fraction: std::env::var("LATENCY_ARB_KELLY_FRACTION")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(0.10),
-            kelly_bankroll: std::env::var("LATENCY_ARB_KELLY_BANKROLL")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(Decimal::new(1000, 0)),
-            max_position_size: std::env::var("LATENCY_ARB_MAX_POSITION_SIZE")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(Decimal::new(100, 0)),
-            min_position_size: std::env::var("LATENCY_ARB_MIN_POSITION_SIZE")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(Decimal::new(5, 0)),
-            min_yes_price: std::env::var("LATENCY_ARB_MIN_YES_PRICE")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(0.05),
-            max_yes_price: std::env::var("LATENCY_ARB_MAX_YES_PRICE")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(0.95),
-            cooldown_ms: std::env::var("LATENCY_ARB_COOLDOWN_MS")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(30000),
+            enabled: polymarket_core::settings::get("LATENCY_ARB_ENABLED"),
+            min_edge: polymarket_core::settings::get("LATENCY_ARB_MIN_EDGE"),
+            fee_rate: polymarket_core::settings::get("LATENCY_ARB_FEE_RATE"),
+            vol_buffer: polymarket_core::settings::get("LATENCY_ARB_VOL_BUFFER"),
+            vol_buffer_horizon_secs: polymarket_core::settings::get(
+                "LATENCY_ARB_VOL_BUFFER_HORIZON_SECS",
+            ),
+            max_signal_age_ms: polymarket_core::settings::get("LATENCY_ARB_MAX_SIGNAL_AGE_MS"),
+            kelly_fraction: polymarket_core::settings::get("LATENCY_ARB_KELLY_FRACTION"),
+            kelly_bankroll: polymarket_core::settings::get("LATENCY_ARB_KELLY_BANKROLL"),
+            max_position_size: polymarket_core::settings::get("LATENCY_ARB_MAX_POSITION_SIZE"),
+            min_position_size: polymarket_core::settings::get("LATENCY_ARB_MIN_POSITION_SIZE"),
+            min_yes_price: polymarket_core::settings::get("LATENCY_ARB_MIN_YES_PRICE"),
+            max_yes_price: polymarket_core::settings::get("LATENCY_ARB_MAX_YES_PRICE"),
+            cooldown_ms: polymarket_core::settings::get("LATENCY_ARB_COOLDOWN_MS"),
         }
     }
 }
 
-/// Compute Kelly-sized position using the shared sizing module.
+/// Per-position limits passed to the portfolio sizer.
+fn kelly_limits(config: &LatencyArbExecutorConfig) -> KellyLimits {
+    KellyLimits {
+        fraction: config.kelly_fraction,
+        max_position: config.max_position_size,
+        min_position: config.min_position_size,
+    }
+}
+
+/// Compute a standalone Kelly-sized position (fallback when portfolio sizing fails).
 fn compute_kelly_size(
     p_win: f64,
     price: f64,
@@ -108,17 +106,22 @@ fn compute_kelly_size(
 }
 
 /// Spawn the latency arb executor loop.
+#[allow(clippy::too_many_arguments)]
 pub fn spawn_latency_arb_executor(
     config: LatencyArbExecutorConfig,
     tracker_config: PriceTrackerConfig,
     mut price_rx: mpsc::Receiver<CexPriceTick>,
     market_mapper: Arc<MarketMapper>,
+    clob_client: Arc<ClobClient>,
     circuit_breaker: Arc<CircuitBreaker>,
     pool: PgPool,
+    portfolio_sizer: Arc<PortfolioSizer>,
 ) -> JoinHandle<()> {
     info!(
         enabled = config.enabled,
         min_edge = config.min_edge,
+        fee_rate = config.fee_rate,
+        vol_buffer = config.vol_buffer,
         max_signal_age_ms = config.max_signal_age_ms,
         kelly_fraction = config.kelly_fraction,
         "Spawning latency arb executor"
@@ -154,6 +157,15 @@ pub fn spawn_latency_arb_executor(
                 continue;
             }
 
+            // Contracts are priced off realized volatility; wait until it is measured.
+            let Some(sigma) = tracker.realized_vol(movement.symbol) else {
+                debug!(
+                    symbol = movement.symbol.as_str(),
+                    "Latency arb: realized volatility still warming up, skipping"
+                );
+                continue;
+            };
+
             // Look up matched Polymarket markets
             let matched = market_mapper.get_markets_for_symbol(movement.symbol).await;
 
@@ -161,6 +173,9 @@ pub fn spawn_latency_arb_executor(
                 continue;
             }
 
+            // Collect every tradeable market for this movement so they are sized jointly.
+            let mut priced: Vec<(&MappedMarket, bool, DigitalQuote)> = Vec::new();
+            let now = Utc::now();
             for market in &matched {
                 signals_evaluated += 1;
 
@@ -178,24 +193,103 @@ pub fn spawn_latency_arb_executor(
                     continue;
                 }
 
-                // Determine direction and edge
-                let (should_buy_yes, p_win, edge) = compute_edge(&movement, market);
+                // Up/down windows that have started are priced off their recorded open.
+                let window_open = market
+                    .spec
+                    .window_start
+                    .and_then(|start| tracker.open_at(market.spec.symbol, start));
+                let Some(quote) =
+                    market
+                        .spec
+                        .fair_value(movement.price_now, window_open, sigma, now)
+                else {
+                    debug!(
+                        condition_id = %market.condition_id,
+                        payoff = market.spec.kind(),
+                        "Latency arb: contract cannot be valued yet, skipping"
+                    );
+                    continue;
+                };
+
+                // Buy whichever side the model says the last Polymarket price undervalues,
+                // then check that side's live ask.
+                let should_buy_yes = quote.p_yes > market.yes_price;
+                priced.push((market, should_buy_yes, quote));
+            }
+
+            // Fetch every candidate's book at once so one slow market doesn't stall the rest.
+            let books = futures_util::future::join_all(priced.iter().map(
+                |&(market, should_buy_yes, _)| {
+                    let token_id = if should_buy_yes {
+                        &market.yes_token_id
+                    } else {
+                        &market.no_token_id
+                    };
+                    clob_client.get_order_book(token_id)
+                },
+            ))
+            .await;
+
+            let mut candidates: Vec<(&MappedMarket, bool, EdgeEstimate)> = Vec::new();
+            for ((market, should_buy_yes, quote), book) in priced.into_iter().zip(books) {
+                let ask = match book {
+                    Ok(book) => book.best_ask().and_then(|p| p.to_f64()),
+                    Err(e) => {
+                        debug!(error = %e, condition_id = %market.condition_id, "Latency arb: order book fetch failed");
+                        None
+                    }
+                };
+                let Some(ask) = ask else {
+                    continue;
+                };
 
-                if edge < config.min_edge {
+                let estimate = compute_edge(&quote, should_buy_yes, ask, sigma, &config);
+                if estimate.edge < config.min_edge {
+                    debug!(
+                        condition_id = %market.condition_id,
+                        fair_value = estimate.fair_value,
+                        ask,
+                        edge = estimate.edge,
+                        "Latency arb: ask within fees and volatility buffer, skipping"
+                    );
                     continue;
                 }
 
-                let price = if should_buy_yes {
-                    market.yes_price
-                } else {
-                    1.0 - market.yes_price // NO price
-                };
+                candidates.push((market, should_buy_yes, estimate));
+            }
+
+            let bets: Vec<PortfolioBet> = candidates
+                .iter()
+                .map(|&(market, should_buy_yes, ref estimate)| PortfolioBet {
+                    market_id: market.condition_id.clone(),
+                    buys_yes: should_buy_yes,
+                    p_win: estimate.fair_value,
+                    price: estimate.ask,
+                })
+                .collect();
+            let sizes = if bets.is_empty() {
+                Vec::new()
+            } else {
+                match portfolio_sizer
+                    .allocate(&bets, &kelly_limits(&config))
+                    .await
+                {
+                    Ok(allocation) => allocation.sizes,
+                    Err(e) => {
+                        warn!(error = %e, "Portfolio sizing failed, using standalone Kelly");
+                        bets.iter()
+                            .map(|bet| compute_kelly_size(bet.p_win, bet.price, &config))
+                            .collect()
+                    }
+                }
+            };
 
-                let Some(position_size) = compute_kelly_size(p_win, price, &config) else {
+            for (&(market, should_buy_yes, ref estimate), size) in candidates.iter().zip(sizes) {
+                let Some(position_size) = size else {
                     debug!(
                         condition_id = %market.condition_id,
-                        p_win,
-                        price,
+                        p_win = estimate.fair_value,
+                        price = estimate.ask,
                         "Kelly size too small or zero, skipping"
                     );
                     continue;
@@ -216,6 +310,8 @@ pub fn spawn_latency_arb_executor(
                     &market.condition_id,
                     side,
                     market.yes_price,
+                    estimate,
+                    sigma,
                     position_size,
                     age_ms as i32,
                 )
@@ -231,7 +327,9 @@ pub fn spawn_latency_arb_executor(
                     condition_id = %market.condition_id,
                     side,
                     yes_price = format!("{:.4}", market.yes_price),
-                    edge = format!("{:.4}", edge),
+                    fair_value = format!("{:.4}", estimate.fair_value),
+                    ask = format!("{:.4}", estimate.ask),
+                    edge = format!("{:.4}", estimate.edge),
                     position_size = %position_size,
                     age_ms,
                     "Latency arb signal detected"
@@ -266,70 +364,46 @@ pub fn spawn_latency_arb_executor(
     })
 }
 
-/// Compute the edge for a given movement + market combination.
-///
-/// Returns (should_buy_yes, p_win, edge).
-fn compute_edge(movement: &PriceMovement, market: &MappedMarket) -> (bool, f64, f64) {
-    // If CEX shows BTC going UP and the market is "BTC above $X":
-    //   - If BTC is now above threshold → YES should be ~1.0 → buy YES if underpriced
-    //   - If BTC is below threshold but moving toward it → YES should increase
-    let cex_above_threshold = movement.price_now > market.threshold_price;
-
-    let (should_buy_yes, implied_prob) = if market.is_above {
-        // "BTC above $X" market
-        match movement.direction {
-            PriceDirection::Up => {
-                if cex_above_threshold {
-                    // CEX confirms above threshold, YES should be high
-                    (true, 0.85_f64.min(0.50 + movement.magnitude_pct * 50.0))
-                } else {
-                    // Moving up but not yet above — moderate confidence
-                    (true, 0.60_f64.min(0.50 + movement.magnitude_pct * 20.0))
-                }
-            }
-            PriceDirection::Down => {
-                if !cex_above_threshold {
-                    // CEX confirms below threshold, NO should be high
-                    (false, 0.85_f64.min(0.50 + movement.magnitude_pct * 50.0))
-                } else {
-                    // Moving down but still above — moderate confidence for NO
-                    (false, 0.60_f64.min(0.50 + movement.magnitude_pct * 20.0))
-                }
-            }
-        }
-    } else {
-        // "BTC below $X" market — logic is inverted
-        match movement.direction {
-            PriceDirection::Down => {
-                if !cex_above_threshold {
-                    (true, 0.85_f64.min(0.50 + movement.magnitude_pct * 50.0))
-                } else {
-                    (true, 0.60_f64.min(0.50 + movement.magnitude_pct * 20.0))
-                }
-            }
-            PriceDirection::Up => {
-                if cex_above_threshold {
-                    (false, 0.85_f64.min(0.50 + movement.magnitude_pct * 50.0))
-                } else {
-                    (false, 0.60_f64.min(0.50 + movement.magnitude_pct * 20.0))
-                }
-            }
-        }
-    };
+/// Fair value of the side being bought, its live ask, and what is left of
+/// the difference after costs.
+#[derive(Debug, Clone, Copy)]
+struct EdgeEstimate {
+    /// Model probability that the bought side wins.
+    fair_value: f64,
+    ask: f64,
+    fee: f64,
+    /// Volatility-scaled allowance for the quote moving before we fill.
+    buffer: f64,
+    /// `fair_value - ask - fee - buffer`.
+    edge: f64,
+}
 
-    // Edge = how much Polymarket misprices relative to our implied probability
-    let poly_price = if should_buy_yes {
-        market.yes_price
+/// Net edge of buying one side of a contract at `ask`.
+fn compute_edge(
+    quote: &DigitalQuote,
+    should_buy_yes: bool,
+    ask: f64,
+    sigma: f64,
+    config: &LatencyArbExecutorConfig,
+) -> EdgeEstimate {
+    let fair_value = if should_buy_yes {
+        quote.p_yes
     } else {
-        1.0 - market.yes_price
+        1.0 - quote.p_yes
     };
-
-    let edge = implied_prob - poly_price;
-
-    (should_buy_yes, implied_prob, edge)
+    let fee = config.fee_rate * ask;
+    let buffer = config.vol_buffer * quote.fair_value_move(sigma, config.vol_buffer_horizon_secs);
+    EdgeEstimate {
+        fair_value,
+        ask,
+        fee,
+        buffer,
+        edge: fair_value - ask - fee - buffer,
+    }
 }
 
 /// Record a latency arb signal to the database for tracking and paper-mode analysis.
+#[allow(clippy::too_many_arguments)]
 async fn record_latency_arb_signal(
     pool: &PgPool,
     cex_symbol: &str,
@@ -338,6 +412,8 @@ async fn record_latency_arb_signal(
     condition_id: &str,
     polymarket_side: &str,
     yes_price_at_signal: f64,
+    estimate: &EdgeEstimate,
+    realized_vol: f64,
     kelly_size_usd: Decimal,
     signal_age_ms: i32,
 ) -> anyhow::Result<()> {
@@ -345,10 +421,11 @@ async fn record_latency_arb_signal(
         r#"
         INSERT INTO latency_arb_signals (
             id, cex_symbol, direction, magnitude_pct, condition_id,
-            polymarket_side, yes_price_at_signal, kelly_size_usd,
+            polymarket_side, yes_price_at_signal, fair_value, ask_price,
+            fee_estimate, vol_buffer, realized_vol, kelly_size_usd,
             executed, signal_age_ms, generated_at
         )
-        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, false, $9, NOW())
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, false, $14, NOW())
         "#,
     )
     .bind(Uuid::new_v4())
@@ -358,6 +435,11 @@ async fn record_latency_arb_signal(
     .bind(condition_id)
     .bind(polymarket_side)
     .bind(Decimal::from_f64_retain(yes_price_at_signal))
+    .bind(estimate.fair_value)
+    .bind(estimate.ask)
+    .bind(estimate.fee)
+    .bind(estimate.buffer)
+    .bind(realized_vol)
     .bind(kelly_size_usd)
     .bind(signal_age_ms)
     .execute(pool)
@@ -369,12 +451,16 @@ async fn record_latency_arb_signal(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::cex::fair_value::digital_fair_value;
 
     #[test]
     fn test_compute_kelly_size() {
         let config = LatencyArbExecutorConfig {
             enabled: true,
             min_edge: 0.10,
+            fee_rate: 0.02,
+            vol_buffer: 1.0,
+            vol_buffer_horizon_secs: 5.0,
             max_signal_age_ms: 1500,
             kelly_fraction: 0.25,
             kelly_bankroll: Decimal::new(1000, 0),
@@ -397,6 +483,9 @@ mod tests {
         let config = LatencyArbExecutorConfig {
             enabled: true,
             min_edge: 0.10,
+            fee_rate: 0.02,
+            vol_buffer: 1.0,
+            vol_buffer_horizon_secs: 5.0,
             max_signal_age_ms: 1500,
             kelly_fraction: 0.01,                 // Very conservative
             kelly_bankroll: Decimal::new(100, 0), // Small bankroll
@@ -411,4 +500,39 @@ mod tests {
         let size = compute_kelly_size(0.55, 0.50, &config);
         assert!(size.is_none());
     }
+
+    #[test]
+    fn test_compute_edge_nets_out_fees_and_volatility_buffer() {
+        let config = LatencyArbExecutorConfig {
+            enabled: true,
+            min_edge: 0.02,
+            fee_rate: 0.02,
+            vol_buffer: 1.0,
+            vol_buffer_horizon_secs: 5.0,
+            max_signal_age_ms: 1500,
+            kelly_fraction: 0.10,
+            kelly_bankroll: Decimal::new(1000, 0),
+            max_position_size: Decimal::new(100, 0),
+            min_position_size: Decimal::new(5, 0),
+            min_yes_price: 0.05,
+            max_yes_price: 0.95,
+            cooldown_ms: 30000,
+        };
+        // ~60% annualized volatility; $300 above the strike with 10 minutes
+        // left puts YES at ~0.95.
+        let sigma = 0.60 / 5_615.69;
+        let quote = digital_fair_value(70_300.0, 70_000.0, sigma, 600.0, true).unwrap();
+
+        let cheap = compute_edge(&quote, true, 0.80, sigma, &config);
+        assert!(cheap.edge > 0.10);
+        assert!(cheap.buffer > 0.0);
+
+        // Below fair value, but not by more than fees plus the buffer.
+        let rich = compute_edge(&quote, true, 0.93, sigma, &config);
+        assert!(rich.fair_value > rich.ask);
+        assert!(rich.edge < 0.0);
+
+        let no_side = compute_edge(&quote, false, 0.03, sigma, &config);
+        assert!((no_side.fair_value + cheap.fair_value - 1.0).abs() < 1e-12);
+    }
 }
@@ -8,6 +8,7 @@ use std::time;
 use tokio::sync::RwLock;
 use tracing::{info, warn};
 
+use super::contract_spec::{parse_contract_spec, ContractSpec};
 use super::price_tracker::CexSymbol;
 
 /// A Polymarket market that is mappable to a CEX price signal.
@@ -17,14 +18,14 @@ pub struct MappedMarket {
     pub condition_id: String,
     /// Human-readable market question.
     pub question: String,
-    /// The strike/threshold price parsed from the title (e.g. 70000.0 for "BTC above $70,000").
-    pub threshold_price: f64,
-    /// Which CEX symbol this maps to.
-    pub cex_symbol: CexSymbol,
-    /// Whether the contract is "above" (YES = price > threshold).
-    pub is_above: bool,
+    /// Payoff parsed from the title: symbol, bounds and reference time.
+    pub spec: ContractSpec,
     /// When the market resolves.
     pub end_date: DateTime<Utc>,
+    /// CLOB token for the YES outcome ("Up" on up/down contracts).
+    pub yes_token_id: String,
+    /// CLOB token for the NO outcome ("Down" on up/down contracts).
+    pub no_token_id: String,
     /// Current YES price on Polymarket (refreshed periodically).
     pub yes_price: f64,
     /// Last time the yes_price was refreshed.
@@ -54,7 +55,7 @@ impl MarketMapper {
         let markets = self.markets.read().await;
         markets
             .values()
-            .filter(|m| m.cex_symbol == symbol)
+            .filter(|m| m.spec.symbol == symbol)
             .cloned()
             .collect()
     }
@@ -84,22 +85,6 @@ impl MarketMapper {
         let mut mapped = HashMap::new();
 
         for market in &all_markets {
-            let question_lower = market.question.to_lowercase();
-
-            // Filter to crypto price prediction markets
-            let (cex_symbol, is_crypto) =
-                if question_lower.contains("bitcoin") || question_lower.contains("btc") {
-                    (CexSymbol::BtcUsdt, true)
-                } else if question_lower.contains("ethereum") || question_lower.contains("eth") {
-                    (CexSymbol::EthUsdt, true)
-                } else {
-                    (CexSymbol::BtcUsdt, false)
-                };
-
-            if !is_crypto {
-                continue;
-            }
-
             // Must resolve within 24 hours
             let Some(end_date) = market.end_date else {
                 continue;
@@ -109,29 +94,26 @@ impl MarketMapper {
                 continue;
             }
 
-            // Determine direction
-            let is_above = question_lower.contains("above")
-                || question_lower.contains("higher")
-                || question_lower.contains("over");
-            let is_below = question_lower.contains("below")
-                || question_lower.contains("lower")
-                || question_lower.contains("under");
-
-            if !is_above && !is_below {
+            // Filter to crypto price contracts with a payoff we can value
+            let Some(spec) =
+                parse_contract_spec(&market.question, market.description.as_deref(), end_date)
+            else {
                 continue;
-            }
+            };
 
-            // Parse threshold price from question
-            let Some(threshold) = parse_price_from_question(&market.question) else {
+            let outcome = |names: [&str; 2]| {
+                market
+                    .outcomes
+                    .iter()
+                    .find(|o| names.iter().any(|n| o.name.eq_ignore_ascii_case(n)))
+            };
+            let (Some(yes), Some(no)) = (outcome(["yes", "up"]), outcome(["no", "down"])) else {
                 continue;
             };
 
             // Get YES price from outcomes
-            let yes_price = market
-                .outcomes
-                .iter()
-                .find(|o| o.name.to_lowercase() == "yes")
-                .and_then(|o| o.price)
+            let yes_price = yes
+                .price
                 .and_then(|p| p.to_string().parse::<f64>().ok())
                 .unwrap_or(0.5);
 
@@ -140,10 +122,10 @@ impl MarketMapper {
                 MappedMarket {
                     condition_id: market.id.clone(),
                     question: market.question.clone(),
-                    threshold_price: threshold,
-                    cex_symbol,
-                    is_above,
+                    spec,
                     end_date,
+                    yes_token_id: yes.token_id.clone(),
+                    no_token_id: no.token_id.clone(),
                     yes_price,
                     price_updated_at: time::Instant::now(),
                 },
@@ -169,67 +151,3 @@ impl MarketMapper {
         }
     }
 }
-
-/// Parse a dollar price from a market question like:
-/// "Will BTC be above $70,000 on Jan 31?"
-/// "Bitcoin price higher than $68,500?"
-fn parse_price_from_question(question: &str) -> Option<f64> {
-    let mut chars = question.chars().peekable();
-    while let Some(c) = chars.next() {
-        if c == '$' {
-            let mut num_str = String::new();
-            while let Some(&next) = chars.peek() {
-                if next.is_ascii_digit() || next == '.' {
-                    num_str.push(next);
-                    chars.next();
-                } else if next == ',' {
-                    chars.next(); // Skip commas in numbers like $70,000
-                } else {
-                    break;
-                }
-            }
-            if !num_str.is_empty() {
-                if let Ok(price) = num_str.parse::<f64>() {
-                    if price > 0.0 {
-                        return Some(price);
-                    }
-                }
-            }
-        }
-    }
-    None
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_parse_price_standard() {
-        assert_eq!(
-            parse_price_from_question("Will BTC be above $70,000?"),
-            Some(70000.0)
-        );
-    }
-
-    #[test]
-    fn test_parse_price_with_decimals() {
-        assert_eq!(
-            parse_price_from_question("ETH price higher than $3,500.50?"),
-            Some(3500.50)
-        );
-    }
-
-    #[test]
-    fn test_parse_price_no_commas() {
-        assert_eq!(
-            parse_price_from_question("Bitcoin above $95000 by Friday?"),
-            Some(95000.0)
-        );
-    }
-
-    #[test]
-    fn test_parse_price_none() {
-        assert_eq!(parse_price_from_question("Will it rain tomorrow?"), None);
-    }
-}
@@ -1,16 +1,31 @@
 //! CEX latency arbitrage module.
 //!
-//! Monitors Binance real-time price feeds via WebSocket, detects when
-//! Polymarket short-duration contract odds diverge from CEX-implied
-//! probabilities, and executes FOK orders on the implied-correct side
-//! before the market corrects (~2.7s lag).
+//! Monitors real-time exchange price feeds via WebSocket (a median index
+//! across Binance, Coinbase, Kraken and OKX), detects when Polymarket
+//! short-duration contract odds diverge from CEX-implied probabilities, and
+//! executes FOK orders on the implied-correct side before the market corrects
+//! (~2.7s lag).
 
 pub mod binance_ws;
+pub mod coinbase_ws;
+pub mod composite;
+pub mod contract_spec;
+pub mod fair_value;
+pub mod feed;
+pub mod kraken_ws;
 pub mod latency_arb_executor;
 pub mod market_mapper;
+pub mod okx_ws;
 pub mod price_tracker;
 
-pub use binance_ws::{spawn_binance_ws_client, BinanceWsConfig};
+pub use binance_ws::BinanceFeed;
+pub use coinbase_ws::CoinbaseFeed;
+pub use composite::{spawn_composite_feed, CompositeFeedConfig, CompositeIndex, VenueStatus};
+pub use contract_spec::{parse_contract_spec, Bound, ContractSpec, ResolutionSource};
+pub use fair_value::{digital_fair_value, range_fair_value, DigitalQuote};
+pub use feed::{spawn_feed, CexFeed, FeedConnectionConfig, VenueTick};
+pub use kraken_ws::KrakenFeed;
 pub use latency_arb_executor::{spawn_latency_arb_executor, LatencyArbExecutorConfig};
 pub use market_mapper::MarketMapper;
+pub use okx_ws::OkxFeed;
 pub use price_tracker::{CexPriceTick, CexSymbol, PriceMovement, PriceTracker, PriceTrackerConfig};
@@ -0,0 +1,120 @@
+//! OKX public tickers feed (USDT pairs).
+
+use serde::Deserialize;
+use std::time;
+
+use super::feed::{parse_price, CexFeed, VenueTick};
+use super::price_tracker::CexSymbol;
+
+/// OKX v5 public `tickers` channel.
+#[derive(Debug, Clone)]
+pub struct OkxFeed {
+    /// Default: wss://ws.okx.com:8443/ws/v5/public
+    pub url: String,
+    pub symbols: Vec<CexSymbol>,
+}
+
+impl OkxFeed {
+    pub fn from_env(symbols: Vec<CexSymbol>) -> Self {
+        Self {
+            url: polymarket_core::settings::get("OKX_WS_URL"),
+            symbols,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TickersMessage {
+    #[serde(default)]
+    data: Vec<TickerData>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TickerData {
+    inst_id: String,
+    last: String,
+    ts: Option<String>,
+}
+
+impl CexFeed for OkxFeed {
+    fn venue(&self) -> &'static str {
+        "okx"
+    }
+
+    fn url(&self) -> String {
+        self.url.clone()
+    }
+
+    fn subscribe_messages(&self) -> Vec<String> {
+        let args: Vec<serde_json::Value> = self
+            .symbols
+            .iter()
+            .map(|symbol| {
+                serde_json::json!({
+                    "channel": "tickers",
+                    "instId": format!("{}-USDT", symbol.base()),
+                })
+            })
+            .collect();
+        vec![serde_json::json!({ "op": "subscribe", "args": args }).to_string()]
+    }
+
+    fn keepalive(&self) -> Option<(time::Duration, String)> {
+        // OKX drops connections that are silent for 30 seconds.
+        Some((time::Duration::from_secs(20), "ping".to_string()))
+    }
+
+    fn parse(&self, text: &str) -> Vec<VenueTick> {
+        // Replies to the keepalive are a bare "pong".
+        let Ok(message) = serde_json::from_str::<TickersMessage>(text) else {
+            return Vec::new();
+        };
+        let received_at = time::Instant::now();
+        message
+            .data
+            .into_iter()
+            .filter_map(|ticker| {
+                let base = ticker.inst_id.split('-').next()?;
+                Some(VenueTick {
+                    venue: self.venue(),
+                    symbol: CexSymbol::parse(base)?,
+                    price: parse_price(&ticker.last)?,
+                    exchange_ts_ms: ticker.ts.and_then(|ts| ts.parse().ok()),
+                    received_at,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cex::feed::test_support::run_against_stub;
+
+    #[tokio::test]
+    async fn test_subscribes_and_parses_tickers_from_stub() {
+        let frames = vec![
+            r#"{"event":"subscribe","arg":{"channel":"tickers","instId":"ETH-USDT"},"connId":"a"}"#.to_string(),
+            "pong".to_string(),
+            r#"{"arg":{"channel":"tickers","instId":"ETH-USDT"},"data":[{"instType":"SPOT","instId":"ETH-USDT","last":"3501.2","ts":"1700000000999"}]}"#.to_string(),
+        ];
+        let (received, ticks) = run_against_stub(
+            |url| OkxFeed {
+                url,
+                symbols: vec![CexSymbol::EthUsdt],
+            },
+            frames,
+            1,
+        )
+        .await;
+
+        let subscription: serde_json::Value = serde_json::from_str(&received[0]).unwrap();
+        assert_eq!(subscription["args"][0]["instId"], "ETH-USDT");
+        assert_eq!(ticks[0].venue, "okx");
+        assert_eq!(ticks[0].symbol, CexSymbol::EthUsdt);
+        assert_eq!(ticks[0].price, 3501.2);
+        assert_eq!(ticks[0].exchange_ts_ms, Some(1_700_000_000_999));
+    }
+}
@@ -1,21 +1,55 @@
-//! EMA-based price tracking and divergence detection for CEX feeds.
+//! EMA-based price tracking and divergence detection for CEX feeds, plus the
+//! rolling realized volatility and per-minute opens used to price contracts
+//! off those feeds.
 
+use chrono::{DateTime, Utc};
+use std::collections::{HashMap, VecDeque};
 use std::time;
 
-/// Identifier for a CEX trading pair.
+/// Identifier for a tracked asset, quoted in USD(T). Venues quoting in USD and
+/// USDT feed the same symbol.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum CexSymbol {
     BtcUsdt,
     EthUsdt,
+    SolUsdt,
+    XrpUsdt,
 }
 
 impl CexSymbol {
+    pub const ALL: [CexSymbol; 4] = [
+        CexSymbol::BtcUsdt,
+        CexSymbol::EthUsdt,
+        CexSymbol::SolUsdt,
+        CexSymbol::XrpUsdt,
+    ];
+
     pub fn as_str(&self) -> &'static str {
         match self {
             CexSymbol::BtcUsdt => "BTCUSDT",
             CexSymbol::EthUsdt => "ETHUSDT",
+            CexSymbol::SolUsdt => "SOLUSDT",
+            CexSymbol::XrpUsdt => "XRPUSDT",
         }
     }
+
+    /// Base asset ticker, e.g. `BTC`.
+    pub fn base(&self) -> &'static str {
+        match self {
+            CexSymbol::BtcUsdt => "BTC",
+            CexSymbol::EthUsdt => "ETH",
+            CexSymbol::SolUsdt => "SOL",
+            CexSymbol::XrpUsdt => "XRP",
+        }
+    }
+
+    /// Parse a base ticker (`btc`) or pair name (`BTCUSDT`).
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim().to_ascii_uppercase();
+        Self::ALL
+            .into_iter()
+            .find(|symbol| value == symbol.base() || value == symbol.as_str())
+    }
 }
 
 /// A single price tick from a CEX feed.
@@ -76,6 +110,100 @@ impl EmaState {
     }
 }
 
+/// Realized volatility from log returns sampled at a fixed minimum spacing.
+///
+/// Sampling (rather than using every trade) keeps bid-ask bounce out of the
+/// estimate. Returns are normalized by the time they span, so bursts and lulls
+/// in the tick stream do not bias it.
+#[derive(Debug, Default)]
+struct RealizedVol {
+    last_sample: Option<(time::Instant, f64)>,
+    /// (sampled at, seconds spanned, squared log return)
+    returns: VecDeque<(time::Instant, f64, f64)>,
+    sum_sq: f64,
+    sum_secs: f64,
+}
+
+impl RealizedVol {
+    fn update(&mut self, price: f64, at: time::Instant, config: &PriceTrackerConfig) {
+        if price <= 0.0 {
+            return;
+        }
+        if let Some((last_at, last_price)) = self.last_sample {
+            let elapsed = at.saturating_duration_since(last_at);
+            if elapsed < time::Duration::from_millis(config.vol_sample_ms) {
+                return;
+            }
+            let log_return = (price / last_price).ln();
+            let secs = elapsed.as_secs_f64();
+            self.returns.push_back((at, secs, log_return * log_return));
+            self.sum_sq += log_return * log_return;
+            self.sum_secs += secs;
+        }
+        self.last_sample = Some((at, price));
+
+        let window = time::Duration::from_secs(config.vol_window_secs);
+        while let Some(&(sampled_at, secs, sq)) = self.returns.front() {
+            if at.saturating_duration_since(sampled_at) <= window {
+                break;
+            }
+            self.returns.pop_front();
+            self.sum_sq -= sq;
+            self.sum_secs -= secs;
+        }
+    }
+
+    /// Volatility per √second, once enough returns are in the window.
+    fn sigma(&self, min_samples: usize) -> Option<f64> {
+        if self.returns.len() < min_samples.max(1) || self.sum_secs <= 0.0 {
+            return None;
+        }
+        Some((self.sum_sq.max(0.0) / self.sum_secs).sqrt())
+    }
+}
+
+/// First price seen in each wall-clock minute, which is how exchange candles
+/// (and so "Up or Down" windows) define their open.
+#[derive(Debug, Default)]
+struct MinuteOpens {
+    last_minute: Option<i64>,
+    opens: VecDeque<(i64, f64)>,
+}
+
+impl MinuteOpens {
+    /// Minutes of opens kept: enough for a daily window plus slack.
+    const RETAINED: usize = 25 * 60;
+
+    fn update(&mut self, price: f64, now: DateTime<Utc>) {
+        let minute = now.timestamp().div_euclid(60);
+        // The first minute we see was joined part-way through, so its first
+        // tick is not its open.
+        if self.last_minute.is_some_and(|last| minute > last) {
+            self.opens.push_back((minute, price));
+            if self.opens.len() > Self::RETAINED {
+                self.opens.pop_front();
+            }
+        }
+        if self.last_minute.is_none_or(|last| minute > last) {
+            self.last_minute = Some(minute);
+        }
+    }
+
+    fn open_at(&self, at: DateTime<Utc>) -> Option<f64> {
+        let minute = at.timestamp().div_euclid(60);
+        let idx = self.opens.binary_search_by_key(&minute, |&(m, _)| m).ok()?;
+        Some(self.opens[idx].1)
+    }
+}
+
+/// Per-symbol tracking state.
+#[derive(Debug)]
+struct SymbolState {
+    ema: EmaState,
+    vol: RealizedVol,
+    opens: MinuteOpens,
+}
+
 /// Configuration for the price tracker.
 #[derive(Debug, Clone)]
 pub struct PriceTrackerConfig {
@@ -85,23 +213,25 @@ pub struct PriceTrackerConfig {
     pub divergence_threshold: f64,
     /// Minimum ticks before the EMA is considered stable. Default 10.
     pub min_warmup_ticks: u64,
+    /// Lookback for realized volatility. Default 900s.
+    pub vol_window_secs: u64,
+    /// Minimum spacing between volatility samples. Default 1000ms.
+    pub vol_sample_ms: u64,
+    /// Samples required before volatility is reported. Default 60.
+    pub min_vol_samples: usize,
 }
 
 impl PriceTrackerConfig {
     pub fn from_env() -> Self {
         Self {
-            ema_alpha: std::env::var("LATENCY_ARB_EMA_ALPHA")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(0.3),
-            divergence_threshold: std::env::var("LATENCY_ARB_DIVERGENCE_THRESHOLD")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(0.003),
-            min_warmup_ticks: std::env::var("LATENCY_ARB_WARMUP_TICKS")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(10),
+            ema_alpha: polymarket_core::settings::get("LATENCY_ARB_EMA_ALPHA"),
+            divergence_threshold: polymarket_core::settings::get(
+                "LATENCY_ARB_DIVERGENCE_THRESHOLD",
+            ),
+            min_warmup_ticks: polymarket_core::settings::get("LATENCY_ARB_WARMUP_TICKS"),
+            vol_window_secs: polymarket_core::settings::get("LATENCY_ARB_VOL_WINDOW_SECS"),
+            vol_sample_ms: polymarket_core::settings::get("LATENCY_ARB_VOL_SAMPLE_MS"),
+            min_vol_samples: polymarket_core::settings::get("LATENCY_ARB_MIN_VOL_SAMPLES"),
         }
     }
 }
@@ -109,25 +239,37 @@ impl PriceTrackerConfig {
 /// Tracks EMA per symbol and emits `PriceMovement` on significant divergence.
 pub struct PriceTracker {
     config: PriceTrackerConfig,
-    btc: EmaState,
-    eth: EmaState,
+    symbols: HashMap<CexSymbol, SymbolState>,
 }
 
 impl PriceTracker {
     pub fn new(config: PriceTrackerConfig) -> Self {
         Self {
             config,
-            btc: EmaState::new(),
-            eth: EmaState::new(),
+            symbols: HashMap::new(),
         }
     }
 
     /// Process a tick and return a movement if divergence exceeds the threshold.
     pub fn on_tick(&mut self, tick: &CexPriceTick) -> Option<PriceMovement> {
-        let state = match tick.symbol {
-            CexSymbol::BtcUsdt => &mut self.btc,
-            CexSymbol::EthUsdt => &mut self.eth,
-        };
+        self.on_tick_at(tick, Utc::now())
+    }
+
+    fn on_tick_at(&mut self, tick: &CexPriceTick, now: DateTime<Utc>) -> Option<PriceMovement> {
+        let SymbolState {
+            ema: state,
+            vol,
+            opens,
+        } = self
+            .symbols
+            .entry(tick.symbol)
+            .or_insert_with(|| SymbolState {
+                ema: EmaState::new(),
+                vol: RealizedVol::default(),
+                opens: MinuteOpens::default(),
+            });
+        vol.update(tick.price, tick.received_at, &self.config);
+        opens.update(tick.price, now);
 
         let ema_before = state.value;
         state.update(tick.price, self.config.ema_alpha);
@@ -167,16 +309,31 @@ impl PriceTracker {
 
     /// Get current EMA value for a symbol.
     pub fn ema(&self, symbol: CexSymbol) -> f64 {
-        match symbol {
-            CexSymbol::BtcUsdt => self.btc.value,
-            CexSymbol::EthUsdt => self.eth.value,
-        }
+        self.symbols
+            .get(&symbol)
+            .map_or(0.0, |state| state.ema.value)
+    }
+
+    /// Realized volatility for a symbol in log-return units per √second, or
+    /// `None` until enough samples have been collected.
+    pub fn realized_vol(&self, symbol: CexSymbol) -> Option<f64> {
+        self.symbols
+            .get(&symbol)?
+            .vol
+            .sigma(self.config.min_vol_samples)
+    }
+
+    /// The first price seen in the minute starting at `at`, if the feed was
+    /// already running before that minute began.
+    pub fn open_at(&self, symbol: CexSymbol, at: DateTime<Utc>) -> Option<f64> {
+        self.symbols.get(&symbol)?.opens.open_at(at)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::TimeZone;
 
     fn make_tick(symbol: CexSymbol, price: f64) -> CexPriceTick {
         CexPriceTick {
@@ -192,6 +349,9 @@ mod tests {
             ema_alpha: 0.3,
             divergence_threshold: 0.003,
             min_warmup_ticks: 5,
+            vol_window_secs: 900,
+            vol_sample_ms: 1000,
+            min_vol_samples: 60,
         };
         let mut tracker = PriceTracker::new(config);
 
@@ -209,6 +369,9 @@ mod tests {
             ema_alpha: 0.3,
             divergence_threshold: 0.003, // 0.3%
             min_warmup_ticks: 3,
+            vol_window_secs: 900,
+            vol_sample_ms: 1000,
+            min_vol_samples: 60,
         };
         let mut tracker = PriceTracker::new(config);
 
@@ -231,6 +394,9 @@ mod tests {
             ema_alpha: 0.3,
             divergence_threshold: 0.003,
             min_warmup_ticks: 3,
+            vol_window_secs: 900,
+            vol_sample_ms: 1000,
+            min_vol_samples: 60,
         };
         let mut tracker = PriceTracker::new(config);
 
@@ -250,6 +416,9 @@ mod tests {
             ema_alpha: 0.3,
             divergence_threshold: 0.003,
             min_warmup_ticks: 3,
+            vol_window_secs: 900,
+            vol_sample_ms: 1000,
+            min_vol_samples: 60,
         };
         let mut tracker = PriceTracker::new(config);
 
@@ -268,4 +437,62 @@ mod tests {
         let m = tracker.on_tick(&make_tick(CexSymbol::BtcUsdt, 70500.0));
         assert!(m.is_some());
     }
+
+    #[test]
+    fn test_realized_vol_from_sampled_returns() {
+        let config = PriceTrackerConfig {
+            ema_alpha: 0.3,
+            divergence_threshold: 0.003,
+            min_warmup_ticks: 3,
+            vol_window_secs: 60,
+            vol_sample_ms: 1000,
+            min_vol_samples: 10,
+        };
+        let mut tracker = PriceTracker::new(config);
+        let start = time::Instant::now();
+        let tick = |secs: f64, price: f64| CexPriceTick {
+            symbol: CexSymbol::BtcUsdt,
+            price,
+            received_at: start + time::Duration::from_secs_f64(secs),
+        };
+
+        // Alternating ±0.1% moves once per second; ticks in between are
+        // below the sampling spacing and ignored.
+        for i in 0..30 {
+            let price = if i % 2 == 0 { 70_000.0 } else { 70_070.0 };
+            tracker.on_tick(&tick(i as f64, price));
+            tracker.on_tick(&tick(i as f64 + 0.5, 75_000.0));
+            if i < 10 {
+                assert!(tracker.realized_vol(CexSymbol::BtcUsdt).is_none());
+            }
+        }
+        let sigma = tracker.realized_vol(CexSymbol::BtcUsdt).unwrap();
+        assert!((sigma - 0.001).abs() < 0.0001, "sigma = {sigma}");
+        assert!(tracker.realized_vol(CexSymbol::EthUsdt).is_none());
+    }
+
+    #[test]
+    fn test_open_at_records_first_tick_of_each_minute() {
+        let mut tracker = PriceTracker::new(PriceTrackerConfig::from_env());
+        let minute = |m: i64, s: i64| Utc.timestamp_opt(1_800_000_000 + m * 60 + s, 0).unwrap();
+        let tick = |price: f64| make_tick(CexSymbol::BtcUsdt, price);
+
+        // Joined mid-minute: that minute's open is unknown.
+        tracker.on_tick_at(&tick(70_000.0), minute(0, 30));
+        tracker.on_tick_at(&tick(70_010.0), minute(1, 2));
+        tracker.on_tick_at(&tick(70_020.0), minute(1, 40));
+        tracker.on_tick_at(&tick(70_030.0), minute(3, 0));
+
+        assert_eq!(tracker.open_at(CexSymbol::BtcUsdt, minute(0, 0)), None);
+        assert_eq!(
+            tracker.open_at(CexSymbol::BtcUsdt, minute(1, 0)),
+            Some(70_010.0)
+        );
+        assert_eq!(tracker.open_at(CexSymbol::BtcUsdt, minute(2, 0)), None);
+        assert_eq!(
+            tracker.open_at(CexSymbol::BtcUsdt, minute(3, 0)),
+            Some(70_030.0)
+        );
+        assert_eq!(tracker.open_at(CexSymbol::EthUsdt, minute(1, 0)), None);
+    }
 }
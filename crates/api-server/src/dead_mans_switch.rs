@@ -0,0 +1,428 @@
+//! Dead-man's switch for resting CLOB orders.
+//!
+//! GTC orders keep resting on the CLOB after api-server crashes or loses
+//! connectivity. Two layers guard against that:
+//!
+//! * An in-process watchdog (its own task, independent of the executors)
+//!   publishes a heartbeat to Redis every few seconds and mass-cancels when
+//!   the circuit breaker hard-kills or when the arb executor / exit handler
+//!   stop updating their liveness stamps.
+//! * The `dead-mans-switch` binary runs as a separate process, watches the
+//!   Redis heartbeat, and mass-cancels with its own wallet credentials once
+//!   the heartbeat has not been renewed within the timeout.
+//!
+//! Every mass-cancel is logged and written to the audit log.
+
+use auth::{AuditAction, AuditEvent, AuditLogger};
+use chrono::{DateTime, TimeZone, Utc};
+use redis::AsyncCommands;
+use risk_manager::circuit_breaker::{CircuitBreaker, TripReason};
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::Arc;
+use tracing::{error, info, warn};
+use trading_engine::OrderExecutor;
+use uuid::Uuid;
+
+/// Redis key holding the latest api-server heartbeat.
+pub const HEARTBEAT_KEY: &str = "dead_mans_switch:heartbeat";
+const AUDIT_RESOURCE: &str = "orders/mass_cancel";
+
+#[derive(Debug, Clone)]
+pub struct DeadMansSwitchConfig {
+    pub enabled: bool,
+    pub redis_url: String,
+    /// How often the heartbeat is renewed (and the watchdog checks run).
+    pub heartbeat_interval_secs: u64,
+    /// Heartbeat age after which the external watchdog mass-cancels.
+    pub heartbeat_timeout_secs: i64,
+    /// Executor liveness-stamp age after which the in-process watchdog mass-cancels.
+    pub executor_timeout_secs: i64,
+}
+
+impl DeadMansSwitchConfig {
+    pub fn from_env() -> Self {
+        Self {
+            enabled: polymarket_core::settings::get("DEAD_MANS_SWITCH_ENABLED"),
+            redis_url: polymarket_core::settings::get("REDIS_URL"),
+            heartbeat_interval_secs: polymarket_core::settings::get(
+                "DEAD_MANS_SWITCH_HEARTBEAT_INTERVAL_SECS",
+            ),
+            heartbeat_timeout_secs: polymarket_core::settings::get(
+                "DEAD_MANS_SWITCH_HEARTBEAT_TIMEOUT_SECS",
+            ),
+            executor_timeout_secs: polymarket_core::settings::get(
+                "DEAD_MANS_SWITCH_EXECUTOR_TIMEOUT_SECS",
+            ),
+        }
+    }
+}
+
+/// Heartbeat published by api-server and read by the external watchdog.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Heartbeat {
+    pub instance_id: String,
+    pub sent_at: DateTime<Utc>,
+    /// Whether the executor has live credentials (paper mode has no resting orders).
+    pub live: bool,
+    /// Set while the circuit breaker is tripped by the hard kill switch.
+    #[serde(default)]
+    pub hard_kill: bool,
+}
+
+/// Why a mass-cancel was issued.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum MassCancelReason {
+    /// The api-server heartbeat was not renewed within the timeout.
+    HeartbeatLost { age_secs: i64 },
+    /// An executor task stopped updating its liveness stamp.
+    ExecutorStalled { task: String, age_secs: i64 },
+    /// The circuit breaker tripped the hard kill switch.
+    HardKill,
+}
+
+impl MassCancelReason {
+    pub fn describe(&self) -> String {
+        match self {
+            Self::HeartbeatLost { age_secs } => {
+                format!("heartbeat not renewed for {}s", age_secs)
+            }
+            Self::ExecutorStalled { task, age_secs } => {
+                format!("{} liveness stamp stale for {}s", task, age_secs)
+            }
+            Self::HardKill => "circuit breaker hard kill switch".to_string(),
+        }
+    }
+}
+
+/// Tracks one liveness signal and decides when it has gone stale.
+///
+/// A watch only arms after it has seen a first beat, and fires at most once
+/// per beat: after a mass-cancel it stays quiet until a newer beat arrives.
+#[derive(Debug, Clone)]
+pub struct HeartbeatWatch {
+    timeout_secs: i64,
+    last_beat: Option<DateTime<Utc>>,
+    cancelled_for: Option<DateTime<Utc>>,
+}
+
+impl HeartbeatWatch {
+    pub fn new(timeout_secs: i64) -> Self {
+        Self {
+            timeout_secs: timeout_secs.max(1),
+            last_beat: None,
+            cancelled_for: None,
+        }
+    }
+
+    /// Record a beat; older or repeated beats are ignored.
+    pub fn record_beat(&mut self, at: DateTime<Utc>) {
+        if self.last_beat.is_none_or(|last| at > last) {
+            self.last_beat = Some(at);
+        }
+    }
+
+    pub fn last_beat(&self) -> Option<DateTime<Utc>> {
+        self.last_beat
+    }
+
+    /// Age of the last beat in seconds if it is stale and not yet acted on.
+    pub fn due(&self, now: DateTime<Utc>) -> Option<i64> {
+        let last = self.last_beat?;
+        if self.cancelled_for == Some(last) {
+            return None;
+        }
+        let age_secs = (now - last).num_seconds();
+        (age_secs >= self.timeout_secs).then_some(age_secs)
+    }
+
+    /// Mark the current stale beat as handled.
+    pub fn mark_cancelled(&mut self) {
+        self.cancelled_for = self.last_beat;
+    }
+}
+
+/// Fires once on the rising edge of the hard-kill flag.
+#[derive(Debug, Clone, Default)]
+pub struct HardKillLatch {
+    fired: bool,
+}
+
+impl HardKillLatch {
+    pub fn observe(&mut self, hard_kill: bool) -> bool {
+        if !hard_kill {
+            self.fired = false;
+            return false;
+        }
+        !self.fired
+    }
+
+    pub fn mark_cancelled(&mut self) {
+        self.fired = true;
+    }
+}
+
+/// Record a mass-cancel in the logs and (when available) the audit log.
+pub fn log_mass_cancel(
+    audit_logger: Option<&AuditLogger>,
+    source: &str,
+    reason: &MassCancelReason,
+    result: &anyhow::Result<()>,
+) {
+    match result {
+        Ok(()) => warn!(
+            source,
+            reason = %reason.describe(),
+            "Dead-man's switch cancelled all resting orders"
+        ),
+        Err(e) => error!(
+            source,
+            reason = %reason.describe(),
+            error = %e,
+            "Dead-man's switch mass-cancel failed"
+        ),
+    }
+
+    if let Some(audit_logger) = audit_logger {
+        let event = AuditEvent::builder(
+            AuditAction::Custom("mass_cancel_orders".to_string()),
+            AUDIT_RESOURCE,
+        )
+        .user("system")
+        .details(serde_json::json!({
+            "source": source,
+            "reason": reason,
+            "summary": reason.describe(),
+            "success": result.is_ok(),
+            "error": result.as_ref().err().map(|e| e.to_string()),
+        }))
+        .build();
+        audit_logger.log(event);
+    }
+}
+
+/// Spawn the in-process heartbeat publisher and watchdog.
+pub fn spawn_dead_mans_switch(
+    config: DeadMansSwitchConfig,
+    order_executor: Arc<OrderExecutor>,
+    circuit_breaker: Arc<CircuitBreaker>,
+    audit_logger: Arc<AuditLogger>,
+    executor_heartbeats: Vec<(&'static str, Arc<AtomicI64>)>,
+) {
+    if !config.enabled {
+        info!("Dead-man's switch disabled (DEAD_MANS_SWITCH_ENABLED != true)");
+        return;
+    }
+
+    info!(
+        interval_secs = config.heartbeat_interval_secs,
+        heartbeat_timeout_secs = config.heartbeat_timeout_secs,
+        executor_timeout_secs = config.executor_timeout_secs,
+        "Spawning dead-man's switch heartbeat"
+    );
+
+    tokio::spawn(run_loop(
+        config,
+        order_executor,
+        circuit_breaker,
+        audit_logger,
+        executor_heartbeats,
+    ));
+}
+
+async fn run_loop(
+    config: DeadMansSwitchConfig,
+    order_executor: Arc<OrderExecutor>,
+    circuit_breaker: Arc<CircuitBreaker>,
+    audit_logger: Arc<AuditLogger>,
+    executor_heartbeats: Vec<(&'static str, Arc<AtomicI64>)>,
+) {
+    let instance_id = Uuid::new_v4().to_string();
+    let mut redis: Option<redis::aio::ConnectionManager> = None;
+    let mut hard_kill_latch = HardKillLatch::default();
+    let mut executor_watches: Vec<(&'static str, Arc<AtomicI64>, HeartbeatWatch)> =
+        executor_heartbeats
+            .into_iter()
+            .map(|(task, stamp)| {
+                (
+                    task,
+                    stamp,
+                    HeartbeatWatch::new(config.executor_timeout_secs),
+                )
+            })
+            .collect();
+
+    let mut ticker = tokio::time::interval(tokio::time::Duration::from_secs(
+        config.heartbeat_interval_secs.max(1),
+    ));
+    loop {
+        ticker.tick().await;
+        let now = Utc::now();
+        let live = order_executor.is_live_ready().await;
+        let hard_kill =
+            circuit_breaker.state().await.trip_reason == Some(TripReason::HardKillSwitch);
+
+        if redis.is_none() {
+            redis = connect_redis(&config.redis_url).await;
+        }
+        if let Some(conn) = redis.as_mut() {
+            let heartbeat = Heartbeat {
+                instance_id: instance_id.clone(),
+                sent_at: now,
+                live,
+                hard_kill,
+            };
+            if let Err(e) = publish_heartbeat(conn, &heartbeat, config.heartbeat_timeout_secs).await
+            {
+                warn!(error = %e, "Failed to publish dead-man's switch heartbeat");
+            }
+        }
+
+        let mut reason = None;
+        if hard_kill_latch.observe(hard_kill) {
+            reason = Some(MassCancelReason::HardKill);
+        }
+        for (task, stamp, watch) in executor_watches.iter_mut() {
+            let ts = stamp.load(Ordering::Relaxed);
+            if ts > 0 {
+                if let Some(at) = Utc.timestamp_opt(ts, 0).single() {
+                    watch.record_beat(at);
+                }
+            }
+            if reason.is_none() {
+                if let Some(age_secs) = watch.due(now) {
+                    reason = Some(MassCancelReason::ExecutorStalled {
+                        task: task.to_string(),
+                        age_secs,
+                    });
+                }
+            }
+        }
+
+        let Some(reason) = reason else {
+            continue;
+        };
+        if !live {
+            // Nothing can rest on the CLOB without live credentials.
+            mark_handled(&reason, &mut hard_kill_latch, &mut executor_watches);
+            continue;
+        }
+
+        let result = order_executor.cancel_all_live_orders().await;
+        log_mass_cancel(Some(&audit_logger), "api-server", &reason, &result);
+        if result.is_ok() {
+            mark_handled(&reason, &mut hard_kill_latch, &mut executor_watches);
+        }
+    }
+}
+
+fn mark_handled(
+    reason: &MassCancelReason,
+    hard_kill_latch: &mut HardKillLatch,
+    executor_watches: &mut [(&'static str, Arc<AtomicI64>, HeartbeatWatch)],
+) {
+    match reason {
+        MassCancelReason::HardKill => hard_kill_latch.mark_cancelled(),
+        MassCancelReason::ExecutorStalled { task, .. } => {
+            for (name, _, watch) in executor_watches.iter_mut() {
+                if name == task {
+                    watch.mark_cancelled();
+                }
+            }
+        }
+        MassCancelReason::HeartbeatLost { .. } => {}
+    }
+}
+
+pub async fn connect_redis(url: &str) -> Option<redis::aio::ConnectionManager> {
+    let client = match redis::Client::open(url) {
+        Ok(client) => client,
+        Err(e) => {
+            warn!(error = %e, "Invalid Redis URL for dead-man's switch");
+            return None;
+        }
+    };
+    match redis::aio::ConnectionManager::new(client).await {
+        Ok(conn) => Some(conn),
+        Err(e) => {
+            warn!(error = %e, "Dead-man's switch could not connect to Redis");
+            None
+        }
+    }
+}
+
+async fn publish_heartbeat(
+    conn: &mut redis::aio::ConnectionManager,
+    heartbeat: &Heartbeat,
+    timeout_secs: i64,
+) -> anyhow::Result<()> {
+    let payload = serde_json::to_string(heartbeat)?;
+    // Keep the key around well past the timeout so the watchdog can still
+    // report how stale the last beat was.
+    let ttl_secs = (timeout_secs.max(1) * 10) as u64;
+    conn.set_ex::<_, _, ()>(HEARTBEAT_KEY, payload, ttl_secs)
+        .await?;
+    Ok(())
+}
+
+/// Read the latest heartbeat; `Ok(None)` when the key is absent or unparsable.
+pub async fn read_heartbeat(
+    conn: &mut redis::aio::ConnectionManager,
+) -> anyhow::Result<Option<Heartbeat>> {
+    let raw: Option<String> = conn.get(HEARTBEAT_KEY).await?;
+    Ok(raw.and_then(|v| serde_json::from_str(&v).ok()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_watch_not_armed_before_first_beat() {
+        let watch = HeartbeatWatch::new(30);
+        assert_eq!(watch.due(Utc::now()), None);
+    }
+
+    #[test]
+    fn test_watch_fires_once_per_stale_beat() {
+        let start = Utc::now();
+        let mut watch = HeartbeatWatch::new(30);
+        watch.record_beat(start);
+
+        assert_eq!(watch.due(start + Duration::seconds(10)), None);
+        assert_eq!(watch.due(start + Duration::seconds(31)), Some(31));
+
+        watch.mark_cancelled();
+        assert_eq!(watch.due(start + Duration::seconds(60)), None);
+
+        // A fresh beat re-arms the watch.
+        watch.record_beat(start + Duration::seconds(70));
+        assert_eq!(watch.due(start + Duration::seconds(80)), None);
+        assert_eq!(watch.due(start + Duration::seconds(100)), Some(30));
+    }
+
+    #[test]
+    fn test_watch_ignores_older_beats() {
+        let start = Utc::now();
+        let mut watch = HeartbeatWatch::new(30);
+        watch.record_beat(start);
+        watch.record_beat(start - Duration::seconds(100));
+        assert_eq!(watch.last_beat(), Some(start));
+    }
+
+    #[test]
+    fn test_hard_kill_latch_fires_on_rising_edge() {
+        let mut latch = HardKillLatch::default();
+        assert!(!latch.observe(false));
+        assert!(latch.observe(true));
+        // Not handled yet (e.g. cancel failed) -> keep firing.
+        assert!(latch.observe(true));
+        latch.mark_cancelled();
+        assert!(!latch.observe(true));
+        // Reset then a new trip fires again.
+        assert!(!latch.observe(false));
+        assert!(latch.observe(true));
+    }
+}
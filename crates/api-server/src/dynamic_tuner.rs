@@ -29,8 +29,11 @@ const KEY_ARB_MIN_NET_PROFIT: &str = "ARB_MIN_NET_PROFIT";
 const KEY_ARB_MIN_BOOK_DEPTH: &str = "ARB_MIN_BOOK_DEPTH";
 const KEY_ARB_MAX_SIGNAL_AGE_SECS: &str = "ARB_MAX_SIGNAL_AGE_SECS";
 const KEY_QUANT_BASE_POSITION_SIZE: &str = "QUANT_BASE_POSITION_SIZE";
+const KEY_EXIT_PASSIVE_FILL_PROB: &str = "EXIT_PASSIVE_FILL_PROB";
 
 const EPSILON: f64 = 1e-6;
+/// Settled passive exits needed before the fill probability is recalibrated.
+const MIN_PASSIVE_EXIT_SAMPLES: f64 = 10.0;
 
 /// Redis keys/channels for dynamic tuning.
 pub mod channels {
@@ -38,6 +41,12 @@ pub mod channels {
     pub const ARB_RUNTIME_STATS_LATEST: &str = "arb:runtime:stats:latest";
 }
 
+/// Dynamic config key for operator breaker commands: value 1 trips the live
+/// circuit breaker, 0 resets it.
+pub const CIRCUIT_BREAKER_CONFIG_KEY: &str = "CIRCUIT_BREAKER";
+/// Source tag for updates sent by the `abbot` operator CLI.
+pub const OPERATOR_UPDATE_SOURCE: &str = "operator_cli";
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DynamicConfigUpdate {
     pub key: String,
@@ -78,6 +87,15 @@ struct TuningMetrics {
     current_regime: String,
     /// Quant strategy net P&L over the last 7 days.
     quant_pnl_7d: f64,
+    /// Passive exits settled over the last 7 days.
+    #[serde(default)]
+    passive_exit_samples: f64,
+    /// Share of those where both passive orders filled.
+    #[serde(default)]
+    passive_exit_fill_rate: f64,
+    /// Joint fill probability the optimizer expected for them.
+    #[serde(default)]
+    passive_exit_expected_fill_rate: f64,
 }
 
 #[derive(Debug, Clone)]
@@ -120,54 +138,32 @@ impl Default for DynamicTunerConfig {
 impl DynamicTunerConfig {
     pub fn from_env() -> Self {
         Self {
-            enabled: std::env::var("DYNAMIC_TUNER_ENABLED")
-                .map(|v| v == "true" || v == "1")
-                .unwrap_or(true),
-            interval_secs: std::env::var("DYNAMIC_TUNER_INTERVAL_SECS")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(300),
-            apply_changes: std::env::var("DYNAMIC_TUNER_APPLY")
-                .map(|v| v == "true" || v == "1")
-                .unwrap_or(true),
-            regime_hysteresis_intervals: std::env::var("DYNAMIC_TUNER_REGIME_STREAK")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(2),
-            max_drawdown_freeze: std::env::var("DYNAMIC_TUNER_FREEZE_DRAWDOWN")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(0.20),
-            evaluation_delay_minutes: std::env::var("DYNAMIC_TUNER_EVAL_DELAY_MINUTES")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(10),
-            fill_rate_degrade_delta: std::env::var("DYNAMIC_TUNER_FILL_DEGRADE_DELTA")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(0.08),
-            pnl_degrade_delta: std::env::var("DYNAMIC_TUNER_PNL_DEGRADE_DELTA")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(75.0),
-            bootstrap_enabled: std::env::var("DYNAMIC_TUNER_BOOTSTRAP_ENABLED")
-                .map(|v| v == "true" || v == "1")
-                .unwrap_or(true),
-            bootstrap_max_attempts: std::env::var("DYNAMIC_TUNER_BOOTSTRAP_MAX_ATTEMPTS")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(100),
-            no_trade_window_minutes: std::env::var("DYNAMIC_TUNER_NO_TRADE_WINDOW_MINUTES")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(120),
-            no_trade_min_attempts: std::env::var("DYNAMIC_TUNER_NO_TRADE_MIN_ATTEMPTS")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(20),
-            redis_url: std::env::var("DYNAMIC_TUNER_REDIS_URL")
-                .or_else(|_| std::env::var("REDIS_URL"))
-                .unwrap_or_else(|_| "redis://127.0.0.1:6379".to_string()),
+            enabled: polymarket_core::settings::get("DYNAMIC_TUNER_ENABLED"),
+            interval_secs: polymarket_core::settings::get("DYNAMIC_TUNER_INTERVAL_SECS"),
+            apply_changes: polymarket_core::settings::get("DYNAMIC_TUNER_APPLY"),
+            regime_hysteresis_intervals: polymarket_core::settings::get(
+                "DYNAMIC_TUNER_REGIME_STREAK",
+            ),
+            max_drawdown_freeze: polymarket_core::settings::get("DYNAMIC_TUNER_FREEZE_DRAWDOWN"),
+            evaluation_delay_minutes: polymarket_core::settings::get(
+                "DYNAMIC_TUNER_EVAL_DELAY_MINUTES",
+            ),
+            fill_rate_degrade_delta: polymarket_core::settings::get(
+                "DYNAMIC_TUNER_FILL_DEGRADE_DELTA",
+            ),
+            pnl_degrade_delta: polymarket_core::settings::get("DYNAMIC_TUNER_PNL_DEGRADE_DELTA"),
+            bootstrap_enabled: polymarket_core::settings::get("DYNAMIC_TUNER_BOOTSTRAP_ENABLED"),
+            bootstrap_max_attempts: polymarket_core::settings::get(
+                "DYNAMIC_TUNER_BOOTSTRAP_MAX_ATTEMPTS",
+            ),
+            no_trade_window_minutes: polymarket_core::settings::get(
+                "DYNAMIC_TUNER_NO_TRADE_WINDOW_MINUTES",
+            ),
+            no_trade_min_attempts: polymarket_core::settings::get(
+                "DYNAMIC_TUNER_NO_TRADE_MIN_ATTEMPTS",
+            ),
+            redis_url: polymarket_core::settings::get_opt("DYNAMIC_TUNER_REDIS_URL")
+                .unwrap_or_else(|| polymarket_core::settings::get("REDIS_URL")),
         }
     }
 }
@@ -727,6 +723,22 @@ impl DynamicTuner {
         };
         targets.insert(KEY_QUANT_BASE_POSITION_SIZE.to_string(), desired_quant_size);
 
+        // EXIT_PASSIVE_FILL_PROB: scale toward the realized passive fill rate.
+        // Both legs must fill, so the joint expected/realized ratio maps to
+        // its square root per leg.
+        if metrics.passive_exit_samples >= MIN_PASSIVE_EXIT_SAMPLES
+            && metrics.passive_exit_expected_fill_rate > EPSILON
+        {
+            if let Some(row) = rows.get(KEY_EXIT_PASSIVE_FILL_PROB) {
+                let ratio =
+                    metrics.passive_exit_fill_rate / metrics.passive_exit_expected_fill_rate;
+                targets.insert(
+                    KEY_EXIT_PASSIVE_FILL_PROB.to_string(),
+                    decimal_to_f64(row.current_value) * ratio.sqrt(),
+                );
+            }
+        }
+
         targets
     }
 
@@ -854,6 +866,28 @@ impl DynamicTuner {
         .await
         .unwrap_or(Decimal::ZERO);
 
+        // Passive exits settled in the last 7 days: how often both orders
+        // filled versus how often the optimizer expected them to.
+        let (passive_exit_samples, passive_exit_fill_rate, passive_exit_expected_fill_rate): (
+            f64,
+            f64,
+            f64,
+        ) = sqlx::query_as(
+            r#"
+            SELECT
+                COUNT(*)::double precision,
+                COALESCE(AVG(CASE WHEN realized_via = 'passive' THEN 1.0 ELSE 0.0 END), 0)::double precision,
+                COALESCE(AVG(passive_fill_probability), 0)::double precision
+            FROM exit_policy_decisions
+            WHERE policy = 'passive'
+              AND realized_via IN ('passive', 'passive_fallback')
+              AND realized_at >= NOW() - INTERVAL '7 days'
+            "#,
+        )
+        .fetch_one(&self.pool)
+        .await
+        .unwrap_or((0.0, 0.0, 0.0));
+
         // Query the most common arb skip reason from recent trade events.
         let top_skip_reason: Option<String> = sqlx::query_scalar(
             r#"
@@ -895,6 +929,9 @@ impl DynamicTuner {
             cb_tripped: cb_state.tripped,
             current_regime: "Uncertain".to_string(),
             quant_pnl_7d: decimal_to_f64(quant_pnl_7d),
+            passive_exit_samples,
+            passive_exit_fill_rate,
+            passive_exit_expected_fill_rate,
         })
     }
 
@@ -919,7 +956,7 @@ impl DynamicTuner {
         let seeds = vec![
             ConfigSeed {
                 key: KEY_ARB_MIN_PROFIT_THRESHOLD,
-                default_value: env_decimal("ARB_MIN_PROFIT_THRESHOLD", Decimal::new(5, 3)),
+                default_value: polymarket_core::settings::get("ARB_MIN_PROFIT_THRESHOLD"),
                 min_value: Decimal::new(2, 3),
                 // Hard-cap at 0.005 (0.5%) — real arb spreads are 0.5-2%.
                 // Higher values create a death spiral: no signals → tuner raises → even fewer signals.
@@ -928,14 +965,16 @@ impl DynamicTuner {
             },
             ConfigSeed {
                 key: KEY_ARB_MONITOR_MAX_MARKETS,
-                default_value: env_decimal("ARB_MONITOR_MAX_MARKETS", Decimal::new(300, 0)),
+                default_value: polymarket_core::settings::get_opt("ARB_MONITOR_MAX_MARKETS")
+                    .unwrap_or(Decimal::new(300, 0)),
                 min_value: Decimal::new(25, 0),
                 max_value: Decimal::new(1500, 0),
                 max_step_pct: Decimal::new(15, 2),
             },
             ConfigSeed {
                 key: KEY_ARB_MONITOR_EXPLORATION_SLOTS,
-                default_value: env_decimal("ARB_MONITOR_EXPLORATION_SLOTS", Decimal::new(5, 0)),
+                default_value: polymarket_core::settings::get_opt("ARB_MONITOR_EXPLORATION_SLOTS")
+                    .unwrap_or(Decimal::new(5, 0)),
                 min_value: Decimal::new(1, 0),
                 max_value: Decimal::new(500, 0),
                 max_step_pct: Decimal::new(25, 2),
@@ -950,28 +989,31 @@ impl DynamicTuner {
             // ── Arb executor tuning knobs ──
             ConfigSeed {
                 key: KEY_ARB_POSITION_SIZE,
-                default_value: env_decimal("ARB_POSITION_SIZE", Decimal::new(10, 0)),
+                // Seeds the small-wallet size, not the executor's default,
+                // unless the operator set one.
+                default_value: polymarket_core::settings::explicit("ARB_POSITION_SIZE")
+                    .unwrap_or(Decimal::new(10, 0)),
                 min_value: Decimal::new(5, 0),  // $5 floor
                 max_value: Decimal::new(25, 0), // $25 ceiling (small wallet)
                 max_step_pct: Decimal::new(20, 2),
             },
             ConfigSeed {
                 key: KEY_ARB_MIN_NET_PROFIT,
-                default_value: env_decimal("ARB_MIN_NET_PROFIT", Decimal::new(1, 3)),
+                default_value: polymarket_core::settings::get("ARB_MIN_NET_PROFIT"),
                 min_value: Decimal::new(5, 4), // 0.0005 floor
                 max_value: Decimal::new(5, 2), // 0.05 ceiling
                 max_step_pct: Decimal::new(15, 2),
             },
             ConfigSeed {
                 key: KEY_ARB_MIN_BOOK_DEPTH,
-                default_value: env_decimal("ARB_MIN_BOOK_DEPTH", Decimal::new(25, 0)),
+                default_value: polymarket_core::settings::get("ARB_MIN_BOOK_DEPTH"),
                 min_value: Decimal::new(10, 0),  // $10 floor
                 max_value: Decimal::new(200, 0), // $200 ceiling (small wallet)
                 max_step_pct: Decimal::new(20, 2),
             },
             ConfigSeed {
                 key: KEY_ARB_MAX_SIGNAL_AGE_SECS,
-                default_value: env_decimal("ARB_MAX_SIGNAL_AGE_SECS", Decimal::new(30, 0)),
+                default_value: polymarket_core::settings::get("ARB_MAX_SIGNAL_AGE_SECS"),
                 min_value: Decimal::new(5, 0),   // 5s floor
                 max_value: Decimal::new(300, 0), // 300s ceiling
                 max_step_pct: Decimal::new(25, 2),
@@ -979,11 +1021,19 @@ impl DynamicTuner {
             // ── Quant signal executor tuning knob ──
             ConfigSeed {
                 key: KEY_QUANT_BASE_POSITION_SIZE,
-                default_value: env_decimal("QUANT_BASE_POSITION_SIZE", Decimal::new(30, 0)),
+                default_value: polymarket_core::settings::get("QUANT_BASE_POSITION_SIZE"),
                 min_value: Decimal::new(10, 0),    // $10 floor
                 max_value: Decimal::new(200, 0),   // $200 ceiling
                 max_step_pct: Decimal::new(15, 2), // 15% per cycle
             },
+            // ── Exit optimizer tuning knob ──
+            ConfigSeed {
+                key: KEY_EXIT_PASSIVE_FILL_PROB,
+                default_value: polymarket_core::settings::get("EXIT_PASSIVE_FILL_PROB"),
+                min_value: Decimal::new(5, 2),  // 0.05 floor
+                max_value: Decimal::new(95, 2), // 0.95 ceiling
+                max_step_pct: Decimal::new(20, 2),
+            },
         ];
 
         for seed in seeds {
@@ -1244,6 +1294,28 @@ impl DynamicTuner {
     }
 }
 
+/// Broadcast an operator trip (`tripped`) or reset of the circuit breaker so
+/// running api-servers apply it without a restart.
+pub async fn publish_circuit_breaker_command(
+    redis: &mut redis::aio::ConnectionManager,
+    tripped: bool,
+    reason: &str,
+    operator: &str,
+) -> anyhow::Result<()> {
+    let payload = DynamicConfigUpdate {
+        key: CIRCUIT_BREAKER_CONFIG_KEY.to_string(),
+        value: if tripped { Decimal::ONE } else { Decimal::ZERO },
+        reason: reason.to_string(),
+        source: OPERATOR_UPDATE_SOURCE.to_string(),
+        timestamp: Utc::now(),
+        metrics: serde_json::json!({ "operator": operator }),
+    };
+    let _: () = redis
+        .publish(channels::CONFIG_UPDATES, serde_json::to_string(&payload)?)
+        .await?;
+    Ok(())
+}
+
 /// Subscribes to dynamic config updates and applies them to local API runtime.
 pub fn spawn_dynamic_config_subscriber(
     redis_url: String,
@@ -1252,6 +1324,8 @@ pub fn spawn_dynamic_config_subscriber(
     quant_executor_config: Option<
         Arc<RwLock<crate::quant_signal_executor::QuantSignalExecutorConfig>>,
     >,
+    exit_handler_config: Option<Arc<RwLock<crate::exit_handler::ExitHandlerConfig>>>,
+    circuit_breaker: Option<Arc<CircuitBreaker>>,
 ) {
     tokio::spawn(async move {
         loop {
@@ -1260,6 +1334,8 @@ pub fn spawn_dynamic_config_subscriber(
                 pool.clone(),
                 arb_executor_config.clone(),
                 quant_executor_config.clone(),
+                exit_handler_config.clone(),
+                circuit_breaker.clone(),
             )
             .await
             {
@@ -1279,6 +1355,8 @@ async fn run_dynamic_config_subscriber(
     quant_executor_config: Option<
         Arc<RwLock<crate::quant_signal_executor::QuantSignalExecutorConfig>>,
     >,
+    exit_handler_config: Option<Arc<RwLock<crate::exit_handler::ExitHandlerConfig>>>,
+    circuit_breaker: Option<Arc<CircuitBreaker>>,
 ) -> anyhow::Result<()> {
     let allowed_sources = load_allowed_update_sources();
     let bounds = load_dynamic_bounds(&pool).await;
@@ -1305,6 +1383,26 @@ async fn run_dynamic_config_subscriber(
             warn!(error = %e, "Failed applying startup dynamic config snapshot to quant executor");
         }
     }
+    if let Some(ref exit_config) = exit_handler_config {
+        if let Err(e) = apply_startup_snapshot_to_exit_handler(&pool, exit_config, &bounds).await {
+            warn!(error = %e, "Failed applying startup dynamic config snapshot to exit handler");
+        }
+    }
+
+    // Workspace risk limits are caps, so they go on after the tuned values.
+    match crate::risk_limits::load_canonical_active_document(&pool).await {
+        Ok(Some((version, document))) => {
+            apply_risk_limits(
+                &document,
+                arb_executor_config.as_ref(),
+                quant_executor_config.as_ref(),
+            )
+            .await;
+            info!(version, "Applied stored workspace risk limits at startup");
+        }
+        Ok(None) => {}
+        Err(e) => warn!(error = %e, "Failed loading workspace risk limits at startup"),
+    }
 
     let mut stream = pubsub.on_message();
     while let Some(msg) = stream.next().await {
@@ -1333,6 +1431,49 @@ async fn run_dynamic_config_subscriber(
             continue;
         }
 
+        if update.key == CIRCUIT_BREAKER_CONFIG_KEY {
+            if let Some(ref breaker) = circuit_breaker {
+                let operator = update
+                    .metrics
+                    .get("operator")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("unknown");
+                if update.value.is_zero() {
+                    breaker.reset().await;
+                } else {
+                    breaker.manual_trip(Some(update.reason.clone())).await;
+                }
+                warn!(
+                    tripped = !update.value.is_zero(),
+                    operator,
+                    reason = %update.reason,
+                    source = %update.source,
+                    "Applied operator circuit breaker command"
+                );
+            }
+            continue;
+        }
+
+        if update.key == crate::risk_limits::RISK_LIMITS_CONFIG_KEY {
+            match crate::risk_limits::document_from_update(&update) {
+                Ok(document) => {
+                    apply_risk_limits(
+                        &document,
+                        arb_executor_config.as_ref(),
+                        quant_executor_config.as_ref(),
+                    )
+                    .await;
+                    info!(
+                        version = %update.value,
+                        source = %update.source,
+                        "Applied workspace risk limits at runtime"
+                    );
+                }
+                Err(e) => warn!(error = %e, "Ignoring invalid risk limits update"),
+            }
+            continue;
+        }
+
         let Some(validated) = clamp_dynamic_value(&update.key, update.value, &bounds) else {
             warn!(key = %update.key, "Ignoring dynamic config update for unsupported key");
             continue;
@@ -1400,11 +1541,52 @@ async fn run_dynamic_config_subscriber(
                 );
             }
         }
+
+        if let Some(ref exit_config) = exit_handler_config {
+            let applied = match update.key.as_str() {
+                KEY_EXIT_PASSIVE_FILL_PROB => {
+                    if let Some(probability) = update.value.to_f64() {
+                        exit_config
+                            .write()
+                            .await
+                            .exit_optimizer
+                            .passive_fill_probability = probability;
+                        true
+                    } else {
+                        false
+                    }
+                }
+                _ => false,
+            };
+            if applied {
+                info!(
+                    key = %update.key,
+                    value = %update.value,
+                    source = %update.source,
+                    "Applied exit handler config update at runtime"
+                );
+            }
+        }
     }
 
     Ok(())
 }
 
+async fn apply_risk_limits(
+    document: &crate::risk_limits::RiskLimitsDocument,
+    arb_executor_config: Option<&Arc<RwLock<crate::arb_executor::ArbExecutorConfig>>>,
+    quant_executor_config: Option<
+        &Arc<RwLock<crate::quant_signal_executor::QuantSignalExecutorConfig>>,
+    >,
+) {
+    if let Some(arb_config) = arb_executor_config {
+        document.apply_to_arb(&mut *arb_config.write().await);
+    }
+    if let Some(quant_config) = quant_executor_config {
+        document.apply_to_quant(&mut *quant_config.write().await);
+    }
+}
+
 #[derive(Debug, sqlx::FromRow)]
 struct DynamicBoundsRow {
     key: String,
@@ -1544,16 +1726,56 @@ async fn apply_startup_snapshot_to_quant_executor(
     Ok(())
 }
 
+/// Applies the current dynamic config snapshot to the exit handler config.
+async fn apply_startup_snapshot_to_exit_handler(
+    pool: &PgPool,
+    exit_config: &Arc<RwLock<crate::exit_handler::ExitHandlerConfig>>,
+    bounds: &HashMap<String, (Decimal, Decimal)>,
+) -> anyhow::Result<()> {
+    let rows: Vec<DynamicValueRow> = sqlx::query_as(
+        r#"
+        SELECT key, current_value
+        FROM dynamic_config
+        WHERE enabled = TRUE
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let mut applied = 0usize;
+    for row in rows {
+        let Some(value) = clamp_dynamic_value(&row.key, row.current_value, bounds) else {
+            continue;
+        };
+        let did_apply = match row.key.as_str() {
+            KEY_EXIT_PASSIVE_FILL_PROB => {
+                if let Some(probability) = value.to_f64() {
+                    exit_config
+                        .write()
+                        .await
+                        .exit_optimizer
+                        .passive_fill_probability = probability;
+                    true
+                } else {
+                    false
+                }
+            }
+            _ => false,
+        };
+        if did_apply {
+            applied += 1;
+        }
+    }
+
+    info!(
+        applied,
+        "Applied startup dynamic config snapshot to exit handler"
+    );
+    Ok(())
+}
+
 fn load_allowed_update_sources() -> Vec<String> {
-    std::env::var("DYNAMIC_CONFIG_ALLOWED_SOURCES")
-        .unwrap_or_else(|_| {
-            "dynamic_tuner,dynamic_tuner_rollback,dynamic_tuner_sync,workspace_manual".to_string()
-        })
-        .split(',')
-        .map(str::trim)
-        .filter(|s| !s.is_empty())
-        .map(|s| s.to_string())
-        .collect()
+    polymarket_core::settings::get("DYNAMIC_CONFIG_ALLOWED_SOURCES")
 }
 
 fn source_allowed(source: &str, allowed: &[String]) -> bool {
@@ -1587,6 +1809,7 @@ fn fallback_dynamic_bounds() -> HashMap<String, (Decimal, Decimal)> {
         KEY_ARB_MIN_BOOK_DEPTH,
         KEY_ARB_MAX_SIGNAL_AGE_SECS,
         KEY_QUANT_BASE_POSITION_SIZE,
+        KEY_EXIT_PASSIVE_FILL_PROB,
     ] {
         if let Some(bounds) = fallback_bounds_for_key(key) {
             map.insert(key.to_string(), bounds);
@@ -1606,6 +1829,7 @@ fn fallback_bounds_for_key(key: &str) -> Option<(Decimal, Decimal)> {
         KEY_ARB_MIN_BOOK_DEPTH => Some((Decimal::new(10, 0), Decimal::new(200, 0))),
         KEY_ARB_MAX_SIGNAL_AGE_SECS => Some((Decimal::new(5, 0), Decimal::new(300, 0))),
         KEY_QUANT_BASE_POSITION_SIZE => Some((Decimal::new(10, 0), Decimal::new(200, 0))),
+        KEY_EXIT_PASSIVE_FILL_PROB => Some((Decimal::new(5, 2), Decimal::new(95, 2))),
         _ => None,
     }
 }
@@ -1623,16 +1847,8 @@ fn clamp_to_bounds(value: Decimal, min: Decimal, max: Decimal) -> Decimal {
     value.max(min).min(max)
 }
 
-fn env_decimal(name: &str, fallback: Decimal) -> Decimal {
-    std::env::var(name)
-        .ok()
-        .and_then(|v| v.parse().ok())
-        .unwrap_or(fallback)
-}
-
 fn env_aggressiveness_level() -> Decimal {
-    match std::env::var("ARB_MONITOR_AGGRESSIVENESS")
-        .unwrap_or_else(|_| "balanced".to_string())
+    match polymarket_core::settings::get::<String>("ARB_MONITOR_AGGRESSIVENESS")
         .to_lowercase()
         .as_str()
     {
@@ -3,13 +3,13 @@
 //! Uses Resend's HTTP API instead of SMTP for reliable email delivery
 //! in cloud environments where SMTP ports may be blocked.
 
+use async_trait::async_trait;
+use polymarket_core::alerting::{Alert, AlertSink};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use thiserror::Error;
 
-const RESEND_API_URL: &str = "https://api.resend.com/emails";
-
 /// Email client errors.
 #[derive(Debug, Error)]
 pub enum EmailError {
@@ -32,26 +32,27 @@ pub struct EmailConfig {
     pub from_name: String,
     /// Application URL for reset links.
     pub app_url: String,
+    /// Resend endpoint; overridable for local testing.
+    pub api_url: String,
 }
 
 impl EmailConfig {
     /// Create configuration from environment variables.
     pub fn from_env() -> Option<Self> {
-        let api_key = std::env::var("RESEND_API_KEY").ok()?;
-        let from_email = std::env::var("RESEND_FROM")
-            .or_else(|_| std::env::var("SMTP_FROM"))
-            .ok()?;
-        let from_name = std::env::var("RESEND_FROM_NAME")
-            .or_else(|_| std::env::var("SMTP_FROM_NAME"))
-            .unwrap_or_else(|_| "Polymarket Scanner".to_string());
-        let app_url =
-            std::env::var("APP_URL").unwrap_or_else(|_| "http://localhost:3002".to_string());
+        let api_key = polymarket_core::settings::get_opt::<String>("RESEND_API_KEY")?;
+        let from_email = polymarket_core::settings::get_opt("RESEND_FROM")
+            .or_else(|| polymarket_core::settings::get_opt("SMTP_FROM"))?;
+        let from_name = polymarket_core::settings::explicit("RESEND_FROM_NAME")
+            .unwrap_or_else(|| polymarket_core::settings::get("SMTP_FROM_NAME"));
+        let app_url = polymarket_core::settings::get("APP_URL");
+        let api_url = polymarket_core::settings::get("RESEND_API_URL");
 
         Some(Self {
             api_key,
             from_email,
             from_name,
             app_url,
+            api_url,
         })
     }
 }
@@ -83,6 +84,7 @@ pub struct EmailClient {
     api_key: String,
     from: String,
     app_url: String,
+    api_url: String,
 }
 
 impl EmailClient {
@@ -100,6 +102,7 @@ impl EmailClient {
             api_key: config.api_key,
             from,
             app_url: config.app_url,
+            api_url: config.api_url,
         })
     }
 
@@ -121,7 +124,7 @@ impl EmailClient {
 
         let response = self
             .client
-            .post(RESEND_API_URL)
+            .post(&self.api_url)
             .header("Authorization", format!("Bearer {}", self.api_key))
             .header("Content-Type", "application/json")
             .json(&request)
@@ -234,6 +237,47 @@ If you didn't request this password reset, you can safely ignore this email."#
     }
 }
 
+/// Alert sink delivering alerts as plain-text email to fixed recipients.
+pub struct EmailAlertSink {
+    client: Arc<EmailClient>,
+    recipients: Vec<String>,
+}
+
+impl EmailAlertSink {
+    pub fn new(client: Arc<EmailClient>, recipients: Vec<String>) -> Self {
+        Self { client, recipients }
+    }
+
+    /// Recipients from `ALERT_EMAIL_TO` (comma-separated); `None` when unset.
+    pub fn from_env(client: Arc<EmailClient>) -> Option<Self> {
+        let recipients: Vec<String> = polymarket_core::settings::get_opt("ALERT_EMAIL_TO")?;
+        (!recipients.is_empty()).then(|| Self::new(client, recipients))
+    }
+}
+
+#[async_trait]
+impl AlertSink for EmailAlertSink {
+    fn name(&self) -> &str {
+        "email"
+    }
+
+    async fn send(&self, alert: &Alert) -> anyhow::Result<()> {
+        let subject = format!(
+            "[{}] {}",
+            alert.severity.as_str().to_ascii_uppercase(),
+            alert.title
+        );
+        let body = alert.plain_text();
+        for recipient in &self.recipients {
+            self.client
+                .send_simple(recipient, &subject, &body)
+                .await
+                .map_err(|e| anyhow::anyhow!("{}: {}", recipient, e))?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
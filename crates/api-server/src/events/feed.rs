@@ -0,0 +1,330 @@
+//! Minimal RSS 2.0 and Atom 1.0 reader.
+//!
+//! Extracts what event ingestion needs from each item or entry: id, title,
+//! summary, link, publication time and categories. It scans for tags rather
+//! than building a document tree, understands CDATA sections and character
+//! references, and strips any HTML left in titles and summaries. Same-name
+//! nested elements and RSS 1.0 are not supported.
+
+use anyhow::bail;
+use chrono::{DateTime, Utc};
+
+/// One item of an RSS feed or entry of an Atom feed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeedEntry {
+    /// RSS `guid` or Atom `id`.
+    pub id: Option<String>,
+    pub title: String,
+    pub summary: Option<String>,
+    pub link: Option<String>,
+    pub published_at: Option<DateTime<Utc>>,
+    pub categories: Vec<String>,
+}
+
+/// Parse an RSS or Atom document. Entries without a title are skipped.
+pub fn parse_feed(xml: &str) -> anyhow::Result<Vec<FeedEntry>> {
+    let entries = if find_open(xml, "rss", 0).is_some() {
+        elements(xml, "item")
+            .into_iter()
+            .map(|(_, item)| rss_item(item))
+            .collect::<Vec<_>>()
+    } else if find_open(xml, "feed", 0).is_some() {
+        elements(xml, "entry")
+            .into_iter()
+            .map(|(_, entry)| atom_entry(entry))
+            .collect()
+    } else {
+        bail!("not an RSS or Atom document");
+    };
+    Ok(entries
+        .into_iter()
+        .filter(|e| !e.title.is_empty())
+        .collect())
+}
+
+fn rss_item(item: &str) -> FeedEntry {
+    FeedEntry {
+        id: first_text(item, "guid"),
+        title: first_text(item, "title").unwrap_or_default(),
+        summary: first_text(item, "description"),
+        link: first_text(item, "link"),
+        published_at: first_text(item, "pubDate")
+            .or_else(|| first_text(item, "dc:date"))
+            .and_then(|raw| parse_date(&raw)),
+        categories: elements(item, "category")
+            .into_iter()
+            .map(|(_, inner)| text(inner))
+            .filter(|c| !c.is_empty())
+            .collect(),
+    }
+}
+
+fn atom_entry(entry: &str) -> FeedEntry {
+    let links = elements(entry, "link");
+    let link = links
+        .iter()
+        .find(|(attrs, _)| attr(attrs, "rel").is_none_or(|rel| rel == "alternate"))
+        .or(links.first())
+        .and_then(|(attrs, _)| attr(attrs, "href"));
+    FeedEntry {
+        id: first_text(entry, "id"),
+        title: first_text(entry, "title").unwrap_or_default(),
+        summary: first_text(entry, "summary").or_else(|| first_text(entry, "content")),
+        link,
+        published_at: first_text(entry, "published")
+            .or_else(|| first_text(entry, "updated"))
+            .and_then(|raw| parse_date(&raw)),
+        categories: elements(entry, "category")
+            .into_iter()
+            .filter_map(|(attrs, _)| attr(attrs, "term").or_else(|| attr(attrs, "label")))
+            .collect(),
+    }
+}
+
+/// RFC 3339 (Atom, `dc:date`) or RFC 2822 (RSS `pubDate`).
+fn parse_date(raw: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(raw)
+        .or_else(|_| DateTime::parse_from_rfc2822(raw))
+        .ok()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+/// Text of the first `tag` element, or `None` if it is missing or empty.
+fn first_text(xml: &str, tag: &str) -> Option<String> {
+    let (_, inner) = elements(xml, tag).into_iter().next()?;
+    Some(text(inner)).filter(|t| !t.is_empty())
+}
+
+/// Offset of the next `<tag` at or after `from` whose name is exactly `tag`.
+fn find_open(xml: &str, tag: &str, from: usize) -> Option<usize> {
+    let pattern = format!("<{tag}");
+    let mut at = from;
+    while let Some(rel) = xml[at..].find(&pattern) {
+        let start = at + rel;
+        let after = start + pattern.len();
+        if xml[after..]
+            .chars()
+            .next()
+            .is_some_and(|c| c == '>' || c == '/' || c.is_whitespace())
+        {
+            return Some(start);
+        }
+        at = after;
+    }
+    None
+}
+
+/// Every `tag` element in document order, as `(attributes, inner markup)`.
+/// Self-closing elements have empty inner markup.
+fn elements<'a>(xml: &'a str, tag: &str) -> Vec<(&'a str, &'a str)> {
+    let close = format!("</{tag}>");
+    let mut found = Vec::new();
+    let mut at = 0;
+    while let Some(start) = find_open(xml, tag, at) {
+        let Some(head_len) = xml[start..].find('>') else {
+            break;
+        };
+        let head_end = start + head_len;
+        let head = &xml[start + 1 + tag.len()..head_end];
+        if let Some(attrs) = head.strip_suffix('/') {
+            found.push((attrs.trim(), ""));
+            at = head_end + 1;
+            continue;
+        }
+        let body = head_end + 1;
+        let Some(body_len) = xml[body..].find(&close) else {
+            break;
+        };
+        found.push((head.trim(), &xml[body..body + body_len]));
+        at = body + body_len + close.len();
+    }
+    found
+}
+
+/// Value of attribute `name` in an element's attribute list.
+fn attr(attrs: &str, name: &str) -> Option<String> {
+    for quote in ['"', '\''] {
+        let pattern = format!("{name}={quote}");
+        let mut at = 0;
+        while let Some(rel) = attrs[at..].find(&pattern) {
+            let start = at + rel;
+            let value = start + pattern.len();
+            if start == 0 || attrs[..start].ends_with(char::is_whitespace) {
+                let len = attrs[value..].find(quote)?;
+                return Some(decode_entities(&attrs[value..value + len]));
+            }
+            at = value;
+        }
+    }
+    None
+}
+
+/// Plain text of an element's inner markup: CDATA unwrapped, character
+/// references decoded, tags stripped and whitespace collapsed.
+fn text(inner: &str) -> String {
+    let mut raw = String::with_capacity(inner.len());
+    let mut rest = inner;
+    while let Some(start) = rest.find("<![CDATA[") {
+        raw.push_str(&decode_entities(&rest[..start]));
+        let cdata = &rest[start + "<![CDATA[".len()..];
+        let end = cdata.find("]]>").unwrap_or(cdata.len());
+        raw.push_str(&cdata[..end]);
+        rest = cdata.get(end + "]]>".len()..).unwrap_or("");
+    }
+    raw.push_str(&decode_entities(rest));
+    decode_entities(&strip_tags(&raw))
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Drop `<...>` markup, keeping a bare `<` that does not start a tag.
+fn strip_tags(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        let starts_tag = c == '<'
+            && chars
+                .peek()
+                .is_some_and(|&n| n.is_ascii_alphabetic() || n == '/' || n == '!');
+        if !starts_tag {
+            out.push(c);
+            continue;
+        }
+        for skipped in chars.by_ref() {
+            if skipped == '>' {
+                break;
+            }
+        }
+        out.push(' ');
+    }
+    out
+}
+
+/// Decode the predefined XML entities, `&nbsp;` and numeric references;
+/// anything else is left as written.
+fn decode_entities(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(amp) = rest.find('&') {
+        out.push_str(&rest[..amp]);
+        let tail = &rest[amp..];
+        let decoded = tail.find(';').filter(|&semi| semi <= 10).and_then(|semi| {
+            let c = match &tail[1..semi] {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                "nbsp" => Some(' '),
+                name => name
+                    .strip_prefix("#x")
+                    .or_else(|| name.strip_prefix("#X"))
+                    .map(|hex| u32::from_str_radix(hex, 16).ok())
+                    .unwrap_or_else(|| name.strip_prefix('#').and_then(|d| d.parse().ok()))
+                    .and_then(char::from_u32),
+            };
+            c.map(|c| (c, semi))
+        });
+        match decoded {
+            Some((c, semi)) => {
+                out.push(c);
+                rest = &tail[semi + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &tail[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RSS: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/events/wire.rss"
+    ));
+    const ATOM: &str = include_str!(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/fixtures/events/newsroom.atom"
+    ));
+
+    #[test]
+    fn test_parses_rss_items() {
+        let entries = parse_feed(RSS).unwrap();
+        assert_eq!(entries.len(), 3);
+
+        let first = &entries[0];
+        assert_eq!(first.id.as_deref(), Some("wire-2026-10-18-0001"));
+        assert_eq!(
+            first.title,
+            "Senate approves stopgap funding bill, averting shutdown"
+        );
+        assert_eq!(
+            first.summary.as_deref(),
+            Some("The bill passed 68-31 & now heads to the House.")
+        );
+        assert_eq!(
+            first.published_at.unwrap().to_rfc3339(),
+            "2026-10-18T13:05:00+00:00"
+        );
+        assert_eq!(first.categories, vec!["Politics", "US Government"]);
+
+        // Entities in the title, no guid, no date.
+        assert_eq!(
+            entries[2].title,
+            "Fed's Powell: \"no rush\" on December cut"
+        );
+        assert!(entries[2].id.is_none());
+        assert!(entries[2].published_at.is_none());
+    }
+
+    #[test]
+    fn test_parses_atom_entries() {
+        let entries = parse_feed(ATOM).unwrap();
+        assert_eq!(entries.len(), 2);
+
+        let first = &entries[0];
+        assert_eq!(
+            first.id.as_deref(),
+            Some("tag:newsroom.example,2026:launch-delay")
+        );
+        assert_eq!(
+            first.link.as_deref(),
+            Some("https://newsroom.example/launch-delay")
+        );
+        assert_eq!(first.categories, vec!["space", "launches"]);
+        assert_eq!(
+            first.published_at.unwrap().to_rfc3339(),
+            "2026-10-18T09:30:00+00:00"
+        );
+        // `updated` stands in for a missing `published`; `content` for `summary`.
+        assert_eq!(
+            entries[1].published_at.unwrap().to_rfc3339(),
+            "2026-10-18T11:00:00+00:00"
+        );
+        assert_eq!(
+            entries[1].summary.as_deref(),
+            Some("Regulators confirmed the approval on Saturday.")
+        );
+    }
+
+    #[test]
+    fn test_rejects_non_feeds_and_decodes_text() {
+        assert!(parse_feed("<html><body>not a feed</body></html>").is_err());
+        assert_eq!(
+            text("  <![CDATA[<b>Bold</b> &amp; raw]]> &#8212; &#x41;&unknown; "),
+            "Bold & raw \u{2014} A&unknown;"
+        );
+        assert_eq!(
+            attr(r#"rel="alternate" href='x?a=1&amp;b=2'"#, "href").unwrap(),
+            "x?a=1&b=2"
+        );
+        assert!(find_open("<titles>x</titles>", "title", 0).is_none());
+    }
+}
@@ -0,0 +1,202 @@
+//! The event-impact feature.
+//!
+//! A linked event's impact on a market is its match score times its
+//! sentiment, halving every half-life after publication: signed, in
+//! `[-1, 1]`, positive when the event points to YES. The market's reaction is
+//! how far its YES price has moved since the link was made, signed the same
+//! way, so a large impact with little reaction is news the market has not
+//! priced yet.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use sqlx::PgPool;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// One linked event as seen from its market.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct EventImpact {
+    pub event_id: Uuid,
+    pub condition_id: String,
+    pub title: String,
+    pub url: Option<String>,
+    pub source: String,
+    pub published_at: DateTime<Utc>,
+    /// When the event was linked and `price_at_event` taken.
+    pub linked_at: DateTime<Utc>,
+    pub match_score: f64,
+    pub matched_terms: Vec<String>,
+    pub sentiment: f64,
+    /// Whether the sender supplied the sentiment. A lexicon score reads the
+    /// headline, not the question, so its sign is not a YES direction.
+    pub sender_sentiment: bool,
+    /// Decayed signed impact as of the query, in `[-1, 1]`.
+    pub impact: f64,
+    /// YES price when the event was linked.
+    pub price_at_event: Option<f64>,
+    /// Latest YES price.
+    pub current_price: Option<f64>,
+    /// YES move since the link, positive when in the event's direction.
+    pub reaction: Option<f64>,
+}
+
+/// Signed impact of an event `age` after publication.
+pub fn event_impact(match_score: f64, sentiment: f64, age: Duration, half_life: Duration) -> f64 {
+    let half_lives = age.num_seconds().max(0) as f64 / half_life.num_seconds().max(1) as f64;
+    (match_score * sentiment * 0.5f64.powf(half_lives)).clamp(-1.0, 1.0)
+}
+
+/// YES move from `from` to `to`, positive when it goes the way `impact` points.
+pub fn reaction(impact: f64, from: f64, to: f64) -> f64 {
+    (to - from) * impact.signum()
+}
+
+#[derive(sqlx::FromRow)]
+struct ImpactRow {
+    event_id: Uuid,
+    condition_id: String,
+    title: String,
+    url: Option<String>,
+    source: String,
+    published_at: DateTime<Utc>,
+    linked_at: DateTime<Utc>,
+    match_score: f64,
+    matched_terms: Vec<String>,
+    sentiment: f64,
+    sender_sentiment: bool,
+    price_at_event: Option<f64>,
+    current_price: Option<f64>,
+}
+
+/// Events published since `since` with their market links, newest first,
+/// for one market or all of them.
+pub async fn recent_impacts(
+    pool: &PgPool,
+    condition_id: Option<&str>,
+    since: DateTime<Utc>,
+    half_life: Duration,
+    now: DateTime<Utc>,
+    limit: i64,
+) -> sqlx::Result<Vec<EventImpact>> {
+    let rows = sqlx::query_as::<_, ImpactRow>(
+        r#"
+        SELECT
+            e.id AS event_id,
+            l.condition_id,
+            e.title,
+            e.url,
+            e.source,
+            e.published_at,
+            l.created_at AS linked_at,
+            l.match_score,
+            l.matched_terms,
+            e.sentiment,
+            e.sentiment_source = 'sender' AS sender_sentiment,
+            l.price_at_event,
+            COALESCE(
+                (SELECT s.yes_mid::float8
+                 FROM orderbook_snapshots s
+                 WHERE s.market_id = l.condition_id
+                   AND s.timestamp >= NOW() - INTERVAL '1 hour'
+                 ORDER BY s.timestamp DESC
+                 LIMIT 1),
+                (SELECT h.close::float8
+                 FROM orderbook_hourly h
+                 WHERE h.market_id = l.condition_id
+                   AND h.bucket >= NOW() - INTERVAL '72 hours'
+                 ORDER BY h.bucket DESC
+                 LIMIT 1)
+            ) AS current_price
+        FROM external_event_market_links l
+        JOIN external_events e ON e.id = l.event_id
+        WHERE e.published_at >= $1
+          AND ($2::text IS NULL OR l.condition_id = $2)
+        ORDER BY e.published_at DESC
+        LIMIT $3
+        "#,
+    )
+    .bind(since)
+    .bind(condition_id)
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| {
+            let impact = event_impact(
+                row.match_score,
+                row.sentiment,
+                now.signed_duration_since(row.published_at),
+                half_life,
+            );
+            let reaction = row
+                .price_at_event
+                .zip(row.current_price)
+                .map(|(from, to)| reaction(impact, from, to));
+            EventImpact {
+                event_id: row.event_id,
+                condition_id: row.condition_id,
+                title: row.title,
+                url: row.url,
+                source: row.source,
+                published_at: row.published_at,
+                linked_at: row.linked_at,
+                match_score: row.match_score,
+                matched_terms: row.matched_terms,
+                sentiment: row.sentiment,
+                sender_sentiment: row.sender_sentiment,
+                impact,
+                price_at_event: row.price_at_event,
+                current_price: row.current_price,
+                reaction,
+            }
+        })
+        .collect())
+}
+
+/// Latest YES mid: the last snapshot within the hour, else the last hourly close.
+pub(crate) async fn latest_yes_price(
+    pool: &PgPool,
+    condition_id: &str,
+) -> sqlx::Result<Option<f64>> {
+    sqlx::query_scalar::<_, Option<f64>>(
+        r#"
+        SELECT COALESCE(
+            (SELECT yes_mid::float8
+             FROM orderbook_snapshots
+             WHERE market_id = $1
+               AND timestamp >= NOW() - INTERVAL '1 hour'
+             ORDER BY timestamp DESC
+             LIMIT 1),
+            (SELECT close::float8
+             FROM orderbook_hourly
+             WHERE market_id = $1
+               AND bucket >= NOW() - INTERVAL '72 hours'
+             ORDER BY bucket DESC
+             LIMIT 1)
+        )
+        "#,
+    )
+    .bind(condition_id)
+    .fetch_one(pool)
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_impact_decays_and_reaction_follows_direction() {
+        let half_life = Duration::minutes(30);
+        assert!((event_impact(0.8, 0.5, Duration::zero(), half_life) - 0.4).abs() < 1e-9);
+        assert!((event_impact(0.8, 0.5, half_life, half_life) - 0.2).abs() < 1e-9);
+        assert!((event_impact(1.0, -1.0, half_life * 2, half_life) + 0.25).abs() < 1e-9);
+        // A future-dated event is not boosted.
+        assert!((event_impact(1.0, 1.0, Duration::minutes(-10), half_life) - 1.0).abs() < 1e-9);
+
+        assert!((reaction(0.4, 0.40, 0.43) - 0.03).abs() < 1e-9);
+        assert!((reaction(-0.4, 0.40, 0.43) + 0.03).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,243 @@
+//! Event ingestion: polling sources, storing events and linking them to markets.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use sqlx::PgPool;
+use std::path::PathBuf;
+use std::time;
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::impact::latest_yes_price;
+use super::matcher::{EventMatch, MarketMatcher, MarketMatcherCache};
+use super::sentiment::lexicon_sentiment;
+use super::source::{EventSource, ExternalEvent, FeedSource, FileDropSource};
+
+/// Configuration for event ingestion and the webhook.
+#[derive(Debug, Clone)]
+pub struct EventIngestConfig {
+    /// Whether the polling task runs. The webhook only needs a secret.
+    pub enabled: bool,
+    /// RSS/Atom feed URLs or local file paths.
+    pub feeds: Vec<String>,
+    /// Directory swept for dropped event files.
+    pub drop_dir: Option<PathBuf>,
+    /// Polling interval in seconds.
+    pub interval_secs: u64,
+    /// Feed items published longer ago than this are ignored.
+    pub max_age_hours: i64,
+    /// Minimum keyword/tag score for a market link.
+    pub min_match_score: f64,
+    /// Most markets one event is linked to.
+    pub max_links_per_event: usize,
+    /// Half-life of an event's impact, for the events API.
+    pub impact_half_life_mins: i64,
+    /// Shared secret the webhook expects in `X-Event-Secret`; the webhook is
+    /// off without one.
+    pub webhook_secret: Option<String>,
+}
+
+impl EventIngestConfig {
+    pub fn from_env() -> Self {
+        Self {
+            enabled: polymarket_core::settings::get("EVENT_INGEST_ENABLED"),
+            feeds: polymarket_core::settings::get_opt("EVENT_INGEST_FEEDS").unwrap_or_default(),
+            drop_dir: polymarket_core::settings::get_opt::<String>("EVENT_INGEST_DROP_DIR")
+                .map(PathBuf::from),
+            interval_secs: polymarket_core::settings::get("EVENT_INGEST_INTERVAL_SECS"),
+            max_age_hours: polymarket_core::settings::get("EVENT_INGEST_MAX_AGE_HOURS"),
+            min_match_score: polymarket_core::settings::get("EVENT_INGEST_MIN_MATCH_SCORE"),
+            max_links_per_event: polymarket_core::settings::get("EVENT_INGEST_MAX_LINKS"),
+            impact_half_life_mins: polymarket_core::settings::get("EVENT_IMPACT_HALF_LIFE_MINS"),
+            webhook_secret: polymarket_core::settings::get_opt("EVENT_WEBHOOK_SECRET"),
+        }
+    }
+
+    /// The configured feeds and drop directory as sources.
+    pub fn sources(&self, http: reqwest::Client) -> Vec<Box<dyn EventSource>> {
+        let mut sources: Vec<Box<dyn EventSource>> = self
+            .feeds
+            .iter()
+            .map(|feed| {
+                Box::new(FeedSource::new(feed.clone(), http.clone())) as Box<dyn EventSource>
+            })
+            .collect();
+        if let Some(dir) = &self.drop_dir {
+            sources.push(Box::new(FileDropSource::new(dir.clone())));
+        }
+        sources
+    }
+
+    /// Load the market matcher with this config's thresholds.
+    pub async fn matcher(&self, pool: &PgPool) -> sqlx::Result<MarketMatcher> {
+        MarketMatcher::load(pool, self.min_match_score, self.max_links_per_event).await
+    }
+
+    /// A matcher cache with this config's thresholds, reloaded once per
+    /// ingestion interval.
+    pub fn matcher_cache(&self) -> MarketMatcherCache {
+        MarketMatcherCache::new(
+            self.min_match_score,
+            self.max_links_per_event,
+            std::time::Duration::from_secs(self.interval_secs),
+        )
+    }
+}
+
+/// A newly stored event and the markets it was linked to.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct IngestedEvent {
+    pub event_id: Uuid,
+    pub title: String,
+    pub sentiment: f64,
+    /// Whether the sender supplied the sentiment rather than the lexicon.
+    pub sender_sentiment: bool,
+    pub links: Vec<EventMatch>,
+}
+
+/// Store events not seen before and link each to its matching markets,
+/// recording the market's current YES price with the link.
+pub async fn ingest_events(
+    pool: &PgPool,
+    matcher: &MarketMatcher,
+    events: Vec<ExternalEvent>,
+) -> sqlx::Result<Vec<IngestedEvent>> {
+    let mut ingested = Vec::new();
+    for event in events {
+        let sender_sentiment = event.sentiment.is_some();
+        let sentiment = event
+            .sentiment
+            .unwrap_or_else(|| lexicon_sentiment(&event.text()));
+        let event_id: Option<Uuid> = sqlx::query_scalar(
+            r#"
+            INSERT INTO external_events
+                (source, source_kind, external_id, title, summary, url, tags, sentiment,
+                 sentiment_source, published_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            ON CONFLICT (source, external_id) DO NOTHING
+            RETURNING id
+            "#,
+        )
+        .bind(&event.source)
+        .bind(event.source_kind.as_str())
+        .bind(&event.external_id)
+        .bind(&event.title)
+        .bind(&event.summary)
+        .bind(&event.url)
+        .bind(&event.tags)
+        .bind(sentiment)
+        .bind(if sender_sentiment {
+            "sender"
+        } else {
+            "lexicon"
+        })
+        .bind(event.published_at)
+        .fetch_optional(pool)
+        .await?;
+        let Some(event_id) = event_id else {
+            continue;
+        };
+
+        let links = matcher.match_event(&event);
+        for link in &links {
+            let price = latest_yes_price(pool, &link.condition_id).await?;
+            sqlx::query(
+                r#"
+                INSERT INTO external_event_market_links
+                    (event_id, condition_id, match_score, matched_terms, price_at_event)
+                VALUES ($1, $2, $3, $4, $5)
+                ON CONFLICT (event_id, condition_id) DO NOTHING
+                "#,
+            )
+            .bind(event_id)
+            .bind(&link.condition_id)
+            .bind(link.score)
+            .bind(&link.matched_terms)
+            .bind(price)
+            .execute(pool)
+            .await?;
+        }
+        debug!(
+            event_id = %event_id,
+            source = %event.source,
+            title = %event.title,
+            links = links.len(),
+            "External event stored"
+        );
+        ingested.push(IngestedEvent {
+            event_id,
+            title: event.title,
+            sentiment,
+            sender_sentiment,
+            links,
+        });
+    }
+    Ok(ingested)
+}
+
+/// Spawn the polling task. Does nothing unless enabled with at least one
+/// feed or a drop directory.
+pub fn spawn_event_ingestion(config: EventIngestConfig, pool: PgPool) -> Option<JoinHandle<()>> {
+    if !config.enabled {
+        info!("Event ingestion disabled (EVENT_INGEST_ENABLED != true)");
+        return None;
+    }
+    let sources = config.sources(reqwest::Client::new());
+    if sources.is_empty() {
+        warn!("Event ingestion enabled but no EVENT_INGEST_FEEDS or EVENT_INGEST_DROP_DIR set");
+        return None;
+    }
+    info!(
+        sources = sources.len(),
+        interval_secs = config.interval_secs,
+        "Spawning event ingestion"
+    );
+    Some(tokio::spawn(async move {
+        let mut interval = tokio::time::interval(time::Duration::from_secs(config.interval_secs));
+        loop {
+            interval.tick().await;
+            run_cycle(&config, &sources, &pool, Utc::now()).await;
+        }
+    }))
+}
+
+async fn run_cycle(
+    config: &EventIngestConfig,
+    sources: &[Box<dyn EventSource>],
+    pool: &PgPool,
+    now: DateTime<Utc>,
+) {
+    let cutoff = now - Duration::hours(config.max_age_hours);
+    let mut events = Vec::new();
+    for source in sources {
+        match source.fetch(now).await {
+            Ok(fetched) => events.extend(fetched.into_iter().filter(|e| e.published_at >= cutoff)),
+            Err(e) => warn!(source = source.name(), error = %e, "Event source fetch failed"),
+        }
+    }
+    if events.is_empty() {
+        return;
+    }
+
+    let matcher = match config.matcher(pool).await {
+        Ok(matcher) => matcher,
+        Err(e) => {
+            warn!(error = %e, "Event ingestion: failed to load markets");
+            return;
+        }
+    };
+    let received = events.len();
+    match ingest_events(pool, &matcher, events).await {
+        Ok(stored) if !stored.is_empty() => info!(
+            received,
+            stored = stored.len(),
+            links = stored.iter().map(|e| e.links.len()).sum::<usize>(),
+            markets = matcher.market_count(),
+            "External events ingested"
+        ),
+        Ok(_) => debug!(received, "Event ingestion: nothing new"),
+        Err(e) => warn!(error = %e, "Event ingestion failed"),
+    }
+}
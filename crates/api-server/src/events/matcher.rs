@@ -0,0 +1,358 @@
+//! Linking events to markets by keyword and tag overlap.
+//!
+//! A market's keywords are the content words of its question. An event
+//! matches a market when its title and summary mention at least two of them
+//! (or all of them, for one-word questions); the score is the share mentioned,
+//! plus a bonus when one of the market's tags or its category appears among
+//! the event's tags or words. Markets named explicitly by the sender are
+//! linked with a score of 1.
+
+use serde::Serialize;
+use sqlx::PgPool;
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+use utoipa::ToSchema;
+
+use super::source::ExternalEvent;
+
+/// Minimum question keywords an event must mention.
+const MIN_KEYWORD_HITS: usize = 2;
+/// Added to the keyword score when a market tag matches.
+const TAG_BONUS: f64 = 0.25;
+
+const STOPWORDS: &[&str] = &[
+    "about", "above", "after", "again", "all", "and", "any", "are", "be", "been", "before",
+    "being", "below", "between", "but", "by", "can", "could", "did", "does", "during", "end",
+    "for", "from", "had", "has", "have", "how", "into", "its", "least", "less", "more", "most",
+    "not", "off", "once", "out", "over", "per", "than", "that", "the", "their", "then", "there",
+    "this", "under", "until", "via", "was", "were", "what", "when", "which", "who", "why", "will",
+    "with", "would", "yes",
+];
+
+/// One event-to-market link.
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct EventMatch {
+    pub condition_id: String,
+    /// Share of the question's keywords mentioned, plus the tag bonus, in `[0, 1]`.
+    pub score: f64,
+    /// Keywords and tags the event mentioned.
+    pub matched_terms: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+struct MarketTerms {
+    condition_id: String,
+    keywords: BTreeSet<String>,
+    /// Lowercased tags and category.
+    tags: Vec<String>,
+}
+
+/// Keyword and tag index over the active markets.
+#[derive(Debug, Clone)]
+pub struct MarketMatcher {
+    markets: Vec<MarketTerms>,
+    min_score: f64,
+    max_links: usize,
+}
+
+#[derive(sqlx::FromRow)]
+struct MarketRow {
+    condition_id: String,
+    question: String,
+    category: Option<String>,
+    tags: Option<Vec<String>>,
+}
+
+impl MarketMatcher {
+    /// An empty matcher keeping links scoring at least `min_score`, at most
+    /// `max_links` per event.
+    pub fn new(min_score: f64, max_links: usize) -> Self {
+        Self {
+            markets: Vec::new(),
+            min_score,
+            max_links,
+        }
+    }
+
+    /// Index every active, unexpired market in `market_metadata`.
+    pub async fn load(pool: &PgPool, min_score: f64, max_links: usize) -> sqlx::Result<Self> {
+        let rows = sqlx::query_as::<_, MarketRow>(
+            r#"
+            SELECT condition_id, question, category, tags
+            FROM market_metadata
+            WHERE active = true
+              AND (end_date IS NULL OR end_date > NOW())
+            "#,
+        )
+        .fetch_all(pool)
+        .await?;
+
+        let mut matcher = Self::new(min_score, max_links);
+        for row in rows {
+            matcher.add_market(
+                row.condition_id,
+                &row.question,
+                row.category.as_deref(),
+                &row.tags.unwrap_or_default(),
+            );
+        }
+        Ok(matcher)
+    }
+
+    pub fn add_market(
+        &mut self,
+        condition_id: impl Into<String>,
+        question: &str,
+        category: Option<&str>,
+        tags: &[String],
+    ) {
+        let keywords = keywords(question);
+        if keywords.is_empty() {
+            return;
+        }
+        let tags = tags
+            .iter()
+            .map(String::as_str)
+            .chain(category)
+            .map(|t| t.trim().to_lowercase())
+            .filter(|t| !t.is_empty())
+            .collect();
+        self.markets.push(MarketTerms {
+            condition_id: condition_id.into(),
+            keywords,
+            tags,
+        });
+    }
+
+    pub fn market_count(&self) -> usize {
+        self.markets.len()
+    }
+
+    /// Markets the event concerns, best first.
+    pub fn match_event(&self, event: &ExternalEvent) -> Vec<EventMatch> {
+        let mut matches: Vec<EventMatch> = event
+            .condition_ids
+            .iter()
+            .map(|id| EventMatch {
+                condition_id: id.clone(),
+                score: 1.0,
+                matched_terms: Vec::new(),
+            })
+            .collect();
+
+        let text = event.text();
+        let event_keywords = keywords(&text);
+        let event_words: BTreeSet<String> = words(&text).map(|w| stem(&w)).collect();
+        let event_tags: BTreeSet<String> =
+            event.tags.iter().map(|t| t.trim().to_lowercase()).collect();
+
+        let mut scored: Vec<EventMatch> = self
+            .markets
+            .iter()
+            .filter(|m| !event.condition_ids.contains(&m.condition_id))
+            .filter_map(|market| {
+                let hits: Vec<String> = market
+                    .keywords
+                    .intersection(&event_keywords)
+                    .cloned()
+                    .collect();
+                if hits.len() < MIN_KEYWORD_HITS.min(market.keywords.len()) {
+                    return None;
+                }
+                let tag_hits: Vec<String> = market
+                    .tags
+                    .iter()
+                    .filter(|tag| {
+                        event_tags.contains(*tag)
+                            || words(tag).all(|w| event_words.contains(&stem(&w)))
+                    })
+                    .cloned()
+                    .collect();
+                let coverage = hits.len() as f64 / market.keywords.len() as f64;
+                let bonus = if tag_hits.is_empty() { 0.0 } else { TAG_BONUS };
+                let score = (coverage + bonus).min(1.0);
+                (score >= self.min_score).then(|| EventMatch {
+                    condition_id: market.condition_id.clone(),
+                    score,
+                    matched_terms: hits.into_iter().chain(tag_hits).collect(),
+                })
+            })
+            .collect();
+        scored.sort_by(|a, b| b.score.total_cmp(&a.score));
+
+        matches.extend(scored);
+        matches.truncate(self.max_links.max(event.condition_ids.len()));
+        matches
+    }
+}
+
+/// A loaded [`MarketMatcher`], reused until it is `ttl` old so the webhook
+/// does not re-read every market per event.
+#[derive(Debug)]
+pub struct MarketMatcherCache {
+    min_score: f64,
+    max_links: usize,
+    ttl: Duration,
+    cached: Mutex<Option<(Instant, Arc<MarketMatcher>)>>,
+}
+
+impl MarketMatcherCache {
+    pub fn new(min_score: f64, max_links: usize, ttl: Duration) -> Self {
+        Self {
+            min_score,
+            max_links,
+            ttl,
+            cached: Mutex::new(None),
+        }
+    }
+
+    /// The cached matcher, reloaded from `market_metadata` once stale.
+    pub async fn get(&self, pool: &PgPool) -> sqlx::Result<Arc<MarketMatcher>> {
+        let mut cached = self.cached.lock().await;
+        if let Some((loaded_at, matcher)) = cached.as_ref() {
+            if loaded_at.elapsed() < self.ttl {
+                return Ok(matcher.clone());
+            }
+        }
+        let matcher = Arc::new(MarketMatcher::load(pool, self.min_score, self.max_links).await?);
+        *cached = Some((Instant::now(), matcher.clone()));
+        Ok(matcher)
+    }
+}
+
+/// Lowercased alphanumeric words.
+pub(crate) fn words(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(str::to_lowercase)
+}
+
+/// Stemmed content words: three characters or more, not a stopword, not a
+/// bare number.
+fn keywords(text: &str) -> BTreeSet<String> {
+    words(text)
+        .filter(|w| w.chars().count() >= 3)
+        .filter(|w| !STOPWORDS.contains(&w.as_str()))
+        .filter(|w| !w.chars().all(|c| c.is_ascii_digit()))
+        .map(|w| stem(&w))
+        .collect()
+}
+
+/// Fold plurals so "rates" matches "rate".
+fn stem(word: &str) -> String {
+    if let Some(base) = word.strip_suffix("ies").filter(|b| b.len() >= 2) {
+        return format!("{base}y");
+    }
+    match word.strip_suffix('s') {
+        Some(base) if base.len() >= 3 && !base.ends_with('s') => base.to_string(),
+        _ => word.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::source::EventSourceKind;
+    use chrono::Utc;
+
+    fn event(title: &str, tags: &[&str], condition_ids: &[&str]) -> ExternalEvent {
+        ExternalEvent {
+            source: "test".to_string(),
+            source_kind: EventSourceKind::Webhook,
+            external_id: "1".to_string(),
+            title: title.to_string(),
+            summary: None,
+            url: None,
+            tags: tags.iter().map(|t| t.to_string()).collect(),
+            published_at: Utc::now(),
+            sentiment: None,
+            condition_ids: condition_ids.iter().map(|c| c.to_string()).collect(),
+        }
+    }
+
+    fn matcher() -> MarketMatcher {
+        let mut matcher = MarketMatcher::new(0.5, 5);
+        matcher.add_market(
+            "0xfed",
+            "Will the Fed cut interest rates in December 2026?",
+            Some("Economics"),
+            &["Fed".to_string()],
+        );
+        matcher.add_market(
+            "0xshutdown",
+            "Will the US government shut down before November 1?",
+            Some("Politics"),
+            &["US Government".to_string()],
+        );
+        matcher.add_market(
+            "0xlaunch",
+            "Will the orbital launch happen by October 31?",
+            None,
+            &[],
+        );
+        matcher
+    }
+
+    #[test]
+    fn test_keyword_coverage_and_tag_bonus() {
+        let matches = matcher().match_event(&event(
+            "Fed signals a December rate cut as inflation cools",
+            &[],
+            &[],
+        ));
+        assert_eq!(matches.len(), 1);
+        let fed = &matches[0];
+        assert_eq!(fed.condition_id, "0xfed");
+        // fed, cut, rate, december of fed/cut/interest/rate/december, plus the "fed" tag.
+        assert!((fed.score - 1.0).abs() < 1e-9);
+        assert!(fed.matched_terms.contains(&"rate".to_string()));
+        assert!(fed.matched_terms.contains(&"fed".to_string()));
+
+        let tagged = matcher().match_event(&event(
+            "Government could shut as talks stall",
+            &["US Government"],
+            &[],
+        ));
+        assert_eq!(tagged[0].condition_id, "0xshutdown");
+        assert!((tagged[0].score - (2.0 / 4.0 + TAG_BONUS)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_weak_and_tag_only_mentions_do_not_link() {
+        // One keyword is not enough.
+        assert!(matcher()
+            .match_event(&event("Launch party tonight", &[], &[]))
+            .is_empty());
+        // Nor is a tag alone.
+        assert!(matcher()
+            .match_event(&event("Markets quiet", &["politics"], &[]))
+            .is_empty());
+        // Below the minimum score: 2 of 5 keywords.
+        assert!(matcher()
+            .match_event(&event("Interest rates rise", &[], &[]))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_explicit_markets_link_first() {
+        let matches =
+            matcher().match_event(&event("Orbital launch slips a week", &[], &["0xother"]));
+        assert_eq!(matches[0].condition_id, "0xother");
+        assert_eq!(matches[0].score, 1.0);
+        assert!(matches[0].matched_terms.is_empty());
+        assert_eq!(matches[1].condition_id, "0xlaunch");
+    }
+
+    #[test]
+    fn test_keywords_and_stemming() {
+        let k = keywords("Will the Fed's rates rise by 2026? Yes, 50bps, parties");
+        assert_eq!(
+            k.into_iter().collect::<Vec<_>>(),
+            vec!["50bp", "fed", "party", "rate", "rise"]
+        );
+        assert_eq!(stem("class"), "class");
+        assert_eq!(stem("gas"), "gas");
+    }
+}
@@ -0,0 +1,27 @@
+//! External events (news, announcements) as a signal source.
+//!
+//! Events come in through pluggable [`EventSource`]s — RSS/Atom feeds polled
+//! over HTTP or read from local files, and a drop directory of JSON or feed
+//! files — and through the JSON webhook. Each new event is stored in
+//! `external_events` with a sentiment (the sender's, or a lexicon score) and
+//! linked to markets in `market_metadata` by keyword and tag overlap, with the
+//! market's YES price at the time. From those links comes the
+//! [`EventImpact`] feature, which the event-reaction signal generator trades
+//! when a market has not yet moved on a strongly matched event whose
+//! sentiment the sender supplied.
+
+pub mod feed;
+pub mod impact;
+pub mod ingest;
+pub mod matcher;
+pub mod sentiment;
+pub mod source;
+
+pub use feed::{parse_feed, FeedEntry};
+pub use impact::{event_impact, reaction, recent_impacts, EventImpact};
+pub use ingest::{ingest_events, spawn_event_ingestion, EventIngestConfig, IngestedEvent};
+pub use matcher::{EventMatch, MarketMatcher, MarketMatcherCache};
+pub use sentiment::lexicon_sentiment;
+pub use source::{
+    EventPayload, EventSource, EventSourceKind, ExternalEvent, FeedSource, FileDropSource,
+};
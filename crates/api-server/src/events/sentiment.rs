@@ -0,0 +1,162 @@
+//! Lexicon sentiment for event text.
+//!
+//! Counts outcome words: positive ones ("approves", "wins", "confirmed")
+//! suggest the event makes YES more likely for the markets it mentions,
+//! negative ones ("rejects", "loses", "delayed") suggest NO. A negation in the
+//! two words before a term flips it. This is deliberately crude and blind to
+//! how a market's question is phrased, so lexicon scores are shown but never
+//! traded; senders that know better supply `sentiment` with the event.
+
+use super::matcher::words;
+
+const POSITIVE: &[&str] = &[
+    "agree",
+    "agreed",
+    "agreement",
+    "agrees",
+    "approval",
+    "approve",
+    "approved",
+    "approves",
+    "beat",
+    "beats",
+    "clear",
+    "cleared",
+    "clears",
+    "confirm",
+    "confirmed",
+    "confirms",
+    "elected",
+    "lead",
+    "leads",
+    "pass",
+    "passed",
+    "passes",
+    "rally",
+    "secure",
+    "secured",
+    "secures",
+    "sign",
+    "signed",
+    "signs",
+    "succeed",
+    "succeeded",
+    "succeeds",
+    "success",
+    "surge",
+    "surges",
+    "victory",
+    "win",
+    "wins",
+    "won",
+];
+
+const NEGATIVE: &[&str] = &[
+    "ban",
+    "banned",
+    "block",
+    "blocked",
+    "blocks",
+    "cancel",
+    "canceled",
+    "cancelled",
+    "cancels",
+    "collapse",
+    "collapses",
+    "defeat",
+    "defeated",
+    "delay",
+    "delayed",
+    "delays",
+    "deny",
+    "denied",
+    "denies",
+    "fail",
+    "failed",
+    "fails",
+    "halt",
+    "halted",
+    "lose",
+    "loses",
+    "lost",
+    "miss",
+    "missed",
+    "misses",
+    "postpone",
+    "postponed",
+    "postpones",
+    "reject",
+    "rejected",
+    "rejects",
+    "scrap",
+    "scrapped",
+    "suspend",
+    "suspended",
+    "suspends",
+    "trail",
+    "trails",
+    "veto",
+    "vetoed",
+    "withdraw",
+    "withdraws",
+    "withdrew",
+];
+
+/// `t` is what is left of "n't" once text is split into words.
+const NEGATIONS: &[&str] = &["cannot", "never", "no", "not", "t", "without"];
+
+/// Sentiment of `text` in `[-1, 1]`; one unopposed term scores ±0.5, two or
+/// more ±1.
+pub fn lexicon_sentiment(text: &str) -> f64 {
+    let words: Vec<String> = words(text).collect();
+    let (mut positive, mut negative) = (0.0, 0.0);
+    for (i, word) in words.iter().enumerate() {
+        // The "won" of "won't".
+        if words.get(i + 1).is_some_and(|next| next == "t") {
+            continue;
+        }
+        let polarity = if POSITIVE.contains(&word.as_str()) {
+            1
+        } else if NEGATIVE.contains(&word.as_str()) {
+            -1
+        } else {
+            continue;
+        };
+        let negated = words[i.saturating_sub(2)..i]
+            .iter()
+            .any(|w| NEGATIONS.contains(&w.as_str()));
+        if (polarity > 0) != negated {
+            positive += 1.0;
+        } else {
+            negative += 1.0;
+        }
+    }
+    let total: f64 = positive + negative;
+    (positive - negative) / total.max(2.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lexicon_sentiment() {
+        assert_eq!(lexicon_sentiment("Senate approves stopgap bill"), 0.5);
+        assert_eq!(lexicon_sentiment("Bill passes and is signed into law"), 1.0);
+        assert_eq!(
+            lexicon_sentiment("Launch delayed after engine anomaly"),
+            -0.5
+        );
+        assert_eq!(lexicon_sentiment("Senate won't pass the bill"), -0.5);
+        assert_eq!(lexicon_sentiment("Bill passes, then is vetoed"), 0.0);
+        assert_eq!(
+            lexicon_sentiment("Court rejects appeal; merger blocked"),
+            -1.0
+        );
+        assert_eq!(
+            lexicon_sentiment("Regulator did not approve the filing"),
+            -0.5
+        );
+        assert_eq!(lexicon_sentiment("Quiet day in markets"), 0.0);
+    }
+}
@@ -0,0 +1,366 @@
+//! Where external events come from.
+//!
+//! Every source yields [`ExternalEvent`]s: feeds are polled over HTTP or read
+//! from a local path (so a fixture file works as a feed), drop directories are
+//! swept for JSON and feed files, and the webhook handler builds events from
+//! the same [`EventPayload`] shape the JSON drops use.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+use tracing::warn;
+use utoipa::ToSchema;
+
+use super::feed::{parse_feed, FeedEntry};
+
+/// How an event reached us; stored as `external_events.source_kind`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventSourceKind {
+    Feed,
+    FileDrop,
+    Webhook,
+}
+
+impl EventSourceKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Feed => "feed",
+            Self::FileDrop => "file_drop",
+            Self::Webhook => "webhook",
+        }
+    }
+}
+
+/// A timestamped real-world event, before it is stored and linked.
+#[derive(Debug, Clone)]
+pub struct ExternalEvent {
+    /// Feed location, drop directory or webhook sender.
+    pub source: String,
+    pub source_kind: EventSourceKind,
+    /// The sender's id; events are deduplicated per source on it.
+    pub external_id: String,
+    pub title: String,
+    pub summary: Option<String>,
+    pub url: Option<String>,
+    pub tags: Vec<String>,
+    pub published_at: DateTime<Utc>,
+    /// YES-leaning sentiment in `[-1, 1]` supplied by the sender; scored
+    /// from the text when absent.
+    pub sentiment: Option<f64>,
+    /// Markets the sender says the event concerns.
+    pub condition_ids: Vec<String>,
+}
+
+impl ExternalEvent {
+    /// Title and summary, for matching and sentiment.
+    pub fn text(&self) -> String {
+        match &self.summary {
+            Some(summary) => format!("{} {}", self.title, summary),
+            None => self.title.clone(),
+        }
+    }
+
+    fn from_feed_entry(
+        entry: FeedEntry,
+        source: &str,
+        source_kind: EventSourceKind,
+        received_at: DateTime<Utc>,
+    ) -> Self {
+        let external_id = entry
+            .id
+            .clone()
+            .or_else(|| entry.link.clone())
+            .unwrap_or_else(|| fallback_id(&entry.title, entry.published_at));
+        Self {
+            source: source.to_string(),
+            source_kind,
+            external_id,
+            title: entry.title,
+            summary: entry.summary,
+            url: entry.link,
+            tags: entry.categories,
+            published_at: entry.published_at.unwrap_or(received_at),
+            sentiment: None,
+            condition_ids: Vec::new(),
+        }
+    }
+}
+
+/// JSON event accepted by the webhook and by `.json` file drops.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct EventPayload {
+    /// Sender's id for the event; derived from the title and time when absent.
+    pub id: Option<String>,
+    /// Sender name events are deduplicated under (default: `webhook`, or the
+    /// drop directory).
+    pub source: Option<String>,
+    pub title: String,
+    pub summary: Option<String>,
+    pub url: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Defaults to the time of receipt.
+    pub published_at: Option<DateTime<Utc>>,
+    /// YES-leaning sentiment in `[-1, 1]`; scored from the text when absent.
+    pub sentiment: Option<f64>,
+    /// Markets the event concerns, linked without keyword matching.
+    #[serde(default)]
+    pub condition_ids: Vec<String>,
+}
+
+impl EventPayload {
+    pub fn into_event(
+        self,
+        default_source: &str,
+        source_kind: EventSourceKind,
+        received_at: DateTime<Utc>,
+    ) -> ExternalEvent {
+        let external_id = self
+            .id
+            .unwrap_or_else(|| fallback_id(&self.title, self.published_at));
+        ExternalEvent {
+            source: self.source.unwrap_or_else(|| default_source.to_string()),
+            source_kind,
+            external_id,
+            title: self.title.trim().to_string(),
+            summary: self.summary,
+            url: self.url,
+            tags: self.tags,
+            published_at: self.published_at.unwrap_or(received_at),
+            sentiment: self.sentiment.map(|s| s.clamp(-1.0, 1.0)),
+            condition_ids: self.condition_ids,
+        }
+    }
+}
+
+/// Stable id for an event whose sender gave none.
+fn fallback_id(title: &str, published_at: Option<DateTime<Utc>>) -> String {
+    let published = published_at.map(|p| p.to_rfc3339()).unwrap_or_default();
+    let digest = Sha256::digest(format!("{title}|{published}").as_bytes());
+    hex::encode(&digest[..16])
+}
+
+/// A pollable supplier of external events.
+#[async_trait]
+pub trait EventSource: Send + Sync {
+    /// Name used in logs.
+    fn name(&self) -> &str;
+
+    /// Events currently available. Sources may return events already seen;
+    /// storage deduplicates them.
+    async fn fetch(&self, now: DateTime<Utc>) -> anyhow::Result<Vec<ExternalEvent>>;
+}
+
+/// An RSS or Atom feed, fetched over HTTP(S) or read from a local path.
+pub struct FeedSource {
+    location: String,
+    http: reqwest::Client,
+}
+
+impl FeedSource {
+    pub fn new(location: impl Into<String>, http: reqwest::Client) -> Self {
+        Self {
+            location: location.into(),
+            http,
+        }
+    }
+}
+
+#[async_trait]
+impl EventSource for FeedSource {
+    fn name(&self) -> &str {
+        &self.location
+    }
+
+    async fn fetch(&self, now: DateTime<Utc>) -> anyhow::Result<Vec<ExternalEvent>> {
+        let body = if self.location.starts_with("http://") || self.location.starts_with("https://")
+        {
+            self.http
+                .get(&self.location)
+                .send()
+                .await?
+                .error_for_status()?
+                .text()
+                .await?
+        } else {
+            tokio::fs::read_to_string(&self.location).await?
+        };
+        Ok(parse_feed(&body)?
+            .into_iter()
+            .map(|entry| {
+                ExternalEvent::from_feed_entry(entry, &self.location, EventSourceKind::Feed, now)
+            })
+            .collect())
+    }
+}
+
+/// A directory other processes drop event files into.
+///
+/// `.json` files hold one [`EventPayload`] or an array of them; `.xml`,
+/// `.rss` and `.atom` files hold a feed. Each file is read once and then
+/// moved to `processed/` under the directory, or to `failed/` if it does not
+/// parse. Other files are left alone.
+pub struct FileDropSource {
+    dir: PathBuf,
+    name: String,
+}
+
+impl FileDropSource {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        let dir = dir.into();
+        Self {
+            name: dir.display().to_string(),
+            dir,
+        }
+    }
+}
+
+#[async_trait]
+impl EventSource for FileDropSource {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn fetch(&self, now: DateTime<Utc>) -> anyhow::Result<Vec<ExternalEvent>> {
+        let mut files = Vec::new();
+        let mut entries = tokio::fs::read_dir(&self.dir).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            if entry.file_type().await?.is_file() {
+                files.push(entry.path());
+            }
+        }
+        files.sort();
+
+        let mut events = Vec::new();
+        for path in files {
+            let Some(ext) = path
+                .extension()
+                .and_then(|e| e.to_str())
+                .map(str::to_ascii_lowercase)
+            else {
+                continue;
+            };
+            if !matches!(ext.as_str(), "json" | "xml" | "rss" | "atom") {
+                continue;
+            }
+            let parsed = match tokio::fs::read_to_string(&path).await {
+                Ok(body) => parse_drop(&body, &ext, &self.name, now),
+                Err(e) => Err(e.into()),
+            };
+            let destination = match parsed {
+                Ok(mut dropped) => {
+                    events.append(&mut dropped);
+                    "processed"
+                }
+                Err(e) => {
+                    warn!(file = %path.display(), error = %e, "Unreadable event drop file");
+                    "failed"
+                }
+            };
+            move_into(&self.dir.join(destination), &path).await?;
+        }
+        Ok(events)
+    }
+}
+
+fn parse_drop(
+    body: &str,
+    ext: &str,
+    source: &str,
+    now: DateTime<Utc>,
+) -> anyhow::Result<Vec<ExternalEvent>> {
+    if ext != "json" {
+        return Ok(parse_feed(body)?
+            .into_iter()
+            .map(|entry| {
+                ExternalEvent::from_feed_entry(entry, source, EventSourceKind::FileDrop, now)
+            })
+            .collect());
+    }
+    let payloads: Vec<EventPayload> = match serde_json::from_str(body)? {
+        serde_json::Value::Array(items) => items
+            .into_iter()
+            .map(serde_json::from_value)
+            .collect::<Result<_, _>>()?,
+        single => vec![serde_json::from_value(single)?],
+    };
+    Ok(payloads
+        .into_iter()
+        .map(|payload| payload.into_event(source, EventSourceKind::FileDrop, now))
+        .collect())
+}
+
+async fn move_into(dir: &Path, file: &Path) -> std::io::Result<()> {
+    tokio::fs::create_dir_all(dir).await?;
+    let name = file.file_name().unwrap_or_default();
+    tokio::fs::rename(file, dir.join(name)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture(name: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures/events")
+            .join(name)
+    }
+
+    #[tokio::test]
+    async fn test_feed_source_reads_local_fixture() {
+        let location = fixture("wire.rss").display().to_string();
+        let source = FeedSource::new(location.clone(), reqwest::Client::new());
+        let now = Utc::now();
+        let events = source.fetch(now).await.unwrap();
+
+        assert_eq!(events.len(), 3);
+        assert!(events.iter().all(|e| e.source == location));
+        assert_eq!(events[0].external_id, "wire-2026-10-18-0001");
+        assert_eq!(events[0].tags, vec!["Politics", "US Government"]);
+        // No guid: the link identifies the item. No date: time of receipt.
+        assert_eq!(
+            events[2].external_id,
+            "https://wire.example/2026/10/18/powell"
+        );
+        assert_eq!(events[2].published_at, now);
+    }
+
+    #[tokio::test]
+    async fn test_file_drop_sweeps_and_moves_files() {
+        let dir = std::env::temp_dir().join(format!("event-drop-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::copy(fixture("drop.json"), dir.join("drop.json")).unwrap();
+        std::fs::copy(fixture("newsroom.atom"), dir.join("newsroom.atom")).unwrap();
+        std::fs::write(dir.join("broken.json"), "{ not json").unwrap();
+        std::fs::write(dir.join("notes.txt"), "ignored").unwrap();
+
+        let source = FileDropSource::new(&dir);
+        let events = source.fetch(Utc::now()).await.unwrap();
+        assert_eq!(events.len(), 4);
+        assert!(events
+            .iter()
+            .all(|e| e.source_kind == EventSourceKind::FileDrop));
+
+        let merger = events
+            .iter()
+            .find(|e| e.external_id == "desk-0042")
+            .unwrap();
+        assert_eq!(merger.condition_ids, vec!["0xmerger"]);
+        let nomination = events
+            .iter()
+            .find(|e| e.title.starts_with("Candidate wins"))
+            .unwrap();
+        assert_eq!(nomination.sentiment, Some(0.9));
+        assert_eq!(nomination.external_id.len(), 32);
+
+        assert!(dir.join("processed/drop.json").exists());
+        assert!(dir.join("processed/newsroom.atom").exists());
+        assert!(dir.join("failed/broken.json").exists());
+        assert!(dir.join("notes.txt").exists());
+        // A second sweep finds nothing new.
+        assert!(source.fetch(Utc::now()).await.unwrap().is_empty());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
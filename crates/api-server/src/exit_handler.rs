@@ -5,10 +5,17 @@
 //! - **ExitOnCorrection**: sell YES + NO when arb-monitor marks position `ExitReady`
 //! - **HoldToResolution**: wait for market to resolve ($1 payout)
 //!
+//! One-legged arbs queued for exit first go through the unwind policy
+//! (`unwind_policy.rs`), which may complete the missing leg or keep holding
+//! instead of selling the filled leg. Full pairs go through the exit optimizer
+//! (`exit_optimizer.rs`) when it is enabled, which may rest passive sell orders
+//! or hold to resolution instead of selling into the bids.
+//!
 //! Shares the `active_markets` dedup set with `ArbAutoExecutor` via `Arc<RwLock<>>`
 //! so closed positions unblock their markets for future trades.
 
-use chrono::Utc;
+use chrono::{DateTime, Utc};
+use polymarket_core::alerting::{Alert, AlertRouter, Severity};
 use polymarket_core::api::ClobClient;
 use polymarket_core::db::positions::{PositionRepository, SOURCE_ARBITRAGE, SOURCE_RECOMMENDATION};
 use polymarket_core::error::Error as PolymarketError;
@@ -20,12 +27,21 @@ use sqlx::PgPool;
 use std::collections::{HashMap, HashSet};
 use std::sync::atomic::{AtomicI64, Ordering};
 use std::sync::Arc;
-use tokio::sync::{broadcast, RwLock};
+use tokio::sync::{broadcast, Mutex, RwLock};
 use tracing::{debug, error, info, warn};
 use trading_engine::OrderExecutor;
 
+use crate::exit_optimizer::{
+    self, ExitMarketSource, ExitMarketView, ExitOptimizer, ExitOptimizerConfig, ExitPolicy,
+    PairedExit, PassiveQuote,
+};
 use crate::position_service::{CloseMethod, EventContext, Leg};
+use crate::signals::{ExitContext, SignalGeneratorRegistry};
 use crate::trade_events::TradeEventRecorder;
+use crate::unwind_policy::{
+    StrandedLeg, UnwindAction, UnwindMarketSource, UnwindMarketView, UnwindPolicy,
+    UnwindPolicyConfig,
+};
 use crate::wallet_inventory::recover_wallet_orphan_inventory;
 use crate::websocket::{SignalType, SignalUpdate};
 
@@ -52,6 +68,10 @@ pub struct ExitHandlerConfig {
     pub quant_max_hold_hours: i64,
     /// Minimum cooldown before retrying an ExitFailed position.
     pub failed_exit_retry_backoff_secs: u64,
+    /// Policy for one-legged arb positions queued for exit.
+    pub unwind_policy: UnwindPolicyConfig,
+    /// Exit timing for full pairs queued for exit.
+    pub exit_optimizer: ExitOptimizerConfig,
 }
 
 impl Default for ExitHandlerConfig {
@@ -64,6 +84,8 @@ impl Default for ExitHandlerConfig {
             quant_stop_loss_pct: Decimal::new(10, 2),
             quant_max_hold_hours: 24,
             failed_exit_retry_backoff_secs: 300,
+            unwind_policy: UnwindPolicyConfig::default(),
+            exit_optimizer: ExitOptimizerConfig::default(),
         }
     }
 }
@@ -72,33 +94,17 @@ impl ExitHandlerConfig {
     /// Create config from environment variables.
     pub fn from_env() -> Self {
         Self {
-            enabled: std::env::var("EXIT_HANDLER_ENABLED")
-                .map(|v| v == "true")
-                .unwrap_or(false),
-            exit_poll_interval_secs: std::env::var("EXIT_POLL_INTERVAL_SECS")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(30),
-            resolution_check_secs: std::env::var("EXIT_RESOLUTION_CHECK_SECS")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(300),
-            quant_take_profit_pct: std::env::var("QUANT_TAKE_PROFIT_PCT")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(Decimal::new(15, 2)),
-            quant_stop_loss_pct: std::env::var("QUANT_STOP_LOSS_PCT")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(Decimal::new(10, 2)),
-            quant_max_hold_hours: std::env::var("QUANT_MAX_HOLD_HOURS")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(24),
-            failed_exit_retry_backoff_secs: std::env::var("EXIT_FAILED_RETRY_BACKOFF_SECS")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(300),
+            enabled: polymarket_core::settings::get("EXIT_HANDLER_ENABLED"),
+            exit_poll_interval_secs: polymarket_core::settings::get("EXIT_POLL_INTERVAL_SECS"),
+            resolution_check_secs: polymarket_core::settings::get("EXIT_RESOLUTION_CHECK_SECS"),
+            quant_take_profit_pct: polymarket_core::settings::get("QUANT_TAKE_PROFIT_PCT"),
+            quant_stop_loss_pct: polymarket_core::settings::get("QUANT_STOP_LOSS_PCT"),
+            quant_max_hold_hours: polymarket_core::settings::get("QUANT_MAX_HOLD_HOURS"),
+            failed_exit_retry_backoff_secs: polymarket_core::settings::get(
+                "EXIT_FAILED_RETRY_BACKOFF_SECS",
+            ),
+            unwind_policy: UnwindPolicyConfig::from_env(),
+            exit_optimizer: ExitOptimizerConfig::from_env(),
         }
     }
 }
@@ -115,6 +121,7 @@ pub struct ExitHandler {
     circuit_breaker: Arc<CircuitBreaker>,
     clob_client: Arc<ClobClient>,
     signal_tx: broadcast::Sender<SignalUpdate>,
+    alert_router: Arc<AlertRouter>,
     token_cache: Arc<OutcomeTokenCache>,
     /// Shared dedup set with ArbAutoExecutor.
     arb_dedup: Arc<RwLock<HashSet<String>>>,
@@ -123,6 +130,47 @@ pub struct ExitHandler {
     position_service: crate::position_service::PositionService,
     /// Heartbeat timestamp (epoch secs) — updated every tick to prove liveness.
     heartbeat: Arc<AtomicI64>,
+    /// Per-position unwind state for one-legged arbs awaiting a decision.
+    unwind_trackers: Mutex<HashMap<uuid::Uuid, UnwindTracker>>,
+    /// Signal generators whose strategy-specific exit rules apply to quant positions.
+    signal_generators: Arc<SignalGeneratorRegistry>,
+    /// Last exit policy recorded per ExitReady pair.
+    exit_policies: Mutex<HashMap<uuid::Uuid, &'static str>>,
+    /// Passive exit orders resting for ExitReady pairs, mirrored to
+    /// `passive_exit_orders` so they survive a restart.
+    passive_exits: Mutex<HashMap<uuid::Uuid, PassiveExit>>,
+}
+
+#[derive(Debug, Default)]
+struct UnwindTracker {
+    completion_attempts: u32,
+    last_action: Option<&'static str>,
+}
+
+/// Passive sell orders resting for one pair until filled or expired.
+#[derive(Debug)]
+struct PassiveExit {
+    expires_at: DateTime<Utc>,
+    legs: Vec<PassiveLeg>,
+}
+
+#[derive(Debug)]
+struct PassiveLeg {
+    leg: Leg,
+    token_id: String,
+    price: Decimal,
+    quantity: Decimal,
+    /// Exchange order id; `None` for paper orders.
+    order_id: Option<String>,
+    filled: Decimal,
+    /// False once the order can no longer fill.
+    open: bool,
+}
+
+impl PassiveLeg {
+    fn remaining(&self) -> Decimal {
+        (self.quantity - self.filled).max(Decimal::ZERO)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -142,11 +190,13 @@ impl ExitHandler {
         circuit_breaker: Arc<CircuitBreaker>,
         clob_client: Arc<ClobClient>,
         signal_tx: broadcast::Sender<SignalUpdate>,
+        alert_router: Arc<AlertRouter>,
         trade_event_tx: broadcast::Sender<crate::trade_events::TradeEventUpdate>,
         pool: PgPool,
         arb_dedup: Arc<RwLock<HashSet<String>>>,
         heartbeat: Arc<AtomicI64>,
         token_cache: Arc<OutcomeTokenCache>,
+        signal_generators: Arc<SignalGeneratorRegistry>,
     ) -> Self {
         let position_service =
             crate::position_service::PositionService::new(pool.clone(), trade_event_tx.clone());
@@ -158,11 +208,16 @@ impl ExitHandler {
             circuit_breaker,
             clob_client: clob_client.clone(),
             signal_tx,
+            alert_router,
             token_cache,
             arb_dedup,
             trade_event_recorder: TradeEventRecorder::new(pool.clone(), trade_event_tx),
             position_service,
             heartbeat,
+            unwind_trackers: Mutex::new(HashMap::new()),
+            signal_generators,
+            exit_policies: Mutex::new(HashMap::new()),
+            passive_exits: Mutex::new(HashMap::new()),
         }
     }
 
@@ -194,6 +249,16 @@ impl ExitHandler {
         // Mark liveness before the initial cache load, which can block on network I/O.
         self.touch_heartbeat();
 
+        // Passive exits resting before a restart are polled and settled as usual.
+        match load_passive_exits(&self.pool).await {
+            Ok(loaded) if !loaded.is_empty() => {
+                info!(count = loaded.len(), "Restored resting passive exits");
+                self.passive_exits.lock().await.extend(loaded);
+            }
+            Ok(_) => {}
+            Err(e) => error!(error = %e, "Failed to restore passive exits"),
+        }
+
         // Token cache is shared with arb_executor — no separate refresh needed.
         // The arb_executor refreshes every cache_refresh_secs (default 300s).
         let cache_size = self.token_cache.len().await;
@@ -230,10 +295,10 @@ impl ExitHandler {
                     if let Err(e) = self.process_failed_exits(&cfg).await {
                         error!(error = %e, "Failed to process failed exits");
                     }
-                    if let Err(e) = self.process_exit_ready().await {
+                    if let Err(e) = self.process_exit_ready(&cfg).await {
                         error!(error = %e, "Failed to process exit-ready positions");
                     }
-                    if let Err(e) = self.process_one_legged_recovery().await {
+                    if let Err(e) = self.process_one_legged_recovery(&cfg).await {
                         error!(error = %e, "Failed to process one-legged recovery");
                     }
                     if let Err(e) = self.process_orphan_inventory_recovery(&cfg).await {
@@ -314,8 +379,40 @@ impl ExitHandler {
     }
 
     /// Process ExitReady positions (ExitOnCorrection strategy).
-    async fn process_exit_ready(&self) -> anyhow::Result<()> {
+    async fn process_exit_ready(&self, cfg: &ExitHandlerConfig) -> anyhow::Result<()> {
         let positions = self.position_repo.get_exit_ready().await?;
+        {
+            let live_ids: HashSet<uuid::Uuid> = positions.iter().map(|p| p.id).collect();
+            self.unwind_trackers
+                .lock()
+                .await
+                .retain(|id, _| live_ids.contains(id));
+            self.exit_policies
+                .lock()
+                .await
+                .retain(|id, _| live_ids.contains(id));
+            // Positions closed elsewhere leave their passive orders behind.
+            let orphaned: Vec<(uuid::Uuid, PassiveExit)> = {
+                let mut passive_exits = self.passive_exits.lock().await;
+                let ids: Vec<uuid::Uuid> = passive_exits
+                    .keys()
+                    .filter(|id| !live_ids.contains(id))
+                    .copied()
+                    .collect();
+                ids.into_iter()
+                    .filter_map(|id| passive_exits.remove(&id).map(|p| (id, p)))
+                    .collect()
+            };
+            for (position_id, mut passive) in orphaned {
+                if self.cancel_passive_orders(&mut passive.legs).await {
+                    self.forget_passive_exit(position_id).await;
+                } else {
+                    // Retried next tick.
+                    self.save_passive_exit(position_id, &passive).await;
+                    self.passive_exits.lock().await.insert(position_id, passive);
+                }
+            }
+        }
         if positions.is_empty() {
             return Ok(());
         }
@@ -324,7 +421,7 @@ impl ExitHandler {
 
         for mut position in positions {
             self.touch_heartbeat();
-            if let Err(e) = self.execute_exit(&mut position).await {
+            if let Err(e) = self.exit_or_unwind(&mut position, cfg).await {
                 warn!(
                     position_id = %position.id,
                     market_id = %position.market_id,
@@ -560,17 +657,30 @@ impl ExitHandler {
             }
         }
 
+        self.finish_market_exit(position, &ctx, "sell_now").await;
+        Ok(())
+    }
+
+    /// Close a position whose exit fills are all recorded and report it.
+    async fn finish_market_exit(
+        &self,
+        position: &mut Position,
+        ctx: &EventContext,
+        realized_via: &str,
+    ) {
+        let market_id = position.market_id.clone();
         let fee = Decimal::new(2, 2); // 2%
 
         // Close position
         if let Err(e) = self
             .position_service
-            .close_position(position, CloseMethod::MarketExit { fee }, &ctx)
+            .close_position(position, CloseMethod::MarketExit { fee }, ctx)
             .await
         {
             warn!(position_id = %position.id, error = %e, "close_position failed");
-            return Ok(());
+            return;
         }
+        self.record_exit_outcome(position, realized_via).await;
 
         let yes_price = position.yes_exit_price.unwrap_or(Decimal::ZERO);
         let no_price = position.no_exit_price.unwrap_or(Decimal::ZERO);
@@ -611,10 +721,27 @@ impl ExitHandler {
             market_id = %market_id,
             position_id = %position.id,
             realized_pnl = %realized_pnl,
+            realized_via,
             "Position closed via exit"
         );
+    }
 
-        Ok(())
+    /// Attach a closed position's realized proceeds to its exit decision, if any.
+    async fn record_exit_outcome(&self, position: &Position, realized_via: &str) {
+        let Some(realized_pnl) = position.realized_pnl else {
+            return;
+        };
+        let realized_value = realized_pnl + position.entry_cost();
+        if let Err(error) =
+            exit_optimizer::record_outcome(&self.pool, position.id, realized_via, realized_value)
+                .await
+        {
+            warn!(
+                position_id = %position.id,
+                error = %error,
+                "Failed to record exit outcome"
+            );
+        }
     }
 
     async fn current_exit_bids(&self, position: &Position) -> anyhow::Result<ExitBidStatus> {
@@ -742,6 +869,7 @@ impl ExitHandler {
             );
             return Ok(false);
         }
+        self.record_exit_outcome(position, "resolution").await;
 
         let realized_pnl = position.realized_pnl.unwrap_or_default();
         let is_win = realized_pnl > Decimal::ZERO;
@@ -916,7 +1044,7 @@ impl ExitHandler {
 
         let mut map = HashMap::new();
         for row in rows {
-            let Some(kind) = parse_quant_kind(&row.kind) else {
+            let Some(kind) = QuantSignalKind::parse(&row.kind) else {
                 continue;
             };
             let Some(direction) = parse_signal_direction(&row.direction) else {
@@ -968,7 +1096,7 @@ impl ExitHandler {
         Ok(row.and_then(|row| {
             Some(QuantExitContext {
                 signal_id: row.signal_id,
-                kind: parse_quant_kind(&row.kind)?,
+                kind: QuantSignalKind::parse(&row.kind)?,
                 direction: parse_signal_direction(&row.direction)?,
                 metadata: row.metadata,
             })
@@ -991,56 +1119,6 @@ impl ExitHandler {
             || held_hours >= cfg.quant_max_hold_hours
     }
 
-    async fn flow_strategy_exit(
-        &self,
-        position: &Position,
-        quant_ctx: &QuantExitContext,
-        _cfg: &ExitHandlerConfig,
-    ) -> anyhow::Result<bool> {
-        let window_minutes = quant_ctx
-            .metadata
-            .get("window_minutes")
-            .and_then(|value| value.as_i64())
-            .unwrap_or(60) as i32;
-        let entry_imbalance =
-            json_decimal_abs(&quant_ctx.metadata, "imbalance_ratio").unwrap_or(Decimal::ZERO);
-
-        let latest_row: Option<(Decimal,)> = sqlx::query_as(
-            r#"
-            SELECT imbalance_ratio
-            FROM market_flow_features
-            WHERE condition_id = $1
-              AND window_minutes = $2
-            ORDER BY window_end DESC
-            LIMIT 1
-            "#,
-        )
-        .bind(&position.market_id)
-        .bind(window_minutes)
-        .fetch_optional(&self.pool)
-        .await?;
-
-        let Some((imbalance_ratio,)) = latest_row else {
-            return Ok(false);
-        };
-
-        let original_sign = direction_sign(quant_ctx.direction);
-        let current_sign = if imbalance_ratio > Decimal::ZERO {
-            1
-        } else if imbalance_ratio < Decimal::ZERO {
-            -1
-        } else {
-            0
-        };
-        let min_supported_imbalance = entry_imbalance
-            .checked_div(Decimal::new(2, 0))
-            .unwrap_or(Decimal::ZERO)
-            .max(Decimal::new(15, 2));
-
-        Ok((current_sign != 0 && current_sign != original_sign)
-            || imbalance_ratio.abs() < min_supported_imbalance)
-    }
-
     async fn should_mark_exit_ready(
         &self,
         position: &Position,
@@ -1063,20 +1141,28 @@ impl ExitHandler {
             return Ok(false);
         };
 
-        let current_yes = infer_yes_price(yes_bid, no_bid);
-        let current_no = infer_no_price(yes_bid, no_bid);
-
-        let strategy_exit = match quant_ctx.kind {
-            QuantSignalKind::Flow => self
-                .flow_strategy_exit(position, quant_ctx, cfg)
-                .await
-                .unwrap_or(false),
-            QuantSignalKind::MeanReversion => {
-                mean_reversion_target_hit(quant_ctx, current_yes, current_no)
-            }
-            QuantSignalKind::CrossMarket => cross_market_target_hit(quant_ctx, current_yes),
-            QuantSignalKind::ResolutionProximity => resolution_lean_decay(quant_ctx, current_yes),
+        let Some(generator) = self.signal_generators.get(quant_ctx.kind) else {
+            return Ok(false);
         };
+        let exit_ctx = ExitContext {
+            market_id: &position.market_id,
+            direction: quant_ctx.direction,
+            metadata: &quant_ctx.metadata,
+            current_yes: infer_yes_price(yes_bid, no_bid),
+            current_no: infer_no_price(yes_bid, no_bid),
+        };
+        let strategy_exit = generator
+            .should_exit(&self.pool, &exit_ctx)
+            .await
+            .unwrap_or_else(|e| {
+                warn!(
+                    position_id = %position.id,
+                    strategy = generator.name(),
+                    error = %e,
+                    "Strategy exit check failed"
+                );
+                false
+            });
 
         Ok(strategy_exit)
     }
@@ -1244,8 +1330,8 @@ impl ExitHandler {
             .await
     }
 
-    /// Process one-legged entry failures by flattening the filled YES leg.
-    async fn process_one_legged_recovery(&self) -> anyhow::Result<()> {
+    /// Process one-legged entry failures by queueing the filled YES leg for unwind.
+    async fn process_one_legged_recovery(&self, cfg: &ExitHandlerConfig) -> anyhow::Result<()> {
         let positions = self.position_repo.get_one_legged_entry_failed().await?;
         if positions.is_empty() {
             return Ok(());
@@ -1293,7 +1379,7 @@ impl ExitHandler {
 
             self.arb_dedup.write().await.insert(market_id.clone());
 
-            if let Err(error) = self.execute_exit(&mut position).await {
+            if let Err(error) = self.exit_or_unwind(&mut position, cfg).await {
                 warn!(
                     market_id = %market_id,
                     position_id = %position.id,
@@ -1304,7 +1390,7 @@ impl ExitHandler {
                 info!(
                     market_id = %market_id,
                     position_id = %position.id,
-                    "One-legged position sent through exit flow for flattening"
+                    "One-legged position sent through unwind/exit flow"
                 );
             }
         }
@@ -1312,6 +1398,574 @@ impl ExitHandler {
         Ok(())
     }
 
+    /// Exit an ExitReady position, routing one-legged arbs through the unwind
+    /// policy and full pairs through the exit optimizer before falling back to
+    /// selling the held legs.
+    async fn exit_or_unwind(
+        &self,
+        position: &mut Position,
+        cfg: &ExitHandlerConfig,
+    ) -> anyhow::Result<()> {
+        if self.passive_exits.lock().await.contains_key(&position.id) {
+            return self.progress_passive_exit(position).await;
+        }
+        if cfg.unwind_policy.enabled {
+            if let Some(held_leg) = position.stranded_leg().map(str::to_string) {
+                if self
+                    .apply_unwind_policy(position, &held_leg, &cfg.unwind_policy)
+                    .await?
+                {
+                    return Ok(());
+                }
+            }
+        }
+        if cfg.exit_optimizer.enabled
+            && position.stranded_leg().is_none()
+            && position.has_full_pair_exposure()
+            && self
+                .apply_exit_optimizer(position, &cfg.exit_optimizer)
+                .await?
+        {
+            return Ok(());
+        }
+        self.execute_exit(position).await
+    }
+
+    /// Decide sell now / passive / hold for a full pair.
+    ///
+    /// Returns true when the position was handled here (passive orders placed
+    /// or held) and must not go through the sell flow this tick.
+    async fn apply_exit_optimizer(
+        &self,
+        position: &mut Position,
+        config: &ExitOptimizerConfig,
+    ) -> anyhow::Result<bool> {
+        let exit = PairedExit::from_position(position, config.legacy_fee_rate);
+        let decision = match ExitOptimizer::new(config.clone())
+            .evaluate(self, &exit)
+            .await
+        {
+            Ok(decision) => decision,
+            Err(error) => {
+                warn!(
+                    market_id = %position.market_id,
+                    position_id = %position.id,
+                    error = %error,
+                    "Exit optimizer evaluation failed; selling now"
+                );
+                return Ok(false);
+            }
+        };
+
+        // Holds are re-evaluated every tick; only record them when the decision changes.
+        let changed = self
+            .exit_policies
+            .lock()
+            .await
+            .insert(position.id, decision.policy.as_str())
+            != Some(decision.policy.as_str());
+        if changed || decision.policy != ExitPolicy::Hold {
+            info!(
+                market_id = %position.market_id,
+                position_id = %position.id,
+                policy = decision.policy.as_str(),
+                reason = %decision.reason,
+                "Exit policy decision"
+            );
+            if let Err(error) = exit_optimizer::record_decision(&self.pool, &exit, &decision).await
+            {
+                warn!(
+                    position_id = %position.id,
+                    error = %error,
+                    "Failed to record exit policy decision"
+                );
+            }
+        }
+
+        match decision.policy {
+            ExitPolicy::SellNow => Ok(false),
+            ExitPolicy::Hold => Ok(true),
+            ExitPolicy::Passive { yes, no } => {
+                self.start_passive_exit(position, [yes, no], config.passive_window_secs)
+                    .await
+            }
+        }
+    }
+
+    /// Rest a passive sell order for each leg. Returns false, so the pair is
+    /// sold now instead, when the orders cannot be placed.
+    async fn start_passive_exit(
+        &self,
+        position: &Position,
+        quotes: [PassiveQuote; 2],
+        window_secs: u64,
+    ) -> anyhow::Result<bool> {
+        let Some((yes_token_id, no_token_id)) =
+            self.resolve_market_tokens(&position.market_id).await?
+        else {
+            return Ok(false);
+        };
+        let live = self.order_executor.is_live_ready().await;
+
+        let mut legs: Vec<PassiveLeg> = Vec::with_capacity(quotes.len());
+        for quote in quotes {
+            let (token_id, held_qty) = match quote.leg {
+                Leg::Yes => (yes_token_id.clone(), position.held_yes_qty),
+                Leg::No => (no_token_id.clone(), position.held_no_qty),
+            };
+            // Legacy rows predate per-leg quantities.
+            let quantity = if held_qty > Decimal::ZERO {
+                held_qty
+            } else {
+                position.quantity
+            };
+            let order_id = if live {
+                match self
+                    .order_executor
+                    .place_post_only_order(&token_id, OrderSide::Sell, quote.price, quantity)
+                    .await
+                {
+                    Ok(order_id) => Some(order_id),
+                    Err(error) => {
+                        warn!(
+                            market_id = %position.market_id,
+                            position_id = %position.id,
+                            leg = %quote.leg,
+                            error = %error,
+                            "Failed to place passive exit order; selling now"
+                        );
+                        if !self.cancel_passive_orders(&mut legs).await {
+                            // Keep tracking the placed orders until their
+                            // cancels are confirmed, so nothing sells twice.
+                            let passive = PassiveExit {
+                                expires_at: Utc::now(),
+                                legs,
+                            };
+                            self.save_passive_exit(position.id, &passive).await;
+                            self.passive_exits.lock().await.insert(position.id, passive);
+                            return Ok(true);
+                        }
+                        return Ok(false);
+                    }
+                }
+            } else {
+                None
+            };
+            legs.push(PassiveLeg {
+                leg: quote.leg,
+                token_id,
+                price: quote.price,
+                quantity,
+                order_id,
+                filled: Decimal::ZERO,
+                open: true,
+            });
+        }
+
+        info!(
+            market_id = %position.market_id,
+            position_id = %position.id,
+            yes_price = %legs[0].price,
+            no_price = %legs[1].price,
+            window_secs,
+            live,
+            "Resting passive exit orders"
+        );
+        let passive = PassiveExit {
+            expires_at: Utc::now() + chrono::Duration::seconds(window_secs as i64),
+            legs,
+        };
+        self.save_passive_exit(position.id, &passive).await;
+        self.passive_exits.lock().await.insert(position.id, passive);
+        Ok(true)
+    }
+
+    /// Check a resting passive exit and settle it once both orders filled or
+    /// the window closed.
+    async fn progress_passive_exit(&self, position: &mut Position) -> anyhow::Result<()> {
+        let Some(mut passive) = self.passive_exits.lock().await.remove(&position.id) else {
+            return Ok(());
+        };
+        if self
+            .poll_passive_fills(&position.market_id, &mut passive)
+            .await
+        {
+            self.save_passive_exit(position.id, &passive).await;
+        }
+        let settled = passive.legs.iter().all(|leg| !leg.open);
+        if !settled && Utc::now() < passive.expires_at {
+            self.passive_exits.lock().await.insert(position.id, passive);
+            return Ok(());
+        }
+        self.finish_passive_exit(position, passive).await
+    }
+
+    /// Update passive fills; returns whether any leg changed. Live orders
+    /// are read back by id, so one closed elsewhere keeps only what it
+    /// matched; paper orders fill once the bid reaches them.
+    async fn poll_passive_fills(&self, market_id: &str, passive: &mut PassiveExit) -> bool {
+        let clob = self.order_executor.clob_client();
+        let mut changed = false;
+        for leg in passive.legs.iter_mut().filter(|leg| leg.open) {
+            let (filled, open) = match leg.order_id.as_deref() {
+                Some(order_id) => match self.order_executor.live_order_fill(order_id).await {
+                    Ok(fill) => (fill.matched, fill.open),
+                    Err(error) => {
+                        debug!(market_id = %market_id, order_id, error = %error, "Passive exit order poll failed");
+                        continue;
+                    }
+                },
+                None => match clob.get_order_book(&leg.token_id).await {
+                    Ok(book) if book.best_bid().is_some_and(|bid| bid >= leg.price) => {
+                        (leg.quantity, false)
+                    }
+                    Ok(_) => continue,
+                    Err(error) => {
+                        debug!(market_id = %market_id, error = %error, "Passive exit book poll failed");
+                        continue;
+                    }
+                },
+            };
+            let filled = filled.clamp(leg.filled, leg.quantity);
+            let open = open && filled < leg.quantity;
+            changed |= filled != leg.filled || open != leg.open;
+            leg.filled = filled;
+            leg.open = open;
+        }
+        changed
+    }
+
+    /// Cancel what is still resting, sell any remainder into the bids and
+    /// close the position.
+    async fn finish_passive_exit(
+        &self,
+        position: &mut Position,
+        mut passive: PassiveExit,
+    ) -> anyhow::Result<()> {
+        // An order can still fill between the last poll and its cancel, so
+        // the remainder is sized from the fill read back after the cancel.
+        // Until every cancel is confirmed nothing is sold.
+        if !self.cancel_passive_orders(&mut passive.legs).await {
+            warn!(
+                market_id = %position.market_id,
+                position_id = %position.id,
+                "Passive exit cancel not confirmed; retrying next tick"
+            );
+            self.save_passive_exit(position.id, &passive).await;
+            self.passive_exits.lock().await.insert(position.id, passive);
+            return Ok(());
+        }
+        self.forget_passive_exit(position.id).await;
+
+        let market_id = position.market_id.clone();
+        let execution_mode = self.current_execution_mode().await;
+        let quant_ctx = self
+            .load_quant_exit_context(position.id)
+            .await
+            .ok()
+            .flatten();
+        let source = if quant_ctx.is_some() {
+            SOURCE_RECOMMENDATION
+        } else {
+            SOURCE_ARBITRAGE
+        };
+        let ctx = Self::event_context(&execution_mode, source, quant_ctx.as_ref());
+        if let Err(e) = self.position_service.mark_closing(position, &ctx).await {
+            warn!(error = %e, "mark_closing failed");
+            return Ok(());
+        }
+
+        let mut fell_back = false;
+        let mut failure = None;
+        for leg in &passive.legs {
+            let label = match leg.leg {
+                Leg::Yes => "YES",
+                Leg::No => "NO",
+            };
+            let mut filled = leg.filled;
+            let mut notional = leg.filled * leg.price;
+            let remaining = leg.remaining();
+            if !remaining.is_zero() {
+                fell_back = true;
+                match self
+                    .execute_sell_order_with_refresh(
+                        &market_id,
+                        label,
+                        &leg.token_id,
+                        OrderSide::Sell,
+                        remaining,
+                    )
+                    .await
+                {
+                    Ok(report) if report.is_success() => {
+                        filled += report.filled_quantity;
+                        notional += report.filled_quantity * report.average_price;
+                    }
+                    Ok(report) => {
+                        let msg = report
+                            .error_message
+                            .unwrap_or_else(|| "not filled".to_string());
+                        failure = Some(format!("{label} remainder sell failed: {msg}"));
+                    }
+                    Err(e) => {
+                        if self
+                            .close_via_resolution_if_market_resolved(position, quant_ctx.as_ref())
+                            .await?
+                        {
+                            return Ok(());
+                        }
+                        failure = Some(format!("{label} remainder sell error: {e}"));
+                    }
+                }
+            }
+            if filled.is_zero() {
+                continue;
+            }
+            if let Err(error) = self
+                .position_service
+                .record_exit_fill(position, leg.leg, notional / filled, filled, &ctx)
+                .await
+            {
+                failure = Some(format!("failed to record {label} exit fill: {error}"));
+            }
+        }
+
+        if held_outcomes(position) != (false, false) {
+            let message = failure.unwrap_or_else(|| "passive exit left a leg unsold".to_string());
+            error!(market_id = %market_id, position_id = %position.id, reason = %message, "Passive exit failed");
+            let _ = self
+                .position_service
+                .mark_exit_failed(position, FailureReason::OrderRejected { message }, &ctx)
+                .await;
+            self.publish_alert(&market_id, "exit_failed", "Passive exit remainder not sold");
+            return Ok(());
+        }
+        if let Some(message) = failure {
+            // Both legs are recorded; the unsold part of a leg is left to
+            // orphan inventory recovery.
+            warn!(market_id = %market_id, position_id = %position.id, reason = %message, "Passive exit left shares unsold");
+        }
+
+        let realized_via = if fell_back {
+            "passive_fallback"
+        } else {
+            "passive"
+        };
+        self.finish_market_exit(position, &ctx, realized_via).await;
+        Ok(())
+    }
+
+    /// Cancel the passive orders still open and take their final fills.
+    /// Returns false when a cancel could not be confirmed.
+    async fn cancel_passive_orders(&self, legs: &mut [PassiveLeg]) -> bool {
+        let mut confirmed = true;
+        for leg in legs.iter_mut().filter(|leg| leg.open) {
+            if let Some(order_id) = leg.order_id.as_deref() {
+                match self
+                    .order_executor
+                    .cancel_live_order_and_read_fill(order_id)
+                    .await
+                {
+                    Ok(fill) => leg.filled = fill.matched.clamp(leg.filled, leg.quantity),
+                    Err(error) => {
+                        warn!(order_id = %order_id, error = %error, "Failed to cancel passive exit order");
+                        confirmed = false;
+                        continue;
+                    }
+                }
+            }
+            leg.open = false;
+        }
+        confirmed
+    }
+
+    async fn save_passive_exit(&self, position_id: uuid::Uuid, passive: &PassiveExit) {
+        if let Err(error) = save_passive_exit(&self.pool, position_id, passive).await {
+            warn!(position_id = %position_id, error = %error, "Failed to save passive exit");
+        }
+    }
+
+    async fn forget_passive_exit(&self, position_id: uuid::Uuid) {
+        if let Err(error) = sqlx::query("DELETE FROM passive_exit_orders WHERE position_id = $1")
+            .bind(position_id)
+            .execute(&self.pool)
+            .await
+        {
+            warn!(position_id = %position_id, error = %error, "Failed to delete passive exit");
+        }
+    }
+
+    /// Decide complete / sell / hold for a one-legged arb.
+    ///
+    /// Returns true when the position was handled here (completed or held)
+    /// and must not go through the sell flow this tick.
+    async fn apply_unwind_policy(
+        &self,
+        position: &mut Position,
+        held_leg: &str,
+        policy_config: &UnwindPolicyConfig,
+    ) -> anyhow::Result<bool> {
+        let held_leg = if held_leg.eq_ignore_ascii_case("no") {
+            Leg::No
+        } else {
+            Leg::Yes
+        };
+        let (held_qty, entry_price) = match held_leg {
+            Leg::Yes => (position.held_yes_qty, position.yes_entry_price),
+            Leg::No => (position.held_no_qty, position.no_entry_price),
+        };
+        let completion_attempts = self
+            .unwind_trackers
+            .lock()
+            .await
+            .get(&position.id)
+            .map(|tracker| tracker.completion_attempts)
+            .unwrap_or(0);
+        let stranded = StrandedLeg {
+            position_id: position.id,
+            market_id: position.market_id.clone(),
+            held_leg,
+            // Legacy rows predate per-leg quantities.
+            quantity: if held_qty > Decimal::ZERO {
+                held_qty
+            } else {
+                position.quantity
+            },
+            entry_price,
+            completion_attempts,
+        };
+
+        let decision = match UnwindPolicy::new(policy_config.clone())
+            .evaluate(self, &stranded)
+            .await
+        {
+            Ok(decision) => decision,
+            Err(error) => {
+                warn!(
+                    market_id = %position.market_id,
+                    position_id = %position.id,
+                    error = %error,
+                    "Unwind policy evaluation failed; selling held leg"
+                );
+                return Ok(false);
+            }
+        };
+
+        // Holds are re-evaluated every tick; only record them when the decision changes.
+        let changed = {
+            let mut trackers = self.unwind_trackers.lock().await;
+            let tracker = trackers.entry(position.id).or_default();
+            let changed = tracker.last_action != Some(decision.action.as_str());
+            tracker.last_action = Some(decision.action.as_str());
+            changed
+        };
+        let execution_mode = self.current_execution_mode().await;
+        let ctx = Self::event_context(&execution_mode, SOURCE_ARBITRAGE, None);
+        if changed || decision.action != UnwindAction::Hold {
+            info!(
+                market_id = %position.market_id,
+                position_id = %position.id,
+                action = decision.action.as_str(),
+                reason = %decision.reason,
+                "One-legged unwind decision"
+            );
+            self.position_service
+                .record_unwind_decision(position, &decision, &ctx)
+                .await;
+        }
+
+        match decision.action {
+            UnwindAction::SellHeldLeg { .. } => {
+                self.unwind_trackers.lock().await.remove(&position.id);
+                Ok(false)
+            }
+            UnwindAction::Hold => Ok(true),
+            UnwindAction::CompleteMissingLeg { limit_price } => {
+                self.complete_missing_leg(position, &stranded, limit_price, &ctx)
+                    .await?;
+                Ok(true)
+            }
+        }
+    }
+
+    /// Buy the missing leg (FOK, capped at `limit_price`) and reopen the pair.
+    async fn complete_missing_leg(
+        &self,
+        position: &mut Position,
+        stranded: &StrandedLeg,
+        limit_price: Decimal,
+        ctx: &EventContext,
+    ) -> anyhow::Result<()> {
+        if let Some(tracker) = self.unwind_trackers.lock().await.get_mut(&position.id) {
+            tracker.completion_attempts += 1;
+        }
+
+        let market_id = position.market_id.clone();
+        let Some((yes_token_id, no_token_id)) = self.resolve_market_tokens(&market_id).await?
+        else {
+            warn!(market_id = %market_id, "No token IDs to complete missing leg");
+            return Ok(());
+        };
+        let missing_leg = stranded.missing_leg();
+        let token_id = match missing_leg {
+            Leg::Yes => yes_token_id,
+            Leg::No => no_token_id,
+        };
+
+        let order = MarketOrder::new(
+            market_id.clone(),
+            token_id,
+            OrderSide::Buy,
+            stranded.quantity,
+        )
+        .with_expected_price(limit_price)
+        .with_slippage(Decimal::ZERO);
+        match self.order_executor.execute_market_order(order).await {
+            Ok(report) if report.is_success() => {
+                let fill_price = if report.average_price > Decimal::ZERO {
+                    report.average_price
+                } else {
+                    limit_price
+                };
+                self.position_service
+                    .complete_one_legged(
+                        position,
+                        missing_leg,
+                        fill_price,
+                        report.filled_quantity,
+                        ctx,
+                    )
+                    .await?;
+                self.unwind_trackers.lock().await.remove(&position.id);
+                info!(
+                    market_id = %market_id,
+                    position_id = %position.id,
+                    leg = %missing_leg,
+                    fill_price = %fill_price,
+                    "Completed missing leg of one-legged arb"
+                );
+            }
+            Ok(report) => {
+                warn!(
+                    market_id = %market_id,
+                    position_id = %position.id,
+                    limit_price = %limit_price,
+                    reason = report.error_message.as_deref().unwrap_or("not filled"),
+                    "Missing-leg completion order not filled"
+                );
+            }
+            Err(error) => {
+                warn!(
+                    market_id = %market_id,
+                    position_id = %position.id,
+                    error = %error,
+                    "Missing-leg completion order failed"
+                );
+            }
+        }
+        Ok(())
+    }
+
     async fn process_orphan_inventory_recovery(
         &self,
         cfg: &ExitHandlerConfig,
@@ -1388,8 +2042,20 @@ impl ExitHandler {
         Ok(())
     }
 
-    /// Publish an alert signal to WebSocket clients.
+    /// Publish an alert signal to WebSocket clients and the operator alert sinks.
     fn publish_alert(&self, market_id: &str, action: &str, reason: &str) {
+        // A failed exit leaves capital at risk until someone looks at it.
+        self.alert_router.dispatch_background(
+            Alert::new(
+                format!("exit.{}", action),
+                Severity::Critical,
+                format!("Exit {}: {}", action.replace('_', " "), market_id),
+            )
+            .with_body(reason)
+            .with_dedup_key(format!("exit.{}:{}", action, market_id))
+            .with_field("market_id", market_id),
+        );
+
         let signal = SignalUpdate {
             signal_id: uuid::Uuid::new_v4(),
             signal_type: SignalType::Alert,
@@ -1406,13 +2072,53 @@ impl ExitHandler {
     }
 }
 
-fn parse_quant_kind(kind: &str) -> Option<QuantSignalKind> {
-    match kind {
-        "flow" => Some(QuantSignalKind::Flow),
-        "mean_reversion" => Some(QuantSignalKind::MeanReversion),
-        "cross_market" => Some(QuantSignalKind::CrossMarket),
-        "resolution_proximity" => Some(QuantSignalKind::ResolutionProximity),
-        _ => None,
+#[async_trait::async_trait]
+impl UnwindMarketSource for ExitHandler {
+    async fn market_view(&self, stranded: &StrandedLeg) -> anyhow::Result<UnwindMarketView> {
+        let market_id = stranded.market_id.as_str();
+        let market = self.clob_client.get_market_by_id(market_id).await?;
+        if market.resolved {
+            // The sell flow closes resolved markets via resolution.
+            anyhow::bail!("market {market_id} already resolved");
+        }
+        let Some((yes_token_id, no_token_id)) = self.resolve_market_tokens(market_id).await? else {
+            anyhow::bail!("no token IDs for market {market_id}");
+        };
+        let (held_token, missing_token) = match stranded.held_leg {
+            Leg::Yes => (yes_token_id, no_token_id),
+            Leg::No => (no_token_id, yes_token_id),
+        };
+        let clob = self.order_executor.clob_client();
+        let held_book = clob.get_order_book(&held_token).await?;
+        let missing_book = clob.get_order_book(&missing_token).await?;
+        Ok(UnwindMarketView {
+            held_book,
+            missing_book,
+            time_to_resolution: market.end_date.map(|end| end - Utc::now()),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl ExitMarketSource for ExitHandler {
+    async fn exit_market_view(&self, exit: &PairedExit) -> anyhow::Result<ExitMarketView> {
+        let market_id = exit.market_id.as_str();
+        let market = self.clob_client.get_market_by_id(market_id).await?;
+        if market.resolved {
+            // The sell flow closes resolved markets via resolution.
+            anyhow::bail!("market {market_id} already resolved");
+        }
+        let Some((yes_token_id, no_token_id)) = self.resolve_market_tokens(market_id).await? else {
+            anyhow::bail!("no token IDs for market {market_id}");
+        };
+        let clob = self.order_executor.clob_client();
+        let yes_book = clob.get_order_book(&yes_token_id).await?;
+        let no_book = clob.get_order_book(&no_token_id).await?;
+        Ok(ExitMarketView {
+            yes_book,
+            no_book,
+            time_to_resolution: market.end_date.map(|end| end - Utc::now()),
+        })
     }
 }
 
@@ -1465,88 +2171,84 @@ fn infer_no_price(yes_bid: Decimal, no_bid: Decimal) -> Decimal {
     }
 }
 
-fn direction_sign(direction: SignalDirection) -> i32 {
-    match direction {
-        SignalDirection::BuyYes => 1,
-        SignalDirection::BuyNo => -1,
-    }
-}
-
-fn json_decimal_abs(metadata: &serde_json::Value, key: &str) -> Option<Decimal> {
-    let value = metadata.get(key)?;
-    if let Some(raw) = value.as_str() {
-        return raw.parse::<Decimal>().ok().map(|v| v.abs());
-    }
-    if let Some(raw) = value.as_f64() {
-        return Decimal::try_from(raw).ok().map(|v| v.abs());
-    }
-    None
-}
-
-fn json_decimal(metadata: &serde_json::Value, key: &str) -> Option<Decimal> {
-    let value = metadata.get(key)?;
-    if let Some(raw) = value.as_str() {
-        return raw.parse::<Decimal>().ok();
-    }
-    if let Some(raw) = value.as_f64() {
-        return Decimal::try_from(raw).ok();
-    }
-    None
-}
-
-fn mean_reversion_target_hit(
-    quant_ctx: &QuantExitContext,
-    current_yes: Decimal,
-    current_no: Decimal,
-) -> bool {
-    let Some(current_price) = json_decimal(&quant_ctx.metadata, "current_price") else {
-        return false;
-    };
-    let Some(previous_price) = json_decimal(&quant_ctx.metadata, "previous_price") else {
-        return false;
-    };
-    let target_yes = (current_price + previous_price) / Decimal::new(2, 0);
-    match quant_ctx.direction {
-        SignalDirection::BuyYes => current_yes >= target_yes,
-        SignalDirection::BuyNo => current_no >= (Decimal::ONE - target_yes).max(Decimal::ZERO),
+async fn save_passive_exit(
+    pool: &PgPool,
+    position_id: uuid::Uuid,
+    passive: &PassiveExit,
+) -> sqlx::Result<()> {
+    for leg in &passive.legs {
+        sqlx::query(
+            r#"
+            INSERT INTO passive_exit_orders (
+                position_id, leg, token_id, price, quantity, order_id, filled, open, expires_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            ON CONFLICT (position_id, leg) DO UPDATE SET
+                filled = EXCLUDED.filled,
+                open = EXCLUDED.open,
+                expires_at = EXCLUDED.expires_at,
+                updated_at = NOW()
+            "#,
+        )
+        .bind(position_id)
+        .bind(leg.leg.to_string())
+        .bind(&leg.token_id)
+        .bind(leg.price)
+        .bind(leg.quantity)
+        .bind(leg.order_id.as_deref())
+        .bind(leg.filled)
+        .bind(leg.open)
+        .bind(passive.expires_at)
+        .execute(pool)
+        .await?;
     }
+    Ok(())
 }
 
-fn cross_market_target_hit(quant_ctx: &QuantExitContext, current_yes: Decimal) -> bool {
-    let Some(lag_current_price) = json_decimal(&quant_ctx.metadata, "lag_current_price") else {
-        return false;
-    };
-    let lead_change = quant_ctx
-        .metadata
-        .get("lead_change")
-        .and_then(|value| value.as_f64())
-        .unwrap_or(0.0)
-        .abs();
-    let lag_change = quant_ctx
-        .metadata
-        .get("lag_change")
-        .and_then(|value| value.as_f64())
-        .unwrap_or(0.0)
-        .abs();
-    let divergence_gap = ((lead_change - lag_change) / 2.0).max(0.0);
-    let Ok(divergence_gap) = Decimal::try_from(divergence_gap) else {
-        return false;
-    };
-
-    match quant_ctx.direction {
-        SignalDirection::BuyYes => current_yes >= lag_current_price + divergence_gap,
-        SignalDirection::BuyNo => {
-            current_yes <= (lag_current_price - divergence_gap).max(Decimal::ZERO)
-        }
+async fn load_passive_exits(pool: &PgPool) -> sqlx::Result<HashMap<uuid::Uuid, PassiveExit>> {
+    let rows = sqlx::query_as::<
+        _,
+        (
+            uuid::Uuid,
+            String,
+            String,
+            Decimal,
+            Decimal,
+            Option<String>,
+            Decimal,
+            bool,
+            DateTime<Utc>,
+        ),
+    >(
+        r#"
+        SELECT position_id, leg, token_id, price, quantity, order_id, filled, open, expires_at
+        FROM passive_exit_orders
+        ORDER BY position_id, leg DESC
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let mut passive_exits: HashMap<uuid::Uuid, PassiveExit> = HashMap::new();
+    for (position_id, leg, token_id, price, quantity, order_id, filled, open, expires_at) in rows {
+        passive_exits
+            .entry(position_id)
+            .or_insert_with(|| PassiveExit {
+                expires_at,
+                legs: Vec::with_capacity(2),
+            })
+            .legs
+            .push(PassiveLeg {
+                leg: if leg == "no" { Leg::No } else { Leg::Yes },
+                token_id,
+                price,
+                quantity,
+                order_id,
+                filled,
+                open,
+            });
     }
-}
-
-fn resolution_lean_decay(quant_ctx: &QuantExitContext, current_yes: Decimal) -> bool {
-    let Some(entry_deviation) = json_decimal_abs(&quant_ctx.metadata, "deviation") else {
-        return false;
-    };
-    let current_deviation = (current_yes - Decimal::new(50, 2)).abs();
-    current_deviation <= entry_deviation / Decimal::new(2, 0)
+    Ok(passive_exits)
 }
 
 /// Spawn the exit handler as a background task.
@@ -1557,11 +2259,13 @@ pub(crate) fn spawn_exit_handler(
     circuit_breaker: Arc<CircuitBreaker>,
     clob_client: Arc<ClobClient>,
     signal_tx: broadcast::Sender<SignalUpdate>,
+    alert_router: Arc<AlertRouter>,
     trade_event_tx: broadcast::Sender<crate::trade_events::TradeEventUpdate>,
     pool: PgPool,
     arb_dedup: Arc<RwLock<HashSet<String>>>,
     heartbeat: Arc<AtomicI64>,
     token_cache: Arc<OutcomeTokenCache>,
+    signal_generators: Arc<SignalGeneratorRegistry>,
 ) {
     let handler = ExitHandler::new(
         config,
@@ -1569,11 +2273,13 @@ pub(crate) fn spawn_exit_handler(
         circuit_breaker,
         clob_client,
         signal_tx,
+        alert_router,
         trade_event_tx,
         pool,
         arb_dedup,
         heartbeat,
         token_cache,
+        signal_generators,
     );
 
     tokio::spawn(async move {
@@ -1585,76 +2291,21 @@ pub(crate) fn spawn_exit_handler(
     info!("Exit handler spawned as background task");
 }
 
-#[cfg(test)]
-mod strategy_exit_tests {
-    use super::*;
-    use serde_json::json;
-
-    fn quant_ctx(
-        kind: QuantSignalKind,
-        direction: SignalDirection,
-        metadata: serde_json::Value,
-    ) -> QuantExitContext {
-        QuantExitContext {
-            signal_id: uuid::Uuid::new_v4(),
-            kind,
-            direction,
-            metadata,
-        }
-    }
-
-    #[test]
-    fn mean_reversion_exit_hits_midpoint_target_for_yes() {
-        let ctx = quant_ctx(
-            QuantSignalKind::MeanReversion,
-            SignalDirection::BuyYes,
-            json!({
-                "current_price": "0.40",
-                "previous_price": "0.60"
-            }),
-        );
-
-        assert!(mean_reversion_target_hit(
-            &ctx,
-            Decimal::new(50, 2),
-            Decimal::new(50, 2)
-        ));
-        assert!(!mean_reversion_target_hit(
-            &ctx,
-            Decimal::new(45, 2),
-            Decimal::new(55, 2)
-        ));
-    }
-
-    #[test]
-    fn cross_market_exit_requires_divergence_compression() {
-        let ctx = quant_ctx(
-            QuantSignalKind::CrossMarket,
-            SignalDirection::BuyYes,
-            json!({
-                "lag_current_price": "0.40",
-                "lead_change": 0.20,
-                "lag_change": 0.04
-            }),
-        );
-
-        assert!(cross_market_target_hit(&ctx, Decimal::new(48, 2)));
-        assert!(!cross_market_target_hit(&ctx, Decimal::new(45, 2)));
-    }
-
-    #[test]
-    fn resolution_exit_triggers_when_lean_decays_by_half() {
-        let ctx = quant_ctx(
-            QuantSignalKind::ResolutionProximity,
-            SignalDirection::BuyYes,
-            json!({
-                "deviation": "0.20"
-            }),
-        );
+fn held_outcomes(position: &Position) -> (bool, bool) {
+    position.held_outcomes()
+}
 
-        assert!(resolution_lean_decay(&ctx, Decimal::new(58, 2)));
-        assert!(!resolution_lean_decay(&ctx, Decimal::new(70, 2)));
-    }
+pub(crate) fn resolved_yes_winner(market: &Market) -> Option<bool> {
+    market.outcomes.iter().find_map(|outcome| {
+        let winner = outcome.winner?;
+        if outcome.name.eq_ignore_ascii_case("yes") {
+            Some(winner)
+        } else if outcome.name.eq_ignore_ascii_case("no") {
+            Some(!winner)
+        } else {
+            None
+        }
+    })
 }
 
 #[cfg(test)]
@@ -1668,6 +2319,7 @@ mod tests {
         assert_eq!(config.exit_poll_interval_secs, 30);
         assert_eq!(config.resolution_check_secs, 300);
         assert_eq!(config.failed_exit_retry_backoff_secs, 300);
+        assert!(!config.exit_optimizer.enabled);
     }
 
     #[test]
@@ -1754,20 +2406,3 @@ mod tests {
         assert_eq!(resolved_yes_winner(&market), Some(true));
     }
 }
-
-fn held_outcomes(position: &Position) -> (bool, bool) {
-    position.held_outcomes()
-}
-
-fn resolved_yes_winner(market: &Market) -> Option<bool> {
-    market.outcomes.iter().find_map(|outcome| {
-        let winner = outcome.winner?;
-        if outcome.name.eq_ignore_ascii_case("yes") {
-            Some(winner)
-        } else if outcome.name.eq_ignore_ascii_case("no") {
-            Some(!winner)
-        } else {
-            None
-        }
-    })
-}
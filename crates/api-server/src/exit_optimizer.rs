@@ -0,0 +1,578 @@
+//! Exit timing for paired positions.
+//!
+//! Once a YES+NO pair is ready to exit, selling both legs into the bids is
+//! not always the best use of it. For each pair the optimizer values three
+//! policies against the live books and picks the one worth the most:
+//! - **Sell now**: walk both bid ladders for the full quantity
+//! - **Passive**: rest a sell order at or inside each ask for a window, then
+//!   sell whatever is left into bids assumed to have moved against us
+//! - **Hold**: keep the pair until resolution pays out, discounted by the
+//!   cost of the capital it ties up
+//!
+//! The executor cannot merge a pair back into collateral, so holding is the
+//! only route to the full payout. Decisions and the value each exit went on
+//! to realize are stored in `exit_policy_decisions`; the dynamic tuner
+//! calibrates the passive fill probability from them.
+
+use async_trait::async_trait;
+use chrono::Duration;
+use polymarket_core::types::{OrderBook, Position, PositionFeeModel, PriceLevel};
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use crate::position_service::Leg;
+
+/// Configuration for the exit optimizer (env-var driven).
+#[derive(Debug, Clone)]
+pub struct ExitOptimizerConfig {
+    /// When false, exit-ready pairs are always sold immediately (legacy behavior).
+    pub enabled: bool,
+    /// How long passive exit orders rest before the remainder is sold.
+    pub passive_window_secs: u64,
+    /// Chance that a passive order with no queue ahead of it fills within the
+    /// window. Tuned from realized passive exits.
+    pub passive_fill_probability: f64,
+    /// How far the bids are assumed to fall while a passive order goes unfilled.
+    pub passive_adverse_move: Decimal,
+    /// Annual cost of the capital a held position ties up.
+    pub capital_cost_apr: f64,
+    /// Holding is only considered when the market resolves within this many days.
+    pub hold_max_days: f64,
+    /// Price increment used to step inside the ask.
+    pub tick_size: Decimal,
+    /// Fee rate on exit proceeds for legacy flat-fee positions.
+    pub legacy_fee_rate: Decimal,
+}
+
+impl Default for ExitOptimizerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            passive_window_secs: 300,
+            passive_fill_probability: 0.5,
+            passive_adverse_move: Decimal::new(1, 2),
+            capital_cost_apr: 0.10,
+            hold_max_days: 14.0,
+            tick_size: Decimal::new(1, 2),
+            legacy_fee_rate: Decimal::new(2, 2),
+        }
+    }
+}
+
+impl ExitOptimizerConfig {
+    /// Create config from environment variables.
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        Self {
+            enabled: polymarket_core::settings::get("EXIT_OPTIMIZER_ENABLED"),
+            passive_window_secs: polymarket_core::settings::get("EXIT_PASSIVE_WINDOW_SECS"),
+            passive_fill_probability: polymarket_core::settings::get("EXIT_PASSIVE_FILL_PROB"),
+            passive_adverse_move: polymarket_core::settings::get("EXIT_PASSIVE_ADVERSE_MOVE"),
+            capital_cost_apr: polymarket_core::settings::get("EXIT_CAPITAL_COST_APR"),
+            hold_max_days: polymarket_core::settings::get("EXIT_HOLD_MAX_DAYS"),
+            tick_size: defaults.tick_size,
+            legacy_fee_rate: defaults.legacy_fee_rate,
+        }
+    }
+}
+
+/// A position holding both legs, ready to exit.
+#[derive(Debug, Clone)]
+pub struct PairedExit {
+    pub position_id: Uuid,
+    pub market_id: String,
+    /// Shares of each leg to sell.
+    pub quantity: Decimal,
+    /// Fee taken from sale proceeds.
+    pub exit_fee_rate: Decimal,
+    /// What one pair pays at resolution, net of fees.
+    pub payout_per_pair: Decimal,
+}
+
+impl PairedExit {
+    pub fn from_position(position: &Position, legacy_fee_rate: Decimal) -> Self {
+        // Share-based positions paid their fees at entry.
+        let (exit_fee_rate, payout_per_pair) = match position.fee_model {
+            PositionFeeModel::LegacyFlat => (legacy_fee_rate, Decimal::ONE),
+            PositionFeeModel::ShareBased => (Decimal::ZERO, position.resolution_payout_per_share),
+        };
+        Self {
+            position_id: position.id,
+            market_id: position.market_id.clone(),
+            quantity: position.quantity,
+            exit_fee_rate,
+            payout_per_pair,
+        }
+    }
+}
+
+/// Live market inputs for one pair.
+#[derive(Debug, Clone)]
+pub struct ExitMarketView {
+    pub yes_book: OrderBook,
+    pub no_book: OrderBook,
+    /// `None` when the market has no known end date.
+    pub time_to_resolution: Option<Duration>,
+}
+
+/// Supplies live books and resolution timing to the optimizer.
+#[async_trait]
+pub trait ExitMarketSource {
+    async fn exit_market_view(&self, exit: &PairedExit) -> anyhow::Result<ExitMarketView>;
+}
+
+/// A passive sell order for one leg.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PassiveQuote {
+    pub leg: Leg,
+    pub price: Decimal,
+    /// Estimated chance the order fills within the window.
+    pub fill_probability: f64,
+}
+
+/// How to exit a pair.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExitPolicy {
+    /// Sell both legs into the bids.
+    SellNow,
+    /// Rest sell orders at these prices for the passive window.
+    Passive { yes: PassiveQuote, no: PassiveQuote },
+    /// Keep the pair and re-evaluate on the next pass.
+    Hold,
+}
+
+impl ExitPolicy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::SellNow => "sell_now",
+            Self::Passive { .. } => "passive",
+            Self::Hold => "hold",
+        }
+    }
+}
+
+/// Policy plus the valuations behind it.
+#[derive(Debug, Clone)]
+pub struct ExitDecision {
+    pub policy: ExitPolicy,
+    pub reason: String,
+    /// Expected net proceeds in USD of each policy; `None` when not executable.
+    pub sell_now_value: Option<Decimal>,
+    pub passive_value: Option<Decimal>,
+    pub hold_value: Option<Decimal>,
+    pub hours_to_resolution: Option<f64>,
+}
+
+impl ExitDecision {
+    /// Expected net proceeds of the chosen policy.
+    pub fn expected_value(&self) -> Option<Decimal> {
+        match self.policy {
+            ExitPolicy::SellNow => self.sell_now_value,
+            ExitPolicy::Passive { .. } => self.passive_value,
+            ExitPolicy::Hold => self.hold_value,
+        }
+    }
+
+    /// Joint chance that both passive orders fill, when passive was chosen.
+    pub fn passive_fill_probability(&self) -> Option<f64> {
+        match &self.policy {
+            ExitPolicy::Passive { yes, no } => Some(yes.fill_probability * no.fill_probability),
+            _ => None,
+        }
+    }
+}
+
+/// Values and chooses among sell-now / passive / hold for exit-ready pairs.
+pub struct ExitOptimizer {
+    config: ExitOptimizerConfig,
+}
+
+impl ExitOptimizer {
+    pub fn new(config: ExitOptimizerConfig) -> Self {
+        Self { config }
+    }
+
+    /// Fetch live inputs from `source` and decide.
+    pub async fn evaluate<S: ExitMarketSource + Sync>(
+        &self,
+        source: &S,
+        exit: &PairedExit,
+    ) -> anyhow::Result<ExitDecision> {
+        let view = source.exit_market_view(exit).await?;
+        Ok(self.decide(exit, &view))
+    }
+
+    /// Choose the policy with the highest expected net proceeds.
+    ///
+    /// Ties prefer selling now, then passive, since both free the capital
+    /// sooner than holding.
+    pub fn decide(&self, exit: &PairedExit, view: &ExitMarketView) -> ExitDecision {
+        let qty = exit.quantity;
+        let net = |gross: Decimal| gross * (Decimal::ONE - exit.exit_fee_rate);
+        let hours_to_resolution = view
+            .time_to_resolution
+            .map(|d| d.num_seconds() as f64 / 3600.0);
+
+        let mut notes = Vec::new();
+
+        let yes_bid = walk_levels(&view.yes_book.bids, qty).map(|(avg, _)| avg);
+        let no_bid = walk_levels(&view.no_book.bids, qty).map(|(avg, _)| avg);
+        let sell_now = match (yes_bid, no_bid) {
+            (Some(yes), Some(no)) => Some(net(qty * (yes + no))),
+            _ => {
+                notes.push("bids too thin to sell both legs".to_string());
+                None
+            }
+        };
+
+        // Whatever does not fill passively is sold at the end of the window,
+        // so passive needs bids as much as selling now does.
+        let passive = match (
+            yes_bid,
+            no_bid,
+            self.passive_quote(Leg::Yes, &view.yes_book, qty),
+            self.passive_quote(Leg::No, &view.no_book, qty),
+        ) {
+            (Some(yes_bid), Some(no_bid), Some(yes), Some(no)) => {
+                let gross = qty
+                    * (self.passive_leg_price(&yes, yes_bid) + self.passive_leg_price(&no, no_bid));
+                let window_years = self.config.passive_window_secs as f64 / SECS_PER_YEAR;
+                let carry = Decimal::from_f64(self.config.capital_cost_apr * window_years)
+                    .unwrap_or(Decimal::ZERO);
+                Some((net(gross) * (Decimal::ONE - carry), yes, no))
+            }
+            (Some(_), Some(_), _, _) => {
+                notes.push("no ask to rest a passive order behind".to_string());
+                None
+            }
+            _ => None,
+        };
+
+        let hold = match hours_to_resolution {
+            Some(hours) if hours <= self.config.hold_max_days * 24.0 => {
+                let years = hours.max(0.0) / (24.0 * 365.0);
+                let discount = Decimal::from_f64(1.0 + self.config.capital_cost_apr * years)
+                    .unwrap_or(Decimal::ONE);
+                Some(qty * exit.payout_per_pair / discount)
+            }
+            Some(_) => {
+                notes.push("resolution too far out to hold".to_string());
+                None
+            }
+            None => {
+                notes.push("no resolution date to hold against".to_string());
+                None
+            }
+        };
+
+        let passive_value = passive.as_ref().map(|(value, _, _)| *value);
+        let candidates = [
+            sell_now.map(|value| (value, ExitPolicy::SellNow)),
+            passive.map(|(value, yes, no)| (value, ExitPolicy::Passive { yes, no })),
+            hold.map(|value| (value, ExitPolicy::Hold)),
+        ];
+        let best = candidates.into_iter().flatten().fold(
+            None::<(Decimal, ExitPolicy)>,
+            |best, candidate| match best {
+                Some(current) if current.0 >= candidate.0 => Some(current),
+                _ => Some(candidate),
+            },
+        );
+
+        let (policy, reason) = match best {
+            Some((value, policy)) => {
+                let mut reason = format!(
+                    "{} has highest expected value {}",
+                    policy.as_str(),
+                    value.round_dp(4)
+                );
+                if !notes.is_empty() {
+                    reason.push_str(&format!(" ({})", notes.join("; ")));
+                }
+                (policy, reason)
+            }
+            None => (
+                ExitPolicy::Hold,
+                format!("no executable option: {}", notes.join("; ")),
+            ),
+        };
+
+        ExitDecision {
+            policy,
+            reason,
+            sell_now_value: sell_now,
+            passive_value,
+            hold_value: hold,
+            hours_to_resolution,
+        }
+    }
+
+    /// Step inside the ask when the spread allows it, otherwise join the ask
+    /// behind the size already resting there.
+    fn passive_quote(&self, leg: Leg, book: &OrderBook, quantity: Decimal) -> Option<PassiveQuote> {
+        let best_ask = book.best_ask()?;
+        let best_bid = book.best_bid().unwrap_or(Decimal::ZERO);
+        let inside = best_ask - self.config.tick_size;
+        let (price, queue_ahead) = if inside > best_bid {
+            (inside, Decimal::ZERO)
+        } else {
+            let resting = book
+                .asks
+                .iter()
+                .filter(|level| level.price == best_ask)
+                .map(|level| level.size)
+                .sum::<Decimal>();
+            (best_ask, resting)
+        };
+        let share = (quantity / (quantity + queue_ahead))
+            .to_f64()
+            .unwrap_or(0.0);
+        Some(PassiveQuote {
+            leg,
+            price,
+            fill_probability: (self.config.passive_fill_probability * share).clamp(0.0, 1.0),
+        })
+    }
+
+    /// Expected price per share of a passive leg: its own price if it fills,
+    /// else the bid after an adverse move.
+    fn passive_leg_price(&self, quote: &PassiveQuote, bid: Decimal) -> Decimal {
+        let p = Decimal::from_f64(quote.fill_probability).unwrap_or(Decimal::ZERO);
+        let fallback = (bid - self.config.passive_adverse_move).max(Decimal::ZERO);
+        p * quote.price + (Decimal::ONE - p) * fallback
+    }
+}
+
+const SECS_PER_YEAR: f64 = 365.0 * 24.0 * 3600.0;
+
+/// Average and worst price to fill `quantity` against `levels`, best first.
+///
+/// Returns `None` when the book cannot fill the full quantity.
+fn walk_levels(levels: &[PriceLevel], quantity: Decimal) -> Option<(Decimal, Decimal)> {
+    if quantity <= Decimal::ZERO {
+        return None;
+    }
+    let mut remaining = quantity;
+    let mut notional = Decimal::ZERO;
+    for level in levels {
+        if level.size <= Decimal::ZERO {
+            continue;
+        }
+        let take = remaining.min(level.size);
+        notional += take * level.price;
+        remaining -= take;
+        if remaining.is_zero() {
+            return Some((notional / quantity, level.price));
+        }
+    }
+    None
+}
+
+/// Store a decision for `exit` and return its id.
+pub async fn record_decision(
+    pool: &PgPool,
+    exit: &PairedExit,
+    decision: &ExitDecision,
+) -> sqlx::Result<Uuid> {
+    sqlx::query_scalar(
+        r#"
+        INSERT INTO exit_policy_decisions (
+            position_id, market_id, policy, reason, quantity,
+            sell_now_value, passive_value, hold_value, expected_value,
+            passive_fill_probability, hours_to_resolution
+        ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+        RETURNING id
+        "#,
+    )
+    .bind(exit.position_id)
+    .bind(&exit.market_id)
+    .bind(decision.policy.as_str())
+    .bind(&decision.reason)
+    .bind(exit.quantity)
+    .bind(decision.sell_now_value)
+    .bind(decision.passive_value)
+    .bind(decision.hold_value)
+    .bind(decision.expected_value())
+    .bind(decision.passive_fill_probability())
+    .bind(decision.hours_to_resolution)
+    .fetch_one(pool)
+    .await
+}
+
+/// Attach the realized outcome to a closed position's latest decision.
+///
+/// `realized_value` is net proceeds, comparable with the expected values.
+/// Positions closed without a decision are left alone.
+pub async fn record_outcome(
+    pool: &PgPool,
+    position_id: Uuid,
+    realized_via: &str,
+    realized_value: Decimal,
+) -> sqlx::Result<()> {
+    sqlx::query(
+        r#"
+        UPDATE exit_policy_decisions
+        SET realized_via = $2,
+            realized_value = $3,
+            realized_at = NOW()
+        WHERE id = (
+            SELECT id
+            FROM exit_policy_decisions
+            WHERE position_id = $1
+              AND realized_at IS NULL
+            ORDER BY decided_at DESC
+            LIMIT 1
+        )
+        "#,
+    )
+    .bind(position_id)
+    .bind(realized_via)
+    .bind(realized_value)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    struct MockBook {
+        view: ExitMarketView,
+    }
+
+    #[async_trait]
+    impl ExitMarketSource for MockBook {
+        async fn exit_market_view(&self, _exit: &PairedExit) -> anyhow::Result<ExitMarketView> {
+            Ok(self.view.clone())
+        }
+    }
+
+    fn book(bids: &[(i64, i64)], asks: &[(i64, i64)]) -> OrderBook {
+        let levels = |raw: &[(i64, i64)]| {
+            raw.iter()
+                .map(|&(price, size)| PriceLevel {
+                    price: Decimal::new(price, 2),
+                    size: Decimal::new(size, 0),
+                })
+                .collect()
+        };
+        OrderBook {
+            market_id: "m1".to_string(),
+            outcome_id: "token".to_string(),
+            timestamp: Utc::now(),
+            bids: levels(bids),
+            asks: levels(asks),
+        }
+    }
+
+    fn pair() -> PairedExit {
+        PairedExit {
+            position_id: Uuid::new_v4(),
+            market_id: "m1".to_string(),
+            quantity: Decimal::new(100, 0),
+            exit_fee_rate: Decimal::new(2, 2),
+            payout_per_pair: Decimal::ONE,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sells_now_into_tight_books() {
+        // One-tick spreads: a passive order can only join a deep ask queue.
+        let source = MockBook {
+            view: ExitMarketView {
+                yes_book: book(&[(55, 500)], &[(56, 500)]),
+                no_book: book(&[(43, 500)], &[(44, 500)]),
+                time_to_resolution: Some(Duration::days(30)),
+            },
+        };
+        let optimizer = ExitOptimizer::new(ExitOptimizerConfig::default());
+        let decision = optimizer.evaluate(&source, &pair()).await.unwrap();
+        assert_eq!(decision.policy, ExitPolicy::SellNow);
+        // 100 pairs at 0.98 less the 2% fee.
+        assert_eq!(decision.sell_now_value, Some(Decimal::new(9604, 2)));
+        assert!(decision.passive_value.unwrap() < decision.sell_now_value.unwrap());
+        assert!(decision.hold_value.is_none());
+        assert!(decision.reason.contains("too far out"));
+    }
+
+    #[test]
+    fn test_rests_inside_wide_spreads() {
+        let view = ExitMarketView {
+            yes_book: book(&[(50, 500)], &[(58, 500)]),
+            no_book: book(&[(38, 500)], &[(46, 500)]),
+            time_to_resolution: Some(Duration::days(30)),
+        };
+        let optimizer = ExitOptimizer::new(ExitOptimizerConfig::default());
+        let decision = optimizer.decide(&pair(), &view);
+        let ExitPolicy::Passive { yes, no } = &decision.policy else {
+            panic!("expected passive, got {:?}", decision.policy);
+        };
+        assert_eq!(yes.price, Decimal::new(57, 2));
+        assert_eq!(no.price, Decimal::new(45, 2));
+        assert!((yes.fill_probability - 0.5).abs() < 1e-9);
+        assert!((decision.passive_fill_probability().unwrap() - 0.25).abs() < 1e-9);
+        assert!(decision.expected_value() > decision.sell_now_value);
+
+        // A lower assumed fill rate makes waiting not worth the adverse move.
+        let pessimist = ExitOptimizer::new(ExitOptimizerConfig {
+            passive_fill_probability: 0.05,
+            ..ExitOptimizerConfig::default()
+        });
+        assert_eq!(pessimist.decide(&pair(), &view).policy, ExitPolicy::SellNow);
+    }
+
+    #[test]
+    fn test_holds_near_resolution_unless_capital_is_dear() {
+        let view = ExitMarketView {
+            yes_book: book(&[(55, 500)], &[(56, 500)]),
+            no_book: book(&[(43, 500)], &[(44, 500)]),
+            time_to_resolution: Some(Duration::days(2)),
+        };
+        let optimizer = ExitOptimizer::new(ExitOptimizerConfig::default());
+        let decision = optimizer.decide(&pair(), &view);
+        assert_eq!(decision.policy, ExitPolicy::Hold);
+        assert!(decision.hold_value.unwrap() > Decimal::new(99, 0));
+
+        // At 1000% a year, two days of capital cost more than the fee saved.
+        let dear = ExitOptimizer::new(ExitOptimizerConfig {
+            capital_cost_apr: 10.0,
+            ..ExitOptimizerConfig::default()
+        });
+        assert_eq!(dear.decide(&pair(), &view).policy, ExitPolicy::SellNow);
+
+        let empty = ExitMarketView {
+            yes_book: book(&[], &[]),
+            no_book: book(&[], &[]),
+            time_to_resolution: None,
+        };
+        let decision = optimizer.decide(&pair(), &empty);
+        assert_eq!(decision.policy, ExitPolicy::Hold);
+        assert!(decision.reason.starts_with("no executable option"));
+        assert!(decision.expected_value().is_none());
+    }
+
+    #[test]
+    fn test_share_based_pairs_sell_fee_free() {
+        let mut position = Position::new(
+            "m1".to_string(),
+            Decimal::new(48, 2),
+            Decimal::new(49, 2),
+            Decimal::new(10, 0),
+            polymarket_core::types::ExitStrategy::ExitOnCorrection,
+        );
+        let legacy = PairedExit::from_position(&position, Decimal::new(2, 2));
+        assert_eq!(legacy.exit_fee_rate, Decimal::new(2, 2));
+        assert_eq!(legacy.payout_per_pair, Decimal::ONE);
+
+        position.fee_model = PositionFeeModel::ShareBased;
+        position.resolution_payout_per_share = Decimal::new(995, 3);
+        let share_based = PairedExit::from_position(&position, Decimal::new(2, 2));
+        assert_eq!(share_based.exit_fee_rate, Decimal::ZERO);
+        assert_eq!(share_based.payout_per_pair, Decimal::new(995, 3));
+        assert_eq!(share_based.quantity, Decimal::new(10, 0));
+    }
+}
@@ -3,6 +3,11 @@
 //! Background task that aggregates `wallet_trades` into `market_flow_features`
 //! at multiple time windows (15min, 60min, 240min). Joins `bot_scores` to
 //! weight smart money flows (non-bot wallets with bot_score < 30).
+//!
+//! Trades newer than the latest harvested wallet trade are taken from
+//! `market_trade_prints` (recorded live by arb-monitor). Prints add volume and
+//! trade count only; they have no wallet, so they never count as buyers,
+//! sellers or smart money.
 
 use sqlx::PgPool;
 use std::sync::Arc;
@@ -27,18 +32,10 @@ impl FlowFeatureConfig {
     /// Load configuration from environment variables.
     pub fn from_env() -> Self {
         Self {
-            enabled: std::env::var("FLOW_FEATURE_ENABLED")
-                .map(|v| v == "true")
-                .unwrap_or(true),
-            interval_secs: std::env::var("FLOW_FEATURE_INTERVAL_SECS")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(300),
+            enabled: polymarket_core::settings::get("FLOW_FEATURE_ENABLED"),
+            interval_secs: polymarket_core::settings::get("FLOW_FEATURE_INTERVAL_SECS"),
             windows: vec![15, 60, 240],
-            smart_money_threshold: std::env::var("SMART_MONEY_BOT_THRESHOLD")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(30),
+            smart_money_threshold: polymarket_core::settings::get("SMART_MONEY_BOT_THRESHOLD"),
         }
     }
 }
@@ -111,17 +108,35 @@ async fn compute_cycle(
         // - Counts unique buyers and sellers
         let rows = sqlx::query_as::<_, FlowFeatureRow>(
             r#"
-            WITH recent_trades AS (
+            WITH harvested_trades AS (
                 SELECT
                     condition_id,
                     wallet_address,
                     side,
-                    value
+                    value,
+                    timestamp
                 FROM wallet_trades
                 WHERE condition_id IS NOT NULL
                   AND timestamp >= $4
                   AND timestamp <= $1
             ),
+            harvested_until AS (
+                SELECT condition_id, MAX(timestamp) AS last_harvested
+                FROM harvested_trades
+                GROUP BY condition_id
+            ),
+            recent_trades AS (
+                SELECT condition_id, wallet_address, side, value
+                FROM harvested_trades
+                UNION ALL
+                -- Prints fill in each market only past its last harvested trade
+                SELECT p.condition_id, NULL::varchar AS wallet_address, p.side, p.value
+                FROM market_trade_prints p
+                LEFT JOIN harvested_until h ON h.condition_id = p.condition_id
+                WHERE p.timestamp >= $4
+                  AND p.timestamp <= $1
+                  AND p.timestamp > COALESCE(h.last_harvested, '-infinity'::timestamptz)
+            ),
             latest_bot_scores AS (
                 SELECT DISTINCT ON (bs.address)
                     bs.address,
@@ -156,7 +171,8 @@ async fn compute_cycle(
                 COALESCE(
                     SUM(
                         CASE
-                            WHEN bs.total_score IS NULL OR bs.total_score < $3
+                            WHEN rt.wallet_address IS NOT NULL
+                             AND (bs.total_score IS NULL OR bs.total_score < $3)
                             THEN CASE WHEN rt.side = 'BUY' THEN rt.value ELSE -rt.value END
                             ELSE 0
                         END
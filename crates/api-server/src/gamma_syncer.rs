@@ -26,17 +26,9 @@ impl GammaSyncerConfig {
     /// Load configuration from environment variables.
     pub fn from_env() -> Self {
         Self {
-            enabled: std::env::var("GAMMA_SYNCER_ENABLED")
-                .map(|v| v == "true")
-                .unwrap_or(true),
-            interval_secs: std::env::var("GAMMA_SYNCER_INTERVAL_SECS")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(3600),
-            page_size: std::env::var("GAMMA_SYNCER_PAGE_SIZE")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(100),
+            enabled: polymarket_core::settings::get("GAMMA_SYNCER_ENABLED"),
+            interval_secs: polymarket_core::settings::get("GAMMA_SYNCER_INTERVAL_SECS"),
+            page_size: polymarket_core::settings::get("GAMMA_SYNCER_PAGE_SIZE"),
         }
     }
 }
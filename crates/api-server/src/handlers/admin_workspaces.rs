@@ -343,7 +343,7 @@ pub async fn create_workspace(
         // Try to send the invite email BEFORE committing
         let invite_link = format!(
             "{}/invite/{}",
-            std::env::var("DASHBOARD_URL").unwrap_or_else(|_| "http://localhost:3002".to_string()),
+            polymarket_core::settings::get::<String>("DASHBOARD_URL"),
             token
         );
 
@@ -619,7 +619,9 @@ pub async fn forgot_password(
     } else {
         tracing::warn!("Email client not configured, password reset token generated but not sent");
         // In development, log the token for testing
-        if std::env::var("ENVIRONMENT").unwrap_or_default() == "development" {
+        if polymarket_core::settings::get_opt::<String>("ENVIRONMENT").as_deref()
+            == Some("development")
+        {
             tracing::info!(token = %token, "Development mode: password reset token");
         }
     }
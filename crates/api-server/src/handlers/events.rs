@@ -0,0 +1,155 @@
+//! External event API handlers.
+//!
+//! Receives events from external systems over a shared-secret webhook and
+//! lists recently linked events with their impact on each market.
+
+use axum::extract::{Query, State};
+use axum::http::HeaderMap;
+use axum::Json;
+use chrono::{Duration, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+use utoipa::{IntoParams, ToSchema};
+
+use crate::error::{ApiError, ApiResult};
+use crate::events::{
+    ingest_events, recent_impacts, EventImpact, EventPayload, EventSourceKind, IngestedEvent,
+};
+use crate::state::AppState;
+
+/// Header carrying the webhook's shared secret.
+pub const EVENT_SECRET_HEADER: &str = "x-event-secret";
+
+/// Result of posting an event to the webhook.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EventWebhookResponse {
+    /// False when the source already sent an event with this id.
+    pub stored: bool,
+    pub event: Option<IngestedEvent>,
+}
+
+/// POST /api/v1/events/webhook — Ingest one external event.
+///
+/// Authenticated by the `X-Event-Secret` header rather than a user session;
+/// disabled unless `EVENT_WEBHOOK_SECRET` is set.
+#[utoipa::path(
+    post,
+    path = "/api/v1/events/webhook",
+    tag = "events",
+    request_body = EventPayload,
+    responses(
+        (status = 200, description = "Event stored and linked, or already known", body = EventWebhookResponse),
+        (status = 400, description = "Invalid event"),
+        (status = 401, description = "Missing or wrong secret"),
+        (status = 404, description = "Webhook not configured")
+    )
+)]
+pub async fn receive_event_webhook(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Json(payload): Json<EventPayload>,
+) -> ApiResult<Json<EventWebhookResponse>> {
+    let config = &state.event_ingest;
+    let Some(secret) = config.webhook_secret.as_deref() else {
+        return Err(ApiError::NotFound("Event webhook is not configured".into()));
+    };
+    let given = headers
+        .get(EVENT_SECRET_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default();
+    if !secret_matches(secret, given) {
+        return Err(ApiError::Unauthorized(
+            "Invalid event webhook secret".into(),
+        ));
+    }
+    if payload.title.trim().is_empty() {
+        return Err(ApiError::BadRequest("title must not be empty".into()));
+    }
+    if payload
+        .sentiment
+        .is_some_and(|s| !(-1.0..=1.0).contains(&s))
+    {
+        return Err(ApiError::BadRequest("sentiment must be in [-1, 1]".into()));
+    }
+
+    let event = payload.into_event("webhook", EventSourceKind::Webhook, Utc::now());
+    let matcher = state
+        .event_matcher
+        .get(&state.pool)
+        .await
+        .map_err(|e| ApiError::Internal(format!("DB error: {}", e)))?;
+    let ingested = ingest_events(&state.pool, &matcher, vec![event])
+        .await
+        .map_err(|e| ApiError::Internal(format!("DB error: {}", e)))?;
+
+    let event = ingested.into_iter().next();
+    Ok(Json(EventWebhookResponse {
+        stored: event.is_some(),
+        event,
+    }))
+}
+
+/// Compare secrets through their digests, so the comparison takes the same
+/// time whatever the input.
+fn secret_matches(expected: &str, given: &str) -> bool {
+    let expected = Sha256::digest(expected.as_bytes());
+    let given = Sha256::digest(given.as_bytes());
+    expected
+        .iter()
+        .zip(given.iter())
+        .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+        == 0
+}
+
+/// Query parameters for the events endpoint.
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct EventsQuery {
+    /// Only events linked to this market (optional).
+    pub condition_id: Option<String>,
+    /// Lookback in hours (default: 24).
+    pub hours: Option<i64>,
+    /// Limit results (default: 100).
+    pub limit: Option<i64>,
+}
+
+/// GET /api/v1/events — Recent linked events and their impact.
+#[utoipa::path(
+    get,
+    path = "/api/v1/events",
+    tag = "events",
+    params(EventsQuery),
+    responses(
+        (status = 200, description = "Linked events, newest first", body = Vec<EventImpact>)
+    )
+)]
+pub async fn list_events(
+    State(state): State<Arc<AppState>>,
+    Query(params): Query<EventsQuery>,
+) -> ApiResult<Json<Vec<EventImpact>>> {
+    let now = Utc::now();
+    let hours = params.hours.unwrap_or(24).clamp(1, 24 * 30);
+    let impacts = recent_impacts(
+        &state.pool,
+        params.condition_id.as_deref(),
+        now - Duration::hours(hours),
+        Duration::minutes(state.event_ingest.impact_half_life_mins),
+        now,
+        params.limit.unwrap_or(100).clamp(1, 500),
+    )
+    .await
+    .map_err(|e| ApiError::Internal(format!("DB error: {}", e)))?;
+    Ok(Json(impacts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_secret_matches() {
+        assert!(secret_matches("s3cret", "s3cret"));
+        assert!(!secret_matches("s3cret", "s3cret "));
+        assert!(!secret_matches("s3cret", ""));
+    }
+}
@@ -0,0 +1,163 @@
+//! Prometheus metrics.
+//!
+//! By default metrics are served only on the internal listener
+//! (`METRICS_PORT`, default 9100), which is not published with the API port.
+//! The public `/metrics` route answers only when `METRICS_TOKEN` is set, and
+//! then requires it as a bearer token.
+
+use axum::extract::State;
+use axum::http::{header, HeaderMap};
+use axum::response::IntoResponse;
+use polymarket_core::metrics::{self, MetricsServerConfig, MirroredCounterVec};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use std::sync::{Arc, LazyLock};
+use std::time::Duration;
+use tracing::{info, warn};
+
+use crate::arb_executor::ArbExecutorRuntimeStatus;
+use crate::error::{ApiError, ApiResult};
+use crate::state::AppState;
+
+/// Arb executor outcomes, copied from [`ArbExecutorRuntimeStatus`] on scrape.
+static ARB_EXECUTOR_DECISIONS: LazyLock<MirroredCounterVec> = LazyLock::new(|| {
+    let counter = MirroredCounterVec::new(
+        prometheus::Opts::new(
+            "arb_executor_decisions_total",
+            "Arb executor decisions by outcome",
+        )
+        .namespace("abbot"),
+        &["decision"],
+    )
+    .expect("metric definition is valid");
+    if let Err(e) = metrics::global()
+        .registry
+        .register(Box::new(counter.clone()))
+    {
+        warn!(error = %e, "Failed to register arb executor metrics");
+    }
+    counter
+});
+
+fn sync_arb_executor(status: &ArbExecutorRuntimeStatus) {
+    for (decision, total) in [
+        ("signal", status.signals_seen),
+        ("lagged", status.lagged_signals),
+        ("executed", status.executed),
+        ("execution_failure", status.execution_failures),
+        ("skip_disabled", status.disabled_skips),
+        ("skip_stale", status.stale_skips),
+        ("skip_min_profit", status.min_profit_skips),
+        ("skip_active_position", status.active_position_skips),
+        ("skip_circuit_breaker", status.circuit_breaker_skips),
+        ("skip_token_lookup", status.token_lookup_skips),
+        ("skip_depth", status.depth_skips),
+        ("skip_zero_cost", status.zero_cost_skips),
+    ] {
+        ARB_EXECUTOR_DECISIONS.set(&[decision], total);
+    }
+}
+
+/// Internal listener port when `METRICS_PORT` is unset.
+const DEFAULT_METRICS_PORT: u16 = 9100;
+/// How often the internal listener's sampled values are refreshed.
+const REFRESH_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Serve metrics on the internal listener, refreshing sampled values in the
+/// background since the listener cannot query the database per scrape.
+pub fn spawn_metrics_listener(state: Arc<AppState>) {
+    let config = MetricsServerConfig::from_env(DEFAULT_METRICS_PORT);
+    if !config.enabled {
+        info!("Metrics listener disabled (METRICS_ENABLED=false)");
+        return;
+    }
+    metrics::spawn_metrics_server(config, || {});
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(REFRESH_INTERVAL);
+        loop {
+            interval.tick().await;
+            refresh(&state).await;
+        }
+    });
+}
+
+/// Sample the values api-server does not record as they happen.
+async fn refresh(state: &AppState) {
+    let registry = metrics::global();
+    registry
+        .circuit_breaker_tripped
+        .set(i64::from(state.circuit_breaker.is_tripped()));
+    metrics::observe_db_pool(&state.pool);
+    match load_open_exposure(&state.pool).await {
+        Ok(exposure) => registry
+            .open_exposure_usd
+            .set(exposure.to_f64().unwrap_or(0.0)),
+        Err(e) => warn!(error = %e, "Failed to load open exposure for metrics"),
+    }
+    sync_arb_executor(&*state.arb_executor_status.read().await);
+}
+
+/// Capital committed to non-closed positions and market-maker inventory;
+/// pending entries reserve their full requested quantity.
+async fn load_open_exposure(pool: &sqlx::PgPool) -> sqlx::Result<Decimal> {
+    sqlx::query_scalar(
+        r#"
+        SELECT
+            COALESCE((
+                SELECT SUM(
+                    CASE WHEN state = 0 AND yes_entry_price > 0 THEN quantity ELSE held_yes_qty END
+                        * yes_entry_price
+                    + CASE WHEN state = 0 AND no_entry_price > 0 THEN quantity ELSE held_no_qty END
+                        * no_entry_price
+                )
+                FROM positions
+                WHERE state IN (0, 1, 2, 3)
+            ), 0)
+            + COALESCE((
+                SELECT SUM(
+                    CASE WHEN position >= 0 THEN position * avg_price
+                         ELSE -position * (1 - avg_price) END
+                )::numeric
+                FROM mm_inventory
+            ), 0)
+        "#,
+    )
+    .fetch_one(pool)
+    .await
+}
+
+/// Prometheus scrape endpoint.
+///
+/// Not found unless `METRICS_TOKEN` is set; then requires it as a bearer token.
+#[utoipa::path(
+    get,
+    path = "/metrics",
+    tag = "health",
+    responses(
+        (status = 200, description = "Metrics in the Prometheus text format", body = String, content_type = "text/plain"),
+        (status = 401, description = "Missing or wrong METRICS_TOKEN"),
+        (status = 404, description = "METRICS_TOKEN is not set; use the internal listener")
+    )
+)]
+pub async fn prometheus_metrics(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+) -> ApiResult<impl IntoResponse> {
+    let token = polymarket_core::settings::get_opt::<String>("METRICS_TOKEN")
+        .filter(|token| !token.is_empty())
+        .ok_or_else(|| ApiError::NotFound("Metrics are served on the internal listener".into()))?;
+    let presented = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "));
+    if presented != Some(token.as_str()) {
+        return Err(ApiError::Unauthorized("Invalid metrics token".into()));
+    }
+
+    refresh(&state).await;
+
+    Ok((
+        [(header::CONTENT_TYPE, metrics::content_type())],
+        metrics::render(),
+    ))
+}
@@ -7,8 +7,10 @@ pub mod auth;
 pub mod backtest;
 
 pub mod discover;
+pub mod events;
 pub mod health;
 pub mod markets;
+pub mod metrics;
 pub mod order_signing;
 pub mod positions;
 pub mod recommendations;
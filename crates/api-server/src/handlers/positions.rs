@@ -15,7 +15,7 @@ use utoipa::{IntoParams, ToSchema};
 use uuid::Uuid;
 
 use crate::error::{ApiError, ApiResult};
-use crate::position_service::EventContext;
+use crate::position_service::{EventContext, ManualCloseError};
 use crate::state::AppState;
 
 use crate::websocket::{PositionUpdate, PositionUpdateType};
@@ -241,7 +241,8 @@ fn position_state_name(state: i16) -> &'static str {
     }
 }
 
-fn position_state_label(state: PositionState) -> &'static str {
+/// API label for a position lifecycle state.
+pub fn position_state_label(state: PositionState) -> &'static str {
     match state {
         PositionState::Pending => "pending",
         PositionState::Open => "open",
@@ -254,7 +255,8 @@ fn position_state_label(state: PositionState) -> &'static str {
     }
 }
 
-fn trade_event_labels(source: i16) -> (&'static str, &'static str) {
+/// Strategy and source labels recorded on trade events for a position source.
+pub fn trade_event_labels(source: i16) -> (&'static str, &'static str) {
     match source {
         SOURCE_ARBITRAGE => ("arb", "arb"),
         SOURCE_COPY_TRADE => ("copy_trade", "copy_trade"),
@@ -687,93 +689,17 @@ pub async fn close_position(
         source_label: source_label.to_string(),
     };
 
-    match position.state {
-        PositionState::Open => {
-            state
-                .position_service
-                .mark_exit_ready(&mut position, "manual_close_request", &ctx)
-                .await
-                .map_err(|e| ApiError::BadRequest(e.to_string()))?;
-        }
-        PositionState::ExitReady | PositionState::Closing => {
-            // Already in exit flow — just touch the timestamp and persist
-            position.touch();
-            state
-                .position_service
-                .repo()
-                .update(&position)
-                .await
-                .map_err(|e| ApiError::Internal(e.to_string()))?;
-        }
-        PositionState::ExitFailed => {
-            let recovered = state
-                .position_service
-                .attempt_exit_recovery(&mut position, &ctx)
-                .await
-                .map_err(|e| ApiError::Internal(e.to_string()))?;
-            if !recovered {
-                return Err(ApiError::BadRequest(
-                    "Position has exhausted exit retries and cannot be re-queued".to_string(),
-                ));
-            }
-        }
-        PositionState::Stalled => {
-            let recovered_state = state
-                .position_service
-                .attempt_stalled_recovery(&mut position, &ctx)
-                .await
-                .map_err(|e| ApiError::Internal(e.to_string()))?;
-            match recovered_state {
-                Some(PositionState::Open) => {
-                    state
-                        .position_service
-                        .mark_exit_ready(&mut position, "manual_close_after_stall_recovery", &ctx)
-                        .await
-                        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
-                }
-                Some(PositionState::ExitReady | PositionState::Closing) => {}
-                Some(state) => {
-                    return Err(ApiError::BadRequest(format!(
-                        "Position recovered to {} and cannot be manually closed yet",
-                        position_state_label(state)
-                    )))
-                }
-                None => {
-                    return Err(ApiError::BadRequest(
-                        "Position could not be recovered for manual close".to_string(),
-                    ))
-                }
+    state
+        .position_service
+        .queue_manual_close(&mut position, &ctx)
+        .await
+        .map_err(|e| match e {
+            ManualCloseError::NotOpen => {
+                ApiError::NotFound(format!("Open position {} not found", position_id))
             }
-        }
-        PositionState::EntryFailed if position.is_one_legged_entry_fail() => {
-            state
-                .position_service
-                .transition_one_legged_to_exit_ready(
-                    &mut position,
-                    "yes",
-                    "manual_close_one_legged",
-                    &ctx,
-                )
-                .await
-                .map_err(|e| ApiError::BadRequest(e.to_string()))?;
-        }
-        PositionState::Pending => {
-            return Err(ApiError::BadRequest(
-                "Position is still entering and cannot be manually closed yet".to_string(),
-            ))
-        }
-        PositionState::EntryFailed => {
-            return Err(ApiError::BadRequest(
-                "Position failed to enter and has no exit flow to queue".to_string(),
-            ))
-        }
-        PositionState::Closed => {
-            return Err(ApiError::NotFound(format!(
-                "Open position {} not found",
-                position_id
-            )))
-        }
-    }
+            ManualCloseError::Rejected(message) => ApiError::BadRequest(message),
+            ManualCloseError::Internal(e) => ApiError::Internal(e.to_string()),
+        })?;
 
     // Publish position update via WebSocket
     let update = PositionUpdate {
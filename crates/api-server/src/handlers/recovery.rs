@@ -4,6 +4,7 @@ use axum::extract::{Path, State};
 use axum::Extension;
 use axum::Json;
 use chrono::{DateTime, Utc};
+use polymarket_core::db::inventory::{WalletInventoryRepository, WalletInventorySummary};
 use polymarket_core::types::{FailureReason, PositionState};
 use rust_decimal::Decimal;
 use serde::Serialize;
@@ -20,8 +21,8 @@ use crate::position_service::EventContext;
 use crate::runtime_sync;
 use crate::state::AppState;
 use crate::wallet_inventory::{
-    load_canonical_inventory_summary, load_canonical_orphan_inventory,
     recover_canonical_orphan_inventory, refresh_canonical_wallet_inventory,
+    resolve_canonical_wallet_address,
 };
 use crate::workspace_scope::resolve_canonical_workspace_membership;
 
@@ -31,6 +32,36 @@ pub struct RecoveryBucketSummary {
     pub marked_value: Decimal,
 }
 
+/// Recovery buckets behind a preview; see [`load_recovery_buckets`].
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct RecoveryBuckets {
+    pub recoverable_now: RecoveryBucketSummary,
+    pub liquidity_blocked: RecoveryBucketSummary,
+    pub orphan_inventory: RecoveryBucketSummary,
+    pub stalled: RecoveryBucketSummary,
+    pub suspect_inventory: RecoveryBucketSummary,
+    pub open_monitoring: RecoveryBucketSummary,
+    pub other_blocked: RecoveryBucketSummary,
+}
+
+impl RecoveryBuckets {
+    /// Everything a safe recovery run can act on; orphaned and suspect
+    /// inventory need manual review.
+    pub fn safe_recovery(&self) -> RecoveryBucketSummary {
+        let parts = [
+            &self.recoverable_now,
+            &self.liquidity_blocked,
+            &self.stalled,
+            &self.open_monitoring,
+            &self.other_blocked,
+        ];
+        RecoveryBucketSummary {
+            positions: parts.iter().map(|b| b.positions).sum(),
+            marked_value: parts.iter().map(|b| b.marked_value).sum(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct RecoveryPreviewResponse {
     pub workspace_id: String,
@@ -278,74 +309,23 @@ async fn build_recovery_preview(
     }
 
     let runtime = runtime_snapshot(state, workspace_id).await?;
-    let inventory_summary = load_canonical_inventory_summary(state)
+    let wallet_address = resolve_canonical_wallet_address(state)
         .await
         .map_err(map_anyhow)?;
-    let mut recoverable_now = RecoveryBucketSummary::default();
-    let mut liquidity_blocked = RecoveryBucketSummary::default();
-    let orphan_inventory = RecoveryBucketSummary {
-        positions: inventory_summary
-            .as_ref()
-            .map(|summary| summary.orphan_positions)
-            .unwrap_or(0),
-        marked_value: inventory_summary
-            .as_ref()
-            .map(|summary| summary.orphan_marked_value)
-            .unwrap_or(Decimal::ZERO),
-    };
-    let mut stalled = RecoveryBucketSummary::default();
-    let mut suspect_inventory = RecoveryBucketSummary::default();
-    let mut open_monitoring = RecoveryBucketSummary::default();
-    let mut other_blocked = RecoveryBucketSummary::default();
-
-    for row in load_effective_positions(&state.pool).await? {
-        let marked_value = effective_marked_value(&row);
-        match row.state {
-            2 | 3 => accumulate(&mut recoverable_now, marked_value),
-            7 => accumulate(&mut stalled, marked_value),
-            6 => match classify_failure_reason(row.failure_reason.as_ref()) {
-                FailureBucket::SuspectInventory => accumulate(&mut suspect_inventory, marked_value),
-                FailureBucket::LiquidityBlocked => accumulate(&mut liquidity_blocked, marked_value),
-                FailureBucket::SafeRetry | FailureBucket::Other => {
-                    accumulate(&mut other_blocked, marked_value)
-                }
-            },
-            1 => accumulate(&mut open_monitoring, marked_value),
-            _ => accumulate(&mut other_blocked, marked_value),
-        }
-    }
-
-    for entry in load_canonical_orphan_inventory(state)
-        .await
-        .map_err(map_anyhow)?
-    {
-        let marked_value = entry
-            .marked_value
-            .or(entry.cost_basis)
-            .unwrap_or(Decimal::ZERO);
-        match entry.last_exit_error.as_deref() {
-            Some(message) => match classify_failure_message(message) {
-                FailureBucket::SuspectInventory => accumulate(&mut suspect_inventory, marked_value),
-                FailureBucket::LiquidityBlocked => accumulate(&mut liquidity_blocked, marked_value),
-                FailureBucket::SafeRetry => accumulate(&mut recoverable_now, marked_value),
-                FailureBucket::Other => accumulate(&mut other_blocked, marked_value),
-            },
-            None => accumulate(&mut suspect_inventory, marked_value),
-        }
-    }
-
-    let safe_recovery = RecoveryBucketSummary {
-        positions: recoverable_now.positions
-            + liquidity_blocked.positions
-            + stalled.positions
-            + open_monitoring.positions
-            + other_blocked.positions,
-        marked_value: recoverable_now.marked_value
-            + liquidity_blocked.marked_value
-            + stalled.marked_value
-            + open_monitoring.marked_value
-            + other_blocked.marked_value,
-    };
+    let (buckets, inventory_summary) =
+        load_recovery_buckets(&state.pool, wallet_address.as_deref())
+            .await
+            .map_err(map_anyhow)?;
+    let safe_recovery = buckets.safe_recovery();
+    let RecoveryBuckets {
+        recoverable_now,
+        liquidity_blocked,
+        orphan_inventory,
+        stalled,
+        suspect_inventory,
+        open_monitoring,
+        other_blocked,
+    } = buckets;
 
     Ok(RecoveryPreviewResponse {
         workspace_id: workspace_id.to_string(),
@@ -383,6 +363,74 @@ async fn build_recovery_preview(
     })
 }
 
+/// Open positions and orphaned wallet inventory, bucketed by how they can be
+/// recovered. Reads only the database, so it also serves `abbot recovery
+/// preview` while api-server is down.
+pub async fn load_recovery_buckets(
+    pool: &sqlx::PgPool,
+    wallet_address: Option<&str>,
+) -> anyhow::Result<(RecoveryBuckets, Option<WalletInventorySummary>)> {
+    let (inventory_summary, orphans) = match wallet_address {
+        Some(wallet_address) => {
+            let repo = WalletInventoryRepository::new(pool.clone());
+            (
+                Some(repo.summarize_wallet(wallet_address).await?),
+                repo.get_recoverable_orphans(wallet_address, 0).await?,
+            )
+        }
+        None => (None, Vec::new()),
+    };
+
+    let mut buckets = RecoveryBuckets {
+        orphan_inventory: RecoveryBucketSummary {
+            positions: inventory_summary
+                .as_ref()
+                .map(|summary| summary.orphan_positions)
+                .unwrap_or(0),
+            marked_value: inventory_summary
+                .as_ref()
+                .map(|summary| summary.orphan_marked_value)
+                .unwrap_or(Decimal::ZERO),
+        },
+        ..RecoveryBuckets::default()
+    };
+
+    for row in load_effective_positions(pool).await? {
+        let marked_value = effective_marked_value(&row);
+        let bucket = match row.state {
+            2 | 3 => &mut buckets.recoverable_now,
+            7 => &mut buckets.stalled,
+            6 => match classify_failure_reason(row.failure_reason.as_ref()) {
+                FailureBucket::SuspectInventory => &mut buckets.suspect_inventory,
+                FailureBucket::LiquidityBlocked => &mut buckets.liquidity_blocked,
+                FailureBucket::SafeRetry | FailureBucket::Other => &mut buckets.other_blocked,
+            },
+            1 => &mut buckets.open_monitoring,
+            _ => &mut buckets.other_blocked,
+        };
+        accumulate(bucket, marked_value);
+    }
+
+    for entry in orphans {
+        let marked_value = entry
+            .marked_value
+            .or(entry.cost_basis)
+            .unwrap_or(Decimal::ZERO);
+        let bucket = match entry.last_exit_error.as_deref() {
+            Some(message) => match classify_failure_message(message) {
+                FailureBucket::SuspectInventory => &mut buckets.suspect_inventory,
+                FailureBucket::LiquidityBlocked => &mut buckets.liquidity_blocked,
+                FailureBucket::SafeRetry => &mut buckets.recoverable_now,
+                FailureBucket::Other => &mut buckets.other_blocked,
+            },
+            None => &mut buckets.suspect_inventory,
+        };
+        accumulate(bucket, marked_value);
+    }
+
+    Ok((buckets, inventory_summary))
+}
+
 async fn runtime_snapshot(state: &AppState, workspace_id: Uuid) -> ApiResult<RuntimeSnapshot> {
     let flags = sqlx::query_as::<_, WorkspaceRecoveryFlags>(
         r#"
@@ -435,7 +483,9 @@ async fn ensure_workspace_runtime_enabled(
     Ok(())
 }
 
-async fn load_effective_positions(pool: &sqlx::PgPool) -> ApiResult<Vec<EffectivePositionRow>> {
+async fn load_effective_positions(
+    pool: &sqlx::PgPool,
+) -> anyhow::Result<Vec<EffectivePositionRow>> {
     #[derive(Debug, FromRow)]
     struct EffectivePositionRowDb {
         state: i16,
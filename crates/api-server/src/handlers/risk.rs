@@ -10,13 +10,14 @@ use std::sync::Arc;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
-use auth::Claims;
+use auth::{AuditAction, AuditEvent, Claims};
 
 use crate::error::{ApiError, ApiResult};
+use crate::risk_limits::{self, RiskLimitsDocument};
 use crate::state::AppState;
 use crate::workspace_scope::resolve_canonical_workspace_membership;
 
-async fn require_canonical_workspace_member(
+pub(crate) async fn require_canonical_workspace_member(
     pool: &sqlx::PgPool,
     user_id: Uuid,
 ) -> ApiResult<(Uuid, String)> {
@@ -26,7 +27,7 @@ async fn require_canonical_workspace_member(
         .ok_or_else(|| ApiError::Forbidden("Not a member of the canonical workspace".into()))
 }
 
-fn can_manage_risk(role: &str) -> bool {
+pub(crate) fn can_manage_risk(role: &str) -> bool {
     role == "owner" || role == "admin"
 }
 
@@ -439,3 +440,494 @@ pub async fn reset_circuit_breaker(
         },
     }))
 }
+
+/// Alerts awaiting acknowledgement before they escalate.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PendingAlertsResponse {
+    pub dedup_keys: Vec<String>,
+}
+
+/// Request to acknowledge an alert.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct AcknowledgeAlertRequest {
+    pub dedup_key: String,
+}
+
+/// List alerts that will escalate unless acknowledged.
+#[utoipa::path(
+    get,
+    path = "/api/v1/workspaces/{workspace_id}/risk/alerts/pending",
+    params(
+        ("workspace_id" = String, Path, description = "Workspace ID")
+    ),
+    responses(
+        (status = 200, description = "Pending alerts", body = PendingAlertsResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not a member"),
+    ),
+    security(("bearer_auth" = [])),
+    tag = "risk"
+)]
+pub async fn list_pending_alerts(
+    State(state): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+    Path(_workspace_id): Path<String>,
+) -> ApiResult<Json<PendingAlertsResponse>> {
+    let user_id =
+        Uuid::parse_str(&claims.sub).map_err(|_| ApiError::Internal("Invalid user ID".into()))?;
+    require_canonical_workspace_member(&state.pool, user_id).await?;
+
+    let dedup_keys = state.alert_router.pending_acknowledgements().await;
+    Ok(Json(PendingAlertsResponse { dedup_keys }))
+}
+
+/// Acknowledge an alert so it stops escalating.
+#[utoipa::path(
+    post,
+    path = "/api/v1/workspaces/{workspace_id}/risk/alerts/ack",
+    params(
+        ("workspace_id" = String, Path, description = "Workspace ID")
+    ),
+    request_body = AcknowledgeAlertRequest,
+    responses(
+        (status = 200, description = "Alert acknowledged", body = PendingAlertsResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not a member"),
+        (status = 404, description = "No pending alert with this key"),
+    ),
+    security(("bearer_auth" = [])),
+    tag = "risk"
+)]
+pub async fn acknowledge_alert(
+    State(state): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+    Path(_workspace_id): Path<String>,
+    Json(request): Json<AcknowledgeAlertRequest>,
+) -> ApiResult<Json<PendingAlertsResponse>> {
+    let user_id =
+        Uuid::parse_str(&claims.sub).map_err(|_| ApiError::Internal("Invalid user ID".into()))?;
+    require_canonical_workspace_member(&state.pool, user_id).await?;
+
+    if !state.alert_router.acknowledge(&request.dedup_key).await {
+        return Err(ApiError::NotFound(format!(
+            "No pending alert for {}",
+            request.dedup_key
+        )));
+    }
+    tracing::info!(user_id = %user_id, dedup_key = %request.dedup_key, "Alert acknowledged");
+
+    let dedup_keys = state.alert_router.pending_acknowledgements().await;
+    Ok(Json(PendingAlertsResponse { dedup_keys }))
+}
+
+/// A stored version of the workspace risk limits.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RiskLimitsVersionResponse {
+    pub version: i32,
+    /// `active`, `pending_approval`, `superseded` or `rejected`.
+    pub status: String,
+    pub limits: RiskLimitsDocument,
+    /// Fields that allow more risk than the version this was proposed against.
+    pub loosened_fields: Vec<String>,
+    /// Active version at proposal time (`None` = env defaults).
+    pub base_version: Option<i32>,
+    pub reason: Option<String>,
+    pub proposed_by: Uuid,
+    pub reviewed_by: Option<Uuid>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Risk limits currently in effect plus any changes awaiting approval.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct RiskLimitsResponse {
+    /// Active version, or `None` while the env defaults are in effect.
+    pub active_version: Option<i32>,
+    pub limits: RiskLimitsDocument,
+    pub pending: Vec<RiskLimitsVersionResponse>,
+}
+
+/// Request to store a new risk-limits version.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateRiskLimitsRequest {
+    pub limits: RiskLimitsDocument,
+    pub reason: Option<String>,
+}
+
+fn risk_limits_version_response(
+    row: &risk_limits::RiskLimitsVersionRow,
+) -> ApiResult<RiskLimitsVersionResponse> {
+    let limits = row
+        .document()
+        .map_err(|e| ApiError::Internal(format!("Corrupt risk limits v{}: {e}", row.version)))?;
+    Ok(RiskLimitsVersionResponse {
+        version: row.version,
+        status: row.status.clone(),
+        limits,
+        loosened_fields: row.loosened_fields.clone(),
+        base_version: row.base_version,
+        reason: row.reason.clone(),
+        proposed_by: row.proposed_by,
+        reviewed_by: row.reviewed_by,
+        reviewed_at: row.reviewed_at,
+        created_at: row.created_at,
+    })
+}
+
+/// Active version and document, falling back to the executor configs.
+async fn current_risk_limits(
+    state: &AppState,
+    workspace_id: Uuid,
+) -> ApiResult<(Option<i32>, RiskLimitsDocument)> {
+    if let Some(row) = risk_limits::load_active_version(&state.pool, workspace_id).await? {
+        let document = risk_limits_version_response(&row)?.limits;
+        return Ok((Some(row.version), document));
+    }
+
+    let arb = match &state.arb_executor_config {
+        Some(config) => config.read().await.clone(),
+        None => crate::arb_executor::ArbExecutorConfig::from_env(),
+    };
+    let quant = match &state.quant_executor_config {
+        Some(config) => config.read().await.clone(),
+        None => crate::quant_signal_executor::QuantSignalExecutorConfig::from_env(
+            &state.signal_generators,
+        ),
+    };
+    Ok((None, RiskLimitsDocument::from_configs(&arb, &quant)))
+}
+
+/// Audit an activated version and broadcast it to the running executors.
+async fn announce_risk_limits_activation(
+    state: &AppState,
+    user_id: Uuid,
+    previous: &RiskLimitsDocument,
+    row: &risk_limits::RiskLimitsVersionRow,
+    document: &RiskLimitsDocument,
+) -> ApiResult<()> {
+    state.audit_logger.log_config_change(
+        &user_id.to_string(),
+        &format!("risk_limits/{}", row.workspace_id),
+        serde_json::to_value(previous)?,
+        serde_json::json!({
+            "version": row.version,
+            "limits": document,
+            "loosened_fields": row.loosened_fields,
+            "proposed_by": row.proposed_by,
+        }),
+    );
+
+    // Postgres is the source of truth; executors reload it on restart, so a
+    // failed broadcast only delays the change.
+    match state.redis_conn.clone() {
+        Some(mut conn) => {
+            if let Err(e) =
+                risk_limits::publish_risk_limits(&mut conn, row.workspace_id, row.version, document)
+                    .await
+            {
+                tracing::warn!(
+                    error = %e,
+                    version = row.version,
+                    "Failed to broadcast risk limits; executors pick them up on restart"
+                );
+            }
+        }
+        None => tracing::warn!(
+            version = row.version,
+            "Redis unavailable; risk limits take effect on restart"
+        ),
+    }
+    Ok(())
+}
+
+/// Get the risk limits in effect for a workspace.
+#[utoipa::path(
+    get,
+    path = "/api/v1/workspaces/{workspace_id}/risk/limits",
+    params(
+        ("workspace_id" = String, Path, description = "Workspace ID")
+    ),
+    responses(
+        (status = 200, description = "Current risk limits", body = RiskLimitsResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not a member of this workspace"),
+    ),
+    security(("bearer_auth" = [])),
+    tag = "risk"
+)]
+pub async fn get_risk_limits(
+    State(state): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+    Path(_workspace_id): Path<String>,
+) -> ApiResult<Json<RiskLimitsResponse>> {
+    let user_id =
+        Uuid::parse_str(&claims.sub).map_err(|_| ApiError::Internal("Invalid user ID".into()))?;
+    let (workspace_id, _) = require_canonical_workspace_member(&state.pool, user_id).await?;
+
+    let (active_version, limits) = current_risk_limits(&state, workspace_id).await?;
+    let pending = risk_limits::load_history(&state.pool, workspace_id, 50)
+        .await?
+        .iter()
+        .filter(|row| row.status == risk_limits::STATUS_PENDING_APPROVAL)
+        .map(risk_limits_version_response)
+        .collect::<ApiResult<Vec<_>>>()?;
+
+    Ok(Json(RiskLimitsResponse {
+        active_version,
+        limits,
+        pending,
+    }))
+}
+
+/// List stored risk-limits versions, newest first.
+#[utoipa::path(
+    get,
+    path = "/api/v1/workspaces/{workspace_id}/risk/limits/history",
+    params(
+        ("workspace_id" = String, Path, description = "Workspace ID")
+    ),
+    responses(
+        (status = 200, description = "Risk limits history", body = Vec<RiskLimitsVersionResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not a member of this workspace"),
+    ),
+    security(("bearer_auth" = [])),
+    tag = "risk"
+)]
+pub async fn get_risk_limits_history(
+    State(state): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+    Path(_workspace_id): Path<String>,
+) -> ApiResult<Json<Vec<RiskLimitsVersionResponse>>> {
+    let user_id =
+        Uuid::parse_str(&claims.sub).map_err(|_| ApiError::Internal("Invalid user ID".into()))?;
+    let (workspace_id, _) = require_canonical_workspace_member(&state.pool, user_id).await?;
+
+    let history = risk_limits::load_history(&state.pool, workspace_id, 200)
+        .await?
+        .iter()
+        .map(risk_limits_version_response)
+        .collect::<ApiResult<Vec<_>>>()?;
+    Ok(Json(history))
+}
+
+/// Propose a new risk-limits version.
+///
+/// Changes that only tighten limits are activated immediately. Changes that
+/// loosen any limit are stored as `pending_approval` until a different
+/// owner/admin approves them.
+#[utoipa::path(
+    put,
+    path = "/api/v1/workspaces/{workspace_id}/risk/limits",
+    params(
+        ("workspace_id" = String, Path, description = "Workspace ID")
+    ),
+    request_body = UpdateRiskLimitsRequest,
+    responses(
+        (status = 200, description = "Stored version", body = RiskLimitsVersionResponse),
+        (status = 400, description = "Invalid limits"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not a member / insufficient role"),
+    ),
+    security(("bearer_auth" = [])),
+    tag = "risk"
+)]
+pub async fn update_risk_limits(
+    State(state): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+    Path(_workspace_id): Path<String>,
+    Json(req): Json<UpdateRiskLimitsRequest>,
+) -> ApiResult<Json<RiskLimitsVersionResponse>> {
+    let user_id =
+        Uuid::parse_str(&claims.sub).map_err(|_| ApiError::Internal("Invalid user ID".into()))?;
+    let (workspace_id, role) = require_canonical_workspace_member(&state.pool, user_id).await?;
+    if !can_manage_risk(&role) {
+        return Err(ApiError::Forbidden(
+            "Only workspace owners/admins can change risk limits".into(),
+        ));
+    }
+
+    req.limits.validate().map_err(ApiError::BadRequest)?;
+
+    let (base_version, current) = current_risk_limits(&state, workspace_id).await?;
+    if req.limits == current {
+        return Err(ApiError::BadRequest(
+            "Risk limits are unchanged from the active version".into(),
+        ));
+    }
+    let loosened_fields = req.limits.loosened_fields(&current);
+    let activate = loosened_fields.is_empty();
+
+    let row = risk_limits::insert_version(
+        &state.pool,
+        risk_limits::NewRiskLimitsVersion {
+            workspace_id,
+            base_version,
+            document: &req.limits,
+            loosened_fields: &loosened_fields,
+            reason: req.reason.as_deref(),
+            proposed_by: user_id,
+            activate,
+        },
+    )
+    .await
+    .map_err(|e| ApiError::Internal(format!("Failed to store risk limits: {e}")))?;
+
+    if activate {
+        announce_risk_limits_activation(&state, user_id, &current, &row, &req.limits).await?;
+    } else {
+        state.audit_logger.log(
+            AuditEvent::builder(
+                AuditAction::Custom("risk_limits_proposed".to_string()),
+                format!("config/risk_limits/{workspace_id}"),
+            )
+            .user(user_id.to_string())
+            .details(serde_json::json!({
+                "version": row.version,
+                "base_version": base_version,
+                "loosened_fields": loosened_fields,
+                "limits": req.limits,
+                "reason": req.reason,
+            }))
+            .build(),
+        );
+    }
+
+    tracing::info!(
+        workspace_id = %workspace_id,
+        user_id = %user_id,
+        version = row.version,
+        status = %row.status,
+        "Risk limits version stored"
+    );
+
+    risk_limits_version_response(&row).map(Json)
+}
+
+/// Approve a pending risk-limits version and make it active.
+#[utoipa::path(
+    post,
+    path = "/api/v1/workspaces/{workspace_id}/risk/limits/{version}/approve",
+    params(
+        ("workspace_id" = String, Path, description = "Workspace ID"),
+        ("version" = i32, Path, description = "Pending version to approve")
+    ),
+    responses(
+        (status = 200, description = "Activated version", body = RiskLimitsVersionResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Insufficient role or self-approval"),
+        (status = 404, description = "Version not found"),
+        (status = 409, description = "Version is not pending or limits changed since proposal"),
+    ),
+    security(("bearer_auth" = [])),
+    tag = "risk"
+)]
+pub async fn approve_risk_limits(
+    State(state): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+    Path((_workspace_id, version)): Path<(String, i32)>,
+) -> ApiResult<Json<RiskLimitsVersionResponse>> {
+    let user_id =
+        Uuid::parse_str(&claims.sub).map_err(|_| ApiError::Internal("Invalid user ID".into()))?;
+    let (workspace_id, role) = require_canonical_workspace_member(&state.pool, user_id).await?;
+    if !can_manage_risk(&role) {
+        return Err(ApiError::Forbidden(
+            "Only workspace owners/admins can approve risk limits".into(),
+        ));
+    }
+
+    let pending = risk_limits::load_version(&state.pool, workspace_id, version)
+        .await?
+        .ok_or_else(|| ApiError::NotFound(format!("Risk limits v{version} not found")))?;
+    if pending.status != risk_limits::STATUS_PENDING_APPROVAL {
+        return Err(ApiError::Conflict(format!(
+            "Risk limits v{version} is {}, not pending approval",
+            pending.status
+        )));
+    }
+    if pending.proposed_by == user_id {
+        return Err(ApiError::Forbidden(
+            "Loosened risk limits must be approved by a different owner/admin".into(),
+        ));
+    }
+
+    // The loosening check was made against base_version; re-propose if the
+    // active limits moved since then.
+    let (active_version, current) = current_risk_limits(&state, workspace_id).await?;
+    if active_version != pending.base_version {
+        return Err(ApiError::Conflict(format!(
+            "Risk limits changed since v{version} was proposed; submit it again"
+        )));
+    }
+
+    let row = risk_limits::review_version(&state.pool, workspace_id, version, user_id, true)
+        .await
+        .map_err(|e| ApiError::Internal(format!("Failed to approve risk limits: {e}")))?
+        .ok_or_else(|| {
+            ApiError::Conflict(format!("Risk limits v{version} is no longer pending"))
+        })?;
+    let response = risk_limits_version_response(&row)?;
+    announce_risk_limits_activation(&state, user_id, &current, &row, &response.limits).await?;
+
+    tracing::info!(
+        workspace_id = %workspace_id,
+        approved_by = %user_id,
+        proposed_by = %row.proposed_by,
+        version,
+        "Loosened risk limits approved"
+    );
+
+    Ok(Json(response))
+}
+
+/// Reject a pending risk-limits version.
+#[utoipa::path(
+    post,
+    path = "/api/v1/workspaces/{workspace_id}/risk/limits/{version}/reject",
+    params(
+        ("workspace_id" = String, Path, description = "Workspace ID"),
+        ("version" = i32, Path, description = "Pending version to reject")
+    ),
+    responses(
+        (status = 200, description = "Rejected version", body = RiskLimitsVersionResponse),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Not a member / insufficient role"),
+        (status = 409, description = "Version is not pending"),
+    ),
+    security(("bearer_auth" = [])),
+    tag = "risk"
+)]
+pub async fn reject_risk_limits(
+    State(state): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+    Path((_workspace_id, version)): Path<(String, i32)>,
+) -> ApiResult<Json<RiskLimitsVersionResponse>> {
+    let user_id =
+        Uuid::parse_str(&claims.sub).map_err(|_| ApiError::Internal("Invalid user ID".into()))?;
+    let (workspace_id, role) = require_canonical_workspace_member(&state.pool, user_id).await?;
+    if !can_manage_risk(&role) {
+        return Err(ApiError::Forbidden(
+            "Only workspace owners/admins can reject risk limits".into(),
+        ));
+    }
+
+    let row = risk_limits::review_version(&state.pool, workspace_id, version, user_id, false)
+        .await
+        .map_err(|e| ApiError::Internal(format!("Failed to reject risk limits: {e}")))?
+        .ok_or_else(|| ApiError::Conflict(format!("Risk limits v{version} is not pending")))?;
+
+    state.audit_logger.log(
+        AuditEvent::builder(
+            AuditAction::Custom("risk_limits_rejected".to_string()),
+            format!("config/risk_limits/{workspace_id}"),
+        )
+        .user(user_id.to_string())
+        .details(serde_json::json!({
+            "version": version,
+            "proposed_by": row.proposed_by,
+        }))
+        .build(),
+    );
+
+    risk_limits_version_response(&row).map(Json)
+}
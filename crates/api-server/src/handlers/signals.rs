@@ -1,9 +1,11 @@
 //! Signal-related API handlers.
 //!
 //! Provides endpoints for querying market flow features,
-//! quant signal performance, and recent signals.
+//! quant signal performance, and recent signals, and for inspecting and
+//! toggling the signal generators.
 
-use axum::extract::{Query, State};
+use axum::extract::{Path, Query, State};
+use axum::Extension;
 use axum::Json;
 use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
@@ -11,7 +13,12 @@ use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use utoipa::{IntoParams, ToSchema};
 
-use crate::error::ApiResult;
+use auth::{AuditAction, AuditEvent, Claims};
+use uuid::Uuid;
+
+use crate::error::{ApiError, ApiResult};
+use crate::handlers::risk::{can_manage_risk, require_canonical_workspace_member};
+use crate::signals::GeneratorHealth;
 use crate::state::AppState;
 
 /// Query parameters for the flow features endpoint.
@@ -306,6 +313,10 @@ pub struct StrategyPerformanceResponse {
     pub sharpe: Option<f64>,
     pub max_drawdown_pct: Option<f64>,
     pub avg_hold_hours: Option<f64>,
+    /// Market making only: P&L from quoting inside the mid, at fill time.
+    pub spread_capture: Option<Decimal>,
+    /// Market making only: mid moves against fills over the markout horizon.
+    pub adverse_selection: Option<Decimal>,
 }
 
 /// GET /api/v1/signals/performance — Get per-strategy performance.
@@ -331,7 +342,8 @@ pub async fn get_strategy_performance(
             strategy, period_end, period_days,
             total_signals, executed, wins, losses,
             net_pnl, avg_pnl, win_rate, sharpe,
-            max_drawdown_pct, avg_hold_hours
+            max_drawdown_pct, avg_hold_hours,
+            spread_capture, adverse_selection
         FROM strategy_pnl_snapshots
         WHERE period_days = $1
         ORDER BY strategy, period_end DESC
@@ -358,6 +370,8 @@ pub async fn get_strategy_performance(
             sharpe: r.sharpe,
             max_drawdown_pct: r.max_drawdown_pct,
             avg_hold_hours: r.avg_hold_hours,
+            spread_capture: r.spread_capture,
+            adverse_selection: r.adverse_selection,
         })
         .collect();
 
@@ -396,6 +410,8 @@ struct PerformanceRow {
     sharpe: Option<f64>,
     max_drawdown_pct: Option<f64>,
     avg_hold_hours: Option<f64>,
+    spread_capture: Option<Decimal>,
+    adverse_selection: Option<Decimal>,
 }
 
 #[derive(Debug, sqlx::FromRow)]
@@ -425,3 +441,83 @@ struct MetadataRow {
     active: bool,
     fetched_at: DateTime<Utc>,
 }
+
+/// List the quant signal generators with their run health.
+#[utoipa::path(
+    get,
+    path = "/api/v1/signals/generators",
+    tag = "signals",
+    responses(
+        (status = 200, description = "Signal generators and their health", body = Vec<GeneratorHealth>)
+    )
+)]
+pub async fn list_signal_generators(
+    State(state): State<Arc<AppState>>,
+) -> ApiResult<Json<Vec<GeneratorHealth>>> {
+    Ok(Json(state.signal_generators.health().await))
+}
+
+/// Request body for enabling or disabling a signal generator.
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct UpdateSignalGeneratorRequest {
+    pub enabled: bool,
+}
+
+/// Enable or disable a signal generator at runtime.
+///
+/// Takes effect on the generator's next poll and lasts until restart.
+#[utoipa::path(
+    put,
+    path = "/api/v1/signals/generators/{name}",
+    tag = "signals",
+    params(
+        ("name" = String, Path, description = "Generator name, e.g. flow")
+    ),
+    request_body = UpdateSignalGeneratorRequest,
+    responses(
+        (status = 200, description = "Updated generator", body = GeneratorHealth),
+        (status = 403, description = "Not a workspace owner/admin"),
+        (status = 404, description = "Unknown generator")
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn update_signal_generator(
+    State(state): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
+    Path(name): Path<String>,
+    Json(req): Json<UpdateSignalGeneratorRequest>,
+) -> ApiResult<Json<GeneratorHealth>> {
+    let user_id =
+        Uuid::parse_str(&claims.sub).map_err(|_| ApiError::Internal("Invalid user ID".into()))?;
+    let (_, role) = require_canonical_workspace_member(&state.pool, user_id).await?;
+    if !can_manage_risk(&role) {
+        return Err(ApiError::Forbidden(
+            "Only workspace owners/admins can toggle signal generators".into(),
+        ));
+    }
+
+    if !state.signal_generators.set_enabled(&name, req.enabled) {
+        return Err(ApiError::NotFound(format!(
+            "Unknown signal generator: {name}"
+        )));
+    }
+
+    state.audit_logger.log(
+        AuditEvent::builder(
+            AuditAction::Custom("signal_generator_toggled".to_string()),
+            format!("config/signal_generators/{name}"),
+        )
+        .user(user_id.to_string())
+        .details(serde_json::json!({ "enabled": req.enabled }))
+        .build(),
+    );
+
+    let health = state
+        .signal_generators
+        .health()
+        .await
+        .into_iter()
+        .find(|g| g.name == name)
+        .ok_or_else(|| ApiError::NotFound(format!("Unknown signal generator: {name}")))?;
+    Ok(Json(health))
+}
@@ -203,8 +203,8 @@ pub async fn challenge(
     .await;
 
     // Build SIWE message
-    let domain = std::env::var("APP_DOMAIN").unwrap_or_else(|_| "localhost".to_string());
-    let uri = std::env::var("APP_URI").unwrap_or_else(|_| "http://localhost:3002".to_string());
+    let domain: String = polymarket_core::settings::get("APP_DOMAIN");
+    let uri: String = polymarket_core::settings::get("APP_URI");
 
     let message = format!(
         "{domain} wants you to sign in with your Ethereum account:\n\
@@ -1151,7 +1151,7 @@ pub async fn create_workspace_invite(
 
     let invite_link = format!(
         "{}/invite/{}",
-        std::env::var("DASHBOARD_URL").unwrap_or_else(|_| "http://localhost:3002".to_string()),
+        polymarket_core::settings::get::<String>("DASHBOARD_URL"),
         token
     );
 
@@ -2230,22 +2230,16 @@ pub async fn get_service_status(
     .map_err(|_| ApiError::NotFound("Workspace not found".into()))?;
 
     // Check harvester: enabled by env
-    let harvester_enabled = std::env::var("HARVESTER_ENABLED")
-        .map(|v| v != "false")
-        .unwrap_or(true);
+    let harvester_enabled = polymarket_core::settings::get("HARVESTER_ENABLED");
 
     // Check metrics calculator: enabled by env
-    let metrics_enabled = std::env::var("METRICS_CALCULATOR_ENABLED")
-        .map(|v| v == "true" || v == "1")
-        .unwrap_or(true);
+    let metrics_enabled = polymarket_core::settings::get("METRICS_CALCULATOR_ENABLED");
 
     // Check arb executor — read runtime config for actual enabled state
     let arb_runtime_enabled = if let Some(ref arb_config) = state.arb_executor_config {
         arb_config.read().await.enabled
     } else {
-        let arb_env_enabled = std::env::var("ARB_AUTO_EXECUTE")
-            .map(|v| v == "true" || v == "1")
-            .unwrap_or(false);
+        let arb_env_enabled = polymarket_core::settings::get("ARB_AUTO_EXECUTE");
         arb_env_enabled || flags.arb_auto_execute
     };
 
@@ -2279,9 +2273,7 @@ pub async fn get_service_status(
     let quant_executor_enabled = if let Some(ref quant_config) = state.quant_executor_config {
         quant_config.read().await.enabled
     } else {
-        std::env::var("QUANT_EXECUTOR_ENABLED")
-            .map(|v| v == "true")
-            .unwrap_or(false)
+        polymarket_core::settings::get("QUANT_EXECUTOR_ENABLED")
     };
     let strategy_modes = resolve_strategy_modes(&StrategyModeInputs {
         arb_enabled: arb_runtime_enabled,
@@ -2290,18 +2282,12 @@ pub async fn get_service_status(
         live_executor: live_mode,
         live_ready,
         quant_executor_enabled,
-        flow_signal_enabled: std::env::var("FLOW_SIGNAL_ENABLED")
-            .map(|v| v == "true")
-            .unwrap_or(true),
-        mean_reversion_signal_enabled: std::env::var("MEAN_REVERSION_SIGNAL_ENABLED")
-            .map(|v| v == "true")
-            .unwrap_or(true),
-        cross_market_signal_enabled: std::env::var("CROSS_MARKET_SIGNAL_ENABLED")
-            .map(|v| v == "true")
-            .unwrap_or(false),
-        resolution_signal_enabled: std::env::var("RESOLUTION_SIGNAL_ENABLED")
-            .map(|v| v == "true")
-            .unwrap_or(true),
+        signal_generators: state
+            .signal_generators
+            .enabled_states()
+            .into_iter()
+            .map(|(name, enabled)| (name.to_string(), enabled))
+            .collect(),
     });
 
     Ok(Json(ServiceStatusResponse {
@@ -2422,17 +2408,21 @@ pub async fn get_dynamic_tuner_status(
         .iter()
         .find(|row| row.key == KEY_ARB_MIN_PROFIT_THRESHOLD)
         .map(|row| decimal_to_f64(row.current_value))
-        .unwrap_or_else(|| env_f64(KEY_ARB_MIN_PROFIT_THRESHOLD, 0.005));
+        .unwrap_or_else(|| polymarket_core::settings::get(KEY_ARB_MIN_PROFIT_THRESHOLD));
     let max_markets_cap = rows
         .iter()
         .find(|row| row.key == KEY_ARB_MONITOR_MAX_MARKETS)
         .map(|row| decimal_to_f64(row.current_value))
-        .unwrap_or_else(|| env_f64(KEY_ARB_MONITOR_MAX_MARKETS, 300.0));
+        .unwrap_or_else(|| {
+            polymarket_core::settings::get_opt(KEY_ARB_MONITOR_MAX_MARKETS).unwrap_or(300.0)
+        });
     let exploration_slots = rows
         .iter()
         .find(|row| row.key == KEY_ARB_MONITOR_EXPLORATION_SLOTS)
         .map(|row| decimal_to_f64(row.current_value))
-        .unwrap_or_else(|| env_f64(KEY_ARB_MONITOR_EXPLORATION_SLOTS, 5.0));
+        .unwrap_or_else(|| {
+            polymarket_core::settings::get_opt(KEY_ARB_MONITOR_EXPLORATION_SLOTS).unwrap_or(5.0)
+        });
     let aggressiveness_level = rows
         .iter()
         .find(|row| row.key == KEY_ARB_MONITOR_AGGRESSIVENESS_LEVEL)
@@ -2447,9 +2437,9 @@ pub async fn get_dynamic_tuner_status(
 
     let signal_thresholds = DynamicSignalThresholdsResponse {
         min_net_profit_threshold_pct: min_profit_ratio * 100.0,
-        signal_cooldown_secs: env_i64("ARB_SIGNAL_COOLDOWN_SECS", 60),
-        min_depth_usd: env_f64("ARB_MIN_BOOK_DEPTH", 25.0),
-        trading_fee_pct: env_f64("ARB_TRADING_FEE_PCT", 0.0),
+        signal_cooldown_secs: polymarket_core::settings::get("ARB_SIGNAL_COOLDOWN_SECS"),
+        min_depth_usd: polymarket_core::settings::get("ARB_MIN_BOOK_DEPTH"),
+        trading_fee_pct: polymarket_core::settings::get("ARB_TRADING_FEE_PCT"),
     };
 
     let runtime_state: Option<DynamicTunerStateRow> = sqlx::query_as(
@@ -2484,7 +2474,7 @@ pub async fn get_dynamic_tuner_status(
     } else {
         0.0
     };
-    let freeze_drawdown_threshold = env_f64("DYNAMIC_TUNER_FREEZE_DRAWDOWN", 0.20);
+    let freeze_drawdown_threshold = polymarket_core::settings::get("DYNAMIC_TUNER_FREEZE_DRAWDOWN");
     let (frozen, freeze_reason) = if cb_state.tripped {
         (true, Some("circuit breaker is tripped".to_string()))
     } else if current_drawdown >= freeze_drawdown_threshold {
@@ -2501,8 +2491,8 @@ pub async fn get_dynamic_tuner_status(
     };
 
     let current_regime = format!("{:?}", *state.current_regime.read().await);
-    let enabled = env_bool("DYNAMIC_TUNER_ENABLED", true);
-    let apply_changes = env_bool("DYNAMIC_TUNER_APPLY", true);
+    let enabled = polymarket_core::settings::get("DYNAMIC_TUNER_ENABLED");
+    let apply_changes = polymarket_core::settings::get("DYNAMIC_TUNER_APPLY");
     let (last_run_at, last_run_status, last_run_reason, last_metrics) = match runtime_state {
         Some(row) => (
             row.last_run_at,
@@ -2881,12 +2871,16 @@ pub async fn update_opportunity_selection_settings(
         .iter()
         .find(|row| row.key == KEY_ARB_MONITOR_MAX_MARKETS)
         .map(|row| decimal_to_f64(row.current_value))
-        .unwrap_or_else(|| env_f64(KEY_ARB_MONITOR_MAX_MARKETS, 300.0));
+        .unwrap_or_else(|| {
+            polymarket_core::settings::get_opt(KEY_ARB_MONITOR_MAX_MARKETS).unwrap_or(300.0)
+        });
     let exploration_slots = dynamic_rows
         .iter()
         .find(|row| row.key == KEY_ARB_MONITOR_EXPLORATION_SLOTS)
         .map(|row| decimal_to_f64(row.current_value))
-        .unwrap_or_else(|| env_f64(KEY_ARB_MONITOR_EXPLORATION_SLOTS, 5.0));
+        .unwrap_or_else(|| {
+            polymarket_core::settings::get_opt(KEY_ARB_MONITOR_EXPLORATION_SLOTS).unwrap_or(5.0)
+        });
     let aggressiveness_level = dynamic_rows
         .iter()
         .find(|row| row.key == KEY_ARB_MONITOR_AGGRESSIVENESS_LEVEL)
@@ -2975,18 +2969,8 @@ pub async fn get_dynamic_tuning_history(
     Ok(Json(history))
 }
 
-fn env_bool(name: &str, fallback: bool) -> bool {
-    std::env::var(name)
-        .map(|value| {
-            let normalized = value.trim().to_ascii_lowercase();
-            normalized == "1" || normalized == "true" || normalized == "yes"
-        })
-        .unwrap_or(fallback)
-}
-
 fn env_aggressiveness_level() -> f64 {
-    match std::env::var("ARB_MONITOR_AGGRESSIVENESS")
-        .unwrap_or_else(|_| "balanced".to_string())
+    match polymarket_core::settings::get::<String>("ARB_MONITOR_AGGRESSIVENESS")
         .to_lowercase()
         .as_str()
     {
@@ -3273,9 +3257,8 @@ async fn publish_manual_dynamic_updates(
         owned_conn = conn.clone();
         &mut owned_conn
     } else {
-        let redis_url = std::env::var("DYNAMIC_TUNER_REDIS_URL")
-            .or_else(|_| std::env::var("REDIS_URL"))
-            .unwrap_or_else(|_| "redis://127.0.0.1:6379".to_string());
+        let redis_url: String = polymarket_core::settings::get_opt("DYNAMIC_TUNER_REDIS_URL")
+            .unwrap_or_else(|| polymarket_core::settings::get("REDIS_URL"));
         let client = redis::Client::open(redis_url.as_str())?;
         owned_conn = redis::aio::ConnectionManager::new(client).await?;
         &mut owned_conn
@@ -3309,9 +3292,8 @@ async fn fetch_arb_runtime_stats(
         owned_conn = conn.clone();
         &mut owned_conn
     } else {
-        let redis_url = std::env::var("DYNAMIC_CONFIG_REDIS_URL")
-            .or_else(|_| std::env::var("REDIS_URL"))
-            .unwrap_or_else(|_| "redis://127.0.0.1:6379".to_string());
+        let redis_url: String = polymarket_core::settings::get_opt("DYNAMIC_CONFIG_REDIS_URL")
+            .unwrap_or_else(|| polymarket_core::settings::get("REDIS_URL"));
         let client = redis::Client::open(redis_url.as_str()).ok()?;
         owned_conn = redis::aio::ConnectionManager::new(client).await.ok()?;
         &mut owned_conn
@@ -3320,20 +3302,6 @@ async fn fetch_arb_runtime_stats(
     payload.and_then(|raw| serde_json::from_str::<ArbRuntimeStatsSnapshot>(&raw).ok())
 }
 
-fn env_f64(name: &str, fallback: f64) -> f64 {
-    std::env::var(name)
-        .ok()
-        .and_then(|value| value.parse::<f64>().ok())
-        .unwrap_or(fallback)
-}
-
-fn env_i64(name: &str, fallback: i64) -> i64 {
-    std::env::var(name)
-        .ok()
-        .and_then(|value| value.parse::<i64>().ok())
-        .unwrap_or(fallback)
-}
-
 fn decimal_to_f64(value: Decimal) -> f64 {
     value.to_f64().unwrap_or(0.0)
 }
@@ -118,9 +118,7 @@ impl ShadowPredictionRecorder {
     pub fn new(pool: PgPool) -> Self {
         Self {
             pool: pool.clone(),
-            enabled: std::env::var("LEARNING_SHADOW_ENABLED")
-                .map(|value| value != "false")
-                .unwrap_or(true),
+            enabled: polymarket_core::settings::get("LEARNING_SHADOW_ENABLED"),
             schema_missing: Arc::new(AtomicBool::new(false)),
             model_cache: Arc::new(RwLock::new(HashMap::new())),
             runtime: LearningModelRuntime::new(pool.clone()),
@@ -402,6 +400,7 @@ pub fn score_quant_shadow(input: &QuantShadowPredictionInput) -> QuantShadowScor
         QuantSignalKind::CrossMarket => 0.05,
         QuantSignalKind::MeanReversion => -0.03,
         QuantSignalKind::ResolutionProximity => 0.08,
+        QuantSignalKind::EventReaction => 0.0,
     };
 
     QuantShadowScores {
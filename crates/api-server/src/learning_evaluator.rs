@@ -22,25 +22,13 @@ pub struct LearningEvaluatorConfig {
 impl LearningEvaluatorConfig {
     pub fn from_env() -> Self {
         Self {
-            enabled: std::env::var("LEARNING_EVALUATOR_ENABLED")
-                .map(|v| v == "true")
-                .unwrap_or(true),
-            interval_secs: std::env::var("LEARNING_EVALUATOR_INTERVAL_SECS")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(1800),
-            startup_delay_secs: std::env::var("LEARNING_EVALUATOR_STARTUP_DELAY_SECS")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(240),
-            lookback_hours: std::env::var("LEARNING_EVALUATOR_LOOKBACK_HOURS")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(168),
-            max_models_per_cycle: std::env::var("LEARNING_EVALUATOR_MAX_MODELS")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(24),
+            enabled: polymarket_core::settings::get("LEARNING_EVALUATOR_ENABLED"),
+            interval_secs: polymarket_core::settings::get("LEARNING_EVALUATOR_INTERVAL_SECS"),
+            startup_delay_secs: polymarket_core::settings::get(
+                "LEARNING_EVALUATOR_STARTUP_DELAY_SECS",
+            ),
+            lookback_hours: polymarket_core::settings::get("LEARNING_EVALUATOR_LOOKBACK_HOURS"),
+            max_models_per_cycle: polymarket_core::settings::get("LEARNING_EVALUATOR_MAX_MODELS"),
         }
     }
 }
@@ -91,10 +91,7 @@ impl LearningModelRuntime {
     pub fn new(pool: PgPool) -> Self {
         Self {
             pool,
-            refresh_secs: std::env::var("LEARNING_MODEL_REFRESH_SECS")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(30),
+            refresh_secs: polymarket_core::settings::get("LEARNING_MODEL_REFRESH_SECS"),
             cache: Arc::new(RwLock::new(None)),
         }
     }
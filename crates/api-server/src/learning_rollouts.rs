@@ -68,10 +68,7 @@ impl LearningRolloutController {
     pub fn new(pool: PgPool) -> Self {
         Self {
             pool: pool.clone(),
-            refresh_secs: std::env::var("LEARNING_ROLLOUT_REFRESH_SECS")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(30),
+            refresh_secs: polymarket_core::settings::get("LEARNING_ROLLOUT_REFRESH_SECS"),
             schema_missing: Arc::new(AtomicBool::new(false)),
             cache: Arc::new(RwLock::new(None)),
             runtime: LearningModelRuntime::new(pool.clone()),
@@ -262,17 +259,13 @@ pub struct LearningRolloutObserverConfig {
 impl LearningRolloutObserverConfig {
     pub fn from_env() -> Self {
         Self {
-            enabled: std::env::var("LEARNING_ROLLOUT_OBSERVER_ENABLED")
-                .map(|v| v == "true")
-                .unwrap_or(true),
-            interval_secs: std::env::var("LEARNING_ROLLOUT_OBSERVER_INTERVAL_SECS")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(900),
-            startup_delay_secs: std::env::var("LEARNING_ROLLOUT_OBSERVER_STARTUP_DELAY_SECS")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(300),
+            enabled: polymarket_core::settings::get("LEARNING_ROLLOUT_OBSERVER_ENABLED"),
+            interval_secs: polymarket_core::settings::get(
+                "LEARNING_ROLLOUT_OBSERVER_INTERVAL_SECS",
+            ),
+            startup_delay_secs: polymarket_core::settings::get(
+                "LEARNING_ROLLOUT_OBSERVER_STARTUP_DELAY_SECS",
+            ),
         }
     }
 }
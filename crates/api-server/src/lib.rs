@@ -24,10 +24,13 @@ pub mod arb_executor;
 pub mod backtest_automation;
 pub mod cex;
 pub mod crypto;
+pub mod dead_mans_switch;
 pub mod dynamic_tuner;
 pub mod email;
 pub mod error;
+pub mod events;
 pub mod exit_handler;
+pub mod exit_optimizer;
 pub mod flow_feature_calculator;
 pub mod gamma_syncer;
 pub mod handlers;
@@ -35,12 +38,18 @@ pub mod learning;
 pub mod learning_evaluator;
 pub mod learning_models;
 pub mod learning_rollouts;
+pub mod market_conditions_monitor;
+pub mod market_making;
 pub mod metrics_calculator;
 pub mod middleware;
+pub mod order_router;
+pub mod portfolio_sizing;
 pub mod position_reconciler;
 pub mod position_service;
 pub mod quant_signal_executor;
 pub mod redis_forwarder;
+pub mod resolution_risk;
+pub mod risk_limits;
 pub mod routes;
 pub mod runtime_sync;
 pub mod schema;
@@ -50,6 +59,7 @@ pub mod strategy_health_calculator;
 pub mod strategy_modes;
 pub mod strategy_pnl_calculator;
 pub mod trade_events;
+pub mod unwind_policy;
 pub mod wallet_harvester;
 pub mod wallet_inventory;
 pub mod websocket;
@@ -59,10 +69,12 @@ pub use accounting_ledger::{spawn_account_snapshot_calculator, AccountSnapshotCo
 pub use arb_executor::{spawn_arb_auto_executor, ArbExecutorConfig};
 pub use backtest_automation::{spawn_backtest_automation, BacktestAutomationConfig};
 pub use cex::{
-    spawn_binance_ws_client, spawn_latency_arb_executor, BinanceWsConfig, LatencyArbExecutorConfig,
+    spawn_composite_feed, spawn_latency_arb_executor, CompositeFeedConfig, LatencyArbExecutorConfig,
 };
+pub use dead_mans_switch::{spawn_dead_mans_switch, DeadMansSwitchConfig};
 pub use dynamic_tuner::{spawn_dynamic_config_subscriber, DynamicTuner};
 pub use error::ApiError;
+pub use events::{spawn_event_ingestion, EventIngestConfig};
 use exit_handler::spawn_exit_handler;
 pub use exit_handler::ExitHandlerConfig;
 pub use flow_feature_calculator::{spawn_flow_feature_calculator, FlowFeatureConfig};
@@ -71,14 +83,20 @@ pub use learning_evaluator::{spawn_learning_evaluator, LearningEvaluatorConfig};
 pub use learning_rollouts::{
     spawn_learning_rollout_observer, LearningRolloutController, LearningRolloutObserverConfig,
 };
+pub use market_conditions_monitor::{
+    spawn_market_conditions_monitor, MarketConditionsMonitorConfig,
+};
+pub use market_making::{spawn_market_maker, MarketMakerConfig};
 pub use metrics_calculator::{MetricsCalculator, MetricsCalculatorConfig};
+pub use portfolio_sizing::{PortfolioSizer, PortfolioSizerConfig};
 pub use position_reconciler::{spawn_position_reconciler, PositionReconcilerConfig};
 pub use quant_signal_executor::{spawn_quant_signal_executor, QuantSignalExecutorConfig};
 pub use redis_forwarder::{spawn_redis_forwarder, RedisForwarderConfig};
+pub use resolution_risk::{spawn_resolution_risk_tracker, ResolutionRiskConfig};
+pub use risk_limits::RiskLimitsDocument;
 pub use routes::create_router;
 pub use signals::{
-    spawn_cross_market_signal_generator, spawn_flow_signal_generator,
-    spawn_mean_reversion_signal_generator, spawn_resolution_signal_generator,
+    spawn_streaming_signals, QuantSignalGenerator, SignalGeneratorRegistry, StreamingSignalConfig,
 };
 pub use state::AppState;
 pub use strategy_health_calculator::{spawn_strategy_health_calculator, StrategyHealthConfig};
@@ -128,22 +146,13 @@ impl ServerConfig {
     /// Create from environment variables.
     pub fn from_env() -> Self {
         Self {
-            host: std::env::var("API_HOST").unwrap_or_else(|_| "0.0.0.0".to_string()),
-            // Check PORT first (Railway), then API_PORT, then default to 3000
-            port: std::env::var("PORT")
-                .or_else(|_| std::env::var("API_PORT"))
-                .ok()
-                .and_then(|p| p.parse().ok())
-                .unwrap_or(3000),
-            cors_permissive: std::env::var("CORS_PERMISSIVE")
-                .map(|v| v == "true")
-                .unwrap_or(true),
-            jwt_secret: std::env::var("JWT_SECRET")
-                .unwrap_or_else(|_| "development-secret-change-in-production".to_string()),
-            ws_channel_capacity: std::env::var("WS_CHANNEL_CAPACITY")
-                .ok()
-                .and_then(|c| c.parse().ok())
-                .unwrap_or(1000),
+            host: polymarket_core::settings::get("API_HOST"),
+            // Check PORT first (Railway), then API_PORT
+            port: polymarket_core::settings::get_opt("PORT")
+                .unwrap_or_else(|| polymarket_core::settings::get("API_PORT")),
+            cors_permissive: polymarket_core::settings::get("CORS_PERMISSIVE"),
+            jwt_secret: polymarket_core::settings::get("JWT_SECRET"),
+            ws_channel_capacity: polymarket_core::settings::get("WS_CHANNEL_CAPACITY"),
         }
     }
 
@@ -236,7 +245,9 @@ impl ApiServer {
         let exit_config_arc = Arc::new(RwLock::new(exit_config_pre));
         self.state.exit_handler_config = Some(exit_config_arc.clone());
 
-        let quant_config = Arc::new(RwLock::new(QuantSignalExecutorConfig::from_env()));
+        let quant_config = Arc::new(RwLock::new(QuantSignalExecutorConfig::from_env(
+            &self.state.signal_generators,
+        )));
         self.state.quant_executor_config = Some(quant_config.clone());
 
         // ── Wrap state in Arc and build router ──
@@ -314,6 +325,9 @@ impl ApiServer {
             state.arb_executor_status.clone(),
         );
 
+        // Re-send unacknowledged critical alerts
+        state.alert_router.spawn_escalation_loop();
+
         // Spawn exit handler unconditionally (per-tick guard checks enabled)
         spawn_exit_handler(
             state
@@ -324,22 +338,25 @@ impl ApiServer {
             state.circuit_breaker.clone(),
             state.clob_client.clone(),
             state.signal_tx.clone(),
+            state.alert_router.clone(),
             state.trade_event_tx.clone(),
             state.pool.clone(),
             arb_dedup.clone(),
             state.exit_handler_heartbeat.clone(),
             shared_token_cache,
+            state.signal_generators.clone(),
         );
 
-        // Subscribe local runtime to dynamic updates (arb executor config knobs)
-        let redis_url = std::env::var("DYNAMIC_CONFIG_REDIS_URL")
-            .or_else(|_| std::env::var("REDIS_URL"))
-            .unwrap_or_else(|_| "redis://127.0.0.1:6379".to_string());
+        // Subscribe local runtime to dynamic updates (arb/quant executor and exit handler knobs)
+        let redis_url: String = polymarket_core::settings::get_opt("DYNAMIC_CONFIG_REDIS_URL")
+            .unwrap_or_else(|| polymarket_core::settings::get("REDIS_URL"));
         spawn_dynamic_config_subscriber(
             redis_url,
             state.pool.clone(),
             state.arb_executor_config.clone(),
             Some(quant_config.clone()),
+            state.exit_handler_config.clone(),
+            Some(state.circuit_breaker.clone()),
         );
 
         // Spawn dynamic tuner (adaptive runtime configuration) after subscriber
@@ -351,6 +368,44 @@ impl ApiServer {
         ));
         tokio::spawn(tuner.start());
 
+        // Spawn market maker (post-only two-sided quotes on configured markets)
+        let market_maker = spawn_market_maker(
+            MarketMakerConfig::from_env(),
+            state.order_executor.clone(),
+            state.circuit_breaker.clone(),
+            state.clob_client.clone(),
+            state.pool.clone(),
+            state.trade_event_tx.clone(),
+            state.market_maker_heartbeat.clone(),
+        );
+
+        // Spawn dead-man's switch heartbeat (mass-cancels resting orders on hard kill
+        // or stalled executors; the external watchdog covers a dead process)
+        let dead_mans_switch_config = DeadMansSwitchConfig::from_env();
+        let mut executor_heartbeats = vec![
+            ("arb_executor", state.arb_executor_heartbeat.clone()),
+            ("exit_handler", state.exit_handler_heartbeat.clone()),
+        ];
+        if market_maker.is_some() {
+            executor_heartbeats.push(("market_maker", state.market_maker_heartbeat.clone()));
+        }
+        spawn_dead_mans_switch(
+            dead_mans_switch_config,
+            state.order_executor.clone(),
+            state.circuit_breaker.clone(),
+            state.audit_logger.clone(),
+            executor_heartbeats,
+        );
+
+        // Spawn market-conditions monitor (trips/throttles the circuit breaker on abnormal books)
+        let market_conditions_config = MarketConditionsMonitorConfig::from_env();
+        spawn_market_conditions_monitor(
+            market_conditions_config,
+            state.pool.clone(),
+            state.circuit_breaker.clone(),
+            state.audit_logger.clone(),
+        );
+
         // Spawn wallet harvester (discovers wallets from CLOB trades)
         let harvester_config = WalletHarvesterConfig::from_env();
         spawn_wallet_harvester(
@@ -364,6 +419,17 @@ impl ApiServer {
         let gamma_config = GammaSyncerConfig::from_env();
         spawn_gamma_syncer(gamma_config, state.pool.clone(), db_semaphore.clone());
 
+        // Serve Prometheus metrics on the internal listener
+        handlers::metrics::spawn_metrics_listener(state.clone());
+
+        // Spawn resolution tracker (records payout timing and outcomes per category)
+        let resolution_risk_config = ResolutionRiskConfig::from_env();
+        spawn_resolution_risk_tracker(
+            resolution_risk_config,
+            state.pool.clone(),
+            state.clob_client.clone(),
+        );
+
         let wallet_inventory_config = WalletInventoryConfig::from_env();
         spawn_wallet_inventory_reconciler(wallet_inventory_config, state.clone());
 
@@ -371,6 +437,13 @@ impl ApiServer {
         let flow_config = FlowFeatureConfig::from_env();
         spawn_flow_feature_calculator(flow_config, state.pool.clone(), db_semaphore.clone());
 
+        // Portfolio Kelly sizer shared by the quant and latency-arb executors so
+        // concurrent bets are sized against one equity figure.
+        let portfolio_sizer = Arc::new(PortfolioSizer::new(
+            state.pool.clone(),
+            PortfolioSizerConfig::from_env(),
+        ));
+
         // ── Quant signal system: executor + generators ──
         // Executor receives QuantSignal from broadcast channel and evaluates/executes.
         spawn_quant_signal_executor(
@@ -384,42 +457,27 @@ impl ApiServer {
             state.pool.clone(),
             state.active_clob_markets.clone(),
             state.quant_executor_heartbeat.clone(),
+            portfolio_sizer.clone(),
         );
 
-        // Signal generators — each polls feature tables and emits QuantSignal
-        use signals::{
-            cross_market_signal::CrossMarketSignalConfig, flow_signal::FlowSignalConfig,
-            mean_reversion_signal::MeanReversionSignalConfig,
-            resolution_signal::ResolutionSignalConfig,
-        };
-
-        let flow_signal_config = FlowSignalConfig::from_env();
-        spawn_flow_signal_generator(
-            flow_signal_config,
-            state.pool.clone(),
-            state.quant_signal_tx.clone(),
-        );
-
-        let resolution_config = ResolutionSignalConfig::from_env();
-        spawn_resolution_signal_generator(
-            resolution_config,
-            state.pool.clone(),
-            state.quant_signal_tx.clone(),
-        );
-
-        let mean_rev_config = MeanReversionSignalConfig::from_env();
-        spawn_mean_reversion_signal_generator(
-            mean_rev_config,
-            state.pool.clone(),
+        // Signal generators — the registry polls each at its own cadence and
+        // forwards its QuantSignals to the executor.
+        state
+            .signal_generators
+            .spawn(state.pool.clone(), state.quant_signal_tx.clone());
+
+        // Streaming pipeline — scores the most-traded markets from live book
+        // and trade features between polls.
+        spawn_streaming_signals(
+            StreamingSignalConfig::from_env(),
+            state.signal_generators.clone(),
+            state.clob_client.clone(),
             state.quant_signal_tx.clone(),
         );
 
-        let cross_market_config = CrossMarketSignalConfig::from_env();
-        spawn_cross_market_signal_generator(
-            cross_market_config,
-            state.pool.clone(),
-            state.quant_signal_tx.clone(),
-        );
+        // External events — polls feeds and the drop directory, links new
+        // events to markets for the event-reaction generator.
+        spawn_event_ingestion((*state.event_ingest).clone(), state.pool.clone());
 
         // Spawn strategy P&L calculator (6h, computes per-strategy performance snapshots)
         let pnl_config = StrategyPnlConfig::from_env();
@@ -445,26 +503,28 @@ impl ApiServer {
         let account_snapshot_config = AccountSnapshotConfig::from_env();
         spawn_account_snapshot_calculator(account_snapshot_config, state.clone());
 
-        // Spawn CEX latency arbitrage system (Binance WS → price tracker → executor)
+        // Spawn CEX latency arbitrage system (venue feeds → median index → price tracker → executor)
         let latency_arb_config = LatencyArbExecutorConfig::from_env();
         if latency_arb_config.enabled {
-            let binance_config = BinanceWsConfig::from_env();
+            let feed_config = CompositeFeedConfig::from_env();
             let tracker_config = cex::PriceTrackerConfig::from_env();
             let gamma_client = Arc::new(polymarket_core::api::GammaClient::new(None));
             let market_mapper = Arc::new(cex::MarketMapper::new(gamma_client, 300));
             market_mapper.clone().spawn_refresh_loop();
 
             let (price_tx, price_rx) = tokio::sync::mpsc::channel(2000);
-            spawn_binance_ws_client(binance_config, price_tx);
+            spawn_composite_feed(feed_config, cex::FeedConnectionConfig::default(), price_tx);
             spawn_latency_arb_executor(
                 latency_arb_config,
                 tracker_config,
                 price_rx,
                 market_mapper,
+                state.clob_client.clone(),
                 state.circuit_breaker.clone(),
                 state.pool.clone(),
+                portfolio_sizer.clone(),
             );
-            info!("CEX latency arbitrage system started (Binance WS + executor)");
+            info!("CEX latency arbitrage system started (composite CEX feed + executor)");
         } else {
             info!("CEX latency arbitrage disabled (LATENCY_ARB_ENABLED != true)");
         }
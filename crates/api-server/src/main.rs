@@ -6,7 +6,8 @@ static GLOBAL: tikv_jemallocator::Jemalloc = tikv_jemallocator::Jemalloc;
 use api_server::{ApiServer, ServerConfig};
 use clap::{Parser, Subcommand};
 use polymarket_core::config::DatabaseConfig;
-use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter};
+use polymarket_core::{settings, telemetry};
+use std::path::PathBuf;
 
 mod seed;
 
@@ -18,6 +19,14 @@ mod seed;
 struct Cli {
     #[command(subcommand)]
     command: Option<Commands>,
+
+    /// TOML config file (defaults to $ABBOT_CONFIG); environment variables override it
+    #[arg(long, global = true)]
+    config: Option<PathBuf>,
+
+    /// Print the effective configuration with secrets redacted, then exit
+    #[arg(long, global = true)]
+    print_config: bool,
 }
 
 #[derive(Subcommand)]
@@ -39,54 +48,32 @@ enum Commands {
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
-    // Load environment variables from .env file
-    dotenvy::dotenv().ok();
+    let cli = Cli::parse();
+
+    // Load .env and the config file, refusing to start on invalid settings
+    settings::startup(cli.config.as_deref(), cli.print_config)?;
 
     // Initialize tracing with production-friendly defaults
     // Filter out noisy crates to avoid hitting Railway's 500 logs/sec limit
-    tracing_subscriber::registry()
-        .with(
-            EnvFilter::try_from_default_env().unwrap_or_else(|_| {
-                "api_server=info,tower_http=error,polymarket_core=warn,auth=info,sqlx=warn,hyper=warn,tungstenite=warn,h2=warn".into()
-            }),
-        )
-        .with(tracing_subscriber::fmt::layer())
-        .init();
-
-    let cli = Cli::parse();
+    let _telemetry = telemetry::init_tracing(
+        "api-server",
+        "api_server=info,tower_http=error,polymarket_core=warn,auth=info,sqlx=warn,hyper=warn,tungstenite=warn,h2=warn",
+    );
 
     // Create database connection pool with retry
     let db_config = DatabaseConfig {
-        url: std::env::var("DATABASE_URL").expect("DATABASE_URL must be set"),
-        max_connections: std::env::var("DATABASE_MAX_CONNECTIONS")
-            .ok()
-            .and_then(|s| s.parse().ok())
-            .unwrap_or(20),
-        max_retries: std::env::var("DB_RETRY_MAX_ATTEMPTS")
-            .ok()
-            .and_then(|s| s.parse().ok())
-            .unwrap_or(5),
-        retry_base_delay_ms: std::env::var("DB_RETRY_BASE_DELAY_MS")
-            .ok()
-            .and_then(|s| s.parse().ok())
-            .unwrap_or(1000),
-        retry_max_delay_ms: std::env::var("DB_RETRY_MAX_DELAY_MS")
-            .ok()
-            .and_then(|s| s.parse().ok())
-            .unwrap_or(30000),
-        acquire_timeout_secs: Some(
-            std::env::var("DB_ACQUIRE_TIMEOUT_SECS")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(5),
-        ),
+        url: settings::get_opt("DATABASE_URL").expect("DATABASE_URL must be set"),
+        // One pool serves both the HTTP handlers and the background workers.
+        max_connections: settings::explicit("DATABASE_MAX_CONNECTIONS").unwrap_or(20),
+        max_retries: settings::get("DB_RETRY_MAX_ATTEMPTS"),
+        retry_base_delay_ms: settings::get("DB_RETRY_BASE_DELAY_MS"),
+        retry_max_delay_ms: settings::get("DB_RETRY_MAX_DELAY_MS"),
+        acquire_timeout_secs: Some(settings::get("DB_ACQUIRE_TIMEOUT_SECS")),
     };
     let pool = polymarket_core::db::create_pool(&db_config).await?;
 
     // Run migrations
-    let skip_migrations = std::env::var("SKIP_MIGRATIONS")
-        .map(|v| v == "true" || v == "1")
-        .unwrap_or(false);
+    let skip_migrations: bool = settings::get("SKIP_MIGRATIONS");
 
     if !skip_migrations {
         tracing::info!("Running database migrations...");
@@ -155,7 +142,7 @@ async fn run_server(pool: sqlx::PgPool) -> anyhow::Result<()> {
     tracing::info!("API Server starting up...");
 
     // Validate JWT_SECRET for security
-    let jwt_secret = std::env::var("JWT_SECRET").unwrap_or_default();
+    let jwt_secret: String = settings::get("JWT_SECRET");
     if jwt_secret.is_empty() || jwt_secret == "development-secret-change-in-production" {
         tracing::error!("JWT_SECRET must be set to a secure value (not the default)");
         tracing::error!("Generate a secure secret: openssl rand -base64 32");
@@ -0,0 +1,287 @@
+//! Market-conditions monitor that trips or throttles the circuit breaker.
+//!
+//! Every interval the monitor builds a `MarketConditionsSample` from the
+//! arb-monitor runtime stats published to Redis (spread health and order book
+//! freshness from the live order-book stream) plus recent `trade_events`
+//! (one-legged arb fills and live order rejects). The sample is fed to the
+//! risk-manager detector; each level transition is applied to the shared
+//! circuit breaker and recorded in the audit log.
+
+use auth::{AuditAction, AuditEvent, AuditLogger};
+use chrono::{DateTime, Utc};
+use redis::AsyncCommands;
+use risk_manager::circuit_breaker::{CircuitBreaker, TripReason};
+use risk_manager::market_conditions::{
+    MarketConditionsConfig, MarketConditionsDetector, MarketConditionsLevel,
+    MarketConditionsSample, MarketConditionsTransition,
+};
+use serde::Deserialize;
+use sqlx::PgPool;
+use std::sync::Arc;
+use tracing::{info, warn};
+
+const ARB_RUNTIME_STATS_LATEST: &str = "arb:runtime:stats:latest";
+const AUDIT_RESOURCE: &str = "circuit_breaker/market_conditions";
+
+#[derive(Debug, Clone)]
+pub struct MarketConditionsMonitorConfig {
+    pub enabled: bool,
+    /// When false, transitions are audited but not applied to the circuit breaker.
+    pub apply: bool,
+    pub interval_secs: u64,
+    /// Look-back window for one-legged fills and reject rate.
+    pub window_minutes: i64,
+    pub redis_url: String,
+    pub detector: MarketConditionsConfig,
+}
+
+impl MarketConditionsMonitorConfig {
+    pub fn from_env() -> Self {
+        Self {
+            enabled: polymarket_core::settings::get("MARKET_CONDITIONS_ENABLED"),
+            apply: polymarket_core::settings::get("MARKET_CONDITIONS_APPLY"),
+            interval_secs: polymarket_core::settings::get("MARKET_CONDITIONS_INTERVAL_SECS"),
+            window_minutes: polymarket_core::settings::get("MARKET_CONDITIONS_WINDOW_MINUTES"),
+            redis_url: polymarket_core::settings::get("REDIS_URL"),
+            detector: MarketConditionsConfig {
+                min_markets_sampled: polymarket_core::settings::get(
+                    "MARKET_CONDITIONS_MIN_MARKETS",
+                ),
+                throttle_wide_spread_fraction: polymarket_core::settings::get(
+                    "MARKET_CONDITIONS_THROTTLE_WIDE_SPREAD_FRACTION",
+                ),
+                trip_wide_spread_fraction: polymarket_core::settings::get(
+                    "MARKET_CONDITIONS_TRIP_WIDE_SPREAD_FRACTION",
+                ),
+                throttle_book_stale_secs: polymarket_core::settings::get(
+                    "MARKET_CONDITIONS_THROTTLE_BOOK_STALE_SECS",
+                ),
+                trip_book_stale_secs: polymarket_core::settings::get(
+                    "MARKET_CONDITIONS_TRIP_BOOK_STALE_SECS",
+                ),
+                throttle_one_legged_fills: polymarket_core::settings::get(
+                    "MARKET_CONDITIONS_THROTTLE_ONE_LEGGED_FILLS",
+                ),
+                trip_one_legged_fills: polymarket_core::settings::get(
+                    "MARKET_CONDITIONS_TRIP_ONE_LEGGED_FILLS",
+                ),
+                throttle_reject_rate: polymarket_core::settings::get(
+                    "MARKET_CONDITIONS_THROTTLE_REJECT_RATE",
+                ),
+                trip_reject_rate: polymarket_core::settings::get(
+                    "MARKET_CONDITIONS_TRIP_REJECT_RATE",
+                ),
+                min_orders_for_reject_rate: polymarket_core::settings::get(
+                    "MARKET_CONDITIONS_MIN_ORDERS",
+                ),
+                throttle_capacity: polymarket_core::settings::get(
+                    "MARKET_CONDITIONS_THROTTLE_CAPACITY",
+                ),
+                recovery_samples: polymarket_core::settings::get(
+                    "MARKET_CONDITIONS_RECOVERY_SAMPLES",
+                ),
+            },
+        }
+    }
+}
+
+/// Subset of arb-monitor `RuntimeStats` used for market-conditions sampling.
+#[derive(Debug, Clone, Default, Deserialize)]
+struct ArbRuntimeStats {
+    #[serde(default)]
+    spread_sampled_markets: f64,
+    #[serde(default)]
+    wide_spread_markets: f64,
+    #[serde(default)]
+    ws_last_orderbook_update_at: Option<DateTime<Utc>>,
+}
+
+pub fn spawn_market_conditions_monitor(
+    config: MarketConditionsMonitorConfig,
+    pool: PgPool,
+    circuit_breaker: Arc<CircuitBreaker>,
+    audit_logger: Arc<AuditLogger>,
+) {
+    if !config.enabled {
+        info!("Market-conditions monitor disabled (MARKET_CONDITIONS_ENABLED != true)");
+        return;
+    }
+
+    info!(
+        interval_secs = config.interval_secs,
+        window_minutes = config.window_minutes,
+        apply = config.apply,
+        "Spawning market-conditions monitor"
+    );
+
+    tokio::spawn(run_loop(config, pool, circuit_breaker, audit_logger));
+}
+
+async fn run_loop(
+    config: MarketConditionsMonitorConfig,
+    pool: PgPool,
+    circuit_breaker: Arc<CircuitBreaker>,
+    audit_logger: Arc<AuditLogger>,
+) {
+    // Resume in the tripped state if a market-conditions trip survived a restart,
+    // otherwise the detector would never release it.
+    let initial_level = match circuit_breaker.state().await.trip_reason {
+        Some(TripReason::MarketConditions) => MarketConditionsLevel::Tripped,
+        _ => MarketConditionsLevel::Normal,
+    };
+    let mut detector = MarketConditionsDetector::with_level(config.detector.clone(), initial_level);
+    let mut redis: Option<redis::aio::ConnectionManager> = None;
+
+    let mut ticker = tokio::time::interval(tokio::time::Duration::from_secs(
+        config.interval_secs.max(1),
+    ));
+    loop {
+        ticker.tick().await;
+
+        if redis.is_none() {
+            redis = connect_redis(&config.redis_url).await;
+        }
+
+        let sample = match collect_sample(&pool, redis.as_mut(), config.window_minutes).await {
+            Ok(sample) => sample,
+            Err(e) => {
+                warn!(error = %e, "Market-conditions sample collection failed");
+                continue;
+            }
+        };
+
+        let Some(transition) = detector.observe(&sample) else {
+            continue;
+        };
+
+        if config.apply {
+            apply_transition(&circuit_breaker, &transition, &config.detector).await;
+        }
+        audit_transition(&audit_logger, &transition, &sample, config.apply);
+    }
+}
+
+async fn connect_redis(url: &str) -> Option<redis::aio::ConnectionManager> {
+    let client = match redis::Client::open(url) {
+        Ok(client) => client,
+        Err(e) => {
+            warn!(error = %e, "Invalid Redis URL for market-conditions monitor");
+            return None;
+        }
+    };
+    match redis::aio::ConnectionManager::new(client).await {
+        Ok(conn) => Some(conn),
+        Err(e) => {
+            warn!(error = %e, "Market-conditions monitor could not connect to Redis");
+            None
+        }
+    }
+}
+
+async fn collect_sample(
+    pool: &PgPool,
+    redis: Option<&mut redis::aio::ConnectionManager>,
+    window_minutes: i64,
+) -> anyhow::Result<MarketConditionsSample> {
+    let runtime_stats = match redis {
+        Some(conn) => {
+            let raw: Option<String> = conn.get(ARB_RUNTIME_STATS_LATEST).await.unwrap_or(None);
+            raw.and_then(|v| serde_json::from_str::<ArbRuntimeStats>(&v).ok())
+                .unwrap_or_default()
+        }
+        None => ArbRuntimeStats::default(),
+    };
+
+    let (one_legged_fills, order_attempts, order_rejects): (i64, i64, i64) = sqlx::query_as(
+        r#"
+        SELECT
+            COUNT(*) FILTER (WHERE event_type = 'one_legged_exit_ready') AS one_legged_fills,
+            COUNT(*) FILTER (WHERE event_type = 'entry_requested') AS order_attempts,
+            COUNT(*) FILTER (WHERE event_type = 'entry_failed') AS order_rejects
+        FROM trade_events
+        WHERE execution_mode = 'live'
+          AND occurred_at >= NOW() - ($1::bigint * INTERVAL '1 minute')
+        "#,
+    )
+    .bind(window_minutes)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(MarketConditionsSample {
+        observed_at: Utc::now(),
+        markets_sampled: runtime_stats.spread_sampled_markets.max(0.0) as u32,
+        wide_spread_markets: runtime_stats.wide_spread_markets.max(0.0) as u32,
+        last_book_update_at: runtime_stats.ws_last_orderbook_update_at,
+        one_legged_fills: one_legged_fills.max(0) as u32,
+        order_attempts: order_attempts.max(0) as u32,
+        order_rejects: order_rejects.max(0) as u32,
+    })
+}
+
+async fn apply_transition(
+    circuit_breaker: &CircuitBreaker,
+    transition: &MarketConditionsTransition,
+    detector_config: &MarketConditionsConfig,
+) {
+    let summary = transition.summary();
+    match transition.to {
+        MarketConditionsLevel::Tripped => {
+            if !circuit_breaker.trip_market_conditions(&summary).await {
+                info!(
+                    summary = %summary,
+                    "Market conditions abnormal but circuit breaker already tripped or disabled"
+                );
+            }
+        }
+        MarketConditionsLevel::Throttled => {
+            if transition.from == MarketConditionsLevel::Tripped {
+                circuit_breaker.clear_market_conditions().await;
+            }
+            circuit_breaker
+                .set_market_throttle(Some(detector_config.throttle_capacity))
+                .await;
+        }
+        MarketConditionsLevel::Normal => {
+            if transition.from == MarketConditionsLevel::Tripped {
+                circuit_breaker.clear_market_conditions().await;
+            }
+            circuit_breaker.set_market_throttle(None).await;
+        }
+    }
+}
+
+fn audit_transition(
+    audit_logger: &AuditLogger,
+    transition: &MarketConditionsTransition,
+    sample: &MarketConditionsSample,
+    applied: bool,
+) {
+    let action = if transition.to == MarketConditionsLevel::Tripped {
+        AuditAction::CircuitBreakerTripped
+    } else if transition.from == MarketConditionsLevel::Tripped {
+        AuditAction::CircuitBreakerReset
+    } else {
+        AuditAction::Custom("market_conditions_throttle".to_string())
+    };
+
+    warn!(
+        from = transition.from.as_str(),
+        to = transition.to.as_str(),
+        applied,
+        summary = %transition.summary(),
+        "Market-conditions level changed"
+    );
+
+    let event = AuditEvent::builder(action, AUDIT_RESOURCE)
+        .user("system")
+        .details(serde_json::json!({
+            "from": transition.from,
+            "to": transition.to,
+            "summary": transition.summary(),
+            "triggers": transition.triggers,
+            "sample": sample,
+            "applied": applied,
+        }))
+        .build();
+    audit_logger.log(event);
+}
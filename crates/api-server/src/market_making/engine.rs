@@ -0,0 +1,897 @@
+//! Market-making loop: keeps post-only quotes resting on selected markets.
+//!
+//! Quotes are refreshed on every YES book update and pulled when the book
+//! goes stale, turns one-sided, or its mid moves more than
+//! `vol_spike` within `vol_window_secs` (after which quoting pauses). In
+//! paper mode resting quotes are filled by trade prints that reach them; in
+//! live mode each quote is looked up by order id for its matched size, both
+//! on every poll and after every cancel, so a quote cancelled elsewhere (the
+//! dead-man's switch, the CLOB, expiry) is never booked as a fill.
+
+use chrono::Utc;
+use polymarket_core::api::clob::{cached_tick_size, MarketChannelEvent, OrderBookUpdate};
+use polymarket_core::api::ClobClient;
+use polymarket_core::types::OrderSide;
+use prometheus::{GaugeVec, IntCounterVec, Opts};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::{Arc, LazyLock};
+use std::time;
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+use uuid::Uuid;
+
+use risk_manager::circuit_breaker::CircuitBreaker;
+use trading_engine::OrderExecutor;
+
+use super::inventory::{Fill, InventorySnapshot, MarketInventory};
+use super::quoting::{
+    compute_quotes, execution_leg, BookTop, MidWindow, Outcome, Quote, QuoteParams, QuoteSide,
+};
+use crate::trade_events::{NewTradeEvent, TradeEventRecorder, TradeEventUpdate};
+
+/// Strategy name on trade events and P&L snapshots.
+pub const STRATEGY: &str = "market_making";
+
+static INVENTORY: LazyLock<GaugeVec> = LazyLock::new(|| {
+    let gauge = GaugeVec::new(
+        Opts::new(
+            "mm_inventory_shares",
+            "Net YES shares held by the market maker",
+        )
+        .namespace("abbot"),
+        &["market"],
+    )
+    .expect("metric definition is valid");
+    register(Box::new(gauge.clone()));
+    gauge
+});
+
+static FILLS: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    let counter = IntCounterVec::new(
+        Opts::new("mm_fills_total", "Market-maker quote fills").namespace("abbot"),
+        &["side"],
+    )
+    .expect("metric definition is valid");
+    register(Box::new(counter.clone()));
+    counter
+});
+
+static QUOTES_PULLED: LazyLock<IntCounterVec> = LazyLock::new(|| {
+    let counter = IntCounterVec::new(
+        Opts::new(
+            "mm_quotes_pulled_total",
+            "Times the market maker cancelled its quotes on a market",
+        )
+        .namespace("abbot"),
+        &["reason"],
+    )
+    .expect("metric definition is valid");
+    register(Box::new(counter.clone()));
+    counter
+});
+
+fn register(collector: Box<dyn prometheus::core::Collector>) {
+    if let Err(e) = polymarket_core::metrics::global()
+        .registry
+        .register(collector)
+    {
+        warn!(error = %e, "Failed to register market maker metrics");
+    }
+}
+
+/// Configuration for the market maker.
+#[derive(Debug, Clone)]
+pub struct MarketMakerConfig {
+    pub enabled: bool,
+    /// Condition ids to quote.
+    pub markets: Vec<String>,
+    /// Distance from the reservation price to each quote (e.g. 0.02 = 2 cents).
+    pub half_spread: f64,
+    /// Reservation price shift at full inventory.
+    pub inventory_skew: f64,
+    /// Shares per quote.
+    pub quote_size: f64,
+    /// Cap on net YES (or NO) shares per market.
+    pub max_inventory: f64,
+    /// Minimum time between cancel/replace cycles on one market.
+    pub min_requote_ms: u64,
+    /// Pull quotes when the book has not updated for this long.
+    pub stale_book_secs: u64,
+    /// Lookback for the volatility check.
+    pub vol_window_secs: u64,
+    /// Mid range within the lookback that pulls quotes.
+    pub vol_spike: f64,
+    /// How long to stay out after a volatility spike.
+    pub pause_secs: u64,
+    /// Horizon for adverse-selection markouts.
+    pub markout_secs: u64,
+    /// How often live fills are read back from the CLOB.
+    pub fill_poll_secs: u64,
+}
+
+impl MarketMakerConfig {
+    pub fn from_env() -> Self {
+        Self {
+            enabled: polymarket_core::settings::get("MARKET_MAKER_ENABLED"),
+            markets: polymarket_core::settings::get_opt("MARKET_MAKER_MARKETS").unwrap_or_default(),
+            half_spread: polymarket_core::settings::get("MARKET_MAKER_HALF_SPREAD"),
+            inventory_skew: polymarket_core::settings::get("MARKET_MAKER_INVENTORY_SKEW"),
+            quote_size: polymarket_core::settings::get("MARKET_MAKER_QUOTE_SIZE"),
+            max_inventory: polymarket_core::settings::get("MARKET_MAKER_MAX_INVENTORY"),
+            min_requote_ms: polymarket_core::settings::get("MARKET_MAKER_MIN_REQUOTE_MS"),
+            stale_book_secs: polymarket_core::settings::get("MARKET_MAKER_STALE_BOOK_SECS"),
+            vol_window_secs: polymarket_core::settings::get("MARKET_MAKER_VOL_WINDOW_SECS"),
+            vol_spike: polymarket_core::settings::get("MARKET_MAKER_VOL_SPIKE"),
+            pause_secs: polymarket_core::settings::get("MARKET_MAKER_PAUSE_SECS"),
+            markout_secs: polymarket_core::settings::get("MARKET_MAKER_MARKOUT_SECS"),
+            fill_poll_secs: polymarket_core::settings::get("MARKET_MAKER_FILL_POLL_SECS"),
+        }
+    }
+
+    fn quote_params(&self, tick: f64) -> QuoteParams {
+        QuoteParams {
+            half_spread: self.half_spread,
+            inventory_skew: self.inventory_skew,
+            quote_size: self.quote_size,
+            max_inventory: self.max_inventory,
+            tick,
+        }
+    }
+}
+
+/// A quote resting on the book.
+#[derive(Debug, Clone)]
+struct RestingQuote {
+    order_id: String,
+    /// YES-terms price.
+    price: f64,
+    outcome: Outcome,
+    /// Price on the outcome's own book.
+    leg_price: f64,
+    size: f64,
+    filled: f64,
+    live: bool,
+}
+
+impl RestingQuote {
+    fn remaining(&self) -> f64 {
+        (self.size - self.filled).max(0.0)
+    }
+}
+
+/// Quoting state for one market.
+struct MarketState {
+    market_id: String,
+    yes_token: String,
+    no_token: String,
+    tick: f64,
+    book: Option<BookTop>,
+    book_at: Option<time::Instant>,
+    mids: MidWindow,
+    paused_until: Option<time::Instant>,
+    last_requote: Option<time::Instant>,
+    quotes: HashMap<QuoteSide, RestingQuote>,
+    /// Live quotes whose cancel could not be confirmed; retried on every poll.
+    cancelling: Vec<(QuoteSide, RestingQuote)>,
+    inventory: MarketInventory,
+}
+
+/// Shared handles for the loop.
+struct Context {
+    config: MarketMakerConfig,
+    order_executor: Arc<OrderExecutor>,
+    circuit_breaker: Arc<CircuitBreaker>,
+    pool: PgPool,
+    recorder: TradeEventRecorder,
+}
+
+impl Context {
+    async fn execution_mode(&self) -> &'static str {
+        if self.order_executor.is_live_ready().await {
+            "live"
+        } else {
+            "paper"
+        }
+    }
+}
+
+/// Spawn the market maker. Does nothing unless enabled with at least one market.
+pub fn spawn_market_maker(
+    config: MarketMakerConfig,
+    order_executor: Arc<OrderExecutor>,
+    circuit_breaker: Arc<CircuitBreaker>,
+    clob_client: Arc<ClobClient>,
+    pool: PgPool,
+    trade_event_tx: broadcast::Sender<TradeEventUpdate>,
+    heartbeat: Arc<AtomicI64>,
+) -> Option<JoinHandle<()>> {
+    if !config.enabled || config.markets.is_empty() {
+        info!("Market maker disabled (MARKET_MAKER_ENABLED != true or no MARKET_MAKER_MARKETS)");
+        return None;
+    }
+    info!(
+        markets = config.markets.len(),
+        half_spread = config.half_spread,
+        quote_size = config.quote_size,
+        max_inventory = config.max_inventory,
+        "Spawning market maker"
+    );
+
+    let ctx = Context {
+        config,
+        order_executor,
+        circuit_breaker,
+        pool: pool.clone(),
+        recorder: TradeEventRecorder::new(pool, trade_event_tx),
+    };
+    Some(tokio::spawn(run(ctx, clob_client, heartbeat)))
+}
+
+async fn run(ctx: Context, clob_client: Arc<ClobClient>, heartbeat: Arc<AtomicI64>) {
+    let mut markets = resolve_markets(&ctx.config.markets, &clob_client, &ctx.pool).await;
+    if markets.is_empty() {
+        warn!("Market maker: none of the configured markets could be resolved");
+        return;
+    }
+    let token_to_market: HashMap<String, String> = markets
+        .values()
+        .flat_map(|m| {
+            [
+                (m.yes_token.clone(), m.market_id.clone()),
+                (m.no_token.clone(), m.market_id.clone()),
+            ]
+        })
+        .collect();
+
+    let mut subscription = match clob_client
+        .subscribe_orderbook(token_to_market.keys().cloned().collect())
+        .await
+    {
+        Ok(subscription) => subscription,
+        Err(e) => {
+            warn!(error = %e, "Market maker: order book subscription failed");
+            return;
+        }
+    };
+    let mut events = subscription.subscribe_events();
+    let mut housekeeping = tokio::time::interval(time::Duration::from_secs(1));
+    let mut fill_poll =
+        tokio::time::interval(time::Duration::from_secs(ctx.config.fill_poll_secs.max(1)));
+
+    loop {
+        tokio::select! {
+            update = subscription.recv() => {
+                let Some(update) = update else {
+                    warn!("Market maker: order book stream closed");
+                    break;
+                };
+                let Some(state) = token_to_market.get(&update.asset_id).and_then(|id| markets.get_mut(id)) else {
+                    continue;
+                };
+                if update.asset_id == state.yes_token {
+                    on_book(&ctx, state, &update).await;
+                }
+            }
+            event = events.recv() => match event {
+                Ok(MarketChannelEvent::Trade(print)) => {
+                    let Some(state) = token_to_market.get(&print.asset_id).and_then(|id| markets.get_mut(id)) else {
+                        continue;
+                    };
+                    // Express the print in YES terms: a NO buy is a YES sell.
+                    let (price, taker_side) = if print.asset_id == state.yes_token {
+                        (print.price.to_f64().unwrap_or(0.0), print.side)
+                    } else {
+                        let flipped = match print.side {
+                            OrderSide::Buy => OrderSide::Sell,
+                            OrderSide::Sell => OrderSide::Buy,
+                        };
+                        (1.0 - print.price.to_f64().unwrap_or(1.0), flipped)
+                    };
+                    on_paper_print(&ctx, state, price, taker_side, print.size.to_f64().unwrap_or(0.0)).await;
+                }
+                Ok(MarketChannelEvent::TickSizeChange(change)) => {
+                    if let Some(state) = markets.values_mut().find(|m| m.yes_token == change.asset_id) {
+                        state.tick = change.new_tick_size.to_f64().unwrap_or(state.tick);
+                    }
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    debug!(skipped, "Market maker: trade print stream lagged");
+                }
+                Err(broadcast::error::RecvError::Closed) => {
+                    events = subscription.subscribe_events();
+                }
+            },
+            _ = housekeeping.tick() => {
+                heartbeat.store(Utc::now().timestamp(), Ordering::Relaxed);
+                for state in markets.values_mut() {
+                    on_housekeeping(&ctx, state).await;
+                }
+            }
+            _ = fill_poll.tick() => {
+                for state in markets.values_mut() {
+                    poll_live_fills(&ctx, state).await;
+                }
+            }
+        }
+    }
+
+    for state in markets.values_mut() {
+        pull_quotes(&ctx, state, "shutdown").await;
+    }
+}
+
+/// Look up each market's YES/NO tokens and tick size, and restore its inventory.
+async fn resolve_markets(
+    ids: &[String],
+    clob_client: &ClobClient,
+    pool: &PgPool,
+) -> HashMap<String, MarketState> {
+    let mut markets = HashMap::new();
+    for id in ids {
+        let market = match clob_client.get_market_by_id(id).await {
+            Ok(market) => market,
+            Err(e) => {
+                warn!(market_id = %id, error = %e, "Market maker: failed to load market");
+                continue;
+            }
+        };
+        let token = |name: &str| {
+            market
+                .outcomes
+                .iter()
+                .find(|o| o.name.eq_ignore_ascii_case(name))
+                .map(|o| o.token_id.clone())
+        };
+        let (yes_token, no_token) = match (token("yes"), token("no")) {
+            (Some(yes), Some(no)) => (yes, no),
+            _ if market.outcomes.len() == 2 => (
+                market.outcomes[0].token_id.clone(),
+                market.outcomes[1].token_id.clone(),
+            ),
+            _ => {
+                warn!(market_id = %id, "Market maker: market is not binary, skipping");
+                continue;
+            }
+        };
+        let tick = cached_tick_size(&yes_token, time::Duration::from_secs(3600))
+            .and_then(|t| t.to_f64())
+            .unwrap_or(0.01);
+        let inventory = match load_inventory(pool, id).await {
+            Ok(snapshot) => MarketInventory::restore(snapshot.unwrap_or_default()),
+            Err(e) => {
+                // Quoting from a zero inventory could breach the cap.
+                warn!(market_id = %id, error = %e, "Market maker: failed to load inventory, skipping");
+                continue;
+            }
+        };
+        INVENTORY
+            .with_label_values(&[id.as_str()])
+            .set(inventory.position());
+        markets.insert(
+            id.clone(),
+            MarketState {
+                market_id: id.clone(),
+                yes_token,
+                no_token,
+                tick,
+                book: None,
+                book_at: None,
+                mids: MidWindow::default(),
+                paused_until: None,
+                last_requote: None,
+                quotes: HashMap::new(),
+                cancelling: Vec::new(),
+                inventory,
+            },
+        );
+    }
+    markets
+}
+
+async fn on_book(ctx: &Context, state: &mut MarketState, update: &OrderBookUpdate) {
+    let now = time::Instant::now();
+    if update.invalidated {
+        state.book = None;
+        pull_quotes(ctx, state, "book_invalidated").await;
+        return;
+    }
+    let Some(book) = BookTop::from_levels(&update.bids, &update.asks) else {
+        state.book = None;
+        pull_quotes(ctx, state, "one_sided_book").await;
+        return;
+    };
+    state.book = Some(book);
+    state.book_at = Some(now);
+
+    let window = time::Duration::from_secs(ctx.config.vol_window_secs);
+    let range = state.mids.push(book.mid(), now, window);
+    if range >= ctx.config.vol_spike {
+        info!(
+            market_id = %state.market_id,
+            mid_range = range,
+            "Market maker: volatility spike, pulling quotes"
+        );
+        pull_quotes(ctx, state, "volatility_spike").await;
+        state.paused_until = Some(now + time::Duration::from_secs(ctx.config.pause_secs));
+        state.mids.clear();
+        return;
+    }
+    if state.paused_until.is_some_and(|until| now < until) {
+        return;
+    }
+    requote(ctx, state, now).await;
+}
+
+/// Bring resting quotes in line with where they should be for the current book.
+async fn requote(ctx: &Context, state: &mut MarketState, now: time::Instant) {
+    if !ctx.circuit_breaker.can_trade().await {
+        pull_quotes(ctx, state, "circuit_breaker").await;
+        return;
+    }
+    let min_interval = time::Duration::from_millis(ctx.config.min_requote_ms);
+    if state
+        .last_requote
+        .is_some_and(|last| now.saturating_duration_since(last) < min_interval)
+    {
+        return;
+    }
+    let Some(book) = state.book else {
+        return;
+    };
+    let params = ctx.config.quote_params(state.tick);
+    let desired = compute_quotes(
+        book.microprice(),
+        &book,
+        state.inventory.position(),
+        &params,
+    );
+
+    let mut changed = false;
+    for (side, want) in [(QuoteSide::Bid, desired.bid), (QuoteSide::Ask, desired.ask)] {
+        let keep = match (state.quotes.get(&side), want) {
+            (Some(resting), Some(want)) => {
+                (resting.price - want.price).abs() < state.tick / 2.0
+                    && resting.remaining() >= want.size.min(resting.size) - 1e-9
+            }
+            (None, None) => true,
+            _ => false,
+        };
+        if keep {
+            continue;
+        }
+        changed = true;
+        if let Some(resting) = state.quotes.remove(&side) {
+            cancel(ctx, state, side, resting).await;
+        }
+        // An unconfirmed cancel can still fill; wait for it before replacing.
+        if state.cancelling.iter().any(|(s, _)| *s == side) {
+            continue;
+        }
+        if let Some(want) = want {
+            if let Some(placed) = place(ctx, state, side, want).await {
+                state.quotes.insert(side, placed);
+            }
+        }
+    }
+    if changed {
+        state.last_requote = Some(now);
+        debug!(
+            market_id = %state.market_id,
+            reservation = desired.reservation,
+            bid = ?desired.bid.map(|q| q.price),
+            ask = ?desired.ask.map(|q| q.price),
+            inventory = state.inventory.position(),
+            "Market maker requoted"
+        );
+    }
+}
+
+async fn place(
+    ctx: &Context,
+    state: &MarketState,
+    side: QuoteSide,
+    quote: Quote,
+) -> Option<RestingQuote> {
+    let mut leg = execution_leg(side, quote, state.inventory.position());
+    leg.size = leg
+        .size
+        .min(inventory_room(state, side, ctx.config.max_inventory));
+    if leg.size <= 0.0 {
+        return None;
+    }
+    let live = ctx.order_executor.is_live_ready().await;
+    let order_id = if live {
+        let token = match leg.outcome {
+            Outcome::Yes => &state.yes_token,
+            Outcome::No => &state.no_token,
+        };
+        match ctx
+            .order_executor
+            .place_post_only_order(token, leg.side, to_decimal(leg.price), to_decimal(leg.size))
+            .await
+        {
+            Ok(order_id) => order_id,
+            Err(e) => {
+                warn!(market_id = %state.market_id, side = side.as_str(), error = %e, "Market maker: failed to place quote");
+                return None;
+            }
+        }
+    } else {
+        format!("paper-{}", Uuid::new_v4())
+    };
+    Some(RestingQuote {
+        order_id,
+        price: quote.price,
+        outcome: leg.outcome,
+        leg_price: leg.price,
+        size: leg.size,
+        filled: 0.0,
+        live,
+    })
+}
+
+/// Shares `side` may still add before the position reaches `max_inventory`,
+/// counting what resting and not-yet-cancelled quotes on that side could fill.
+fn inventory_room(state: &MarketState, side: QuoteSide, max_inventory: f64) -> f64 {
+    let open: f64 = state
+        .quotes
+        .get(&side)
+        .into_iter()
+        .chain(
+            state
+                .cancelling
+                .iter()
+                .filter(|(s, _)| *s == side)
+                .map(|(_, q)| q),
+        )
+        .map(RestingQuote::remaining)
+        .sum();
+    max_inventory - side.sign() * state.inventory.position() - open
+}
+
+/// Cancel a quote and book whatever it filled before the cancel landed. A
+/// live quote whose final state cannot be read stays tracked for retry.
+async fn cancel(ctx: &Context, state: &mut MarketState, side: QuoteSide, mut quote: RestingQuote) {
+    if !quote.live {
+        return;
+    }
+    match ctx
+        .order_executor
+        .cancel_live_order_and_read_fill(&quote.order_id)
+        .await
+    {
+        Ok(fill) => {
+            let new_fill = fill.matched.to_f64().unwrap_or(0.0).min(quote.size) - quote.filled;
+            if new_fill > 1e-9 {
+                quote.filled += new_fill;
+                book_fill(ctx, state, side, &quote, new_fill).await;
+            }
+        }
+        Err(e) => {
+            warn!(order_id = %quote.order_id, error = %e, "Market maker: failed to cancel quote, will retry");
+            state.cancelling.push((side, quote));
+        }
+    }
+}
+
+/// Cancel every resting quote on a market.
+async fn pull_quotes(ctx: &Context, state: &mut MarketState, reason: &str) {
+    if state.quotes.is_empty() {
+        return;
+    }
+    let quotes: Vec<_> = state.quotes.drain().collect();
+    for (side, quote) in quotes {
+        cancel(ctx, state, side, quote).await;
+    }
+    QUOTES_PULLED.with_label_values(&[reason]).inc();
+
+    let mut event = NewTradeEvent::new(
+        STRATEGY,
+        ctx.execution_mode().await,
+        "market_maker",
+        &state.market_id,
+        "mm_quotes_pulled",
+    );
+    event.reason = Some(reason.to_string());
+    event.metadata = serde_json::json!({ "inventory": state.inventory.position() });
+    ctx.recorder.record_warn(event).await;
+}
+
+/// Paper fills: a print at or through a resting quote fills it, up to the
+/// print's size.
+async fn on_paper_print(
+    ctx: &Context,
+    state: &mut MarketState,
+    price: f64,
+    taker_side: OrderSide,
+    size: f64,
+) {
+    let side = match taker_side {
+        OrderSide::Sell => QuoteSide::Bid,
+        OrderSide::Buy => QuoteSide::Ask,
+    };
+    let Some(quote) = state.quotes.get(&side) else {
+        return;
+    };
+    // A taker sell fills bids at or above its price; a taker buy, asks at or below.
+    let reached = match side {
+        QuoteSide::Bid => price <= quote.price + 1e-9,
+        QuoteSide::Ask => price >= quote.price - 1e-9,
+    };
+    if quote.live || !reached {
+        return;
+    }
+    let filled = size.min(quote.remaining());
+    if filled > 0.0 {
+        record_fill(ctx, state, side, filled).await;
+    }
+}
+
+/// Live fills: look each resting quote up by order id and book any newly
+/// matched size. A quote that is no longer open (filled, or cancelled
+/// elsewhere) is dropped once its final fill is booked. Quotes whose cancel
+/// failed earlier are retried first.
+async fn poll_live_fills(ctx: &Context, state: &mut MarketState) {
+    for (side, quote) in std::mem::take(&mut state.cancelling) {
+        cancel(ctx, state, side, quote).await;
+    }
+
+    let live: Vec<(QuoteSide, String)> = state
+        .quotes
+        .iter()
+        .filter(|(_, q)| q.live)
+        .map(|(side, q)| (*side, q.order_id.clone()))
+        .collect();
+    for (side, order_id) in live {
+        let fill = match ctx.order_executor.live_order_fill(&order_id).await {
+            Ok(fill) => fill,
+            Err(e) => {
+                debug!(order_id = %order_id, error = %e, "Market maker: order status poll failed");
+                continue;
+            }
+        };
+        let Some(quote) = state.quotes.get(&side) else {
+            continue;
+        };
+        let new_fill = fill.matched.to_f64().unwrap_or(0.0).min(quote.size) - quote.filled;
+        if new_fill > 1e-9 {
+            record_fill(ctx, state, side, new_fill).await;
+        }
+        if !fill.open && state.quotes.remove(&side).is_some() {
+            debug!(order_id = %order_id, "Market maker: quote closed on the CLOB");
+        }
+    }
+}
+
+/// Book a fill on a tracked quote, dropping the quote once exhausted.
+async fn record_fill(ctx: &Context, state: &mut MarketState, side: QuoteSide, size: f64) {
+    let Some(quote) = state.quotes.get_mut(&side) else {
+        return;
+    };
+    quote.filled += size;
+    let quote = quote.clone();
+    if quote.remaining() <= 1e-9 {
+        state.quotes.remove(&side);
+    }
+    book_fill(ctx, state, side, &quote, size).await;
+}
+
+/// Book a fill against inventory, persist it, and report it.
+async fn book_fill(
+    ctx: &Context,
+    state: &mut MarketState,
+    side: QuoteSide,
+    quote: &RestingQuote,
+    size: f64,
+) {
+    let mid = state.book.map_or(quote.price, |b| b.mid());
+    let outcome = state.inventory.on_fill(Fill {
+        side,
+        price: quote.price,
+        size,
+        mid,
+        at: time::Instant::now(),
+    });
+    FILLS.with_label_values(&[side.as_str()]).inc();
+    INVENTORY
+        .with_label_values(&[&state.market_id])
+        .set(outcome.position);
+    save_inventory(ctx, state).await;
+    info!(
+        market_id = %state.market_id,
+        side = side.as_str(),
+        price = quote.price,
+        size,
+        spread_capture = outcome.spread_capture,
+        inventory = outcome.position,
+        "Market maker fill"
+    );
+
+    let mut event = NewTradeEvent::new(
+        STRATEGY,
+        if quote.live { "live" } else { "paper" },
+        "market_maker",
+        &state.market_id,
+        "mm_fill",
+    );
+    event.direction = Some(side.as_str().to_string());
+    event.fill_price = Some(to_decimal(quote.price));
+    event.filled_size_usd = Some(to_decimal(size * quote.leg_price));
+    event.realized_pnl = Some(to_decimal(outcome.realized_pnl));
+    event.unrealized_pnl = Some(to_decimal(state.inventory.unrealized_pnl(mid)));
+    event.metadata = serde_json::json!({
+        "order_id": quote.order_id,
+        "outcome": match quote.outcome { Outcome::Yes => "yes", Outcome::No => "no" },
+        "leg_price": quote.leg_price,
+        "shares": size,
+        "mid": mid,
+        "spread_capture": outcome.spread_capture,
+        "inventory": outcome.position,
+    });
+    ctx.recorder.record_warn(event).await;
+}
+
+/// Once-a-second checks: stale books and due markouts.
+async fn on_housekeeping(ctx: &Context, state: &mut MarketState) {
+    let now = time::Instant::now();
+    let stale_after = time::Duration::from_secs(ctx.config.stale_book_secs);
+    if state
+        .book_at
+        .is_none_or(|at| now.saturating_duration_since(at) > stale_after)
+    {
+        pull_quotes(ctx, state, "stale_book").await;
+        return;
+    }
+    let Some(book) = state.book else {
+        return;
+    };
+
+    let horizon = time::Duration::from_secs(ctx.config.markout_secs);
+    let markouts = state.inventory.due_markouts(book.mid(), now, horizon);
+    if markouts.is_empty() {
+        return;
+    }
+    save_inventory(ctx, state).await;
+    let mode = ctx.execution_mode().await;
+    for markout in markouts {
+        let mut event = NewTradeEvent::new(
+            STRATEGY,
+            mode,
+            "market_maker",
+            &state.market_id,
+            "mm_markout",
+        );
+        event.direction = Some(markout.side.as_str().to_string());
+        event.metadata = serde_json::json!({
+            "shares": markout.size,
+            "mid_at_fill": markout.mid_at_fill,
+            "mid_after": markout.mid_after,
+            "markout_secs": ctx.config.markout_secs,
+            "adverse_selection": markout.adverse_selection,
+        });
+        ctx.recorder.record_warn(event).await;
+    }
+}
+
+async fn load_inventory(
+    pool: &PgPool,
+    market_id: &str,
+) -> Result<Option<InventorySnapshot>, sqlx::Error> {
+    let row: Option<(f64, f64, f64, f64, f64)> = sqlx::query_as(
+        r#"
+        SELECT position, avg_price, realized_pnl, spread_capture, adverse_selection
+        FROM mm_inventory
+        WHERE market_id = $1
+        "#,
+    )
+    .bind(market_id)
+    .fetch_optional(pool)
+    .await?;
+    Ok(row.map(
+        |(position, avg_price, realized_pnl, spread_capture, adverse_selection)| {
+            InventorySnapshot {
+                position,
+                avg_price,
+                realized_pnl,
+                spread_capture,
+                adverse_selection,
+            }
+        },
+    ))
+}
+
+async fn save_inventory(ctx: &Context, state: &MarketState) {
+    let snapshot = state.inventory.snapshot();
+    let result = sqlx::query(
+        r#"
+        INSERT INTO mm_inventory
+            (market_id, position, avg_price, realized_pnl, spread_capture, adverse_selection, updated_at)
+        VALUES ($1, $2, $3, $4, $5, $6, NOW())
+        ON CONFLICT (market_id) DO UPDATE SET
+            position = EXCLUDED.position,
+            avg_price = EXCLUDED.avg_price,
+            realized_pnl = EXCLUDED.realized_pnl,
+            spread_capture = EXCLUDED.spread_capture,
+            adverse_selection = EXCLUDED.adverse_selection,
+            updated_at = NOW()
+        "#,
+    )
+    .bind(&state.market_id)
+    .bind(snapshot.position)
+    .bind(snapshot.avg_price)
+    .bind(snapshot.realized_pnl)
+    .bind(snapshot.spread_capture)
+    .bind(snapshot.adverse_selection)
+    .execute(&ctx.pool)
+    .await;
+    if let Err(e) = result {
+        warn!(market_id = %state.market_id, error = %e, "Market maker: failed to persist inventory");
+    }
+}
+
+fn to_decimal(value: f64) -> Decimal {
+    Decimal::from_f64_retain(value)
+        .map(|d| d.round_dp(6))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_defaults() {
+        let config = MarketMakerConfig::from_env();
+        assert!(!config.enabled);
+        assert!(config.markets.is_empty());
+        assert_eq!(config.half_spread, 0.02);
+        assert_eq!(config.max_inventory, 500.0);
+    }
+
+    #[test]
+    fn test_inventory_room_counts_resting_and_cancelling_quotes() {
+        let quote = |size: f64, filled: f64| RestingQuote {
+            order_id: "order".to_string(),
+            price: 0.5,
+            outcome: Outcome::Yes,
+            leg_price: 0.5,
+            size,
+            filled,
+            live: true,
+        };
+        let mut state = MarketState {
+            market_id: "0xmarket".to_string(),
+            yes_token: "yes".to_string(),
+            no_token: "no".to_string(),
+            tick: 0.01,
+            book: None,
+            book_at: None,
+            mids: MidWindow::default(),
+            paused_until: None,
+            last_requote: None,
+            quotes: HashMap::new(),
+            cancelling: Vec::new(),
+            inventory: MarketInventory::default(),
+        };
+        state.inventory.on_fill(Fill {
+            side: QuoteSide::Bid,
+            price: 0.5,
+            size: 60.0,
+            mid: 0.5,
+            at: time::Instant::now(),
+        });
+        assert_eq!(inventory_room(&state, QuoteSide::Bid, 100.0), 40.0);
+
+        state.quotes.insert(QuoteSide::Bid, quote(20.0, 5.0));
+        state.cancelling.push((QuoteSide::Bid, quote(10.0, 0.0)));
+        state.cancelling.push((QuoteSide::Ask, quote(50.0, 0.0)));
+        assert_eq!(inventory_room(&state, QuoteSide::Bid, 100.0), 15.0);
+        // Selling works the position down, so the ask has room past the cap.
+        assert_eq!(inventory_room(&state, QuoteSide::Ask, 100.0), 110.0);
+    }
+}
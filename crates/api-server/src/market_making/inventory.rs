@@ -0,0 +1,271 @@
+//! Per-market inventory and P&L attribution for the market maker.
+//!
+//! Every fill is booked in YES terms against an average-cost position. Its
+//! P&L is split two ways: spread capture (how far inside the mid we traded,
+//! at the moment of the fill) and adverse selection (how far the mid then
+//! moved against the fill over the markout horizon). Good market making is
+//! the first outweighing the second.
+
+use std::collections::VecDeque;
+use std::time;
+
+use super::quoting::QuoteSide;
+
+/// One executed quote.
+#[derive(Debug, Clone, Copy)]
+pub struct Fill {
+    pub side: QuoteSide,
+    /// Execution price in YES terms.
+    pub price: f64,
+    pub size: f64,
+    /// YES mid when the fill happened.
+    pub mid: f64,
+    pub at: time::Instant,
+}
+
+/// What a fill did to the book.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FillOutcome {
+    /// P&L realized by closing part of an existing position.
+    pub realized_pnl: f64,
+    /// `(mid - price) * signed size`: positive when we bought below or sold above the mid.
+    pub spread_capture: f64,
+    /// Net YES position after the fill.
+    pub position: f64,
+}
+
+/// A fill measured against the mid one horizon later.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Markout {
+    pub side: QuoteSide,
+    pub size: f64,
+    pub mid_at_fill: f64,
+    pub mid_after: f64,
+    /// `(mid_after - mid_at_fill) * signed size`: negative when the market
+    /// moved against what we were filled on.
+    pub adverse_selection: f64,
+}
+
+/// Position and attribution totals as persisted across restarts; fills
+/// still waiting for their markout are not kept.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct InventorySnapshot {
+    pub position: f64,
+    pub avg_price: f64,
+    pub realized_pnl: f64,
+    pub spread_capture: f64,
+    pub adverse_selection: f64,
+}
+
+/// Position and attribution totals for one market.
+#[derive(Debug, Default)]
+pub struct MarketInventory {
+    /// Net YES shares; negative is net NO.
+    position: f64,
+    /// Average YES-terms entry price of `position`.
+    avg_price: f64,
+    pub realized_pnl: f64,
+    pub spread_capture: f64,
+    pub adverse_selection: f64,
+    pending_markouts: VecDeque<Fill>,
+}
+
+impl MarketInventory {
+    pub fn restore(snapshot: InventorySnapshot) -> Self {
+        Self {
+            position: snapshot.position,
+            avg_price: snapshot.avg_price,
+            realized_pnl: snapshot.realized_pnl,
+            spread_capture: snapshot.spread_capture,
+            adverse_selection: snapshot.adverse_selection,
+            pending_markouts: VecDeque::new(),
+        }
+    }
+
+    pub fn snapshot(&self) -> InventorySnapshot {
+        InventorySnapshot {
+            position: self.position,
+            avg_price: self.avg_price,
+            realized_pnl: self.realized_pnl,
+            spread_capture: self.spread_capture,
+            adverse_selection: self.adverse_selection,
+        }
+    }
+
+    pub fn position(&self) -> f64 {
+        self.position
+    }
+
+    /// Capital tied up in the position: YES shares at the average price, or
+    /// NO shares at its complement.
+    pub fn cost_basis(&self) -> f64 {
+        if self.position >= 0.0 {
+            self.position * self.avg_price
+        } else {
+            -self.position * (1.0 - self.avg_price)
+        }
+    }
+
+    /// Mark-to-mid P&L of the open position.
+    pub fn unrealized_pnl(&self, mid: f64) -> f64 {
+        (mid - self.avg_price) * self.position
+    }
+
+    /// Book a fill and queue it for markout.
+    pub fn on_fill(&mut self, fill: Fill) -> FillOutcome {
+        let signed = fill.side.sign() * fill.size;
+        let mut realized = 0.0;
+
+        if self.position == 0.0 || self.position.signum() == signed.signum() {
+            let held = self.position.abs();
+            self.avg_price = (held * self.avg_price + fill.size * fill.price) / (held + fill.size);
+            self.position += signed;
+        } else {
+            let closed = fill.size.min(self.position.abs());
+            realized = closed * (fill.price - self.avg_price) * self.position.signum();
+            self.position += signed;
+            if self.position.abs() < 1e-9 {
+                self.position = 0.0;
+                self.avg_price = 0.0;
+            } else if fill.size > closed {
+                // Flipped through flat: the remainder opens at the fill price.
+                self.avg_price = fill.price;
+            }
+        }
+
+        let spread_capture = (fill.mid - fill.price) * signed;
+        self.realized_pnl += realized;
+        self.spread_capture += spread_capture;
+        self.pending_markouts.push_back(fill);
+
+        FillOutcome {
+            realized_pnl: realized,
+            spread_capture,
+            position: self.position,
+        }
+    }
+
+    /// Mark out every fill at least `horizon` old against `mid`.
+    pub fn due_markouts(
+        &mut self,
+        mid: f64,
+        now: time::Instant,
+        horizon: time::Duration,
+    ) -> Vec<Markout> {
+        let mut markouts = Vec::new();
+        while let Some(fill) = self.pending_markouts.front() {
+            if now.saturating_duration_since(fill.at) < horizon {
+                break;
+            }
+            let fill = self.pending_markouts.pop_front().expect("front checked");
+            let adverse_selection = (mid - fill.mid) * fill.side.sign() * fill.size;
+            self.adverse_selection += adverse_selection;
+            markouts.push(Markout {
+                side: fill.side,
+                size: fill.size,
+                mid_at_fill: fill.mid,
+                mid_after: mid,
+                adverse_selection,
+            });
+        }
+        markouts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fill(side: QuoteSide, price: f64, size: f64, mid: f64, at: time::Instant) -> Fill {
+        Fill {
+            side,
+            price,
+            size,
+            mid,
+            at,
+        }
+    }
+
+    fn approx(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn test_round_trip_realizes_the_spread() {
+        let now = time::Instant::now();
+        let mut inventory = MarketInventory::default();
+
+        let buy = inventory.on_fill(fill(QuoteSide::Bid, 0.48, 100.0, 0.50, now));
+        assert!(approx(buy.spread_capture, 2.0));
+        assert_eq!(buy.realized_pnl, 0.0);
+        assert_eq!(buy.position, 100.0);
+
+        let sell = inventory.on_fill(fill(QuoteSide::Ask, 0.52, 100.0, 0.50, now));
+        assert!(approx(sell.spread_capture, 2.0));
+        assert!(approx(sell.realized_pnl, 4.0));
+        assert_eq!(sell.position, 0.0);
+        assert!(approx(inventory.spread_capture, 4.0));
+    }
+
+    #[test]
+    fn test_short_side_and_flip_through_flat() {
+        let now = time::Instant::now();
+        let mut inventory = MarketInventory::default();
+
+        // Short 50 YES (long NO) at 0.60, then buy 80 back at 0.55.
+        inventory.on_fill(fill(QuoteSide::Ask, 0.60, 50.0, 0.58, now));
+        let flip = inventory.on_fill(fill(QuoteSide::Bid, 0.55, 80.0, 0.57, now));
+        assert!(approx(flip.realized_pnl, 2.5));
+        assert!(approx(flip.position, 30.0));
+        assert!(approx(inventory.unrealized_pnl(0.60), 1.5));
+    }
+
+    #[test]
+    fn test_restore_keeps_position_and_attribution() {
+        let now = time::Instant::now();
+        let mut inventory = MarketInventory::default();
+        inventory.on_fill(fill(QuoteSide::Ask, 0.60, 50.0, 0.62, now));
+        assert!(approx(inventory.cost_basis(), 20.0));
+
+        let mut restored = MarketInventory::restore(inventory.snapshot());
+        assert_eq!(restored.snapshot(), inventory.snapshot());
+        assert!(restored
+            .due_markouts(
+                0.50,
+                now + time::Duration::from_secs(3600),
+                time::Duration::ZERO
+            )
+            .is_empty());
+        let close = restored.on_fill(fill(QuoteSide::Bid, 0.55, 50.0, 0.55, now));
+        assert!(approx(close.realized_pnl, 2.5));
+    }
+
+    #[test]
+    fn test_markouts_charge_adverse_moves_after_the_horizon() {
+        let start = time::Instant::now();
+        let horizon = time::Duration::from_secs(60);
+        let mut inventory = MarketInventory::default();
+        inventory.on_fill(fill(QuoteSide::Bid, 0.48, 100.0, 0.50, start));
+        inventory.on_fill(fill(
+            QuoteSide::Ask,
+            0.47,
+            40.0,
+            0.45,
+            start + time::Duration::from_secs(30),
+        ));
+
+        assert!(inventory
+            .due_markouts(0.40, start + time::Duration::from_secs(59), horizon)
+            .is_empty());
+
+        // Bought at a 0.50 mid that fell to 0.40: adverse.
+        let first = inventory.due_markouts(0.40, start + time::Duration::from_secs(60), horizon);
+        assert_eq!(first.len(), 1);
+        assert!(approx(first[0].adverse_selection, -10.0));
+
+        // Sold at a 0.45 mid that fell further: favourable.
+        let second = inventory.due_markouts(0.40, start + time::Duration::from_secs(95), horizon);
+        assert!(approx(second[0].adverse_selection, 2.0));
+        assert!(approx(inventory.adverse_selection, -8.0));
+    }
+}
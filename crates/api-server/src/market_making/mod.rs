@@ -0,0 +1,18 @@
+//! Two-sided market making.
+//!
+//! Rests post-only bids and offers around the microprice on configured
+//! markets, skewing both quotes away from accumulated YES/NO inventory and
+//! capping it per market. Quotes are refreshed on book changes and pulled on
+//! volatility spikes, stale books, or a tripped circuit breaker. Fills are
+//! reported as `trade_events` split into spread capture and adverse
+//! selection, which the strategy P&L calculator rolls up. Inventory is
+//! persisted to `mm_inventory` so it survives restarts and counts toward
+//! open exposure.
+
+pub mod engine;
+pub mod inventory;
+pub mod quoting;
+
+pub use engine::{spawn_market_maker, MarketMakerConfig};
+pub use inventory::{Fill, FillOutcome, InventorySnapshot, MarketInventory, Markout};
+pub use quoting::{compute_quotes, execution_leg, BookTop, QuotePair, QuoteParams, QuoteSide};
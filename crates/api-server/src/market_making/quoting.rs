@@ -0,0 +1,381 @@
+//! Quote placement: where to bid and offer around a fair value, skewed by
+//! inventory, and which order actually expresses each side on Polymarket.
+//!
+//! Everything is in YES terms. Offering YES is done by selling YES held, or
+//! else by bidding NO at the complement; bidding YES while holding NO sells
+//! the NO instead. That keeps the book of holdings one-sided, so the net YES
+//! position is the only inventory figure that matters.
+
+use polymarket_core::types::{OrderSide, PriceLevel};
+use rust_decimal::prelude::ToPrimitive;
+use std::collections::VecDeque;
+use std::time;
+
+/// Top of the YES book.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BookTop {
+    pub best_bid: f64,
+    pub bid_size: f64,
+    pub best_ask: f64,
+    pub ask_size: f64,
+}
+
+impl BookTop {
+    /// Best bid and ask with their sizes, or `None` for a one-sided or crossed book.
+    pub fn from_levels(bids: &[PriceLevel], asks: &[PriceLevel]) -> Option<Self> {
+        let best_bid = bids.iter().max_by(|a, b| a.price.cmp(&b.price))?;
+        let best_ask = asks.iter().min_by(|a, b| a.price.cmp(&b.price))?;
+        let top = Self {
+            best_bid: best_bid.price.to_f64()?,
+            bid_size: best_bid.size.to_f64()?,
+            best_ask: best_ask.price.to_f64()?,
+            ask_size: best_ask.size.to_f64()?,
+        };
+        (top.best_bid < top.best_ask).then_some(top)
+    }
+
+    pub fn mid(&self) -> f64 {
+        (self.best_bid + self.best_ask) / 2.0
+    }
+
+    /// Size-weighted mid: leans toward the side with less resting size,
+    /// which is where the next trade is likelier to move the price.
+    pub fn microprice(&self) -> f64 {
+        let depth = self.bid_size + self.ask_size;
+        if depth <= 0.0 {
+            return self.mid();
+        }
+        (self.best_bid * self.ask_size + self.best_ask * self.bid_size) / depth
+    }
+}
+
+/// Quoting knobs, in YES price units and shares.
+#[derive(Debug, Clone, Copy)]
+pub struct QuoteParams {
+    /// Distance from the reservation price to each quote.
+    pub half_spread: f64,
+    /// How far the reservation price moves away from inventory at the cap.
+    pub inventory_skew: f64,
+    /// Shares per quote.
+    pub quote_size: f64,
+    /// Largest net YES position (long or short) to hold.
+    pub max_inventory: f64,
+    pub tick: f64,
+}
+
+/// A desired resting quote.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Quote {
+    pub price: f64,
+    pub size: f64,
+}
+
+/// Both sides of a market's quotes; a side is `None` when it should not rest.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QuotePair {
+    /// Inventory-skewed fair value the quotes are centred on.
+    pub reservation: f64,
+    pub bid: Option<Quote>,
+    pub ask: Option<Quote>,
+}
+
+/// Quotes around `fair` for a net YES position of `net_inventory` shares.
+///
+/// Long inventory lowers both quotes (selling becomes likelier, buying less
+/// so) and short inventory raises them. Each quote stays one tick behind the
+/// opposite side of the book so it can rest post-only, and a side is dropped
+/// once filling it would take the position past the cap.
+pub fn compute_quotes(
+    fair: f64,
+    book: &BookTop,
+    net_inventory: f64,
+    params: &QuoteParams,
+) -> QuotePair {
+    let tick = params.tick;
+    let max_inventory = params.max_inventory.max(f64::EPSILON);
+    let inventory_ratio = (net_inventory / max_inventory).clamp(-1.0, 1.0);
+    let reservation = fair - params.inventory_skew * inventory_ratio;
+
+    let bid_price = floor_to_tick(reservation - params.half_spread, tick)
+        .min(round_to_tick(book.best_ask - tick, tick))
+        .max(tick);
+    let ask_price = ceil_to_tick(reservation + params.half_spread, tick)
+        .max(round_to_tick(book.best_bid + tick, tick))
+        .min(1.0 - tick);
+
+    let bid_room = params.max_inventory - net_inventory;
+    let ask_room = params.max_inventory + net_inventory;
+    let quote = |price: f64, room: f64| {
+        let size = params.quote_size.min(room);
+        (size > 0.0).then_some(Quote { price, size })
+    };
+
+    if ask_price - bid_price < tick / 2.0 {
+        // Only reachable with a tick too coarse for the price range.
+        return QuotePair {
+            reservation,
+            bid: None,
+            ask: None,
+        };
+    }
+    QuotePair {
+        reservation,
+        bid: quote(bid_price, bid_room),
+        ask: quote(ask_price, ask_room),
+    }
+}
+
+/// Which side of the YES market a quote makes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QuoteSide {
+    /// Buys YES.
+    Bid,
+    /// Sells YES.
+    Ask,
+}
+
+impl QuoteSide {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Bid => "buy_yes",
+            Self::Ask => "sell_yes",
+        }
+    }
+
+    /// Signed change in the YES position per share filled.
+    pub fn sign(&self) -> f64 {
+        match self {
+            Self::Bid => 1.0,
+            Self::Ask => -1.0,
+        }
+    }
+}
+
+/// Outcome token an order is placed on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Yes,
+    No,
+}
+
+/// The order that expresses a YES quote.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExecutionLeg {
+    pub outcome: Outcome,
+    pub side: OrderSide,
+    /// Limit price on `outcome`'s own book.
+    pub price: f64,
+    pub size: f64,
+}
+
+/// The order for `quote` on `side` given the current net YES position. A
+/// quote that unwinds held shares is capped at what is held.
+pub fn execution_leg(side: QuoteSide, quote: Quote, net_inventory: f64) -> ExecutionLeg {
+    let complement = round_to_tick(1.0 - quote.price, 1e-4);
+    match side {
+        QuoteSide::Bid if net_inventory < 0.0 => ExecutionLeg {
+            outcome: Outcome::No,
+            side: OrderSide::Sell,
+            price: complement,
+            size: quote.size.min(-net_inventory),
+        },
+        QuoteSide::Bid => ExecutionLeg {
+            outcome: Outcome::Yes,
+            side: OrderSide::Buy,
+            price: quote.price,
+            size: quote.size,
+        },
+        QuoteSide::Ask if net_inventory > 0.0 => ExecutionLeg {
+            outcome: Outcome::Yes,
+            side: OrderSide::Sell,
+            price: quote.price,
+            size: quote.size.min(net_inventory),
+        },
+        QuoteSide::Ask => ExecutionLeg {
+            outcome: Outcome::No,
+            side: OrderSide::Buy,
+            price: complement,
+            size: quote.size,
+        },
+    }
+}
+
+fn round_to_tick(price: f64, tick: f64) -> f64 {
+    (price / tick).round() * tick
+}
+
+fn floor_to_tick(price: f64, tick: f64) -> f64 {
+    // Nudge so a price already on the grid is not pushed down by float error.
+    (price / tick + 1e-9).floor() * tick
+}
+
+fn ceil_to_tick(price: f64, tick: f64) -> f64 {
+    (price / tick - 1e-9).ceil() * tick
+}
+
+/// Recent mids, for spotting moves too fast to keep quotes out through.
+#[derive(Debug, Default)]
+pub struct MidWindow {
+    mids: VecDeque<(time::Instant, f64)>,
+}
+
+impl MidWindow {
+    /// Record `mid` and return the high-low range over the trailing `window`.
+    pub fn push(&mut self, mid: f64, at: time::Instant, window: time::Duration) -> f64 {
+        self.mids.push_back((at, mid));
+        while let Some(&(seen_at, _)) = self.mids.front() {
+            if at.saturating_duration_since(seen_at) <= window {
+                break;
+            }
+            self.mids.pop_front();
+        }
+        let (low, high) = self
+            .mids
+            .iter()
+            .fold((f64::MAX, f64::MIN), |(low, high), &(_, m)| {
+                (low.min(m), high.max(m))
+            });
+        high - low
+    }
+
+    pub fn clear(&mut self) {
+        self.mids.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::Decimal;
+
+    fn params() -> QuoteParams {
+        QuoteParams {
+            half_spread: 0.02,
+            inventory_skew: 0.02,
+            quote_size: 50.0,
+            max_inventory: 200.0,
+            tick: 0.01,
+        }
+    }
+
+    fn book(bid: f64, ask: f64) -> BookTop {
+        BookTop {
+            best_bid: bid,
+            bid_size: 100.0,
+            best_ask: ask,
+            ask_size: 100.0,
+        }
+    }
+
+    fn approx(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    fn level(price: i64, size: i64) -> PriceLevel {
+        PriceLevel {
+            price: Decimal::new(price, 2),
+            size: Decimal::new(size, 0),
+        }
+    }
+
+    #[test]
+    fn test_from_levels_ignores_level_order_and_rejects_crossed_books() {
+        let top = BookTop::from_levels(
+            &[level(45, 100), level(47, 30), level(40, 500)],
+            &[level(60, 10), level(52, 80)],
+        )
+        .unwrap();
+        assert_eq!(top.best_bid, 0.47);
+        assert_eq!(top.bid_size, 30.0);
+        assert_eq!(top.best_ask, 0.52);
+
+        assert!(BookTop::from_levels(&[level(50, 10)], &[level(50, 10)]).is_none());
+        assert!(BookTop::from_levels(&[level(50, 10)], &[]).is_none());
+    }
+
+    #[test]
+    fn test_flat_inventory_quotes_symmetrically() {
+        let quotes = compute_quotes(0.50, &book(0.45, 0.55), 0.0, &params());
+        assert!(approx(quotes.bid.unwrap().price, 0.48));
+        assert!(approx(quotes.ask.unwrap().price, 0.52));
+        assert_eq!(quotes.bid.unwrap().size, 50.0);
+    }
+
+    #[test]
+    fn test_inventory_skews_both_quotes_away_from_position() {
+        let long = compute_quotes(0.50, &book(0.45, 0.55), 200.0, &params());
+        assert!(approx(long.reservation, 0.48));
+        // At the cap: no more buying, and the offer moves in to shed inventory.
+        assert!(long.bid.is_none());
+        assert!(approx(long.ask.unwrap().price, 0.50));
+
+        let short = compute_quotes(0.50, &book(0.45, 0.55), -100.0, &params());
+        assert!(approx(short.bid.unwrap().price, 0.49));
+        assert!(approx(short.ask.unwrap().price, 0.53));
+
+        // Near the cap the bid only takes what room is left.
+        let near_cap = compute_quotes(0.50, &book(0.45, 0.55), 180.0, &params());
+        assert_eq!(near_cap.bid.unwrap().size, 20.0);
+    }
+
+    #[test]
+    fn test_quotes_stay_behind_the_opposite_touch() {
+        // Tight book: a 2c half-spread around 0.50 would cross 0.49 / 0.51.
+        let quotes = compute_quotes(0.50, &book(0.50, 0.51), 0.0, &params());
+        assert!(approx(quotes.bid.unwrap().price, 0.48));
+        assert!(approx(quotes.ask.unwrap().price, 0.52));
+
+        // Fair value far above the book: the bid pins a tick under the ask.
+        let rich = compute_quotes(0.70, &book(0.40, 0.45), 0.0, &params());
+        assert!(approx(rich.bid.unwrap().price, 0.44));
+    }
+
+    #[test]
+    fn test_execution_leg_unwinds_before_adding() {
+        let quote = Quote {
+            price: 0.52,
+            size: 50.0,
+        };
+        let sell_held = execution_leg(QuoteSide::Ask, quote, 30.0);
+        assert_eq!(sell_held.outcome, Outcome::Yes);
+        assert_eq!(sell_held.side, OrderSide::Sell);
+        assert_eq!(sell_held.size, 30.0);
+
+        let buy_no = execution_leg(QuoteSide::Ask, quote, 0.0);
+        assert_eq!(buy_no.outcome, Outcome::No);
+        assert_eq!(buy_no.side, OrderSide::Buy);
+        assert!(approx(buy_no.price, 0.48));
+
+        let sell_no = execution_leg(QuoteSide::Bid, quote, -10.0);
+        assert_eq!(sell_no.outcome, Outcome::No);
+        assert_eq!(sell_no.side, OrderSide::Sell);
+        assert_eq!(sell_no.size, 10.0);
+    }
+
+    #[test]
+    fn test_microprice_leans_toward_thin_side() {
+        let top = BookTop {
+            best_bid: 0.48,
+            bid_size: 900.0,
+            best_ask: 0.52,
+            ask_size: 100.0,
+        };
+        assert!(top.microprice() > top.mid());
+    }
+
+    #[test]
+    fn test_mid_window_range_drops_old_mids() {
+        let mut window = MidWindow::default();
+        let start = time::Instant::now();
+        let span = time::Duration::from_secs(10);
+        window.push(0.50, start, span);
+        assert!(approx(
+            window.push(0.56, start + time::Duration::from_secs(5), span),
+            0.06
+        ));
+        assert!(approx(
+            window.push(0.57, start + time::Duration::from_secs(12), span),
+            0.01
+        ));
+    }
+}
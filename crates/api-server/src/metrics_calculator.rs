@@ -53,21 +53,10 @@ impl MetricsCalculatorConfig {
     /// Load configuration from environment variables.
     pub fn from_env() -> Self {
         Self {
-            enabled: std::env::var("METRICS_CALCULATOR_ENABLED")
-                .map(|v| v == "true" || v == "1")
-                .unwrap_or(true),
-            interval_secs: std::env::var("METRICS_CALCULATOR_INTERVAL_SECS")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(900),
-            batch_size: std::env::var("METRICS_CALCULATOR_BATCH_SIZE")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(50),
-            recalc_after_hours: std::env::var("METRICS_RECALC_AFTER_HOURS")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(6),
+            enabled: polymarket_core::settings::get("METRICS_CALCULATOR_ENABLED"),
+            interval_secs: polymarket_core::settings::get("METRICS_CALCULATOR_INTERVAL_SECS"),
+            batch_size: polymarket_core::settings::get("METRICS_CALCULATOR_BATCH_SIZE"),
+            recalc_after_hours: polymarket_core::settings::get("METRICS_RECALC_AFTER_HOURS"),
         }
     }
 }
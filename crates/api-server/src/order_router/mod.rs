@@ -0,0 +1,23 @@
+//! Order router: execution algorithms between strategies and the order
+//! executor.
+//!
+//! A parent order carries a quantity, a limit price and a deadline, and is
+//! worked with TWAP, participation-of-volume or passive-then-aggressive
+//! slicing instead of going out as one market order that walks a thin book.
+//! Child orders are tracked per parent, work stops at the deadline (or the
+//! originating signal's expiry), and each parent is recorded with its
+//! implementation shortfall against the mid at arrival. Children are
+//! journalled as they go out, so parents a restart interrupted are closed
+//! out on the next startup.
+
+pub mod runner;
+pub mod schedule;
+
+pub use runner::{
+    execute_parent, reconcile_interrupted, run_parent, ChildJournal, ChildKind, ChildOrder,
+    ExecutionOutcome, ExecutionVenue, ExecutorVenue, InterruptedParent, StopReason, TakeFill,
+};
+pub use schedule::{
+    implementation_shortfall, plan_child, target_quantity, AlgoKind, ChildPlan, OrderRouterConfig,
+    ParentOrder, Shortfall,
+};
@@ -0,0 +1,1044 @@
+//! Works a parent order to completion through child orders.
+//!
+//! Every poll re-reads the book, folds in fills on the resting child, and
+//! asks the schedule for the next child. Work stops when the parent is
+//! filled or its deadline passes; a resting child is cancelled on the way
+//! out. Fills on a resting child come from the order's own status, re-read
+//! after every cancel, so a child cancelled elsewhere is never counted as
+//! filled. The venue is a trait so the loop runs the same against the CLOB,
+//! paper trading and tests.
+//!
+//! Children are journalled as they are placed and as they fill, so a
+//! restart mid-run can find live children: [`reconcile_interrupted`]
+//! cancels them, reads back their final fills and closes out the parent.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use polymarket_core::types::{MarketOrder, OrderBook, OrderSide};
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use std::sync::Arc;
+use tracing::{debug, info, warn};
+use trading_engine::{LiveOrderFill, OrderExecutor};
+use uuid::Uuid;
+
+use super::schedule::{
+    implementation_shortfall, plan_child, AlgoKind, ChildPlan, OrderRouterConfig, ParentOrder,
+    Shortfall,
+};
+
+/// Fill of a marketable child order.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TakeFill {
+    pub quantity: Decimal,
+    pub price: Decimal,
+    pub fees: Decimal,
+}
+
+/// Where child orders go.
+#[async_trait]
+pub trait ExecutionVenue: Send + Sync {
+    async fn order_book(&self, token_id: &str) -> anyhow::Result<OrderBook>;
+
+    /// Cross the spread for up to `quantity` at no worse than `price`.
+    async fn take(
+        &self,
+        parent: &ParentOrder,
+        price: Decimal,
+        quantity: Decimal,
+    ) -> anyhow::Result<TakeFill>;
+
+    /// Rest a post-only order; returns its exchange id, or `None` when the
+    /// order is simulated.
+    async fn rest(
+        &self,
+        parent: &ParentOrder,
+        price: Decimal,
+        quantity: Decimal,
+    ) -> anyhow::Result<Option<String>>;
+
+    /// Cumulative fill of a resting child and whether it can still fill,
+    /// given the latest book.
+    async fn resting_fill(
+        &self,
+        parent: &ParentOrder,
+        child: &ChildOrder,
+        book: &OrderBook,
+    ) -> anyhow::Result<LiveOrderFill>;
+
+    /// Cancel a resting child and return its final cumulative fill. Errors
+    /// when the child may still be live.
+    async fn cancel_resting(&self, child: &ChildOrder) -> anyhow::Result<Decimal>;
+
+    /// Shares traded in the token since `since`.
+    async fn traded_volume(&self, token_id: &str, since: DateTime<Utc>) -> anyhow::Result<Decimal>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChildKind {
+    Take,
+    Rest,
+}
+
+impl ChildKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Take => "take",
+            Self::Rest => "rest",
+        }
+    }
+}
+
+/// Records child orders as they are placed and as their fills change.
+#[async_trait]
+pub trait ChildJournal: Send + Sync {
+    async fn record(&self, parent: &ParentOrder, child: &ChildOrder);
+}
+
+#[async_trait]
+impl ChildJournal for PgPool {
+    async fn record(&self, parent: &ParentOrder, child: &ChildOrder) {
+        if let Err(e) = upsert_child(self, parent.id, child).await {
+            warn!(parent_id = %parent.id, child_id = %child.id, error = %e, "Failed to record child order");
+        }
+    }
+}
+
+/// One child order sent for a parent.
+#[derive(Debug, Clone)]
+pub struct ChildOrder {
+    pub id: Uuid,
+    pub kind: ChildKind,
+    pub exchange_order_id: Option<String>,
+    pub price: Decimal,
+    pub quantity: Decimal,
+    pub filled: Decimal,
+    pub fees: Decimal,
+    pub placed_at: DateTime<Utc>,
+    pub done_at: Option<DateTime<Utc>>,
+}
+
+/// Why work on a parent order stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StopReason {
+    Filled,
+    Deadline,
+    SignalExpired,
+    /// The process stopped mid-run; closed out on the next startup.
+    Interrupted,
+}
+
+impl StopReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Filled => "filled",
+            Self::Deadline => "deadline",
+            Self::SignalExpired => "signal_expired",
+            Self::Interrupted => "interrupted",
+        }
+    }
+}
+
+/// Result of working a parent order.
+#[derive(Debug, Clone)]
+pub struct ExecutionOutcome {
+    pub stop_reason: StopReason,
+    pub filled_quantity: Decimal,
+    /// Volume-weighted fill price; `None` when nothing filled.
+    pub average_price: Option<Decimal>,
+    pub fees: Decimal,
+    pub shortfall: Option<Shortfall>,
+    pub children: Vec<ChildOrder>,
+    pub started_at: DateTime<Utc>,
+    pub finished_at: DateTime<Utc>,
+}
+
+impl ExecutionOutcome {
+    fn from_children(
+        parent: &ParentOrder,
+        stop_reason: StopReason,
+        children: Vec<ChildOrder>,
+        started_at: DateTime<Utc>,
+    ) -> Self {
+        let filled_quantity: Decimal = children.iter().map(|c| c.filled).sum();
+        let notional: Decimal = children.iter().map(|c| c.filled * c.price).sum();
+        let fees: Decimal = children.iter().map(|c| c.fees).sum();
+        let average_price = (filled_quantity > Decimal::ZERO).then(|| notional / filled_quantity);
+        let shortfall = average_price.and_then(|price| {
+            implementation_shortfall(
+                parent.side,
+                parent.arrival_mid,
+                filled_quantity,
+                price,
+                fees,
+            )
+        });
+        Self {
+            stop_reason,
+            filled_quantity,
+            average_price,
+            fees,
+            shortfall,
+            children,
+            started_at,
+            finished_at: Utc::now(),
+        }
+    }
+}
+
+/// Work `parent` until it is filled or its deadline passes.
+pub async fn run_parent(
+    venue: &dyn ExecutionVenue,
+    journal: &dyn ChildJournal,
+    cfg: &OrderRouterConfig,
+    parent: &ParentOrder,
+) -> ExecutionOutcome {
+    let started_at = Utc::now();
+    let poll = std::time::Duration::from_millis(cfg.poll_interval_ms.max(1));
+    let mut children: Vec<ChildOrder> = Vec::new();
+    let mut resting: Option<usize> = None;
+    let mut last_book: Option<OrderBook> = None;
+
+    let stop_reason = loop {
+        let now = Utc::now();
+        match venue.order_book(&parent.token_id).await {
+            Ok(book) => last_book = Some(book),
+            Err(e) => debug!(parent_id = %parent.id, error = %e, "Order router: book fetch failed"),
+        }
+        if let (Some(index), Some(book)) = (resting, last_book.as_ref()) {
+            if !sync_resting(venue, journal, parent, &mut children[index], book).await {
+                resting = None;
+            }
+        }
+
+        let filled: Decimal = children.iter().map(|c| c.filled).sum();
+        if filled >= parent.quantity {
+            break StopReason::Filled;
+        }
+        if now >= parent.deadline {
+            break if parent.expires_with_signal {
+                StopReason::SignalExpired
+            } else {
+                StopReason::Deadline
+            };
+        }
+
+        if let Some(book) = last_book.as_ref() {
+            let market_volume = if parent.algo == AlgoKind::Pov {
+                venue
+                    .traded_volume(&parent.token_id, started_at)
+                    .await
+                    .unwrap_or_else(|e| {
+                        debug!(parent_id = %parent.id, error = %e, "Order router: volume read failed");
+                        Decimal::ZERO
+                    })
+            } else {
+                Decimal::ZERO
+            };
+            match plan_child(cfg, parent, book, started_at, now, filled, market_volume) {
+                Some(ChildPlan::Rest { price, quantity }) => {
+                    let current = resting.map(|index| children[index].price);
+                    if current != Some(price) {
+                        if let Some(index) = resting.take() {
+                            end_resting(venue, journal, parent, &mut children[index], book).await;
+                        }
+                        let filled: Decimal = children.iter().map(|c| c.filled).sum();
+                        let quantity = quantity.min(parent.quantity - filled);
+                        if quantity > Decimal::ZERO && !has_unconfirmed_cancel(&children) {
+                            match venue.rest(parent, price, quantity).await {
+                                Ok(exchange_order_id) => {
+                                    let child = ChildOrder {
+                                        id: Uuid::new_v4(),
+                                        kind: ChildKind::Rest,
+                                        exchange_order_id,
+                                        price,
+                                        quantity,
+                                        filled: Decimal::ZERO,
+                                        fees: Decimal::ZERO,
+                                        placed_at: now,
+                                        done_at: None,
+                                    };
+                                    journal.record(parent, &child).await;
+                                    children.push(child);
+                                    resting = Some(children.len() - 1);
+                                }
+                                Err(e) => {
+                                    warn!(parent_id = %parent.id, error = %e, "Order router: resting child rejected");
+                                }
+                            }
+                        }
+                    }
+                }
+                Some(ChildPlan::Take { price, quantity }) => {
+                    // Crossing ends the passive phase.
+                    if let Some(index) = resting.take() {
+                        end_resting(venue, journal, parent, &mut children[index], book).await;
+                    }
+                    let filled: Decimal = children.iter().map(|c| c.filled).sum();
+                    let quantity = quantity.min(parent.quantity - filled);
+                    if quantity > Decimal::ZERO && !has_unconfirmed_cancel(&children) {
+                        let mut child = ChildOrder {
+                            id: Uuid::new_v4(),
+                            kind: ChildKind::Take,
+                            exchange_order_id: None,
+                            price,
+                            quantity,
+                            filled: Decimal::ZERO,
+                            fees: Decimal::ZERO,
+                            placed_at: now,
+                            done_at: None,
+                        };
+                        journal.record(parent, &child).await;
+                        let fill = venue
+                            .take(parent, price, quantity)
+                            .await
+                            .unwrap_or_else(|e| {
+                                warn!(parent_id = %parent.id, error = %e, "Order router: child order failed");
+                                TakeFill::default()
+                            });
+                        if fill.quantity > Decimal::ZERO {
+                            child.price = fill.price;
+                        }
+                        child.filled = fill.quantity.min(quantity);
+                        child.fees = fill.fees;
+                        child.done_at = Some(Utc::now());
+                        journal.record(parent, &child).await;
+                        children.push(child);
+                    }
+                }
+                None => {}
+            }
+        }
+
+        tokio::time::sleep(poll).await;
+    };
+
+    if let Some(index) = resting {
+        if let Some(book) = last_book.as_ref() {
+            sync_resting(venue, journal, parent, &mut children[index], book).await;
+        }
+    }
+    // Every resting child still open gets one more cancel; one that still
+    // cannot be confirmed is left open in the journal for startup
+    // reconciliation.
+    for child in children
+        .iter_mut()
+        .filter(|c| c.kind == ChildKind::Rest && c.done_at.is_none())
+    {
+        cancel_resting(venue, journal, parent, child).await;
+    }
+
+    ExecutionOutcome::from_children(parent, stop_reason, children, started_at)
+}
+
+/// A resting child whose cancel failed may still fill; no new child goes
+/// out until it is confirmed closed.
+fn has_unconfirmed_cancel(children: &[ChildOrder]) -> bool {
+    children
+        .iter()
+        .any(|c| c.kind == ChildKind::Rest && c.done_at.is_none())
+}
+
+/// Fold in the resting child's latest fill; returns whether it is still open.
+async fn sync_resting(
+    venue: &dyn ExecutionVenue,
+    journal: &dyn ChildJournal,
+    parent: &ParentOrder,
+    child: &mut ChildOrder,
+    book: &OrderBook,
+) -> bool {
+    match venue.resting_fill(parent, child, book).await {
+        Ok(fill) => {
+            let filled = fill.matched.clamp(child.filled, child.quantity);
+            let open = fill.open && filled < child.quantity;
+            if filled != child.filled || !open {
+                child.filled = filled;
+                if !open {
+                    child.done_at = Some(Utc::now());
+                }
+                journal.record(parent, child).await;
+            }
+            open
+        }
+        Err(e) => {
+            debug!(parent_id = %parent.id, error = %e, "Order router: fill poll failed");
+            true
+        }
+    }
+}
+
+/// Take the last fills on a resting child and cancel the rest of it.
+async fn end_resting(
+    venue: &dyn ExecutionVenue,
+    journal: &dyn ChildJournal,
+    parent: &ParentOrder,
+    child: &mut ChildOrder,
+    book: &OrderBook,
+) {
+    if sync_resting(venue, journal, parent, child, book).await {
+        cancel_resting(venue, journal, parent, child).await;
+    }
+}
+
+/// Cancel a resting child and book its final fill. A cancel that cannot be
+/// confirmed leaves the child open.
+async fn cancel_resting(
+    venue: &dyn ExecutionVenue,
+    journal: &dyn ChildJournal,
+    parent: &ParentOrder,
+    child: &mut ChildOrder,
+) {
+    match venue.cancel_resting(child).await {
+        Ok(filled) => {
+            child.filled = filled.clamp(child.filled, child.quantity);
+            child.done_at = Some(Utc::now());
+            journal.record(parent, child).await;
+        }
+        Err(e) => {
+            warn!(
+                parent_id = %parent.id,
+                order_id = child.exchange_order_id.as_deref(),
+                error = %e,
+                "Order router: cancel not confirmed"
+            );
+        }
+    }
+}
+
+/// Work `parent` and record it and its children in `execution_parent_orders`
+/// and `execution_child_orders`.
+pub async fn execute_parent(
+    pool: &PgPool,
+    venue: &dyn ExecutionVenue,
+    cfg: &OrderRouterConfig,
+    parent: &ParentOrder,
+    position_id: Option<Uuid>,
+) -> ExecutionOutcome {
+    if let Err(e) = record_parent_start(pool, parent, position_id).await {
+        warn!(parent_id = %parent.id, error = %e, "Failed to record parent order");
+    }
+    let outcome = run_parent(venue, pool, cfg, parent).await;
+    if let Err(e) = record_parent_outcome(pool, parent, &outcome).await {
+        warn!(parent_id = %parent.id, error = %e, "Failed to record parent order outcome");
+    }
+    info!(
+        parent_id = %parent.id,
+        algo = parent.algo.as_str(),
+        stop_reason = outcome.stop_reason.as_str(),
+        filled = %outcome.filled_quantity,
+        quantity = %parent.quantity,
+        children = outcome.children.len(),
+        shortfall_bps = outcome.shortfall.map(|s| s.bps),
+        "Order router finished parent order"
+    );
+    outcome
+}
+
+async fn record_parent_start(
+    pool: &PgPool,
+    parent: &ParentOrder,
+    position_id: Option<Uuid>,
+) -> sqlx::Result<()> {
+    sqlx::query(
+        r#"
+        INSERT INTO execution_parent_orders (
+            id, signal_id, position_id, market_id, token_id, side, algo,
+            quantity, limit_price, deadline, arrival_mid
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+        "#,
+    )
+    .bind(parent.id)
+    .bind(parent.signal_id)
+    .bind(position_id)
+    .bind(&parent.market_id)
+    .bind(&parent.token_id)
+    .bind(side_str(parent.side))
+    .bind(parent.algo.as_str())
+    .bind(parent.quantity)
+    .bind(parent.limit_price)
+    .bind(parent.deadline)
+    .bind(parent.arrival_mid)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+async fn record_parent_outcome(
+    pool: &PgPool,
+    parent: &ParentOrder,
+    outcome: &ExecutionOutcome,
+) -> sqlx::Result<()> {
+    let mut tx = pool.begin().await?;
+    sqlx::query(
+        r#"
+        UPDATE execution_parent_orders
+        SET finished_at = $2,
+            stop_reason = $3,
+            filled_quantity = $4,
+            average_price = $5,
+            fees = $6,
+            shortfall_usd = $7,
+            shortfall_bps = $8
+        WHERE id = $1
+        "#,
+    )
+    .bind(parent.id)
+    .bind(outcome.finished_at)
+    .bind(outcome.stop_reason.as_str())
+    .bind(outcome.filled_quantity)
+    .bind(outcome.average_price)
+    .bind(outcome.fees)
+    .bind(outcome.shortfall.map(|s| s.usd))
+    .bind(outcome.shortfall.map(|s| s.bps))
+    .execute(&mut *tx)
+    .await?;
+    for child in &outcome.children {
+        upsert_child(&mut *tx, parent.id, child).await?;
+    }
+    tx.commit().await
+}
+
+async fn upsert_child<'e, E>(executor: E, parent_id: Uuid, child: &ChildOrder) -> sqlx::Result<()>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    sqlx::query(
+        r#"
+        INSERT INTO execution_child_orders (
+            id, parent_id, kind, exchange_order_id, price, quantity,
+            filled_quantity, fees, placed_at, done_at
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+        ON CONFLICT (id) DO UPDATE SET
+            price = EXCLUDED.price,
+            filled_quantity = EXCLUDED.filled_quantity,
+            fees = EXCLUDED.fees,
+            done_at = EXCLUDED.done_at
+        "#,
+    )
+    .bind(child.id)
+    .bind(parent_id)
+    .bind(child.kind.as_str())
+    .bind(child.exchange_order_id.as_deref())
+    .bind(child.price)
+    .bind(child.quantity)
+    .bind(child.filled)
+    .bind(child.fees)
+    .bind(child.placed_at)
+    .bind(child.done_at)
+    .execute(executor)
+    .await?;
+    Ok(())
+}
+
+/// A parent order a restart left unfinished, closed out with the fills its
+/// children had at the time.
+#[derive(Debug, Clone)]
+pub struct InterruptedParent {
+    pub parent: ParentOrder,
+    pub position_id: Option<Uuid>,
+    pub outcome: ExecutionOutcome,
+}
+
+#[derive(sqlx::FromRow)]
+struct UnfinishedParentRow {
+    id: Uuid,
+    signal_id: Option<Uuid>,
+    position_id: Option<Uuid>,
+    market_id: String,
+    token_id: String,
+    side: String,
+    algo: String,
+    quantity: Decimal,
+    limit_price: Decimal,
+    deadline: DateTime<Utc>,
+    arrival_mid: Decimal,
+    started_at: DateTime<Utc>,
+}
+
+#[derive(sqlx::FromRow)]
+struct ChildRow {
+    id: Uuid,
+    kind: String,
+    exchange_order_id: Option<String>,
+    price: Decimal,
+    quantity: Decimal,
+    filled_quantity: Decimal,
+    fees: Decimal,
+    placed_at: DateTime<Utc>,
+    done_at: Option<DateTime<Utc>>,
+}
+
+/// Close out parent orders a previous run left unfinished: cancel their
+/// live resting children, read back the final fills and record the parents
+/// as interrupted. A marketable child caught mid-flight has an unknown fill
+/// and is left to wallet inventory reconciliation.
+pub async fn reconcile_interrupted(
+    pool: &PgPool,
+    venue: &dyn ExecutionVenue,
+) -> sqlx::Result<Vec<InterruptedParent>> {
+    let rows = sqlx::query_as::<_, UnfinishedParentRow>(
+        r#"
+        SELECT id, signal_id, position_id, market_id, token_id, side, algo,
+               quantity, limit_price, deadline, arrival_mid, started_at
+        FROM execution_parent_orders
+        WHERE finished_at IS NULL
+        ORDER BY started_at
+        "#,
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let mut interrupted = Vec::with_capacity(rows.len());
+    for row in rows {
+        let parent = ParentOrder {
+            id: row.id,
+            signal_id: row.signal_id,
+            market_id: row.market_id,
+            token_id: row.token_id,
+            side: if row.side == "SELL" {
+                OrderSide::Sell
+            } else {
+                OrderSide::Buy
+            },
+            quantity: row.quantity,
+            limit_price: row.limit_price,
+            deadline: row.deadline,
+            expires_with_signal: false,
+            arrival_mid: row.arrival_mid,
+            algo: AlgoKind::parse(&row.algo).unwrap_or(AlgoKind::Market),
+        };
+        let child_rows = sqlx::query_as::<_, ChildRow>(
+            r#"
+            SELECT id, kind, exchange_order_id, price, quantity,
+                   filled_quantity, fees, placed_at, done_at
+            FROM execution_child_orders
+            WHERE parent_id = $1
+            ORDER BY placed_at
+            "#,
+        )
+        .bind(parent.id)
+        .fetch_all(pool)
+        .await?;
+
+        let mut children: Vec<ChildOrder> = child_rows
+            .into_iter()
+            .map(|c| ChildOrder {
+                id: c.id,
+                kind: if c.kind == "rest" {
+                    ChildKind::Rest
+                } else {
+                    ChildKind::Take
+                },
+                exchange_order_id: c.exchange_order_id,
+                price: c.price,
+                quantity: c.quantity,
+                filled: c.filled_quantity,
+                fees: c.fees,
+                placed_at: c.placed_at,
+                done_at: c.done_at,
+            })
+            .collect();
+        for child in children.iter_mut().filter(|c| c.done_at.is_none()) {
+            match child.kind {
+                ChildKind::Rest => cancel_resting(venue, pool, &parent, child).await,
+                ChildKind::Take => warn!(
+                    parent_id = %parent.id,
+                    child_id = %child.id,
+                    "Order router: marketable child interrupted, fill unknown"
+                ),
+            }
+        }
+
+        let outcome = ExecutionOutcome::from_children(
+            &parent,
+            StopReason::Interrupted,
+            children,
+            row.started_at,
+        );
+        record_parent_outcome(pool, &parent, &outcome).await?;
+        info!(
+            parent_id = %parent.id,
+            filled = %outcome.filled_quantity,
+            quantity = %parent.quantity,
+            "Order router closed out interrupted parent order"
+        );
+        interrupted.push(InterruptedParent {
+            parent,
+            position_id: row.position_id,
+            outcome,
+        });
+    }
+    Ok(interrupted)
+}
+
+fn side_str(side: OrderSide) -> &'static str {
+    match side {
+        OrderSide::Buy => "BUY",
+        OrderSide::Sell => "SELL",
+    }
+}
+
+/// Venue backed by the order executor: marketable children are FOK market
+/// orders, resting children are post-only GTC orders in live mode and
+/// simulated against the book in paper mode.
+pub struct ExecutorVenue {
+    executor: Arc<OrderExecutor>,
+    pool: PgPool,
+}
+
+impl ExecutorVenue {
+    pub fn new(executor: Arc<OrderExecutor>, pool: PgPool) -> Self {
+        Self { executor, pool }
+    }
+}
+
+#[async_trait]
+impl ExecutionVenue for ExecutorVenue {
+    async fn order_book(&self, token_id: &str) -> anyhow::Result<OrderBook> {
+        Ok(self.executor.clob_client().get_order_book(token_id).await?)
+    }
+
+    async fn take(
+        &self,
+        parent: &ParentOrder,
+        price: Decimal,
+        quantity: Decimal,
+    ) -> anyhow::Result<TakeFill> {
+        // Zero slippage: if the touch has moved, the next poll re-plans.
+        let order = MarketOrder::new(
+            parent.market_id.clone(),
+            parent.token_id.clone(),
+            parent.side,
+            quantity,
+        )
+        .with_expected_price(price)
+        .with_slippage(Decimal::ZERO);
+        let report = self.executor.execute_market_order(order).await?;
+        if !report.is_success() {
+            debug!(
+                parent_id = %parent.id,
+                reason = report.error_message.as_deref().unwrap_or("unknown"),
+                "Order router: child order not filled"
+            );
+            return Ok(TakeFill::default());
+        }
+        Ok(TakeFill {
+            quantity: report.filled_quantity,
+            price: report.average_price,
+            fees: report.fees_paid,
+        })
+    }
+
+    async fn rest(
+        &self,
+        parent: &ParentOrder,
+        price: Decimal,
+        quantity: Decimal,
+    ) -> anyhow::Result<Option<String>> {
+        if !self.executor.is_live() {
+            return Ok(None);
+        }
+        self.executor
+            .place_post_only_order(&parent.token_id, parent.side, price, quantity)
+            .await
+            .map(Some)
+    }
+
+    async fn resting_fill(
+        &self,
+        parent: &ParentOrder,
+        child: &ChildOrder,
+        book: &OrderBook,
+    ) -> anyhow::Result<LiveOrderFill> {
+        let Some(order_id) = child.exchange_order_id.as_deref() else {
+            // Paper: filled once the other side trades through our price.
+            let crossed = match parent.side {
+                OrderSide::Buy => book.best_ask().is_some_and(|ask| ask <= child.price),
+                OrderSide::Sell => book.best_bid().is_some_and(|bid| bid >= child.price),
+            };
+            return Ok(LiveOrderFill {
+                matched: if crossed {
+                    child.quantity
+                } else {
+                    child.filled
+                },
+                open: !crossed,
+            });
+        };
+        self.executor.live_order_fill(order_id).await
+    }
+
+    async fn cancel_resting(&self, child: &ChildOrder) -> anyhow::Result<Decimal> {
+        match child.exchange_order_id.as_deref() {
+            Some(order_id) => Ok(self
+                .executor
+                .cancel_live_order_and_read_fill(order_id)
+                .await?
+                .matched),
+            None => Ok(child.filled),
+        }
+    }
+
+    async fn traded_volume(&self, token_id: &str, since: DateTime<Utc>) -> anyhow::Result<Decimal> {
+        let volume: Option<Decimal> = sqlx::query_scalar(
+            r#"
+            SELECT SUM(size)
+            FROM market_trade_prints
+            WHERE asset_id = $1 AND timestamp >= $2
+            "#,
+        )
+        .bind(token_id)
+        .bind(since)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(volume.unwrap_or(Decimal::ZERO))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use polymarket_core::types::PriceLevel;
+    use std::sync::Mutex;
+
+    fn dec(value: &str) -> Decimal {
+        value.parse().unwrap()
+    }
+
+    /// Fixed book; takes fill in full at the touch, resting orders fill when
+    /// `cross_after` books have been read. With `closed_after`, the first
+    /// resting order is instead closed elsewhere after that many books, with
+    /// `closed_fill` matched.
+    struct MockVenue {
+        bid: Decimal,
+        ask: Decimal,
+        cross_after: Option<usize>,
+        closed_after: Option<usize>,
+        closed_fill: Decimal,
+        books: Mutex<usize>,
+        takes: Mutex<Vec<Decimal>>,
+        rests: Mutex<usize>,
+        cancels: Mutex<usize>,
+    }
+
+    impl MockVenue {
+        fn new(bid: &str, ask: &str, cross_after: Option<usize>) -> Self {
+            Self {
+                bid: dec(bid),
+                ask: dec(ask),
+                cross_after,
+                closed_after: None,
+                closed_fill: Decimal::ZERO,
+                books: Mutex::new(0),
+                takes: Mutex::new(Vec::new()),
+                rests: Mutex::new(0),
+                cancels: Mutex::new(0),
+            }
+        }
+    }
+
+    struct NullJournal;
+
+    #[async_trait]
+    impl ChildJournal for NullJournal {
+        async fn record(&self, _parent: &ParentOrder, _child: &ChildOrder) {}
+    }
+
+    #[async_trait]
+    impl ExecutionVenue for MockVenue {
+        async fn order_book(&self, token_id: &str) -> anyhow::Result<OrderBook> {
+            *self.books.lock().unwrap() += 1;
+            Ok(OrderBook {
+                market_id: "m".into(),
+                outcome_id: token_id.into(),
+                timestamp: Utc::now(),
+                bids: vec![PriceLevel {
+                    price: self.bid,
+                    size: dec("1000"),
+                }],
+                asks: vec![PriceLevel {
+                    price: self.ask,
+                    size: dec("1000"),
+                }],
+            })
+        }
+
+        async fn take(
+            &self,
+            _parent: &ParentOrder,
+            price: Decimal,
+            quantity: Decimal,
+        ) -> anyhow::Result<TakeFill> {
+            self.takes.lock().unwrap().push(quantity);
+            Ok(TakeFill {
+                quantity,
+                price,
+                fees: Decimal::ZERO,
+            })
+        }
+
+        async fn rest(
+            &self,
+            _parent: &ParentOrder,
+            _price: Decimal,
+            _quantity: Decimal,
+        ) -> anyhow::Result<Option<String>> {
+            let mut rests = self.rests.lock().unwrap();
+            *rests += 1;
+            Ok(Some(format!("resting-{rests}")))
+        }
+
+        async fn resting_fill(
+            &self,
+            _parent: &ParentOrder,
+            child: &ChildOrder,
+            _book: &OrderBook,
+        ) -> anyhow::Result<LiveOrderFill> {
+            let books = *self.books.lock().unwrap();
+            let first = child.exchange_order_id.as_deref() == Some("resting-1");
+            if first && self.closed_after.is_some_and(|n| books > n) {
+                return Ok(LiveOrderFill {
+                    matched: self.closed_fill,
+                    open: false,
+                });
+            }
+            Ok(match self.cross_after {
+                Some(n) if books > n => LiveOrderFill {
+                    matched: child.quantity,
+                    open: false,
+                },
+                _ => LiveOrderFill {
+                    matched: child.filled,
+                    open: true,
+                },
+            })
+        }
+
+        async fn cancel_resting(&self, child: &ChildOrder) -> anyhow::Result<Decimal> {
+            *self.cancels.lock().unwrap() += 1;
+            Ok(child.filled)
+        }
+
+        async fn traded_volume(
+            &self,
+            _token_id: &str,
+            _since: DateTime<Utc>,
+        ) -> anyhow::Result<Decimal> {
+            Ok(Decimal::ZERO)
+        }
+    }
+
+    fn parent(algo: AlgoKind, deadline_ms: i64, expires_with_signal: bool) -> ParentOrder {
+        ParentOrder {
+            id: Uuid::new_v4(),
+            signal_id: None,
+            market_id: "m".into(),
+            token_id: "t".into(),
+            side: OrderSide::Buy,
+            quantity: dec("60"),
+            limit_price: dec("0.52"),
+            deadline: Utc::now() + Duration::milliseconds(deadline_ms),
+            expires_with_signal,
+            arrival_mid: dec("0.48"),
+            algo,
+        }
+    }
+
+    fn config() -> OrderRouterConfig {
+        OrderRouterConfig {
+            twap_slices: 3,
+            min_child_quantity: Decimal::ONE,
+            passive_fraction: 0.5,
+            poll_interval_ms: 5,
+            ..OrderRouterConfig::from_env()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_twap_fills_in_slices_and_reports_shortfall() {
+        let venue = MockVenue::new("0.46", "0.50", None);
+        let outcome = run_parent(
+            &venue,
+            &NullJournal,
+            &config(),
+            &parent(AlgoKind::Twap, 600, false),
+        )
+        .await;
+
+        assert_eq!(outcome.stop_reason, StopReason::Filled);
+        assert_eq!(outcome.filled_quantity, dec("60"));
+        assert_eq!(*venue.takes.lock().unwrap(), vec![dec("20"); 3]);
+        assert_eq!(outcome.average_price, Some(dec("0.50")));
+        // 2 cents over the 0.48 mid on 60 shares.
+        let shortfall = outcome.shortfall.unwrap();
+        assert_eq!(shortfall.usd, dec("1.2"));
+        assert!(outcome.children.iter().all(|c| c.kind == ChildKind::Take));
+    }
+
+    #[tokio::test]
+    async fn test_passive_fill_avoids_crossing() {
+        let venue = MockVenue::new("0.46", "0.50", Some(2));
+        let outcome = run_parent(
+            &venue,
+            &NullJournal,
+            &config(),
+            &parent(AlgoKind::PassiveAggressive, 2_000, false),
+        )
+        .await;
+
+        assert_eq!(outcome.stop_reason, StopReason::Filled);
+        assert!(venue.takes.lock().unwrap().is_empty());
+        assert_eq!(outcome.average_price, Some(dec("0.47")));
+        // Bought a cent under the mid.
+        assert_eq!(outcome.shortfall.unwrap().usd, dec("-0.6"));
+    }
+
+    #[tokio::test]
+    async fn test_stops_at_signal_expiry_and_cancels_resting_child() {
+        let venue = MockVenue::new("0.46", "0.50", None);
+        let cfg = OrderRouterConfig {
+            passive_fraction: 1.0,
+            ..config()
+        };
+        let outcome = run_parent(
+            &venue,
+            &NullJournal,
+            &cfg,
+            &parent(AlgoKind::PassiveAggressive, 50, true),
+        )
+        .await;
+
+        assert_eq!(outcome.stop_reason, StopReason::SignalExpired);
+        assert_eq!(outcome.filled_quantity, Decimal::ZERO);
+        assert!(outcome.average_price.is_none());
+        assert_eq!(*venue.cancels.lock().unwrap(), 1);
+        assert!(outcome.children.iter().all(|c| c.done_at.is_some()));
+    }
+
+    #[tokio::test]
+    async fn test_resting_child_closed_elsewhere_is_not_filled() {
+        let mut venue = MockVenue::new("0.46", "0.50", None);
+        venue.closed_after = Some(2);
+        venue.closed_fill = dec("10");
+        let cfg = OrderRouterConfig {
+            passive_fraction: 1.0,
+            ..config()
+        };
+        let outcome = run_parent(
+            &venue,
+            &NullJournal,
+            &cfg,
+            &parent(AlgoKind::PassiveAggressive, 50, true),
+        )
+        .await;
+
+        // Only the 10 shares matched before the order was closed count.
+        assert_eq!(outcome.children[0].filled, dec("10"));
+        assert_eq!(outcome.filled_quantity, dec("10"));
+        assert_eq!(outcome.stop_reason, StopReason::SignalExpired);
+        assert!(outcome.children.iter().all(|c| c.done_at.is_some()));
+    }
+}
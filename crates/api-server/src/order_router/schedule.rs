@@ -0,0 +1,502 @@
+//! Slicing schedules: how much of a parent order should be done by now and
+//! what the next child order is.
+//!
+//! Pure functions of the parent, the book and the clock, so the runner can
+//! re-plan on every poll and catch up on slices it had to skip.
+
+use chrono::{DateTime, Duration, Utc};
+use polymarket_core::types::{OrderBook, OrderSide};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+/// Execution algorithm for a parent order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlgoKind {
+    /// One order for the whole quantity, as before the router existed.
+    Market,
+    /// Equal slices at even intervals up to the deadline.
+    Twap,
+    /// A fixed share of the volume traded in the token since work started.
+    Pov,
+    /// Rest inside the spread for part of the horizon, then cross for the rest.
+    PassiveAggressive,
+}
+
+impl AlgoKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Market => "market",
+            Self::Twap => "twap",
+            Self::Pov => "pov",
+            Self::PassiveAggressive => "passive_aggressive",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "market" => Some(Self::Market),
+            "twap" => Some(Self::Twap),
+            "pov" => Some(Self::Pov),
+            "passive_aggressive" => Some(Self::PassiveAggressive),
+            _ => None,
+        }
+    }
+}
+
+/// Configuration for the order router.
+#[derive(Debug, Clone)]
+pub struct OrderRouterConfig {
+    /// Algorithm used for orders at or above `min_parent_notional`.
+    pub algo: AlgoKind,
+    /// Orders below this notional (USD) go out as a single market order.
+    pub min_parent_notional: Decimal,
+    /// Time allowed to work a parent order; signal expiry shortens it.
+    pub horizon_secs: u64,
+    /// Number of TWAP slices.
+    pub twap_slices: u32,
+    /// Share of traded volume taken by participation-of-volume.
+    pub participation: Decimal,
+    /// Share of the horizon passive-then-aggressive spends resting.
+    pub passive_fraction: f64,
+    /// Limit price offset from the touch at arrival, as a fraction of it.
+    pub limit_slippage: Decimal,
+    /// Smallest child order sent, unless less than that remains.
+    pub min_child_quantity: Decimal,
+    /// How often the book is re-read and the next child planned.
+    pub poll_interval_ms: u64,
+    /// Price increment used when stepping inside the spread.
+    pub tick_size: Decimal,
+}
+
+impl OrderRouterConfig {
+    pub fn from_env() -> Self {
+        Self {
+            algo: AlgoKind::parse(&polymarket_core::settings::get::<String>(
+                "ORDER_ROUTER_ALGO",
+            ))
+            .unwrap_or(AlgoKind::Market),
+            min_parent_notional: polymarket_core::settings::get("ORDER_ROUTER_MIN_NOTIONAL"),
+            horizon_secs: polymarket_core::settings::get("ORDER_ROUTER_HORIZON_SECS"),
+            twap_slices: polymarket_core::settings::get("ORDER_ROUTER_TWAP_SLICES"),
+            participation: polymarket_core::settings::get("ORDER_ROUTER_PARTICIPATION"),
+            passive_fraction: polymarket_core::settings::get("ORDER_ROUTER_PASSIVE_FRACTION"),
+            limit_slippage: polymarket_core::settings::get("ORDER_ROUTER_LIMIT_SLIPPAGE"),
+            min_child_quantity: polymarket_core::settings::get("ORDER_ROUTER_MIN_CHILD_QTY"),
+            poll_interval_ms: polymarket_core::settings::get("ORDER_ROUTER_POLL_MS"),
+            tick_size: Decimal::new(1, 2),
+        }
+    }
+
+    /// Whether an order of this notional is worked rather than sent whole.
+    pub fn routes(&self, notional: Decimal) -> bool {
+        self.algo != AlgoKind::Market && notional >= self.min_parent_notional
+    }
+
+    /// Limit price for a parent order whose touch at arrival is `touch`.
+    pub fn limit_price(&self, side: OrderSide, touch: Decimal) -> Decimal {
+        match side {
+            OrderSide::Buy => {
+                (touch * (Decimal::ONE + self.limit_slippage)).min(Decimal::ONE - self.tick_size)
+            }
+            OrderSide::Sell => (touch * (Decimal::ONE - self.limit_slippage)).max(self.tick_size),
+        }
+    }
+
+    /// Deadline for work starting at `now`, and whether the signal's expiry
+    /// rather than the horizon sets it.
+    pub fn deadline(
+        &self,
+        now: DateTime<Utc>,
+        expiry: Option<DateTime<Utc>>,
+    ) -> (DateTime<Utc>, bool) {
+        let horizon = now + Duration::seconds(self.horizon_secs as i64);
+        match expiry {
+            Some(expiry) if expiry < horizon => (expiry, true),
+            _ => (horizon, false),
+        }
+    }
+}
+
+/// An order the router works through child orders.
+#[derive(Debug, Clone)]
+pub struct ParentOrder {
+    pub id: Uuid,
+    pub signal_id: Option<Uuid>,
+    pub market_id: String,
+    pub token_id: String,
+    pub side: OrderSide,
+    pub quantity: Decimal,
+    /// No child trades through this price.
+    pub limit_price: Decimal,
+    pub deadline: DateTime<Utc>,
+    /// True when the deadline is the originating signal's expiry.
+    pub expires_with_signal: bool,
+    /// Mid at arrival, the benchmark for implementation shortfall.
+    pub arrival_mid: Decimal,
+    pub algo: AlgoKind,
+}
+
+impl ParentOrder {
+    /// Whether trading at `price` respects the limit.
+    pub fn within_limit(&self, price: Decimal) -> bool {
+        match self.side {
+            OrderSide::Buy => price <= self.limit_price,
+            OrderSide::Sell => price >= self.limit_price,
+        }
+    }
+}
+
+/// The next child order to send.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChildPlan {
+    /// Cross the spread at the touch.
+    Take { price: Decimal, quantity: Decimal },
+    /// Rest a post-only order at `price`.
+    Rest { price: Decimal, quantity: Decimal },
+}
+
+/// Quantity the algorithm should have done by `now`. `market_volume` is the
+/// volume traded in the token since work started, used by participation.
+pub fn target_quantity(
+    cfg: &OrderRouterConfig,
+    parent: &ParentOrder,
+    started_at: DateTime<Utc>,
+    now: DateTime<Utc>,
+    market_volume: Decimal,
+) -> Decimal {
+    match parent.algo {
+        AlgoKind::Market | AlgoKind::PassiveAggressive => parent.quantity,
+        AlgoKind::Twap => {
+            let total = (parent.deadline - started_at).num_milliseconds().max(1);
+            let elapsed = (now - started_at).num_milliseconds().clamp(0, total);
+            let slices = i64::from(cfg.twap_slices.max(1));
+            // Slice k is due at the start of its interval, so the first goes
+            // out immediately and the last well before the deadline.
+            let due = (elapsed * slices / total + 1).min(slices);
+            parent.quantity * Decimal::from(due) / Decimal::from(slices)
+        }
+        AlgoKind::Pov => (market_volume * cfg.participation).min(parent.quantity),
+    }
+}
+
+/// When passive-then-aggressive stops resting and starts crossing.
+pub fn passive_until(
+    cfg: &OrderRouterConfig,
+    parent: &ParentOrder,
+    started_at: DateTime<Utc>,
+) -> DateTime<Utc> {
+    // Measured back from the deadline so a fraction of 1 never crosses.
+    let window = (parent.deadline - started_at).num_milliseconds().max(0) as f64;
+    let aggressive = window * (1.0 - cfg.passive_fraction.clamp(0.0, 1.0));
+    parent.deadline - Duration::milliseconds(aggressive as i64)
+}
+
+/// The next child order, or `None` when the parent is on schedule, done, or
+/// the touch is through the limit.
+pub fn plan_child(
+    cfg: &OrderRouterConfig,
+    parent: &ParentOrder,
+    book: &OrderBook,
+    started_at: DateTime<Utc>,
+    now: DateTime<Utc>,
+    filled: Decimal,
+    market_volume: Decimal,
+) -> Option<ChildPlan> {
+    let remaining = parent.quantity - filled;
+    if remaining <= Decimal::ZERO {
+        return None;
+    }
+
+    if parent.algo == AlgoKind::PassiveAggressive && now < passive_until(cfg, parent, started_at) {
+        return passive_price(cfg, parent, book).map(|price| ChildPlan::Rest {
+            price,
+            quantity: remaining,
+        });
+    }
+
+    let behind = target_quantity(cfg, parent, started_at, now, market_volume) - filled;
+    if behind <= Decimal::ZERO {
+        return None;
+    }
+    let touch = match parent.side {
+        OrderSide::Buy => book.asks.first(),
+        OrderSide::Sell => book.bids.first(),
+    }?;
+    if !parent.within_limit(touch.price) {
+        return None;
+    }
+    let quantity = behind
+        .max(cfg.min_child_quantity)
+        .min(remaining)
+        .min(touch.size);
+    (quantity > Decimal::ZERO).then_some(ChildPlan::Take {
+        price: touch.price,
+        quantity,
+    })
+}
+
+/// One tick inside our side's touch while that stays off the other side,
+/// otherwise joining; never through the limit.
+fn passive_price(
+    cfg: &OrderRouterConfig,
+    parent: &ParentOrder,
+    book: &OrderBook,
+) -> Option<Decimal> {
+    let tick = cfg.tick_size;
+    let (bid, ask) = (book.best_bid(), book.best_ask());
+    let price = match parent.side {
+        OrderSide::Buy => {
+            let price = match (bid.map(|b| b + tick), ask) {
+                (Some(improved), Some(ask)) if improved < ask => improved,
+                (_, Some(ask)) => ask - tick,
+                (Some(improved), None) => improved,
+                (None, None) => return None,
+            };
+            price.min(parent.limit_price)
+        }
+        OrderSide::Sell => {
+            let price = match (ask.map(|a| a - tick), bid) {
+                (Some(improved), Some(bid)) if improved > bid => improved,
+                (_, Some(bid)) => bid + tick,
+                (Some(improved), None) => improved,
+                (None, None) => return None,
+            };
+            price.max(parent.limit_price)
+        }
+    };
+    (price > Decimal::ZERO && price < Decimal::ONE).then_some(price)
+}
+
+/// Cost of an execution against the arrival mid, positive when worse.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Shortfall {
+    /// Price impact and fees in USD on the filled quantity.
+    pub usd: Decimal,
+    /// The same, in basis points of the arrival value of that quantity.
+    pub bps: f64,
+}
+
+/// Implementation shortfall of `filled` shares at `average_price` plus
+/// `fees`, against `arrival_mid`.
+pub fn implementation_shortfall(
+    side: OrderSide,
+    arrival_mid: Decimal,
+    filled: Decimal,
+    average_price: Decimal,
+    fees: Decimal,
+) -> Option<Shortfall> {
+    if filled <= Decimal::ZERO || arrival_mid <= Decimal::ZERO {
+        return None;
+    }
+    let per_share = match side {
+        OrderSide::Buy => average_price - arrival_mid,
+        OrderSide::Sell => arrival_mid - average_price,
+    };
+    let usd = per_share * filled + fees;
+    let bps = usd / (arrival_mid * filled) * Decimal::new(10_000, 0);
+    Some(Shortfall {
+        usd,
+        bps: bps.to_f64().unwrap_or(0.0),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use polymarket_core::types::PriceLevel;
+
+    fn dec(value: &str) -> Decimal {
+        value.parse().unwrap()
+    }
+
+    fn book(bid: Decimal, ask: Decimal, size: Decimal) -> OrderBook {
+        OrderBook {
+            market_id: "m".into(),
+            outcome_id: "t".into(),
+            timestamp: Utc::now(),
+            bids: vec![PriceLevel { price: bid, size }],
+            asks: vec![PriceLevel { price: ask, size }],
+        }
+    }
+
+    fn parent(algo: AlgoKind, started_at: DateTime<Utc>) -> ParentOrder {
+        ParentOrder {
+            id: Uuid::new_v4(),
+            signal_id: None,
+            market_id: "m".into(),
+            token_id: "t".into(),
+            side: OrderSide::Buy,
+            quantity: dec("60"),
+            limit_price: dec("0.52"),
+            deadline: started_at + Duration::seconds(60),
+            expires_with_signal: false,
+            arrival_mid: dec("0.49"),
+            algo,
+        }
+    }
+
+    #[test]
+    fn test_twap_releases_equal_slices_and_catches_up() {
+        let cfg = OrderRouterConfig {
+            twap_slices: 6,
+            ..OrderRouterConfig::from_env()
+        };
+        let t0 = Utc::now();
+        let p = parent(AlgoKind::Twap, t0);
+        let b = book(dec("0.48"), dec("0.50"), dec("1000"));
+
+        assert_eq!(target_quantity(&cfg, &p, t0, t0, Decimal::ZERO), dec("10"));
+        assert_eq!(
+            plan_child(&cfg, &p, &b, t0, t0, Decimal::ZERO, Decimal::ZERO),
+            Some(ChildPlan::Take {
+                price: dec("0.50"),
+                quantity: dec("10")
+            })
+        );
+        // On schedule: nothing to do until the next interval.
+        let t = t0 + Duration::seconds(5);
+        assert_eq!(
+            plan_child(&cfg, &p, &b, t0, t, dec("10"), Decimal::ZERO),
+            None
+        );
+        // Three slices due but only one done: catch up in one child.
+        let t = t0 + Duration::seconds(25);
+        assert_eq!(
+            plan_child(&cfg, &p, &b, t0, t, dec("10"), Decimal::ZERO),
+            Some(ChildPlan::Take {
+                price: dec("0.50"),
+                quantity: dec("20")
+            })
+        );
+        // Ask through the limit: wait.
+        let through = book(dec("0.48"), dec("0.53"), dec("1000"));
+        assert_eq!(
+            plan_child(&cfg, &p, &through, t0, t, dec("10"), Decimal::ZERO),
+            None
+        );
+    }
+
+    #[test]
+    fn test_pov_follows_traded_volume_with_minimum_child() {
+        let cfg = OrderRouterConfig {
+            participation: dec("0.1"),
+            min_child_quantity: dec("5"),
+            ..OrderRouterConfig::from_env()
+        };
+        let t0 = Utc::now();
+        let p = parent(AlgoKind::Pov, t0);
+        let b = book(dec("0.48"), dec("0.50"), dec("8"));
+
+        assert_eq!(
+            plan_child(&cfg, &p, &b, t0, t0, Decimal::ZERO, Decimal::ZERO),
+            None
+        );
+        // 30 traded -> 3 due, sent as the 5-share minimum.
+        assert_eq!(
+            plan_child(&cfg, &p, &b, t0, t0, Decimal::ZERO, dec("30")),
+            Some(ChildPlan::Take {
+                price: dec("0.50"),
+                quantity: dec("5")
+            })
+        );
+        // 200 traded -> 20 due, capped by the 8 shown at the touch.
+        assert_eq!(
+            plan_child(&cfg, &p, &b, t0, t0, Decimal::ZERO, dec("200")),
+            Some(ChildPlan::Take {
+                price: dec("0.50"),
+                quantity: dec("8")
+            })
+        );
+    }
+
+    #[test]
+    fn test_passive_then_aggressive_rests_inside_then_crosses() {
+        let cfg = OrderRouterConfig {
+            passive_fraction: 0.5,
+            ..OrderRouterConfig::from_env()
+        };
+        let t0 = Utc::now();
+        let p = parent(AlgoKind::PassiveAggressive, t0);
+
+        let wide = book(dec("0.46"), dec("0.50"), dec("1000"));
+        assert_eq!(
+            plan_child(&cfg, &p, &wide, t0, t0, dec("15"), Decimal::ZERO),
+            Some(ChildPlan::Rest {
+                price: dec("0.47"),
+                quantity: dec("45")
+            })
+        );
+        // One-tick spread: join the bid rather than cross.
+        let tight = book(dec("0.49"), dec("0.50"), dec("1000"));
+        assert_eq!(
+            plan_child(&cfg, &p, &tight, t0, t0, Decimal::ZERO, Decimal::ZERO),
+            Some(ChildPlan::Rest {
+                price: dec("0.49"),
+                quantity: dec("60")
+            })
+        );
+        let late = t0 + Duration::seconds(31);
+        assert_eq!(
+            plan_child(&cfg, &p, &wide, t0, late, dec("15"), Decimal::ZERO),
+            Some(ChildPlan::Take {
+                price: dec("0.50"),
+                quantity: dec("45")
+            })
+        );
+    }
+
+    #[test]
+    fn test_shortfall_against_arrival_mid() {
+        let buy = implementation_shortfall(
+            OrderSide::Buy,
+            dec("0.50"),
+            dec("100"),
+            dec("0.51"),
+            dec("0.5"),
+        )
+        .unwrap();
+        assert_eq!(buy.usd, dec("1.5"));
+        assert!((buy.bps - 300.0).abs() < 1e-9);
+
+        // Selling above the mid is a negative cost.
+        let sell = implementation_shortfall(
+            OrderSide::Sell,
+            dec("0.50"),
+            dec("100"),
+            dec("0.51"),
+            Decimal::ZERO,
+        )
+        .unwrap();
+        assert_eq!(sell.usd, dec("-1"));
+        assert!(implementation_shortfall(
+            OrderSide::Buy,
+            dec("0.50"),
+            Decimal::ZERO,
+            dec("0.51"),
+            Decimal::ZERO
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_deadline_and_limit_price() {
+        let cfg = OrderRouterConfig {
+            horizon_secs: 120,
+            limit_slippage: dec("0.02"),
+            ..OrderRouterConfig::from_env()
+        };
+        let now = Utc::now();
+        assert_eq!(
+            cfg.deadline(now, Some(now + Duration::seconds(30))),
+            (now + Duration::seconds(30), true)
+        );
+        assert_eq!(
+            cfg.deadline(now, Some(now + Duration::seconds(300))),
+            (now + Duration::seconds(120), false)
+        );
+        assert_eq!(cfg.limit_price(OrderSide::Buy, dec("0.50")), dec("0.51"));
+        assert_eq!(cfg.limit_price(OrderSide::Buy, dec("0.99")), dec("0.99"));
+        assert_eq!(cfg.limit_price(OrderSide::Sell, dec("0.50")), dec("0.49"));
+    }
+}
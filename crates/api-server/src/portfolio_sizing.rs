@@ -0,0 +1,250 @@
+//! Portfolio-level Kelly sizing shared by the quant and latency-arb executors.
+//!
+//! Wraps `polymarket_core::sizing::portfolio_kelly_sizes` with the live inputs
+//! it needs: account equity from the `accounting_ledger` snapshots, open
+//! position legs from `positions`, and pairwise correlations from
+//! `market_correlations`. Equity and open positions are cached briefly so the
+//! latency-arb hot path does not hit Postgres on every price tick.
+
+use chrono::{Duration, Utc};
+use polymarket_core::sizing::{
+    portfolio_kelly_sizes, OpenExposure, PortfolioBet, PortfolioKellyConfig,
+};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use sqlx::{FromRow, PgPool};
+use std::collections::HashMap;
+use std::time;
+use tokio::sync::RwLock;
+use tracing::debug;
+
+use crate::accounting_ledger::load_latest_total_equity;
+
+#[derive(Debug, Clone)]
+pub struct PortfolioSizerConfig {
+    /// Cap on open plus new capital as a fraction of equity.
+    pub max_portfolio_fraction: f64,
+    /// Equity used when no recent account snapshot exists.
+    pub fallback_equity: Decimal,
+    /// Account snapshots older than this are ignored.
+    pub max_equity_age_secs: i64,
+    /// How long equity and open positions are cached.
+    pub cache_ttl_secs: u64,
+}
+
+impl PortfolioSizerConfig {
+    pub fn from_env() -> Self {
+        Self {
+            max_portfolio_fraction: polymarket_core::settings::get(
+                "PORTFOLIO_KELLY_MAX_PORTFOLIO_FRACTION",
+            ),
+            fallback_equity: polymarket_core::settings::get("PORTFOLIO_KELLY_FALLBACK_EQUITY"),
+            max_equity_age_secs: polymarket_core::settings::get(
+                "PORTFOLIO_KELLY_MAX_EQUITY_AGE_SECS",
+            ),
+            cache_ttl_secs: polymarket_core::settings::get("PORTFOLIO_KELLY_CACHE_TTL_SECS"),
+        }
+    }
+}
+
+/// Per-caller sizing limits (each executor keeps its own Kelly fraction and caps).
+#[derive(Debug, Clone)]
+pub struct KellyLimits {
+    pub fraction: f64,
+    pub max_position: Decimal,
+    pub min_position: Decimal,
+}
+
+/// Result of sizing a candidate set.
+#[derive(Debug, Clone)]
+pub struct PortfolioAllocation {
+    /// One entry per candidate, in order; `None` means "do not trade".
+    pub sizes: Vec<Option<Decimal>>,
+    pub equity: Decimal,
+    /// Whether `equity` came from the fallback instead of a live snapshot.
+    pub equity_is_fallback: bool,
+    pub open_exposure: Decimal,
+}
+
+#[derive(Debug, Clone)]
+struct PortfolioSnapshot {
+    loaded_at: time::Instant,
+    equity: Decimal,
+    equity_is_fallback: bool,
+    open_positions: Vec<OpenExposure>,
+}
+
+#[derive(Debug, FromRow)]
+struct OpenLegRow {
+    market_id: String,
+    yes_stake: Decimal,
+    yes_price: Decimal,
+    no_stake: Decimal,
+    no_price: Decimal,
+}
+
+pub struct PortfolioSizer {
+    pool: PgPool,
+    config: PortfolioSizerConfig,
+    cache: RwLock<Option<PortfolioSnapshot>>,
+}
+
+impl PortfolioSizer {
+    pub fn new(pool: PgPool, config: PortfolioSizerConfig) -> Self {
+        Self {
+            pool,
+            config,
+            cache: RwLock::new(None),
+        }
+    }
+
+    /// Size a candidate set jointly against equity, open positions and correlations.
+    pub async fn allocate(
+        &self,
+        candidates: &[PortfolioBet],
+        limits: &KellyLimits,
+    ) -> anyhow::Result<PortfolioAllocation> {
+        let snapshot = self.snapshot().await?;
+
+        let mut market_ids: Vec<String> = candidates
+            .iter()
+            .map(|bet| bet.market_id.clone())
+            .chain(
+                snapshot
+                    .open_positions
+                    .iter()
+                    .map(|open| open.market_id.clone()),
+            )
+            .collect();
+        market_ids.sort();
+        market_ids.dedup();
+        let correlations = self.load_correlations(&market_ids).await?;
+
+        let kelly_config = PortfolioKellyConfig {
+            fraction: limits.fraction,
+            max_position: limits.max_position,
+            min_position: limits.min_position,
+            max_portfolio_fraction: self.config.max_portfolio_fraction,
+        };
+        let sizes = portfolio_kelly_sizes(
+            candidates,
+            &snapshot.open_positions,
+            snapshot.equity,
+            &kelly_config,
+            |a, b| {
+                let key = if a < b { (a, b) } else { (b, a) };
+                correlations
+                    .get(&(key.0.to_string(), key.1.to_string()))
+                    .copied()
+                    .unwrap_or(0.0)
+            },
+        );
+
+        Ok(PortfolioAllocation {
+            sizes,
+            equity: snapshot.equity,
+            equity_is_fallback: snapshot.equity_is_fallback,
+            open_exposure: snapshot.open_positions.iter().map(|o| o.stake).sum(),
+        })
+    }
+
+    /// Drop cached equity/positions, e.g. right after opening a position.
+    pub async fn invalidate(&self) {
+        *self.cache.write().await = None;
+    }
+
+    async fn snapshot(&self) -> anyhow::Result<PortfolioSnapshot> {
+        let ttl = time::Duration::from_secs(self.config.cache_ttl_secs);
+        if let Some(cached) = self.cache.read().await.as_ref() {
+            if cached.loaded_at.elapsed() < ttl {
+                return Ok(cached.clone());
+            }
+        }
+
+        let (equity, equity_is_fallback) = match load_latest_total_equity(&self.pool).await? {
+            Some((equity, at))
+                if equity > Decimal::ZERO
+                    && Utc::now() - at <= Duration::seconds(self.config.max_equity_age_secs) =>
+            {
+                (equity, false)
+            }
+            _ => {
+                debug!(
+                    fallback = %self.config.fallback_equity,
+                    "No recent account snapshot, using fallback equity for portfolio sizing"
+                );
+                (self.config.fallback_equity, true)
+            }
+        };
+        let open_positions = self.load_open_positions().await?;
+
+        let snapshot = PortfolioSnapshot {
+            loaded_at: time::Instant::now(),
+            equity,
+            equity_is_fallback,
+            open_positions,
+        };
+        *self.cache.write().await = Some(snapshot.clone());
+        Ok(snapshot)
+    }
+
+    async fn load_open_positions(&self) -> anyhow::Result<Vec<OpenExposure>> {
+        // Pending entries (state 0) have no held quantity yet but reserve capital.
+        let rows: Vec<OpenLegRow> = sqlx::query_as(
+            r#"
+            SELECT
+                market_id,
+                CASE WHEN state = 0 AND yes_entry_price > 0 THEN quantity ELSE held_yes_qty END
+                    * yes_entry_price AS yes_stake,
+                yes_entry_price AS yes_price,
+                CASE WHEN state = 0 AND no_entry_price > 0 THEN quantity ELSE held_no_qty END
+                    * no_entry_price AS no_stake,
+                no_entry_price AS no_price
+            FROM positions
+            WHERE state IN (0, 1, 2, 3)
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut legs = Vec::new();
+        for row in rows {
+            for (holds_yes, stake, price) in [
+                (true, row.yes_stake, row.yes_price),
+                (false, row.no_stake, row.no_price),
+            ] {
+                if stake > Decimal::ZERO {
+                    legs.push(OpenExposure {
+                        market_id: row.market_id.clone(),
+                        holds_yes,
+                        stake,
+                        price: price.to_f64().unwrap_or(0.5),
+                    });
+                }
+            }
+        }
+        Ok(legs)
+    }
+
+    async fn load_correlations(
+        &self,
+        market_ids: &[String],
+    ) -> anyhow::Result<HashMap<(String, String), f64>> {
+        if market_ids.len() < 2 {
+            return Ok(HashMap::new());
+        }
+        let rows: Vec<(String, String, f64)> = sqlx::query_as(
+            r#"
+            SELECT condition_id_a, condition_id_b, correlation
+            FROM market_correlations
+            WHERE condition_id_a = ANY($1)
+              AND condition_id_b = ANY($1)
+            "#,
+        )
+        .bind(market_ids)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.into_iter().map(|(a, b, rho)| ((a, b), rho)).collect())
+    }
+}
@@ -19,17 +19,11 @@ pub struct PositionReconcilerConfig {
 impl PositionReconcilerConfig {
     pub fn from_env() -> Self {
         Self {
-            enabled: std::env::var("POSITION_RECONCILER_ENABLED")
-                .map(|value| value == "true")
-                .unwrap_or(true),
-            interval_secs: std::env::var("POSITION_RECONCILER_INTERVAL_SECS")
-                .ok()
-                .and_then(|value| value.parse().ok())
-                .unwrap_or(300),
-            startup_delay_secs: std::env::var("POSITION_RECONCILER_STARTUP_DELAY_SECS")
-                .ok()
-                .and_then(|value| value.parse().ok())
-                .unwrap_or(0),
+            enabled: polymarket_core::settings::get("POSITION_RECONCILER_ENABLED"),
+            interval_secs: polymarket_core::settings::get("POSITION_RECONCILER_INTERVAL_SECS"),
+            startup_delay_secs: polymarket_core::settings::get(
+                "POSITION_RECONCILER_STARTUP_DELAY_SECS",
+            ),
         }
     }
 }
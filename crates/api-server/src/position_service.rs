@@ -13,9 +13,11 @@ use rust_decimal::Decimal;
 use sqlx::PgPool;
 use std::sync::Arc;
 use tokio::sync::broadcast;
+use tracing::instrument;
 use uuid::Uuid;
 
 use crate::trade_events::{NewTradeEvent, TradeEventRecorder, TradeEventUpdate};
+use crate::unwind_policy::UnwindDecision;
 
 // ── Supporting types ───────────────────────────────────────────────────
 
@@ -46,6 +48,19 @@ pub enum CloseMethod {
     ResolutionConservative { fee: Decimal },
 }
 
+/// Why a manual close could not be queued.
+#[derive(Debug, thiserror::Error)]
+pub enum ManualCloseError {
+    /// The position is not open: it has already closed.
+    #[error("position is not open")]
+    NotOpen,
+    /// The position is in a state with no exit flow to queue.
+    #[error("{0}")]
+    Rejected(String),
+    #[error(transparent)]
+    Internal(anyhow::Error),
+}
+
 /// Parameters for creating a new position.
 #[derive(Debug, Clone)]
 pub struct CreatePositionParams {
@@ -102,6 +117,7 @@ impl PositionService {
     // ── Position creation ──────────────────────────────────────────
 
     /// Create a new PENDING position, persist it, and record "entry_requested".
+    #[instrument(name = "position.create", skip_all, fields(market_id = %params.market_id))]
     pub async fn create_position(
         &self,
         params: CreatePositionParams,
@@ -151,6 +167,7 @@ impl PositionService {
     ///
     /// This does NOT transition state — the caller must call `mark_open`
     /// after all expected fills are recorded.
+    #[instrument(name = "position.entry_fill", skip_all, fields(position_id = %position.id, leg = ?leg))]
     pub async fn record_entry_fill(
         &self,
         position: &mut Position,
@@ -205,6 +222,7 @@ impl PositionService {
     // ── State transitions ──────────────────────────────────────────
 
     /// Transition Pending → Open. Records "position_open" event.
+    #[instrument(name = "position.open", skip_all, fields(position_id = %position.id))]
     pub async fn mark_open(
         &self,
         position: &mut Position,
@@ -238,6 +256,7 @@ impl PositionService {
     }
 
     /// Transition Pending → EntryFailed. Records "entry_failed" event.
+    #[instrument(name = "position.entry_failed", skip_all, fields(position_id = %position.id))]
     pub async fn mark_entry_failed(
         &self,
         position: &mut Position,
@@ -345,6 +364,7 @@ impl PositionService {
     ///
     /// Dual-writes: calls the legacy `record_*_exit_fill(price)` for PnL
     /// compatibility AND the new `apply_*_exit_fill(qty)` for explicit tracking.
+    #[instrument(name = "position.exit_fill", skip_all, fields(position_id = %position.id, leg = ?leg))]
     pub async fn record_exit_fill(
         &self,
         position: &mut Position,
@@ -418,6 +438,7 @@ impl PositionService {
     // ── Position close ─────────────────────────────────────────────
 
     /// Final close: computes PnL, sets state=Closed, records event.
+    #[instrument(name = "position.close", skip_all, fields(position_id = %position.id))]
     pub async fn close_position(
         &self,
         position: &mut Position,
@@ -492,6 +513,7 @@ impl PositionService {
     // ── Failure and recovery ───────────────────────────────────────
 
     /// Mark exit as failed. Records "exit_failed" event.
+    #[instrument(name = "position.exit_failed", skip_all, fields(position_id = %position.id))]
     pub async fn mark_exit_failed(
         &self,
         position: &mut Position,
@@ -677,6 +699,158 @@ impl PositionService {
         Ok(())
     }
 
+    /// Record the unwind policy decision for a stranded one-legged position.
+    pub async fn record_unwind_decision(
+        &self,
+        position: &Position,
+        decision: &UnwindDecision,
+        ctx: &EventContext,
+    ) {
+        self.events
+            .record_warn(
+                NewTradeEvent::new(
+                    &ctx.strategy,
+                    &ctx.execution_mode,
+                    &ctx.source_label,
+                    &position.market_id,
+                    "one_legged_unwind_decision",
+                )
+                .with_position(position.id)
+                .with_reason(Some(&decision.reason))
+                .with_metadata(decision.metadata()),
+            )
+            .await;
+    }
+
+    /// Record the fill that completed a one-legged arb and move it
+    /// ExitReady → Open so it is held to resolution as a normal pair.
+    pub async fn complete_one_legged(
+        &self,
+        position: &mut Position,
+        missing_leg: Leg,
+        fill_price: Decimal,
+        fill_qty: Decimal,
+        ctx: &EventContext,
+    ) -> anyhow::Result<()> {
+        match missing_leg {
+            Leg::Yes => {
+                position.yes_entry_price = fill_price;
+                position.apply_yes_entry_fill(fill_qty);
+            }
+            Leg::No => {
+                position.no_entry_price = fill_price;
+                position.apply_no_entry_fill(fill_qty);
+            }
+        }
+        position
+            .reopen_completed_one_legged()
+            .map_err(|e| anyhow!("complete one-legged: {}", e))?;
+
+        self.repo
+            .update(position)
+            .await
+            .map_err(|e| anyhow!("update after one-legged completion: {}", e))?;
+
+        self.events
+            .record_warn(
+                NewTradeEvent::new(
+                    &ctx.strategy,
+                    &ctx.execution_mode,
+                    &ctx.source_label,
+                    &position.market_id,
+                    "one_legged_completed",
+                )
+                .with_position(position.id)
+                .with_state(Some("exit_ready"), Some("open"))
+                .with_fill_price(fill_price)
+                .with_filled_size(fill_qty * fill_price)
+                .with_metadata(serde_json::json!({
+                    "leg": missing_leg.to_string(),
+                    "fill_qty": fill_qty.to_string(),
+                    "pair_cost": (position.yes_entry_price + position.no_entry_price).to_string(),
+                })),
+            )
+            .await;
+
+        Ok(())
+    }
+
+    // ── Manual close ───────────────────────────────────────────────
+
+    /// Queue a full-position market exit on operator request, routing the
+    /// position through whichever recovery its state needs first. The exit
+    /// handler performs the actual sells.
+    pub async fn queue_manual_close(
+        &self,
+        position: &mut Position,
+        ctx: &EventContext,
+    ) -> Result<(), ManualCloseError> {
+        let rejected = |e: anyhow::Error| ManualCloseError::Rejected(e.to_string());
+        match position.state {
+            PositionState::Open => self
+                .mark_exit_ready(position, "manual_close_request", ctx)
+                .await
+                .map_err(rejected),
+            PositionState::ExitReady | PositionState::Closing => {
+                // Already in exit flow — just touch the timestamp and persist
+                position.touch();
+                self.repo
+                    .update(position)
+                    .await
+                    .map_err(|e| ManualCloseError::Internal(e.into()))
+            }
+            PositionState::ExitFailed => {
+                let recovered = self
+                    .attempt_exit_recovery(position, ctx)
+                    .await
+                    .map_err(ManualCloseError::Internal)?;
+                if recovered {
+                    Ok(())
+                } else {
+                    Err(ManualCloseError::Rejected(
+                        "Position has exhausted exit retries and cannot be re-queued".to_string(),
+                    ))
+                }
+            }
+            PositionState::Stalled => {
+                match self
+                    .attempt_stalled_recovery(position, ctx)
+                    .await
+                    .map_err(ManualCloseError::Internal)?
+                {
+                    Some(PositionState::Open) => self
+                        .mark_exit_ready(position, "manual_close_after_stall_recovery", ctx)
+                        .await
+                        .map_err(rejected),
+                    Some(PositionState::ExitReady | PositionState::Closing) => Ok(()),
+                    Some(state) => Err(ManualCloseError::Rejected(format!(
+                        "Position recovered to {} and cannot be manually closed yet",
+                        crate::handlers::positions::position_state_label(state)
+                    ))),
+                    None => Err(ManualCloseError::Rejected(
+                        "Position could not be recovered for manual close".to_string(),
+                    )),
+                }
+            }
+            PositionState::EntryFailed if position.is_one_legged_entry_fail() => self
+                .transition_one_legged_to_exit_ready(
+                    position,
+                    "yes",
+                    "manual_close_one_legged",
+                    ctx,
+                )
+                .await
+                .map_err(rejected),
+            PositionState::Pending => Err(ManualCloseError::Rejected(
+                "Position is still entering and cannot be manually closed yet".to_string(),
+            )),
+            PositionState::EntryFailed => Err(ManualCloseError::Rejected(
+                "Position failed to enter and has no exit flow to queue".to_string(),
+            )),
+            PositionState::Closed => Err(ManualCloseError::NotOpen),
+        }
+    }
+
     // ── Utility ────────────────────────────────────────────────────
 
     /// Load a position by ID.
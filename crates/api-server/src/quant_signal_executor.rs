@@ -3,12 +3,16 @@
 //! Receives `QuantSignal` from the broadcast channel, evaluates each against
 //! risk limits, dedup, and confidence thresholds, then executes single-leg
 //! market orders. Structural copy of `arb_executor.rs` adapted for quant signals.
+//! Entries large enough to move a thin book are handed to the order router
+//! instead and worked in the background; the position opens when it reports.
 
 use chrono::Utc;
 use polymarket_core::db::positions::{PositionRepository, SOURCE_RECOMMENDATION};
+use polymarket_core::sizing::PortfolioBet;
 use polymarket_core::types::signal::{QuantSignal, QuantSignalKind, SignalDirection};
-use polymarket_core::types::{ExitStrategy, MarketOrder, OrderSide, Position};
+use polymarket_core::types::{ExitStrategy, MarketOrder, OrderSide, Position, PositionState};
 use risk_manager::circuit_breaker::CircuitBreaker;
+use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
 use sqlx::PgPool;
 use std::collections::{HashMap, HashSet};
@@ -21,6 +25,8 @@ use trading_engine::OrderExecutor;
 use crate::arb_executor::OutcomeTokenCache;
 use crate::learning::{QuantShadowPredictionInput, ShadowPredictionRecorder};
 use crate::learning_rollouts::LearningRolloutController;
+use crate::order_router::{self, ExecutionOutcome, ExecutorVenue, OrderRouterConfig, ParentOrder};
+use crate::portfolio_sizing::{KellyLimits, PortfolioSizer};
 use crate::position_service::{CreatePositionParams, EventContext, Leg, PositionService};
 use crate::trade_events::{NewTradeEvent, TradeEventRecorder};
 use crate::websocket::{SignalType, SignalUpdate};
@@ -31,7 +37,12 @@ pub struct QuantSignalExecutorConfig {
     /// Whether execution is enabled (false = paper mode, signals still logged).
     pub enabled: bool,
     /// Base position size in USD (before confidence weighting).
+    /// The confidence-weighted size caps the portfolio Kelly allocation.
     pub base_position_size_usd: Decimal,
+    /// Hard cap on a single position in USD.
+    pub max_position_size_usd: Decimal,
+    /// Fractional Kelly multiplier for portfolio sizing.
+    pub kelly_fraction: f64,
     /// Minimum confidence to execute (0.0–1.0).
     pub min_confidence: f64,
     /// Maximum signal age in seconds before discarding.
@@ -40,11 +51,9 @@ pub struct QuantSignalExecutorConfig {
     pub cache_refresh_secs: u64,
     /// Maximum simultaneous quant positions.
     pub max_quant_positions: usize,
-    /// Strategy allocation weights (should sum to ~1.0).
-    pub flow_allocation_pct: f64,
-    pub cross_market_allocation_pct: f64,
-    pub mean_reversion_allocation_pct: f64,
-    pub resolution_allocation_pct: f64,
+    /// Strategy allocation weights (should sum to ~1.0), as configured on
+    /// each signal generator.
+    pub allocations: HashMap<QuantSignalKind, f64>,
     /// Minimum orderbook depth in USD on the target side.
     pub min_book_depth: Decimal,
     /// Maximum total exposure across all open positions (shared with arb executor).
@@ -55,81 +64,48 @@ pub struct QuantSignalExecutorConfig {
     pub strategy_max_consecutive_losses: u32,
     /// Cooldown before a consecutive-loss halt is allowed to resume.
     pub strategy_halt_cooldown_secs: u64,
+    /// Signals whose entry price is below this are skipped.
+    pub min_entry_price: Decimal,
+    /// Signals whose entry price is above this are skipped.
+    pub max_entry_price: Decimal,
+    /// How large entries are sliced.
+    pub order_router: OrderRouterConfig,
 }
 
 impl QuantSignalExecutorConfig {
-    pub fn from_env() -> Self {
+    /// Allocations are taken from `generators`, the registry the signal
+    /// generators actually run from.
+    pub fn from_env(generators: &crate::signals::SignalGeneratorRegistry) -> Self {
         Self {
-            enabled: std::env::var("QUANT_EXECUTOR_ENABLED")
-                .map(|v| v == "true")
-                .unwrap_or(false),
-            base_position_size_usd: std::env::var("QUANT_BASE_POSITION_SIZE")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(Decimal::new(30, 0)),
-            min_confidence: std::env::var("QUANT_MIN_CONFIDENCE")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(0.65),
-            max_signal_age_secs: std::env::var("QUANT_MAX_SIGNAL_AGE_SECS")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(120),
-            cache_refresh_secs: std::env::var("QUANT_CACHE_REFRESH_SECS")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(300),
-            max_quant_positions: std::env::var("QUANT_MAX_POSITIONS")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(20),
-            flow_allocation_pct: std::env::var("QUANT_FLOW_ALLOCATION_PCT")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(0.40),
-            cross_market_allocation_pct: std::env::var("QUANT_CROSS_MARKET_ALLOCATION_PCT")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(0.30),
-            mean_reversion_allocation_pct: std::env::var("QUANT_MEAN_REVERSION_ALLOCATION_PCT")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(0.20),
-            resolution_allocation_pct: std::env::var("QUANT_RESOLUTION_ALLOCATION_PCT")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(0.10),
-            min_book_depth: std::env::var("QUANT_MIN_BOOK_DEPTH")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(Decimal::new(50, 0)),
-            max_total_exposure: std::env::var("ARB_MAX_TOTAL_EXPOSURE")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(Decimal::new(25000, 0)),
-            strategy_max_daily_loss_usd: std::env::var("QUANT_STRATEGY_MAX_DAILY_LOSS")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(Decimal::new(200, 0)),
-            strategy_max_consecutive_losses: std::env::var("QUANT_STRATEGY_MAX_CONSECUTIVE_LOSSES")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(5),
-            strategy_halt_cooldown_secs: std::env::var("QUANT_STRATEGY_HALT_COOLDOWN_SECS")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(3600),
+            enabled: polymarket_core::settings::get("QUANT_EXECUTOR_ENABLED"),
+            base_position_size_usd: polymarket_core::settings::get("QUANT_BASE_POSITION_SIZE"),
+            max_position_size_usd: polymarket_core::settings::get("QUANT_MAX_POSITION_SIZE"),
+            kelly_fraction: polymarket_core::settings::get("QUANT_KELLY_FRACTION"),
+            min_confidence: polymarket_core::settings::get("QUANT_MIN_CONFIDENCE"),
+            max_signal_age_secs: polymarket_core::settings::get("QUANT_MAX_SIGNAL_AGE_SECS"),
+            cache_refresh_secs: polymarket_core::settings::get("QUANT_CACHE_REFRESH_SECS"),
+            max_quant_positions: polymarket_core::settings::get("QUANT_MAX_POSITIONS"),
+            allocations: generators.allocations(),
+            min_book_depth: polymarket_core::settings::get("QUANT_MIN_BOOK_DEPTH"),
+            max_total_exposure: polymarket_core::settings::get("ARB_MAX_TOTAL_EXPOSURE"),
+            strategy_max_daily_loss_usd: polymarket_core::settings::get(
+                "QUANT_STRATEGY_MAX_DAILY_LOSS",
+            ),
+            strategy_max_consecutive_losses: polymarket_core::settings::get(
+                "QUANT_STRATEGY_MAX_CONSECUTIVE_LOSSES",
+            ),
+            strategy_halt_cooldown_secs: polymarket_core::settings::get(
+                "QUANT_STRATEGY_HALT_COOLDOWN_SECS",
+            ),
+            min_entry_price: polymarket_core::settings::get("RISK_MIN_ENTRY_PRICE"),
+            max_entry_price: polymarket_core::settings::get("RISK_MAX_ENTRY_PRICE"),
+            order_router: OrderRouterConfig::from_env(),
         }
     }
 
     /// Get the allocation weight for a signal kind.
-    fn allocation_for(&self, kind: QuantSignalKind) -> f64 {
-        match kind {
-            QuantSignalKind::Flow => self.flow_allocation_pct,
-            QuantSignalKind::CrossMarket => self.cross_market_allocation_pct,
-            QuantSignalKind::MeanReversion => self.mean_reversion_allocation_pct,
-            QuantSignalKind::ResolutionProximity => self.resolution_allocation_pct,
-        }
+    pub(crate) fn allocation_for(&self, kind: QuantSignalKind) -> f64 {
+        self.allocations.get(&kind).copied().unwrap_or(0.0)
     }
 }
 
@@ -329,6 +305,18 @@ impl StrategyState {
     }
 }
 
+/// An entry being worked by the order router, returned to the executor
+/// loop when work stops.
+struct RoutedEntry {
+    signal: QuantSignal,
+    position: Position,
+    ctx: EventContext,
+    parent: ParentOrder,
+    outcome_name: &'static str,
+    size_usd: Decimal,
+    outcome: ExecutionOutcome,
+}
+
 /// The quant signal executor background task.
 struct QuantSignalExecutor {
     config: Arc<RwLock<QuantSignalExecutorConfig>>,
@@ -343,6 +331,10 @@ struct QuantSignalExecutor {
     trade_event_recorder: TradeEventRecorder,
     shadow_prediction_recorder: ShadowPredictionRecorder,
     rollout_controller: LearningRolloutController,
+    portfolio_sizer: Arc<PortfolioSizer>,
+    router_venue: Arc<ExecutorVenue>,
+    routed_tx: mpsc::UnboundedSender<RoutedEntry>,
+    routed_rx: mpsc::UnboundedReceiver<RoutedEntry>,
     /// Per-strategy risk state (daily P&L, consecutive losses).
     strategy_states: HashMap<QuantSignalKind, StrategyState>,
     /// Closed quant positions that have already been folded into strategy state.
@@ -366,6 +358,7 @@ impl QuantSignalExecutor {
         pool: PgPool,
         active_clob_markets: Arc<RwLock<HashSet<String>>>,
         heartbeat: Arc<AtomicI64>,
+        portfolio_sizer: Arc<PortfolioSizer>,
     ) -> Self {
         let position_repo = PositionRepository::new(pool.clone());
         let position_service = PositionService::new(pool.clone(), trade_event_tx.clone());
@@ -375,12 +368,13 @@ impl QuantSignalExecutor {
         let trade_event_recorder = TradeEventRecorder::new(pool.clone(), trade_event_tx);
         let shadow_prediction_recorder = ShadowPredictionRecorder::new(pool.clone());
         let rollout_controller = LearningRolloutController::new(pool.clone());
+        let router_venue = Arc::new(ExecutorVenue::new(order_executor.clone(), pool.clone()));
+        let (routed_tx, routed_rx) = mpsc::unbounded_channel();
 
-        let mut strategy_states = HashMap::new();
-        strategy_states.insert(QuantSignalKind::Flow, StrategyState::new());
-        strategy_states.insert(QuantSignalKind::CrossMarket, StrategyState::new());
-        strategy_states.insert(QuantSignalKind::MeanReversion, StrategyState::new());
-        strategy_states.insert(QuantSignalKind::ResolutionProximity, StrategyState::new());
+        let strategy_states = QuantSignalKind::ALL
+            .into_iter()
+            .map(|kind| (kind, StrategyState::new()))
+            .collect();
 
         Self {
             config,
@@ -395,6 +389,10 @@ impl QuantSignalExecutor {
             trade_event_recorder,
             shadow_prediction_recorder,
             rollout_controller,
+            portfolio_sizer,
+            router_venue,
+            routed_tx,
+            routed_rx,
             strategy_states,
             processed_strategy_outcomes: HashSet::new(),
             processed_outcomes_date: Utc::now().date_naive(),
@@ -467,6 +465,9 @@ impl QuantSignalExecutor {
             *state = loaded;
         }
 
+        // Close out routed entries a previous run left mid-flight.
+        self.resume_interrupted_entries(&cfg).await;
+
         let mut cache_ticker =
             tokio::time::interval(std::time::Duration::from_secs(cfg.cache_refresh_secs));
         cache_ticker.tick().await; // skip first tick (just loaded)
@@ -500,6 +501,9 @@ impl QuantSignalExecutor {
                         }
                     }
                 }
+                Some(routed) = self.routed_rx.recv() => {
+                    self.finish_routed_entry(routed).await;
+                }
                 Some(result) = cache_refresh_rx.recv() => {
                     cache_refresh_in_flight = false;
                     match result {
@@ -578,7 +582,7 @@ impl QuantSignalExecutor {
                 continue;
             }
 
-            if let Some(kind) = parse_quant_signal_kind(&row.kind) {
+            if let Some(kind) = QuantSignalKind::parse(&row.kind) {
                 self.record_strategy_outcome(
                     kind,
                     row.realized_pnl,
@@ -594,6 +598,7 @@ impl QuantSignalExecutor {
 
     /// Process a single quant signal through the execution pipeline.
     async fn process_signal(&mut self, signal: QuantSignal) -> anyhow::Result<()> {
+        polymarket_core::metrics::record_signal(signal.kind.as_str(), "entry");
         let cfg = self.snapshot_config().await;
         let execution_mode = self.execution_mode(&cfg).await.to_string();
 
@@ -929,6 +934,26 @@ impl QuantSignalExecutor {
             }
         };
 
+        if best_ask < cfg.min_entry_price || best_ask > cfg.max_entry_price {
+            debug!(
+                signal_id = %signal.id,
+                price = %best_ask,
+                min = %cfg.min_entry_price,
+                max = %cfg.max_entry_price,
+                "Entry price outside order band, skipping"
+            );
+            self.update_signal_status(signal.id, "skipped", Some("price_out_of_band"))
+                .await;
+            self.record_signal_outcome_event(
+                &signal,
+                &execution_mode,
+                "signal_skipped",
+                Some("price_out_of_band"),
+            )
+            .await;
+            return Ok(());
+        }
+
         let total_depth: Decimal = book.asks.iter().map(|l| l.price * l.size).sum();
         if total_depth < cfg.min_book_depth {
             debug!(
@@ -949,14 +974,15 @@ impl QuantSignalExecutor {
             return Ok(());
         }
 
-        // Step 11: Confidence-weighted sizing
+        // Step 11: Confidence-weighted sizing (cap for the portfolio Kelly allocation)
         let allocation_weight = cfg.allocation_for(signal.kind);
         let confidence_decimal =
             Decimal::try_from(signal.confidence).unwrap_or(Decimal::new(65, 2));
         let allocation_decimal =
             Decimal::try_from(allocation_weight).unwrap_or(Decimal::new(40, 2));
         let mut position_size_usd =
-            cfg.base_position_size_usd * confidence_decimal * allocation_decimal;
+            (cfg.base_position_size_usd * confidence_decimal * allocation_decimal)
+                .min(cfg.max_position_size_usd);
 
         let rollout_decision = self
             .rollout_controller
@@ -975,10 +1001,80 @@ impl QuantSignalExecutor {
             return Ok(());
         }
 
+        // Step 11a: Portfolio Kelly — size jointly with open positions against live equity
+        let ask_price = best_ask.to_f64().unwrap_or(0.0);
+        let candidate = PortfolioBet {
+            market_id: signal.condition_id.clone(),
+            buys_yes: signal.direction == SignalDirection::BuyYes,
+            p_win: quant_win_probability(&signal, ask_price),
+            price: quant_kelly_price(&signal, ask_price),
+        };
+        let limits = KellyLimits {
+            fraction: cfg.kelly_fraction,
+            max_position: position_size_usd,
+            min_position: Decimal::ONE,
+        };
+        match self.portfolio_sizer.allocate(&[candidate], &limits).await {
+            Ok(allocation) => match allocation.sizes.first().copied().flatten() {
+                Some(size) => {
+                    debug!(
+                        signal_id = %signal.id,
+                        kelly_size = %size,
+                        cap = %position_size_usd,
+                        equity = %allocation.equity,
+                        open_exposure = %allocation.open_exposure,
+                        "Portfolio Kelly allocation"
+                    );
+                    position_size_usd = size;
+                }
+                None => {
+                    debug!(
+                        signal_id = %signal.id,
+                        equity = %allocation.equity,
+                        open_exposure = %allocation.open_exposure,
+                        "No portfolio Kelly allocation, skipping"
+                    );
+                    self.update_signal_status(signal.id, "skipped", Some("kelly_no_allocation"))
+                        .await;
+                    self.record_signal_outcome_event(
+                        &signal,
+                        &execution_mode,
+                        "signal_skipped",
+                        Some("kelly_no_allocation"),
+                    )
+                    .await;
+                    return Ok(());
+                }
+            },
+            Err(e) => {
+                warn!(
+                    signal_id = %signal.id,
+                    error = %e,
+                    "Portfolio sizing failed"
+                );
+                self.update_signal_status(signal.id, "skipped", Some("portfolio_sizing_failed"))
+                    .await;
+                self.record_signal_outcome_event(
+                    &signal,
+                    &execution_mode,
+                    "signal_skipped",
+                    Some("portfolio_sizing_failed"),
+                )
+                .await;
+                return Ok(());
+            }
+        }
+
         if rollout_decision.size_multiplier < Decimal::ONE {
             position_size_usd *= rollout_decision.size_multiplier;
         }
 
+        // Scale down while the circuit breaker is in recovery or throttled by market conditions.
+        let capacity = self.circuit_breaker.trading_capacity().await;
+        if capacity < Decimal::ONE {
+            position_size_usd *= capacity;
+        }
+
         if position_size_usd < Decimal::new(1, 0) {
             debug!(
                 signal_id = %signal.id,
@@ -1137,6 +1233,8 @@ impl QuantSignalExecutor {
                 }),
             )
             .await?;
+        // The new pending position must count against the next allocation.
+        self.portfolio_sizer.invalidate().await;
 
         info!(
             signal_id = %signal.id,
@@ -1151,6 +1249,61 @@ impl QuantSignalExecutor {
             "Executing quant signal"
         );
 
+        // Step 14a: Large entries are worked by the order router in the
+        // background; the position stays PENDING until it reports.
+        if cfg.order_router.routes(position_size_usd) {
+            let now = Utc::now();
+            let (deadline, expires_with_signal) =
+                cfg.order_router.deadline(now, Some(signal.expiry));
+            let arrival_mid = book
+                .best_bid()
+                .map(|bid| (bid + best_ask) / Decimal::TWO)
+                .unwrap_or(best_ask);
+            let parent = ParentOrder {
+                id: uuid::Uuid::new_v4(),
+                signal_id: Some(signal.id),
+                market_id: signal.condition_id.clone(),
+                token_id: target_token_id.clone(),
+                side: OrderSide::Buy,
+                quantity,
+                limit_price: cfg
+                    .order_router
+                    .limit_price(OrderSide::Buy, best_ask)
+                    .min(cfg.max_entry_price),
+                deadline,
+                expires_with_signal,
+                arrival_mid,
+                algo: cfg.order_router.algo,
+            };
+            let pool = self.pool.clone();
+            let venue = self.router_venue.clone();
+            let router_cfg = cfg.order_router.clone();
+            let routed_tx = self.routed_tx.clone();
+            tokio::spawn(async move {
+                let outcome = order_router::execute_parent(
+                    &pool,
+                    venue.as_ref(),
+                    &router_cfg,
+                    &parent,
+                    Some(position.id),
+                )
+                .await;
+                let routed = RoutedEntry {
+                    signal,
+                    position,
+                    ctx,
+                    parent,
+                    outcome_name,
+                    size_usd: position_size_usd,
+                    outcome,
+                };
+                if routed_tx.send(routed).is_err() {
+                    warn!("Quant executor gone before routed entry finished");
+                }
+            });
+            return Ok(());
+        }
+
         // Step 14: Execute single-leg FOK market order
         let order = MarketOrder::new(
             signal.condition_id.clone(),
@@ -1281,6 +1434,223 @@ impl QuantSignalExecutor {
         Ok(())
     }
 
+    /// Open (or fail) the position behind an entry the order router has
+    /// finished working.
+    /// Cancel live children of parent orders a restart interrupted, then
+    /// settle each still-PENDING position with what its parent filled.
+    async fn resume_interrupted_entries(&mut self, cfg: &QuantSignalExecutorConfig) {
+        let interrupted =
+            match order_router::reconcile_interrupted(&self.pool, self.router_venue.as_ref()).await
+            {
+                Ok(interrupted) => interrupted,
+                Err(e) => {
+                    error!(error = %e, "Failed to reconcile interrupted parent orders");
+                    return;
+                }
+            };
+        if interrupted.is_empty() {
+            return;
+        }
+        let execution_mode = self.execution_mode(cfg).await;
+        for order_router::InterruptedParent {
+            parent,
+            position_id,
+            outcome,
+        } in interrupted
+        {
+            let (Some(position_id), Some(signal_id)) = (position_id, parent.signal_id) else {
+                continue;
+            };
+            let position = match self.position_repo.get(position_id).await {
+                Ok(Some(position)) if position.state == PositionState::Pending => position,
+                Ok(_) => continue,
+                Err(e) => {
+                    warn!(error = %e, position_id = %position_id, "Failed to load interrupted position");
+                    continue;
+                }
+            };
+            let signal = match self.load_signal(signal_id).await {
+                Ok(Some(signal)) => signal,
+                Ok(None) => {
+                    warn!(signal_id = %signal_id, "Signal for interrupted parent order not found");
+                    continue;
+                }
+                Err(e) => {
+                    warn!(error = %e, signal_id = %signal_id, "Failed to load interrupted signal");
+                    continue;
+                }
+            };
+            info!(
+                signal_id = %signal.id,
+                position_id = %position.id,
+                filled = %outcome.filled_quantity,
+                "Settling routed entry interrupted by restart"
+            );
+            let routed = RoutedEntry {
+                ctx: EventContext {
+                    execution_mode: execution_mode.to_string(),
+                    strategy: signal.kind.as_str().to_string(),
+                    source_label: "quant".to_string(),
+                },
+                outcome_name: match signal.direction {
+                    SignalDirection::BuyYes => "Yes",
+                    SignalDirection::BuyNo => "No",
+                },
+                size_usd: parent.quantity * parent.arrival_mid,
+                signal,
+                position,
+                parent,
+                outcome,
+            };
+            self.finish_routed_entry(routed).await;
+        }
+    }
+
+    /// Rebuild a stored quant signal.
+    async fn load_signal(&self, signal_id: uuid::Uuid) -> sqlx::Result<Option<QuantSignal>> {
+        let row = sqlx::query_as::<
+            _,
+            (
+                String,
+                String,
+                String,
+                f64,
+                Option<Decimal>,
+                Option<serde_json::Value>,
+                chrono::DateTime<Utc>,
+            ),
+        >(
+            r#"
+            SELECT kind, condition_id, direction, confidence, size_usd, metadata, generated_at
+            FROM quant_signals
+            WHERE id = $1
+            "#,
+        )
+        .bind(signal_id)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(row.and_then(
+            |(kind, condition_id, direction, confidence, size_usd, metadata, generated_at)| {
+                Some(QuantSignal {
+                    id: signal_id,
+                    kind: QuantSignalKind::parse(&kind)?,
+                    condition_id,
+                    direction: SignalDirection::parse(&direction)?,
+                    confidence,
+                    suggested_size_usd: size_usd.unwrap_or_default(),
+                    // Only settlement is left; the signal is no longer traded.
+                    expiry: generated_at,
+                    metadata: metadata.unwrap_or_default(),
+                    generated_at,
+                })
+            },
+        ))
+    }
+
+    async fn finish_routed_entry(&mut self, routed: RoutedEntry) {
+        let RoutedEntry {
+            signal,
+            mut position,
+            ctx,
+            parent,
+            outcome_name,
+            size_usd,
+            outcome,
+        } = routed;
+        let execution_mode = ctx.execution_mode.clone();
+
+        let Some(average_price) = outcome.average_price else {
+            warn!(
+                signal_id = %signal.id,
+                position_id = %position.id,
+                stop_reason = outcome.stop_reason.as_str(),
+                "Order router filled nothing"
+            );
+            let _ = self
+                .position_service
+                .mark_entry_failed(
+                    &mut position,
+                    polymarket_core::types::FailureReason::OrderRejected {
+                        message: format!(
+                            "Order router filled nothing before {}",
+                            outcome.stop_reason.as_str()
+                        ),
+                    },
+                    &ctx,
+                )
+                .await;
+            self.update_signal_status(signal.id, "failed", Some("router_unfilled"))
+                .await;
+            self.record_position_failure_event(
+                &signal,
+                &execution_mode,
+                &position,
+                "router_unfilled",
+            )
+            .await;
+            self.publish_failure_signal(&signal.condition_id, "Order router filled nothing");
+            return;
+        };
+
+        let fill_leg = match signal.direction {
+            SignalDirection::BuyYes => Leg::Yes,
+            SignalDirection::BuyNo => Leg::No,
+        };
+        if let Err(e) = self
+            .position_service
+            .record_entry_fill(
+                &mut position,
+                fill_leg,
+                average_price,
+                outcome.filled_quantity,
+                &ctx,
+            )
+            .await
+        {
+            warn!(error = %e, position_id = %position.id, "Failed to record entry fill");
+        }
+        if let Err(e) = self.position_service.mark_open(&mut position, &ctx).await {
+            error!(error = %e, "Failed to transition position to OPEN");
+        }
+        self.link_signal_to_position(signal.id, position.id).await;
+
+        let ws_signal = SignalUpdate {
+            signal_id: uuid::Uuid::new_v4(),
+            signal_type: SignalType::Arbitrage, // reuse existing type for now
+            market_id: signal.condition_id.clone(),
+            outcome_id: outcome_name.to_string(),
+            action: "quant_executed".to_string(),
+            confidence: signal.confidence,
+            timestamp: Utc::now(),
+            metadata: serde_json::json!({
+                "position_id": position.id.to_string(),
+                "signal_kind": signal.kind.as_str(),
+                "direction": signal.direction.as_str(),
+                "quantity": outcome.filled_quantity.to_string(),
+                "price": average_price.to_string(),
+                "size_usd": size_usd.to_string(),
+                "confidence": signal.confidence,
+                "algo": parent.algo.as_str(),
+                "stop_reason": outcome.stop_reason.as_str(),
+                "child_orders": outcome.children.len(),
+                "arrival_mid": parent.arrival_mid.to_string(),
+                "shortfall_bps": outcome.shortfall.map(|s| s.bps),
+            }),
+        };
+        let _ = self.signal_tx.send(ws_signal);
+
+        info!(
+            signal_id = %signal.id,
+            position_id = %position.id,
+            kind = signal.kind.as_str(),
+            algo = parent.algo.as_str(),
+            filled = %outcome.filled_quantity,
+            requested = %parent.quantity,
+            shortfall_bps = outcome.shortfall.map(|s| s.bps),
+            "Quant signal executed through order router"
+        );
+    }
+
     /// Record an outcome with the per-strategy circuit breaker.
     async fn record_strategy_outcome(
         &mut self,
@@ -1439,16 +1809,6 @@ impl QuantSignalExecutor {
     }
 }
 
-fn parse_quant_signal_kind(kind: &str) -> Option<QuantSignalKind> {
-    match kind {
-        "flow" => Some(QuantSignalKind::Flow),
-        "cross_market" => Some(QuantSignalKind::CrossMarket),
-        "mean_reversion" => Some(QuantSignalKind::MeanReversion),
-        "resolution_proximity" => Some(QuantSignalKind::ResolutionProximity),
-        _ => None,
-    }
-}
-
 /// Spawn the quant signal executor background task.
 #[allow(clippy::too_many_arguments)]
 pub fn spawn_quant_signal_executor(
@@ -1462,6 +1822,7 @@ pub fn spawn_quant_signal_executor(
     pool: PgPool,
     active_clob_markets: Arc<RwLock<HashSet<String>>>,
     heartbeat: Arc<AtomicI64>,
+    portfolio_sizer: Arc<PortfolioSizer>,
 ) {
     let executor = QuantSignalExecutor::new(
         config,
@@ -1474,6 +1835,7 @@ pub fn spawn_quant_signal_executor(
         pool,
         active_clob_markets,
         heartbeat,
+        portfolio_sizer,
     );
 
     tokio::spawn(async move {
@@ -1556,18 +1918,94 @@ fn expected_edge_from_signal(signal: &QuantSignal) -> Option<Decimal> {
         .and_then(Decimal::from_f64_retain)
 }
 
+/// Win probability for the purchased outcome, used for Kelly sizing.
+///
+/// Prefers an explicit `p_win` from the generator, then `expected_edge_bps`
+/// over the entry price. Otherwise confidence above 0.5 is read as the share
+/// of the remaining upside `(1 - price)` the signal expects to capture.
+fn quant_win_probability(signal: &QuantSignal, price: f64) -> f64 {
+    let p_win = if let Some(p) = signal.metadata.get("p_win").and_then(|v| v.as_f64()) {
+        p
+    } else if let Some(bps) = signal
+        .metadata
+        .get("expected_edge_bps")
+        .and_then(|v| v.as_f64())
+    {
+        price + bps / 10_000.0
+    } else {
+        price + (signal.confidence - 0.5).max(0.0) * (1.0 - price)
+    };
+    p_win.clamp(0.0, 0.999)
+}
+
+/// Entry price charged for Kelly sizing: the ask plus the carrying cost of
+/// the stake until payout, when the generator reports `capital_lockup_cost`
+/// as a fraction of the stake.
+fn quant_kelly_price(signal: &QuantSignal, ask_price: f64) -> f64 {
+    let lockup_cost = signal
+        .metadata
+        .get("capital_lockup_cost")
+        .and_then(|v| v.as_f64())
+        .unwrap_or(0.0)
+        .max(0.0);
+    (ask_price * (1.0 + lockup_cost)).min(0.999)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_quant_win_probability_sources() {
+        let expiry = Utc::now() + chrono::Duration::minutes(5);
+        let signal = QuantSignal::new(
+            QuantSignalKind::Flow,
+            "0xabc".to_string(),
+            SignalDirection::BuyYes,
+            0.70,
+            Decimal::new(30, 0),
+            expiry,
+        );
+        // Confidence 0.70 captures 20% of the remaining upside from 0.50.
+        assert!((quant_win_probability(&signal, 0.50) - 0.60).abs() < 1e-9);
+
+        let with_edge = signal
+            .clone()
+            .with_metadata(serde_json::json!({ "expected_edge_bps": 500.0 }));
+        assert!((quant_win_probability(&with_edge, 0.50) - 0.55).abs() < 1e-9);
+
+        let explicit = signal.with_metadata(serde_json::json!({ "p_win": 0.8 }));
+        assert!((quant_win_probability(&explicit, 0.50) - 0.80).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_kelly_price_charges_capital_lockup() {
+        let signal = QuantSignal::new(
+            QuantSignalKind::ResolutionProximity,
+            "0xabc".to_string(),
+            SignalDirection::BuyYes,
+            0.70,
+            Decimal::new(30, 0),
+            Utc::now() + chrono::Duration::minutes(60),
+        );
+        assert_eq!(quant_kelly_price(&signal, 0.80), 0.80);
+
+        let locked = signal.with_metadata(serde_json::json!({ "capital_lockup_cost": 0.01 }));
+        assert!((quant_kelly_price(&locked, 0.80) - 0.808).abs() < 1e-9);
+        assert_eq!(quant_kelly_price(&locked, 0.995), 0.999);
+    }
+
     #[test]
     fn test_config_defaults() {
-        let config = QuantSignalExecutorConfig::from_env();
+        let config = QuantSignalExecutorConfig::from_env(
+            &crate::signals::SignalGeneratorRegistry::from_env(),
+        );
         assert!(!config.enabled); // default disabled (paper first)
         assert_eq!(config.base_position_size_usd, Decimal::new(30, 0));
         assert_eq!(config.min_confidence, 0.65);
         assert_eq!(config.cache_refresh_secs, 300);
         assert_eq!(config.max_quant_positions, 20);
+        assert_eq!(config.order_router.algo, order_router::AlgoKind::Market);
     }
 
     #[test]
@@ -1653,7 +2091,9 @@ mod tests {
 
     #[test]
     fn test_allocation_weights() {
-        let config = QuantSignalExecutorConfig::from_env();
+        let config = QuantSignalExecutorConfig::from_env(
+            &crate::signals::SignalGeneratorRegistry::from_env(),
+        );
         assert_eq!(config.allocation_for(QuantSignalKind::Flow), 0.40);
         assert_eq!(config.allocation_for(QuantSignalKind::CrossMarket), 0.30);
         assert_eq!(config.allocation_for(QuantSignalKind::MeanReversion), 0.20);
@@ -1663,10 +2103,7 @@ mod tests {
         );
 
         // Weights should approximately sum to 1.0
-        let total = config.flow_allocation_pct
-            + config.cross_market_allocation_pct
-            + config.mean_reversion_allocation_pct
-            + config.resolution_allocation_pct;
+        let total: f64 = config.allocations.values().sum();
         assert!((total - 1.0).abs() < 0.01);
     }
 
@@ -2,13 +2,27 @@
 //!
 //! Subscribes to Redis channels from arb-monitor and other services,
 //! then forwards signals to the API server's WebSocket broadcast channels.
+//!
+//! With the stream transport, arb entry signals are read from a Redis stream
+//! consumer group instead of pub/sub and acknowledged once forwarded, so
+//! signals published while the server is down are delivered on reconnect.
+//!
+//! Tick-size changes relayed by arb-monitor are written into the CLOB tick
+//! cache, so orders placed here snap to a new grid straight away.
 
 use chrono::Utc;
 use futures_util::StreamExt;
+use polymarket_core::api::clob::{record_tick_size, TickSizeChange, TICK_SIZE_CHANNEL};
+use polymarket_core::signal_stream::{
+    SignalStreamConfig, SignalStreamConsumer, SignalStreamMetrics, SignalTransport, StreamEntry,
+};
+use polymarket_core::telemetry;
 use polymarket_core::types::ArbOpportunity;
 use rust_decimal::Decimal;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tokio::sync::broadcast;
-use tracing::{debug, error, info, warn};
+use tracing::{debug, error, info, info_span, warn};
 
 use crate::websocket::{OrderbookUpdate, SignalType, SignalUpdate};
 
@@ -32,6 +46,8 @@ pub struct RedisForwarderConfig {
     pub subscribe_orderbook: bool,
     /// Reconnection delay in seconds.
     pub reconnect_delay_secs: u64,
+    /// Transport and consumer-group settings for arb entry signals.
+    pub entry_stream: SignalStreamConfig,
 }
 
 impl Default for RedisForwarderConfig {
@@ -41,6 +57,7 @@ impl Default for RedisForwarderConfig {
             subscribe_arb: true,
             subscribe_orderbook: true,
             reconnect_delay_secs: 5,
+            entry_stream: SignalStreamConfig::default(),
         }
     }
 }
@@ -49,28 +66,25 @@ impl RedisForwarderConfig {
     /// Create config from environment variables.
     pub fn from_env() -> Self {
         Self {
-            redis_url: std::env::var("REDIS_URL")
-                .unwrap_or_else(|_| "redis://127.0.0.1:6379".to_string()),
-            subscribe_arb: std::env::var("REDIS_SUBSCRIBE_ARB")
-                .map(|v| v == "true")
-                .unwrap_or(true),
-            subscribe_orderbook: std::env::var("REDIS_SUBSCRIBE_ORDERBOOK")
-                .map(|v| v == "true")
-                .unwrap_or(true),
-            reconnect_delay_secs: std::env::var("REDIS_RECONNECT_DELAY")
-                .ok()
-                .and_then(|s| s.parse().ok())
-                .unwrap_or(5),
+            redis_url: polymarket_core::settings::get("REDIS_URL"),
+            subscribe_arb: polymarket_core::settings::get("REDIS_SUBSCRIBE_ARB"),
+            subscribe_orderbook: polymarket_core::settings::get("REDIS_SUBSCRIBE_ORDERBOOK"),
+            reconnect_delay_secs: polymarket_core::settings::get("REDIS_RECONNECT_DELAY"),
+            entry_stream: SignalStreamConfig::from_env(),
         }
     }
 }
 
+/// How often stream delivery metrics are logged.
+const STREAM_METRICS_LOG_INTERVAL: Duration = Duration::from_secs(60);
+
 /// Redis signal forwarder that bridges Redis pub/sub to WebSocket broadcasts.
 pub struct RedisForwarder {
     config: RedisForwarderConfig,
     signal_tx: broadcast::Sender<SignalUpdate>,
     orderbook_tx: broadcast::Sender<OrderbookUpdate>,
     arb_entry_tx: broadcast::Sender<ArbOpportunity>,
+    stream_metrics: Arc<SignalStreamMetrics>,
 }
 
 impl RedisForwarder {
@@ -86,11 +100,30 @@ impl RedisForwarder {
             signal_tx,
             orderbook_tx,
             arb_entry_tx,
+            stream_metrics: Arc::new(SignalStreamMetrics::default()),
         }
     }
 
+    /// Delivery counters and latency for the entry stream consumer.
+    pub fn stream_metrics(&self) -> Arc<SignalStreamMetrics> {
+        self.stream_metrics.clone()
+    }
+
+    fn entry_via_stream(&self) -> bool {
+        self.config.subscribe_arb && self.config.entry_stream.transport == SignalTransport::Stream
+    }
+
     /// Start the forwarder - runs until cancelled.
     pub async fn run(&self) -> anyhow::Result<()> {
+        if self.entry_via_stream() {
+            tokio::join!(self.run_pubsub(), self.run_entry_stream());
+        } else {
+            self.run_pubsub().await;
+        }
+        Ok(())
+    }
+
+    async fn run_pubsub(&self) {
         loop {
             match self.run_inner().await {
                 Ok(_) => {
@@ -118,7 +151,10 @@ impl RedisForwarder {
 
         // Subscribe to configured channels
         if self.config.subscribe_arb {
-            pubsub.subscribe(channels::ARB_ENTRY).await?;
+            // Entry signals arrive on the stream instead when it is enabled.
+            if !self.entry_via_stream() {
+                pubsub.subscribe(channels::ARB_ENTRY).await?;
+            }
             pubsub.subscribe(channels::ARB_EXIT).await?;
             pubsub.subscribe(channels::ARB_ALERTS).await?;
             debug!("Subscribed to arbitrage channels");
@@ -129,6 +165,9 @@ impl RedisForwarder {
             debug!("Subscribed to orderbook updates channel");
         }
 
+        // Keeps the tick cache order placement reads current.
+        pubsub.subscribe(TICK_SIZE_CHANNEL).await?;
+
         info!("Redis forwarder listening for signals");
 
         // Process messages
@@ -153,6 +192,127 @@ impl RedisForwarder {
         Ok(())
     }
 
+    async fn run_entry_stream(&self) {
+        loop {
+            if let Err(e) = self.run_entry_stream_inner().await {
+                error!(error = %e, "Arb entry stream consumer error, reconnecting...");
+            }
+
+            tokio::time::sleep(tokio::time::Duration::from_secs(
+                self.config.reconnect_delay_secs,
+            ))
+            .await;
+        }
+    }
+
+    async fn run_entry_stream_inner(&self) -> anyhow::Result<()> {
+        let client = redis::Client::open(self.config.redis_url.as_str())?;
+        let mut consumer = SignalStreamConsumer::connect(
+            client,
+            self.config.entry_stream.clone(),
+            self.stream_metrics.clone(),
+        )
+        .await?;
+
+        info!(
+            stream = %consumer.config().stream_key,
+            group = %consumer.config().group,
+            consumer = %consumer.config().consumer,
+            "Consuming arb entry signals from Redis stream"
+        );
+
+        // Entries delivered to us before a restart but never acknowledged.
+        let pending = consumer.read_own_pending().await?;
+        if !pending.is_empty() {
+            info!(
+                count = pending.len(),
+                "Replaying unacknowledged arb entry signals"
+            );
+        }
+        self.forward_stream_entries(&mut consumer, pending).await?;
+
+        let reclaim_interval =
+            Duration::from_millis(consumer.config().reclaim_idle_ms.max(1_000) as u64);
+        let mut last_reclaim = Instant::now();
+        let mut last_metrics_log = Instant::now();
+        loop {
+            if last_reclaim.elapsed() >= reclaim_interval {
+                if let Err(e) = consumer.refresh_backlog().await {
+                    debug!(error = %e, "Failed to sample arb entry stream backlog");
+                }
+                let reclaimed = consumer.reclaim_stale().await?;
+                if !reclaimed.is_empty() {
+                    warn!(
+                        count = reclaimed.len(),
+                        "Reclaimed idle pending arb entry signals"
+                    );
+                }
+                self.forward_stream_entries(&mut consumer, reclaimed)
+                    .await?;
+                last_reclaim = Instant::now();
+            }
+
+            let entries = consumer.read_new().await?;
+            self.forward_stream_entries(&mut consumer, entries).await?;
+
+            if last_metrics_log.elapsed() >= STREAM_METRICS_LOG_INTERVAL {
+                let snapshot = self.stream_metrics.snapshot();
+                info!(
+                    received = snapshot.received,
+                    acked = snapshot.acked,
+                    reclaimed = snapshot.reclaimed,
+                    dead_lettered = snapshot.dead_lettered,
+                    left_pending = snapshot.left_pending,
+                    pending = snapshot.pending,
+                    lag = snapshot.lag,
+                    avg_latency_ms = snapshot.avg_latency_ms,
+                    max_latency_ms = snapshot.max_latency_ms,
+                    "Arb entry stream delivery metrics"
+                );
+                last_metrics_log = Instant::now();
+            }
+        }
+    }
+
+    /// Forward each entry to the executor, then acknowledge it. An entry no
+    /// executor received stays pending and is reclaimed once idle; entries
+    /// that fail to parse are acknowledged: redelivering them cannot help.
+    async fn forward_stream_entries(
+        &self,
+        consumer: &mut SignalStreamConsumer,
+        entries: Vec<StreamEntry>,
+    ) -> anyhow::Result<()> {
+        for entry in entries {
+            let latency_ms = entry.latency_ms(Utc::now().timestamp_millis());
+            if let Some(latency_ms) = latency_ms {
+                self.stream_metrics.record_latency(latency_ms);
+            }
+            debug!(
+                id = %entry.id,
+                latency_ms = ?latency_ms,
+                reclaimed = entry.reclaimed,
+                "Received arb entry from stream"
+            );
+
+            match entry.payload.as_deref() {
+                Some(payload) => match self.handle_arb_entry(payload).await {
+                    Ok(0) => {
+                        warn!(id = %entry.id, "No executor received stream entry, leaving it pending");
+                        consumer.metrics().record_left_pending();
+                        continue;
+                    }
+                    Ok(_) => {}
+                    Err(e) => {
+                        warn!(id = %entry.id, error = %e, "Failed to process stream entry");
+                    }
+                },
+                None => warn!(id = %entry.id, "Stream entry has no payload"),
+            }
+            consumer.ack(&entry.id).await?;
+        }
+        Ok(())
+    }
+
     async fn process_message(&self, channel: &str, payload: &str) -> anyhow::Result<()> {
         match channel {
             channels::ARB_ENTRY => {
@@ -167,6 +327,15 @@ impl RedisForwarder {
             channels::ORDERBOOK_UPDATES => {
                 self.handle_orderbook_update(payload).await?;
             }
+            TICK_SIZE_CHANNEL => {
+                let change: TickSizeChange = serde_json::from_str(payload)?;
+                debug!(
+                    asset_id = %change.asset_id,
+                    tick_size = %change.new_tick_size,
+                    "Recorded relayed tick size change"
+                );
+                record_tick_size(&change.asset_id, change.new_tick_size);
+            }
             _ => {
                 debug!(channel = %channel, "Unknown channel");
             }
@@ -175,8 +344,18 @@ impl RedisForwarder {
         Ok(())
     }
 
-    async fn handle_arb_entry(&self, payload: &str) -> anyhow::Result<()> {
-        let arb: ArbOpportunity = serde_json::from_str(payload)?;
+    /// Returns how many executors received the signal.
+    async fn handle_arb_entry(&self, payload: &str) -> anyhow::Result<usize> {
+        let mut arb: ArbOpportunity = serde_json::from_str(payload)?;
+
+        // Continue arb-monitor's trace, then hand this span's context to the
+        // executor, which picks the signal up on another task.
+        let span = info_span!("arb.signal.forward", market_id = %arb.market_id);
+        telemetry::set_parent(&span, arb.trace_context.as_ref());
+        let _entered = span.enter();
+        if let Some(context) = telemetry::current_context() {
+            arb.trace_context = Some(context);
+        }
 
         // Forward to arb auto-executor before WebSocket processing
         let receivers = self.arb_entry_tx.send(arb.clone()).unwrap_or(0);
@@ -230,7 +409,7 @@ impl RedisForwarder {
             "Forwarded arbitrage entry signal"
         );
 
-        Ok(())
+        Ok(receivers)
     }
 
     async fn handle_arb_exit(&self, payload: &str) -> anyhow::Result<()> {
@@ -363,6 +542,7 @@ mod tests {
         assert!(config.subscribe_arb);
         assert!(config.subscribe_orderbook);
         assert_eq!(config.reconnect_delay_secs, 5);
+        assert_eq!(config.entry_stream.transport, SignalTransport::PubSub);
     }
 
     #[test]
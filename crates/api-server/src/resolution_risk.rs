@@ -0,0 +1,618 @@
+//! Resolution-risk model for near-expiry favourites.
+//!
+//! A market does not always pay out on its end date, and a heavy favourite
+//! sometimes loses. The tracker watches markets from a week before their end
+//! date, polls them once the end date has passed and records into
+//! `market_resolutions` when each one actually closed on the venue (Gamma's
+//! close time, not when the tracker noticed) and which side won. [`ResolutionRiskModel`] is fitted per
+//! category from that history:
+//! - **Reversal**: how often the favourite lost, relative to the losses its
+//!   price implied
+//! - **Delay**: how often payout came later than the grace period after the
+//!   end date, and by how many days
+//!
+//! Category figures are shrunk toward the all-market figures, which are in
+//! turn shrunk toward fixed priors, so thin categories fall back smoothly.
+//! Disputes are not exposed by the APIs; a disputed market shows up as a
+//! late resolution and, if the outcome is overturned, as a reversal.
+//!
+//! The resolution signal discounts its confidence by the estimate and
+//! attaches the expected capital-lockup cost, which the quant executor
+//! charges against the entry price when sizing.
+
+use chrono::{DateTime, Duration, Utc};
+use polymarket_core::api::gamma::GammaClient;
+use polymarket_core::api::ClobClient;
+use sqlx::PgPool;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time;
+use tracing::{debug, info, warn};
+
+use crate::exit_handler::resolved_yes_winner;
+
+/// Days before the end date a market starts being watched.
+const WATCH_DAYS: i64 = 7;
+/// Prior chance of a market paying out after the grace period.
+const PRIOR_DELAY_PROBABILITY: f64 = 0.05;
+/// Prior days past the end date for a late payout.
+const PRIOR_DELAY_DAYS: f64 = 3.0;
+/// Underdog price assumed when there are no priced resolutions yet.
+const DEFAULT_IMPLIED_REVERSAL: f64 = 0.2;
+
+/// Configuration for resolution tracking and the risk model (env-var driven).
+#[derive(Debug, Clone)]
+pub struct ResolutionRiskConfig {
+    /// Whether the resolution tracker runs.
+    pub enabled: bool,
+    /// Interval between tracking cycles in seconds; the model refits as often.
+    pub interval_secs: u64,
+    /// Ended, unresolved markets checked against the CLOB per cycle.
+    pub max_checks: i64,
+    /// Resolution history the model is fitted on, in days.
+    pub lookback_days: i64,
+    /// Hours after the end date before a payout counts as late.
+    pub grace_hours: f64,
+    /// Pseudo-markets of prior weight behind every estimate.
+    pub prior_strength: f64,
+    /// Annual cost of capital charged for the expected lockup.
+    pub capital_cost_apr: f64,
+    /// Share of confidence removed per unit of delay probability.
+    pub delay_confidence_weight: f64,
+}
+
+impl Default for ResolutionRiskConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval_secs: 1800,
+            max_checks: 50,
+            lookback_days: 180,
+            grace_hours: 24.0,
+            prior_strength: 20.0,
+            capital_cost_apr: 0.10,
+            delay_confidence_weight: 0.5,
+        }
+    }
+}
+
+impl ResolutionRiskConfig {
+    /// Load configuration from environment variables.
+    pub fn from_env() -> Self {
+        Self {
+            enabled: polymarket_core::settings::get("RESOLUTION_RISK_ENABLED"),
+            interval_secs: polymarket_core::settings::get("RESOLUTION_RISK_INTERVAL_SECS"),
+            max_checks: polymarket_core::settings::get("RESOLUTION_RISK_MAX_CHECKS"),
+            lookback_days: polymarket_core::settings::get("RESOLUTION_RISK_LOOKBACK_DAYS"),
+            grace_hours: polymarket_core::settings::get("RESOLUTION_RISK_GRACE_HOURS"),
+            prior_strength: polymarket_core::settings::get("RESOLUTION_RISK_PRIOR_STRENGTH"),
+            capital_cost_apr: polymarket_core::settings::get("RESOLUTION_RISK_CAPITAL_COST_APR"),
+            delay_confidence_weight: polymarket_core::settings::get("RESOLUTION_RISK_DELAY_WEIGHT"),
+        }
+    }
+
+    fn grace(&self) -> Duration {
+        Duration::minutes((self.grace_hours * 60.0) as i64)
+    }
+}
+
+/// One tracked market from `market_resolutions`.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct ResolutionObservation {
+    pub category: Option<String>,
+    pub end_date: DateTime<Utc>,
+    /// YES price a day before the end date.
+    pub favourite_yes_price: Option<f64>,
+    pub resolved_at: Option<DateTime<Utc>>,
+    pub yes_won: Option<bool>,
+    /// When tracking started; timing is unknown for markets first seen late.
+    pub first_seen_at: DateTime<Utc>,
+}
+
+/// Resolution behaviour of one category, or of all markets.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CategoryRisk {
+    /// Favourite losses over the losses their prices implied; 1.0 is fairly priced.
+    pub reversal_ratio: f64,
+    /// Chance of paying out after the grace period.
+    pub delay_probability: f64,
+    /// Mean days past the end date for a late payout.
+    pub mean_delay_days: f64,
+    /// Tracked markets behind the figures.
+    pub samples: u32,
+}
+
+impl CategoryRisk {
+    const PRIOR: Self = Self {
+        reversal_ratio: 1.0,
+        delay_probability: PRIOR_DELAY_PROBABILITY,
+        mean_delay_days: PRIOR_DELAY_DAYS,
+        samples: 0,
+    };
+}
+
+/// Running counts for one category.
+#[derive(Debug, Clone, Copy, Default)]
+struct Tally {
+    /// Markets whose payout timing is known or overdue.
+    due: f64,
+    delayed: f64,
+    /// Days past the end date, summed over late markets (to now if still open).
+    delay_days: f64,
+    /// Resolved markets with a priced favourite.
+    decided: f64,
+    reversals: f64,
+    /// Underdog prices summed over decided markets.
+    implied_reversals: f64,
+}
+
+impl Tally {
+    fn add(&mut self, obs: &ResolutionObservation, now: DateTime<Utc>, grace: Duration) {
+        let deadline = obs.end_date + grace;
+        if obs.first_seen_at <= deadline {
+            let late_until = match obs.resolved_at {
+                Some(at) => Some(at).filter(|&at| at > deadline),
+                None if now > deadline => Some(now),
+                None => None,
+            };
+            if obs.resolved_at.is_some() || now > deadline {
+                self.due += 1.0;
+            }
+            if let Some(until) = late_until {
+                self.delayed += 1.0;
+                self.delay_days += days(until - obs.end_date);
+            }
+        }
+
+        if let (Some(_), Some(yes_won), Some(price)) =
+            (obs.resolved_at, obs.yes_won, obs.favourite_yes_price)
+        {
+            if price != 0.5 {
+                self.decided += 1.0;
+                if (price > 0.5) != yes_won {
+                    self.reversals += 1.0;
+                }
+                self.implied_reversals += price.min(1.0 - price).max(0.0);
+            }
+        }
+    }
+
+    /// Shrink the counts toward `prior` with `strength` pseudo-markets.
+    fn shrink(&self, prior: CategoryRisk, strength: f64) -> CategoryRisk {
+        let mean_implied = if self.decided > 0.0 {
+            self.implied_reversals / self.decided
+        } else {
+            DEFAULT_IMPLIED_REVERSAL
+        };
+        let implied_prior = strength * mean_implied;
+        CategoryRisk {
+            reversal_ratio: (self.reversals + implied_prior * prior.reversal_ratio)
+                / (self.implied_reversals + implied_prior).max(f64::EPSILON),
+            delay_probability: (self.delayed + strength * prior.delay_probability)
+                / (self.due + strength).max(f64::EPSILON),
+            // One pseudo-late market at the prior delay.
+            mean_delay_days: (self.delay_days + prior.mean_delay_days) / (self.delayed + 1.0),
+            samples: self.due.max(self.decided) as u32,
+        }
+    }
+}
+
+fn days(duration: Duration) -> f64 {
+    duration.num_seconds().max(0) as f64 / 86_400.0
+}
+
+/// Risk of entering a favourite a given time before its end date.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ResolutionRisk {
+    /// Chance of the favourite losing that its price implies.
+    pub implied_reversal: f64,
+    /// Chance of the favourite losing after the category's track record.
+    pub reversal_probability: f64,
+    pub delay_probability: f64,
+    /// Days until payout, including the expected delay.
+    pub expected_lockup_days: f64,
+    /// Carrying cost of the stake over the expected lockup, as a fraction of it.
+    pub capital_lockup_cost: f64,
+}
+
+impl ResolutionRisk {
+    /// Discount a confidence score for reversal risk beyond what the price
+    /// implies and for the chance of a late payout. Never raises it.
+    pub fn adjust_confidence(&self, confidence: f64, config: &ResolutionRiskConfig) -> f64 {
+        let excess_reversal = (self.reversal_probability - self.implied_reversal).max(0.0);
+        let delay_discount =
+            (1.0 - self.delay_probability * config.delay_confidence_weight).clamp(0.0, 1.0);
+        ((confidence - excess_reversal) * delay_discount).clamp(0.0, confidence.max(0.0))
+    }
+}
+
+/// Per-category reversal and delay estimates.
+#[derive(Debug, Clone)]
+pub struct ResolutionRiskModel {
+    overall: CategoryRisk,
+    /// Keyed by lowercased category.
+    categories: HashMap<String, CategoryRisk>,
+    fitted_at: Option<DateTime<Utc>>,
+}
+
+impl Default for ResolutionRiskModel {
+    /// Priors only, and stale so the first use refits.
+    fn default() -> Self {
+        Self {
+            overall: CategoryRisk::PRIOR,
+            categories: HashMap::new(),
+            fitted_at: None,
+        }
+    }
+}
+
+impl ResolutionRiskModel {
+    /// Fit from tracked markets as of `now`.
+    pub fn fit(
+        observations: &[ResolutionObservation],
+        now: DateTime<Utc>,
+        config: &ResolutionRiskConfig,
+    ) -> Self {
+        let grace = config.grace();
+        let mut overall = Tally::default();
+        let mut by_category: HashMap<String, Tally> = HashMap::new();
+        for obs in observations {
+            overall.add(obs, now, grace);
+            if let Some(category) = obs.category.as_deref().filter(|c| !c.is_empty()) {
+                by_category
+                    .entry(category.to_lowercase())
+                    .or_default()
+                    .add(obs, now, grace);
+            }
+        }
+
+        let overall = overall.shrink(CategoryRisk::PRIOR, config.prior_strength);
+        let categories = by_category
+            .into_iter()
+            .map(|(category, tally)| (category, tally.shrink(overall, config.prior_strength)))
+            .collect();
+        Self {
+            overall,
+            categories,
+            fitted_at: Some(now),
+        }
+    }
+
+    /// Load the lookback window from `market_resolutions` and fit.
+    pub async fn load(
+        pool: &PgPool,
+        config: &ResolutionRiskConfig,
+        now: DateTime<Utc>,
+    ) -> Result<Self, sqlx::Error> {
+        let observations = sqlx::query_as::<_, ResolutionObservation>(
+            r#"
+            SELECT category, end_date, favourite_yes_price, resolved_at, yes_won, first_seen_at
+            FROM market_resolutions
+            WHERE end_date >= $1
+              AND (end_date <= $2 OR resolved_at IS NOT NULL)
+            "#,
+        )
+        .bind(now - Duration::days(config.lookback_days))
+        .bind(now)
+        .fetch_all(pool)
+        .await?;
+        Ok(Self::fit(&observations, now, config))
+    }
+
+    /// Whether the fit is older than one tracking interval.
+    pub fn is_stale(&self, now: DateTime<Utc>, config: &ResolutionRiskConfig) -> bool {
+        self.fitted_at
+            .is_none_or(|at| now - at >= Duration::seconds(config.interval_secs as i64))
+    }
+
+    /// Figures for `category`, or for all markets when it has no history.
+    pub fn category(&self, category: Option<&str>) -> CategoryRisk {
+        category
+            .and_then(|c| self.categories.get(&c.to_lowercase()))
+            .copied()
+            .unwrap_or(self.overall)
+    }
+
+    /// Risk of buying the favourite of a market at `yes_price` with
+    /// `days_remaining` to its end date.
+    pub fn estimate(
+        &self,
+        category: Option<&str>,
+        yes_price: f64,
+        days_remaining: f64,
+        config: &ResolutionRiskConfig,
+    ) -> ResolutionRisk {
+        let risk = self.category(category);
+        let implied_reversal = yes_price.min(1.0 - yes_price).clamp(0.0, 0.5);
+        let expected_lockup_days =
+            days_remaining.max(0.0) + risk.delay_probability * risk.mean_delay_days;
+        ResolutionRisk {
+            implied_reversal,
+            reversal_probability: (implied_reversal * risk.reversal_ratio).clamp(0.0, 1.0),
+            delay_probability: risk.delay_probability,
+            expected_lockup_days,
+            capital_lockup_cost: config.capital_cost_apr * expected_lockup_days / 365.0,
+        }
+    }
+}
+
+/// Spawn the resolution tracker background task.
+pub fn spawn_resolution_risk_tracker(
+    config: ResolutionRiskConfig,
+    pool: PgPool,
+    clob_client: Arc<ClobClient>,
+) {
+    if !config.enabled {
+        info!("Resolution risk tracker disabled (RESOLUTION_RISK_ENABLED != true)");
+        return;
+    }
+
+    info!(
+        interval_secs = config.interval_secs,
+        max_checks = config.max_checks,
+        "Spawning resolution risk tracker"
+    );
+
+    tokio::spawn(tracker_loop(config, pool, clob_client));
+}
+
+async fn tracker_loop(config: ResolutionRiskConfig, pool: PgPool, clob_client: Arc<ClobClient>) {
+    // Let the Gamma syncer populate market_metadata first.
+    tokio::time::sleep(time::Duration::from_secs(120)).await;
+    let gamma_client = GammaClient::new(None);
+
+    loop {
+        match track_cycle(&config, &pool, &clob_client, &gamma_client).await {
+            Ok(stats) => info!(
+                seeded = stats.seeded,
+                checked = stats.checked,
+                resolved = stats.resolved,
+                "Resolution tracking cycle completed"
+            ),
+            Err(e) => warn!(error = %e, "Resolution tracking cycle failed"),
+        }
+
+        tokio::time::sleep(time::Duration::from_secs(config.interval_secs)).await;
+    }
+}
+
+/// Stats from a single tracking cycle.
+struct TrackStats {
+    seeded: u64,
+    checked: usize,
+    resolved: usize,
+}
+
+/// Start watching markets near their end date, then check the ended,
+/// unresolved ones against the CLOB: never-checked first, newest end date
+/// first, then the least recently checked.
+async fn track_cycle(
+    config: &ResolutionRiskConfig,
+    pool: &PgPool,
+    clob_client: &ClobClient,
+    gamma_client: &GammaClient,
+) -> anyhow::Result<TrackStats> {
+    let now = Utc::now();
+    let since = now - Duration::days(config.lookback_days);
+
+    // The end date is kept as first seen so a reschedule counts as a delay.
+    let seeded = sqlx::query(
+        r#"
+        INSERT INTO market_resolutions (condition_id, category, end_date)
+        SELECT condition_id, category, end_date
+        FROM market_metadata
+        WHERE end_date >= $1
+          AND end_date <= $2
+        ON CONFLICT (condition_id) DO NOTHING
+        "#,
+    )
+    .bind(since)
+    .bind(now + Duration::days(WATCH_DAYS))
+    .execute(pool)
+    .await?
+    .rows_affected();
+
+    let pending = sqlx::query_scalar::<_, String>(
+        r#"
+        SELECT condition_id
+        FROM market_resolutions
+        WHERE resolved_at IS NULL
+          AND end_date >= $1
+          AND end_date <= $2
+        ORDER BY last_checked_at NULLS FIRST, end_date DESC
+        LIMIT $3
+        "#,
+    )
+    .bind(since)
+    .bind(now)
+    .bind(config.max_checks)
+    .fetch_all(pool)
+    .await?;
+
+    let mut resolved = 0;
+    for condition_id in &pending {
+        let market = match clob_client.get_market_by_id(condition_id).await {
+            Ok(market) => Some(market),
+            Err(e) => {
+                debug!(condition_id = %condition_id, error = %e, "Resolution check failed");
+                None
+            }
+        };
+        let resolution = match market.filter(|m| m.resolved) {
+            Some(market) => closed_at(gamma_client, condition_id)
+                .await
+                .map(|at| (resolved_yes_winner(&market), at)),
+            None => None,
+        };
+        match resolution {
+            Some((yes_won, closed_at)) => {
+                record_resolution(pool, condition_id, yes_won, closed_at.min(now), now).await?;
+                resolved += 1;
+            }
+            None => {
+                sqlx::query(
+                    "UPDATE market_resolutions SET last_checked_at = $2 WHERE condition_id = $1",
+                )
+                .bind(condition_id)
+                .bind(now)
+                .execute(pool)
+                .await?;
+            }
+        }
+    }
+
+    Ok(TrackStats {
+        seeded,
+        checked: pending.len(),
+        resolved,
+    })
+}
+
+/// When the venue closed a resolved market. Without it the market stays
+/// pending, since the time the tracker noticed would overstate the delay.
+async fn closed_at(gamma_client: &GammaClient, condition_id: &str) -> Option<DateTime<Utc>> {
+    match gamma_client.get_market(condition_id).await {
+        Ok(market) => {
+            let closed_at = market.closed_at();
+            if closed_at.is_none() {
+                debug!(condition_id = %condition_id, "Resolved market has no Gamma close time yet");
+            }
+            closed_at
+        }
+        Err(e) => {
+            debug!(condition_id = %condition_id, error = %e, "Gamma close time lookup failed");
+            None
+        }
+    }
+}
+
+/// Record a payout made at `resolved_at`, with the YES close a day before the
+/// end date (or before the payout, if that came first) as the favourite price.
+async fn record_resolution(
+    pool: &PgPool,
+    condition_id: &str,
+    yes_won: Option<bool>,
+    resolved_at: DateTime<Utc>,
+    now: DateTime<Utc>,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        r#"
+        UPDATE market_resolutions mr
+        SET resolved_at = $2,
+            last_checked_at = $4,
+            yes_won = $3,
+            favourite_yes_price = (
+                SELECT close
+                FROM orderbook_hourly
+                WHERE market_id = mr.condition_id
+                  AND bucket >= mr.end_date - INTERVAL '8 days'
+                  AND bucket <= LEAST(mr.end_date - INTERVAL '1 day', $2)
+                ORDER BY bucket DESC
+                LIMIT 1
+            )
+        WHERE condition_id = $1
+        "#,
+    )
+    .bind(condition_id)
+    .bind(resolved_at)
+    .bind(yes_won)
+    .bind(now)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn observation(
+        category: &str,
+        end_days_ago: i64,
+        resolved_days_after_end: Option<f64>,
+        favourite_yes_price: f64,
+        yes_won: bool,
+    ) -> ResolutionObservation {
+        let end_date = Utc::now() - Duration::days(end_days_ago);
+        ResolutionObservation {
+            category: Some(category.to_string()),
+            end_date,
+            favourite_yes_price: Some(favourite_yes_price),
+            resolved_at: resolved_days_after_end
+                .map(|d| end_date + Duration::minutes((d * 1440.0) as i64)),
+            yes_won: resolved_days_after_end.map(|_| yes_won),
+            first_seen_at: end_date - Duration::days(WATCH_DAYS),
+        }
+    }
+
+    #[test]
+    fn test_config_defaults() {
+        let config = ResolutionRiskConfig::default();
+        assert!(config.enabled);
+        assert_eq!(config.grace(), Duration::hours(24));
+        assert_eq!(config.lookback_days, 180);
+    }
+
+    #[test]
+    fn test_priors_without_history() {
+        let config = ResolutionRiskConfig::default();
+        let model = ResolutionRiskModel::default();
+        assert!(model.is_stale(Utc::now(), &config));
+
+        let risk = model.estimate(Some("Sports"), 0.80, 2.0, &config);
+        assert!((risk.implied_reversal - 0.20).abs() < 1e-9);
+        assert!((risk.reversal_probability - 0.20).abs() < 1e-9);
+        assert_eq!(risk.delay_probability, PRIOR_DELAY_PROBABILITY);
+        assert!((risk.expected_lockup_days - 2.15).abs() < 1e-9);
+        assert!((risk.capital_lockup_cost - 0.10 * 2.15 / 365.0).abs() < 1e-12);
+        // Fairly priced favourites only lose confidence to the delay risk.
+        assert!((risk.adjust_confidence(0.6, &config) - 0.6 * 0.975).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_unreliable_category_raises_reversal_and_delay() {
+        let config = ResolutionRiskConfig::default();
+        let mut observations = Vec::new();
+        // Politics: 80c favourites lose half the time and pay out five days late.
+        for i in 0..40 {
+            observations.push(observation("Politics", 30, Some(5.0), 0.8, i % 2 == 0));
+        }
+        // Sports: on time, favourites win.
+        for _ in 0..40 {
+            observations.push(observation("Sports", 30, Some(0.1), 0.8, true));
+        }
+        let model = ResolutionRiskModel::fit(&observations, Utc::now(), &config);
+        assert!(!model.is_stale(Utc::now(), &config));
+
+        let politics = model.estimate(Some("politics"), 0.8, 2.0, &config);
+        let sports = model.estimate(Some("Sports"), 0.8, 2.0, &config);
+        assert!(politics.reversal_probability > 0.3);
+        assert!(sports.reversal_probability < 0.1);
+        assert!(politics.delay_probability > 0.5);
+        assert!(sports.delay_probability < 0.2);
+        assert!(politics.expected_lockup_days > sports.expected_lockup_days + 2.0);
+        assert!(politics.capital_lockup_cost > sports.capital_lockup_cost);
+        assert!(politics.adjust_confidence(0.6, &config) < 0.6 - 0.1);
+
+        // Unknown categories get the all-market figures.
+        assert_eq!(model.category(Some("Weather")), model.category(None),);
+    }
+
+    #[test]
+    fn test_overdue_counts_as_delayed_and_late_seen_timing_is_ignored() {
+        let config = ResolutionRiskConfig::default();
+        let now = Utc::now();
+        let overdue = observation("Crypto", 3, None, 0.9, true);
+        let mut late_seen = observation("Crypto", 30, Some(10.0), 0.9, true);
+        late_seen.first_seen_at = late_seen.end_date + Duration::days(20);
+
+        let mut tally = Tally::default();
+        tally.add(&overdue, now, config.grace());
+        assert_eq!((tally.due, tally.delayed), (1.0, 1.0));
+        assert!((tally.delay_days - 3.0).abs() < 0.01);
+
+        let mut tally = Tally::default();
+        tally.add(&late_seen, now, config.grace());
+        assert_eq!((tally.due, tally.delayed, tally.decided), (0.0, 0.0, 1.0));
+    }
+}
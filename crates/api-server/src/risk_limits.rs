@@ -0,0 +1,657 @@
+//! Versioned per-workspace risk limits.
+//!
+//! A single document covers exposure caps, per-strategy allocations and entry
+//! price bands. Every change is stored as a new version in
+//! `workspace_risk_limits`; tightening takes effect immediately, loosening
+//! waits for a second owner/admin to approve it. Activated versions are
+//! broadcast on the dynamic config channel so running executors apply them
+//! without a restart.
+
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::{DateTime, Utc};
+use polymarket_core::types::signal::QuantSignalKind;
+use redis::AsyncCommands;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use sqlx::{FromRow, PgPool};
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use crate::arb_executor::ArbExecutorConfig;
+use crate::dynamic_tuner::{channels, DynamicConfigUpdate};
+use crate::quant_signal_executor::QuantSignalExecutorConfig;
+
+/// Dynamic config key carrying a full risk-limits document in `metrics`.
+pub const RISK_LIMITS_CONFIG_KEY: &str = "RISK_LIMITS";
+/// Dynamic config source used when broadcasting activated risk limits.
+pub const RISK_LIMITS_UPDATE_SOURCE: &str = "risk_limits";
+
+pub const STATUS_ACTIVE: &str = "active";
+pub const STATUS_PENDING_APPROVAL: &str = "pending_approval";
+pub const STATUS_SUPERSEDED: &str = "superseded";
+pub const STATUS_REJECTED: &str = "rejected";
+
+/// Caps on capital at risk. The arb and quant executors keep their own
+/// budgets, so each gets its own total and per-position cap.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct ExposureCaps {
+    /// Maximum total exposure across arb positions (USD).
+    pub arb_max_total_exposure: Decimal,
+    /// Maximum size of a single new arb position (USD).
+    pub arb_max_position_size: Decimal,
+    /// Maximum total exposure across quant positions (USD).
+    pub quant_max_total_exposure: Decimal,
+    /// Maximum size of a single new quant position (USD).
+    pub quant_max_position_size: Decimal,
+    /// Maximum simultaneous quant positions.
+    pub max_open_positions: u32,
+}
+
+impl ExposureCaps {
+    /// `(executor, max_total_exposure, max_position_size)` per executor.
+    fn budgets(&self) -> [(&'static str, Decimal, Decimal); 2] {
+        [
+            (
+                "arb",
+                self.arb_max_total_exposure,
+                self.arb_max_position_size,
+            ),
+            (
+                "quant",
+                self.quant_max_total_exposure,
+                self.quant_max_position_size,
+            ),
+        ]
+    }
+}
+
+/// Share of the quant position budget given to each signal kind.
+///
+/// Serialized as an object keyed by [`QuantSignalKind::as_str`]. Kinds missing
+/// from a document get no budget, so a newly added generator stays off until
+/// a version allocating it is approved.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(try_from = "HashMap<String, f64>", into = "BTreeMap<String, f64>")]
+#[schema(value_type = HashMap<String, f64>)]
+pub struct StrategyAllocations(HashMap<QuantSignalKind, f64>);
+
+impl StrategyAllocations {
+    pub fn get(&self, kind: QuantSignalKind) -> f64 {
+        self.0.get(&kind).copied().unwrap_or(0.0)
+    }
+
+    pub fn set(&mut self, kind: QuantSignalKind, allocation: f64) {
+        self.0.insert(kind, allocation);
+    }
+
+    /// Every kind with its allocation, in registration order.
+    pub fn entries(&self) -> impl Iterator<Item = (QuantSignalKind, f64)> + '_ {
+        QuantSignalKind::ALL
+            .into_iter()
+            .map(|kind| (kind, self.get(kind)))
+    }
+}
+
+impl TryFrom<HashMap<String, f64>> for StrategyAllocations {
+    type Error = String;
+
+    fn try_from(raw: HashMap<String, f64>) -> Result<Self, Self::Error> {
+        raw.into_iter()
+            .map(|(name, allocation)| {
+                QuantSignalKind::parse(&name)
+                    .map(|kind| (kind, allocation))
+                    .ok_or_else(|| format!("unknown strategy allocation `{name}`"))
+            })
+            .collect::<Result<_, _>>()
+            .map(Self)
+    }
+}
+
+impl From<StrategyAllocations> for BTreeMap<String, f64> {
+    fn from(allocations: StrategyAllocations) -> Self {
+        allocations
+            .entries()
+            .map(|(kind, allocation)| (kind.as_str().to_string(), allocation))
+            .collect()
+    }
+}
+
+/// Entry prices outside `[min_entry_price, max_entry_price]` are rejected.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct OrderBands {
+    pub min_entry_price: Decimal,
+    pub max_entry_price: Decimal,
+}
+
+/// The full risk-limits document for a workspace.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct RiskLimitsDocument {
+    pub exposure: ExposureCaps,
+    pub strategy_allocations: StrategyAllocations,
+    pub order_bands: OrderBands,
+}
+
+impl RiskLimitsDocument {
+    /// Build the document implied by the env-derived executor configs.
+    ///
+    /// Used as the baseline before a workspace has stored any version.
+    pub fn from_configs(arb: &ArbExecutorConfig, quant: &QuantSignalExecutorConfig) -> Self {
+        Self {
+            exposure: ExposureCaps {
+                arb_max_total_exposure: arb.max_total_exposure,
+                arb_max_position_size: arb.max_position_size,
+                quant_max_total_exposure: quant.max_total_exposure,
+                quant_max_position_size: quant.max_position_size_usd,
+                max_open_positions: quant.max_quant_positions as u32,
+            },
+            strategy_allocations: StrategyAllocations(
+                QuantSignalKind::ALL
+                    .into_iter()
+                    .map(|kind| (kind, quant.allocation_for(kind)))
+                    .collect(),
+            ),
+            order_bands: OrderBands {
+                min_entry_price: arb.min_entry_price.max(quant.min_entry_price),
+                max_entry_price: arb.max_entry_price.min(quant.max_entry_price),
+            },
+        }
+    }
+
+    /// Check the document is internally consistent.
+    ///
+    /// Returns every problem found, joined into one message.
+    pub fn validate(&self) -> Result<(), String> {
+        let mut errors = Vec::new();
+        let exposure = &self.exposure;
+        for (executor, total, position) in exposure.budgets() {
+            if total <= Decimal::ZERO {
+                errors.push(format!(
+                    "exposure.{executor}_max_total_exposure must be > 0"
+                ));
+            }
+            if position <= Decimal::ZERO {
+                errors.push(format!("exposure.{executor}_max_position_size must be > 0"));
+            }
+            if position > total {
+                errors.push(format!(
+                    "exposure.{executor}_max_position_size must not exceed \
+                     exposure.{executor}_max_total_exposure"
+                ));
+            }
+        }
+        if exposure.max_open_positions == 0 {
+            errors.push("exposure.max_open_positions must be >= 1".to_string());
+        }
+
+        let mut allocation_total = 0.0;
+        for (kind, value) in self.strategy_allocations.entries() {
+            if !value.is_finite() || !(0.0..=1.0).contains(&value) {
+                errors.push(format!(
+                    "strategy_allocations.{kind} must be between 0 and 1"
+                ));
+            } else {
+                allocation_total += value;
+            }
+        }
+        if allocation_total > 1.0 + 1e-9 {
+            errors.push(format!(
+                "strategy_allocations must sum to at most 1.0 (got {allocation_total:.4})"
+            ));
+        }
+
+        let bands = &self.order_bands;
+        if bands.min_entry_price < Decimal::ZERO || bands.max_entry_price > Decimal::ONE {
+            errors.push("order_bands prices must be between 0 and 1".to_string());
+        }
+        if bands.min_entry_price >= bands.max_entry_price {
+            errors.push(
+                "order_bands.min_entry_price must be below order_bands.max_entry_price".to_string(),
+            );
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors.join("; "))
+        }
+    }
+
+    /// Fields where `self` allows more risk than `current`.
+    pub fn loosened_fields(&self, current: &Self) -> Vec<String> {
+        let mut loosened = Vec::new();
+        for ((executor, total, position), (_, current_total, current_position)) in self
+            .exposure
+            .budgets()
+            .into_iter()
+            .zip(current.exposure.budgets())
+        {
+            if total > current_total {
+                loosened.push(format!("exposure.{executor}_max_total_exposure"));
+            }
+            if position > current_position {
+                loosened.push(format!("exposure.{executor}_max_position_size"));
+            }
+        }
+        if self.exposure.max_open_positions > current.exposure.max_open_positions {
+            loosened.push("exposure.max_open_positions".to_string());
+        }
+        for (kind, proposed) in self.strategy_allocations.entries() {
+            if proposed > current.strategy_allocations.get(kind) + 1e-9 {
+                loosened.push(format!("strategy_allocations.{kind}"));
+            }
+        }
+        if self.order_bands.min_entry_price < current.order_bands.min_entry_price {
+            loosened.push("order_bands.min_entry_price".to_string());
+        }
+        if self.order_bands.max_entry_price > current.order_bands.max_entry_price {
+            loosened.push("order_bands.max_entry_price".to_string());
+        }
+        loosened
+    }
+
+    /// Apply the limits to a running arb executor config.
+    pub fn apply_to_arb(&self, config: &mut ArbExecutorConfig) {
+        config.max_total_exposure = self.exposure.arb_max_total_exposure;
+        config.max_position_size = self.exposure.arb_max_position_size;
+        config.min_position_size = config.min_position_size.min(config.max_position_size);
+        config.min_entry_price = self.order_bands.min_entry_price;
+        config.max_entry_price = self.order_bands.max_entry_price;
+    }
+
+    /// Apply the limits to a running quant executor config.
+    pub fn apply_to_quant(&self, config: &mut QuantSignalExecutorConfig) {
+        config.max_total_exposure = self.exposure.quant_max_total_exposure;
+        config.max_position_size_usd = self.exposure.quant_max_position_size;
+        config.max_quant_positions = self.exposure.max_open_positions as usize;
+        config
+            .allocations
+            .extend(self.strategy_allocations.entries());
+        config.min_entry_price = self.order_bands.min_entry_price;
+        config.max_entry_price = self.order_bands.max_entry_price;
+    }
+}
+
+/// A stored version of a workspace's risk limits.
+#[derive(Debug, Clone, FromRow)]
+pub struct RiskLimitsVersionRow {
+    pub id: Uuid,
+    pub workspace_id: Uuid,
+    pub version: i32,
+    pub base_version: Option<i32>,
+    pub document: serde_json::Value,
+    pub status: String,
+    pub loosened_fields: Vec<String>,
+    pub reason: Option<String>,
+    pub proposed_by: Uuid,
+    pub reviewed_by: Option<Uuid>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl RiskLimitsVersionRow {
+    pub fn document(&self) -> anyhow::Result<RiskLimitsDocument> {
+        Ok(serde_json::from_value(self.document.clone())?)
+    }
+}
+
+const VERSION_COLUMNS: &str = "id, workspace_id, version, base_version, document, status, \
+     loosened_fields, reason, proposed_by, reviewed_by, reviewed_at, created_at";
+
+pub async fn load_active_version(
+    pool: &PgPool,
+    workspace_id: Uuid,
+) -> Result<Option<RiskLimitsVersionRow>, sqlx::Error> {
+    sqlx::query_as(&format!(
+        "SELECT {VERSION_COLUMNS} FROM workspace_risk_limits \
+         WHERE workspace_id = $1 AND status = 'active'"
+    ))
+    .bind(workspace_id)
+    .fetch_optional(pool)
+    .await
+}
+
+pub async fn load_version(
+    pool: &PgPool,
+    workspace_id: Uuid,
+    version: i32,
+) -> Result<Option<RiskLimitsVersionRow>, sqlx::Error> {
+    sqlx::query_as(&format!(
+        "SELECT {VERSION_COLUMNS} FROM workspace_risk_limits \
+         WHERE workspace_id = $1 AND version = $2"
+    ))
+    .bind(workspace_id)
+    .bind(version)
+    .fetch_optional(pool)
+    .await
+}
+
+pub async fn load_history(
+    pool: &PgPool,
+    workspace_id: Uuid,
+    limit: i64,
+) -> Result<Vec<RiskLimitsVersionRow>, sqlx::Error> {
+    sqlx::query_as(&format!(
+        "SELECT {VERSION_COLUMNS} FROM workspace_risk_limits \
+         WHERE workspace_id = $1 ORDER BY version DESC LIMIT $2"
+    ))
+    .bind(workspace_id)
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+}
+
+/// A version about to be stored.
+#[derive(Debug)]
+pub struct NewRiskLimitsVersion<'a> {
+    pub workspace_id: Uuid,
+    /// Active version the change was compared against (`None` = env defaults).
+    pub base_version: Option<i32>,
+    pub document: &'a RiskLimitsDocument,
+    pub loosened_fields: &'a [String],
+    pub reason: Option<&'a str>,
+    pub proposed_by: Uuid,
+    /// Activate immediately instead of waiting for approval.
+    pub activate: bool,
+}
+
+/// Store a new version, activating it immediately when `activate` is set.
+///
+/// The workspace row is locked so concurrent writers get distinct versions.
+pub async fn insert_version(
+    pool: &PgPool,
+    new: NewRiskLimitsVersion<'_>,
+) -> anyhow::Result<RiskLimitsVersionRow> {
+    let NewRiskLimitsVersion {
+        workspace_id,
+        base_version,
+        document,
+        loosened_fields,
+        reason,
+        proposed_by,
+        activate,
+    } = new;
+    let mut tx = pool.begin().await?;
+    sqlx::query("SELECT id FROM workspaces WHERE id = $1 FOR UPDATE")
+        .bind(workspace_id)
+        .execute(&mut *tx)
+        .await?;
+
+    let next_version: i32 = sqlx::query_scalar(
+        "SELECT COALESCE(MAX(version), 0) + 1 FROM workspace_risk_limits WHERE workspace_id = $1",
+    )
+    .bind(workspace_id)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    if activate {
+        supersede_active(&mut tx, workspace_id).await?;
+    }
+
+    let status = if activate {
+        STATUS_ACTIVE
+    } else {
+        STATUS_PENDING_APPROVAL
+    };
+    let row: RiskLimitsVersionRow = sqlx::query_as(&format!(
+        r#"
+        INSERT INTO workspace_risk_limits
+            (id, workspace_id, version, base_version, document, status,
+             loosened_fields, reason, proposed_by, reviewed_by, reviewed_at)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9,
+                CASE WHEN $10 THEN $9 ELSE NULL END,
+                CASE WHEN $10 THEN NOW() ELSE NULL END)
+        RETURNING {VERSION_COLUMNS}
+        "#
+    ))
+    .bind(Uuid::new_v4())
+    .bind(workspace_id)
+    .bind(next_version)
+    .bind(base_version)
+    .bind(serde_json::to_value(document)?)
+    .bind(status)
+    .bind(loosened_fields)
+    .bind(reason)
+    .bind(proposed_by)
+    .bind(activate)
+    .fetch_one(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+    Ok(row)
+}
+
+/// Resolve a pending version as approved (activating it) or rejected.
+///
+/// Returns `None` when the version is no longer pending.
+pub async fn review_version(
+    pool: &PgPool,
+    workspace_id: Uuid,
+    version: i32,
+    reviewer: Uuid,
+    approve: bool,
+) -> anyhow::Result<Option<RiskLimitsVersionRow>> {
+    let mut tx = pool.begin().await?;
+    sqlx::query("SELECT id FROM workspaces WHERE id = $1 FOR UPDATE")
+        .bind(workspace_id)
+        .execute(&mut *tx)
+        .await?;
+
+    if approve {
+        supersede_active(&mut tx, workspace_id).await?;
+    }
+
+    let row: Option<RiskLimitsVersionRow> = sqlx::query_as(&format!(
+        r#"
+        UPDATE workspace_risk_limits
+        SET status = $4, reviewed_by = $5, reviewed_at = NOW()
+        WHERE workspace_id = $1 AND version = $2 AND status = $3
+        RETURNING {VERSION_COLUMNS}
+        "#
+    ))
+    .bind(workspace_id)
+    .bind(version)
+    .bind(STATUS_PENDING_APPROVAL)
+    .bind(if approve {
+        STATUS_ACTIVE
+    } else {
+        STATUS_REJECTED
+    })
+    .bind(reviewer)
+    .fetch_optional(&mut *tx)
+    .await?;
+
+    if row.is_some() {
+        tx.commit().await?;
+    } else {
+        tx.rollback().await?;
+    }
+    Ok(row)
+}
+
+async fn supersede_active(
+    tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+    workspace_id: Uuid,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "UPDATE workspace_risk_limits SET status = $2 WHERE workspace_id = $1 AND status = $3",
+    )
+    .bind(workspace_id)
+    .bind(STATUS_SUPERSEDED)
+    .bind(STATUS_ACTIVE)
+    .execute(&mut **tx)
+    .await?;
+    Ok(())
+}
+
+/// Load the active document for the canonical workspace, if one is stored.
+pub async fn load_canonical_active_document(
+    pool: &PgPool,
+) -> anyhow::Result<Option<(i32, RiskLimitsDocument)>> {
+    let Some(workspace_id) = crate::workspace_scope::resolve_canonical_workspace_id(pool).await?
+    else {
+        return Ok(None);
+    };
+    match load_active_version(pool, workspace_id).await? {
+        Some(row) => Ok(Some((row.version, row.document()?))),
+        None => Ok(None),
+    }
+}
+
+/// Broadcast an activated version on the dynamic config channel.
+pub async fn publish_risk_limits(
+    redis: &mut redis::aio::ConnectionManager,
+    workspace_id: Uuid,
+    version: i32,
+    document: &RiskLimitsDocument,
+) -> anyhow::Result<()> {
+    let payload = DynamicConfigUpdate {
+        key: RISK_LIMITS_CONFIG_KEY.to_string(),
+        value: Decimal::from(version),
+        reason: format!("risk limits v{version} activated"),
+        source: RISK_LIMITS_UPDATE_SOURCE.to_string(),
+        timestamp: Utc::now(),
+        metrics: serde_json::json!({
+            "workspace_id": workspace_id,
+            "version": version,
+            "document": document,
+        }),
+    };
+    let _: () = redis
+        .publish(channels::CONFIG_UPDATES, serde_json::to_string(&payload)?)
+        .await?;
+    Ok(())
+}
+
+/// Extract the document from a risk-limits dynamic config update.
+pub fn document_from_update(update: &DynamicConfigUpdate) -> anyhow::Result<RiskLimitsDocument> {
+    let document = update
+        .metrics
+        .get("document")
+        .cloned()
+        .ok_or_else(|| anyhow::anyhow!("risk limits update has no document"))?;
+    let document: RiskLimitsDocument = serde_json::from_value(document)?;
+    document.validate().map_err(|e| anyhow::anyhow!(e))?;
+    Ok(document)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn document() -> RiskLimitsDocument {
+        RiskLimitsDocument {
+            exposure: ExposureCaps {
+                arb_max_total_exposure: Decimal::new(5000, 0),
+                arb_max_position_size: Decimal::new(100, 0),
+                quant_max_total_exposure: Decimal::new(2000, 0),
+                quant_max_position_size: Decimal::new(50, 0),
+                max_open_positions: 10,
+            },
+            strategy_allocations: StrategyAllocations(HashMap::from([
+                (QuantSignalKind::Flow, 0.4),
+                (QuantSignalKind::CrossMarket, 0.2),
+                (QuantSignalKind::MeanReversion, 0.2),
+                (QuantSignalKind::ResolutionProximity, 0.1),
+                (QuantSignalKind::EventReaction, 0.1),
+            ])),
+            order_bands: OrderBands {
+                min_entry_price: Decimal::new(5, 2),
+                max_entry_price: Decimal::new(95, 2),
+            },
+        }
+    }
+
+    #[test]
+    fn test_validate_rejects_inconsistent_documents() {
+        assert!(document().validate().is_ok());
+
+        let mut doc = document();
+        doc.exposure.quant_max_position_size = Decimal::new(3000, 0);
+        doc.strategy_allocations.set(QuantSignalKind::Flow, 0.9);
+        doc.order_bands.min_entry_price = Decimal::new(96, 2);
+        let err = doc.validate().unwrap_err();
+        assert!(err.contains("quant_max_position_size"));
+        assert!(!err.contains("arb_max_position_size"));
+        assert!(err.contains("sum to at most 1.0"));
+        assert!(err.contains("min_entry_price"));
+    }
+
+    #[test]
+    fn test_loosened_fields_only_reports_riskier_changes() {
+        let current = document();
+        let mut tighter = document();
+        tighter.exposure.arb_max_total_exposure = Decimal::new(4000, 0);
+        tighter.order_bands.min_entry_price = Decimal::new(10, 2);
+        assert!(tighter.loosened_fields(&current).is_empty());
+
+        let mut looser = document();
+        looser.exposure.max_open_positions = 12;
+        looser.exposure.quant_max_total_exposure = Decimal::new(3000, 0);
+        looser.strategy_allocations.set(QuantSignalKind::Flow, 0.3);
+        looser
+            .strategy_allocations
+            .set(QuantSignalKind::EventReaction, 0.2);
+        looser.order_bands.max_entry_price = Decimal::new(99, 2);
+        assert_eq!(
+            looser.loosened_fields(&current),
+            vec![
+                "exposure.quant_max_total_exposure",
+                "exposure.max_open_positions",
+                "strategy_allocations.event_reaction",
+                "order_bands.max_entry_price",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_document_round_trips_through_config_update() {
+        let doc = document();
+        let update = DynamicConfigUpdate {
+            key: RISK_LIMITS_CONFIG_KEY.to_string(),
+            value: Decimal::from(3),
+            reason: String::new(),
+            source: RISK_LIMITS_UPDATE_SOURCE.to_string(),
+            timestamp: Utc::now(),
+            metrics: serde_json::json!({ "document": doc }),
+        };
+        assert_eq!(document_from_update(&update).unwrap(), doc);
+
+        let mut quant = QuantSignalExecutorConfig::from_env(
+            &crate::signals::SignalGeneratorRegistry::from_env(),
+        );
+        doc.apply_to_quant(&mut quant);
+        assert_eq!(quant.max_quant_positions, 10);
+        assert_eq!(quant.max_position_size_usd, Decimal::new(50, 0));
+        assert_eq!(quant.max_total_exposure, Decimal::new(2000, 0));
+
+        // Each executor keeps its own budget.
+        let mut arb = ArbExecutorConfig::from_env();
+        doc.apply_to_arb(&mut arb);
+        assert_eq!(arb.max_total_exposure, Decimal::new(5000, 0));
+        assert_eq!(arb.max_position_size, Decimal::new(100, 0));
+        assert_eq!(quant.min_entry_price, Decimal::new(5, 2));
+        assert_eq!(quant.allocation_for(QuantSignalKind::EventReaction), 0.1);
+    }
+
+    #[test]
+    fn test_allocations_cover_every_signal_kind() {
+        let mut doc = document();
+        doc.strategy_allocations
+            .set(QuantSignalKind::EventReaction, 0.5);
+        let err = doc.validate().unwrap_err();
+        assert!(err.contains("sum to at most 1.0"));
+
+        let json = serde_json::to_value(&doc.strategy_allocations).unwrap();
+        assert_eq!(json["event_reaction"], 0.5);
+
+        let partial: StrategyAllocations =
+            serde_json::from_value(serde_json::json!({ "flow": 0.4 })).unwrap();
+        assert_eq!(partial.get(QuantSignalKind::EventReaction), 0.0);
+
+        assert!(serde_json::from_value::<StrategyAllocations>(
+            serde_json::json!({ "resolution": 0.1 })
+        )
+        .is_err());
+    }
+}
@@ -11,8 +11,8 @@ use utoipa::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
 
 use crate::handlers::{
-    accounting, activity, admin_workspaces, auth, backtest, discover, health, markets,
-    order_signing, positions, recommendations, recovery, risk, signals, strategy_health,
+    accounting, activity, admin_workspaces, auth, backtest, discover, events, health, markets,
+    metrics, order_signing, positions, recommendations, recovery, risk, signals, strategy_health,
     trade_flow, trading, users, vault, wallet_auth, wallets, workspaces,
 };
 use crate::middleware::{require_admin, require_auth, require_trader};
@@ -30,6 +30,7 @@ use crate::websocket;
     paths(
         health::health_check,
         health::readiness,
+        metrics::prometheus_metrics,
         auth::register,
         auth::login,
         auth::refresh_token,
@@ -139,6 +140,13 @@ use crate::websocket;
         risk::manual_trip_circuit_breaker,
         risk::reset_circuit_breaker,
         risk::update_circuit_breaker_config,
+        risk::get_risk_limits,
+        risk::get_risk_limits_history,
+        risk::update_risk_limits,
+        risk::approve_risk_limits,
+        risk::reject_risk_limits,
+        risk::list_pending_alerts,
+        risk::acknowledge_alert,
         // Arb executor config
         workspaces::update_arb_executor_config,
         // Signals (quant signal system)
@@ -146,7 +154,12 @@ use crate::websocket;
         signals::get_market_metadata,
         signals::get_recent_signals,
         signals::get_strategy_performance,
+        signals::list_signal_generators,
+        signals::update_signal_generator,
         strategy_health::get_strategy_health,
+        // External events
+        events::receive_event_webhook,
+        events::list_events,
     ),
     components(
         schemas(
@@ -289,6 +302,15 @@ use crate::websocket;
             risk::StopLossStatsResponse,
             risk::RecentStopExecution,
             risk::UpdateCircuitBreakerConfigRequest,
+            risk::RiskLimitsResponse,
+            risk::RiskLimitsVersionResponse,
+            risk::UpdateRiskLimitsRequest,
+            risk::PendingAlertsResponse,
+            risk::AcknowledgeAlertRequest,
+            crate::risk_limits::RiskLimitsDocument,
+            crate::risk_limits::ExposureCaps,
+            crate::risk_limits::StrategyAllocations,
+            crate::risk_limits::OrderBands,
             workspaces::UpdateArbExecutorConfigRequest,
             workspaces::ArbExecutorConfigResponse,
             // Signals
@@ -296,8 +318,16 @@ use crate::websocket;
             signals::MarketMetadataResponse,
             signals::RecentSignalResponse,
             signals::StrategyPerformanceResponse,
+            signals::UpdateSignalGeneratorRequest,
+            crate::signals::GeneratorHealth,
             strategy_health::StrategyHealthResponse,
             strategy_health::StrategyHealthItemResponse,
+            // External events
+            crate::events::EventPayload,
+            crate::events::EventImpact,
+            crate::events::EventMatch,
+            crate::events::IngestedEvent,
+            events::EventWebhookResponse,
         )
     ),
     tags(
@@ -319,6 +349,7 @@ use crate::websocket;
         (name = "trade_flow", description = "Derived trade lifecycle and conversion analytics"),
         (name = "risk", description = "Risk monitoring and circuit breaker management"),
         (name = "signals", description = "Quant signal system: flow features, performance, and recent signals"),
+        (name = "events", description = "External news and event ingestion"),
         (name = "websocket", description = "Real-time WebSocket endpoints"),
     )
 )]
@@ -374,6 +405,7 @@ pub fn create_router(state: Arc<AppState>) -> Router {
     let public_routes = Router::new()
         .route("/health", get(health::health_check))
         .route("/ready", get(health::readiness))
+        .route("/metrics", get(metrics::prometheus_metrics))
         // Discovery/demo endpoints (public for demo purposes)
         .route("/api/v1/discover/trades", get(discover::get_live_trades))
         .route("/api/v1/discover/wallets", get(discover::discover_wallets))
@@ -400,6 +432,11 @@ pub fn create_router(state: Arc<AppState>) -> Router {
             "/api/v1/invites/:token/accept",
             post(workspaces::accept_invite),
         )
+        // External event webhook (authenticated by shared secret)
+        .route(
+            "/api/v1/events/webhook",
+            post(events::receive_event_webhook),
+        )
         // WebSocket endpoints (auth handled via query param or message)
         .route("/ws/orderbook", get(websocket::ws_orderbook_handler))
         .route("/ws/positions", get(websocket::ws_positions_handler))
@@ -521,6 +558,11 @@ pub fn create_router(state: Arc<AppState>) -> Router {
             "/api/v1/signals/health",
             get(strategy_health::get_strategy_health),
         )
+        .route(
+            "/api/v1/signals/generators",
+            get(signals::list_signal_generators),
+        )
+        .route("/api/v1/events", get(events::list_events))
         // Activity feed (read-only for all members)
         .route("/api/v1/activity", get(activity::list_activity))
         .route(
@@ -569,6 +611,14 @@ pub fn create_router(state: Arc<AppState>) -> Router {
             "/api/v1/workspaces/:workspace_id/risk/status",
             get(risk::get_risk_status),
         )
+        .route(
+            "/api/v1/workspaces/:workspace_id/risk/limits",
+            get(risk::get_risk_limits),
+        )
+        .route(
+            "/api/v1/workspaces/:workspace_id/risk/limits/history",
+            get(risk::get_risk_limits_history),
+        )
         // Apply auth middleware
         .layer(axum_middleware::from_fn_with_state(
             state.clone(),
@@ -639,6 +689,15 @@ pub fn create_router(state: Arc<AppState>) -> Router {
             "/api/v1/workspaces/:workspace_id/risk/circuit-breaker/reset",
             post(risk::reset_circuit_breaker),
         )
+        // Alert acknowledgement
+        .route(
+            "/api/v1/workspaces/:workspace_id/risk/alerts/pending",
+            get(risk::list_pending_alerts),
+        )
+        .route(
+            "/api/v1/workspaces/:workspace_id/risk/alerts/ack",
+            post(risk::acknowledge_alert),
+        )
         // Apply trader check first, then auth
         .layer(axum_middleware::from_fn_with_state(
             state.clone(),
@@ -746,10 +805,26 @@ pub fn create_router(state: Arc<AppState>) -> Router {
             "/api/v1/workspaces/:workspace_id/risk/circuit-breaker/config",
             put(risk::update_circuit_breaker_config),
         )
+        .route(
+            "/api/v1/workspaces/:workspace_id/risk/limits",
+            put(risk::update_risk_limits),
+        )
+        .route(
+            "/api/v1/workspaces/:workspace_id/risk/limits/:version/approve",
+            post(risk::approve_risk_limits),
+        )
+        .route(
+            "/api/v1/workspaces/:workspace_id/risk/limits/:version/reject",
+            post(risk::reject_risk_limits),
+        )
         .route(
             "/api/v1/workspaces/:workspace_id/dynamic-tuning/arb-executor",
             put(workspaces::update_arb_executor_config),
         )
+        .route(
+            "/api/v1/signals/generators/:name",
+            put(signals::update_signal_generator),
+        )
         .route(
             "/api/v1/workspaces/:workspace_id/recovery/preview",
             post(recovery::preview_recovery),
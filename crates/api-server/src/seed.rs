@@ -92,8 +92,8 @@ pub async fn seed_admin(pool: &PgPool, email: &str, password: &str) -> anyhow::R
 ///
 /// Reads SEED_ADMIN_EMAIL and SEED_ADMIN_PASSWORD from environment.
 pub async fn seed_admin_from_env(pool: &PgPool) -> anyhow::Result<()> {
-    let email = std::env::var("SEED_ADMIN_EMAIL").ok();
-    let password = std::env::var("SEED_ADMIN_PASSWORD").ok();
+    let email = polymarket_core::settings::get_opt::<String>("SEED_ADMIN_EMAIL");
+    let password = polymarket_core::settings::get_opt::<String>("SEED_ADMIN_PASSWORD");
 
     match (email, password) {
         (Some(email), Some(password)) => {
@@ -12,14 +12,19 @@
 //! Direction: towards convergence with the lead market
 //! Confidence: based on correlation strength × divergence magnitude
 //! Expiry: 60 minutes
+//! Exit: the lag market closes half the divergence gap
 
-use chrono::{Duration, Utc};
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
 use polymarket_core::types::signal::{QuantSignal, QuantSignalKind, SignalDirection};
 use rust_decimal::Decimal;
 use sqlx::PgPool;
 use std::time;
-use tokio::sync::broadcast;
-use tracing::{debug, info, warn};
+use tracing::debug;
+
+use super::generator::{
+    json_decimal, ExitContext, GeneratorContext, GeneratorError, QuantSignalGenerator,
+};
 
 /// Configuration for the cross-market signal generator.
 #[derive(Debug, Clone)]
@@ -38,32 +43,21 @@ pub struct CrossMarketSignalConfig {
     pub base_position_size_usd: Decimal,
     /// Signal expiry in minutes.
     pub expiry_minutes: i64,
+    /// Share of quant capital allocated to cross-market signals.
+    pub allocation_pct: f64,
 }
 
 impl CrossMarketSignalConfig {
     pub fn from_env() -> Self {
         Self {
-            enabled: std::env::var("CROSS_MARKET_SIGNAL_ENABLED")
-                .map(|v| v == "true")
-                .unwrap_or(false),
-            interval_secs: std::env::var("CROSS_MARKET_INTERVAL_SECS")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(900),
-            min_correlation: std::env::var("CROSS_MARKET_MIN_CORRELATION")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(0.70),
-            min_lead_move: std::env::var("CROSS_MARKET_MIN_LEAD_MOVE")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(0.05),
-            max_lag_move: std::env::var("CROSS_MARKET_MAX_LAG_MOVE")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(0.02),
+            enabled: polymarket_core::settings::get("CROSS_MARKET_SIGNAL_ENABLED"),
+            interval_secs: polymarket_core::settings::get("CROSS_MARKET_INTERVAL_SECS"),
+            min_correlation: polymarket_core::settings::get("CROSS_MARKET_MIN_CORRELATION"),
+            min_lead_move: polymarket_core::settings::get("CROSS_MARKET_MIN_LEAD_MOVE"),
+            max_lag_move: polymarket_core::settings::get("CROSS_MARKET_MAX_LAG_MOVE"),
             base_position_size_usd: Decimal::new(30, 0),
             expiry_minutes: 60,
+            allocation_pct: polymarket_core::settings::get("QUANT_CROSS_MARKET_ALLOCATION_PCT"),
         }
     }
 }
@@ -85,66 +79,96 @@ struct DivergenceRow {
     lag_current_price: f64,
 }
 
-/// Spawn the cross-market signal generator.
-///
-/// Polls `market_correlations` + `orderbook_hourly` on a configurable interval
-/// to detect correlated pairs where one market diverged from the other.
-pub fn spawn_cross_market_signal_generator(
+/// Settings keys read by [`CrossMarketSignalConfig::from_env`].
+const CONFIG_KEYS: &[&str] = &[
+    "CROSS_MARKET_INTERVAL_SECS",
+    "CROSS_MARKET_MAX_LAG_MOVE",
+    "CROSS_MARKET_MIN_CORRELATION",
+    "CROSS_MARKET_MIN_LEAD_MOVE",
+    "CROSS_MARKET_SIGNAL_ENABLED",
+    "QUANT_CROSS_MARKET_ALLOCATION_PCT",
+];
+
+/// Cross-market divergence strategy: polls `market_correlations` +
+/// `orderbook_hourly` for correlated pairs where one market diverged from
+/// the other.
+pub struct CrossMarketSignalGenerator {
     config: CrossMarketSignalConfig,
-    pool: PgPool,
-    signal_tx: broadcast::Sender<QuantSignal>,
-) {
-    if !config.enabled {
-        info!("Cross-market signal generator disabled (CROSS_MARKET_SIGNAL_ENABLED != true)");
-        return;
+}
+
+impl CrossMarketSignalGenerator {
+    pub fn new(config: CrossMarketSignalConfig) -> Self {
+        Self { config }
+    }
+}
+
+#[async_trait]
+impl QuantSignalGenerator for CrossMarketSignalGenerator {
+    fn kind(&self) -> QuantSignalKind {
+        QuantSignalKind::CrossMarket
+    }
+
+    fn config_keys(&self) -> &'static [&'static str] {
+        CONFIG_KEYS
+    }
+
+    fn enabled(&self) -> bool {
+        self.config.enabled
+    }
+
+    fn poll_interval(&self) -> time::Duration {
+        time::Duration::from_secs(self.config.interval_secs)
+    }
+
+    fn allocation(&self) -> f64 {
+        self.config.allocation_pct
     }
 
-    info!(
-        interval_secs = config.interval_secs,
-        min_correlation = config.min_correlation,
-        min_lead_move = config.min_lead_move,
-        max_lag_move = config.max_lag_move,
-        "Spawning cross-market signal generator"
-    );
-
-    tokio::spawn(async move {
-        // Startup delay — cross-market detection queries orderbook_hourly aggressively;
-        // give the pool and continuous aggregates time to settle before first run.
-        tokio::time::sleep(time::Duration::from_secs(60)).await;
-
-        let mut interval = tokio::time::interval(time::Duration::from_secs(config.interval_secs));
-        interval.tick().await; // consume the first immediate tick
-
-        loop {
-            interval.tick().await;
-
-            match generate_signals(&config, &pool).await {
-                Ok(signals) => {
-                    let count = signals.len();
-                    for signal in signals {
-                        if let Err(e) = signal_tx.send(signal) {
-                            debug!(error = %e, "No quant executor subscribers for cross-market signal");
-                        }
-                    }
-                    if count > 0 {
-                        info!(count, "Cross-market divergence signals generated");
-                    }
-                }
-                Err(e) => {
-                    warn!(error = %e, "Cross-market signal generation failed");
-                }
-            }
+    async fn generate(&self, ctx: &GeneratorContext) -> Result<Vec<QuantSignal>, GeneratorError> {
+        Ok(generate_signals(&self.config, &ctx.pool, ctx.now).await?)
+    }
+
+    async fn should_exit(
+        &self,
+        _pool: &PgPool,
+        position: &ExitContext<'_>,
+    ) -> anyhow::Result<bool> {
+        Ok(divergence_closed(position))
+    }
+}
+
+/// Whether the lag market has moved half the lead/lag gap in the expected direction.
+fn divergence_closed(position: &ExitContext<'_>) -> bool {
+    let Some(lag_current_price) = json_decimal(position.metadata, "lag_current_price") else {
+        return false;
+    };
+    let change = |key: &str| {
+        position
+            .metadata
+            .get(key)
+            .and_then(|value| value.as_f64())
+            .unwrap_or(0.0)
+            .abs()
+    };
+    let divergence_gap = ((change("lead_change") - change("lag_change")) / 2.0).max(0.0);
+    let Ok(divergence_gap) = Decimal::try_from(divergence_gap) else {
+        return false;
+    };
+
+    match position.direction {
+        SignalDirection::BuyYes => position.current_yes >= lag_current_price + divergence_gap,
+        SignalDirection::BuyNo => {
+            position.current_yes <= (lag_current_price - divergence_gap).max(Decimal::ZERO)
         }
-    });
+    }
 }
 
 /// Query for divergent correlated pairs and produce signals.
 async fn generate_signals(
     config: &CrossMarketSignalConfig,
     pool: &PgPool,
+    now: DateTime<Utc>,
 ) -> anyhow::Result<Vec<QuantSignal>> {
-    let now = Utc::now();
-
     // Find correlated pairs where one market moved significantly in the last
     // 4 hours and the other didn't follow.
     //
@@ -333,6 +357,25 @@ mod tests {
         assert!(expected_neg < 0.0); // BuyNo
     }
 
+    #[test]
+    fn test_exit_requires_divergence_compression() {
+        let metadata = serde_json::json!({
+            "lag_current_price": "0.40",
+            "lead_change": 0.20,
+            "lag_change": 0.04
+        });
+        let position = |current_yes: i64| ExitContext {
+            market_id: "0x1234",
+            direction: SignalDirection::BuyYes,
+            metadata: &metadata,
+            current_yes: Decimal::new(current_yes, 2),
+            current_no: Decimal::new(100 - current_yes, 2),
+        };
+
+        assert!(divergence_closed(&position(48)));
+        assert!(!divergence_closed(&position(45)));
+    }
+
     #[test]
     fn test_confidence_calculation() {
         let corr_factor: f64 = 0.85;
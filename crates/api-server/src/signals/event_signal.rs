@@ -0,0 +1,424 @@
+//! Event reaction signal generator.
+//!
+//! Polls the event-impact feature (`external_events` linked to markets, see
+//! [`crate::events`]) every minute for strongly matched events the linked
+//! market has not yet priced.
+//!
+//! Trigger conditions:
+//!   - The sender supplied the event's sentiment (a lexicon score says whether
+//!     something happened, not whether that is YES for the market's question)
+//!   - Event published within the last hour and linked at least 2 minutes ago
+//!   - |match score × sentiment|, decayed by the impact half-life, ≥ 0.35
+//!   - YES has moved no more than 2¢ either way since the link
+//!
+//! Direction: the way the event points (positive impact → BuyYes)
+//! Confidence: 0.5 + |impact| × 0.45, capped at 0.95
+//! Expiry: 15 minutes (the edge is gone once the market catches up)
+//! Exit: YES has moved the target distance from the link price, either way
+//!
+//! Streamed markets are checked against the last poll's pending events on
+//! every book change, so a reaction that starts between polls is seen at once.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
+use polymarket_core::types::signal::{QuantSignal, QuantSignalKind, SignalDirection};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use std::collections::HashMap;
+use std::sync::RwLock;
+use std::time;
+use tracing::debug;
+
+use super::features::MarketFeatures;
+use super::generator::{
+    json_decimal, ExitContext, GeneratorContext, GeneratorError, QuantSignalGenerator,
+};
+use crate::events::{event_impact, reaction, recent_impacts, EventImpact};
+
+/// Configuration for the event reaction signal generator.
+#[derive(Debug, Clone)]
+pub struct EventReactionSignalConfig {
+    /// Whether the generator is enabled.
+    pub enabled: bool,
+    /// Polling interval in seconds.
+    pub interval_secs: u64,
+    /// Half-life of an event's impact in minutes.
+    pub half_life_mins: i64,
+    /// Minimum absolute decayed impact to trade.
+    pub min_impact: f64,
+    /// Time after the link before an unmoved price counts as a non-reaction.
+    pub min_lag_secs: i64,
+    /// Events older than this are assumed priced or irrelevant.
+    pub max_age_minutes: i64,
+    /// Largest YES move since the link that still counts as not yet priced.
+    pub max_reaction: f64,
+    /// YES move from the link price at which the position exits.
+    pub target_move: f64,
+    /// Base position size for suggested_size_usd.
+    pub base_position_size_usd: Decimal,
+    /// Signal expiry in minutes.
+    pub expiry_minutes: i64,
+    /// Share of quant capital allocated to event reaction signals.
+    pub allocation_pct: f64,
+}
+
+impl EventReactionSignalConfig {
+    pub fn from_env() -> Self {
+        Self {
+            enabled: polymarket_core::settings::get("EVENT_SIGNAL_ENABLED"),
+            interval_secs: polymarket_core::settings::get("EVENT_SIGNAL_INTERVAL_SECS"),
+            half_life_mins: polymarket_core::settings::get("EVENT_IMPACT_HALF_LIFE_MINS"),
+            min_impact: polymarket_core::settings::get("EVENT_SIGNAL_MIN_IMPACT"),
+            min_lag_secs: polymarket_core::settings::get("EVENT_SIGNAL_MIN_LAG_SECS"),
+            max_age_minutes: polymarket_core::settings::get("EVENT_SIGNAL_MAX_AGE_MINUTES"),
+            max_reaction: polymarket_core::settings::get("EVENT_SIGNAL_MAX_REACTION"),
+            target_move: polymarket_core::settings::get("EVENT_SIGNAL_TARGET_MOVE"),
+            base_position_size_usd: polymarket_core::settings::get("QUANT_BASE_POSITION_SIZE"),
+            expiry_minutes: 15,
+            allocation_pct: polymarket_core::settings::get("QUANT_EVENT_ALLOCATION_PCT"),
+        }
+    }
+
+    fn half_life(&self) -> Duration {
+        Duration::minutes(self.half_life_mins)
+    }
+}
+
+/// Settings keys read by [`EventReactionSignalConfig::from_env`].
+const CONFIG_KEYS: &[&str] = &[
+    "EVENT_IMPACT_HALF_LIFE_MINS",
+    "EVENT_SIGNAL_ENABLED",
+    "EVENT_SIGNAL_INTERVAL_SECS",
+    "EVENT_SIGNAL_MAX_AGE_MINUTES",
+    "EVENT_SIGNAL_MAX_REACTION",
+    "EVENT_SIGNAL_MIN_IMPACT",
+    "EVENT_SIGNAL_MIN_LAG_SECS",
+    "EVENT_SIGNAL_TARGET_MOVE",
+    "QUANT_BASE_POSITION_SIZE",
+    "QUANT_EVENT_ALLOCATION_PCT",
+];
+
+/// Event reaction strategy.
+pub struct EventReactionSignalGenerator {
+    config: EventReactionSignalConfig,
+    /// Recent linked events by market from the last poll, for the streaming path.
+    pending: RwLock<HashMap<String, Vec<EventImpact>>>,
+}
+
+impl EventReactionSignalGenerator {
+    pub fn new(config: EventReactionSignalConfig) -> Self {
+        Self {
+            config,
+            pending: RwLock::new(HashMap::new()),
+        }
+    }
+}
+
+#[async_trait]
+impl QuantSignalGenerator for EventReactionSignalGenerator {
+    fn kind(&self) -> QuantSignalKind {
+        QuantSignalKind::EventReaction
+    }
+
+    fn config_keys(&self) -> &'static [&'static str] {
+        CONFIG_KEYS
+    }
+
+    fn enabled(&self) -> bool {
+        self.config.enabled
+    }
+
+    fn poll_interval(&self) -> time::Duration {
+        time::Duration::from_secs(self.config.interval_secs)
+    }
+
+    /// Events are linked as they arrive; there is nothing to wait for.
+    fn startup_delay(&self) -> time::Duration {
+        time::Duration::from_secs(30)
+    }
+
+    fn allocation(&self) -> f64 {
+        self.config.allocation_pct
+    }
+
+    async fn generate(&self, ctx: &GeneratorContext) -> Result<Vec<QuantSignal>, GeneratorError> {
+        let impacts = recent_impacts(
+            &ctx.pool,
+            None,
+            ctx.now - Duration::minutes(self.config.max_age_minutes),
+            self.config.half_life(),
+            ctx.now,
+            500,
+        )
+        .await?;
+
+        let mut by_market: HashMap<String, Vec<EventImpact>> = HashMap::new();
+        for impact in impacts {
+            by_market
+                .entry(impact.condition_id.clone())
+                .or_default()
+                .push(impact);
+        }
+        let signals = by_market
+            .values()
+            .filter_map(|events| {
+                let yes_price = events.first()?.current_price?;
+                strongest_signal(&self.config, events, yes_price, ctx.now, "poll")
+            })
+            .collect();
+        *self.pending.write().unwrap_or_else(|e| e.into_inner()) = by_market;
+        Ok(signals)
+    }
+
+    fn on_features(&self, features: &MarketFeatures) -> Option<QuantSignal> {
+        let pending = self.pending.read().unwrap_or_else(|e| e.into_inner());
+        let events = pending.get(&features.market_id)?;
+        strongest_signal(&self.config, events, features.mid, features.at, "stream")
+    }
+
+    async fn should_exit(
+        &self,
+        _pool: &PgPool,
+        position: &ExitContext<'_>,
+    ) -> anyhow::Result<bool> {
+        Ok(moved_to_target(&self.config, position))
+    }
+}
+
+/// Whether YES has moved `target_move` from the entry signal's link price.
+fn moved_to_target(config: &EventReactionSignalConfig, position: &ExitContext<'_>) -> bool {
+    let Some(price_at_event) = json_decimal(position.metadata, "price_at_event") else {
+        return false;
+    };
+    let moved = (position.current_yes - price_at_event)
+        .abs()
+        .to_f64()
+        .unwrap_or(0.0);
+    moved >= config.target_move
+}
+
+/// The signal for the market's highest-impact unpriced event, if any.
+fn strongest_signal(
+    config: &EventReactionSignalConfig,
+    events: &[EventImpact],
+    yes_price: f64,
+    now: DateTime<Utc>,
+    source: &str,
+) -> Option<QuantSignal> {
+    events
+        .iter()
+        .filter_map(|event| score(config, event, yes_price, now, source))
+        .max_by(|(a, _), (b, _)| a.abs().total_cmp(&b.abs()))
+        .map(|(_, signal)| signal)
+}
+
+/// Impact and signal for one linked event at `yes_price`.
+fn score(
+    config: &EventReactionSignalConfig,
+    event: &EventImpact,
+    yes_price: f64,
+    now: DateTime<Utc>,
+    source: &str,
+) -> Option<(f64, QuantSignal)> {
+    if !event.sender_sentiment {
+        return None;
+    }
+    let price_at_event = event.price_at_event?;
+    let age = now.signed_duration_since(event.published_at);
+    if now.signed_duration_since(event.linked_at) < Duration::seconds(config.min_lag_secs)
+        || age > Duration::minutes(config.max_age_minutes)
+    {
+        return None;
+    }
+    let impact = event_impact(event.match_score, event.sentiment, age, config.half_life());
+    if impact.abs() < config.min_impact {
+        return None;
+    }
+    let reacted = reaction(impact, price_at_event, yes_price);
+    if reacted.abs() > config.max_reaction {
+        return None;
+    }
+
+    let direction = if impact > 0.0 {
+        SignalDirection::BuyYes
+    } else {
+        SignalDirection::BuyNo
+    };
+    let confidence = (0.5 + impact.abs() * 0.45).min(0.95);
+    let signal = QuantSignal::new(
+        QuantSignalKind::EventReaction,
+        event.condition_id.clone(),
+        direction,
+        confidence,
+        config.base_position_size_usd,
+        now + Duration::minutes(config.expiry_minutes),
+    )
+    .with_metadata(serde_json::json!({
+        "source": source,
+        "event_id": event.event_id,
+        "title": event.title,
+        "published_at": event.published_at,
+        "match_score": event.match_score,
+        "matched_terms": event.matched_terms,
+        "sentiment": event.sentiment,
+        "impact": impact,
+        "price_at_event": price_at_event,
+        "yes_price": yes_price,
+        "reaction": reacted,
+    }));
+
+    debug!(
+        condition_id = %event.condition_id,
+        event_id = %event.event_id,
+        direction = signal.direction.as_str(),
+        impact,
+        reaction = reacted,
+        "Event reaction signal generated"
+    );
+    Some((impact, signal))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn config() -> EventReactionSignalConfig {
+        EventReactionSignalConfig {
+            enabled: true,
+            interval_secs: 60,
+            half_life_mins: 30,
+            min_impact: 0.35,
+            min_lag_secs: 120,
+            max_age_minutes: 60,
+            max_reaction: 0.02,
+            target_move: 0.05,
+            base_position_size_usd: Decimal::new(30, 0),
+            expiry_minutes: 15,
+            allocation_pct: 0.1,
+        }
+    }
+
+    fn event(now: DateTime<Utc>, sentiment: f64, minutes_ago: i64) -> EventImpact {
+        EventImpact {
+            event_id: Uuid::new_v4(),
+            condition_id: "0xfed".to_string(),
+            title: "Fed signals December cut".to_string(),
+            url: None,
+            source: "wire".to_string(),
+            published_at: now - Duration::minutes(minutes_ago),
+            linked_at: now - Duration::minutes(minutes_ago),
+            match_score: 0.8,
+            matched_terms: vec!["fed".to_string(), "cut".to_string()],
+            sentiment,
+            sender_sentiment: true,
+            impact: 0.0,
+            price_at_event: Some(0.40),
+            current_price: None,
+            reaction: None,
+        }
+    }
+
+    #[test]
+    fn test_config_defaults() {
+        let config = EventReactionSignalConfig::from_env();
+        assert!(!config.enabled);
+        assert_eq!(config.allocation_pct, 0.0);
+        assert_eq!(config.max_reaction, 0.02);
+    }
+
+    #[test]
+    fn test_fires_only_while_the_market_has_not_moved() {
+        let config = config();
+        let now = Utc::now();
+        let fresh = event(now, 1.0, 5);
+
+        let (impact, signal) = score(&config, &fresh, 0.41, now, "poll").unwrap();
+        assert!(impact > 0.7);
+        assert_eq!(signal.kind, QuantSignalKind::EventReaction);
+        assert_eq!(signal.direction, SignalDirection::BuyYes);
+        assert!(signal.confidence > 0.8);
+
+        // Already priced in.
+        assert!(score(&config, &fresh, 0.45, now, "poll").is_none());
+        // Too soon to call it a non-reaction.
+        assert!(score(&config, &event(now, 1.0, 1), 0.40, now, "poll").is_none());
+        // Two half-lives on, 0.8 decays to 0.2.
+        assert!(score(&config, &event(now, 1.0, 60), 0.40, now, "poll").is_none());
+        // Weak sentiment.
+        assert!(score(&config, &event(now, 0.3, 5), 0.40, now, "poll").is_none());
+        // Headline-scored sentiment is not a direction for this question.
+        let lexicon = EventImpact {
+            sender_sentiment: false,
+            ..event(now, 1.0, 5)
+        };
+        assert!(score(&config, &lexicon, 0.40, now, "poll").is_none());
+
+        let (_, bearish) = score(&config, &event(now, -1.0, 5), 0.39, now, "poll").unwrap();
+        assert_eq!(bearish.direction, SignalDirection::BuyNo);
+    }
+
+    #[test]
+    fn test_strongest_event_wins_and_stream_uses_cached_events() {
+        let now = Utc::now();
+        let mut weaker = event(now, 0.6, 5);
+        weaker.event_id = Uuid::nil();
+        let stronger = event(now, -1.0, 5);
+        let generator = EventReactionSignalGenerator::new(config());
+        generator
+            .pending
+            .write()
+            .unwrap()
+            .insert("0xfed".to_string(), vec![weaker, stronger.clone()]);
+
+        let features = MarketFeatures {
+            market_id: "0xfed".to_string(),
+            question: "Will the Fed cut in December?".to_string(),
+            end_date: None,
+            category: None,
+            volume_usd: 50_000.0,
+            at: now,
+            window: Duration::minutes(15),
+            mid: 0.40,
+            microprice: 0.40,
+            spread: 0.01,
+            book_imbalance: 0.0,
+            window_open_mid: 0.40,
+            realized_vol: 0.0,
+            buy_volume: 0.0,
+            sell_volume: 0.0,
+            trade_count: 0,
+        };
+        let signal = generator.on_features(&features).unwrap();
+        assert_eq!(signal.direction, SignalDirection::BuyNo);
+        assert_eq!(
+            signal.metadata["event_id"],
+            serde_json::json!(stronger.event_id)
+        );
+        assert_eq!(signal.metadata["source"], "stream");
+
+        let other = MarketFeatures {
+            market_id: "0xother".to_string(),
+            ..features
+        };
+        assert!(generator.on_features(&other).is_none());
+    }
+
+    #[test]
+    fn test_exit_when_price_reaches_target_either_way() {
+        let config = config();
+        let metadata = serde_json::json!({ "price_at_event": 0.40 });
+        let position = |current_yes: i64| ExitContext {
+            market_id: "0xfed",
+            direction: SignalDirection::BuyYes,
+            metadata: &metadata,
+            current_yes: Decimal::new(current_yes, 2),
+            current_no: Decimal::new(100 - current_yes, 2),
+        };
+
+        assert!(!moved_to_target(&config, &position(42)));
+        assert!(moved_to_target(&config, &position(46)));
+        assert!(moved_to_target(&config, &position(35)));
+    }
+}
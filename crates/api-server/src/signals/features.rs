@@ -0,0 +1,251 @@
+//! In-memory rolling market features for streaming signal generation.
+//!
+//! Each streamed market keeps a trailing window of YES mids (from book
+//! updates) and trade prints, from which a [`MarketFeatures`] snapshot is
+//! taken whenever something changes. Everything is in YES terms: a NO print
+//! is folded in as the opposite YES trade at the complementary price.
+
+use chrono::{DateTime, Duration, Utc};
+use std::collections::VecDeque;
+
+use crate::market_making::BookTop;
+
+/// Static facts about a streamed market, from the universe refresh.
+#[derive(Debug, Clone)]
+pub struct MarketInfo {
+    pub market_id: String,
+    pub question: String,
+    pub end_date: Option<DateTime<Utc>>,
+    pub category: Option<String>,
+    /// Lifetime traded volume in USD.
+    pub volume_usd: f64,
+}
+
+/// Rolling state for one market at one moment.
+#[derive(Debug, Clone)]
+pub struct MarketFeatures {
+    pub market_id: String,
+    pub question: String,
+    pub end_date: Option<DateTime<Utc>>,
+    pub category: Option<String>,
+    pub volume_usd: f64,
+    pub at: DateTime<Utc>,
+    /// Length of the trailing window the figures below cover.
+    pub window: Duration,
+    pub mid: f64,
+    pub microprice: f64,
+    pub spread: f64,
+    /// `(bid_size - ask_size) / (bid_size + ask_size)` at the touch.
+    pub book_imbalance: f64,
+    /// Oldest mid still inside the window.
+    pub window_open_mid: f64,
+    /// Square root of the summed squared mid changes over the window.
+    pub realized_vol: f64,
+    /// Taker-buy YES notional over the window, in USD.
+    pub buy_volume: f64,
+    /// Taker-sell YES notional over the window, in USD.
+    pub sell_volume: f64,
+    pub trade_count: u32,
+}
+
+impl MarketFeatures {
+    /// Signed taker flow imbalance in `[-1, 1]`; `None` without trades.
+    pub fn flow_imbalance(&self) -> Option<f64> {
+        let total = self.buy_volume + self.sell_volume;
+        (total > 0.0).then(|| (self.buy_volume - self.sell_volume) / total)
+    }
+
+    /// Relative move of the mid across the window.
+    pub fn window_return(&self) -> Option<f64> {
+        (self.window_open_mid > 0.0)
+            .then(|| (self.mid - self.window_open_mid) / self.window_open_mid)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Print {
+    at: DateTime<Utc>,
+    /// Positive for a taker buying YES.
+    signed_notional: f64,
+}
+
+/// Trailing mids and prints for one market.
+#[derive(Debug)]
+pub struct MarketWindow {
+    info: MarketInfo,
+    window: Duration,
+    book: Option<BookTop>,
+    mids: VecDeque<(DateTime<Utc>, f64)>,
+    prints: VecDeque<Print>,
+}
+
+impl MarketWindow {
+    pub fn new(info: MarketInfo, window: Duration) -> Self {
+        Self {
+            info,
+            window,
+            book: None,
+            mids: VecDeque::new(),
+            prints: VecDeque::new(),
+        }
+    }
+
+    pub fn info(&self) -> &MarketInfo {
+        &self.info
+    }
+
+    /// Replace the static facts after a universe refresh, keeping the window.
+    pub fn set_info(&mut self, info: MarketInfo) {
+        self.info = info;
+    }
+
+    /// Record a new YES top of book; `None` for a one-sided or crossed book.
+    pub fn on_book(&mut self, top: Option<BookTop>, at: DateTime<Utc>) {
+        self.book = top;
+        let Some(top) = top else {
+            return;
+        };
+        let mid = top.mid();
+        if self.mids.back().is_none_or(|&(_, last)| last != mid) {
+            self.mids.push_back((at, mid));
+        }
+        self.prune(at);
+    }
+
+    /// Record a trade print already expressed in YES terms.
+    pub fn on_trade(&mut self, yes_price: f64, size: f64, taker_buys_yes: bool, at: DateTime<Utc>) {
+        let notional = yes_price * size;
+        self.prints.push_back(Print {
+            at,
+            signed_notional: if taker_buys_yes { notional } else { -notional },
+        });
+        self.prune(at);
+    }
+
+    /// Features as of `at`, or `None` until a two-sided book has been seen.
+    pub fn snapshot(&mut self, at: DateTime<Utc>) -> Option<MarketFeatures> {
+        self.prune(at);
+        let book = self.book?;
+        let (buy_volume, sell_volume) = self.prints.iter().fold((0.0, 0.0), |(buy, sell), p| {
+            if p.signed_notional >= 0.0 {
+                (buy + p.signed_notional, sell)
+            } else {
+                (buy, sell - p.signed_notional)
+            }
+        });
+        let realized_vol = self
+            .mids
+            .iter()
+            .zip(self.mids.iter().skip(1))
+            .map(|(&(_, a), &(_, b))| (b - a).powi(2))
+            .sum::<f64>()
+            .sqrt();
+        let depth = book.bid_size + book.ask_size;
+
+        Some(MarketFeatures {
+            market_id: self.info.market_id.clone(),
+            question: self.info.question.clone(),
+            end_date: self.info.end_date,
+            category: self.info.category.clone(),
+            volume_usd: self.info.volume_usd,
+            at,
+            window: self.window,
+            mid: book.mid(),
+            microprice: book.microprice(),
+            spread: book.best_ask - book.best_bid,
+            book_imbalance: if depth > 0.0 {
+                (book.bid_size - book.ask_size) / depth
+            } else {
+                0.0
+            },
+            window_open_mid: self.mids.front().map_or(book.mid(), |&(_, mid)| mid),
+            realized_vol,
+            buy_volume,
+            sell_volume,
+            trade_count: self.prints.len() as u32,
+        })
+    }
+
+    fn prune(&mut self, now: DateTime<Utc>) {
+        let cutoff = now - self.window;
+        // Keep the last mid before the cutoff as the window's opening level.
+        while self.mids.len() > 1 && self.mids[1].0 <= cutoff {
+            self.mids.pop_front();
+        }
+        while self.prints.front().is_some_and(|p| p.at < cutoff) {
+            self.prints.pop_front();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn window() -> MarketWindow {
+        MarketWindow::new(
+            MarketInfo {
+                market_id: "0xabc".to_string(),
+                question: "Will it rain?".to_string(),
+                end_date: None,
+                category: None,
+                volume_usd: 10_000.0,
+            },
+            Duration::minutes(15),
+        )
+    }
+
+    fn top(bid: f64, ask: f64) -> Option<BookTop> {
+        Some(BookTop {
+            best_bid: bid,
+            bid_size: 300.0,
+            best_ask: ask,
+            ask_size: 100.0,
+        })
+    }
+
+    #[test]
+    fn test_snapshot_needs_a_book() {
+        let mut w = window();
+        let now = Utc::now();
+        w.on_trade(0.5, 100.0, true, now);
+        assert!(w.snapshot(now).is_none());
+        w.on_book(top(0.48, 0.52), now);
+        w.on_book(top(0.48, 0.52), now);
+
+        let f = w.snapshot(now).unwrap();
+        assert!((f.mid - 0.50).abs() < 1e-9);
+        assert!((f.book_imbalance - 0.5).abs() < 1e-9);
+        assert!(f.microprice > f.mid);
+        assert_eq!(f.trade_count, 1);
+        assert_eq!(f.realized_vol, 0.0);
+
+        w.on_book(None, now);
+        assert!(w.snapshot(now).is_none());
+    }
+
+    #[test]
+    fn test_window_tracks_move_flow_and_vol() {
+        let mut w = window();
+        let start = Utc::now();
+        w.on_book(top(0.39, 0.41), start);
+        w.on_book(top(0.44, 0.46), start + Duration::minutes(5));
+        w.on_book(top(0.49, 0.51), start + Duration::minutes(10));
+        w.on_trade(0.50, 300.0, true, start + Duration::minutes(10));
+        w.on_trade(0.50, 100.0, false, start + Duration::minutes(11));
+
+        let f = w.snapshot(start + Duration::minutes(12)).unwrap();
+        assert!((f.window_return().unwrap() - 0.25).abs() < 1e-9);
+        assert!((f.flow_imbalance().unwrap() - 0.5).abs() < 1e-9);
+        assert!((f.realized_vol - (2.0 * 0.05f64.powi(2)).sqrt()).abs() < 1e-9);
+
+        // Twenty minutes on, the 0.40 open has aged out and the 0.45 mid
+        // before the cutoff becomes the opening level.
+        let later = w.snapshot(start + Duration::minutes(21)).unwrap();
+        assert!((later.window_open_mid - 0.45).abs() < 1e-9);
+        assert_eq!(later.trade_count, 2);
+        let gone = w.snapshot(start + Duration::minutes(27)).unwrap();
+        assert_eq!(gone.trade_count, 0);
+        assert!(gone.flow_imbalance().is_none());
+    }
+}
@@ -12,14 +12,26 @@
 //! Confidence: heuristic score using imbalance, smart-money share, participant
 //! breadth, liquidity, and price regime
 //! Expiry: 30 minutes from generation
+//! Exit: imbalance flips sign or falls below half its entry level
+//!
+//! Streamed markets are also scored from live taker flow between polls.
+//! Prints carry no wallet, so that path leans on the book agreeing with the
+//! flow instead of smart-money share, and needs `FLOW_STREAM_MIN_VOLUME_USD`
+//! of notional in the window. Calibration from the last poll still applies.
 
-use chrono::{Duration, Utc};
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
 use polymarket_core::types::signal::{QuantSignal, QuantSignalKind, SignalDirection};
 use rust_decimal::Decimal;
 use sqlx::PgPool;
+use std::sync::RwLock;
 use std::time;
-use tokio::sync::broadcast;
-use tracing::{debug, info, warn};
+use tracing::{debug, warn};
+
+use super::features::MarketFeatures;
+use super::generator::{
+    json_decimal, ExitContext, GeneratorContext, GeneratorError, QuantSignalGenerator,
+};
 
 /// Configuration for the flow signal generator.
 #[derive(Debug, Clone)]
@@ -58,73 +70,38 @@ pub struct FlowSignalConfig {
     pub calibration_min_closed_trades: i64,
     /// Whether to suppress signals until calibration data exists.
     pub require_calibration: bool,
+    /// Share of quant capital allocated to flow signals.
+    pub allocation_pct: f64,
+    /// Minimum taker notional in the streaming window to score a market live.
+    pub stream_min_volume_usd: f64,
 }
 
 impl FlowSignalConfig {
     pub fn from_env() -> Self {
         Self {
-            enabled: std::env::var("FLOW_SIGNAL_ENABLED")
-                .map(|v| v == "true")
-                .unwrap_or(true),
-            interval_secs: std::env::var("FLOW_SIGNAL_INTERVAL_SECS")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(300),
-            min_imbalance: std::env::var("FLOW_MIN_IMBALANCE")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(0.25),
-            min_smart_money_flow: std::env::var("FLOW_MIN_SMART_MONEY_USD")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(Decimal::new(500, 0)),
-            min_trade_count: std::env::var("FLOW_MIN_TRADE_COUNT")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(5),
-            min_score: std::env::var("FLOW_MIN_SCORE")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(0.58),
-            min_expected_edge_bps: std::env::var("FLOW_MIN_EXPECTED_EDGE_BPS")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(35.0),
-            min_smart_money_share: std::env::var("FLOW_MIN_SMART_MONEY_SHARE")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(0.35),
-            min_bot_score_coverage: std::env::var("FLOW_MIN_BOT_SCORE_COVERAGE")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(0.50),
-            require_yes_price: std::env::var("FLOW_REQUIRE_YES_PRICE")
-                .map(|v| v == "true")
-                .unwrap_or(true),
-            max_signals_per_cycle: std::env::var("FLOW_MAX_SIGNALS_PER_CYCLE")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(15),
-            window_minutes: std::env::var("FLOW_SIGNAL_WINDOW_MINUTES")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(60),
-            base_position_size_usd: std::env::var("QUANT_BASE_POSITION_SIZE")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(Decimal::new(30, 0)),
+            enabled: polymarket_core::settings::get("FLOW_SIGNAL_ENABLED"),
+            interval_secs: polymarket_core::settings::get("FLOW_SIGNAL_INTERVAL_SECS"),
+            min_imbalance: polymarket_core::settings::get("FLOW_MIN_IMBALANCE"),
+            min_smart_money_flow: polymarket_core::settings::get("FLOW_MIN_SMART_MONEY_USD"),
+            min_trade_count: polymarket_core::settings::get("FLOW_MIN_TRADE_COUNT"),
+            min_score: polymarket_core::settings::get("FLOW_MIN_SCORE"),
+            min_expected_edge_bps: polymarket_core::settings::get("FLOW_MIN_EXPECTED_EDGE_BPS"),
+            min_smart_money_share: polymarket_core::settings::get("FLOW_MIN_SMART_MONEY_SHARE"),
+            min_bot_score_coverage: polymarket_core::settings::get("FLOW_MIN_BOT_SCORE_COVERAGE"),
+            require_yes_price: polymarket_core::settings::get("FLOW_REQUIRE_YES_PRICE"),
+            max_signals_per_cycle: polymarket_core::settings::get("FLOW_MAX_SIGNALS_PER_CYCLE"),
+            window_minutes: polymarket_core::settings::get("FLOW_SIGNAL_WINDOW_MINUTES"),
+            base_position_size_usd: polymarket_core::settings::get("QUANT_BASE_POSITION_SIZE"),
             expiry_minutes: 30,
-            calibration_lookback_days: std::env::var("FLOW_CALIBRATION_LOOKBACK_DAYS")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(14),
-            calibration_min_closed_trades: std::env::var("FLOW_CALIBRATION_MIN_CLOSED_TRADES")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(8),
-            require_calibration: std::env::var("FLOW_REQUIRE_CALIBRATION")
-                .map(|v| v == "true")
-                .unwrap_or(true),
+            calibration_lookback_days: polymarket_core::settings::get(
+                "FLOW_CALIBRATION_LOOKBACK_DAYS",
+            ),
+            calibration_min_closed_trades: polymarket_core::settings::get(
+                "FLOW_CALIBRATION_MIN_CLOSED_TRADES",
+            ),
+            require_calibration: polymarket_core::settings::get("FLOW_REQUIRE_CALIBRATION"),
+            allocation_pct: polymarket_core::settings::get("QUANT_FLOW_ALLOCATION_PCT"),
+            stream_min_volume_usd: polymarket_core::settings::get("FLOW_STREAM_MIN_VOLUME_USD"),
         }
     }
 }
@@ -200,75 +177,145 @@ struct FlowCalibrationRow {
     edge_capture_ratio: Option<f64>,
 }
 
-/// Spawn the flow signal generator background task.
-pub fn spawn_flow_signal_generator(
+/// Settings keys read by [`FlowSignalConfig::from_env`].
+const CONFIG_KEYS: &[&str] = &[
+    "FLOW_CALIBRATION_LOOKBACK_DAYS",
+    "FLOW_CALIBRATION_MIN_CLOSED_TRADES",
+    "FLOW_MAX_SIGNALS_PER_CYCLE",
+    "FLOW_MIN_BOT_SCORE_COVERAGE",
+    "FLOW_MIN_EXPECTED_EDGE_BPS",
+    "FLOW_MIN_IMBALANCE",
+    "FLOW_MIN_SCORE",
+    "FLOW_MIN_SMART_MONEY_SHARE",
+    "FLOW_MIN_SMART_MONEY_USD",
+    "FLOW_MIN_TRADE_COUNT",
+    "FLOW_REQUIRE_CALIBRATION",
+    "FLOW_REQUIRE_YES_PRICE",
+    "FLOW_SIGNAL_ENABLED",
+    "FLOW_SIGNAL_INTERVAL_SECS",
+    "FLOW_SIGNAL_WINDOW_MINUTES",
+    "FLOW_STREAM_MIN_VOLUME_USD",
+    "QUANT_BASE_POSITION_SIZE",
+    "QUANT_FLOW_ALLOCATION_PCT",
+];
+
+/// Smart money flow strategy.
+pub struct FlowSignalGenerator {
     config: FlowSignalConfig,
-    pool: PgPool,
-    signal_tx: broadcast::Sender<QuantSignal>,
-) {
-    if !config.enabled {
-        info!("Flow signal generator disabled (FLOW_SIGNAL_ENABLED != true)");
-        return;
+    /// Calibration loaded by the last poll, reused by the streaming path.
+    calibration: RwLock<Option<FlowCalibrationSnapshot>>,
+}
+
+impl FlowSignalGenerator {
+    pub fn new(config: FlowSignalConfig) -> Self {
+        Self {
+            config,
+            calibration: RwLock::new(None),
+        }
     }
+}
 
-    info!(
-        interval_secs = config.interval_secs,
-        min_imbalance = config.min_imbalance,
-        min_smart_money_flow = %config.min_smart_money_flow,
-        min_trade_count = config.min_trade_count,
-        min_score = config.min_score,
-        min_expected_edge_bps = config.min_expected_edge_bps,
-        min_smart_money_share = config.min_smart_money_share,
-        min_bot_score_coverage = config.min_bot_score_coverage,
-        require_yes_price = config.require_yes_price,
-        max_signals_per_cycle = config.max_signals_per_cycle,
-        window_minutes = config.window_minutes,
-        calibration_lookback_days = config.calibration_lookback_days,
-        calibration_min_closed_trades = config.calibration_min_closed_trades,
-        require_calibration = config.require_calibration,
-        "Spawning flow signal generator"
-    );
+#[async_trait]
+impl QuantSignalGenerator for FlowSignalGenerator {
+    fn kind(&self) -> QuantSignalKind {
+        QuantSignalKind::Flow
+    }
 
-    tokio::spawn(generator_loop(config, pool, signal_tx));
-}
+    fn config_keys(&self) -> &'static [&'static str] {
+        CONFIG_KEYS
+    }
 
-async fn generator_loop(
-    config: FlowSignalConfig,
-    pool: PgPool,
-    signal_tx: broadcast::Sender<QuantSignal>,
-) {
-    let interval = time::Duration::from_secs(config.interval_secs);
-
-    // Startup delay — let flow features populate first
-    tokio::time::sleep(time::Duration::from_secs(60)).await;
-
-    loop {
-        match scan_and_emit(&config, &pool, &signal_tx).await {
-            Ok(count) => {
-                if count > 0 {
-                    info!(signals = count, "Flow signal generator emitted signals");
-                } else {
-                    debug!("Flow signal generator: no qualifying markets this cycle");
-                }
-            }
-            Err(e) => {
-                warn!(error = %e, "Flow signal generator cycle failed");
-            }
-        }
+    fn enabled(&self) -> bool {
+        self.config.enabled
+    }
+
+    fn poll_interval(&self) -> time::Duration {
+        time::Duration::from_secs(self.config.interval_secs)
+    }
+
+    fn allocation(&self) -> f64 {
+        self.config.allocation_pct
+    }
 
-        tokio::time::sleep(interval).await;
+    async fn generate(&self, ctx: &GeneratorContext) -> Result<Vec<QuantSignal>, GeneratorError> {
+        let calibration = load_flow_calibration(
+            &ctx.pool,
+            ctx.now - Duration::days(self.config.calibration_lookback_days),
+        )
+        .await?;
+        *self.calibration.write().unwrap_or_else(|e| e.into_inner()) = Some(calibration.clone());
+        scan(&self.config, &ctx.pool, ctx.now, &calibration).await
+    }
+
+    fn on_features(&self, features: &MarketFeatures) -> Option<QuantSignal> {
+        let calibration = self
+            .calibration
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone();
+        stream_signal(&self.config, calibration.as_ref(), features)
     }
+
+    async fn should_exit(&self, pool: &PgPool, position: &ExitContext<'_>) -> anyhow::Result<bool> {
+        let window_minutes = position
+            .metadata
+            .get("window_minutes")
+            .and_then(|value| value.as_i64())
+            .unwrap_or(60) as i32;
+
+        let latest_row: Option<(Decimal,)> = sqlx::query_as(
+            r#"
+            SELECT imbalance_ratio
+            FROM market_flow_features
+            WHERE condition_id = $1
+              AND window_minutes = $2
+            ORDER BY window_end DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(position.market_id)
+        .bind(window_minutes)
+        .fetch_optional(pool)
+        .await?;
+
+        Ok(latest_row
+            .is_some_and(|(imbalance_ratio,)| flow_support_lost(position, imbalance_ratio)))
+    }
+}
+
+/// Whether the latest imbalance no longer supports the position: it flipped
+/// sign, or fell below half its entry level (and never below 0.15).
+fn flow_support_lost(position: &ExitContext<'_>, imbalance_ratio: Decimal) -> bool {
+    let entry_imbalance = json_decimal(position.metadata, "imbalance_ratio")
+        .map(|v| v.abs())
+        .unwrap_or(Decimal::ZERO);
+    let original_sign = match position.direction {
+        SignalDirection::BuyYes => 1,
+        SignalDirection::BuyNo => -1,
+    };
+    let current_sign = if imbalance_ratio > Decimal::ZERO {
+        1
+    } else if imbalance_ratio < Decimal::ZERO {
+        -1
+    } else {
+        0
+    };
+    let min_supported_imbalance = entry_imbalance
+        .checked_div(Decimal::new(2, 0))
+        .unwrap_or(Decimal::ZERO)
+        .max(Decimal::new(15, 2));
+
+    (current_sign != 0 && current_sign != original_sign)
+        || imbalance_ratio.abs() < min_supported_imbalance
 }
 
-async fn scan_and_emit(
+async fn scan(
     config: &FlowSignalConfig,
     pool: &PgPool,
-    signal_tx: &broadcast::Sender<QuantSignal>,
-) -> Result<usize, Box<dyn std::error::Error + Send + Sync>> {
-    let now = Utc::now();
+    now: DateTime<Utc>,
+    calibration: &FlowCalibrationSnapshot,
+) -> Result<Vec<QuantSignal>, GeneratorError> {
     let window_start = now - Duration::minutes(config.window_minutes as i64);
-    let calibration =
-        load_flow_calibration(pool, now - Duration::days(config.calibration_lookback_days)).await?;
 
     if config.require_calibration
         && !calibration.has_usable_data(config.calibration_min_closed_trades)
@@ -278,7 +325,7 @@ async fn scan_and_emit(
             min_closed_trades = config.calibration_min_closed_trades,
             "Flow calibration unavailable, suppressing signal emission"
         );
-        return Ok(0);
+        return Ok(Vec::new());
     }
 
     // Query the most recent flow features for the configured window size
@@ -385,7 +432,7 @@ async fn scan_and_emit(
     .fetch_all(pool)
     .await?;
 
-    let mut emitted = 0;
+    let mut signals = Vec::new();
     let mut scored_rows: Vec<(f64, f64, &FlowFeatureRow, f64, f64, f64, f64, f64, f64)> =
         Vec::new();
 
@@ -543,11 +590,112 @@ async fn scan_and_emit(
             "Flow signal generated"
         );
 
-        let _ = signal_tx.send(signal);
-        emitted += 1;
+        signals.push(signal);
+    }
+
+    Ok(signals)
+}
+
+/// Score a streamed market from its live taker flow and book.
+fn stream_signal(
+    config: &FlowSignalConfig,
+    calibration: Option<&FlowCalibrationSnapshot>,
+    features: &MarketFeatures,
+) -> Option<QuantSignal> {
+    let imbalance = features.flow_imbalance()?;
+    let flow_volume = features.buy_volume + features.sell_volume;
+    if imbalance.abs() < config.min_imbalance
+        || features.trade_count < config.min_trade_count.max(0) as u32
+        || flow_volume < config.stream_min_volume_usd
+    {
+        return None;
+    }
+
+    let direction = if imbalance > 0.0 {
+        SignalDirection::BuyYes
+    } else {
+        SignalDirection::BuyNo
+    };
+    let (score, expected_edge_bps) = stream_score_components(
+        imbalance,
+        features.book_imbalance,
+        features.trade_count,
+        flow_volume / config.stream_min_volume_usd.max(1.0),
+        price_regime_score(Some(features.mid)),
+    );
+    if score < config.min_score {
+        return None;
+    }
+
+    let calibration_stats = match calibration
+        .and_then(|c| c.usable_for(direction, config.calibration_min_closed_trades))
+    {
+        Some(stats) => stats,
+        None if config.require_calibration => return None,
+        None => FlowCalibrationStats::default(),
+    };
+    let calibrated_expected_edge_bps = if config.require_calibration {
+        calibrate_expected_edge_bps(expected_edge_bps, calibration_stats)
+    } else {
+        expected_edge_bps
+    };
+    if calibrated_expected_edge_bps < config.min_expected_edge_bps {
+        return None;
     }
 
-    Ok(emitted)
+    Some(
+        QuantSignal::new(
+            QuantSignalKind::Flow,
+            features.market_id.clone(),
+            direction,
+            score.clamp(0.0, 0.95),
+            config.base_position_size_usd,
+            features.at + Duration::minutes(config.expiry_minutes),
+        )
+        .with_metadata(serde_json::json!({
+            "source": "stream",
+            "imbalance_ratio": imbalance,
+            "book_imbalance": features.book_imbalance,
+            "trade_count": features.trade_count,
+            "buy_volume": features.buy_volume,
+            "sell_volume": features.sell_volume,
+            "yes_price": features.mid,
+            "microprice": features.microprice,
+            "market_volume": features.volume_usd,
+            "score": score,
+            "raw_expected_edge_bps": expected_edge_bps,
+            "expected_edge_bps": calibrated_expected_edge_bps,
+            "calibration_closed_trades": calibration_stats.closed_trades,
+            "stream_window_secs": features.window.num_seconds(),
+            "window_minutes": config.window_minutes,
+        })),
+    )
+}
+
+/// Score and expected edge for live flow: taker imbalance, the resting book
+/// leaning the same way, and how much trading backs it.
+fn stream_score_components(
+    imbalance: f64,
+    book_imbalance: f64,
+    trade_count: u32,
+    volume_multiple: f64,
+    price_score: f64,
+) -> (f64, f64) {
+    let book_agreement = (book_imbalance * imbalance.signum()).clamp(0.0, 1.0);
+    let trade_count_score = (trade_count as f64 / 14.0).clamp(0.0, 1.0);
+    let volume_score = (volume_multiple / 4.0).clamp(0.0, 1.0);
+    let imbalance_abs = imbalance.abs();
+
+    let score = (imbalance_abs * 0.45
+        + book_agreement * 0.20
+        + trade_count_score * 0.15
+        + volume_score * 0.10)
+        * (0.75 + 0.25 * price_score);
+    let expected_edge_bps =
+        (imbalance_abs * 55.0) + (book_agreement * 25.0) + (trade_count_score * 12.0)
+            - ((1.0 - price_score) * 18.0);
+
+    (score, expected_edge_bps)
 }
 
 /// Convert Decimal to f64 for confidence calculations.
@@ -694,6 +842,69 @@ mod tests {
         assert!(config.require_calibration);
         assert!(config.min_bot_score_coverage > 0.0);
         assert_eq!(config.window_minutes, 60);
+        assert_eq!(config.allocation_pct, 0.40);
+    }
+
+    #[test]
+    fn test_exit_when_imbalance_flips_or_fades() {
+        let metadata = serde_json::json!({ "imbalance_ratio": 0.50 });
+        let position = ExitContext {
+            market_id: "0x1234",
+            direction: SignalDirection::BuyYes,
+            metadata: &metadata,
+            current_yes: Decimal::new(55, 2),
+            current_no: Decimal::new(45, 2),
+        };
+
+        assert!(!flow_support_lost(&position, Decimal::new(30, 2)));
+        assert!(flow_support_lost(&position, Decimal::new(20, 2)));
+        assert!(flow_support_lost(&position, Decimal::new(-40, 2)));
+    }
+
+    #[test]
+    fn test_stream_signal_needs_agreeing_flow_and_calibration() {
+        let mut config = FlowSignalConfig::from_env();
+        let features = MarketFeatures {
+            market_id: "0x1234".to_string(),
+            question: String::new(),
+            end_date: None,
+            category: None,
+            volume_usd: 50_000.0,
+            at: Utc::now(),
+            window: Duration::minutes(15),
+            mid: 0.50,
+            microprice: 0.51,
+            spread: 0.02,
+            book_imbalance: 0.5,
+            window_open_mid: 0.48,
+            realized_vol: 0.02,
+            buy_volume: 8_000.0,
+            sell_volume: 2_000.0,
+            trade_count: 20,
+        };
+
+        // No calibration yet and calibration required: nothing fires.
+        assert!(stream_signal(&config, None, &features).is_none());
+
+        config.require_calibration = false;
+        let signal = stream_signal(&config, None, &features).unwrap();
+        assert_eq!(signal.direction, SignalDirection::BuyYes);
+        assert_eq!(signal.metadata["source"], "stream");
+
+        // The book leaning against the flow costs enough score to drop it.
+        let against = MarketFeatures {
+            book_imbalance: -0.5,
+            trade_count: 6,
+            ..features.clone()
+        };
+        assert!(stream_signal(&config, None, &against).is_none());
+
+        let thin = MarketFeatures {
+            buy_volume: 800.0,
+            sell_volume: 200.0,
+            ..features
+        };
+        assert!(stream_signal(&config, None, &thin).is_none());
     }
 
     #[test]
@@ -0,0 +1,103 @@
+//! The interface every quant signal strategy implements.
+//!
+//! A generator owns its configuration, SQL and scoring; the
+//! [`SignalGeneratorRegistry`](super::SignalGeneratorRegistry) owns everything
+//! around it (scheduling, enable/disable, health, allocation lookup), and the
+//! exit handler calls back into [`QuantSignalGenerator::should_exit`] for
+//! positions the generator opened. Generators that can score a market from
+//! live book and trade state also implement
+//! [`QuantSignalGenerator::on_features`], which the streaming pipeline calls
+//! within seconds of a change instead of waiting for the next poll.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use polymarket_core::types::signal::{QuantSignal, QuantSignalKind, SignalDirection};
+use rust_decimal::Decimal;
+use sqlx::PgPool;
+use std::time;
+
+use super::features::MarketFeatures;
+
+/// Error type for a generator cycle.
+pub type GeneratorError = Box<dyn std::error::Error + Send + Sync>;
+
+/// What a generator is handed on each poll.
+#[derive(Debug, Clone)]
+pub struct GeneratorContext {
+    pub pool: PgPool,
+    /// Cycle start; generators should stamp signals and windows from this.
+    pub now: DateTime<Utc>,
+}
+
+/// An open position opened from one of a generator's signals.
+#[derive(Debug, Clone, Copy)]
+pub struct ExitContext<'a> {
+    pub market_id: &'a str,
+    pub direction: SignalDirection,
+    /// Metadata of the entry signal.
+    pub metadata: &'a serde_json::Value,
+    /// Current YES price, inferred from the bids.
+    pub current_yes: Decimal,
+    /// Current NO price, inferred from the bids.
+    pub current_no: Decimal,
+}
+
+/// A quant signal strategy.
+#[async_trait]
+pub trait QuantSignalGenerator: Send + Sync {
+    /// Kind stamped on every signal this generator emits.
+    fn kind(&self) -> QuantSignalKind;
+
+    /// Name used in logs, health and the enable/disable API.
+    fn name(&self) -> &'static str {
+        self.kind().as_str()
+    }
+
+    /// Settings keys the generator's configuration is read from.
+    fn config_keys(&self) -> &'static [&'static str];
+
+    /// Whether the generator runs at startup; the registry can toggle it later.
+    fn enabled(&self) -> bool;
+
+    /// Time between polls.
+    fn poll_interval(&self) -> time::Duration;
+
+    /// Delay before the first poll, so the feature tables it reads can fill.
+    fn startup_delay(&self) -> time::Duration {
+        time::Duration::from_secs(60)
+    }
+
+    /// Share of quant capital the executor sizes this kind's signals against.
+    fn allocation(&self) -> f64;
+
+    /// Scan for opportunities and return the signals to emit.
+    async fn generate(&self, ctx: &GeneratorContext) -> Result<Vec<QuantSignal>, GeneratorError>;
+
+    /// Score one market's live rolling features. Called on every change to
+    /// a streamed market, so it must be cheap and must not touch the database.
+    fn on_features(&self, _features: &MarketFeatures) -> Option<QuantSignal> {
+        None
+    }
+
+    /// Strategy-specific exit, checked after the generic take-profit,
+    /// stop-loss and max-hold rules have not fired.
+    async fn should_exit(
+        &self,
+        _pool: &PgPool,
+        _position: &ExitContext<'_>,
+    ) -> anyhow::Result<bool> {
+        Ok(false)
+    }
+}
+
+/// Read a decimal from signal metadata stored either as a string or a number.
+pub(crate) fn json_decimal(metadata: &serde_json::Value, key: &str) -> Option<Decimal> {
+    let value = metadata.get(key)?;
+    if let Some(raw) = value.as_str() {
+        return raw.parse::<Decimal>().ok();
+    }
+    if let Some(raw) = value.as_f64() {
+        return Decimal::try_from(raw).ok();
+    }
+    None
+}
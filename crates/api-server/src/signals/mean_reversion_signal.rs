@@ -10,14 +10,24 @@
 //! Direction: opposite of the move (bet on reversion)
 //! Confidence: 0.55 + |price_change| * 1.5, capped at 0.80
 //! Expiry: 20 minutes (short-lived reversion window)
+//! Exit: price reaches the midpoint of the move
+//!
+//! Streamed markets are also checked live: the same move threshold against
+//! the mid at the start of the streaming window, with "thin volume" read as
+//! taker flow not confirming the move.
 
-use chrono::{Duration, Utc};
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
 use polymarket_core::types::signal::{QuantSignal, QuantSignalKind, SignalDirection};
 use rust_decimal::Decimal;
 use sqlx::PgPool;
 use std::time;
-use tokio::sync::broadcast;
-use tracing::{debug, info, warn};
+use tracing::debug;
+
+use super::features::MarketFeatures;
+use super::generator::{
+    json_decimal, ExitContext, GeneratorContext, GeneratorError, QuantSignalGenerator,
+};
 
 /// Configuration for the mean reversion signal generator.
 #[derive(Debug, Clone)]
@@ -32,27 +42,19 @@ pub struct MeanReversionSignalConfig {
     pub base_position_size_usd: Decimal,
     /// Signal expiry in minutes.
     pub expiry_minutes: i64,
+    /// Share of quant capital allocated to mean reversion signals.
+    pub allocation_pct: f64,
 }
 
 impl MeanReversionSignalConfig {
     pub fn from_env() -> Self {
         Self {
-            enabled: std::env::var("MEAN_REVERSION_SIGNAL_ENABLED")
-                .map(|v| v == "true")
-                .unwrap_or(true),
-            interval_secs: std::env::var("MEAN_REVERSION_INTERVAL_SECS")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(600),
-            min_move_pct: std::env::var("MEAN_REV_MIN_MOVE_PCT")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(0.10),
-            base_position_size_usd: std::env::var("QUANT_BASE_POSITION_SIZE")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(Decimal::new(30, 0)),
+            enabled: polymarket_core::settings::get("MEAN_REVERSION_SIGNAL_ENABLED"),
+            interval_secs: polymarket_core::settings::get("MEAN_REVERSION_INTERVAL_SECS"),
+            min_move_pct: polymarket_core::settings::get("MEAN_REV_MIN_MOVE_PCT"),
+            base_position_size_usd: polymarket_core::settings::get("QUANT_BASE_POSITION_SIZE"),
             expiry_minutes: 20,
+            allocation_pct: polymarket_core::settings::get("QUANT_MEAN_REVERSION_ALLOCATION_PCT"),
         }
     }
 }
@@ -73,64 +75,92 @@ struct MeanReversionRow {
     median_volume: f64,
 }
 
-/// Spawn the mean reversion signal generator.
-pub fn spawn_mean_reversion_signal_generator(
+/// Settings keys read by [`MeanReversionSignalConfig::from_env`].
+const CONFIG_KEYS: &[&str] = &[
+    "MEAN_REVERSION_INTERVAL_SECS",
+    "MEAN_REVERSION_SIGNAL_ENABLED",
+    "MEAN_REV_MIN_MOVE_PCT",
+    "QUANT_BASE_POSITION_SIZE",
+    "QUANT_MEAN_REVERSION_ALLOCATION_PCT",
+];
+
+/// Short-term mean reversion strategy.
+pub struct MeanReversionSignalGenerator {
     config: MeanReversionSignalConfig,
-    pool: PgPool,
-    signal_tx: broadcast::Sender<QuantSignal>,
-) {
-    if !config.enabled {
-        info!("Mean reversion signal generator disabled (MEAN_REVERSION_SIGNAL_ENABLED != true)");
-        return;
+}
+
+impl MeanReversionSignalGenerator {
+    pub fn new(config: MeanReversionSignalConfig) -> Self {
+        Self { config }
+    }
+}
+
+#[async_trait]
+impl QuantSignalGenerator for MeanReversionSignalGenerator {
+    fn kind(&self) -> QuantSignalKind {
+        QuantSignalKind::MeanReversion
+    }
+
+    fn config_keys(&self) -> &'static [&'static str] {
+        CONFIG_KEYS
+    }
+
+    fn enabled(&self) -> bool {
+        self.config.enabled
+    }
+
+    fn poll_interval(&self) -> time::Duration {
+        time::Duration::from_secs(self.config.interval_secs)
+    }
+
+    /// Let orderbook data accumulate.
+    fn startup_delay(&self) -> time::Duration {
+        time::Duration::from_secs(45)
+    }
+
+    fn allocation(&self) -> f64 {
+        self.config.allocation_pct
+    }
+
+    async fn generate(&self, ctx: &GeneratorContext) -> Result<Vec<QuantSignal>, GeneratorError> {
+        scan(&self.config, &ctx.pool, ctx.now).await
     }
 
-    info!(
-        interval_secs = config.interval_secs,
-        min_move_pct = config.min_move_pct,
-        "Spawning mean reversion signal generator"
-    );
+    fn on_features(&self, features: &MarketFeatures) -> Option<QuantSignal> {
+        stream_signal(&self.config, features)
+    }
 
-    tokio::spawn(generator_loop(config, pool, signal_tx));
+    async fn should_exit(
+        &self,
+        _pool: &PgPool,
+        position: &ExitContext<'_>,
+    ) -> anyhow::Result<bool> {
+        Ok(midpoint_target_hit(position))
+    }
 }
 
-async fn generator_loop(
-    config: MeanReversionSignalConfig,
-    pool: PgPool,
-    signal_tx: broadcast::Sender<QuantSignal>,
-) {
-    let interval = time::Duration::from_secs(config.interval_secs);
-
-    // Startup delay — let orderbook data accumulate
-    tokio::time::sleep(time::Duration::from_secs(45)).await;
-
-    loop {
-        match scan_and_emit(&config, &pool, &signal_tx).await {
-            Ok(count) => {
-                if count > 0 {
-                    info!(
-                        signals = count,
-                        "Mean reversion signal generator emitted signals"
-                    );
-                } else {
-                    debug!("Mean reversion signal generator: no qualifying markets this cycle");
-                }
-            }
-            Err(e) => {
-                warn!(error = %e, "Mean reversion signal generator cycle failed");
-            }
+/// Whether the held side has recovered to the midpoint of the entry move.
+fn midpoint_target_hit(position: &ExitContext<'_>) -> bool {
+    let Some(current_price) = json_decimal(position.metadata, "current_price") else {
+        return false;
+    };
+    let Some(previous_price) = json_decimal(position.metadata, "previous_price") else {
+        return false;
+    };
+    let target_yes = (current_price + previous_price) / Decimal::new(2, 0);
+    match position.direction {
+        SignalDirection::BuyYes => position.current_yes >= target_yes,
+        SignalDirection::BuyNo => {
+            position.current_no >= (Decimal::ONE - target_yes).max(Decimal::ZERO)
         }
-
-        tokio::time::sleep(interval).await;
     }
 }
 
-async fn scan_and_emit(
+async fn scan(
     config: &MeanReversionSignalConfig,
     pool: &PgPool,
-    signal_tx: &broadcast::Sender<QuantSignal>,
-) -> Result<usize, Box<dyn std::error::Error + Send + Sync>> {
-    let now = Utc::now();
-
+    now: DateTime<Utc>,
+) -> Result<Vec<QuantSignal>, GeneratorError> {
     // Find markets where the most recent hourly price moved > min_move_pct
     // compared to the previous hour, and current volume is below 24h median.
     //
@@ -195,22 +225,11 @@ async fn scan_and_emit(
     .fetch_all(pool)
     .await?;
 
-    let mut emitted = 0;
+    let mut signals = Vec::new();
 
     for row in &rows {
         let abs_change = row.price_change.abs();
-
-        // Direction: opposite of the move (mean reversion)
-        // If price went UP sharply → BuyNo (bet it reverts down)
-        // If price went DOWN sharply → BuyYes (bet it reverts up)
-        let direction = if row.price_change > 0.0 {
-            SignalDirection::BuyNo
-        } else {
-            SignalDirection::BuyYes
-        };
-
-        // Confidence: 0.55 base + magnitude bonus, capped at 0.80
-        let confidence = (0.55 + abs_change * 1.5).min(0.80);
+        let (direction, confidence) = fade(row.price_change);
 
         let expiry = now + Duration::minutes(config.expiry_minutes);
 
@@ -244,17 +263,115 @@ async fn scan_and_emit(
             "Mean reversion signal generated"
         );
 
-        let _ = signal_tx.send(signal);
-        emitted += 1;
+        signals.push(signal);
     }
 
-    Ok(emitted)
+    Ok(signals)
+}
+
+/// Direction and confidence for fading a relative move.
+///
+/// If price went UP sharply → BuyNo (bet it reverts down); if it went DOWN
+/// sharply → BuyYes. Confidence: 0.55 base + magnitude bonus, capped at 0.80.
+fn fade(price_change: f64) -> (SignalDirection, f64) {
+    let direction = if price_change > 0.0 {
+        SignalDirection::BuyNo
+    } else {
+        SignalDirection::BuyYes
+    };
+    (direction, (0.55 + price_change.abs() * 1.5).min(0.80))
 }
 
+/// Fade a streamed market's move across the window when flow did not drive it.
+fn stream_signal(
+    config: &MeanReversionSignalConfig,
+    features: &MarketFeatures,
+) -> Option<QuantSignal> {
+    let price_change = features.window_return()?;
+    if price_change.abs() < config.min_move_pct
+        || features.mid <= 0.05
+        || features.window_open_mid <= 0.05
+    {
+        return None;
+    }
+    let flow_with_move = features
+        .flow_imbalance()
+        .map_or(0.0, |imbalance| imbalance * price_change.signum());
+    if flow_with_move >= MAX_CONFIRMING_FLOW {
+        return None;
+    }
+
+    let (direction, confidence) = fade(price_change);
+    Some(
+        QuantSignal::new(
+            QuantSignalKind::MeanReversion,
+            features.market_id.clone(),
+            direction,
+            confidence,
+            config.base_position_size_usd,
+            features.at + Duration::minutes(config.expiry_minutes),
+        )
+        .with_metadata(serde_json::json!({
+            "source": "stream",
+            "current_price": features.mid,
+            "previous_price": features.window_open_mid,
+            "price_change": price_change,
+            "price_change_pct": price_change.abs() * 100.0,
+            "flow_imbalance": features.flow_imbalance(),
+            "realized_vol": features.realized_vol,
+            "stream_window_secs": features.window.num_seconds(),
+        })),
+    )
+}
+
+/// Taker flow imbalance in the direction of a move above which the move is
+/// treated as informed and not faded.
+const MAX_CONFIRMING_FLOW: f64 = 0.5;
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_stream_fades_moves_not_driven_by_flow() {
+        let config = MeanReversionSignalConfig::from_env();
+        let features = MarketFeatures {
+            market_id: "0x1234".to_string(),
+            question: String::new(),
+            end_date: None,
+            category: None,
+            volume_usd: 10_000.0,
+            at: Utc::now(),
+            window: Duration::minutes(15),
+            mid: 0.60,
+            microprice: 0.60,
+            spread: 0.02,
+            book_imbalance: 0.0,
+            window_open_mid: 0.50,
+            realized_vol: 0.05,
+            buy_volume: 300.0,
+            sell_volume: 200.0,
+            trade_count: 4,
+        };
+
+        let signal = stream_signal(&config, &features).unwrap();
+        assert_eq!(signal.direction, SignalDirection::BuyNo);
+        assert_eq!(signal.metadata["previous_price"], 0.50);
+
+        let informed = MarketFeatures {
+            buy_volume: 900.0,
+            sell_volume: 100.0,
+            ..features.clone()
+        };
+        assert!(stream_signal(&config, &informed).is_none());
+
+        let small = MarketFeatures {
+            mid: 0.52,
+            ..features
+        };
+        assert!(stream_signal(&config, &small).is_none());
+    }
+
     #[test]
     fn test_config_defaults() {
         let config = MeanReversionSignalConfig::from_env();
@@ -276,6 +393,24 @@ mod tests {
         assert!((0.55_f64 + 0.05 * 1.5 - 0.625).abs() < 0.001);
     }
 
+    #[test]
+    fn test_exit_hits_midpoint_target_for_yes() {
+        let metadata = serde_json::json!({
+            "current_price": "0.40",
+            "previous_price": "0.60"
+        });
+        let position = |current_yes: i64| ExitContext {
+            market_id: "0x1234",
+            direction: SignalDirection::BuyYes,
+            metadata: &metadata,
+            current_yes: Decimal::new(current_yes, 2),
+            current_no: Decimal::new(100 - current_yes, 2),
+        };
+
+        assert!(midpoint_target_hit(&position(50)));
+        assert!(!midpoint_target_hit(&position(45)));
+    }
+
     #[test]
     fn test_direction_logic() {
         // Price went up (positive change) → BuyNo (reversion down)
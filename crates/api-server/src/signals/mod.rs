@@ -1,18 +1,36 @@
 //! Quantitative signal generators.
 //!
-//! Each generator runs as an independent background task, polling feature tables
-//! (flow features, orderbook aggregates, market metadata) at its own cadence.
-//! When conditions are met, it emits a `QuantSignal` on the broadcast channel
-//! for the `QuantSignalExecutor` to evaluate and potentially execute.
+//! Each strategy implements [`QuantSignalGenerator`]: it owns its config,
+//! polls feature tables (flow features, orderbook aggregates, market metadata,
+//! linked external events) and returns `QuantSignal`s, plus an optional
+//! strategy-specific exit rule.
+//! The [`SignalGeneratorRegistry`] schedules every registered generator at its
+//! own cadence, forwards its signals on the broadcast channel for the
+//! `QuantSignalExecutor`, tracks run health, toggles generators at runtime and
+//! supplies the executor's per-kind allocation.
 //!
-//! Generators can be independently enabled/disabled via environment variables.
+//! Generators start enabled or disabled from their environment variables.
+//!
+//! With `STREAMING_SIGNALS_ENABLED`, the streaming pipeline additionally keeps
+//! rolling book and trade features for the most-traded markets in memory and
+//! lets generators fire on them within seconds of a change.
 
 pub mod cross_market_signal;
+pub mod event_signal;
+pub mod features;
 pub mod flow_signal;
+pub mod generator;
 pub mod mean_reversion_signal;
+pub mod registry;
 pub mod resolution_signal;
+pub mod stream;
 
-pub use cross_market_signal::spawn_cross_market_signal_generator;
-pub use flow_signal::spawn_flow_signal_generator;
-pub use mean_reversion_signal::spawn_mean_reversion_signal_generator;
-pub use resolution_signal::spawn_resolution_signal_generator;
+pub use cross_market_signal::CrossMarketSignalGenerator;
+pub use event_signal::EventReactionSignalGenerator;
+pub use features::{MarketFeatures, MarketInfo, MarketWindow};
+pub use flow_signal::FlowSignalGenerator;
+pub use generator::{ExitContext, GeneratorContext, GeneratorError, QuantSignalGenerator};
+pub use mean_reversion_signal::MeanReversionSignalGenerator;
+pub use registry::{GeneratorHealth, SignalGeneratorRegistry};
+pub use resolution_signal::ResolutionSignalGenerator;
+pub use stream::{spawn_streaming_signals, StreamingSignalConfig};
@@ -0,0 +1,400 @@
+//! Registry of quant signal generators.
+//!
+//! Runs one polling task per registered generator and forwards what it
+//! generates to the quant executor. Generators can be switched on and off at
+//! runtime (a disabled generator's task idles instead of exiting), and each
+//! one's last run, failures and output are kept for the health endpoint.
+//! The streaming pipeline also routes live market features through
+//! [`SignalGeneratorRegistry::evaluate_features`].
+
+use chrono::{DateTime, Utc};
+use polymarket_core::types::signal::{QuantSignal, QuantSignalKind};
+use serde::Serialize;
+use sqlx::PgPool;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use tokio::sync::{broadcast, RwLock};
+use tracing::{debug, info, warn};
+use utoipa::ToSchema;
+
+use super::cross_market_signal::{CrossMarketSignalConfig, CrossMarketSignalGenerator};
+use super::event_signal::{EventReactionSignalConfig, EventReactionSignalGenerator};
+use super::features::MarketFeatures;
+use super::flow_signal::{FlowSignalConfig, FlowSignalGenerator};
+use super::generator::{GeneratorContext, QuantSignalGenerator};
+use super::mean_reversion_signal::{MeanReversionSignalConfig, MeanReversionSignalGenerator};
+use super::resolution_signal::{ResolutionSignalConfig, ResolutionSignalGenerator};
+
+/// Run history for one generator.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct GeneratorHealth {
+    pub name: String,
+    pub enabled: bool,
+    pub poll_interval_secs: u64,
+    pub allocation: f64,
+    pub last_run_at: Option<DateTime<Utc>>,
+    pub last_success_at: Option<DateTime<Utc>>,
+    pub last_error: Option<String>,
+    pub consecutive_failures: u32,
+    pub last_signal_count: usize,
+    pub total_signals: u64,
+    /// Signals fired from live market features, counted separately from polls.
+    pub stream_signals: u64,
+}
+
+#[derive(Debug, Default)]
+struct RunStats {
+    last_run_at: Option<DateTime<Utc>>,
+    last_success_at: Option<DateTime<Utc>>,
+    last_error: Option<String>,
+    consecutive_failures: u32,
+    last_signal_count: usize,
+    total_signals: u64,
+}
+
+struct Registered {
+    generator: Arc<dyn QuantSignalGenerator>,
+    enabled: AtomicBool,
+    stats: RwLock<RunStats>,
+    stream_signals: AtomicU64,
+}
+
+/// The set of quant signal generators the server runs.
+#[derive(Default)]
+pub struct SignalGeneratorRegistry {
+    generators: Vec<Arc<Registered>>,
+}
+
+impl SignalGeneratorRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The built-in generators, configured from settings.
+    pub fn from_env() -> Self {
+        Self::new()
+            .with(FlowSignalGenerator::new(FlowSignalConfig::from_env()))
+            .with(CrossMarketSignalGenerator::new(
+                CrossMarketSignalConfig::from_env(),
+            ))
+            .with(MeanReversionSignalGenerator::new(
+                MeanReversionSignalConfig::from_env(),
+            ))
+            .with(ResolutionSignalGenerator::new(
+                ResolutionSignalConfig::from_env(),
+            ))
+            .with(EventReactionSignalGenerator::new(
+                EventReactionSignalConfig::from_env(),
+            ))
+    }
+
+    /// Add a generator. A later registration of the same kind replaces the earlier one.
+    pub fn with(mut self, generator: impl QuantSignalGenerator + 'static) -> Self {
+        let kind = generator.kind();
+        self.generators.retain(|r| r.generator.kind() != kind);
+        self.generators.push(Arc::new(Registered {
+            enabled: AtomicBool::new(generator.enabled()),
+            generator: Arc::new(generator),
+            stats: RwLock::new(RunStats::default()),
+            stream_signals: AtomicU64::new(0),
+        }));
+        self
+    }
+
+    pub fn get(&self, kind: QuantSignalKind) -> Option<Arc<dyn QuantSignalGenerator>> {
+        self.find(|g| g.kind() == kind).map(|r| r.generator.clone())
+    }
+
+    /// Kinds of every registered generator, in registration order.
+    pub fn kinds(&self) -> Vec<QuantSignalKind> {
+        self.generators.iter().map(|r| r.generator.kind()).collect()
+    }
+
+    pub fn is_enabled(&self, kind: QuantSignalKind) -> bool {
+        self.find(|g| g.kind() == kind)
+            .is_some_and(|r| r.enabled.load(Ordering::Relaxed))
+    }
+
+    /// `(name, enabled)` for every registered generator.
+    pub fn enabled_states(&self) -> Vec<(&'static str, bool)> {
+        self.generators
+            .iter()
+            .map(|r| (r.generator.name(), r.enabled.load(Ordering::Relaxed)))
+            .collect()
+    }
+
+    /// Enable or disable a generator by name. Returns `false` if no generator has that name.
+    pub fn set_enabled(&self, name: &str, enabled: bool) -> bool {
+        let Some(registered) = self.find(|g| g.name() == name) else {
+            return false;
+        };
+        let was = registered.enabled.swap(enabled, Ordering::Relaxed);
+        if was != enabled {
+            info!(generator = name, enabled, "Signal generator toggled");
+        }
+        true
+    }
+
+    /// Executor allocation weight per kind.
+    pub fn allocations(&self) -> HashMap<QuantSignalKind, f64> {
+        self.generators
+            .iter()
+            .map(|r| (r.generator.kind(), r.generator.allocation()))
+            .collect()
+    }
+
+    pub async fn health(&self) -> Vec<GeneratorHealth> {
+        let mut health = Vec::with_capacity(self.generators.len());
+        for registered in &self.generators {
+            let generator = &registered.generator;
+            let stats = registered.stats.read().await;
+            health.push(GeneratorHealth {
+                name: generator.name().to_string(),
+                enabled: registered.enabled.load(Ordering::Relaxed),
+                poll_interval_secs: generator.poll_interval().as_secs(),
+                allocation: generator.allocation(),
+                last_run_at: stats.last_run_at,
+                last_success_at: stats.last_success_at,
+                last_error: stats.last_error.clone(),
+                consecutive_failures: stats.consecutive_failures,
+                last_signal_count: stats.last_signal_count,
+                total_signals: stats.total_signals,
+                stream_signals: registered.stream_signals.load(Ordering::Relaxed),
+            });
+        }
+        health
+    }
+
+    /// Score one market's live features with every enabled generator whose
+    /// kind `ready` accepts (the caller's per-market cooldown).
+    pub fn evaluate_features(
+        &self,
+        features: &MarketFeatures,
+        ready: impl Fn(QuantSignalKind) -> bool,
+    ) -> Vec<QuantSignal> {
+        self.generators
+            .iter()
+            .filter(|r| r.enabled.load(Ordering::Relaxed) && ready(r.generator.kind()))
+            .filter_map(|r| {
+                let signal = r.generator.on_features(features)?;
+                r.stream_signals.fetch_add(1, Ordering::Relaxed);
+                Some(signal)
+            })
+            .collect()
+    }
+
+    /// Spawn one polling task per registered generator.
+    pub fn spawn(&self, pool: PgPool, signal_tx: broadcast::Sender<QuantSignal>) {
+        for registered in &self.generators {
+            let generator = &registered.generator;
+            info!(
+                generator = generator.name(),
+                enabled = registered.enabled.load(Ordering::Relaxed),
+                interval_secs = generator.poll_interval().as_secs(),
+                allocation = generator.allocation(),
+                "Spawning signal generator"
+            );
+            tokio::spawn(run_generator(
+                registered.clone(),
+                pool.clone(),
+                signal_tx.clone(),
+            ));
+        }
+    }
+
+    fn find(&self, pred: impl Fn(&dyn QuantSignalGenerator) -> bool) -> Option<&Arc<Registered>> {
+        self.generators.iter().find(|r| pred(r.generator.as_ref()))
+    }
+}
+
+async fn run_generator(
+    registered: Arc<Registered>,
+    pool: PgPool,
+    signal_tx: broadcast::Sender<QuantSignal>,
+) {
+    let generator = registered.generator.clone();
+    tokio::time::sleep(generator.startup_delay()).await;
+
+    loop {
+        if registered.enabled.load(Ordering::Relaxed) {
+            run_cycle(&registered, &pool, &signal_tx).await;
+        }
+        tokio::time::sleep(generator.poll_interval()).await;
+    }
+}
+
+async fn run_cycle(
+    registered: &Registered,
+    pool: &PgPool,
+    signal_tx: &broadcast::Sender<QuantSignal>,
+) {
+    let generator = &registered.generator;
+    let ctx = GeneratorContext {
+        pool: pool.clone(),
+        now: Utc::now(),
+    };
+    let result = generator.generate(&ctx).await;
+
+    let mut stats = registered.stats.write().await;
+    stats.last_run_at = Some(ctx.now);
+    match result {
+        Ok(signals) => {
+            let count = signals.len();
+            for signal in signals {
+                if let Err(e) = signal_tx.send(signal) {
+                    debug!(generator = generator.name(), error = %e, "No quant executor subscribers");
+                }
+            }
+            stats.last_success_at = Some(ctx.now);
+            stats.last_error = None;
+            stats.consecutive_failures = 0;
+            stats.last_signal_count = count;
+            stats.total_signals += count as u64;
+            if count > 0 {
+                info!(
+                    generator = generator.name(),
+                    signals = count,
+                    "Signal generator emitted signals"
+                );
+            } else {
+                debug!(
+                    generator = generator.name(),
+                    "Signal generator: no qualifying markets this cycle"
+                );
+            }
+        }
+        Err(e) => {
+            stats.last_error = Some(e.to_string());
+            stats.consecutive_failures += 1;
+            stats.last_signal_count = 0;
+            warn!(
+                generator = generator.name(),
+                consecutive_failures = stats.consecutive_failures,
+                error = %e,
+                "Signal generator cycle failed"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signals::generator::GeneratorError;
+    use async_trait::async_trait;
+    use polymarket_core::types::signal::SignalDirection;
+    use rust_decimal::Decimal;
+    use std::time;
+
+    struct Fixed {
+        kind: QuantSignalKind,
+        fail: bool,
+    }
+
+    #[async_trait]
+    impl QuantSignalGenerator for Fixed {
+        fn kind(&self) -> QuantSignalKind {
+            self.kind
+        }
+        fn config_keys(&self) -> &'static [&'static str] {
+            &[]
+        }
+        fn enabled(&self) -> bool {
+            true
+        }
+        fn poll_interval(&self) -> time::Duration {
+            time::Duration::from_secs(30)
+        }
+        fn allocation(&self) -> f64 {
+            0.5
+        }
+        async fn generate(
+            &self,
+            ctx: &GeneratorContext,
+        ) -> Result<Vec<QuantSignal>, GeneratorError> {
+            if self.fail {
+                return Err("feature table missing".into());
+            }
+            Ok(vec![QuantSignal::new(
+                self.kind,
+                "0xabc".to_string(),
+                SignalDirection::BuyYes,
+                0.7,
+                Decimal::new(30, 0),
+                ctx.now + chrono::Duration::minutes(5),
+            )])
+        }
+    }
+
+    fn lazy_pool() -> PgPool {
+        sqlx::postgres::PgPoolOptions::new()
+            .connect_lazy("postgres://localhost/unused")
+            .unwrap()
+    }
+
+    #[test]
+    fn test_builtin_generators_cover_every_kind_and_known_settings() {
+        let registry = SignalGeneratorRegistry::from_env();
+        assert_eq!(registry.kinds(), QuantSignalKind::ALL.to_vec());
+
+        for kind in registry.kinds() {
+            let generator = registry.get(kind).unwrap();
+            for key in generator.config_keys() {
+                assert!(
+                    polymarket_core::settings::schema::lookup(key).is_some(),
+                    "{} reads unknown setting {key}",
+                    generator.name()
+                );
+            }
+        }
+
+        let allocations = registry.allocations();
+        assert_eq!(allocations[&QuantSignalKind::Flow], 0.40);
+        let total: f64 = allocations.values().sum();
+        assert!((total - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_toggle_by_name() {
+        let registry = SignalGeneratorRegistry::new().with(Fixed {
+            kind: QuantSignalKind::Flow,
+            fail: false,
+        });
+        assert!(registry.is_enabled(QuantSignalKind::Flow));
+        assert!(registry.set_enabled("flow", false));
+        assert!(!registry.is_enabled(QuantSignalKind::Flow));
+        assert!(!registry.set_enabled("unknown", true));
+        assert!(!registry.is_enabled(QuantSignalKind::MeanReversion));
+    }
+
+    #[tokio::test]
+    async fn test_cycle_forwards_signals_and_tracks_failures() {
+        let registry = SignalGeneratorRegistry::new()
+            .with(Fixed {
+                kind: QuantSignalKind::Flow,
+                fail: false,
+            })
+            .with(Fixed {
+                kind: QuantSignalKind::CrossMarket,
+                fail: true,
+            });
+        let (tx, mut rx) = broadcast::channel(8);
+        let pool = lazy_pool();
+        for registered in &registry.generators {
+            run_cycle(registered, &pool, &tx).await;
+            run_cycle(registered, &pool, &tx).await;
+        }
+
+        assert_eq!(rx.recv().await.unwrap().kind, QuantSignalKind::Flow);
+        let health = registry.health().await;
+        assert_eq!(health[0].total_signals, 2);
+        assert_eq!(health[0].consecutive_failures, 0);
+        assert_eq!(health[1].consecutive_failures, 2);
+        assert_eq!(
+            health[1].last_error.as_deref(),
+            Some("feature table missing")
+        );
+        assert!(health[1].last_success_at.is_none());
+    }
+}
@@ -11,14 +11,30 @@
 //! Direction: towards the favored side (price > 0.50 → BuyYes, else BuyNo)
 //! Confidence: based on days remaining + volume trend
 //! Expiry: 60 minutes (longer horizon strategy)
+//! Exit: the lean away from 0.50 decays to half its entry size
+//!
+//! Confidence is discounted by the category's resolution risk (favourites
+//! losing more often than priced, late payouts), and the expected capital
+//! lockup cost rides along in the metadata for sizing. See
+//! [`crate::resolution_risk`].
+//!
+//! Streamed markets are also scored live from their mid, so a market that
+//! crosses the deviation threshold between polls fires within seconds.
 
-use chrono::{Duration, Utc};
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, Utc};
 use polymarket_core::types::signal::{QuantSignal, QuantSignalKind, SignalDirection};
 use rust_decimal::Decimal;
 use sqlx::PgPool;
+use std::sync::RwLock;
 use std::time;
-use tokio::sync::broadcast;
-use tracing::{debug, info, warn};
+use tracing::debug;
+
+use super::features::MarketFeatures;
+use super::generator::{
+    json_decimal, ExitContext, GeneratorContext, GeneratorError, QuantSignalGenerator,
+};
+use crate::resolution_risk::{ResolutionRiskConfig, ResolutionRiskModel};
 
 /// Configuration for the resolution proximity signal generator.
 #[derive(Debug, Clone)]
@@ -39,30 +55,25 @@ pub struct ResolutionSignalConfig {
     pub base_position_size_usd: Decimal,
     /// Signal expiry in minutes.
     pub expiry_minutes: i64,
+    /// Share of quant capital allocated to resolution proximity signals.
+    pub allocation_pct: f64,
+    /// Resolution-risk model settings.
+    pub risk: ResolutionRiskConfig,
 }
 
 impl ResolutionSignalConfig {
     pub fn from_env() -> Self {
         Self {
-            enabled: std::env::var("RESOLUTION_SIGNAL_ENABLED")
-                .map(|v| v == "true")
-                .unwrap_or(true),
-            interval_secs: std::env::var("RESOLUTION_SIGNAL_INTERVAL_SECS")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(900),
+            enabled: polymarket_core::settings::get("RESOLUTION_SIGNAL_ENABLED"),
+            interval_secs: polymarket_core::settings::get("RESOLUTION_SIGNAL_INTERVAL_SECS"),
             min_days_remaining: 1,
             max_days_remaining: 7,
             min_price_deviation: 0.15,
-            min_volume: std::env::var("RESOLUTION_MIN_VOLUME_USD")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(Decimal::new(1000, 0)),
-            base_position_size_usd: std::env::var("QUANT_BASE_POSITION_SIZE")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(Decimal::new(30, 0)),
+            min_volume: polymarket_core::settings::get("RESOLUTION_MIN_VOLUME_USD"),
+            base_position_size_usd: polymarket_core::settings::get("QUANT_BASE_POSITION_SIZE"),
             expiry_minutes: 60,
+            allocation_pct: polymarket_core::settings::get("QUANT_RESOLUTION_ALLOCATION_PCT"),
+            risk: ResolutionRiskConfig::from_env(),
         }
     }
 }
@@ -72,72 +83,123 @@ impl ResolutionSignalConfig {
 struct ResolutionRow {
     condition_id: String,
     question: String,
+    category: Option<String>,
     end_date: chrono::DateTime<Utc>,
     volume: Option<Decimal>,
     /// Most recent YES mid-price from orderbook snapshots.
     yes_price: Option<f64>,
 }
 
-/// Spawn the resolution proximity signal generator.
-pub fn spawn_resolution_signal_generator(
+/// Settings keys read by [`ResolutionSignalConfig::from_env`].
+const CONFIG_KEYS: &[&str] = &[
+    "QUANT_BASE_POSITION_SIZE",
+    "QUANT_RESOLUTION_ALLOCATION_PCT",
+    "RESOLUTION_MIN_VOLUME_USD",
+    "RESOLUTION_RISK_CAPITAL_COST_APR",
+    "RESOLUTION_RISK_DELAY_WEIGHT",
+    "RESOLUTION_RISK_ENABLED",
+    "RESOLUTION_RISK_GRACE_HOURS",
+    "RESOLUTION_RISK_INTERVAL_SECS",
+    "RESOLUTION_RISK_LOOKBACK_DAYS",
+    "RESOLUTION_RISK_MAX_CHECKS",
+    "RESOLUTION_RISK_PRIOR_STRENGTH",
+    "RESOLUTION_SIGNAL_ENABLED",
+    "RESOLUTION_SIGNAL_INTERVAL_SECS",
+];
+
+/// Resolution proximity strategy.
+pub struct ResolutionSignalGenerator {
     config: ResolutionSignalConfig,
-    pool: PgPool,
-    signal_tx: broadcast::Sender<QuantSignal>,
-) {
-    if !config.enabled {
-        info!("Resolution signal generator disabled (RESOLUTION_SIGNAL_ENABLED != true)");
-        return;
-    }
+    /// Last fitted resolution-risk model, shared with streamed scoring.
+    risk: RwLock<ResolutionRiskModel>,
+}
 
-    info!(
-        interval_secs = config.interval_secs,
-        min_days = config.min_days_remaining,
-        max_days = config.max_days_remaining,
-        min_price_deviation = config.min_price_deviation,
-        min_volume = %config.min_volume,
-        "Spawning resolution proximity signal generator"
-    );
+impl ResolutionSignalGenerator {
+    pub fn new(config: ResolutionSignalConfig) -> Self {
+        Self {
+            config,
+            risk: RwLock::new(ResolutionRiskModel::default()),
+        }
+    }
 
-    tokio::spawn(generator_loop(config, pool, signal_tx));
+    /// The risk model, refitted from `market_resolutions` once it is stale.
+    async fn risk_model(
+        &self,
+        pool: &PgPool,
+        now: DateTime<Utc>,
+    ) -> Result<ResolutionRiskModel, GeneratorError> {
+        let current = self.risk.read().unwrap_or_else(|e| e.into_inner()).clone();
+        if !current.is_stale(now, &self.config.risk) {
+            return Ok(current);
+        }
+        let model = ResolutionRiskModel::load(pool, &self.config.risk, now).await?;
+        *self.risk.write().unwrap_or_else(|e| e.into_inner()) = model.clone();
+        Ok(model)
+    }
 }
 
-async fn generator_loop(
-    config: ResolutionSignalConfig,
-    pool: PgPool,
-    signal_tx: broadcast::Sender<QuantSignal>,
-) {
-    let interval = time::Duration::from_secs(config.interval_secs);
-
-    // Startup delay — let market metadata populate first
-    tokio::time::sleep(time::Duration::from_secs(90)).await;
-
-    loop {
-        match scan_and_emit(&config, &pool, &signal_tx).await {
-            Ok(count) => {
-                if count > 0 {
-                    info!(
-                        signals = count,
-                        "Resolution signal generator emitted signals"
-                    );
-                } else {
-                    debug!("Resolution signal generator: no qualifying markets this cycle");
-                }
-            }
-            Err(e) => {
-                warn!(error = %e, "Resolution signal generator cycle failed");
-            }
-        }
+#[async_trait]
+impl QuantSignalGenerator for ResolutionSignalGenerator {
+    fn kind(&self) -> QuantSignalKind {
+        QuantSignalKind::ResolutionProximity
+    }
+
+    fn config_keys(&self) -> &'static [&'static str] {
+        CONFIG_KEYS
+    }
+
+    fn enabled(&self) -> bool {
+        self.config.enabled
+    }
 
-        tokio::time::sleep(interval).await;
+    fn poll_interval(&self) -> time::Duration {
+        time::Duration::from_secs(self.config.interval_secs)
     }
+
+    /// Let market metadata populate first.
+    fn startup_delay(&self) -> time::Duration {
+        time::Duration::from_secs(90)
+    }
+
+    fn allocation(&self) -> f64 {
+        self.config.allocation_pct
+    }
+
+    async fn generate(&self, ctx: &GeneratorContext) -> Result<Vec<QuantSignal>, GeneratorError> {
+        let risk = self.risk_model(&ctx.pool, ctx.now).await?;
+        scan(&self.config, &risk, &ctx.pool, ctx.now).await
+    }
+
+    fn on_features(&self, features: &MarketFeatures) -> Option<QuantSignal> {
+        let risk = self.risk.read().unwrap_or_else(|e| e.into_inner());
+        stream_signal(&self.config, &risk, features)
+    }
+
+    async fn should_exit(
+        &self,
+        _pool: &PgPool,
+        position: &ExitContext<'_>,
+    ) -> anyhow::Result<bool> {
+        Ok(lean_decayed(position))
+    }
+}
+
+/// Whether the YES price has come back to within half the entry deviation from 0.50.
+fn lean_decayed(position: &ExitContext<'_>) -> bool {
+    let Some(entry_deviation) = json_decimal(position.metadata, "deviation").map(|d| d.abs())
+    else {
+        return false;
+    };
+    let current_deviation = (position.current_yes - Decimal::new(50, 2)).abs();
+    current_deviation <= entry_deviation / Decimal::new(2, 0)
 }
 
-async fn scan_and_emit(
+async fn scan(
     config: &ResolutionSignalConfig,
+    risk: &ResolutionRiskModel,
     pool: &PgPool,
-    signal_tx: &broadcast::Sender<QuantSignal>,
-) -> Result<usize, Box<dyn std::error::Error + Send + Sync>> {
-    let now = Utc::now();
+    now: DateTime<Utc>,
+) -> Result<Vec<QuantSignal>, GeneratorError> {
     let min_end = now + Duration::days(config.min_days_remaining);
     let max_end = now + Duration::days(config.max_days_remaining);
 
@@ -149,6 +211,7 @@ async fn scan_and_emit(
         SELECT
             mm.condition_id,
             mm.question,
+            mm.category,
             mm.end_date,
             mm.volume,
             ob.yes_price
@@ -179,7 +242,7 @@ async fn scan_and_emit(
     .fetch_all(pool)
     .await?;
 
-    let mut emitted = 0;
+    let mut signals = Vec::new();
 
     for row in &rows {
         let yes_price = match row.yes_price {
@@ -198,22 +261,16 @@ async fn scan_and_emit(
             SignalDirection::BuyNo
         };
 
-        // Confidence model:
-        // - Base: how far from 0.50 (more conviction = higher confidence)
-        // - Time decay: closer to resolution = more conviction
-        // - Volume: higher volume = more reliable signal
         let deviation = (yes_price - 0.5).abs();
-        let time_factor = (1.0 / days_remaining.sqrt()).clamp(0.0, 1.0);
-        let volume_factor = match row.volume {
-            Some(v) => {
-                let v_f64 = decimal_to_f64(v);
-                ((v_f64 - 1000.0) / 49000.0).clamp(0.0, 1.0) // $1K=0, $50K=1
-            }
-            None => 0.0,
-        };
-
-        let confidence =
-            (deviation * 1.5 * 0.5 + time_factor * 0.35 + volume_factor * 0.15).clamp(0.0, 1.0);
+        let (confidence, time_factor) =
+            resolution_confidence(yes_price, days_remaining, row.volume.map(decimal_to_f64));
+        let resolution_risk = risk.estimate(
+            row.category.as_deref(),
+            yes_price,
+            days_remaining,
+            &config.risk,
+        );
+        let confidence = resolution_risk.adjust_confidence(confidence, &config.risk);
 
         let expiry = now + Duration::minutes(config.expiry_minutes);
 
@@ -233,6 +290,11 @@ async fn scan_and_emit(
             "volume": row.volume.map(decimal_to_f64),
             "deviation": deviation,
             "time_factor": time_factor,
+            "risk_category": row.category,
+            "reversal_probability": resolution_risk.reversal_probability,
+            "delay_probability": resolution_risk.delay_probability,
+            "expected_lockup_days": resolution_risk.expected_lockup_days,
+            "capital_lockup_cost": resolution_risk.capital_lockup_cost,
         }));
 
         debug!(
@@ -241,14 +303,90 @@ async fn scan_and_emit(
             confidence = signal.confidence,
             days_remaining = days_remaining,
             yes_price = yes_price,
+            reversal_probability = resolution_risk.reversal_probability,
+            delay_probability = resolution_risk.delay_probability,
             "Resolution proximity signal generated"
         );
 
-        let _ = signal_tx.send(signal);
-        emitted += 1;
+        signals.push(signal);
+    }
+
+    Ok(signals)
+}
+
+/// Confidence and its time factor.
+///
+/// - Base: how far from 0.50 (more conviction = higher confidence)
+/// - Time decay: closer to resolution = more conviction
+/// - Volume: higher volume = more reliable signal
+fn resolution_confidence(yes_price: f64, days_remaining: f64, volume: Option<f64>) -> (f64, f64) {
+    let deviation = (yes_price - 0.5).abs();
+    let time_factor = (1.0 / days_remaining.sqrt()).clamp(0.0, 1.0);
+    // $1K=0, $50K=1
+    let volume_factor = volume.map_or(0.0, |v| ((v - 1000.0) / 49000.0).clamp(0.0, 1.0));
+    let confidence =
+        (deviation * 1.5 * 0.5 + time_factor * 0.35 + volume_factor * 0.15).clamp(0.0, 1.0);
+    (confidence, time_factor)
+}
+
+/// Score a streamed market from its live mid and end date.
+fn stream_signal(
+    config: &ResolutionSignalConfig,
+    risk: &ResolutionRiskModel,
+    features: &MarketFeatures,
+) -> Option<QuantSignal> {
+    let end_date = features.end_date?;
+    let remaining = end_date.signed_duration_since(features.at);
+    if remaining < Duration::days(config.min_days_remaining)
+        || remaining > Duration::days(config.max_days_remaining)
+        || features.volume_usd < decimal_to_f64(config.min_volume)
+    {
+        return None;
+    }
+    let yes_price = features.mid;
+    let deviation = (yes_price - 0.5).abs();
+    if deviation < config.min_price_deviation {
+        return None;
     }
 
-    Ok(emitted)
+    let hours_remaining = remaining.num_hours().max(1) as f64;
+    let days_remaining = hours_remaining / 24.0;
+    let (confidence, time_factor) =
+        resolution_confidence(yes_price, days_remaining, Some(features.volume_usd));
+    let category = features.category.as_deref();
+    let resolution_risk = risk.estimate(category, yes_price, days_remaining, &config.risk);
+    let confidence = resolution_risk.adjust_confidence(confidence, &config.risk);
+    let direction = if yes_price > 0.5 {
+        SignalDirection::BuyYes
+    } else {
+        SignalDirection::BuyNo
+    };
+
+    Some(
+        QuantSignal::new(
+            QuantSignalKind::ResolutionProximity,
+            features.market_id.clone(),
+            direction,
+            confidence,
+            config.base_position_size_usd,
+            features.at + Duration::minutes(config.expiry_minutes),
+        )
+        .with_metadata(serde_json::json!({
+            "source": "stream",
+            "question": features.question,
+            "yes_price": yes_price,
+            "days_remaining": days_remaining,
+            "hours_remaining": hours_remaining,
+            "volume": features.volume_usd,
+            "deviation": deviation,
+            "time_factor": time_factor,
+            "risk_category": category,
+            "reversal_probability": resolution_risk.reversal_probability,
+            "delay_probability": resolution_risk.delay_probability,
+            "expected_lockup_days": resolution_risk.expected_lockup_days,
+            "capital_lockup_cost": resolution_risk.capital_lockup_cost,
+        })),
+    )
 }
 
 /// Convert Decimal to f64 for confidence calculations.
@@ -260,6 +398,7 @@ fn decimal_to_f64(d: Decimal) -> f64 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::resolution_risk::ResolutionObservation;
 
     #[test]
     fn test_config_defaults() {
@@ -274,19 +413,91 @@ mod tests {
     #[test]
     fn test_confidence_model() {
         // Market at 0.80 YES, 2 days remaining, $10K volume
-        let deviation: f64 = 0.30; // |0.80 - 0.50|
-        let days_remaining: f64 = 2.0;
-        let time_factor = (1.0 / days_remaining.sqrt()).clamp(0.0, 1.0);
-        let volume_factor = ((10000.0_f64 - 1000.0) / 49000.0).clamp(0.0, 1.0);
-
-        let confidence =
-            (deviation * 1.5 * 0.5 + time_factor * 0.35 + volume_factor * 0.15).clamp(0.0, 1.0);
+        let (confidence, _) = resolution_confidence(0.80, 2.0, Some(10_000.0));
 
         // Should be reasonably high confidence
         assert!(confidence > 0.45);
         assert!(confidence < 0.85);
     }
 
+    #[test]
+    fn test_stream_signal_respects_resolution_window() {
+        let config = ResolutionSignalConfig::from_env();
+        let now = Utc::now();
+        let features = MarketFeatures {
+            market_id: "0x1234".to_string(),
+            question: "Will it rain?".to_string(),
+            end_date: Some(now + Duration::days(2)),
+            category: Some("Weather".to_string()),
+            volume_usd: 10_000.0,
+            at: now,
+            window: Duration::minutes(15),
+            mid: 0.25,
+            microprice: 0.25,
+            spread: 0.02,
+            book_imbalance: 0.0,
+            window_open_mid: 0.30,
+            realized_vol: 0.01,
+            buy_volume: 0.0,
+            sell_volume: 0.0,
+            trade_count: 0,
+        };
+
+        let signal = stream_signal(&config, &ResolutionRiskModel::default(), &features).unwrap();
+        assert_eq!(signal.direction, SignalDirection::BuyNo);
+        assert_eq!(signal.metadata["deviation"], 0.25);
+        assert_eq!(signal.metadata["risk_category"], "Weather");
+        assert!(signal.metadata["capital_lockup_cost"].as_f64().unwrap() > 0.0);
+
+        // Weather favourites that lose half the time and pay out late score lower.
+        let end_date = now - Duration::days(30);
+        let history: Vec<_> = (0..40)
+            .map(|i| ResolutionObservation {
+                category: Some("Weather".to_string()),
+                end_date,
+                favourite_yes_price: Some(0.25),
+                resolved_at: Some(end_date + Duration::days(4)),
+                yes_won: Some(i % 2 == 0),
+                first_seen_at: end_date - Duration::days(7),
+            })
+            .collect();
+        let risky = ResolutionRiskModel::fit(&history, now, &config.risk);
+        let discounted = stream_signal(&config, &risky, &features).unwrap();
+        assert!(discounted.confidence < signal.confidence - 0.1);
+        assert!(
+            discounted.metadata["expected_lockup_days"]
+                .as_f64()
+                .unwrap()
+                > signal.metadata["expected_lockup_days"].as_f64().unwrap()
+        );
+
+        let far = MarketFeatures {
+            end_date: Some(now + Duration::days(30)),
+            ..features.clone()
+        };
+        assert!(stream_signal(&config, &ResolutionRiskModel::default(), &far).is_none());
+        let undecided = MarketFeatures {
+            mid: 0.45,
+            ..features
+        };
+        assert!(stream_signal(&config, &ResolutionRiskModel::default(), &undecided).is_none());
+    }
+
+    #[test]
+    fn test_exit_triggers_when_lean_decays_by_half() {
+        let metadata = serde_json::json!({ "deviation": "0.20" });
+        let position = |current_yes: i64| ExitContext {
+            market_id: "0x1234",
+            direction: SignalDirection::BuyYes,
+            metadata: &metadata,
+            current_yes: Decimal::new(current_yes, 2),
+            current_no: Decimal::new(100 - current_yes, 2),
+        };
+
+        assert!(lean_decayed(&position(58)));
+        assert!(!lean_decayed(&position(70)));
+    }
+
     #[test]
     fn test_direction_logic() {
         // YES at 0.75 → should BuyYes (market leans YES near resolution)
@@ -0,0 +1,297 @@
+//! Streaming signal pipeline.
+//!
+//! Subscribes to the CLOB market channel for the most-traded binary markets
+//! (the same book and trade-print streams arb-monitor consumes), keeps a
+//! rolling [`MarketWindow`] per market, and on every change hands the
+//! market's features to the registry's generators. Signals go out on the
+//! same channel as polled ones, so the executor persists them to
+//! `quant_signals` as before; the polling generators and the feature table
+//! writers keep running alongside for history and for what needs SQL.
+
+use chrono::{DateTime, Duration, Utc};
+use polymarket_core::api::clob::{MarketChannelEvent, OrderBookSubscription};
+use polymarket_core::api::{ClobClient, GammaClient};
+use polymarket_core::types::signal::{QuantSignal, QuantSignalKind};
+use polymarket_core::types::{Market, OrderSide};
+use rust_decimal::prelude::ToPrimitive;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time;
+use tokio::sync::broadcast;
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+
+use super::features::{MarketInfo, MarketWindow};
+use super::registry::SignalGeneratorRegistry;
+use crate::market_making::BookTop;
+
+/// Configuration for the streaming signal pipeline.
+#[derive(Debug, Clone)]
+pub struct StreamingSignalConfig {
+    pub enabled: bool,
+    /// Most-traded binary markets to stream.
+    pub max_markets: usize,
+    /// Trailing window for rolling features.
+    pub window_secs: u64,
+    /// Minimum time between evaluations of one market.
+    pub min_eval_ms: u64,
+    /// Minimum time between streamed signals of one kind on one market.
+    pub cooldown_secs: u64,
+    /// How often the market universe is re-ranked and resubscribed.
+    pub universe_refresh_secs: u64,
+}
+
+impl StreamingSignalConfig {
+    pub fn from_env() -> Self {
+        Self {
+            enabled: polymarket_core::settings::get("STREAMING_SIGNALS_ENABLED"),
+            max_markets: polymarket_core::settings::get("STREAMING_SIGNALS_MAX_MARKETS"),
+            window_secs: polymarket_core::settings::get("STREAMING_SIGNALS_WINDOW_SECS"),
+            min_eval_ms: polymarket_core::settings::get("STREAMING_SIGNALS_MIN_EVAL_MS"),
+            cooldown_secs: polymarket_core::settings::get("STREAMING_SIGNALS_COOLDOWN_SECS"),
+            universe_refresh_secs: polymarket_core::settings::get(
+                "STREAMING_SIGNALS_UNIVERSE_REFRESH_SECS",
+            ),
+        }
+    }
+}
+
+/// One streamed market's tokens and rolling state.
+struct StreamedMarket {
+    yes_token: String,
+    window: MarketWindow,
+    last_eval: Option<time::Instant>,
+    /// Last streamed signal per kind, for the cooldown.
+    last_fired: HashMap<QuantSignalKind, DateTime<Utc>>,
+}
+
+/// Spawn the streaming pipeline. Does nothing unless enabled.
+pub fn spawn_streaming_signals(
+    config: StreamingSignalConfig,
+    registry: Arc<SignalGeneratorRegistry>,
+    clob_client: Arc<ClobClient>,
+    signal_tx: broadcast::Sender<QuantSignal>,
+) -> Option<JoinHandle<()>> {
+    if !config.enabled {
+        info!("Streaming signals disabled (STREAMING_SIGNALS_ENABLED != true)");
+        return None;
+    }
+    info!(
+        max_markets = config.max_markets,
+        window_secs = config.window_secs,
+        cooldown_secs = config.cooldown_secs,
+        "Spawning streaming signal pipeline"
+    );
+    Some(tokio::spawn(run(config, registry, clob_client, signal_tx)))
+}
+
+async fn run(
+    config: StreamingSignalConfig,
+    registry: Arc<SignalGeneratorRegistry>,
+    clob_client: Arc<ClobClient>,
+    signal_tx: broadcast::Sender<QuantSignal>,
+) {
+    let gamma = GammaClient::new(None);
+    let window = Duration::seconds(config.window_secs as i64);
+    let mut markets: HashMap<String, StreamedMarket> = HashMap::new();
+    let mut token_to_market: HashMap<String, String> = HashMap::new();
+    refresh_universe(&config, &gamma, window, &mut markets, &mut token_to_market).await;
+    if markets.is_empty() {
+        warn!("Streaming signals: no markets to stream");
+        return;
+    }
+
+    let mut subscription: OrderBookSubscription = match clob_client
+        .subscribe_orderbook(token_to_market.keys().cloned().collect())
+        .await
+    {
+        Ok(subscription) => subscription,
+        Err(e) => {
+            warn!(error = %e, "Streaming signals: order book subscription failed");
+            return;
+        }
+    };
+    let mut events = subscription.subscribe_events();
+    let mut refresh = tokio::time::interval(time::Duration::from_secs(
+        config.universe_refresh_secs.max(60),
+    ));
+    refresh.tick().await;
+
+    loop {
+        let changed = tokio::select! {
+            update = subscription.recv() => {
+                let Some(update) = update else {
+                    warn!("Streaming signals: order book stream closed");
+                    break;
+                };
+                let Some(market) = token_to_market.get(&update.asset_id).and_then(|id| markets.get_mut(id)) else {
+                    continue;
+                };
+                if update.asset_id != market.yes_token {
+                    continue;
+                }
+                market.window.on_book(BookTop::from_levels(&update.bids, &update.asks), Utc::now());
+                Some(market.window.info().market_id.clone())
+            }
+            event = events.recv() => match event {
+                Ok(MarketChannelEvent::Trade(print)) => {
+                    let Some(market) = token_to_market.get(&print.asset_id).and_then(|id| markets.get_mut(id)) else {
+                        continue;
+                    };
+                    // Express the print in YES terms: a NO buy is a YES sell.
+                    let price = print.price.to_f64().unwrap_or(0.0);
+                    let taker_buys = print.side == OrderSide::Buy;
+                    let (yes_price, taker_buys_yes) = if print.asset_id == market.yes_token {
+                        (price, taker_buys)
+                    } else {
+                        (1.0 - price, !taker_buys)
+                    };
+                    market.window.on_trade(yes_price, print.size.to_f64().unwrap_or(0.0), taker_buys_yes, print.timestamp);
+                    Some(market.window.info().market_id.clone())
+                }
+                Ok(MarketChannelEvent::TickSizeChange(_)) => None,
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    debug!(skipped, "Streaming signals: trade print stream lagged");
+                    None
+                }
+                Err(broadcast::error::RecvError::Closed) => {
+                    events = subscription.subscribe_events();
+                    None
+                }
+            },
+            _ = refresh.tick() => {
+                refresh_universe(&config, &gamma, window, &mut markets, &mut token_to_market).await;
+                let delta = subscription.set_assets(token_to_market.keys().cloned().collect());
+                info!(
+                    markets = markets.len(),
+                    added = delta.added,
+                    removed = delta.removed,
+                    "Streaming signals: universe refreshed"
+                );
+                None
+            }
+        };
+
+        let Some(market_id) = changed else {
+            continue;
+        };
+        if let Some(market) = markets.get_mut(&market_id) {
+            evaluate(&config, &registry, market, &signal_tx);
+        }
+    }
+}
+
+/// Score one market if it has not been scored too recently.
+fn evaluate(
+    config: &StreamingSignalConfig,
+    registry: &SignalGeneratorRegistry,
+    market: &mut StreamedMarket,
+    signal_tx: &broadcast::Sender<QuantSignal>,
+) {
+    let now = time::Instant::now();
+    let min_eval = time::Duration::from_millis(config.min_eval_ms);
+    if market
+        .last_eval
+        .is_some_and(|last| now.saturating_duration_since(last) < min_eval)
+    {
+        return;
+    }
+    market.last_eval = Some(now);
+
+    let at = Utc::now();
+    let Some(features) = market.window.snapshot(at) else {
+        return;
+    };
+    let cooldown = Duration::seconds(config.cooldown_secs as i64);
+    let last_fired = &market.last_fired;
+    let signals = registry.evaluate_features(&features, |kind| {
+        last_fired
+            .get(&kind)
+            .is_none_or(|fired| at.signed_duration_since(*fired) >= cooldown)
+    });
+
+    for signal in signals {
+        info!(
+            market_id = %signal.condition_id,
+            kind = signal.kind.as_str(),
+            direction = signal.direction.as_str(),
+            confidence = signal.confidence,
+            "Streamed quant signal"
+        );
+        market.last_fired.insert(signal.kind, at);
+        if let Err(e) = signal_tx.send(signal) {
+            debug!(error = %e, "No quant executor subscribers");
+        }
+    }
+}
+
+/// Re-rank tradable binary markets by volume and keep the top `max_markets`,
+/// preserving the rolling windows of markets that stay in.
+async fn refresh_universe(
+    config: &StreamingSignalConfig,
+    gamma: &GammaClient,
+    window: Duration,
+    markets: &mut HashMap<String, StreamedMarket>,
+    token_to_market: &mut HashMap<String, String>,
+) {
+    let mut candidates = match gamma.get_all_tradable_markets(200).await {
+        Ok(candidates) => candidates,
+        Err(e) => {
+            warn!(error = %e, "Streaming signals: failed to load markets, keeping current set");
+            return;
+        }
+    };
+    candidates.retain(|m| m.outcomes.len() == 2);
+    candidates.sort_by_key(|m| std::cmp::Reverse(m.volume));
+    candidates.truncate(config.max_markets);
+
+    let mut next = HashMap::with_capacity(candidates.len());
+    token_to_market.clear();
+    for market in candidates {
+        let Some((yes_token, no_token)) = binary_tokens(&market) else {
+            continue;
+        };
+        let info = MarketInfo {
+            market_id: market.id.clone(),
+            question: market.question.clone(),
+            end_date: market.end_date,
+            category: market.category.clone(),
+            volume_usd: market.volume.to_f64().unwrap_or(0.0),
+        };
+        let streamed = match markets.remove(&market.id) {
+            Some(mut existing) => {
+                existing.window.set_info(info);
+                existing
+            }
+            None => StreamedMarket {
+                yes_token: yes_token.clone(),
+                window: MarketWindow::new(info, window),
+                last_eval: None,
+                last_fired: HashMap::new(),
+            },
+        };
+        token_to_market.insert(yes_token, market.id.clone());
+        token_to_market.insert(no_token, market.id.clone());
+        next.insert(market.id, streamed);
+    }
+    *markets = next;
+}
+
+/// YES and NO token ids, by outcome name or else outcome order.
+fn binary_tokens(market: &Market) -> Option<(String, String)> {
+    let token = |name: &str| {
+        market
+            .outcomes
+            .iter()
+            .find(|o| o.name.eq_ignore_ascii_case(name))
+            .map(|o| o.token_id.clone())
+    };
+    match (token("yes"), token("no")) {
+        (Some(yes), Some(no)) => Some((yes, no)),
+        _ if market.outcomes.len() == 2 => Some((
+            market.outcomes[0].token_id.clone(),
+            market.outcomes[1].token_id.clone(),
+        )),
+        _ => None,
+    }
+}
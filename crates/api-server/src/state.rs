@@ -11,7 +11,9 @@ use auth::jwt::{JwtAuth, JwtConfig};
 use auth::key_vault::KeyVault;
 use auth::rbac::RbacManager;
 use auth::{AuditLogger, AuditStorage, PostgresAuditStorage, TradingWallet};
+use polymarket_core::alerting::AlertRouter;
 use polymarket_core::api::{ClobClient, PolygonClient};
+use polymarket_core::config::AlertsConfig;
 use polymarket_core::types::{ArbOpportunity, QuantSignal};
 use risk_manager::circuit_breaker::{CircuitBreaker, CircuitBreakerConfig};
 use trading_engine::executor::ExecutorConfig;
@@ -19,12 +21,14 @@ use trading_engine::OrderExecutor;
 use wallet_tracker::discovery::WalletDiscovery;
 use wallet_tracker::MarketRegime;
 
-use crate::email::{EmailClient, EmailConfig};
+use crate::email::{EmailAlertSink, EmailClient, EmailConfig};
+use crate::events::EventIngestConfig;
+use crate::signals::SignalGeneratorRegistry;
 use crate::trade_events::TradeEventUpdate;
 use crate::websocket::{OrderbookUpdate, PositionUpdate, SignalUpdate};
 
 async fn resolve_startup_wallet_address(pool: &PgPool) -> Result<Option<String>, sqlx::Error> {
-    if let Ok(address) = std::env::var("TRADING_WALLET_ADDRESS") {
+    if let Some(address) = polymarket_core::settings::get_opt::<String>("TRADING_WALLET_ADDRESS") {
         return Ok(Some(address.to_lowercase()));
     }
 
@@ -62,6 +66,8 @@ pub struct AppState {
     pub audit_logger: Arc<AuditLogger>,
     /// Email client for sending transactional emails.
     pub email_client: Option<Arc<EmailClient>>,
+    /// Operator alert routing (chat, email and webhook sinks).
+    pub alert_router: Arc<AlertRouter>,
     /// CLOB API client for Polymarket.
     pub clob_client: Arc<ClobClient>,
     /// Order execution engine.
@@ -101,12 +107,20 @@ pub struct AppState {
     /// Shared quant executor config for runtime inspection.
     pub quant_executor_config:
         Option<Arc<RwLock<crate::quant_signal_executor::QuantSignalExecutorConfig>>>,
+    /// Quant signal generators: scheduling, runtime enable/disable, health and exit rules.
+    pub signal_generators: Arc<SignalGeneratorRegistry>,
+    /// External event ingestion settings, shared with the event webhook.
+    pub event_ingest: Arc<EventIngestConfig>,
+    /// Market matcher for webhook events, reloaded once per ingestion interval.
+    pub event_matcher: Arc<crate::events::MarketMatcherCache>,
     /// Heartbeat timestamp (epoch secs) from arb executor loop — 0 means never updated.
     pub arb_executor_heartbeat: Arc<AtomicI64>,
     /// Heartbeat timestamp (epoch secs) from exit handler loop — 0 means never updated.
     pub exit_handler_heartbeat: Arc<AtomicI64>,
     /// Heartbeat timestamp (epoch secs) from quant signal executor — 0 means never updated.
     pub quant_executor_heartbeat: Arc<AtomicI64>,
+    /// Heartbeat timestamp (epoch secs) from the market maker — 0 means never updated.
+    pub market_maker_heartbeat: Arc<AtomicI64>,
 }
 
 impl AppState {
@@ -122,7 +136,8 @@ impl AppState {
         quant_signal_tx: broadcast::Sender<QuantSignal>,
     ) -> anyhow::Result<Self> {
         // Resolve encryption key for sensitive DB fields
-        let encryption_key = std::env::var("ENCRYPTION_KEY").unwrap_or_else(|_| jwt_secret.clone());
+        let encryption_key = polymarket_core::settings::get_opt("ENCRYPTION_KEY")
+            .unwrap_or_else(|| jwt_secret.clone());
 
         // Create JWT auth handler
         let jwt_config = JwtConfig {
@@ -159,13 +174,11 @@ impl AppState {
         let audit_logger = Arc::new(AuditLogger::new(audit_storage));
 
         // Create CLOB client
-        let clob_url = std::env::var("POLYMARKET_CLOB_URL").ok();
+        let clob_url = polymarket_core::settings::get_opt::<String>("POLYMARKET_CLOB_URL");
         let clob_client = Arc::new(ClobClient::new(clob_url, None));
 
         // Create order executor
-        let live_trading_env = std::env::var("LIVE_TRADING")
-            .map(|v| v == "true")
-            .unwrap_or(false);
+        let live_trading_env = polymarket_core::settings::get("LIVE_TRADING");
         let live_trading_workspace = match crate::runtime_sync::canonical_workspace_live_enabled(
             &pool,
         )
@@ -188,10 +201,7 @@ impl AppState {
         }
         let executor_config = ExecutorConfig {
             live_trading,
-            min_book_depth: std::env::var("EXECUTOR_MIN_BOOK_DEPTH")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(rust_decimal::Decimal::new(100, 0)),
+            min_book_depth: polymarket_core::settings::get("EXECUTOR_MIN_BOOK_DEPTH"),
             ..Default::default()
         };
         let order_executor = Arc::new(OrderExecutor::new(clob_client.clone(), executor_config));
@@ -278,12 +288,12 @@ impl AppState {
             // Ensure on-chain Polymarket approvals are set for the trading wallet.
             // This is a one-time operation per wallet; existing approvals are detected and skipped.
             if order_executor.is_live_ready().await {
-                let rpc_url = std::env::var("POLYGON_RPC_URL")
-                    .or_else(|_| {
-                        std::env::var("ALCHEMY_API_KEY")
+                let rpc_url = polymarket_core::settings::get_opt("POLYGON_RPC_URL")
+                    .or_else(|| {
+                        polymarket_core::settings::get_opt::<String>("ALCHEMY_API_KEY")
                             .map(|k| format!("https://polygon-mainnet.g.alchemy.com/v2/{}", k))
                     })
-                    .unwrap_or_else(|_| "https://polygon-rpc.com".to_string());
+                    .unwrap_or_else(|| "https://polygon-rpc.com".to_string());
 
                 // Build a signer from the same key the executor used
                 let approval_wallet = TradingWallet::from_env().ok();
@@ -370,32 +380,14 @@ impl AppState {
         }
 
         // Create circuit breaker for risk management
-        let mut circuit_breaker_config = CircuitBreakerConfig::default();
-        if let Ok(v) = std::env::var("CB_MAX_DAILY_LOSS") {
-            if let Ok(d) = v.parse::<rust_decimal::Decimal>() {
-                circuit_breaker_config.max_daily_loss = d;
-            }
-        }
-        if let Ok(v) = std::env::var("CB_MAX_DRAWDOWN_PCT") {
-            if let Ok(d) = v.parse::<rust_decimal::Decimal>() {
-                circuit_breaker_config.max_drawdown_pct = d;
-            }
-        }
-        if let Ok(v) = std::env::var("CB_MAX_CONSECUTIVE_LOSSES") {
-            if let Ok(n) = v.parse::<u32>() {
-                circuit_breaker_config.max_consecutive_losses = n;
-            }
-        }
-        if let Ok(v) = std::env::var("CB_COOLDOWN_MINUTES") {
-            if let Ok(n) = v.parse::<i64>() {
-                circuit_breaker_config.cooldown_minutes = n;
-            }
-        }
-        if let Ok(v) = std::env::var("CB_HARD_KILL_DRAWDOWN_PCT") {
-            if let Ok(d) = v.parse::<rust_decimal::Decimal>() {
-                circuit_breaker_config.hard_kill_drawdown_pct = d;
-            }
-        }
+        let mut circuit_breaker_config = CircuitBreakerConfig {
+            max_daily_loss: polymarket_core::settings::get("CB_MAX_DAILY_LOSS"),
+            max_drawdown_pct: polymarket_core::settings::get("CB_MAX_DRAWDOWN_PCT"),
+            max_consecutive_losses: polymarket_core::settings::get("CB_MAX_CONSECUTIVE_LOSSES"),
+            cooldown_minutes: polymarket_core::settings::get("CB_COOLDOWN_MINUTES"),
+            hard_kill_drawdown_pct: polymarket_core::settings::get("CB_HARD_KILL_DRAWDOWN_PCT"),
+            ..CircuitBreakerConfig::default()
+        };
 
         // Apply DB overrides (workspace-level CB config takes priority over env vars)
         #[derive(sqlx::FromRow)]
@@ -503,11 +495,15 @@ impl AppState {
             }
         });
 
+        let mut alert_router = AlertRouter::from_env(&AlertsConfig::from_env());
+        if let Some(sink) = email_client.clone().and_then(EmailAlertSink::from_env) {
+            alert_router = alert_router.with_sink(Arc::new(sink));
+        }
+
         // Create shared Redis connection for dynamic config pub/sub
         let redis_conn = {
-            let redis_url = std::env::var("DYNAMIC_TUNER_REDIS_URL")
-                .or_else(|_| std::env::var("REDIS_URL"))
-                .unwrap_or_else(|_| "redis://127.0.0.1:6379".to_string());
+            let redis_url: String = polymarket_core::settings::get_opt("DYNAMIC_TUNER_REDIS_URL")
+                .unwrap_or_else(|| polymarket_core::settings::get("REDIS_URL"));
             match redis::Client::open(redis_url.as_str()) {
                 Ok(client) => match redis::aio::ConnectionManager::new(client).await {
                     Ok(conn) => {
@@ -526,8 +522,17 @@ impl AppState {
             }
         };
 
+        // Acks from the API also stop escalations raised by other services.
+        if let Some(conn) = redis_conn.clone() {
+            alert_router = alert_router.with_shared_acknowledgements(conn);
+        }
+        tracing::info!(sinks = ?alert_router.sink_names(), "Alert router initialized");
+        let alert_router = Arc::new(alert_router);
+
         let position_service =
             crate::position_service::PositionService::new(pool.clone(), trade_event_tx.clone());
+        let event_ingest = EventIngestConfig::from_env();
+        let event_matcher = event_ingest.matcher_cache();
 
         Ok(Self {
             pool,
@@ -538,6 +543,7 @@ impl AppState {
             key_vault,
             audit_logger,
             email_client,
+            alert_router,
             clob_client,
             order_executor,
             circuit_breaker,
@@ -559,9 +565,13 @@ impl AppState {
             )),
             exit_handler_config: None,
             quant_executor_config: None,
+            signal_generators: Arc::new(SignalGeneratorRegistry::from_env()),
+            event_ingest: Arc::new(event_ingest),
+            event_matcher: Arc::new(event_matcher),
             arb_executor_heartbeat: Arc::new(AtomicI64::new(0)),
             exit_handler_heartbeat: Arc::new(AtomicI64::new(0)),
             quant_executor_heartbeat: Arc::new(AtomicI64::new(0)),
+            market_maker_heartbeat: Arc::new(AtomicI64::new(0)),
         })
     }
 
@@ -655,10 +665,10 @@ impl AppState {
 }
 
 fn build_polygon_client_for_discovery() -> Option<PolygonClient> {
-    if let Ok(rpc_url) = std::env::var("POLYGON_RPC_URL") {
+    if let Some(rpc_url) = polymarket_core::settings::get_opt("POLYGON_RPC_URL") {
         return Some(PolygonClient::new(rpc_url));
     }
-    if let Ok(alchemy_api_key) = std::env::var("ALCHEMY_API_KEY") {
+    if let Some(alchemy_api_key) = polymarket_core::settings::get_opt::<String>("ALCHEMY_API_KEY") {
         return Some(PolygonClient::with_alchemy(&alchemy_api_key));
     }
     None
@@ -20,13 +20,8 @@ pub struct StrategyHealthConfig {
 impl StrategyHealthConfig {
     pub fn from_env() -> Self {
         Self {
-            enabled: std::env::var("STRATEGY_HEALTH_ENABLED")
-                .map(|v| v == "true")
-                .unwrap_or(true),
-            interval_secs: std::env::var("STRATEGY_HEALTH_INTERVAL_SECS")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(21600),
+            enabled: polymarket_core::settings::get("STRATEGY_HEALTH_ENABLED"),
+            interval_secs: polymarket_core::settings::get("STRATEGY_HEALTH_INTERVAL_SECS"),
             periods: vec![7, 30],
         }
     }
@@ -481,12 +476,13 @@ fn recommend(row: &StrategyHealthAggRow) -> Recommendation {
         "mean_reversion" => "paper",
         "cross_market" => "paper",
         "resolution_proximity" => "paper",
+        "event_reaction" => "paper",
         _ => "hold",
     };
 
     let label = if matches!(
         row.strategy.as_str(),
-        "cross_market" | "resolution_proximity"
+        "cross_market" | "resolution_proximity" | "event_reaction"
     ) {
         "paper"
     } else if failure_rate >= 0.25 || pnl < 0.0 || edge_capture < 0.20 {
@@ -25,28 +25,19 @@ pub struct StrategyModeInputs {
     pub live_executor: bool,
     pub live_ready: bool,
     pub quant_executor_enabled: bool,
-    pub flow_signal_enabled: bool,
-    pub mean_reversion_signal_enabled: bool,
-    pub cross_market_signal_enabled: bool,
-    pub resolution_signal_enabled: bool,
+    /// `(generator name, enabled)` for each registered signal generator.
+    pub signal_generators: Vec<(String, bool)>,
 }
 
 pub fn resolve_strategy_modes(inputs: &StrategyModeInputs) -> Vec<StrategyModeStatus> {
-    vec![
-        resolve_arb_mode(inputs),
-        resolve_quant_mode("flow", inputs.flow_signal_enabled, inputs),
-        resolve_quant_mode(
-            "mean_reversion",
-            inputs.mean_reversion_signal_enabled,
-            inputs,
-        ),
-        resolve_quant_mode("cross_market", inputs.cross_market_signal_enabled, inputs),
-        resolve_quant_mode(
-            "resolution_proximity",
-            inputs.resolution_signal_enabled,
-            inputs,
-        ),
-    ]
+    std::iter::once(resolve_arb_mode(inputs))
+        .chain(
+            inputs
+                .signal_generators
+                .iter()
+                .map(|(name, enabled)| resolve_quant_mode(name, *enabled, inputs)),
+        )
+        .collect()
 }
 
 fn resolve_arb_mode(inputs: &StrategyModeInputs) -> StrategyModeStatus {
@@ -167,10 +158,12 @@ mod tests {
             live_executor: true,
             live_ready: true,
             quant_executor_enabled: true,
-            flow_signal_enabled: true,
-            mean_reversion_signal_enabled: true,
-            cross_market_signal_enabled: false,
-            resolution_signal_enabled: true,
+            signal_generators: vec![
+                ("flow".to_string(), true),
+                ("mean_reversion".to_string(), true),
+                ("cross_market".to_string(), false),
+                ("resolution_proximity".to_string(), true),
+            ],
         }
     }
 
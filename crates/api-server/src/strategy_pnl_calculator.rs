@@ -1,8 +1,9 @@
 //! Strategy P&L calculator.
 //!
 //! Background task that periodically computes per-strategy performance snapshots
-//! by joining `quant_signals → positions` (for quant strategies) and querying
-//! `positions` directly (for arb source).
+//! by joining `quant_signals → positions` (for quant strategies), querying
+//! `positions` directly (for arb source), and aggregating market-maker
+//! `trade_events` (fills plus spread-capture / adverse-selection attribution).
 //!
 //! Results are written to `strategy_pnl_snapshots` for dashboard display
 //! and dynamic tuner feedback.
@@ -29,13 +30,8 @@ pub struct StrategyPnlConfig {
 impl StrategyPnlConfig {
     pub fn from_env() -> Self {
         Self {
-            enabled: std::env::var("STRATEGY_PNL_ENABLED")
-                .map(|v| v == "true")
-                .unwrap_or(true),
-            interval_secs: std::env::var("STRATEGY_PNL_INTERVAL_SECS")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(21600), // 6 hours
+            enabled: polymarket_core::settings::get("STRATEGY_PNL_ENABLED"),
+            interval_secs: polymarket_core::settings::get("STRATEGY_PNL_INTERVAL_SECS"), // 6 hours
             periods: vec![7, 30],
         }
     }
@@ -105,6 +101,18 @@ struct SourcePnlRow {
     avg_hold_hours: Option<f64>,
 }
 
+/// Row type for market-making P&L, aggregated from `trade_events`.
+#[derive(Debug, sqlx::FromRow)]
+struct MarketMakingPnlRow {
+    fills: i64,
+    wins: i64,
+    losses: i64,
+    net_pnl: Option<Decimal>,
+    avg_pnl: Option<Decimal>,
+    spread_capture: Option<Decimal>,
+    adverse_selection: Option<Decimal>,
+}
+
 /// Execute a single computation cycle.
 async fn compute_cycle(
     pool: &PgPool,
@@ -187,6 +195,30 @@ async fn compute_cycle(
         .fetch_optional(pool)
         .await?;
 
+        // ── Market making: fills and markouts from trade_events ──
+        let mm_row = sqlx::query_as::<_, MarketMakingPnlRow>(
+            r#"
+            SELECT
+                COUNT(*) FILTER (WHERE event_type = 'mm_fill')::bigint AS fills,
+                COUNT(*) FILTER (WHERE event_type = 'mm_fill' AND realized_pnl > 0)::bigint AS wins,
+                COUNT(*) FILTER (WHERE event_type = 'mm_fill' AND realized_pnl < 0)::bigint AS losses,
+                SUM(realized_pnl) FILTER (WHERE event_type = 'mm_fill') AS net_pnl,
+                AVG(realized_pnl) FILTER (WHERE event_type = 'mm_fill') AS avg_pnl,
+                SUM((metadata->>'spread_capture')::numeric)
+                    FILTER (WHERE event_type = 'mm_fill') AS spread_capture,
+                SUM((metadata->>'adverse_selection')::numeric)
+                    FILTER (WHERE event_type = 'mm_markout') AS adverse_selection
+            FROM trade_events
+            WHERE strategy = 'market_making'
+              AND occurred_at >= $1
+              AND occurred_at <= $2
+            "#,
+        )
+        .bind(window_start)
+        .bind(now)
+        .fetch_optional(pool)
+        .await?;
+
         // ── Acquire semaphore for DB writes ──
         let _permit = db_semaphore.acquire().await.expect("semaphore closed");
 
@@ -249,6 +281,47 @@ async fn compute_cycle(
             }
         }
 
+        // Upsert market-making snapshot, then its attribution columns
+        if let Some(row) = mm_row.as_ref().filter(|r| r.fills > 0) {
+            let win_rate = if (row.wins + row.losses) > 0 {
+                Some(row.wins as f64 / (row.wins + row.losses) as f64)
+            } else {
+                None
+            };
+
+            upsert_snapshot(
+                pool,
+                "market_making",
+                now,
+                period_days,
+                row.fills as i32,
+                row.fills as i32,
+                row.wins as i32,
+                row.losses as i32,
+                row.net_pnl.unwrap_or(Decimal::ZERO),
+                row.avg_pnl.unwrap_or(Decimal::ZERO),
+                win_rate,
+                None,
+                0.0,
+            )
+            .await?;
+            sqlx::query(
+                r#"
+                UPDATE strategy_pnl_snapshots
+                SET spread_capture = $4, adverse_selection = $5
+                WHERE strategy = $1 AND period_end = $2 AND period_days = $3
+                "#,
+            )
+            .bind("market_making")
+            .bind(now)
+            .bind(period_days)
+            .bind(row.spread_capture.unwrap_or(Decimal::ZERO))
+            .bind(row.adverse_selection.unwrap_or(Decimal::ZERO))
+            .execute(pool)
+            .await?;
+            total_rows += 1;
+        }
+
         drop(_permit);
 
         debug!(
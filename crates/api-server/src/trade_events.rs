@@ -3,11 +3,11 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use sqlx::PgPool;
 use tokio::sync::broadcast;
-use tracing::warn;
+use tracing::{instrument, warn};
 use utoipa::ToSchema;
 use uuid::Uuid;
 
-#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, sqlx::FromRow)]
 pub struct TradeEventUpdate {
     pub id: Uuid,
     pub occurred_at: DateTime<Utc>,
@@ -101,6 +101,7 @@ impl TradeEventRecorder {
         Self { pool, tx }
     }
 
+    #[instrument(name = "trade_event.record", skip_all, fields(event_type = %event.event_type, market_id = %event.market_id))]
     pub async fn record(&self, event: NewTradeEvent) -> anyhow::Result<TradeEventUpdate> {
         let update = TradeEventUpdate {
             id: Uuid::new_v4(),
@@ -179,6 +180,38 @@ impl TradeEventRecorder {
     }
 }
 
+/// Recorded events for one market, oldest first.
+pub async fn load_market_events(
+    pool: &PgPool,
+    market_id: &str,
+    since: Option<DateTime<Utc>>,
+    limit: i64,
+) -> sqlx::Result<Vec<TradeEventUpdate>> {
+    sqlx::query_as(
+        r#"
+        SELECT *
+        FROM (
+            SELECT
+                id, occurred_at, strategy, execution_mode, source, market_id,
+                position_id, signal_id, event_type, state_from, state_to, reason,
+                direction, confidence, expected_edge, observed_edge, requested_size_usd,
+                filled_size_usd, fill_price, realized_pnl, unrealized_pnl, metadata
+            FROM trade_events
+            WHERE market_id = $1
+              AND ($2::TIMESTAMPTZ IS NULL OR occurred_at >= $2)
+            ORDER BY occurred_at DESC
+            LIMIT $3
+        ) recent
+        ORDER BY occurred_at ASC
+        "#,
+    )
+    .bind(market_id)
+    .bind(since)
+    .bind(limit)
+    .fetch_all(pool)
+    .await
+}
+
 fn ensure_object(value: serde_json::Value) -> serde_json::Value {
     match value {
         serde_json::Value::Object(_) => value,
@@ -0,0 +1,454 @@
+//! Recovery policy for one-legged arb positions.
+//!
+//! When the second leg of an arb entry fails, the position holds a single
+//! outcome. For each stranded leg the policy prices three options against the
+//! live books and picks the one with the lowest expected loss:
+//! - **Complete**: buy the missing leg (up to a price limit) and hold the pair to resolution
+//! - **Sell**: sell the held leg into the bid
+//! - **Hold**: keep the naked leg, valued at the book mid plus a risk penalty
+//!
+//! Book access goes through [`UnwindMarketSource`] so the policy can be driven
+//! by a mocked book in tests.
+
+use async_trait::async_trait;
+use chrono::Duration;
+use polymarket_core::types::{OrderBook, PriceLevel};
+use rust_decimal::prelude::{FromPrimitive, ToPrimitive};
+use rust_decimal::Decimal;
+use uuid::Uuid;
+
+use crate::position_service::Leg;
+
+/// Configuration for the one-legged unwind policy (env-var driven).
+#[derive(Debug, Clone)]
+pub struct UnwindPolicyConfig {
+    /// When false, stranded legs are always sold (legacy behavior).
+    pub enabled: bool,
+    /// Highest combined entry cost accepted when completing the missing leg.
+    pub max_pair_cost: Decimal,
+    /// Completion attempts per position before only sell/hold remain.
+    pub max_completion_attempts: u32,
+    /// Holding is only considered when the market resolves within this many hours.
+    pub hold_max_hours_to_resolution: f64,
+    /// Penalty per share per unit of outcome standard deviation when holding.
+    pub hold_risk_aversion: f64,
+    /// Fee rate charged on resolution winnings.
+    pub fee_rate: Decimal,
+}
+
+impl Default for UnwindPolicyConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_pair_cost: Decimal::ONE,
+            max_completion_attempts: 3,
+            hold_max_hours_to_resolution: 24.0,
+            hold_risk_aversion: 0.5,
+            fee_rate: Decimal::new(2, 2),
+        }
+    }
+}
+
+impl UnwindPolicyConfig {
+    /// Create config from environment variables.
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        Self {
+            enabled: polymarket_core::settings::get("UNWIND_POLICY_ENABLED"),
+            max_pair_cost: polymarket_core::settings::get("UNWIND_MAX_PAIR_COST"),
+            max_completion_attempts: polymarket_core::settings::get(
+                "UNWIND_MAX_COMPLETION_ATTEMPTS",
+            ),
+            hold_max_hours_to_resolution: polymarket_core::settings::get(
+                "UNWIND_HOLD_MAX_HOURS_TO_RESOLUTION",
+            ),
+            hold_risk_aversion: polymarket_core::settings::get("UNWIND_HOLD_RISK_AVERSION"),
+            fee_rate: defaults.fee_rate,
+        }
+    }
+}
+
+/// A position holding only one leg of an arb.
+#[derive(Debug, Clone)]
+pub struct StrandedLeg {
+    pub position_id: Uuid,
+    pub market_id: String,
+    pub held_leg: Leg,
+    pub quantity: Decimal,
+    pub entry_price: Decimal,
+    /// Completion attempts already made for this position.
+    pub completion_attempts: u32,
+}
+
+impl StrandedLeg {
+    pub fn missing_leg(&self) -> Leg {
+        match self.held_leg {
+            Leg::Yes => Leg::No,
+            Leg::No => Leg::Yes,
+        }
+    }
+}
+
+/// Live market inputs for one stranded leg.
+#[derive(Debug, Clone)]
+pub struct UnwindMarketView {
+    /// Book for the outcome we hold.
+    pub held_book: OrderBook,
+    /// Book for the outcome we failed to buy.
+    pub missing_book: OrderBook,
+    /// `None` when the market has no known end date.
+    pub time_to_resolution: Option<Duration>,
+}
+
+/// Supplies live books and resolution timing to the policy.
+#[async_trait]
+pub trait UnwindMarketSource {
+    async fn market_view(&self, stranded: &StrandedLeg) -> anyhow::Result<UnwindMarketView>;
+}
+
+/// What to do with a stranded leg.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UnwindAction {
+    /// Buy the missing leg with a worst acceptable price of `limit_price`.
+    CompleteMissingLeg { limit_price: Decimal },
+    /// Sell the held leg; the book supports `expected_price` on average.
+    SellHeldLeg { expected_price: Decimal },
+    /// Keep the leg and re-evaluate on the next pass.
+    Hold,
+}
+
+impl UnwindAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::CompleteMissingLeg { .. } => "complete",
+            Self::SellHeldLeg { .. } => "sell",
+            Self::Hold => "hold",
+        }
+    }
+}
+
+/// Decision plus the priced options behind it.
+#[derive(Debug, Clone)]
+pub struct UnwindDecision {
+    pub action: UnwindAction,
+    pub reason: String,
+    /// Expected loss in USD of each option; `None` when not executable.
+    pub complete_loss: Option<Decimal>,
+    pub sell_loss: Option<Decimal>,
+    pub hold_loss: Option<Decimal>,
+    /// Estimated probability that the held outcome wins.
+    pub fair_value: Decimal,
+    pub hours_to_resolution: Option<f64>,
+}
+
+impl UnwindDecision {
+    /// Trade event metadata describing the decision.
+    pub fn metadata(&self) -> serde_json::Value {
+        let limit_price = match &self.action {
+            UnwindAction::CompleteMissingLeg { limit_price } => Some(limit_price.to_string()),
+            _ => None,
+        };
+        serde_json::json!({
+            "action": self.action.as_str(),
+            "limit_price": limit_price,
+            "complete_expected_loss": self.complete_loss.map(|v| v.round_dp(4).to_string()),
+            "sell_expected_loss": self.sell_loss.map(|v| v.round_dp(4).to_string()),
+            "hold_expected_loss": self.hold_loss.map(|v| v.round_dp(4).to_string()),
+            "fair_value": self.fair_value.round_dp(4).to_string(),
+            "hours_to_resolution": self.hours_to_resolution,
+        })
+    }
+}
+
+/// Prices and chooses among complete / sell / hold for stranded legs.
+pub struct UnwindPolicy {
+    config: UnwindPolicyConfig,
+}
+
+impl UnwindPolicy {
+    pub fn new(config: UnwindPolicyConfig) -> Self {
+        Self { config }
+    }
+
+    /// Fetch live inputs from `source` and decide.
+    pub async fn evaluate<S: UnwindMarketSource + Sync>(
+        &self,
+        source: &S,
+        stranded: &StrandedLeg,
+    ) -> anyhow::Result<UnwindDecision> {
+        let view = source.market_view(stranded).await?;
+        Ok(self.decide(stranded, &view))
+    }
+
+    /// Choose the option with the lowest expected loss.
+    ///
+    /// Ties prefer completing, then selling, since both remove the naked
+    /// exposure that holding keeps.
+    pub fn decide(&self, stranded: &StrandedLeg, view: &UnwindMarketView) -> UnwindDecision {
+        let qty = stranded.quantity;
+        let fair_value = fair_value(view, stranded.entry_price);
+        let hours_to_resolution = view
+            .time_to_resolution
+            .map(|d| d.num_seconds() as f64 / 3600.0);
+
+        let mut notes = Vec::new();
+
+        let complete = if stranded.completion_attempts >= self.config.max_completion_attempts {
+            notes.push("completion attempts exhausted".to_string());
+            None
+        } else {
+            match walk_levels(&view.missing_book.asks, qty) {
+                Some((avg_price, worst_price)) => {
+                    let pair_cost = stranded.entry_price + avg_price;
+                    if pair_cost > self.config.max_pair_cost {
+                        notes.push(format!(
+                            "pair cost {} above limit {}",
+                            pair_cost.round_dp(4),
+                            self.config.max_pair_cost
+                        ));
+                        None
+                    } else {
+                        // Worst case fee: the cheaper leg wins and its profit is taxed.
+                        let cheaper_leg = stranded.entry_price.min(avg_price);
+                        let payout =
+                            Decimal::ONE - self.config.fee_rate * (Decimal::ONE - cheaper_leg);
+                        Some((qty * (pair_cost - payout), worst_price))
+                    }
+                }
+                None => {
+                    notes.push("missing-leg asks too thin to complete".to_string());
+                    None
+                }
+            }
+        };
+
+        let sell = match walk_levels(&view.held_book.bids, qty) {
+            Some((avg_price, _)) => Some((qty * (stranded.entry_price - avg_price), avg_price)),
+            None => {
+                notes.push("held-leg bids too thin to sell".to_string());
+                None
+            }
+        };
+
+        let hold = match hours_to_resolution {
+            Some(hours) if hours <= self.config.hold_max_hours_to_resolution => {
+                let p = fair_value.to_f64().unwrap_or(0.5).clamp(0.0, 1.0);
+                let penalty =
+                    Decimal::from_f64(self.config.hold_risk_aversion * (p * (1.0 - p)).sqrt())
+                        .unwrap_or(Decimal::ZERO);
+                Some(qty * (stranded.entry_price - fair_value + penalty))
+            }
+            Some(_) => {
+                notes.push("resolution too far out to hold".to_string());
+                None
+            }
+            None => {
+                notes.push("no resolution date to hold against".to_string());
+                None
+            }
+        };
+
+        let complete_loss = complete.map(|(loss, _)| loss);
+        let sell_loss = sell.map(|(loss, _)| loss);
+        let candidates = [
+            complete.map(|(loss, limit_price)| {
+                (loss, UnwindAction::CompleteMissingLeg { limit_price })
+            }),
+            sell.map(|(loss, expected_price)| (loss, UnwindAction::SellHeldLeg { expected_price })),
+            hold.map(|loss| (loss, UnwindAction::Hold)),
+        ];
+        let best = candidates.into_iter().flatten().fold(
+            None::<(Decimal, UnwindAction)>,
+            |best, candidate| match best {
+                Some(current) if current.0 <= candidate.0 => Some(current),
+                _ => Some(candidate),
+            },
+        );
+
+        let (action, reason) = match best {
+            Some((loss, action)) => {
+                let mut reason = format!(
+                    "{} has lowest expected loss {}",
+                    action.as_str(),
+                    loss.round_dp(4)
+                );
+                if !notes.is_empty() {
+                    reason.push_str(&format!(" ({})", notes.join("; ")));
+                }
+                (action, reason)
+            }
+            None => (
+                UnwindAction::Hold,
+                format!("no executable option: {}", notes.join("; ")),
+            ),
+        };
+
+        UnwindDecision {
+            action,
+            reason,
+            complete_loss,
+            sell_loss,
+            hold_loss: hold,
+            fair_value,
+            hours_to_resolution,
+        }
+    }
+}
+
+/// Average and worst price to fill `quantity` against `levels`, best first.
+///
+/// Returns `None` when the book cannot fill the full quantity.
+fn walk_levels(levels: &[PriceLevel], quantity: Decimal) -> Option<(Decimal, Decimal)> {
+    if quantity <= Decimal::ZERO {
+        return None;
+    }
+    let mut remaining = quantity;
+    let mut notional = Decimal::ZERO;
+    for level in levels {
+        if level.size <= Decimal::ZERO {
+            continue;
+        }
+        let take = remaining.min(level.size);
+        notional += take * level.price;
+        remaining -= take;
+        if remaining.is_zero() {
+            return Some((notional / quantity, level.price));
+        }
+    }
+    None
+}
+
+/// Probability the held outcome wins, from its own book or the complement of
+/// the missing outcome's book; the entry price is the last resort.
+fn fair_value(view: &UnwindMarketView, entry_price: Decimal) -> Decimal {
+    let two = Decimal::TWO;
+    let mid = |book: &OrderBook| match (book.best_bid(), book.best_ask()) {
+        (Some(bid), Some(ask)) => Some((bid + ask) / two),
+        _ => None,
+    };
+    mid(&view.held_book)
+        .or_else(|| mid(&view.missing_book).map(|m| Decimal::ONE - m))
+        .or_else(|| view.held_book.best_bid())
+        .unwrap_or(entry_price)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    struct MockBook {
+        view: UnwindMarketView,
+    }
+
+    #[async_trait]
+    impl UnwindMarketSource for MockBook {
+        async fn market_view(&self, _stranded: &StrandedLeg) -> anyhow::Result<UnwindMarketView> {
+            Ok(self.view.clone())
+        }
+    }
+
+    fn book(bids: &[(i64, i64)], asks: &[(i64, i64)]) -> OrderBook {
+        let levels = |raw: &[(i64, i64)]| {
+            raw.iter()
+                .map(|&(price, size)| PriceLevel {
+                    price: Decimal::new(price, 2),
+                    size: Decimal::new(size, 0),
+                })
+                .collect()
+        };
+        OrderBook {
+            market_id: "m1".to_string(),
+            outcome_id: "token".to_string(),
+            timestamp: Utc::now(),
+            bids: levels(bids),
+            asks: levels(asks),
+        }
+    }
+
+    fn stranded(attempts: u32) -> StrandedLeg {
+        StrandedLeg {
+            position_id: Uuid::new_v4(),
+            market_id: "m1".to_string(),
+            held_leg: Leg::Yes,
+            quantity: Decimal::new(100, 0),
+            entry_price: Decimal::new(48, 2),
+            completion_attempts: attempts,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_completes_when_missing_leg_is_cheap() {
+        let source = MockBook {
+            view: UnwindMarketView {
+                held_book: book(&[(44, 500)], &[(47, 500)]),
+                missing_book: book(&[(49, 500)], &[(50, 60), (51, 100)]),
+                time_to_resolution: Some(Duration::days(10)),
+            },
+        };
+        let policy = UnwindPolicy::new(UnwindPolicyConfig::default());
+        let decision = policy.evaluate(&source, &stranded(0)).await.unwrap();
+        assert_eq!(
+            decision.action,
+            UnwindAction::CompleteMissingLeg {
+                limit_price: Decimal::new(51, 2)
+            }
+        );
+        assert!(decision.hold_loss.is_none());
+        assert!(decision.complete_loss.unwrap() < decision.sell_loss.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_sells_when_completion_breaches_pair_limit_or_attempts() {
+        let view = UnwindMarketView {
+            held_book: book(&[(46, 500)], &[(48, 500)]),
+            missing_book: book(&[(52, 500)], &[(56, 500)]),
+            time_to_resolution: Some(Duration::days(10)),
+        };
+        let policy = UnwindPolicy::new(UnwindPolicyConfig::default());
+        let decision = policy.decide(&stranded(0), &view);
+        assert_eq!(
+            decision.action,
+            UnwindAction::SellHeldLeg {
+                expected_price: Decimal::new(46, 2)
+            }
+        );
+        assert!(decision.complete_loss.is_none());
+        assert!(decision.reason.contains("pair cost"));
+
+        let cheap_missing = UnwindMarketView {
+            missing_book: book(&[(49, 500)], &[(50, 500)]),
+            ..view
+        };
+        let decision = policy.decide(&stranded(3), &cheap_missing);
+        assert_eq!(decision.action.as_str(), "sell");
+        assert!(decision.reason.contains("attempts exhausted"));
+    }
+
+    #[test]
+    fn test_holds_near_resolution_when_exits_are_expensive() {
+        // Wide books: selling dumps into a 0.20 bid and completing costs too much,
+        // but the market resolves in two hours and the mid values the leg above entry.
+        let view = UnwindMarketView {
+            held_book: book(&[(20, 500)], &[(99, 500)]),
+            missing_book: book(&[(1, 500)], &[(60, 500)]),
+            time_to_resolution: Some(Duration::hours(2)),
+        };
+        let policy = UnwindPolicy::new(UnwindPolicyConfig {
+            hold_risk_aversion: 0.1,
+            ..UnwindPolicyConfig::default()
+        });
+        let decision = policy.decide(&stranded(0), &view);
+        assert_eq!(decision.action, UnwindAction::Hold);
+        assert!(decision.hold_loss.unwrap() < decision.sell_loss.unwrap());
+
+        let empty = UnwindMarketView {
+            held_book: book(&[], &[]),
+            missing_book: book(&[], &[]),
+            time_to_resolution: None,
+        };
+        let decision = policy.decide(&stranded(0), &empty);
+        assert_eq!(decision.action, UnwindAction::Hold);
+        assert!(decision.reason.starts_with("no executable option"));
+    }
+}
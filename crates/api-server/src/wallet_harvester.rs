@@ -42,21 +42,10 @@ impl WalletHarvesterConfig {
     /// Load configuration from environment variables.
     pub fn from_env() -> Self {
         Self {
-            enabled: std::env::var("HARVESTER_ENABLED")
-                .map(|v| v != "false")
-                .unwrap_or(true),
-            interval_secs: std::env::var("HARVESTER_INTERVAL_SECS")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(120),
-            trades_per_fetch: std::env::var("HARVESTER_TRADES_PER_FETCH")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(1000),
-            max_new_per_cycle: std::env::var("HARVESTER_MAX_NEW_PER_CYCLE")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(1500),
+            enabled: polymarket_core::settings::get("HARVESTER_ENABLED"),
+            interval_secs: polymarket_core::settings::get("HARVESTER_INTERVAL_SECS"),
+            trades_per_fetch: polymarket_core::settings::get("HARVESTER_TRADES_PER_FETCH"),
+            max_new_per_cycle: polymarket_core::settings::get("HARVESTER_MAX_NEW_PER_CYCLE"),
         }
     }
 }
@@ -38,35 +38,20 @@ pub struct WalletInventoryConfig {
 impl WalletInventoryConfig {
     pub fn from_env() -> Self {
         Self {
-            enabled: std::env::var("WALLET_INVENTORY_ENABLED")
-                .map(|v| v == "true")
-                .unwrap_or(true),
-            interval_secs: std::env::var("WALLET_INVENTORY_INTERVAL_SECS")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(180),
-            initial_phase2_lookback_blocks: std::env::var(
+            enabled: polymarket_core::settings::get("WALLET_INVENTORY_ENABLED"),
+            interval_secs: polymarket_core::settings::get("WALLET_INVENTORY_INTERVAL_SECS"),
+            initial_phase2_lookback_blocks: polymarket_core::settings::get(
                 "WALLET_INVENTORY_INITIAL_LOOKBACK_BLOCKS",
-            )
-            .ok()
-            .and_then(|v| v.parse().ok())
-            .unwrap_or(2_000_000),
-            phase2_backfill_start_block: std::env::var(
+            ),
+            phase2_backfill_start_block: polymarket_core::settings::get(
                 "WALLET_INVENTORY_PHASE2_BACKFILL_START_BLOCK",
-            )
-            .ok()
-            .and_then(|v| v.parse().ok())
-            .unwrap_or(0),
-            phase2_chunk_blocks: std::env::var("WALLET_INVENTORY_PHASE2_CHUNK_BLOCKS")
-                .ok()
-                .and_then(|v| v.parse().ok())
-                .unwrap_or(2_000_000),
-            phase2_backfill_chunks_per_refresh: std::env::var(
+            ),
+            phase2_chunk_blocks: polymarket_core::settings::get(
+                "WALLET_INVENTORY_PHASE2_CHUNK_BLOCKS",
+            ),
+            phase2_backfill_chunks_per_refresh: polymarket_core::settings::get(
                 "WALLET_INVENTORY_PHASE2_BACKFILL_CHUNKS_PER_REFRESH",
-            )
-            .ok()
-            .and_then(|v| v.parse().ok())
-            .unwrap_or(4),
+            ),
         }
     }
 }
@@ -390,17 +375,6 @@ pub async fn recover_canonical_orphan_inventory(
     .await
 }
 
-pub async fn load_canonical_orphan_inventory(
-    state: &AppState,
-) -> anyhow::Result<Vec<WalletInventoryEntry>> {
-    let Some(wallet_address) = resolve_canonical_wallet_address(state).await? else {
-        return Ok(Vec::new());
-    };
-
-    let repo = WalletInventoryRepository::new(state.pool.clone());
-    Ok(repo.get_recoverable_orphans(&wallet_address, 0).await?)
-}
-
 pub async fn recover_wallet_orphan_inventory(
     pool: &sqlx::PgPool,
     order_executor: &OrderExecutor,
@@ -593,12 +567,18 @@ impl NewTradeEventExt for NewTradeEvent {
     }
 }
 
-async fn resolve_canonical_wallet_address(state: &AppState) -> anyhow::Result<Option<String>> {
+/// Trading wallet of the running executor, falling back to the one stored on
+/// the canonical workspace.
+pub async fn resolve_canonical_wallet_address(state: &AppState) -> anyhow::Result<Option<String>> {
     if let Some(address) = state.order_executor.wallet_address().await {
         return Ok(Some(address.to_lowercase()));
     }
+    resolve_stored_wallet_address(&state.pool).await
+}
 
-    let Some(workspace_id) = resolve_canonical_workspace_id(&state.pool).await? else {
+/// Trading wallet stored on the canonical workspace.
+pub async fn resolve_stored_wallet_address(pool: &sqlx::PgPool) -> anyhow::Result<Option<String>> {
+    let Some(workspace_id) = resolve_canonical_workspace_id(pool).await? else {
         return Ok(None);
     };
 
@@ -610,7 +590,7 @@ async fn resolve_canonical_wallet_address(state: &AppState) -> anyhow::Result<Op
         "#,
     )
     .bind(workspace_id)
-    .fetch_optional(&state.pool)
+    .fetch_optional(pool)
     .await?;
 
     Ok(row.and_then(|(address,)| address.map(|value| value.to_lowercase())))
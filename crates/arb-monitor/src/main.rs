@@ -7,12 +7,15 @@ static GLOBAL: tikv_jemallocator::Jemalloc = tikv_jemallocator::Jemalloc;
 
 mod monitor;
 mod position_tracker;
+mod sharding;
 mod signals;
+mod trade_prints;
 
 use anyhow::Result;
 use polymarket_core::config::Config;
+use polymarket_core::settings::{self, ServiceArgs};
+use polymarket_core::telemetry;
 use tracing::info;
-use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 const HEALTH_FILE: &str = "/tmp/healthy";
 
@@ -22,16 +25,16 @@ fn touch_health_file() {
 
 #[tokio::main]
 async fn main() -> Result<()> {
+    // Load .env and the config file, refusing to start on invalid settings
+    let args = ServiceArgs::from_env();
+    settings::startup(args.config.as_deref(), args.print_config)?;
+
     // Initialize logging
     // Filter out noisy crates to avoid hitting Railway's 500 logs/sec limit
-    tracing_subscriber::registry()
-        .with(
-            tracing_subscriber::EnvFilter::try_from_default_env().unwrap_or_else(|_| {
-                "arb_monitor=info,polymarket_core=warn,tungstenite=warn,hyper=warn".into()
-            }),
-        )
-        .with(tracing_subscriber::fmt::layer())
-        .init();
+    let _telemetry = telemetry::init_tracing(
+        "arb-monitor",
+        "arb_monitor=info,polymarket_core=warn,tungstenite=warn,hyper=warn",
+    );
 
     info!("Starting Arbitrage Monitor");
     touch_health_file();
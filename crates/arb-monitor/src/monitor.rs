@@ -1,15 +1,19 @@
 //! Core arbitrage monitoring logic.
 
 use crate::position_tracker::PositionTracker;
+use crate::sharding::{aggregate_runtime_stats, ShardConfig, ShardCoordinator};
 use crate::signals::{channels, RuntimeMarketInsight, RuntimeStats, SignalPublisher};
+use crate::trade_prints::{TradePrintConfig, TradePrintRecorder};
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use futures_util::StreamExt;
 use polymarket_core::api::clob::websocket_runtime_stats_snapshot;
-use polymarket_core::api::clob::OrderBookUpdate;
+use polymarket_core::api::clob::{OrderBookSubscription, OrderBookUpdate};
 use polymarket_core::api::{ClobClient, GammaClient};
 use polymarket_core::config::Config;
 use polymarket_core::db;
+use polymarket_core::metrics::{self, MetricsServerConfig};
+use polymarket_core::signal_stream::SignalStreamConfig;
 use polymarket_core::types::{ArbOpportunity, BinaryMarketBook, OrderBook};
 use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
@@ -19,24 +23,11 @@ use std::time::Duration as StdDuration;
 use tokio::sync::mpsc;
 use tracing::{debug, info, warn};
 
-/// Default minimum depth (in USD) at best ask for both sides.
-const DEFAULT_MIN_BOOK_DEPTH_USD: Decimal = Decimal::from_parts(100, 0, 0, false, 0); // $100
-
 /// Cooldown period between signals for the same market (seconds).
 const SIGNAL_COOLDOWN_SECS: i64 = 60;
 
 /// How often to check for stale positions (every N order book updates).
 const STALE_CHECK_INTERVAL: u64 = 500;
-/// Minimum number of market replacements before a periodic rerank triggers a resubscribe.
-const DEFAULT_SELECTION_RESUBSCRIBE_MIN_MARKET_DELTA: usize = 8;
-/// Minimum relative market-set delta before a periodic rerank triggers a resubscribe.
-const DEFAULT_SELECTION_RESUBSCRIBE_MIN_DELTA_RATIO: f64 = 0.08;
-/// Maximum age of a held selection before a periodic rerank is allowed to refresh it anyway.
-const DEFAULT_SELECTION_FORCE_REFRESH_SECS: i64 = 10 * 60;
-/// Minimum time to keep an exploration market before allowing routine replacement.
-const DEFAULT_EXPLORATION_HOLD_SECS: i64 = 10 * 60;
-/// Minimum challenger score edge required to evict a held exploration incumbent.
-const DEFAULT_EXPLORATION_SWAP_MIN_SCORE_DELTA: f64 = 0.75;
 
 const KEY_ARB_MIN_PROFIT_THRESHOLD: &str = "ARB_MIN_PROFIT_THRESHOLD";
 const KEY_ARB_MONITOR_MAX_MARKETS: &str = "ARB_MONITOR_MAX_MARKETS";
@@ -53,8 +44,7 @@ enum AggressivenessProfile {
 
 impl AggressivenessProfile {
     fn from_env() -> Self {
-        match std::env::var("ARB_MONITOR_AGGRESSIVENESS")
-            .unwrap_or_else(|_| "balanced".to_string())
+        match polymarket_core::settings::get::<String>("ARB_MONITOR_AGGRESSIVENESS")
             .to_lowercase()
             .as_str()
         {
@@ -250,6 +240,7 @@ enum SelectionRebuildReason {
     Startup,
     DynamicConfig,
     PeriodicRerank,
+    ShardRebalance,
 }
 
 #[derive(Debug, Clone, Copy, Default)]
@@ -281,6 +272,10 @@ pub struct ArbMonitor {
     gamma_client: GammaClient,
     position_tracker: PositionTracker,
     signal_publisher: SignalPublisher,
+    /// Shard membership when several instances split the market universe.
+    sharding: Option<ShardCoordinator>,
+    /// Persists market-channel trade prints for flow features.
+    trade_prints: Option<TradePrintRecorder>,
     /// Current order books by (market_id, outcome_id).
     order_books: HashMap<(String, String), OrderBook>,
     /// Market outcome pairings (market_id -> (yes_outcome_id, no_outcome_id)).
@@ -333,6 +328,8 @@ pub struct ArbMonitor {
     exploration_hold_secs: i64,
     /// Required score advantage before replacing a held exploration incumbent.
     exploration_swap_min_score_delta: f64,
+    /// Spread above which a monitored market counts as blown out.
+    wide_spread_threshold: Decimal,
     /// Dynamic config update stream from Redis.
     dynamic_config_rx: mpsc::UnboundedReceiver<DynamicConfigUpdate>,
     /// Bounds for dynamic keys loaded from DB (fallbacks if unavailable).
@@ -356,24 +353,37 @@ impl ArbMonitor {
 
         // Create position tracker
         let position_tracker = PositionTracker::new(pool.clone());
+        let trade_prints = TradePrintRecorder::spawn(pool.clone(), TradePrintConfig::from_env());
+
+        let metrics_pool = pool.clone();
+        metrics::spawn_metrics_server(MetricsServerConfig::from_env(9101), move || {
+            metrics::observe_db_pool(&metrics_pool)
+        });
 
         // Create signal publisher
-        let signal_publisher = SignalPublisher::new(redis_client, config.alerts).await?;
+        let stream_config = SignalStreamConfig::from_env();
+        info!(
+            transport = stream_config.transport.as_str(),
+            "Arb entry signal transport"
+        );
+        let signal_publisher =
+            SignalPublisher::new(redis_client.clone(), stream_config, config.alerts).await?;
+
+        let shard_config = ShardConfig::from_env();
+        let sharding = if shard_config.enabled {
+            Some(ShardCoordinator::join(redis_client, shard_config).await?)
+        } else {
+            None
+        };
 
         let dynamic_bounds = load_dynamic_bounds(&pool).await;
         let dynamic_values = load_dynamic_values(&pool).await;
-        let min_profit_env = std::env::var(KEY_ARB_MIN_PROFIT_THRESHOLD)
-            .ok()
-            .and_then(|s| s.parse().ok())
-            .unwrap_or_else(|| Decimal::new(5, 3));
-        let min_book_depth = std::env::var("ARB_MONITOR_MIN_BOOK_DEPTH")
-            .or_else(|_| std::env::var("ARB_MIN_BOOK_DEPTH"))
-            .ok()
-            .and_then(|s| s.parse().ok())
-            .unwrap_or(DEFAULT_MIN_BOOK_DEPTH_USD);
-        let max_markets_env = std::env::var(KEY_ARB_MONITOR_MAX_MARKETS)
-            .ok()
-            .and_then(|s| s.parse::<usize>().ok());
+        let min_profit_env: Decimal = polymarket_core::settings::get(KEY_ARB_MIN_PROFIT_THRESHOLD);
+        let min_book_depth = polymarket_core::settings::explicit("ARB_MONITOR_MIN_BOOK_DEPTH")
+            .or_else(|| polymarket_core::settings::explicit("ARB_MIN_BOOK_DEPTH"))
+            .unwrap_or_else(|| polymarket_core::settings::get("ARB_MONITOR_MIN_BOOK_DEPTH"));
+        let max_markets_env: Option<usize> =
+            polymarket_core::settings::get_opt(KEY_ARB_MONITOR_MAX_MARKETS);
         let min_profit_threshold = dynamic_values
             .get(KEY_ARB_MIN_PROFIT_THRESHOLD)
             .copied()
@@ -396,9 +406,8 @@ impl ArbMonitor {
             .map(AggressivenessProfile::from_level)
             .unwrap_or_else(AggressivenessProfile::from_env);
         let scoring_weights = ScoringWeights::for_profile(aggressiveness_profile);
-        let exploration_slots_env = std::env::var(KEY_ARB_MONITOR_EXPLORATION_SLOTS)
-            .ok()
-            .and_then(|s| s.parse::<usize>().ok());
+        let exploration_slots_env: Option<usize> =
+            polymarket_core::settings::get_opt(KEY_ARB_MONITOR_EXPLORATION_SLOTS);
         let exploration_slots = dynamic_values
             .get(KEY_ARB_MONITOR_EXPLORATION_SLOTS)
             .copied()
@@ -409,35 +418,20 @@ impl ArbMonitor {
             .or(exploration_slots_env)
             .unwrap_or(aggressiveness_profile.default_exploration_slots());
         let selection_resubscribe_min_market_delta =
-            std::env::var("ARB_SELECTION_RESUBSCRIBE_MIN_MARKET_DELTA")
-                .ok()
-                .and_then(|s| s.parse::<usize>().ok())
-                .unwrap_or(DEFAULT_SELECTION_RESUBSCRIBE_MIN_MARKET_DELTA);
+            polymarket_core::settings::get::<usize>("ARB_SELECTION_RESUBSCRIBE_MIN_MARKET_DELTA");
         let selection_resubscribe_min_delta_ratio =
-            std::env::var("ARB_SELECTION_RESUBSCRIBE_MIN_DELTA_RATIO")
-                .ok()
-                .and_then(|s| s.parse::<f64>().ok())
-                .filter(|value| value.is_finite() && *value >= 0.0)
-                .unwrap_or(DEFAULT_SELECTION_RESUBSCRIBE_MIN_DELTA_RATIO);
-        let selection_force_refresh_secs = std::env::var("ARB_SELECTION_FORCE_REFRESH_SECS")
-            .ok()
-            .and_then(|s| s.parse::<i64>().ok())
-            .filter(|value| *value > 0)
-            .unwrap_or(DEFAULT_SELECTION_FORCE_REFRESH_SECS);
-        let exploration_hold_secs = std::env::var("ARB_EXPLORATION_HOLD_SECS")
-            .ok()
-            .and_then(|s| s.parse::<i64>().ok())
-            .filter(|value| *value > 0)
-            .unwrap_or(DEFAULT_EXPLORATION_HOLD_SECS);
+            polymarket_core::settings::get::<f64>("ARB_SELECTION_RESUBSCRIBE_MIN_DELTA_RATIO");
+        let selection_force_refresh_secs =
+            polymarket_core::settings::get::<i64>("ARB_SELECTION_FORCE_REFRESH_SECS");
+        let exploration_hold_secs =
+            polymarket_core::settings::get::<i64>("ARB_EXPLORATION_HOLD_SECS");
         let exploration_swap_min_score_delta =
-            std::env::var("ARB_EXPLORATION_SWAP_MIN_SCORE_DELTA")
-                .ok()
-                .and_then(|s| s.parse::<f64>().ok())
-                .filter(|value| value.is_finite() && *value >= 0.0)
-                .unwrap_or(DEFAULT_EXPLORATION_SWAP_MIN_SCORE_DELTA);
-
-        let dynamic_redis_url =
-            std::env::var("DYNAMIC_CONFIG_REDIS_URL").unwrap_or_else(|_| config.redis.url.clone());
+            polymarket_core::settings::get::<f64>("ARB_EXPLORATION_SWAP_MIN_SCORE_DELTA");
+        let wide_spread_threshold =
+            polymarket_core::settings::get::<Decimal>("ARB_MONITOR_WIDE_SPREAD_THRESHOLD");
+
+        let dynamic_redis_url = polymarket_core::settings::get_opt("DYNAMIC_CONFIG_REDIS_URL")
+            .unwrap_or_else(|| config.redis.url.clone());
         let dynamic_config_rx = spawn_dynamic_config_listener(dynamic_redis_url);
 
         Ok(Self {
@@ -445,6 +439,8 @@ impl ArbMonitor {
             gamma_client,
             position_tracker,
             signal_publisher,
+            sharding,
+            trade_prints,
             order_books: HashMap::new(),
             market_outcomes: HashMap::new(),
             min_profit_threshold,
@@ -471,6 +467,7 @@ impl ArbMonitor {
             selection_force_refresh_secs,
             exploration_hold_secs,
             exploration_swap_min_score_delta,
+            wide_spread_threshold,
             dynamic_config_rx,
             dynamic_bounds,
             allowed_dynamic_sources: load_allowed_dynamic_sources(),
@@ -484,10 +481,7 @@ impl ArbMonitor {
         // Fetch tradable markets from Gamma, which is authoritative for active
         // discovery. CLOB `/markets?active=true` currently includes many closed
         // historical markets and starves the websocket selection.
-        let gamma_page_size = std::env::var("GAMMA_ARB_MARKET_PAGE_SIZE")
-            .ok()
-            .and_then(|s| s.parse::<u32>().ok())
-            .unwrap_or(200);
+        let gamma_page_size = polymarket_core::settings::get::<u32>("GAMMA_ARB_MARKET_PAGE_SIZE");
         let markets = self
             .gamma_client
             .get_all_tradable_markets(gamma_page_size)
@@ -545,6 +539,8 @@ impl ArbMonitor {
             selection_force_refresh_secs = self.selection_force_refresh_secs,
             min_profit_threshold = %self.min_profit_threshold,
             min_book_depth = %self.min_book_depth,
+            shard_instance = ?self.sharding.as_ref().map(|s| s.instance_id().to_string()),
+            shard_members = self.sharding.as_ref().map(|s| s.members().len()).unwrap_or(1),
             "Initialized arb market universe"
         );
 
@@ -572,11 +568,9 @@ impl ArbMonitor {
             .clob_client
             .subscribe_orderbook(self.active_subscription_asset_ids())
             .await?;
+        self.attach_market_events(&updates);
         self.last_resubscribe_at = Some(Utc::now());
-        let update_timeout_secs = std::env::var("ARB_UPDATE_TIMEOUT_SECS")
-            .ok()
-            .and_then(|s| s.parse::<u64>().ok())
-            .unwrap_or(120);
+        let update_timeout_secs = polymarket_core::settings::get::<u64>("ARB_UPDATE_TIMEOUT_SECS");
 
         info!("Subscribed to order book updates, monitoring for arbitrage...");
 
@@ -589,6 +583,13 @@ impl ArbMonitor {
         // depends entirely on update volume.
         let mut heartbeat_tick = tokio::time::interval(tokio::time::Duration::from_secs(60));
         heartbeat_tick.tick().await;
+        let mut shard_tick = tokio::time::interval(tokio::time::Duration::from_secs(
+            self.sharding
+                .as_ref()
+                .map(|s| s.heartbeat_secs())
+                .unwrap_or(60),
+        ));
+        shard_tick.tick().await;
 
         let mut updates_since_tick = 0u64;
         let mut stalls_since_tick = 0u64;
@@ -601,58 +602,21 @@ impl ArbMonitor {
         let mut resubscribe_requested = false;
         let mut ws_runtime_prev = websocket_runtime_stats_snapshot();
 
-        const MAX_RESUBSCRIBE_RETRIES: u32 = 10;
-        const RESUBSCRIBE_BASE_DELAY_SECS: u64 = 3;
-        const RESUBSCRIBE_MAX_DELAY_SECS: u64 = 60;
-
         loop {
             if resubscribe_requested {
+                // Swap assets on the live sockets; books for unchanged assets
+                // keep streaming and there is no blind window.
                 let target_assets = self.active_subscription_asset_ids();
+                let delta = updates.set_assets(target_assets);
                 info!(
-                    asset_count = target_assets.len(),
-                    "Resubscribing orderbook stream after dynamic market selection update"
+                    asset_count = updates.asset_ids().len(),
+                    sockets = updates.socket_count(),
+                    added = delta.added,
+                    removed = delta.removed,
+                    "Updated orderbook subscription in place after market selection change"
                 );
-                let mut attempt = 0u32;
-                loop {
-                    attempt += 1;
-                    match self
-                        .clob_client
-                        .subscribe_orderbook(target_assets.clone())
-                        .await
-                    {
-                        Ok(new_updates) => {
-                            updates = new_updates;
-                            resets_since_tick += 1;
-                            resubscribe_requested = false;
-                            self.last_resubscribe_at = Some(Utc::now());
-                            break;
-                        }
-                        Err(e) => {
-                            let delay = RESUBSCRIBE_BASE_DELAY_SECS
-                                .saturating_mul(2u64.saturating_pow(attempt.saturating_sub(1)))
-                                .min(RESUBSCRIBE_MAX_DELAY_SECS);
-                            if attempt >= MAX_RESUBSCRIBE_RETRIES {
-                                warn!(
-                                    error = %e,
-                                    attempts = attempt,
-                                    "Resubscribe failed after max retries, deferring to next select! cycle"
-                                );
-                                // Leave resubscribe_requested=true so the next
-                                // select! iteration will retry, keeping the
-                                // dynamic_config_rx channel responsive.
-                                break;
-                            }
-                            warn!(
-                                error = %e,
-                                attempt,
-                                max_retries = MAX_RESUBSCRIBE_RETRIES,
-                                retry_delay_secs = delay,
-                                "Failed resubscribing orderbook stream, retrying"
-                            );
-                            tokio::time::sleep(tokio::time::Duration::from_secs(delay)).await;
-                        }
-                    }
-                }
+                resubscribe_requested = false;
+                self.last_resubscribe_at = Some(Utc::now());
             }
 
             tokio::select! {
@@ -677,10 +641,35 @@ impl ArbMonitor {
                 _ = heartbeat_tick.tick() => {
                     crate::touch_health_file();
                 }
+                _ = shard_tick.tick(), if self.sharding.is_some() => {
+                    let membership = match self.sharding.as_mut() {
+                        Some(shard) => shard.heartbeat().await,
+                        None => Ok(false),
+                    };
+                    match membership {
+                        Ok(true) => {
+                            let outcome = self.rebuild_eligible_markets(SelectionRebuildReason::ShardRebalance);
+                            if outcome.applied {
+                                selection_applied_since_tick = selection_applied_since_tick.saturating_add(1);
+                                last_selection_market_delta = outcome.market_delta;
+                                last_selection_asset_delta = outcome.asset_delta;
+                                info!(
+                                    active_markets = self.eligible_markets.len(),
+                                    market_delta = outcome.market_delta,
+                                    "Rebalanced monitored markets after shard membership change"
+                                );
+                                resubscribe_requested = true;
+                            }
+                        }
+                        Ok(false) => {}
+                        Err(e) => warn!(error = %e, "Failed to renew arb-monitor shard lease"),
+                    }
+                }
                 _ = stats_tick.tick() => {
                     let arb_runtime = arb_telemetry.as_runtime_stats();
                     let ws_runtime = websocket_runtime_stats_snapshot();
                     let monitored_assets = self.active_subscription_asset_count() as f64;
+                    let (spread_sampled_markets, wide_spread_markets) = self.spread_health();
                     let stats = RuntimeStats {
                         updates_per_minute: updates_since_tick as f64,
                         stalls_last_minute: stalls_since_tick as f64,
@@ -705,6 +694,15 @@ impl ArbMonitor {
                             .price_change_messages_total
                             .saturating_sub(ws_runtime_prev.price_change_messages_total)
                             as f64,
+                        ws_book_integrity_failures_per_minute: ws_runtime
+                            .book_integrity_failures_total
+                            .saturating_sub(ws_runtime_prev.book_integrity_failures_total)
+                            as f64,
+                        ws_book_resyncs_per_minute: ws_runtime
+                            .book_resyncs_total
+                            .saturating_sub(ws_runtime_prev.book_resyncs_total)
+                            as f64,
+                        ws_quarantined_assets: ws_runtime.quarantined_assets as f64,
                         monitored_markets: self.eligible_markets.len() as f64,
                         monitored_assets,
                         evaluated_books_per_minute: arb_runtime.evaluated_books_per_minute,
@@ -743,9 +741,34 @@ impl ArbMonitor {
                         ws_last_parse_miss_kind: ws_runtime.last_parse_miss_kind.clone(),
                         ws_last_message_kind: ws_runtime.last_message_kind.clone(),
                         selected_markets: self.selection_snapshot.clone(),
+                        spread_sampled_markets: spread_sampled_markets as f64,
+                        wide_spread_markets: wide_spread_markets as f64,
+                        wide_spread_threshold: self.wide_spread_threshold.to_f64().unwrap_or(0.0),
+                        shard_count: 1.0,
                     };
-                    if let Err(e) = self.signal_publisher.publish_runtime_stats(&stats).await {
-                        warn!(error = %e, "Failed to publish arb runtime stats");
+                    // With sharding, the tuner reads one snapshot merged across all
+                    // shards, published by the elected shard only so peers don't
+                    // overwrite each other with differently-timed merges.
+                    let fleet_stats;
+                    let published = match self.sharding.as_mut() {
+                        Some(shard) => match shard.exchange_runtime_stats(&stats).await {
+                            Ok(shards) if shard.is_leader() => {
+                                fleet_stats = aggregate_runtime_stats(&shards);
+                                Some(&fleet_stats)
+                            }
+                            Ok(_) => None,
+                            Err(e) => {
+                                warn!(error = %e, "Failed to exchange shard runtime stats");
+                                None
+                            }
+                        },
+                        None => Some(&stats),
+                    };
+                    if let Some(published) = published {
+                        if let Err(e) = self.signal_publisher.publish_runtime_stats(published).await
+                        {
+                            warn!(error = %e, "Failed to publish arb runtime stats");
+                        }
                     }
                     info!(
                         monitored_markets = self.eligible_markets.len(),
@@ -781,6 +804,9 @@ impl ArbMonitor {
                         ws_parse_misses = stats.ws_parse_misses_per_minute as u64,
                         ws_snapshot_messages = stats.ws_snapshot_messages_per_minute as u64,
                         ws_price_change_messages = stats.ws_price_change_messages_per_minute as u64,
+                        ws_book_integrity_failures = stats.ws_book_integrity_failures_per_minute as u64,
+                        ws_book_resyncs = stats.ws_book_resyncs_per_minute as u64,
+                        ws_quarantined_assets = stats.ws_quarantined_assets as u64,
                         ws_last_message_at = ?stats.ws_last_message_at,
                         ws_last_orderbook_update_at = ?stats.ws_last_orderbook_update_at,
                         ws_last_parse_miss_at = ?stats.ws_last_parse_miss_at,
@@ -844,6 +870,7 @@ impl ArbMonitor {
                                 match self.clob_client.subscribe_orderbook(self.active_subscription_asset_ids()).await {
                                     Ok(new_updates) => {
                                         updates = new_updates;
+                                        self.attach_market_events(&updates);
                                         resets_since_tick += 1;
                                         self.last_resubscribe_at = Some(Utc::now());
                                         break;
@@ -862,6 +889,7 @@ impl ArbMonitor {
                             match self.clob_client.subscribe_orderbook(self.active_subscription_asset_ids()).await {
                                 Ok(new_updates) => {
                                     updates = new_updates;
+                                    self.attach_market_events(&updates);
                                     resets_since_tick += 1;
                                     self.last_resubscribe_at = Some(Utc::now());
                                     break;
@@ -1006,14 +1034,29 @@ impl ArbMonitor {
         reason: SelectionRebuildReason,
     ) -> SelectionRebuildOutcome {
         let previous = self.eligible_markets.clone();
+        // Each shard runs the usual selection over the markets it owns; the
+        // market cap applies per instance.
+        let shard_market_ids: Vec<String>;
+        let universe: &[String] = match &self.sharding {
+            Some(shard) => {
+                shard_market_ids = self
+                    .all_market_ids
+                    .iter()
+                    .filter(|market_id| shard.owns(market_id))
+                    .cloned()
+                    .collect();
+                &shard_market_ids
+            }
+            None => &self.all_market_ids,
+        };
         let active_count = self
             .max_markets_cap
-            .map(|cap| cap.min(self.all_market_ids.len()))
-            .unwrap_or(self.all_market_ids.len());
+            .map(|cap| cap.min(universe.len()))
+            .unwrap_or(universe.len());
 
         let now = Utc::now();
         let selected = select_market_ids(
-            &self.all_market_ids,
+            universe,
             &self.market_profiles,
             &self.market_stats,
             &self.eligible_markets,
@@ -1033,8 +1076,15 @@ impl ArbMonitor {
         }
 
         // Keep markets with open positions subscribed so exit tracking keeps working.
+        // Under sharding only the owning shard does this.
         for position in self.position_tracker.get_active_positions() {
-            next.insert(position.market_id.clone());
+            if self
+                .sharding
+                .as_ref()
+                .is_none_or(|shard| shard.owns(&position.market_id))
+            {
+                next.insert(position.market_id.clone());
+            }
         }
 
         self.last_rerank_at = Some(now);
@@ -1048,7 +1098,9 @@ impl ArbMonitor {
         let asset_delta = self.selection_asset_delta(&previous, &next);
         let forced = matches!(
             reason,
-            SelectionRebuildReason::Startup | SelectionRebuildReason::DynamicConfig
+            SelectionRebuildReason::Startup
+                | SelectionRebuildReason::DynamicConfig
+                | SelectionRebuildReason::ShardRebalance
         );
         let ratio_threshold =
             (previous.len() as f64 * self.selection_resubscribe_min_delta_ratio).ceil() as usize;
@@ -1154,6 +1206,16 @@ impl ArbMonitor {
         stats.last_signal_at = Some(at);
     }
 
+    /// Route a (re)created subscription's trade prints to the recorder and
+    /// its tick-size changes to Redis.
+    fn attach_market_events(&self, updates: &OrderBookSubscription) {
+        if let Some(recorder) = &self.trade_prints {
+            recorder.attach(updates.subscribe_events());
+        }
+        self.signal_publisher
+            .relay_tick_sizes(updates.subscribe_events());
+    }
+
     fn active_subscription_asset_ids(&self) -> Vec<String> {
         let mut assets = HashSet::new();
         for market_id in self
@@ -1173,12 +1235,51 @@ impl ArbMonitor {
         self.active_subscription_asset_ids().len()
     }
 
+    /// Count monitored markets with a two-sided YES book, and how many of
+    /// those have a spread wider than `wide_spread_threshold`.
+    fn spread_health(&self) -> (usize, usize) {
+        let mut sampled = 0usize;
+        let mut wide = 0usize;
+        for market_id in &self.eligible_markets {
+            let Some((yes_id, _)) = self.market_outcomes.get(market_id) else {
+                continue;
+            };
+            let Some(book) = self.order_books.get(&(market_id.clone(), yes_id.clone())) else {
+                continue;
+            };
+            let (Some(bid), Some(ask)) = (book.best_bid(), book.best_ask()) else {
+                continue;
+            };
+            sampled += 1;
+            if ask - bid > self.wide_spread_threshold {
+                wide += 1;
+            }
+        }
+        (sampled, wide)
+    }
+
     /// Process an order book update.
     async fn process_update(
         &mut self,
         update: OrderBookUpdate,
         arb_telemetry: &mut ArbTelemetryCounters,
     ) -> Result<()> {
+        if update.invalidated {
+            // Quarantined upstream: no arb is evaluated against this market
+            // until the resynced book arrives.
+            if self
+                .order_books
+                .remove(&(update.market_id.clone(), update.asset_id.clone()))
+                .is_some()
+            {
+                debug!(
+                    market_id = %update.market_id,
+                    asset_id = %update.asset_id,
+                    "Evicted invalidated order book"
+                );
+            }
+            return Ok(());
+        }
         let eligible_for_entries = self.eligible_markets.contains(&update.market_id);
 
         // Store the updated order book
@@ -1428,14 +1529,8 @@ async fn load_dynamic_values(pool: &sqlx::PgPool) -> HashMap<String, Decimal> {
 }
 
 fn load_allowed_dynamic_sources() -> HashSet<String> {
-    std::env::var("DYNAMIC_CONFIG_ALLOWED_SOURCES")
-        .unwrap_or_else(|_| {
-            "dynamic_tuner,dynamic_tuner_rollback,dynamic_tuner_sync,workspace_manual".to_string()
-        })
-        .split(',')
-        .map(str::trim)
-        .filter(|entry| !entry.is_empty())
-        .map(ToString::to_string)
+    polymarket_core::settings::get::<Vec<String>>("DYNAMIC_CONFIG_ALLOWED_SOURCES")
+        .into_iter()
         .collect()
 }
 
@@ -1972,8 +2067,8 @@ mod tests {
             Decimal::new(5, 3),
             2,
             0,
-            DEFAULT_EXPLORATION_HOLD_SECS,
-            DEFAULT_EXPLORATION_SWAP_MIN_SCORE_DELTA,
+            polymarket_core::settings::get("ARB_EXPLORATION_HOLD_SECS"),
+            polymarket_core::settings::get("ARB_EXPLORATION_SWAP_MIN_SCORE_DELTA"),
             now,
         );
 
@@ -2035,8 +2130,8 @@ mod tests {
             Decimal::new(5, 3),
             3,
             1,
-            DEFAULT_EXPLORATION_HOLD_SECS,
-            DEFAULT_EXPLORATION_SWAP_MIN_SCORE_DELTA,
+            polymarket_core::settings::get("ARB_EXPLORATION_HOLD_SECS"),
+            polymarket_core::settings::get("ARB_EXPLORATION_SWAP_MIN_SCORE_DELTA"),
             now,
         );
 
@@ -0,0 +1,381 @@
+//! Horizontal sharding of the monitored market universe.
+//!
+//! Each arb-monitor instance holds a lease in a Redis sorted set (score =
+//! lease expiry). Live members split the eligible markets with rendezvous
+//! hashing: every market goes to the member with the highest hash weight, so
+//! a join or departure only moves the markets that change owner. Instances
+//! renew their lease on every heartbeat and rebalance when the member set
+//! changes; a dead peer drops out once its lease expires.
+//!
+//! Runtime stats are written per shard and merged into the single
+//! `arb:runtime:stats:latest` snapshot the dynamic tuner reads. Only the
+//! leader (the lowest live instance id) publishes that snapshot; when it
+//! dies, the next member takes over once its lease expires.
+
+use crate::signals::{channels, RuntimeStats};
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use redis::AsyncCommands;
+use tracing::info;
+
+/// Configuration for multi-instance market sharding.
+#[derive(Debug, Clone)]
+pub struct ShardConfig {
+    /// Whether this instance coordinates with peers. Off = scan everything.
+    pub enabled: bool,
+    /// Unique name of this instance within the shard group.
+    pub instance_id: String,
+    /// How often the lease is renewed and membership re-read.
+    pub heartbeat_secs: u64,
+    /// Lease lifetime; a peer that misses renewals this long is considered dead.
+    pub lease_ttl_secs: u64,
+}
+
+impl ShardConfig {
+    /// Create config from environment variables.
+    pub fn from_env() -> Self {
+        let instance_id = polymarket_core::settings::get_opt("ARB_MONITOR_INSTANCE_ID")
+            .or_else(|| polymarket_core::settings::var("HOSTNAME").ok())
+            .unwrap_or_else(|| format!("arb-monitor-{}", uuid::Uuid::new_v4()));
+        let heartbeat_secs =
+            polymarket_core::settings::get::<u64>("ARB_MONITOR_SHARD_HEARTBEAT_SECS");
+        Self {
+            enabled: polymarket_core::settings::get("ARB_MONITOR_SHARDING_ENABLED"),
+            instance_id,
+            heartbeat_secs,
+            lease_ttl_secs: polymarket_core::settings::get_opt("ARB_MONITOR_SHARD_LEASE_TTL_SECS")
+                .filter(|value| *value > heartbeat_secs)
+                .unwrap_or(heartbeat_secs * 3),
+        }
+    }
+}
+
+/// Lease-based membership and market ownership for one instance.
+pub struct ShardCoordinator {
+    redis: redis::aio::ConnectionManager,
+    config: ShardConfig,
+    /// Live members, sorted; always contains this instance.
+    members: Vec<String>,
+}
+
+impl ShardCoordinator {
+    /// Register this instance and load the current member set.
+    pub async fn join(redis_client: redis::Client, config: ShardConfig) -> Result<Self> {
+        let redis = redis::aio::ConnectionManager::new(redis_client).await?;
+        let mut coordinator = Self {
+            redis,
+            members: vec![config.instance_id.clone()],
+            config,
+        };
+        coordinator.heartbeat().await?;
+        info!(
+            instance_id = %coordinator.config.instance_id,
+            members = coordinator.members.len(),
+            "Joined arb-monitor shard group"
+        );
+        Ok(coordinator)
+    }
+
+    pub fn instance_id(&self) -> &str {
+        &self.config.instance_id
+    }
+
+    pub fn members(&self) -> &[String] {
+        &self.members
+    }
+
+    pub fn heartbeat_secs(&self) -> u64 {
+        self.config.heartbeat_secs
+    }
+
+    /// Whether this instance is responsible for `market_id`.
+    pub fn owns(&self, market_id: &str) -> bool {
+        shard_owner(market_id, &self.members)
+            .map(|owner| owner == self.config.instance_id)
+            .unwrap_or(true)
+    }
+
+    /// Whether this instance publishes the fleet-wide runtime stats.
+    pub fn is_leader(&self) -> bool {
+        shard_leader(&self.members).is_none_or(|leader| leader == self.config.instance_id)
+    }
+
+    /// Renew our lease, expire dead peers and re-read membership.
+    ///
+    /// Returns true when the member set changed since the last heartbeat.
+    pub async fn heartbeat(&mut self) -> Result<bool> {
+        let now_ms = Utc::now().timestamp_millis();
+        let expires_at_ms = now_ms + (self.config.lease_ttl_secs as i64) * 1000;
+        let _: () = self
+            .redis
+            .zadd(
+                channels::SHARD_MEMBERS,
+                &self.config.instance_id,
+                expires_at_ms,
+            )
+            .await?;
+        let _: () = self
+            .redis
+            .zrembyscore(channels::SHARD_MEMBERS, "-inf", now_ms)
+            .await?;
+        let mut members: Vec<String> = self
+            .redis
+            .zrangebyscore(channels::SHARD_MEMBERS, now_ms, "+inf")
+            .await?;
+        if !members.contains(&self.config.instance_id) {
+            members.push(self.config.instance_id.clone());
+        }
+        members.sort();
+        members.dedup();
+
+        let changed = members != self.members;
+        if changed {
+            info!(
+                instance_id = %self.config.instance_id,
+                previous = ?self.members,
+                current = ?members,
+                "Arb-monitor shard membership changed"
+            );
+            self.members = members;
+        }
+        Ok(changed)
+    }
+
+    /// Store this shard's stats and return the stats of every live shard
+    /// (including this one) for aggregation.
+    pub async fn exchange_runtime_stats(
+        &mut self,
+        stats: &RuntimeStats,
+    ) -> Result<Vec<RuntimeStats>> {
+        let payload = serde_json::to_string(stats)?;
+        let _: () = self
+            .redis
+            .set_ex(
+                shard_stats_key(&self.config.instance_id),
+                payload,
+                self.config.lease_ttl_secs.max(120),
+            )
+            .await?;
+        let keys: Vec<String> = self.members.iter().map(|m| shard_stats_key(m)).collect();
+        let raw: Vec<Option<String>> = redis::cmd("MGET")
+            .arg(&keys)
+            .query_async(&mut self.redis)
+            .await?;
+        Ok(raw
+            .into_iter()
+            .flatten()
+            .filter_map(|payload| serde_json::from_str(&payload).ok())
+            .collect())
+    }
+}
+
+fn shard_stats_key(instance_id: &str) -> String {
+    format!("{}{}", channels::RUNTIME_STATS_SHARD_PREFIX, instance_id)
+}
+
+/// Elected publisher among sorted `members`: the lowest instance id.
+pub fn shard_leader(members: &[String]) -> Option<&str> {
+    members.first().map(String::as_str)
+}
+
+/// Rendezvous-hash owner of `market_id` among `members`.
+pub fn shard_owner<'a>(market_id: &str, members: &'a [String]) -> Option<&'a str> {
+    members
+        .iter()
+        .max_by_key(|member| (rendezvous_weight(market_id, member), member.as_str()))
+        .map(String::as_str)
+}
+
+/// FNV-1a over `member \0 market_id`, finished with a splitmix64 mix. Stable
+/// across processes and Rust versions, unlike `DefaultHasher`.
+fn rendezvous_weight(market_id: &str, member: &str) -> u64 {
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for byte in member
+        .as_bytes()
+        .iter()
+        .chain(std::iter::once(&0u8))
+        .chain(market_id.as_bytes())
+    {
+        hash ^= u64::from(*byte);
+        hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+    }
+    hash ^= hash >> 30;
+    hash = hash.wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    hash ^= hash >> 27;
+    hash = hash.wrapping_mul(0x94d0_49bb_1331_11eb);
+    hash ^ (hash >> 31)
+}
+
+/// Merge per-shard runtime stats into one fleet-wide snapshot.
+///
+/// Counters and per-minute rates are summed, "best" values take the max,
+/// the closest threshold gap takes the min, and timestamps take the latest.
+pub fn aggregate_runtime_stats(shards: &[RuntimeStats]) -> RuntimeStats {
+    fn latest(values: impl Iterator<Item = Option<DateTime<Utc>>>) -> Option<DateTime<Utc>> {
+        values.flatten().max()
+    }
+    fn max_of(values: impl Iterator<Item = f64>) -> f64 {
+        values.fold(0.0, f64::max)
+    }
+
+    let sum = |f: fn(&RuntimeStats) -> f64| shards.iter().map(f).sum::<f64>();
+    let max = |f: fn(&RuntimeStats) -> f64| max_of(shards.iter().map(f));
+    let freshest = shards
+        .iter()
+        .max_by_key(|s| s.ws_last_message_at)
+        .cloned()
+        .unwrap_or_default();
+    let last_parse_miss = shards.iter().max_by_key(|s| s.ws_last_parse_miss_at);
+
+    let mut selected_markets: Vec<_> = shards
+        .iter()
+        .flat_map(|s| s.selected_markets.iter().cloned())
+        .collect();
+    selected_markets.sort_by(|a, b| {
+        b.total_score
+            .partial_cmp(&a.total_score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    // Same cap as a single shard's snapshot.
+    selected_markets.truncate(12);
+
+    RuntimeStats {
+        updates_per_minute: sum(|s| s.updates_per_minute),
+        stalls_last_minute: sum(|s| s.stalls_last_minute),
+        resets_last_minute: sum(|s| s.resets_last_minute),
+        ws_text_messages_per_minute: sum(|s| s.ws_text_messages_per_minute),
+        ws_orderbook_updates_per_minute: sum(|s| s.ws_orderbook_updates_per_minute),
+        ws_parse_misses_per_minute: sum(|s| s.ws_parse_misses_per_minute),
+        ws_snapshot_messages_per_minute: sum(|s| s.ws_snapshot_messages_per_minute),
+        ws_price_change_messages_per_minute: sum(|s| s.ws_price_change_messages_per_minute),
+        ws_book_integrity_failures_per_minute: sum(|s| s.ws_book_integrity_failures_per_minute),
+        ws_book_resyncs_per_minute: sum(|s| s.ws_book_resyncs_per_minute),
+        ws_quarantined_assets: sum(|s| s.ws_quarantined_assets),
+        monitored_markets: sum(|s| s.monitored_markets),
+        monitored_assets: sum(|s| s.monitored_assets),
+        evaluated_books_per_minute: sum(|s| s.evaluated_books_per_minute),
+        profitable_books_per_minute: sum(|s| s.profitable_books_per_minute),
+        eligible_profitable_books_per_minute: sum(|s| s.eligible_profitable_books_per_minute),
+        filtered_by_selection_per_minute: sum(|s| s.filtered_by_selection_per_minute),
+        filtered_by_profit_per_minute: sum(|s| s.filtered_by_profit_per_minute),
+        filtered_by_depth_per_minute: sum(|s| s.filtered_by_depth_per_minute),
+        filtered_by_cooldown_per_minute: sum(|s| s.filtered_by_cooldown_per_minute),
+        entry_signals_per_minute: sum(|s| s.entry_signals_per_minute),
+        near_miss_under_1bp_per_minute: sum(|s| s.near_miss_under_1bp_per_minute),
+        near_miss_under_5bps_per_minute: sum(|s| s.near_miss_under_5bps_per_minute),
+        near_miss_under_25bps_per_minute: sum(|s| s.near_miss_under_25bps_per_minute),
+        near_miss_under_50bps_per_minute: sum(|s| s.near_miss_under_50bps_per_minute),
+        gross_positive_but_net_negative_per_minute: sum(|s| {
+            s.gross_positive_but_net_negative_per_minute
+        }),
+        best_gross_profit_bps_per_minute: max(|s| s.best_gross_profit_bps_per_minute),
+        best_net_profit_bps_per_minute: max(|s| s.best_net_profit_bps_per_minute),
+        best_eligible_gross_profit_bps_per_minute: max(|s| {
+            s.best_eligible_gross_profit_bps_per_minute
+        }),
+        best_eligible_net_profit_bps_per_minute: max(|s| s.best_eligible_net_profit_bps_per_minute),
+        best_fee_drag_bps_per_minute: max(|s| s.best_fee_drag_bps_per_minute),
+        closest_threshold_gap_bps_per_minute: shards
+            .iter()
+            .filter_map(|s| s.closest_threshold_gap_bps_per_minute)
+            .reduce(f64::min),
+        selection_refreshes_applied_per_minute: sum(|s| s.selection_refreshes_applied_per_minute),
+        selection_refreshes_suppressed_per_minute: sum(|s| {
+            s.selection_refreshes_suppressed_per_minute
+        }),
+        last_selection_market_delta: sum(|s| s.last_selection_market_delta),
+        last_selection_asset_delta: sum(|s| s.last_selection_asset_delta),
+        core_markets: sum(|s| s.core_markets),
+        exploration_markets: sum(|s| s.exploration_markets),
+        last_rerank_at: latest(shards.iter().map(|s| s.last_rerank_at)),
+        last_resubscribe_at: latest(shards.iter().map(|s| s.last_resubscribe_at)),
+        ws_last_message_at: freshest.ws_last_message_at,
+        ws_last_orderbook_update_at: latest(shards.iter().map(|s| s.ws_last_orderbook_update_at)),
+        ws_last_parse_miss_at: last_parse_miss.and_then(|s| s.ws_last_parse_miss_at),
+        ws_last_parse_miss_kind: last_parse_miss.and_then(|s| s.ws_last_parse_miss_kind.clone()),
+        ws_last_message_kind: freshest.ws_last_message_kind,
+        selected_markets,
+        spread_sampled_markets: sum(|s| s.spread_sampled_markets),
+        wide_spread_markets: sum(|s| s.wide_spread_markets),
+        wide_spread_threshold: max(|s| s.wide_spread_threshold),
+        shard_count: shards.len() as f64,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn members(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn rendezvous_owner_only_moves_markets_to_new_member() {
+        let before = members(&["monitor-a", "monitor-b"]);
+        let after = members(&["monitor-a", "monitor-b", "monitor-c"]);
+        let market_ids: Vec<String> = (0..600).map(|i| format!("0xmarket{i}")).collect();
+
+        let mut moved = 0;
+        let mut counts = std::collections::HashMap::new();
+        for market_id in &market_ids {
+            let old_owner = shard_owner(market_id, &before).unwrap();
+            let new_owner = shard_owner(market_id, &after).unwrap();
+            if old_owner != new_owner {
+                assert_eq!(new_owner, "monitor-c");
+                moved += 1;
+            }
+            *counts.entry(new_owner).or_insert(0) += 1;
+        }
+
+        // Roughly a third of the universe moves, and shards stay balanced.
+        assert!((150..=250).contains(&moved), "moved {moved}");
+        for count in counts.values() {
+            assert!((150..=250).contains(count), "unbalanced: {counts:?}");
+        }
+        assert_eq!(shard_owner("0xmarket1", &[]), None);
+    }
+
+    #[test]
+    fn leader_is_lowest_live_member() {
+        assert_eq!(
+            shard_leader(&members(&["monitor-a", "monitor-b"])),
+            Some("monitor-a")
+        );
+        // The next member takes over once the leader's lease expires.
+        assert_eq!(shard_leader(&members(&["monitor-b"])), Some("monitor-b"));
+        assert_eq!(shard_leader(&[]), None);
+    }
+
+    #[test]
+    fn aggregate_sums_rates_and_keeps_best_values() {
+        let now = Utc::now();
+        let a = RuntimeStats {
+            updates_per_minute: 100.0,
+            monitored_markets: 40.0,
+            best_net_profit_bps_per_minute: 12.0,
+            closest_threshold_gap_bps_per_minute: Some(8.0),
+            ws_last_message_at: Some(now - chrono::Duration::seconds(30)),
+            ws_last_message_kind: Some("book".to_string()),
+            ..Default::default()
+        };
+        let b = RuntimeStats {
+            updates_per_minute: 50.0,
+            monitored_markets: 35.0,
+            best_net_profit_bps_per_minute: 20.0,
+            closest_threshold_gap_bps_per_minute: Some(3.0),
+            ws_last_message_at: Some(now),
+            ws_last_message_kind: Some("price_change".to_string()),
+            ..Default::default()
+        };
+
+        let merged = aggregate_runtime_stats(&[a, b]);
+        assert_eq!(merged.updates_per_minute, 150.0);
+        assert_eq!(merged.monitored_markets, 75.0);
+        assert_eq!(merged.best_net_profit_bps_per_minute, 20.0);
+        assert_eq!(merged.closest_threshold_gap_bps_per_minute, Some(3.0));
+        assert_eq!(merged.ws_last_message_at, Some(now));
+        assert_eq!(merged.ws_last_message_kind.as_deref(), Some("price_change"));
+        assert_eq!(merged.shard_count, 2.0);
+    }
+}
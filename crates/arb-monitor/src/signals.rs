@@ -1,12 +1,24 @@
 //! Signal publishing for arbitrage alerts.
+//!
+//! Entry signals go out over pub/sub or, with `ARB_SIGNAL_TRANSPORT=stream`,
+//! over the durable Redis stream in `polymarket_core::signal_stream`.
 
 use anyhow::Result;
 use chrono::{DateTime, Utc};
 use futures_util::StreamExt;
+use polymarket_core::alerting::{Alert, AlertRouter, Severity};
+use polymarket_core::api::clob::{MarketChannelEvent, TICK_SIZE_CHANNEL};
 use polymarket_core::config::AlertsConfig;
+use polymarket_core::metrics;
+use polymarket_core::signal_stream::{
+    self, SignalStreamConfig, SignalStreamConsumer, SignalStreamMetrics, SignalTransport,
+};
+use polymarket_core::telemetry;
 use polymarket_core::types::ArbOpportunity;
 use redis::AsyncCommands;
-use tracing::{debug, warn};
+use std::sync::Arc;
+use tokio::sync::broadcast;
+use tracing::{debug, instrument, warn};
 
 /// Redis channels for pub/sub.
 #[allow(dead_code)]
@@ -18,13 +30,15 @@ pub mod channels {
     pub const RUNTIME_STATS: &str = "arb:runtime:stats";
     pub const RUNTIME_STATS_LATEST: &str = "arb:runtime:stats:latest";
     pub const DYNAMIC_CONFIG_UPDATES: &str = "dynamic:config:update";
+    pub const RUNTIME_STATS_SHARD_PREFIX: &str = "arb:runtime:stats:shard:";
+    pub const SHARD_MEMBERS: &str = "arb:shard:members";
 }
 
 /// Publishes arbitrage signals to Redis and external alerting services.
 pub struct SignalPublisher {
     redis: redis::aio::ConnectionManager,
-    alerts_config: AlertsConfig,
-    http_client: reqwest::Client,
+    stream_config: SignalStreamConfig,
+    alerts: Arc<AlertRouter>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Default)]
@@ -57,6 +71,12 @@ pub struct RuntimeStats {
     pub ws_snapshot_messages_per_minute: f64,
     #[serde(default)]
     pub ws_price_change_messages_per_minute: f64,
+    #[serde(default)]
+    pub ws_book_integrity_failures_per_minute: f64,
+    #[serde(default)]
+    pub ws_book_resyncs_per_minute: f64,
+    #[serde(default)]
+    pub ws_quarantined_assets: f64,
     pub monitored_markets: f64,
     #[serde(default)]
     pub monitored_assets: f64,
@@ -126,20 +146,40 @@ pub struct RuntimeStats {
     pub ws_last_message_kind: Option<String>,
     #[serde(default)]
     pub selected_markets: Vec<RuntimeMarketInsight>,
+    /// Monitored markets with a two-sided YES book at publish time.
+    #[serde(default)]
+    pub spread_sampled_markets: f64,
+    /// Sampled markets whose YES spread exceeds `wide_spread_threshold`.
+    #[serde(default)]
+    pub wide_spread_markets: f64,
+    #[serde(default)]
+    pub wide_spread_threshold: f64,
+    /// Number of arb-monitor shards merged into this snapshot.
+    #[serde(default)]
+    pub shard_count: f64,
 }
 
 impl SignalPublisher {
     /// Create a new signal publisher.
-    pub async fn new(redis_client: redis::Client, alerts_config: AlertsConfig) -> Result<Self> {
+    pub async fn new(
+        redis_client: redis::Client,
+        stream_config: SignalStreamConfig,
+        alerts_config: AlertsConfig,
+    ) -> Result<Self> {
         let redis = redis::aio::ConnectionManager::new(redis_client).await?;
+        let alerts = Arc::new(
+            AlertRouter::from_env(&alerts_config).with_shared_acknowledgements(redis.clone()),
+        );
+        alerts.spawn_escalation_loop();
         Ok(Self {
             redis,
-            alerts_config,
-            http_client: reqwest::Client::new(),
+            stream_config,
+            alerts,
         })
     }
 
     /// Publish an entry signal for an arbitrage opportunity.
+    #[instrument(name = "arb.signal.publish", skip_all, fields(market_id = %arb.market_id))]
     pub async fn publish_entry_signal(
         &mut self,
         arb: &ArbOpportunity,
@@ -149,21 +189,33 @@ impl SignalPublisher {
         // Freshness in the executor should reflect when we decided to trade,
         // not the upstream order book event time carried by the last leg update.
         entry_signal.timestamp = observed_at;
+        entry_signal.trace_context = telemetry::current_context();
         let payload = serde_json::to_string(&entry_signal)?;
 
         // Publish to Redis
-        let _: () = self.redis.publish(channels::ENTRY, &payload).await?;
+        match self.stream_config.transport {
+            SignalTransport::PubSub => {
+                let _: () = self.redis.publish(channels::ENTRY, &payload).await?;
+            }
+            SignalTransport::Stream => {
+                signal_stream::publish(&mut self.redis, &self.stream_config, &payload).await?;
+            }
+        }
         debug!(
-            "Published entry signal to Redis: {}",
-            entry_signal.market_id
+            transport = self.stream_config.transport.as_str(),
+            "Published entry signal to Redis: {}", entry_signal.market_id
         );
+        metrics::record_signal("arb", "entry");
 
-        // Send external alerts if configured
-        self.send_alerts(&format!(
-            "ARB ENTRY: {} | Cost: {:.4} | Profit: {:.4}",
-            entry_signal.market_id, entry_signal.total_cost, entry_signal.net_profit
-        ))
-        .await?;
+        self.alerts.dispatch_background(
+            Alert::new(
+                "arb.entry",
+                Severity::Info,
+                format!("Arb entry: {}", entry_signal.market_id),
+            )
+            .with_field("total_cost", format!("{:.4}", entry_signal.total_cost))
+            .with_field("net_profit", format!("{:.4}", entry_signal.net_profit)),
+        );
 
         Ok(())
     }
@@ -187,9 +239,17 @@ impl SignalPublisher {
             .redis
             .publish(channels::EXIT, payload.to_string())
             .await?;
+        metrics::record_signal("arb", "exit");
 
-        self.send_alerts(&format!("ARB EXIT: {} | Profit: {:.4}", market_id, profit))
-            .await?;
+        self.alerts.dispatch_background(
+            Alert::new(
+                "arb.exit",
+                Severity::Info,
+                format!("Arb exit: {}", market_id),
+            )
+            .with_field("position_id", position_id)
+            .with_field("profit", format!("{:.4}", profit)),
+        );
 
         Ok(())
     }
@@ -205,56 +265,40 @@ impl SignalPublisher {
         Ok(())
     }
 
-    /// Send alerts to configured external services.
-    async fn send_alerts(&self, message: &str) -> Result<()> {
-        // Telegram
-        if let (Some(token), Some(chat_id)) = (
-            &self.alerts_config.telegram_bot_token,
-            &self.alerts_config.telegram_chat_id,
-        ) {
-            if let Err(e) = self.send_telegram(token, chat_id, message).await {
-                warn!("Failed to send Telegram alert: {}", e);
-            }
-        }
-
-        // Discord
-        if let Some(webhook_url) = &self.alerts_config.discord_webhook_url {
-            if let Err(e) = self.send_discord(webhook_url, message).await {
-                warn!("Failed to send Discord alert: {}", e);
+    /// Republish tick-size changes from a subscription's event stream on
+    /// [`TICK_SIZE_CHANNEL`] until it closes, so api-server snaps order prices
+    /// to the new grid without waiting out its tick cache.
+    pub fn relay_tick_sizes(&self, mut events: broadcast::Receiver<MarketChannelEvent>) {
+        let mut redis = self.redis.clone();
+        tokio::spawn(async move {
+            loop {
+                match events.recv().await {
+                    Ok(MarketChannelEvent::TickSizeChange(change)) => {
+                        let payload = match serde_json::to_string(&change) {
+                            Ok(payload) => payload,
+                            Err(e) => {
+                                warn!(error = %e, "Failed to encode tick size change");
+                                continue;
+                            }
+                        };
+                        let published: redis::RedisResult<()> =
+                            redis.publish(TICK_SIZE_CHANNEL, payload).await;
+                        if let Err(e) = published {
+                            warn!(
+                                asset_id = %change.asset_id,
+                                error = %e,
+                                "Failed to publish tick size change"
+                            );
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        warn!(skipped, "Tick size relay lagged; events dropped");
+                    }
+                    Err(broadcast::error::RecvError::Closed) => return,
+                }
             }
-        }
-
-        Ok(())
-    }
-
-    async fn send_telegram(&self, token: &str, chat_id: &str, message: &str) -> Result<()> {
-        let url = format!("https://api.telegram.org/bot{}/sendMessage", token);
-
-        self.http_client
-            .post(&url)
-            .json(&serde_json::json!({
-                "chat_id": chat_id,
-                "text": message,
-                "parse_mode": "HTML"
-            }))
-            .send()
-            .await?;
-
-        debug!("Sent Telegram alert");
-        Ok(())
-    }
-
-    async fn send_discord(&self, webhook_url: &str, message: &str) -> Result<()> {
-        self.http_client
-            .post(webhook_url)
-            .json(&serde_json::json!({
-                "content": message
-            }))
-            .send()
-            .await?;
-
-        debug!("Sent Discord alert");
-        Ok(())
+        });
     }
 }
 
@@ -289,4 +333,13 @@ impl SignalSubscriber {
     pub async fn next_message(&mut self) -> Option<redis::Msg> {
         self.pubsub.on_message().next().await
     }
+
+    /// Join the entry stream's consumer group (stream transport).
+    pub async fn entry_stream(
+        redis_client: redis::Client,
+        config: SignalStreamConfig,
+    ) -> Result<SignalStreamConsumer> {
+        let metrics = Arc::new(SignalStreamMetrics::default());
+        Ok(SignalStreamConsumer::connect(redis_client, config, metrics).await?)
+    }
 }
@@ -0,0 +1,129 @@
+//! Trade print recording.
+//!
+//! Writes `last_trade_price` events from the market-channel subscription into
+//! `market_trade_prints` in small batches, so flow features see trades within
+//! seconds instead of waiting for the next Data API harvest.
+
+use polymarket_core::api::clob::{MarketChannelEvent, TradePrint};
+use polymarket_core::types::OrderSide;
+use sqlx::PgPool;
+use std::time::Duration as StdDuration;
+use tokio::sync::{broadcast, mpsc};
+use tracing::{debug, info, warn};
+
+/// Trade print recorder configuration.
+#[derive(Debug, Clone)]
+pub struct TradePrintConfig {
+    pub enabled: bool,
+    /// Maximum time a print waits in the buffer.
+    pub flush_interval_ms: u64,
+    /// Flush early once this many prints are buffered.
+    pub max_batch: usize,
+}
+
+impl TradePrintConfig {
+    /// Create config from environment variables.
+    pub fn from_env() -> Self {
+        Self {
+            enabled: polymarket_core::settings::get("TRADE_PRINTS_ENABLED"),
+            flush_interval_ms: polymarket_core::settings::get("TRADE_PRINTS_FLUSH_MS"),
+            max_batch: polymarket_core::settings::get("TRADE_PRINTS_MAX_BATCH"),
+        }
+    }
+}
+
+/// Handle to the background writer; attach each new subscription's events.
+pub struct TradePrintRecorder {
+    tx: mpsc::Sender<TradePrint>,
+}
+
+impl TradePrintRecorder {
+    /// Spawn the writer task, or `None` when recording is disabled.
+    pub fn spawn(pool: PgPool, config: TradePrintConfig) -> Option<Self> {
+        if !config.enabled {
+            info!("Trade print recording disabled (TRADE_PRINTS_ENABLED=false)");
+            return None;
+        }
+        let (tx, rx) = mpsc::channel(config.max_batch.saturating_mul(4));
+        tokio::spawn(writer_loop(pool, config, rx));
+        Some(Self { tx })
+    }
+
+    /// Forward trade prints from a subscription's event stream until it closes.
+    pub fn attach(&self, mut events: broadcast::Receiver<MarketChannelEvent>) {
+        let tx = self.tx.clone();
+        tokio::spawn(async move {
+            loop {
+                match events.recv().await {
+                    Ok(MarketChannelEvent::Trade(print)) => {
+                        // Never block the market channel on the database.
+                        if let Err(mpsc::error::TrySendError::Closed(_)) = tx.try_send(print) {
+                            return;
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        warn!(skipped, "Trade print forwarder lagged; prints dropped");
+                    }
+                    Err(broadcast::error::RecvError::Closed) => return,
+                }
+            }
+        });
+    }
+}
+
+async fn writer_loop(pool: PgPool, config: TradePrintConfig, mut rx: mpsc::Receiver<TradePrint>) {
+    let mut buffer: Vec<TradePrint> = Vec::with_capacity(config.max_batch);
+    let mut flush_tick = tokio::time::interval(StdDuration::from_millis(config.flush_interval_ms));
+
+    loop {
+        tokio::select! {
+            print = rx.recv() => {
+                let Some(print) = print else {
+                    flush(&pool, &mut buffer).await;
+                    return;
+                };
+                buffer.push(print);
+                if buffer.len() >= config.max_batch {
+                    flush(&pool, &mut buffer).await;
+                }
+            }
+            _ = flush_tick.tick() => {
+                flush(&pool, &mut buffer).await;
+            }
+        }
+    }
+}
+
+async fn flush(pool: &PgPool, buffer: &mut Vec<TradePrint>) {
+    if buffer.is_empty() {
+        return;
+    }
+    let prints = std::mem::take(buffer);
+    match insert_prints(pool, &prints).await {
+        Ok(inserted) => debug!(received = prints.len(), inserted, "Recorded trade prints"),
+        Err(e) => warn!(error = %e, dropped = prints.len(), "Failed to record trade prints"),
+    }
+}
+
+async fn insert_prints(pool: &PgPool, prints: &[TradePrint]) -> sqlx::Result<u64> {
+    let mut qb: sqlx::QueryBuilder<sqlx::Postgres> = sqlx::QueryBuilder::new(
+        "INSERT INTO market_trade_prints \
+         (asset_id, condition_id, side, price, size, value, fee_rate_bps, timestamp) ",
+    );
+    qb.push_values(prints, |mut b, print| {
+        b.push_bind(&print.asset_id)
+            .push_bind(&print.market_id)
+            .push_bind(match print.side {
+                OrderSide::Buy => "BUY",
+                OrderSide::Sell => "SELL",
+            })
+            .push_bind(print.price)
+            .push_bind(print.size)
+            .push_bind(print.price * print.size)
+            .push_bind(print.fee_rate_bps.map(|bps| bps as i32))
+            .push_bind(print.timestamp);
+    });
+    qb.push(" ON CONFLICT DO NOTHING");
+    Ok(qb.build().execute(pool).await?.rows_affected())
+}
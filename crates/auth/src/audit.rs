@@ -30,6 +30,8 @@ pub enum AuditAction {
     ClosePosition,
     ManualExit,
     EmergencyExitAll,
+    CancelOrder,
+    CancelAllOrders,
 
     // Risk Management
     CreateStopLoss,
@@ -71,6 +71,8 @@ fn parse_action(action: &str) -> AuditAction {
         "close_position" => AuditAction::ClosePosition,
         "manual_exit" => AuditAction::ManualExit,
         "emergency_exit_all" => AuditAction::EmergencyExitAll,
+        "cancel_order" => AuditAction::CancelOrder,
+        "cancel_all_orders" => AuditAction::CancelAllOrders,
         "create_stop_loss" => AuditAction::CreateStopLoss,
         "remove_stop_loss" => AuditAction::RemoveStopLoss,
         "stop_loss_triggered" => AuditAction::StopLossTriggered,
@@ -101,6 +103,8 @@ fn action_to_string(action: &AuditAction) -> String {
         AuditAction::ClosePosition => "close_position".to_string(),
         AuditAction::ManualExit => "manual_exit".to_string(),
         AuditAction::EmergencyExitAll => "emergency_exit_all".to_string(),
+        AuditAction::CancelOrder => "cancel_order".to_string(),
+        AuditAction::CancelAllOrders => "cancel_all_orders".to_string(),
         AuditAction::CreateStopLoss => "create_stop_loss".to_string(),
         AuditAction::RemoveStopLoss => "remove_stop_loss".to_string(),
         AuditAction::StopLossTriggered => "stop_loss_triggered".to_string(),
@@ -5,7 +5,8 @@ use polymarket_core::api::PolygonClient;
 use polymarket_core::config::Config;
 use polymarket_core::db;
 use polymarket_core::db::wallets::WalletRepository;
-use polymarket_core::types::BotScore;
+use polymarket_core::metrics::{self, MetricsServerConfig};
+use polymarket_core::types::{BotScore, WalletClassification};
 use sqlx::PgPool;
 use std::collections::HashSet;
 use std::time::Duration;
@@ -36,6 +37,11 @@ impl TradeIndexer {
         let pool = db::create_pool(&config.database).await?;
         let wallet_repo = WalletRepository::new(pool.clone());
 
+        let metrics_pool = pool.clone();
+        metrics::spawn_metrics_server(MetricsServerConfig::from_env(9102), move || {
+            metrics::observe_db_pool(&metrics_pool)
+        });
+
         let polygon = match config.polygon.get_rpc_url() {
             Some(url) => PolygonClient::new(url),
             None => {
@@ -155,6 +161,14 @@ impl TradeIndexer {
 
         // Calculate bot score
         let score = BotScore::new(address.to_string(), &features);
+        metrics::record_signal(
+            "bot_detection",
+            match score.classification {
+                WalletClassification::LikelyHuman => "likely_human",
+                WalletClassification::Suspicious => "suspicious",
+                WalletClassification::LikelyBot => "likely_bot",
+            },
+        );
 
         // Log interesting findings
         if score.total_score >= 25 {
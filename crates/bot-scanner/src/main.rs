@@ -8,8 +8,9 @@ mod scorer;
 
 use anyhow::Result;
 use polymarket_core::config::Config;
+use polymarket_core::settings::{self, ServiceArgs};
+use polymarket_core::telemetry;
 use tracing::info;
-use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 const HEALTH_FILE: &str = "/tmp/healthy";
 
@@ -19,14 +20,13 @@ fn touch_health_file() {
 
 #[tokio::main]
 async fn main() -> Result<()> {
+    // Load .env and the config file, refusing to start on invalid settings
+    let args = ServiceArgs::from_env();
+    settings::startup(args.config.as_deref(), args.print_config)?;
+
     // Initialize logging
-    tracing_subscriber::registry()
-        .with(
-            tracing_subscriber::EnvFilter::try_from_default_env()
-                .unwrap_or_else(|_| "bot_scanner=info,polymarket_core=info".into()),
-        )
-        .with(tracing_subscriber::fmt::layer())
-        .init();
+    let _telemetry =
+        telemetry::init_tracing("bot-scanner", "bot_scanner=info,polymarket_core=info");
 
     info!("Starting Bot Scanner");
     touch_health_file();
@@ -36,11 +36,8 @@ async fn main() -> Result<()> {
 
     // For now, run in single-wallet analysis mode
     // Future: continuous indexing mode
-    let args: Vec<String> = std::env::args().collect();
-
-    if args.len() > 1 {
+    if let Some(wallet_address) = args.rest.first() {
         // Analyze specific wallet
-        let wallet_address = &args[1];
         analyze_wallet(&config, wallet_address).await?;
     } else {
         // Run continuous indexer
@@ -0,0 +1,29 @@
+//! Audit records for operator actions taken through the CLI.
+
+use auth::{AuditAction, AuditEvent, AuditStorage, PostgresAuditStorage};
+use sqlx::PgPool;
+
+/// The operator running the CLI, from the login name.
+pub fn operator() -> String {
+    let user = std::env::var("USER")
+        .or_else(|_| std::env::var("LOGNAME"))
+        .unwrap_or_else(|_| "unknown".to_string());
+    format!("cli:{user}")
+}
+
+/// Write an audit row for an operator action.
+pub async fn record(
+    pool: &PgPool,
+    action: AuditAction,
+    resource: impl Into<String>,
+    details: serde_json::Value,
+) -> anyhow::Result<()> {
+    let event = AuditEvent::builder(action, resource)
+        .user(operator())
+        .details(details)
+        .build();
+    PostgresAuditStorage::new(pool.clone())
+        .store(&event)
+        .await?;
+    Ok(())
+}
@@ -0,0 +1,145 @@
+//! `abbot breaker`: the persisted circuit breaker.
+//!
+//! Changes are written to `circuit_breaker_state` and broadcast on the
+//! dynamic config channel, so a running api-server trips or resets its live
+//! breaker straight away. Each change is recorded in `audit_log` with the
+//! operator and reason.
+
+use api_server::dynamic_tuner::publish_circuit_breaker_command;
+use auth::AuditAction;
+use clap::Subcommand;
+use polymarket_core::settings;
+use risk_manager::circuit_breaker::{CircuitBreaker, CircuitBreakerConfig, CircuitBreakerState};
+use sqlx::PgPool;
+use std::time::Duration;
+
+use crate::audit;
+use crate::output::{or_dash, print_json};
+
+/// How long to wait for Redis before leaving the change to the next restart.
+const REDIS_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Subcommand)]
+pub enum BreakerCommand {
+    /// Show the persisted breaker state
+    Status {
+        /// Print JSON instead of a summary
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Halt trading
+    Trip {
+        /// Reason recorded in the logs and the audit log
+        #[arg(long)]
+        reason: Option<String>,
+    },
+
+    /// Resume trading, including after a hard-kill trip
+    Reset {
+        /// Reason recorded in the audit log
+        #[arg(long)]
+        reason: Option<String>,
+    },
+}
+
+pub async fn run(command: BreakerCommand, pool: PgPool) -> anyhow::Result<()> {
+    let config = CircuitBreakerConfig {
+        cooldown_minutes: settings::get("CB_COOLDOWN_MINUTES"),
+        ..CircuitBreakerConfig::default()
+    };
+    let breaker = CircuitBreaker::with_persistence(config, pool.clone());
+    breaker.load_state().await?;
+
+    match command {
+        BreakerCommand::Status { json } => {
+            let state = breaker.state().await;
+            if json {
+                return print_json(&state);
+            }
+            print_summary(&state);
+        }
+        BreakerCommand::Trip { reason } => {
+            let reason = reason.unwrap_or_else(|| "manual trip from abbot".to_string());
+            breaker.manual_trip(Some(reason.clone())).await;
+            let broadcast = broadcast(true, &reason).await;
+            record(
+                &pool,
+                AuditAction::CircuitBreakerTripped,
+                &reason,
+                &broadcast,
+            )
+            .await?;
+            print_summary(&breaker.state().await);
+            print_broadcast(&broadcast);
+        }
+        BreakerCommand::Reset { reason } => {
+            let reason = reason.unwrap_or_else(|| "manual reset from abbot".to_string());
+            breaker.reset().await;
+            let broadcast = broadcast(false, &reason).await;
+            record(&pool, AuditAction::CircuitBreakerReset, &reason, &broadcast).await?;
+            print_summary(&breaker.state().await);
+            print_broadcast(&broadcast);
+        }
+    }
+    Ok(())
+}
+
+/// Tell running api-servers about the change over Redis.
+async fn broadcast(tripped: bool, reason: &str) -> anyhow::Result<()> {
+    let redis_url: String = settings::get("REDIS_URL");
+    let publish = async {
+        let client = redis::Client::open(redis_url)?;
+        let mut redis = redis::aio::ConnectionManager::new(client).await?;
+        publish_circuit_breaker_command(&mut redis, tripped, reason, &audit::operator()).await
+    };
+    tokio::time::timeout(REDIS_TIMEOUT, publish)
+        .await
+        .map_err(|_| anyhow::anyhow!("timed out connecting to Redis"))?
+}
+
+async fn record(
+    pool: &PgPool,
+    action: AuditAction,
+    reason: &str,
+    broadcast: &anyhow::Result<()>,
+) -> anyhow::Result<()> {
+    audit::record(
+        pool,
+        action,
+        "circuit_breaker",
+        serde_json::json!({
+            "reason": reason,
+            "broadcast": broadcast.is_ok(),
+        }),
+    )
+    .await
+}
+
+fn print_broadcast(broadcast: &anyhow::Result<()>) {
+    match broadcast {
+        Ok(()) => println!("\nBroadcast to running api-server instances."),
+        Err(e) => println!(
+            "\nCould not broadcast the change ({e}); restart api-server for a running instance to pick it up."
+        ),
+    }
+}
+
+fn print_summary(state: &CircuitBreakerState) {
+    println!("tripped:            {}", state.tripped);
+    println!(
+        "trip reason:        {}",
+        or_dash(state.trip_reason.as_ref().map(|r| format!("{r:?}")))
+    );
+    println!("tripped at:         {}", or_dash(state.tripped_at));
+    println!("resume at:          {}", or_dash(state.resume_at));
+    println!("daily pnl:          {}", state.daily_pnl.round_dp(2));
+    println!("consecutive losses: {}", state.consecutive_losses);
+    println!("trips today:        {}", state.trips_today);
+    if let Some(recovery) = &state.recovery_state {
+        println!(
+            "recovery stage:     {}/{}",
+            recovery.current_stage, recovery.total_stages
+        );
+    }
+}
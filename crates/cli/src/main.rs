@@ -0,0 +1,136 @@
+//! `abbot` operator CLI.
+//!
+//! Lists and closes positions, inspects and cancels CLOB orders, trips and
+//! resets the circuit breaker, previews recovery and dumps trade events. Every
+//! command talks to Postgres (and the CLOB for `orders`) directly, so it keeps
+//! working when api-server or the dashboard is down. Reads the same `.env`,
+//! `--config` file and environment variables as the services.
+
+use clap::{Parser, Subcommand};
+use polymarket_core::config::DatabaseConfig;
+use polymarket_core::settings;
+use std::path::PathBuf;
+
+mod audit;
+mod breaker;
+mod orders;
+mod output;
+mod positions;
+mod recovery;
+mod trade_events;
+
+/// Operator CLI for the Polymarket trading platform
+#[derive(Parser)]
+#[command(name = "abbot")]
+#[command(about = "Inspect and operate positions, orders, risk and recovery without the dashboard")]
+#[command(version)]
+struct Cli {
+    #[command(subcommand)]
+    command: Commands,
+
+    /// TOML config file (defaults to $ABBOT_CONFIG); environment variables override it
+    #[arg(long, global = true)]
+    config: Option<PathBuf>,
+
+    /// Print the effective configuration with secrets redacted, then exit
+    #[arg(long, global = true)]
+    print_config: bool,
+}
+
+#[derive(Subcommand)]
+enum Commands {
+    /// List, inspect and close positions
+    #[command(subcommand)]
+    Positions(positions::PositionsCommand),
+
+    /// Inspect and cancel resting CLOB orders for the trading wallet
+    #[command(subcommand)]
+    Orders(orders::OrdersCommand),
+
+    /// Show, trip or reset the persisted circuit breaker
+    #[command(subcommand)]
+    Breaker(breaker::BreakerCommand),
+
+    /// Preview what a recovery run would act on
+    #[command(subcommand)]
+    Recovery(recovery::RecoveryCommand),
+
+    /// Dump recorded trade events for a market
+    TradeEvents(trade_events::TradeEventsArgs),
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+
+    settings::startup(cli.config.as_deref(), cli.print_config)?;
+
+    // Output is for the operator; keep library logs to warnings unless asked.
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            tracing_subscriber::EnvFilter::try_from_default_env().unwrap_or_else(|_| "warn".into()),
+        )
+        .with_writer(std::io::stderr)
+        .init();
+
+    match cli.command {
+        Commands::Positions(cmd) => positions::run(cmd, &connect().await?).await,
+        Commands::Orders(cmd) => orders::run(cmd).await,
+        Commands::Breaker(cmd) => breaker::run(cmd, connect().await?).await,
+        Commands::Recovery(cmd) => recovery::run(cmd, &connect().await?).await,
+        Commands::TradeEvents(args) => trade_events::run(args, &connect().await?).await,
+    }
+}
+
+/// Small pool with a single connection attempt: an operator wants a fast
+/// failure, not the services' retry loop.
+async fn connect() -> anyhow::Result<sqlx::PgPool> {
+    let url = settings::get_opt::<String>("DATABASE_URL")
+        .ok_or_else(|| anyhow::anyhow!("DATABASE_URL must be set"))?;
+    let config = DatabaseConfig {
+        url,
+        max_connections: 2,
+        max_retries: 0,
+        retry_base_delay_ms: 0,
+        retry_max_delay_ms: 0,
+        acquire_timeout_secs: Some(10),
+    };
+    Ok(polymarket_core::db::create_pool(&config).await?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subcommands_parse() {
+        let cli = Cli::parse_from(["abbot", "breaker", "trip", "--reason", "halt"]);
+        assert!(matches!(
+            cli.command,
+            Commands::Breaker(breaker::BreakerCommand::Trip { reason: Some(ref r) }) if r == "halt"
+        ));
+
+        let cli = Cli::parse_from([
+            "abbot",
+            "--config",
+            "abbot.toml",
+            "orders",
+            "cancel",
+            "--all",
+        ]);
+        assert_eq!(cli.config, Some(PathBuf::from("abbot.toml")));
+        assert!(matches!(
+            cli.command,
+            Commands::Orders(orders::OrdersCommand::Cancel { all: true, .. })
+        ));
+
+        let cli = Cli::parse_from(["abbot", "breaker", "reset", "--reason", "cleared"]);
+        assert!(matches!(
+            cli.command,
+            Commands::Breaker(breaker::BreakerCommand::Reset { reason: Some(ref r) }) if r == "cleared"
+        ));
+
+        assert!(Cli::try_parse_from(["abbot", "orders", "cancel"]).is_err());
+        assert!(Cli::try_parse_from(["abbot", "orders", "cancel", "abc", "--all"]).is_err());
+    }
+}
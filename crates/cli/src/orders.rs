@@ -0,0 +1,123 @@
+//! `abbot orders`: resting CLOB orders for the trading wallet.
+
+use auth::{AuditAction, TradingWallet};
+use clap::Subcommand;
+use polymarket_core::api::clob::AuthenticatedClobClient;
+use polymarket_core::api::ClobClient;
+use polymarket_core::settings;
+use polymarket_core::signing::OrderSigner;
+
+use crate::audit;
+use crate::output::{or_dash, print_json, print_table};
+
+#[derive(Subcommand)]
+pub enum OrdersCommand {
+    /// List open orders
+    List {
+        /// Only orders in this market (condition id)
+        #[arg(long)]
+        market: Option<String>,
+
+        /// Print JSON instead of a table
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Cancel orders by id, or every open order with --all
+    Cancel {
+        /// Order ids to cancel
+        #[arg(required_unless_present = "all", conflicts_with = "all")]
+        ids: Vec<String>,
+
+        /// Cancel every open order for the wallet
+        #[arg(long)]
+        all: bool,
+    },
+}
+
+pub async fn run(command: OrdersCommand) -> anyhow::Result<()> {
+    let client = connect().await?;
+    match command {
+        OrdersCommand::List { market, json } => {
+            let orders = client.get_open_orders(market.as_deref()).await?;
+            if json {
+                return print_json(&orders);
+            }
+            let rows: Vec<Vec<String>> = orders
+                .into_iter()
+                .map(|o| {
+                    vec![
+                        o.id,
+                        o.market,
+                        o.asset_id,
+                        o.side,
+                        o.price,
+                        o.size,
+                        o.status,
+                        or_dash(o.created_at),
+                    ]
+                })
+                .collect();
+            print_table(
+                &[
+                    "ID", "MARKET", "TOKEN", "SIDE", "PRICE", "SIZE", "STATUS", "CREATED",
+                ],
+                &rows,
+            );
+            Ok(())
+        }
+        OrdersCommand::Cancel { all: true, .. } => {
+            let pool = crate::connect().await?;
+            client.cancel_all_orders().await?;
+            let wallet = client.address();
+            audit::record(
+                &pool,
+                AuditAction::CancelAllOrders,
+                format!("wallet:{wallet}"),
+                serde_json::json!({ "wallet": wallet }),
+            )
+            .await?;
+            println!("Cancelled all open orders for {wallet}");
+            Ok(())
+        }
+        OrdersCommand::Cancel { ids, .. } => {
+            let pool = crate::connect().await?;
+            let wallet = client.address();
+            let mut failed = 0;
+            for id in &ids {
+                match client.cancel_order(id).await {
+                    Ok(()) => {
+                        audit::record(
+                            &pool,
+                            AuditAction::CancelOrder,
+                            format!("order:{id}"),
+                            serde_json::json!({ "wallet": wallet }),
+                        )
+                        .await?;
+                        println!("Cancelled {id}");
+                    }
+                    Err(e) => {
+                        failed += 1;
+                        eprintln!("Failed to cancel {id}: {e}");
+                    }
+                }
+            }
+            if failed > 0 {
+                anyhow::bail!("{failed} of {} cancels failed", ids.len());
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Authenticated client for the wallet in `WALLET_PRIVATE_KEY`.
+async fn connect() -> anyhow::Result<AuthenticatedClobClient> {
+    let wallet = TradingWallet::from_env()?;
+    let clob_url = settings::get_opt::<String>("POLYMARKET_CLOB_URL");
+    let mut client = AuthenticatedClobClient::new(
+        ClobClient::new(clob_url, None),
+        OrderSigner::new(wallet.into_signer()),
+    );
+    client.create_or_derive_api_key().await?;
+    Ok(client)
+}
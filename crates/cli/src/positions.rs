@@ -0,0 +1,152 @@
+//! `abbot positions`: list, inspect and close positions.
+
+use anyhow::Context;
+use api_server::handlers::positions::{position_state_label, trade_event_labels};
+use api_server::position_service::{EventContext, ManualCloseError, PositionService};
+use auth::AuditAction;
+use clap::Subcommand;
+use polymarket_core::db::positions::PositionRepository;
+use polymarket_core::settings;
+use polymarket_core::types::Position;
+use sqlx::PgPool;
+use tokio::sync::broadcast;
+use uuid::Uuid;
+
+use crate::audit;
+use crate::output::{or_dash, print_json, print_table};
+
+#[derive(Subcommand)]
+pub enum PositionsCommand {
+    /// List positions that are not closed or entry-failed
+    List {
+        /// Only positions in this state (e.g. open, exit_ready, stalled)
+        #[arg(long)]
+        state: Option<String>,
+
+        /// Only positions in this market
+        #[arg(long)]
+        market: Option<String>,
+
+        /// Print JSON instead of a table
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Show every field of one position as JSON
+    Show { id: Uuid },
+
+    /// Queue a full market exit; api-server's exit handler places the sells
+    Close {
+        id: Uuid,
+
+        /// Reason recorded in the audit log
+        #[arg(long)]
+        reason: Option<String>,
+    },
+}
+
+pub async fn run(command: PositionsCommand, pool: &PgPool) -> anyhow::Result<()> {
+    let repo = PositionRepository::new(pool.clone());
+    match command {
+        PositionsCommand::List {
+            state,
+            market,
+            json,
+        } => {
+            let positions: Vec<Position> = repo
+                .get_active()
+                .await?
+                .into_iter()
+                .filter(|p| {
+                    state
+                        .as_deref()
+                        .is_none_or(|s| position_state_label(p.state) == s)
+                })
+                .filter(|p| market.as_deref().is_none_or(|m| p.market_id == m))
+                .collect();
+            if json {
+                return print_json(&positions);
+            }
+            let rows: Vec<Vec<String>> = positions
+                .iter()
+                .map(|p| {
+                    vec![
+                        p.id.to_string(),
+                        p.market_id.clone(),
+                        position_state_label(p.state).to_string(),
+                        p.held_yes_qty.normalize().to_string(),
+                        p.held_no_qty.normalize().to_string(),
+                        p.unrealized_pnl.round_dp(2).to_string(),
+                        p.last_updated.format("%Y-%m-%d %H:%M:%S").to_string(),
+                        or_dash(p.failure_reason.as_ref().map(|r| format!("{r:?}"))),
+                    ]
+                })
+                .collect();
+            print_table(
+                &[
+                    "ID", "MARKET", "STATE", "YES", "NO", "UPNL", "UPDATED", "FAILURE",
+                ],
+                &rows,
+            );
+            Ok(())
+        }
+        PositionsCommand::Show { id } => {
+            let position = repo
+                .get(id)
+                .await?
+                .with_context(|| format!("position {id} not found"))?;
+            print_json(&position)
+        }
+        PositionsCommand::Close { id, reason } => close(pool, id, reason).await,
+    }
+}
+
+async fn close(pool: &PgPool, id: Uuid, reason: Option<String>) -> anyhow::Result<()> {
+    let source: i16 = sqlx::query_scalar("SELECT COALESCE(source, 0) FROM positions WHERE id = $1")
+        .bind(id)
+        .fetch_optional(pool)
+        .await?
+        .with_context(|| format!("position {id} not found"))?;
+
+    // Nothing subscribes here; the recorded trade event is what the dashboard
+    // and api-server pick up.
+    let (trade_event_tx, _) = broadcast::channel(16);
+    let service = PositionService::new(pool.clone(), trade_event_tx);
+    let mut position = service
+        .repo()
+        .get(id)
+        .await?
+        .with_context(|| format!("position {id} not found"))?;
+
+    let live = settings::get("LIVE_TRADING");
+    let (strategy, source_label) = trade_event_labels(source);
+    let ctx = EventContext {
+        execution_mode: if live { "live" } else { "paper" }.to_string(),
+        strategy: strategy.to_string(),
+        source_label: source_label.to_string(),
+    };
+
+    match service.queue_manual_close(&mut position, &ctx).await {
+        Ok(()) => {
+            audit::record(
+                pool,
+                AuditAction::ManualExit,
+                format!("position:{id}"),
+                serde_json::json!({
+                    "reason": reason.as_deref().unwrap_or("manual close from abbot"),
+                    "market_id": position.market_id,
+                    "state": position_state_label(position.state),
+                }),
+            )
+            .await?;
+            println!(
+                "Position {id} is {}; api-server's exit handler will sell the held tokens",
+                position_state_label(position.state)
+            );
+            Ok(())
+        }
+        Err(ManualCloseError::NotOpen) => anyhow::bail!("position {id} is already closed"),
+        Err(ManualCloseError::Rejected(reason)) => anyhow::bail!(reason),
+        Err(ManualCloseError::Internal(e)) => Err(e),
+    }
+}
@@ -0,0 +1,73 @@
+//! `abbot recovery`: the same buckets as `GET /api/v1/recovery/preview`.
+
+use api_server::handlers::recovery::{load_recovery_buckets, RecoveryBucketSummary};
+use api_server::wallet_inventory::resolve_stored_wallet_address;
+use clap::Subcommand;
+use sqlx::PgPool;
+
+use crate::output::{or_dash, print_json, print_table};
+
+#[derive(Subcommand)]
+pub enum RecoveryCommand {
+    /// Bucket open positions and orphaned inventory by how they can be recovered
+    Preview {
+        /// Wallet whose inventory to include (defaults to the canonical workspace wallet)
+        #[arg(long)]
+        wallet: Option<String>,
+
+        /// Print JSON instead of a table
+        #[arg(long)]
+        json: bool,
+    },
+}
+
+pub async fn run(command: RecoveryCommand, pool: &PgPool) -> anyhow::Result<()> {
+    let RecoveryCommand::Preview { wallet, json } = command;
+    let wallet = match wallet {
+        Some(address) => Some(address.to_lowercase()),
+        None => resolve_stored_wallet_address(pool).await?,
+    };
+    let (buckets, inventory) = load_recovery_buckets(pool, wallet.as_deref()).await?;
+
+    if json {
+        return print_json(&serde_json::json!({
+            "wallet_address": wallet,
+            "safe_recovery": buckets.safe_recovery(),
+            "buckets": buckets,
+            "inventory_last_synced_at": inventory.as_ref().and_then(|s| s.inventory_last_synced_at),
+        }));
+    }
+
+    println!("wallet: {}", or_dash(wallet.as_deref()));
+    if wallet.is_some() {
+        println!(
+            "inventory synced: {}",
+            or_dash(inventory.and_then(|s| s.inventory_last_synced_at))
+        );
+    } else {
+        println!("no trading wallet found; orphaned inventory is not included");
+    }
+    println!();
+
+    let row = |name: &str, bucket: &RecoveryBucketSummary| {
+        vec![
+            name.to_string(),
+            bucket.positions.to_string(),
+            bucket.marked_value.round_dp(2).to_string(),
+        ]
+    };
+    print_table(
+        &["BUCKET", "POSITIONS", "MARKED VALUE"],
+        &[
+            row("recoverable_now", &buckets.recoverable_now),
+            row("liquidity_blocked", &buckets.liquidity_blocked),
+            row("stalled", &buckets.stalled),
+            row("open_monitoring", &buckets.open_monitoring),
+            row("other_blocked", &buckets.other_blocked),
+            row("orphan_inventory", &buckets.orphan_inventory),
+            row("suspect_inventory", &buckets.suspect_inventory),
+            row("safe_recovery", &buckets.safe_recovery()),
+        ],
+    );
+    Ok(())
+}
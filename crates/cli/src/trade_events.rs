@@ -0,0 +1,97 @@
+//! `abbot trade-events`: the recorded lifecycle of one market.
+
+use anyhow::Context;
+use api_server::trade_events::load_market_events;
+use chrono::{DateTime, Duration, Utc};
+use clap::Args;
+use sqlx::PgPool;
+
+use crate::output::{or_dash, print_json, print_table};
+
+#[derive(Args)]
+pub struct TradeEventsArgs {
+    /// Market (condition) id
+    market_id: String,
+
+    /// Only events after this RFC 3339 time or age such as 30m, 6h or 2d
+    #[arg(long, value_parser = parse_since)]
+    since: Option<DateTime<Utc>>,
+
+    /// Most recent events to show
+    #[arg(long, default_value_t = 200)]
+    limit: i64,
+
+    /// Print JSON instead of a table
+    #[arg(long)]
+    json: bool,
+}
+
+pub async fn run(args: TradeEventsArgs, pool: &PgPool) -> anyhow::Result<()> {
+    let events = load_market_events(pool, &args.market_id, args.since, args.limit)
+        .await
+        .context("failed to load trade events")?;
+    if args.json {
+        return print_json(&events);
+    }
+    let rows: Vec<Vec<String>> = events
+        .into_iter()
+        .map(|e| {
+            vec![
+                e.occurred_at.format("%Y-%m-%d %H:%M:%S").to_string(),
+                e.event_type,
+                e.strategy,
+                e.execution_mode,
+                or_dash(e.position_id),
+                match (e.state_from, e.state_to) {
+                    (None, None) => "-".to_string(),
+                    (from, to) => format!("{} -> {}", or_dash(from), or_dash(to)),
+                },
+                or_dash(e.fill_price),
+                or_dash(e.realized_pnl),
+                or_dash(e.reason),
+            ]
+        })
+        .collect();
+    print_table(
+        &[
+            "TIME", "EVENT", "STRATEGY", "MODE", "POSITION", "STATE", "FILL", "PNL", "REASON",
+        ],
+        &rows,
+    );
+    Ok(())
+}
+
+fn parse_since(value: &str) -> Result<DateTime<Utc>, String> {
+    if let Ok(at) = DateTime::parse_from_rfc3339(value) {
+        return Ok(at.with_timezone(&Utc));
+    }
+    let split = value.len().saturating_sub(1);
+    let (amount, unit) = value.split_at(split);
+    let amount: i64 = amount
+        .parse()
+        .map_err(|_| format!("expected RFC 3339 time or age like 6h, got {value:?}"))?;
+    let age = match unit {
+        "m" => Duration::minutes(amount),
+        "h" => Duration::hours(amount),
+        "d" => Duration::days(amount),
+        _ => return Err(format!("unknown age unit in {value:?}; use m, h or d")),
+    };
+    Ok(Utc::now() - age)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_since_accepts_ages_and_timestamps() {
+        let age = Utc::now() - parse_since("6h").unwrap();
+        assert!((age - Duration::hours(6)).num_seconds().abs() < 5);
+        assert_eq!(
+            parse_since("2026-01-02T03:04:05Z").unwrap().to_rfc3339(),
+            "2026-01-02T03:04:05+00:00"
+        );
+        assert!(parse_since("6w").is_err());
+        assert!(parse_since("soon").is_err());
+    }
+}
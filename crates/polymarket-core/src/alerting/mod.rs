@@ -0,0 +1,136 @@
+//! Operator alerting.
+//!
+//! Services build typed [`Alert`]s and hand them to an [`AlertRouter`], which
+//! decides which [`AlertSink`]s receive each one (routing rules, dedup, rate
+//! limits, quiet hours) and re-sends unacknowledged alerts on escalation.
+//! HTTP sinks for Telegram, Discord, Slack-compatible and generic JSON
+//! webhooks live in [`sinks`]; other crates can plug in their own (the
+//! api-server registers its Resend email client this way). Routers can share
+//! pending alerts through Redis so an alert raised by one service can be
+//! acknowledged through the api-server.
+
+mod router;
+pub mod sinks;
+
+pub use router::{
+    AlertRouter, AlertRoutingConfig, DispatchReport, EscalationConfig, QuietHours, RouteRule,
+    PENDING_ALERTS_KEY,
+};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use uuid::Uuid;
+
+/// How urgently an alert needs a human.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Info,
+    Warning,
+    Critical,
+}
+
+impl Severity {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "info" => Some(Self::Info),
+            "warning" | "warn" => Some(Self::Warning),
+            "critical" | "crit" => Some(Self::Critical),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Info => "info",
+            Self::Warning => "warning",
+            Self::Critical => "critical",
+        }
+    }
+}
+
+/// A typed alert event.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Alert {
+    pub id: Uuid,
+    /// Dotted event kind used by routing rules, e.g. `arb.entry`.
+    pub kind: String,
+    pub severity: Severity,
+    pub title: String,
+    #[serde(default)]
+    pub body: String,
+    /// Alerts sharing a key are deduplicated and acknowledged together.
+    pub dedup_key: String,
+    #[serde(default)]
+    pub fields: BTreeMap<String, String>,
+    pub created_at: DateTime<Utc>,
+    /// 0 for the first delivery, then 1, 2, ... for each escalation.
+    #[serde(default)]
+    pub escalation: u32,
+}
+
+impl Alert {
+    /// New alert; the dedup key defaults to `kind` + `title`.
+    pub fn new(kind: impl Into<String>, severity: Severity, title: impl Into<String>) -> Self {
+        let kind = kind.into();
+        let title = title.into();
+        Self {
+            id: Uuid::new_v4(),
+            dedup_key: format!("{}:{}", kind, title),
+            kind,
+            severity,
+            title,
+            body: String::new(),
+            fields: BTreeMap::new(),
+            created_at: Utc::now(),
+            escalation: 0,
+        }
+    }
+
+    pub fn with_body(mut self, body: impl Into<String>) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    pub fn with_dedup_key(mut self, key: impl Into<String>) -> Self {
+        self.dedup_key = key.into();
+        self
+    }
+
+    pub fn with_field(mut self, key: impl Into<String>, value: impl ToString) -> Self {
+        self.fields.insert(key.into(), value.to_string());
+        self
+    }
+
+    /// Human-readable rendering shared by the chat sinks.
+    pub fn plain_text(&self) -> String {
+        let mut text = String::new();
+        if self.escalation > 0 {
+            text.push_str(&format!("[ESCALATED x{}] ", self.escalation));
+        }
+        text.push_str(&format!(
+            "[{}] {}",
+            self.severity.as_str().to_ascii_uppercase(),
+            self.title
+        ));
+        if !self.body.is_empty() {
+            text.push('\n');
+            text.push_str(&self.body);
+        }
+        for (key, value) in &self.fields {
+            text.push_str(&format!("\n{}: {}", key, value));
+        }
+        text
+    }
+}
+
+/// A delivery channel for alerts.
+#[async_trait]
+pub trait AlertSink: Send + Sync {
+    /// Name used by routing rules (`telegram`, `email`, ...).
+    fn name(&self) -> &str;
+
+    async fn send(&self, alert: &Alert) -> anyhow::Result<()>;
+}
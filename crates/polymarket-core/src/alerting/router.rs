@@ -0,0 +1,761 @@
+//! Alert routing policy: which sinks get an alert, and when to hold it back.
+
+use super::sinks::{DiscordSink, SlackSink, TelegramSink, WebhookSink};
+use super::{Alert, AlertSink, Severity};
+use crate::config::AlertsConfig;
+use chrono::{DateTime, Duration, Timelike, Utc};
+use redis::AsyncCommands;
+use std::collections::{BTreeSet, HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use tracing::{debug, info, warn};
+
+/// Send alerts matching `kinds` at or above `min_severity` to `sink`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RouteRule {
+    pub sink: String,
+    pub min_severity: Severity,
+    /// Kind patterns: exact (`arb.entry`) or prefix (`exit.*`). Empty = all.
+    pub kinds: Vec<String>,
+}
+
+impl RouteRule {
+    /// Parse `sink:min_severity[:kind|kind...]`.
+    pub fn parse(spec: &str) -> Option<Self> {
+        let mut parts = spec.trim().splitn(3, ':');
+        let sink = parts.next()?.trim();
+        if sink.is_empty() {
+            return None;
+        }
+        let min_severity = match parts.next() {
+            Some(severity) => Severity::parse(severity)?,
+            None => Severity::Info,
+        };
+        let kinds = parts
+            .next()
+            .map(|kinds| {
+                kinds
+                    .split('|')
+                    .map(|kind| kind.trim().to_string())
+                    .filter(|kind| !kind.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default();
+        Some(Self {
+            sink: sink.to_string(),
+            min_severity,
+            kinds,
+        })
+    }
+
+    pub fn matches(&self, alert: &Alert) -> bool {
+        alert.severity >= self.min_severity
+            && (self.kinds.is_empty()
+                || self
+                    .kinds
+                    .iter()
+                    .any(|pattern| match pattern.strip_suffix('*') {
+                        Some(prefix) => alert.kind.starts_with(prefix),
+                        None => alert.kind == *pattern,
+                    }))
+    }
+}
+
+/// UTC hour range during which low-severity alerts are held back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuietHours {
+    pub start_hour: u32,
+    pub end_hour: u32,
+}
+
+impl QuietHours {
+    /// Parse `start-end` in whole UTC hours, e.g. `22-7`.
+    pub fn parse(spec: &str) -> Option<Self> {
+        let (start, end) = spec.trim().split_once('-')?;
+        let start_hour: u32 = start.trim().parse().ok()?;
+        let end_hour: u32 = end.trim().parse().ok()?;
+        (start_hour < 24 && end_hour < 24 && start_hour != end_hour).then_some(Self {
+            start_hour,
+            end_hour,
+        })
+    }
+
+    pub fn contains(&self, at: DateTime<Utc>) -> bool {
+        let hour = at.hour();
+        if self.start_hour < self.end_hour {
+            hour >= self.start_hour && hour < self.end_hour
+        } else {
+            hour >= self.start_hour || hour < self.end_hour
+        }
+    }
+}
+
+/// Re-send unacknowledged alerts.
+#[derive(Debug, Clone)]
+pub struct EscalationConfig {
+    /// Seconds without acknowledgement before escalating; 0 disables.
+    pub after_secs: i64,
+    pub min_severity: Severity,
+    /// Sinks that receive escalations; empty = the originally routed sinks.
+    pub sinks: Vec<String>,
+    pub max_escalations: u32,
+}
+
+impl EscalationConfig {
+    pub fn enabled(&self) -> bool {
+        self.after_secs > 0 && self.max_escalations > 0
+    }
+}
+
+/// Routing, throttling and escalation policy.
+#[derive(Debug, Clone)]
+pub struct AlertRoutingConfig {
+    /// Empty = every sink receives every alert.
+    pub routes: Vec<RouteRule>,
+    /// Suppress repeats of a dedup key within this window.
+    pub dedup_window_secs: i64,
+    /// Per-sink cap over a rolling minute; critical alerts are exempt.
+    pub rate_limit_per_minute: usize,
+    pub quiet_hours: Option<QuietHours>,
+    /// Alerts below this severity are dropped during quiet hours.
+    pub quiet_hours_min_severity: Severity,
+    pub escalation: EscalationConfig,
+}
+
+impl Default for AlertRoutingConfig {
+    fn default() -> Self {
+        Self {
+            routes: Vec::new(),
+            dedup_window_secs: 300,
+            rate_limit_per_minute: 20,
+            quiet_hours: None,
+            quiet_hours_min_severity: Severity::Critical,
+            escalation: EscalationConfig {
+                after_secs: 900,
+                min_severity: Severity::Critical,
+                sinks: Vec::new(),
+                max_escalations: 2,
+            },
+        }
+    }
+}
+
+impl AlertRoutingConfig {
+    /// Create config from environment variables.
+    pub fn from_env() -> Self {
+        let severity = |key: &str| {
+            Severity::parse(&crate::settings::get::<String>(key)).unwrap_or(Severity::Critical)
+        };
+        Self {
+            routes: crate::settings::get_opt::<Vec<String>>("ALERT_ROUTES")
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|spec| {
+                    let rule = RouteRule::parse(spec);
+                    if rule.is_none() {
+                        warn!(spec = %spec, "Ignoring invalid ALERT_ROUTES entry");
+                    }
+                    rule
+                })
+                .collect(),
+            dedup_window_secs: crate::settings::get("ALERT_DEDUP_WINDOW_SECS"),
+            rate_limit_per_minute: crate::settings::get("ALERT_RATE_LIMIT_PER_MINUTE"),
+            quiet_hours: crate::settings::get_opt::<String>("ALERT_QUIET_HOURS")
+                .and_then(|v| QuietHours::parse(&v)),
+            quiet_hours_min_severity: severity("ALERT_QUIET_HOURS_MIN_SEVERITY"),
+            escalation: EscalationConfig {
+                after_secs: crate::settings::get("ALERT_ESCALATION_AFTER_SECS"),
+                min_severity: severity("ALERT_ESCALATION_MIN_SEVERITY"),
+                sinks: crate::settings::get_opt("ALERT_ESCALATION_SINKS").unwrap_or_default(),
+                max_escalations: crate::settings::get("ALERT_ESCALATION_MAX"),
+            },
+        }
+    }
+}
+
+/// Outcome of one dispatch.
+#[derive(Debug, Clone, Default)]
+pub struct DispatchReport {
+    pub delivered: Vec<String>,
+    pub failed: Vec<(String, String)>,
+    /// Set when the alert was held back before reaching any sink.
+    pub suppressed: Option<&'static str>,
+}
+
+struct PendingEscalation {
+    alert: Alert,
+    sinks: Vec<String>,
+    next_at: DateTime<Utc>,
+}
+
+#[derive(Default)]
+struct RouterState {
+    last_sent: HashMap<String, DateTime<Utc>>,
+    /// Repeats suppressed since the last delivery, per dedup key.
+    suppressed: HashMap<String, u32>,
+    sink_sends: HashMap<String, VecDeque<DateTime<Utc>>>,
+    pending: HashMap<String, PendingEscalation>,
+}
+
+/// Redis hash of alerts awaiting acknowledgement, keyed by dedup key.
+///
+/// Every service whose router shares acknowledgements registers its pending
+/// alerts here; acknowledging deletes the field, and each router drops its
+/// own escalation once the field is gone. Entries older than the last
+/// escalation step are pruned, so alerts left by a service that stopped
+/// before escalating them don't stay pending forever.
+pub const PENDING_ALERTS_KEY: &str = "alerts:pending";
+
+/// Fans alerts out to sinks according to [`AlertRoutingConfig`].
+pub struct AlertRouter {
+    sinks: Vec<Arc<dyn AlertSink>>,
+    config: AlertRoutingConfig,
+    state: Mutex<RouterState>,
+    /// Where pending alerts are shared with other services, if anywhere.
+    shared: Option<redis::aio::ConnectionManager>,
+}
+
+impl AlertRouter {
+    pub fn new(config: AlertRoutingConfig) -> Self {
+        Self {
+            sinks: Vec::new(),
+            config,
+            state: Mutex::new(RouterState::default()),
+            shared: None,
+        }
+    }
+
+    /// Router with the HTTP sinks configured in `alerts` and the routing
+    /// policy from the environment.
+    pub fn from_env(alerts: &AlertsConfig) -> Self {
+        let http = reqwest::Client::builder()
+            .timeout(std::time::Duration::from_secs(10))
+            .build()
+            .unwrap_or_default();
+        let mut router = Self::new(AlertRoutingConfig::from_env());
+        if let (Some(token), Some(chat_id)) = (&alerts.telegram_bot_token, &alerts.telegram_chat_id)
+        {
+            router = router.with_sink(Arc::new(TelegramSink::new(
+                http.clone(),
+                token.clone(),
+                chat_id.clone(),
+            )));
+        }
+        if let Some(url) = &alerts.discord_webhook_url {
+            router = router.with_sink(Arc::new(DiscordSink::new(http.clone(), url.clone())));
+        }
+        if let Some(url) = &alerts.slack_webhook_url {
+            router = router.with_sink(Arc::new(SlackSink::new(http.clone(), url.clone())));
+        }
+        if let Some(url) = &alerts.webhook_url {
+            router = router.with_sink(Arc::new(WebhookSink::new(
+                http,
+                url.clone(),
+                alerts.webhook_bearer_token.clone(),
+            )));
+        }
+        router
+    }
+
+    pub fn with_sink(mut self, sink: Arc<dyn AlertSink>) -> Self {
+        self.sinks.push(sink);
+        self
+    }
+
+    /// Share pending alerts through [`PENDING_ALERTS_KEY`] so an alert raised
+    /// by one service can be acknowledged through another.
+    pub fn with_shared_acknowledgements(mut self, redis: redis::aio::ConnectionManager) -> Self {
+        self.shared = Some(redis);
+        self
+    }
+
+    pub fn sink_names(&self) -> Vec<String> {
+        self.sinks.iter().map(|s| s.name().to_string()).collect()
+    }
+
+    /// Route and deliver an alert.
+    pub async fn dispatch(&self, alert: Alert) -> DispatchReport {
+        let (alert, sinks, suppressed) = self.plan(alert, Utc::now());
+        if let Some(reason) = suppressed {
+            debug!(kind = %alert.kind, dedup_key = %alert.dedup_key, reason, "Alert suppressed");
+            return DispatchReport {
+                suppressed: Some(reason),
+                ..Default::default()
+            };
+        }
+        if self.lock().pending.contains_key(&alert.dedup_key) {
+            self.share_pending(&alert).await;
+        }
+        self.deliver(&alert, &sinks).await
+    }
+
+    /// Fire-and-forget [`dispatch`](Self::dispatch) for synchronous callers.
+    pub fn dispatch_background(self: &Arc<Self>, alert: Alert) {
+        let router = Arc::clone(self);
+        tokio::spawn(async move {
+            router.dispatch(alert).await;
+        });
+    }
+
+    /// Stop escalating `dedup_key`, here and in every service sharing
+    /// acknowledgements. Returns false if nothing was pending.
+    pub async fn acknowledge(&self, dedup_key: &str) -> bool {
+        let local = self.lock().pending.remove(dedup_key).is_some();
+        let shared = match self.shared.clone() {
+            Some(mut redis) => redis
+                .hdel::<_, _, u32>(PENDING_ALERTS_KEY, dedup_key)
+                .await
+                .map_err(|e| warn!(error = %e, dedup_key, "Failed to share alert acknowledgement"))
+                .is_ok_and(|removed| removed > 0),
+            None => false,
+        };
+        local || shared
+    }
+
+    /// Dedup keys awaiting acknowledgement, including ones pending in other
+    /// services when acknowledgements are shared.
+    pub async fn pending_acknowledgements(&self) -> Vec<String> {
+        let mut keys: BTreeSet<String> = self.lock().pending.keys().cloned().collect();
+        keys.extend(self.shared_pending(Utc::now()).await);
+        keys.into_iter().collect()
+    }
+
+    /// Send escalations that are due.
+    ///
+    /// With shared acknowledgements, an alert whose entry was removed from
+    /// [`PENDING_ALERTS_KEY`] was acknowledged elsewhere and is dropped
+    /// instead of escalated.
+    pub async fn run_escalations(&self) -> usize {
+        let now = Utc::now();
+        self.shared_pending(now).await;
+        let due = self.due_escalations(now);
+        let mut count = 0;
+        for (alert, sink_names) in due {
+            if !self.still_pending(&alert.dedup_key).await {
+                debug!(dedup_key = %alert.dedup_key, "Alert acknowledged elsewhere");
+                self.lock().pending.remove(&alert.dedup_key);
+                continue;
+            }
+            if alert.escalation >= self.config.escalation.max_escalations {
+                self.unshare_pending(&alert.dedup_key).await;
+            }
+            count += 1;
+            let sinks: Vec<Arc<dyn AlertSink>> = self
+                .sinks
+                .iter()
+                .filter(|sink| sink_names.iter().any(|name| name == sink.name()))
+                .cloned()
+                .collect();
+            info!(
+                dedup_key = %alert.dedup_key,
+                escalation = alert.escalation,
+                "Escalating unacknowledged alert"
+            );
+            self.deliver(&alert, &sinks).await;
+        }
+        count
+    }
+
+    /// Run [`run_escalations`](Self::run_escalations) periodically, if enabled.
+    pub fn spawn_escalation_loop(self: &Arc<Self>) {
+        if !self.config.escalation.enabled() || self.sinks.is_empty() {
+            return;
+        }
+        let router = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut tick = tokio::time::interval(std::time::Duration::from_secs(30));
+            loop {
+                tick.tick().await;
+                router.run_escalations().await;
+            }
+        });
+    }
+
+    async fn share_pending(&self, alert: &Alert) {
+        let Some(mut redis) = self.shared.clone() else {
+            return;
+        };
+        let payload = match serde_json::to_string(alert) {
+            Ok(payload) => payload,
+            Err(e) => {
+                warn!(error = %e, "Failed to serialize pending alert");
+                return;
+            }
+        };
+        if let Err(e) = redis
+            .hset::<_, _, _, ()>(PENDING_ALERTS_KEY, &alert.dedup_key, payload)
+            .await
+        {
+            warn!(error = %e, dedup_key = %alert.dedup_key, "Failed to share pending alert");
+        }
+    }
+
+    /// Dedup keys in [`PENDING_ALERTS_KEY`], after pruning entries past
+    /// their last escalation step.
+    async fn shared_pending(&self, now: DateTime<Utc>) -> Vec<String> {
+        let Some(mut redis) = self.shared.clone() else {
+            return Vec::new();
+        };
+        let entries = match redis
+            .hgetall::<_, HashMap<String, String>>(PENDING_ALERTS_KEY)
+            .await
+        {
+            Ok(entries) => entries,
+            Err(e) => {
+                warn!(error = %e, "Failed to read shared pending alerts");
+                return Vec::new();
+            }
+        };
+        // Routers with escalation disabled can't tell when an entry is done.
+        let stale = if self.config.escalation.enabled() {
+            stale_pending(&entries, now - self.pending_ttl())
+        } else {
+            Vec::new()
+        };
+        if !stale.is_empty() {
+            debug!(count = stale.len(), "Pruning stale shared pending alerts");
+            if let Err(e) = redis.hdel::<_, _, ()>(PENDING_ALERTS_KEY, &stale).await {
+                warn!(error = %e, "Failed to prune shared pending alerts");
+            }
+        }
+        entries
+            .into_keys()
+            .filter(|key| !stale.contains(key))
+            .collect()
+    }
+
+    /// How long an alert can stay pending: one step per escalation plus the
+    /// wait before the first.
+    fn pending_ttl(&self) -> Duration {
+        let escalation = &self.config.escalation;
+        Duration::seconds(escalation.after_secs * (i64::from(escalation.max_escalations) + 1))
+    }
+
+    async fn unshare_pending(&self, dedup_key: &str) {
+        if let Some(mut redis) = self.shared.clone() {
+            if let Err(e) = redis.hdel::<_, _, ()>(PENDING_ALERTS_KEY, dedup_key).await {
+                warn!(error = %e, dedup_key, "Failed to clear shared pending alert");
+            }
+        }
+    }
+
+    /// False once the shared entry is gone. Escalates when Redis is
+    /// unreachable, since a missed page is worse than a repeated one.
+    async fn still_pending(&self, dedup_key: &str) -> bool {
+        let Some(mut redis) = self.shared.clone() else {
+            return true;
+        };
+        redis
+            .hexists(PENDING_ALERTS_KEY, dedup_key)
+            .await
+            .unwrap_or_else(|e| {
+                warn!(error = %e, dedup_key, "Failed to check shared pending alert");
+                true
+            })
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, RouterState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    async fn deliver(&self, alert: &Alert, sinks: &[Arc<dyn AlertSink>]) -> DispatchReport {
+        let results =
+            futures_util::future::join_all(sinks.iter().map(|sink| sink.send(alert))).await;
+        let mut report = DispatchReport::default();
+        for (sink, result) in sinks.iter().zip(results) {
+            match result {
+                Ok(()) => report.delivered.push(sink.name().to_string()),
+                Err(e) => {
+                    warn!(sink = sink.name(), kind = %alert.kind, error = %e, "Failed to send alert");
+                    report.failed.push((sink.name().to_string(), e.to_string()));
+                }
+            }
+        }
+        report
+    }
+
+    /// Apply quiet hours, dedup, routing and rate limits at `now`.
+    fn plan(
+        &self,
+        mut alert: Alert,
+        now: DateTime<Utc>,
+    ) -> (Alert, Vec<Arc<dyn AlertSink>>, Option<&'static str>) {
+        let config = &self.config;
+        if config.quiet_hours.is_some_and(|quiet| quiet.contains(now))
+            && alert.severity < config.quiet_hours_min_severity
+        {
+            return (alert, Vec::new(), Some("quiet_hours"));
+        }
+
+        let mut state = self.lock();
+        let window = Duration::seconds(config.dedup_window_secs);
+        if let Some(last) = state.last_sent.get(&alert.dedup_key) {
+            if now - *last < window {
+                *state.suppressed.entry(alert.dedup_key.clone()).or_default() += 1;
+                return (alert, Vec::new(), Some("duplicate"));
+            }
+        }
+
+        let minute_ago = now - Duration::minutes(1);
+        let mut targets = Vec::new();
+        for sink in &self.sinks {
+            let routed = config.routes.is_empty()
+                || config
+                    .routes
+                    .iter()
+                    .any(|rule| rule.sink == sink.name() && rule.matches(&alert));
+            if !routed {
+                continue;
+            }
+            let sends = state.sink_sends.entry(sink.name().to_string()).or_default();
+            while sends.front().is_some_and(|at| *at < minute_ago) {
+                sends.pop_front();
+            }
+            if alert.severity < Severity::Critical && sends.len() >= config.rate_limit_per_minute {
+                debug!(sink = sink.name(), kind = %alert.kind, "Alert rate limited");
+                continue;
+            }
+            sends.push_back(now);
+            targets.push(Arc::clone(sink));
+        }
+        if targets.is_empty() {
+            return (alert, targets, Some("no_route"));
+        }
+
+        if let Some(repeats) = state.suppressed.remove(&alert.dedup_key) {
+            alert = alert.with_field("suppressed_repeats", repeats);
+        }
+        state.last_sent.insert(alert.dedup_key.clone(), now);
+        state.last_sent.retain(|_, sent_at| now - *sent_at < window);
+
+        let escalation = &config.escalation;
+        if escalation.enabled() && alert.severity >= escalation.min_severity {
+            let sinks = if escalation.sinks.is_empty() {
+                targets.iter().map(|sink| sink.name().to_string()).collect()
+            } else {
+                escalation.sinks.clone()
+            };
+            state.pending.insert(
+                alert.dedup_key.clone(),
+                PendingEscalation {
+                    alert: alert.clone(),
+                    sinks,
+                    next_at: now + Duration::seconds(escalation.after_secs),
+                },
+            );
+        }
+        (alert, targets, None)
+    }
+
+    fn due_escalations(&self, now: DateTime<Utc>) -> Vec<(Alert, Vec<String>)> {
+        let escalation = &self.config.escalation;
+        let mut state = self.lock();
+        let mut due = Vec::new();
+        state.pending.retain(|_, pending| {
+            if pending.next_at > now {
+                return true;
+            }
+            pending.alert.escalation += 1;
+            due.push((pending.alert.clone(), pending.sinks.clone()));
+            pending.next_at = now + Duration::seconds(escalation.after_secs);
+            pending.alert.escalation < escalation.max_escalations
+        });
+        due
+    }
+}
+
+/// Shared pending entries raised before `cutoff`. Unreadable entries count
+/// as stale, since nothing can escalate them.
+fn stale_pending(entries: &HashMap<String, String>, cutoff: DateTime<Utc>) -> Vec<String> {
+    entries
+        .iter()
+        .filter(|(_, payload)| {
+            serde_json::from_str::<Alert>(payload).map_or(true, |alert| alert.created_at < cutoff)
+        })
+        .map(|(key, _)| key.clone())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use chrono::TimeZone;
+
+    struct NamedSink(&'static str);
+
+    #[async_trait]
+    impl AlertSink for NamedSink {
+        fn name(&self) -> &str {
+            self.0
+        }
+
+        async fn send(&self, _alert: &Alert) -> anyhow::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn router(config: AlertRoutingConfig) -> AlertRouter {
+        AlertRouter::new(config)
+            .with_sink(Arc::new(NamedSink("telegram")))
+            .with_sink(Arc::new(NamedSink("email")))
+    }
+
+    fn planned(router: &AlertRouter, alert: Alert, now: DateTime<Utc>) -> Vec<String> {
+        let (_, sinks, _) = router.plan(alert, now);
+        sinks.iter().map(|s| s.name().to_string()).collect()
+    }
+
+    fn at(hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, 2, hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn test_route_rules_filter_by_severity_and_kind() {
+        let rule = RouteRule::parse("email:warning:exit.*|arb.entry").unwrap();
+        assert_eq!(rule.kinds, vec!["exit.*", "arb.entry"]);
+        assert!(rule.matches(&Alert::new("exit.failed", Severity::Critical, "x")));
+        assert!(!rule.matches(&Alert::new("exit.failed", Severity::Info, "x")));
+        assert!(!rule.matches(&Alert::new("arb.exit", Severity::Critical, "x")));
+        assert!(RouteRule::parse("email:loud").is_none());
+
+        let router = router(AlertRoutingConfig {
+            routes: vec![
+                RouteRule::parse("telegram:info").unwrap(),
+                RouteRule::parse("email:critical").unwrap(),
+            ],
+            ..Default::default()
+        });
+        let now = at(12, 0);
+        assert_eq!(
+            planned(&router, Alert::new("arb.entry", Severity::Info, "a"), now),
+            vec!["telegram"]
+        );
+        assert_eq!(
+            planned(
+                &router,
+                Alert::new("exit.failed", Severity::Critical, "b"),
+                now
+            ),
+            vec!["telegram", "email"]
+        );
+    }
+
+    #[test]
+    fn test_dedup_rate_limit_and_quiet_hours() {
+        let router = router(AlertRoutingConfig {
+            rate_limit_per_minute: 2,
+            quiet_hours: QuietHours::parse("22-7"),
+            ..Default::default()
+        });
+        let now = at(12, 0);
+        let alert = || Alert::new("arb.entry", Severity::Info, "same");
+        assert_eq!(planned(&router, alert(), now).len(), 2);
+        let (_, sinks, reason) = router.plan(alert(), now + Duration::seconds(10));
+        assert!(sinks.is_empty());
+        assert_eq!(reason, Some("duplicate"));
+        // After the window the repeat goes out with a suppressed count.
+        let (resent, sinks, _) = router.plan(alert(), now + Duration::seconds(301));
+        assert_eq!(sinks.len(), 2);
+        assert_eq!(resent.fields["suppressed_repeats"], "1");
+
+        // Third distinct alert in the same minute is rate limited; critical is not.
+        let later = at(13, 0);
+        for title in ["a", "b"] {
+            planned(
+                &router,
+                Alert::new("arb.entry", Severity::Info, title),
+                later,
+            );
+        }
+        assert!(planned(&router, Alert::new("arb.entry", Severity::Info, "c"), later).is_empty());
+        assert_eq!(
+            planned(&router, Alert::new("risk", Severity::Critical, "d"), later).len(),
+            2
+        );
+
+        // Quiet hours wrap midnight and only let critical alerts through.
+        let night = at(23, 30);
+        let (_, _, reason) = router.plan(Alert::new("arb.entry", Severity::Warning, "n"), night);
+        assert_eq!(reason, Some("quiet_hours"));
+        assert_eq!(
+            planned(
+                &router,
+                Alert::new("risk", Severity::Critical, "n"),
+                at(3, 0)
+            )
+            .len(),
+            2
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unacknowledged_critical_alerts_escalate_until_acked_or_capped() {
+        let mut config = AlertRoutingConfig::default();
+        config.escalation.after_secs = 600;
+        config.escalation.sinks = vec!["email".to_string()];
+        let router = router(config);
+        let now = at(12, 0);
+
+        planned(
+            &router,
+            Alert::new("exit.failed", Severity::Critical, "stuck"),
+            now,
+        );
+        planned(
+            &router,
+            Alert::new("arb.entry", Severity::Info, "fine"),
+            now,
+        );
+        assert_eq!(router.pending_acknowledgements().await.len(), 1);
+
+        assert!(router
+            .due_escalations(now + Duration::seconds(599))
+            .is_empty());
+        let due = router.due_escalations(now + Duration::seconds(600));
+        assert_eq!(due.len(), 1);
+        assert_eq!(due[0].0.escalation, 1);
+        assert_eq!(due[0].1, vec!["email".to_string()]);
+
+        // Capped at max_escalations (2), then dropped.
+        assert_eq!(
+            router.due_escalations(now + Duration::seconds(1200)).len(),
+            1
+        );
+        assert!(router.pending_acknowledgements().await.is_empty());
+
+        planned(
+            &router,
+            Alert::new("exit.failed", Severity::Critical, "again"),
+            now,
+        );
+        assert!(router.acknowledge("exit.failed:again").await);
+        assert!(router
+            .due_escalations(now + Duration::seconds(600))
+            .is_empty());
+    }
+
+    #[test]
+    fn test_shared_pending_entries_expire_after_last_escalation() {
+        let now = at(12, 0);
+        let mut old = Alert::new("exit.failed", Severity::Critical, "old");
+        old.created_at = now - Duration::seconds(3000);
+        let mut fresh = Alert::new("exit.failed", Severity::Critical, "fresh");
+        fresh.created_at = now - Duration::seconds(60);
+        let entries: HashMap<String, String> = [
+            (old.dedup_key.clone(), serde_json::to_string(&old).unwrap()),
+            (
+                fresh.dedup_key.clone(),
+                serde_json::to_string(&fresh).unwrap(),
+            ),
+            ("garbled".to_string(), "{".to_string()),
+        ]
+        .into();
+
+        let mut stale = stale_pending(&entries, now - Duration::seconds(2700));
+        stale.sort();
+        assert_eq!(
+            stale,
+            vec!["exit.failed:old".to_string(), "garbled".to_string()]
+        );
+    }
+}
@@ -0,0 +1,295 @@
+//! HTTP alert sinks.
+//!
+//! Every sink takes its endpoint as a plain URL so it can be pointed at a
+//! local stub in tests.
+
+use super::{Alert, AlertSink};
+use anyhow::Context;
+use async_trait::async_trait;
+
+/// Discord rejects messages over 2000 characters.
+const DISCORD_MAX_CHARS: usize = 2000;
+
+fn truncate(text: String, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text;
+    }
+    let mut truncated: String = text.chars().take(max_chars.saturating_sub(1)).collect();
+    truncated.push('…');
+    truncated
+}
+
+async fn post_json(
+    http: &reqwest::Client,
+    url: &str,
+    body: &serde_json::Value,
+    bearer: Option<&str>,
+) -> anyhow::Result<()> {
+    let mut request = http.post(url).json(body);
+    if let Some(token) = bearer {
+        request = request.bearer_auth(token);
+    }
+    request
+        .send()
+        .await
+        .context("alert request failed")?
+        .error_for_status()
+        .context("alert endpoint returned an error")?;
+    Ok(())
+}
+
+/// Telegram Bot API `sendMessage`.
+pub struct TelegramSink {
+    http: reqwest::Client,
+    api_base: String,
+    bot_token: String,
+    chat_id: String,
+}
+
+impl TelegramSink {
+    pub const DEFAULT_API_BASE: &'static str = "https://api.telegram.org";
+
+    pub fn new(http: reqwest::Client, bot_token: String, chat_id: String) -> Self {
+        Self::with_api_base(http, Self::DEFAULT_API_BASE.to_string(), bot_token, chat_id)
+    }
+
+    pub fn with_api_base(
+        http: reqwest::Client,
+        api_base: String,
+        bot_token: String,
+        chat_id: String,
+    ) -> Self {
+        Self {
+            http,
+            api_base: api_base.trim_end_matches('/').to_string(),
+            bot_token,
+            chat_id,
+        }
+    }
+}
+
+#[async_trait]
+impl AlertSink for TelegramSink {
+    fn name(&self) -> &str {
+        "telegram"
+    }
+
+    async fn send(&self, alert: &Alert) -> anyhow::Result<()> {
+        let url = format!("{}/bot{}/sendMessage", self.api_base, self.bot_token);
+        let body = serde_json::json!({
+            "chat_id": self.chat_id,
+            "text": alert.plain_text(),
+        });
+        post_json(&self.http, &url, &body, None).await
+    }
+}
+
+/// Discord incoming webhook.
+pub struct DiscordSink {
+    http: reqwest::Client,
+    webhook_url: String,
+}
+
+impl DiscordSink {
+    pub fn new(http: reqwest::Client, webhook_url: String) -> Self {
+        Self { http, webhook_url }
+    }
+}
+
+#[async_trait]
+impl AlertSink for DiscordSink {
+    fn name(&self) -> &str {
+        "discord"
+    }
+
+    async fn send(&self, alert: &Alert) -> anyhow::Result<()> {
+        let body = serde_json::json!({
+            "content": truncate(alert.plain_text(), DISCORD_MAX_CHARS),
+        });
+        post_json(&self.http, &self.webhook_url, &body, None).await
+    }
+}
+
+/// Slack-compatible incoming webhook (`{"text": ...}`; also accepted by
+/// Mattermost and Rocket.Chat).
+pub struct SlackSink {
+    http: reqwest::Client,
+    webhook_url: String,
+}
+
+impl SlackSink {
+    pub fn new(http: reqwest::Client, webhook_url: String) -> Self {
+        Self { http, webhook_url }
+    }
+}
+
+#[async_trait]
+impl AlertSink for SlackSink {
+    fn name(&self) -> &str {
+        "slack"
+    }
+
+    async fn send(&self, alert: &Alert) -> anyhow::Result<()> {
+        let body = serde_json::json!({ "text": alert.plain_text() });
+        post_json(&self.http, &self.webhook_url, &body, None).await
+    }
+}
+
+/// Generic webhook receiving the full [`Alert`] as JSON.
+pub struct WebhookSink {
+    http: reqwest::Client,
+    url: String,
+    bearer_token: Option<String>,
+}
+
+impl WebhookSink {
+    pub fn new(http: reqwest::Client, url: String, bearer_token: Option<String>) -> Self {
+        Self {
+            http,
+            url,
+            bearer_token,
+        }
+    }
+}
+
+#[async_trait]
+impl AlertSink for WebhookSink {
+    fn name(&self) -> &str {
+        "webhook"
+    }
+
+    async fn send(&self, alert: &Alert) -> anyhow::Result<()> {
+        let body = serde_json::to_value(alert)?;
+        post_json(&self.http, &self.url, &body, self.bearer_token.as_deref()).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alerting::Severity;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+    use tokio::sync::mpsc;
+
+    struct Captured {
+        path: String,
+        authorization: Option<String>,
+        body: serde_json::Value,
+    }
+
+    /// Minimal HTTP/1.1 server answering every request with `status`.
+    async fn http_stub(status: u16) -> (String, mpsc::UnboundedReceiver<Captured>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        let (tx, rx) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let mut raw = Vec::new();
+                let mut chunk = [0u8; 4096];
+                let (head, body) = loop {
+                    let n = stream.read(&mut chunk).await.unwrap();
+                    raw.extend_from_slice(&chunk[..n]);
+                    let text = String::from_utf8_lossy(&raw).to_string();
+                    if let Some((head, body)) = text.split_once("\r\n\r\n") {
+                        let length = head
+                            .lines()
+                            .find_map(|line| {
+                                let (name, value) = line.split_once(':')?;
+                                name.eq_ignore_ascii_case("content-length")
+                                    .then(|| value.trim().parse::<usize>().ok())?
+                            })
+                            .unwrap_or(0);
+                        if body.len() >= length || n == 0 {
+                            break (head.to_string(), body.to_string());
+                        }
+                    }
+                };
+                let path = head
+                    .lines()
+                    .next()
+                    .and_then(|line| line.split_whitespace().nth(1))
+                    .unwrap_or_default()
+                    .to_string();
+                let authorization = head.lines().find_map(|line| {
+                    let (name, value) = line.split_once(':')?;
+                    name.eq_ignore_ascii_case("authorization")
+                        .then(|| value.trim().to_string())
+                });
+                let _ = tx.send(Captured {
+                    path,
+                    authorization,
+                    body: serde_json::from_str(&body).unwrap_or_default(),
+                });
+                let response = format!(
+                    "HTTP/1.1 {} STUB\r\ncontent-length: 0\r\nconnection: close\r\n\r\n",
+                    status
+                );
+                let _ = stream.write_all(response.as_bytes()).await;
+            }
+        });
+        (base, rx)
+    }
+
+    fn alert() -> Alert {
+        Alert::new("exit.failed", Severity::Critical, "Exit failed").with_field("market", "0xabc")
+    }
+
+    #[tokio::test]
+    async fn test_chat_sinks_post_rendered_text() {
+        let http = reqwest::Client::new();
+        let (base, mut rx) = http_stub(200).await;
+
+        TelegramSink::with_api_base(http.clone(), base.clone(), "T0K".into(), "42".into())
+            .send(&alert())
+            .await
+            .unwrap();
+        let request = rx.recv().await.unwrap();
+        assert_eq!(request.path, "/botT0K/sendMessage");
+        assert_eq!(request.body["chat_id"], "42");
+        assert!(request.body["text"]
+            .as_str()
+            .unwrap()
+            .starts_with("[CRITICAL] Exit failed"));
+
+        DiscordSink::new(http.clone(), format!("{base}/discord"))
+            .send(&alert())
+            .await
+            .unwrap();
+        let request = rx.recv().await.unwrap();
+        assert_eq!(request.path, "/discord");
+        assert!(request.body["content"]
+            .as_str()
+            .unwrap()
+            .contains("market: 0xabc"));
+
+        SlackSink::new(http, format!("{base}/slack"))
+            .send(&alert())
+            .await
+            .unwrap();
+        let request = rx.recv().await.unwrap();
+        assert_eq!(request.path, "/slack");
+        assert!(request.body["text"].is_string());
+    }
+
+    #[tokio::test]
+    async fn test_webhook_sink_sends_structured_alert_and_surfaces_errors() {
+        let http = reqwest::Client::new();
+        let (base, mut rx) = http_stub(200).await;
+        WebhookSink::new(http.clone(), format!("{base}/hook"), Some("s3cret".into()))
+            .send(&alert())
+            .await
+            .unwrap();
+        let request = rx.recv().await.unwrap();
+        assert_eq!(request.authorization.as_deref(), Some("Bearer s3cret"));
+        assert_eq!(request.body["kind"], "exit.failed");
+        assert_eq!(request.body["severity"], "critical");
+        assert_eq!(request.body["fields"]["market"], "0xabc");
+
+        let (failing, _rx) = http_stub(500).await;
+        assert!(WebhookSink::new(http, failing, None)
+            .send(&alert())
+            .await
+            .is_err());
+    }
+}
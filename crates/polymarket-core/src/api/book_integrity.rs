@@ -0,0 +1,311 @@
+//! Order book integrity checks for the CLOB market channel.
+//!
+//! The websocket sends a full `book` snapshot per asset and then
+//! `price_change` deltas. A missed or reordered delta silently corrupts the
+//! local book, which then shows phantom liquidity or crossed prices that look
+//! like arbitrage. These checks flag a corrupt book so the socket can
+//! quarantine the asset and resync it from `ClobClient::get_order_book`:
+//!
+//! - crossed (bid > ask) or locked (bid == ask) books;
+//! - deltas whose advertised `best_bid`/`best_ask` disagree with the local book;
+//! - deltas for an asset with no snapshot, or older than the book they modify;
+//! - snapshot hashes, when enabled (see [`summary_hash`]).
+
+use crate::types::{OrderBook, PriceLevel};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use sha1::{Digest, Sha1};
+
+/// Why a local book was judged corrupt.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BookIssue {
+    /// Best bid above best ask.
+    Crossed {
+        best_bid: Decimal,
+        best_ask: Decimal,
+    },
+    /// Best bid equal to best ask; a live CLOB would have matched them.
+    Locked { price: Decimal },
+    /// The server's top of book after a delta differs from ours.
+    TopOfBookMismatch {
+        side: &'static str,
+        expected: Option<Decimal>,
+        local: Option<Decimal>,
+    },
+    /// Snapshot hash differs from the hash computed locally.
+    HashMismatch { expected: String, computed: String },
+    /// Delta for an asset we never received a snapshot for.
+    MissingSnapshot,
+    /// Delta timestamped before the book it would modify.
+    OutOfOrder {
+        book_at: DateTime<Utc>,
+        delta_at: DateTime<Utc>,
+    },
+}
+
+impl BookIssue {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Crossed { .. } => "crossed",
+            Self::Locked { .. } => "locked",
+            Self::TopOfBookMismatch { .. } => "top_of_book_mismatch",
+            Self::HashMismatch { .. } => "hash_mismatch",
+            Self::MissingSnapshot => "missing_snapshot",
+            Self::OutOfOrder { .. } => "out_of_order",
+        }
+    }
+}
+
+/// Which integrity checks quarantine an asset.
+#[derive(Debug, Clone)]
+pub struct BookIntegrityConfig {
+    /// Master switch; off = books are applied without validation.
+    pub enabled: bool,
+    /// Quarantine on snapshot hash mismatch. Off by default: the hash layout
+    /// follows the reference client rather than a documented contract and has
+    /// not been checked against captured venue frames, so mismatches are only
+    /// counted until the `hash_mismatch` metric shows it agrees.
+    pub enforce_hash: bool,
+    /// Treat bid == ask as corrupt.
+    pub locked_is_corrupt: bool,
+    /// Delay before retrying a failed REST resync.
+    pub resync_retry_secs: u64,
+}
+
+impl Default for BookIntegrityConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            enforce_hash: false,
+            locked_is_corrupt: true,
+            resync_retry_secs: 5,
+        }
+    }
+}
+
+impl BookIntegrityConfig {
+    /// Create config from environment variables.
+    pub fn from_env() -> Self {
+        Self {
+            enabled: crate::settings::get("CLOB_BOOK_INTEGRITY_ENABLED"),
+            enforce_hash: crate::settings::get("CLOB_BOOK_ENFORCE_HASH"),
+            locked_is_corrupt: crate::settings::get("CLOB_BOOK_LOCKED_IS_CORRUPT"),
+            resync_retry_secs: crate::settings::get("CLOB_BOOK_RESYNC_RETRY_SECS"),
+        }
+    }
+
+    /// Whether `issue` should quarantine the asset (vs. only being counted).
+    pub fn quarantines(&self, issue: &BookIssue) -> bool {
+        match issue {
+            BookIssue::HashMismatch { .. } => self.enforce_hash,
+            BookIssue::Locked { .. } => self.locked_is_corrupt,
+            _ => true,
+        }
+    }
+}
+
+/// Highest bid and lowest ask with positive size, regardless of level order.
+pub fn top_of_book(book: &OrderBook) -> (Option<Decimal>, Option<Decimal>) {
+    let live = |level: &&PriceLevel| level.size > Decimal::ZERO;
+    (
+        book.bids.iter().filter(live).map(|l| l.price).max(),
+        book.asks.iter().filter(live).map(|l| l.price).min(),
+    )
+}
+
+/// Detect a crossed or locked book.
+pub fn check_crossed(book: &OrderBook) -> Option<BookIssue> {
+    match top_of_book(book) {
+        (Some(best_bid), Some(best_ask)) if best_bid > best_ask => {
+            Some(BookIssue::Crossed { best_bid, best_ask })
+        }
+        (Some(best_bid), Some(best_ask)) if best_bid == best_ask => {
+            Some(BookIssue::Locked { price: best_bid })
+        }
+        _ => None,
+    }
+}
+
+/// Compare the server's advertised top of book with the local one.
+///
+/// A zero or missing advertised price means "empty side" on the wire.
+pub fn check_top_of_book(
+    book: &OrderBook,
+    expected_bid: Option<Decimal>,
+    expected_ask: Option<Decimal>,
+) -> Option<BookIssue> {
+    let (local_bid, local_ask) = top_of_book(book);
+    let non_zero = |price: Option<Decimal>| price.filter(|p| *p > Decimal::ZERO);
+    let sides = [
+        ("bid", expected_bid, local_bid),
+        ("ask", expected_ask, local_ask),
+    ];
+    for (side, expected, local) in sides {
+        // Absent field: the server did not advertise this side.
+        let Some(expected) = expected else { continue };
+        let expected = non_zero(Some(expected));
+        if expected != local {
+            return Some(BookIssue::TopOfBookMismatch {
+                side,
+                expected,
+                local,
+            });
+        }
+    }
+    None
+}
+
+/// Sort levels best-first and drop empty levels, matching how deltas keep books.
+pub fn normalize_book(book: &mut OrderBook) {
+    book.bids.retain(|l| l.size > Decimal::ZERO);
+    book.asks.retain(|l| l.size > Decimal::ZERO);
+    book.bids.sort_by_key(|l| std::cmp::Reverse(l.price));
+    book.asks.sort_by_key(|l| l.price);
+}
+
+/// Raw (string) level as received on the wire; hashing must not reformat prices.
+pub struct RawLevel<'a> {
+    pub price: &'a str,
+    pub size: &'a str,
+}
+
+/// Book summary hash in the layout used by Polymarket's reference client:
+/// SHA-1 of the compact JSON summary with an empty `hash` field.
+pub fn summary_hash(
+    market: &str,
+    asset_id: &str,
+    timestamp: &str,
+    bids: &[RawLevel<'_>],
+    asks: &[RawLevel<'_>],
+) -> String {
+    fn levels(levels: &[RawLevel<'_>]) -> String {
+        let items: Vec<String> = levels
+            .iter()
+            .map(|l| {
+                format!(
+                    "{{\"price\":{},\"size\":{}}}",
+                    serde_json::Value::from(l.price),
+                    serde_json::Value::from(l.size)
+                )
+            })
+            .collect();
+        format!("[{}]", items.join(","))
+    }
+    let summary = format!(
+        "{{\"market\":{},\"asset_id\":{},\"timestamp\":{},\"hash\":\"\",\"bids\":{},\"asks\":{}}}",
+        serde_json::Value::from(market),
+        serde_json::Value::from(asset_id),
+        serde_json::Value::from(timestamp),
+        levels(bids),
+        levels(asks),
+    );
+    hex::encode(Sha1::digest(summary.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(value: &str) -> Decimal {
+        value.parse().unwrap()
+    }
+
+    fn book(bids: &[(Decimal, Decimal)], asks: &[(Decimal, Decimal)]) -> OrderBook {
+        let levels = |levels: &[(Decimal, Decimal)]| {
+            levels
+                .iter()
+                .map(|(price, size)| PriceLevel {
+                    price: *price,
+                    size: *size,
+                })
+                .collect()
+        };
+        OrderBook {
+            market_id: "m".to_string(),
+            outcome_id: "a".to_string(),
+            timestamp: Utc::now(),
+            bids: levels(bids),
+            asks: levels(asks),
+        }
+    }
+
+    #[test]
+    fn test_crossed_and_locked_books_are_detected_regardless_of_order() {
+        // Ascending bids (wire order) must not hide a cross.
+        let crossed = book(
+            &[(d("0.40"), d("10")), (d("0.55"), d("5"))],
+            &[(d("0.60"), d("10")), (d("0.52"), d("5"))],
+        );
+        assert_eq!(
+            check_crossed(&crossed),
+            Some(BookIssue::Crossed {
+                best_bid: d("0.55"),
+                best_ask: d("0.52")
+            })
+        );
+
+        let locked = book(&[(d("0.50"), d("1"))], &[(d("0.50"), d("1"))]);
+        assert_eq!(
+            check_crossed(&locked),
+            Some(BookIssue::Locked { price: d("0.50") })
+        );
+
+        // Zero-size levels are ignored.
+        let healthy = book(
+            &[(d("0.60"), d("0")), (d("0.45"), d("3"))],
+            &[(d("0.50"), d("2"))],
+        );
+        assert_eq!(check_crossed(&healthy), None);
+    }
+
+    #[test]
+    fn test_top_of_book_mismatch_and_empty_sides() {
+        let local = book(&[(d("0.45"), d("3"))], &[(d("0.50"), d("2"))]);
+        assert_eq!(
+            check_top_of_book(&local, Some(d("0.45")), Some(d("0.50"))),
+            None
+        );
+        assert_eq!(check_top_of_book(&local, None, None), None);
+        assert_eq!(
+            check_top_of_book(&local, Some(d("0.46")), Some(d("0.50"))),
+            Some(BookIssue::TopOfBookMismatch {
+                side: "bid",
+                expected: Some(d("0.46")),
+                local: Some(d("0.45")),
+            })
+        );
+
+        let one_sided = book(&[(d("0.45"), d("3"))], &[]);
+        assert_eq!(
+            check_top_of_book(&one_sided, Some(d("0.45")), Some(d("0"))),
+            None
+        );
+    }
+
+    #[test]
+    fn test_summary_hash_is_stable_and_sensitive_to_levels() {
+        let bids = [RawLevel {
+            price: "0.45",
+            size: "100",
+        }];
+        let asks = [RawLevel {
+            price: "0.5",
+            size: "20",
+        }];
+        let hash = summary_hash("0xm", "123", "1700000000000", &bids, &asks);
+        assert_eq!(hash.len(), 40);
+        assert_eq!(
+            hash,
+            summary_hash("0xm", "123", "1700000000000", &bids, &asks)
+        );
+
+        let moved = [RawLevel {
+            price: "0.5",
+            size: "21",
+        }];
+        assert_ne!(
+            hash,
+            summary_hash("0xm", "123", "1700000000000", &bids, &moved)
+        );
+    }
+}
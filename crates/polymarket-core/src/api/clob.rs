@@ -3,8 +3,9 @@
 //! This module provides both read-only and authenticated access to the
 //! Polymarket CLOB API for order book data and order management.
 
+use super::book_integrity::{self, BookIntegrityConfig, BookIssue, RawLevel};
 use crate::signing::{OrderData, OrderSigner, SignedOrder};
-use crate::types::{Market, OrderBook, Outcome, PriceLevel};
+use crate::types::{Market, OrderBook, OrderSide, Outcome, PriceLevel};
 use crate::{Error, Result};
 use alloy_primitives::U256;
 use base64::Engine;
@@ -14,13 +15,13 @@ use rust_decimal::Decimal;
 use rust_decimal::RoundingStrategy;
 use serde::{Deserialize, Serialize};
 use sha2::Sha256;
-use std::collections::HashMap;
-use std::sync::{LazyLock, Mutex};
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, LazyLock, Mutex};
 use std::time::Duration as StdDuration;
 use std::time::{SystemTime, UNIX_EPOCH};
-use tokio::sync::mpsc;
+use tokio::sync::{broadcast, mpsc};
 use tokio_tungstenite::{connect_async, tungstenite::Message};
-use tracing::{debug, info, warn};
+use tracing::{debug, info, instrument, warn};
 
 const COLLATERAL_DECIMALS: u32 = 6;
 const MARKET_ORDER_SIZE_SCALE: u32 = 2;
@@ -39,7 +40,11 @@ fn allowance_needs_refresh(allowance: &str) -> bool {
 #[derive(Debug, Clone, Default)]
 pub struct WebSocketRuntimeStatsSnapshot {
     pub subscribed_assets: usize,
+    /// Open market-channel sockets across all subscriptions.
+    pub active_sockets: usize,
     pub subscriptions_started_total: u64,
+    /// Assets added to or removed from a live socket without reconnecting.
+    pub incremental_subscription_changes_total: u64,
     pub text_messages_received_total: u64,
     pub orderbook_updates_emitted_total: u64,
     pub parse_misses_total: u64,
@@ -53,6 +58,18 @@ pub struct WebSocketRuntimeStatsSnapshot {
     pub last_parse_miss_at: Option<chrono::DateTime<chrono::Utc>>,
     pub last_parse_miss_kind: Option<String>,
     pub last_message_kind: Option<String>,
+    /// Integrity checks that failed (counted even when not quarantining).
+    pub book_integrity_failures_total: u64,
+    pub book_hash_mismatches_total: u64,
+    /// Quarantined books successfully rebuilt from REST.
+    pub book_resyncs_total: u64,
+    pub book_resync_failures_total: u64,
+    /// Assets currently quarantined pending a REST resync.
+    pub quarantined_assets: usize,
+    pub last_integrity_issue_kind: Option<String>,
+    pub last_integrity_issue_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub last_trade_price_messages_total: u64,
+    pub tick_size_change_messages_total: u64,
 }
 
 static WS_RUNTIME_STATS: LazyLock<Mutex<WebSocketRuntimeStatsSnapshot>> =
@@ -71,6 +88,48 @@ fn update_ws_runtime_stats(apply: impl FnOnce(&mut WebSocketRuntimeStatsSnapshot
     }
 }
 
+/// Redis channel on which a process holding the market channel republishes
+/// each [`TickSizeChange`], so other processes' tick caches stay current.
+pub const TICK_SIZE_CHANNEL: &str = "clob:tick_size";
+
+/// Per-token tick sizes learned from `tick_size_change` events and the REST
+/// `/tick-size` endpoint, with the time each was observed.
+static TICK_SIZES: LazyLock<Mutex<HashMap<String, (Decimal, std::time::Instant)>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Record the current tick size for a token.
+pub fn record_tick_size(asset_id: &str, tick_size: Decimal) {
+    if tick_size <= Decimal::ZERO {
+        return;
+    }
+    if let Ok(mut ticks) = TICK_SIZES.lock() {
+        ticks.insert(asset_id.to_string(), (tick_size, std::time::Instant::now()));
+    }
+}
+
+/// Last known tick size for a token, if observed within `max_age`.
+pub fn cached_tick_size(asset_id: &str, max_age: StdDuration) -> Option<Decimal> {
+    let ticks = TICK_SIZES.lock().ok()?;
+    let (tick_size, observed_at) = ticks.get(asset_id)?;
+    (observed_at.elapsed() <= max_age).then_some(*tick_size)
+}
+
+/// Snap a limit price onto the tick grid without making it more aggressive:
+/// buys round down, sells round up. Returns `None` when the passive price falls
+/// outside the tradable range `[tick, 1 - tick]`.
+pub fn round_to_tick(price: Decimal, tick_size: Decimal, side: OrderSide) -> Option<Decimal> {
+    if tick_size <= Decimal::ZERO {
+        return Some(price);
+    }
+    let ticks = price / tick_size;
+    let ticks = match side {
+        OrderSide::Buy => ticks.floor(),
+        OrderSide::Sell => ticks.ceil(),
+    };
+    let rounded = ticks * tick_size;
+    (rounded >= tick_size && rounded <= Decimal::ONE - tick_size).then(|| rounded.normalize())
+}
+
 #[derive(Debug, Clone, Copy)]
 enum WsParseKind {
     ControlPing,
@@ -83,6 +142,8 @@ enum WsParseKind {
     UnsupportedArray,
     UnsupportedObject,
     UnsupportedValue,
+    LastTradePrice,
+    TickSizeChange,
 }
 
 impl WsParseKind {
@@ -98,6 +159,8 @@ impl WsParseKind {
             Self::UnsupportedArray => "unsupported_array",
             Self::UnsupportedObject => "unsupported_object",
             Self::UnsupportedValue => "unsupported_value",
+            Self::LastTradePrice => "last_trade_price",
+            Self::TickSizeChange => "tick_size_change",
         }
     }
 
@@ -117,6 +180,10 @@ impl WsParseKind {
 struct ParsedWsMessage {
     updates: Vec<OrderBookUpdate>,
     kind: WsParseKind,
+    /// Integrity problems found while applying the message, by asset id.
+    issues: Vec<(String, BookIssue)>,
+    /// Trade prints and tick-size changes carried by the message.
+    events: Vec<MarketChannelEvent>,
 }
 
 /// Polymarket CLOB API client for order book data.
@@ -236,10 +303,7 @@ impl ClobClient {
         let mut all_markets = Vec::new();
         let mut cursor: Option<String> = None;
         // Safety valve: configurable via CLOB_MARKET_LIMIT env var (default 200,000).
-        let limit: usize = std::env::var("CLOB_MARKET_LIMIT")
-            .ok()
-            .and_then(|s| s.parse().ok())
-            .unwrap_or(200_000);
+        let limit: usize = crate::settings::get("CLOB_MARKET_LIMIT");
         let mut pages = 0u32;
 
         loop {
@@ -288,6 +352,7 @@ impl ClobClient {
     }
 
     /// Fetch order book for a specific token.
+    #[instrument(name = "clob.get_order_book", skip_all, fields(otel.kind = "client", token_id = %token_id))]
     pub async fn get_order_book(&self, token_id: &str) -> Result<OrderBook> {
         let url = format!("{}/book?token_id={}", self.base_url, token_id);
         let response = self.get_with_retry(&url).await?;
@@ -447,26 +512,29 @@ impl ClobClient {
     /// Subscribe to real-time order book updates via WebSocket.
     ///
     /// Expects token IDs (`asset_id`) for the market channel subscription.
-    /// Returns a channel receiver that yields normalized order book updates and
-    /// automatically reconnects with exponential backoff on disconnection.
+    /// Returns a handle that yields normalized order book updates, can add or
+    /// remove assets on the live connection, and spreads large asset lists
+    /// over several sockets. Each socket reconnects with exponential backoff.
     pub async fn subscribe_orderbook(
         &self,
         asset_ids: Vec<String>,
-    ) -> Result<mpsc::Receiver<OrderBookUpdate>> {
-        let (tx, rx) = mpsc::channel(1000);
-        let ws_url = self.ws_url.clone();
-
-        tokio::spawn(async move {
-            Self::ws_loop_with_reconnect(ws_url, asset_ids, tx).await;
-        });
-
-        Ok(rx)
+    ) -> Result<OrderBookSubscription> {
+        let max_assets_per_socket = crate::settings::get("CLOB_WS_MAX_ASSETS_PER_SOCKET");
+        let mut subscription = OrderBookSubscription::new(
+            self,
+            max_assets_per_socket,
+            BookIntegrityConfig::from_env(),
+        );
+        subscription.set_assets(asset_ids);
+        Ok(subscription)
     }
 
-    /// WebSocket loop with automatic reconnection and exponential backoff.
+    /// WebSocket loop for one socket with automatic reconnection and
+    /// exponential backoff. Runs until its subscription handle drops it.
     async fn ws_loop_with_reconnect(
-        ws_url: String,
-        asset_ids: Vec<String>,
+        socket: Arc<SocketContext>,
+        mut assets: HashSet<String>,
+        mut control: mpsc::UnboundedReceiver<SubscriptionCommand>,
         tx: mpsc::Sender<OrderBookUpdate>,
     ) {
         let mut attempt = 0u32;
@@ -474,8 +542,30 @@ impl ClobClient {
         let base_delay_secs = 1u64;
 
         loop {
-            match Self::ws_loop(&ws_url, &asset_ids, &tx).await {
-                Ok(()) => {
+            // Apply changes queued while disconnected; stop once the handle lets go.
+            loop {
+                match control.try_recv() {
+                    Ok(command) => command.apply(&mut assets),
+                    Err(mpsc::error::TryRecvError::Empty) => break,
+                    Err(mpsc::error::TryRecvError::Disconnected) => return,
+                }
+            }
+            if assets.is_empty() {
+                match control.recv().await {
+                    Some(command) => {
+                        command.apply(&mut assets);
+                        continue;
+                    }
+                    None => return,
+                }
+            }
+
+            match Self::ws_loop(&socket, &mut assets, &mut control, &tx).await {
+                Ok(WsLoopExit::Stopped) => {
+                    debug!("WebSocket subscription released");
+                    return;
+                }
+                Ok(WsLoopExit::Closed) => {
                     info!("WebSocket connection closed cleanly");
                 }
                 Err(e) => {
@@ -507,43 +597,42 @@ impl ClobClient {
     }
 
     async fn ws_loop(
-        ws_url: &str,
-        asset_ids: &[String],
+        socket: &Arc<SocketContext>,
+        assets: &mut HashSet<String>,
+        control: &mut mpsc::UnboundedReceiver<SubscriptionCommand>,
         tx: &mpsc::Sender<OrderBookUpdate>,
-    ) -> Result<()> {
-        if asset_ids.is_empty() {
+    ) -> Result<WsLoopExit> {
+        if assets.is_empty() {
             return Err(Error::Config {
                 message: "Cannot subscribe to orderbook stream with empty asset list".to_string(),
             });
         }
 
-        let (ws_stream, _) = connect_async(ws_url).await?;
+        let (ws_stream, _) = connect_async(&socket.ws_url).await?;
         let (mut write, mut read) = ws_stream.split();
-        let read_timeout_secs = std::env::var("CLOB_WS_READ_TIMEOUT_SECS")
-            .ok()
-            .and_then(|s| s.parse().ok())
-            .unwrap_or(120_u64);
-        let ping_interval_secs = std::env::var("CLOB_WS_PING_INTERVAL_SECS")
-            .ok()
-            .and_then(|s| s.parse().ok())
-            .unwrap_or(10_u64);
+        let read_timeout_secs = crate::settings::get("CLOB_WS_READ_TIMEOUT_SECS");
+        let ping_interval_secs = crate::settings::get("CLOB_WS_PING_INTERVAL_SECS");
         let mut ping_tick = tokio::time::interval(StdDuration::from_secs(ping_interval_secs));
         ping_tick.tick().await;
         let mut order_books_by_asset: HashMap<String, OrderBook> = HashMap::new();
+        // Assets whose local book failed an integrity check; their updates are
+        // withheld until a REST resync lands on `resync_rx`.
+        let mut quarantined: HashSet<String> = HashSet::new();
+        let (resync_tx, mut resync_rx) = mpsc::unbounded_channel::<(String, Result<OrderBook>)>();
 
         // Subscribe to market channel by token IDs.
         let subscribe_msg = serde_json::json!({
             "type": "market",
-            "assets_ids": asset_ids,
+            "assets_ids": assets.iter().collect::<Vec<_>>(),
             "custom_feature_enabled": false
         });
         write.send(Message::Text(subscribe_msg.to_string())).await?;
+        let mut socket_stats = SocketStatsGuard::open(assets.len());
         update_ws_runtime_stats(|stats| {
-            stats.subscribed_assets = asset_ids.len();
             stats.subscriptions_started_total = stats.subscriptions_started_total.saturating_add(1);
             stats.last_message_kind = Some("subscription_started".to_string());
         });
-        info!("Subscribed to {} assets via WebSocket", asset_ids.len());
+        info!("Subscribed to {} assets via WebSocket", assets.len());
 
         // Use a persistent deadline that only resets when data is actually received.
         // This prevents the ping ticker from inadvertently resetting the read timeout
@@ -558,6 +647,94 @@ impl ClobClient {
                 _ = ping_tick.tick() => {
                     write.send(Message::Text("PING".to_string())).await?;
                 }
+                command = control.recv() => {
+                    let Some(command) = command else {
+                        return Ok(WsLoopExit::Stopped);
+                    };
+                    let (operation, changed) = command.apply_live(assets);
+                    if changed.is_empty() {
+                        continue;
+                    }
+                    if operation == "unsubscribe" {
+                        for asset_id in &changed {
+                            order_books_by_asset.remove(asset_id);
+                            quarantined.remove(asset_id);
+                        }
+                        socket_stats.set_quarantined(quarantined.len());
+                    }
+                    // New assets get a book snapshot from the server; books for
+                    // every other asset on this socket are untouched.
+                    let change_msg = serde_json::json!({
+                        "assets_ids": changed,
+                        "operation": operation,
+                    });
+                    write.send(Message::Text(change_msg.to_string())).await?;
+                    socket_stats.set_assets(assets.len());
+                    update_ws_runtime_stats(|stats| {
+                        stats.incremental_subscription_changes_total = stats
+                            .incremental_subscription_changes_total
+                            .saturating_add(changed.len() as u64);
+                    });
+                    debug!(operation, count = changed.len(), "Updated live WebSocket subscription");
+                }
+                Some((asset_id, result)) = resync_rx.recv() => {
+                    // Stale result: the asset was dropped or already recovered.
+                    if !assets.contains(&asset_id) || !quarantined.contains(&asset_id) {
+                        continue;
+                    }
+                    let result = result.and_then(|mut book: OrderBook| {
+                        book_integrity::normalize_book(&mut book);
+                        match book_integrity::check_crossed(&book)
+                            .filter(|issue| socket.integrity.quarantines(issue))
+                        {
+                            Some(issue) => Err(Error::Api {
+                                message: format!("REST book still {}", issue.as_str()),
+                                status: None,
+                            }),
+                            None => Ok(book),
+                        }
+                    });
+                    match result {
+                        Ok(book) => {
+                            let update = OrderBookUpdate {
+                                market_id: book.market_id.clone(),
+                                asset_id: asset_id.clone(),
+                                timestamp: book.timestamp,
+                                bids: book.bids.clone(),
+                                asks: book.asks.clone(),
+                                invalidated: false,
+                            };
+                            order_books_by_asset.insert(asset_id.clone(), book);
+                            quarantined.remove(&asset_id);
+                            socket_stats.set_quarantined(quarantined.len());
+                            update_ws_runtime_stats(|stats| {
+                                stats.book_resyncs_total = stats.book_resyncs_total.saturating_add(1);
+                            });
+                            info!(asset_id = %asset_id, "Order book resynced from REST");
+                            if tx.send(update).await.is_err() {
+                                warn!("Receiver dropped, closing WebSocket");
+                                return Ok(WsLoopExit::Stopped);
+                            }
+                        }
+                        Err(e) => {
+                            update_ws_runtime_stats(|stats| {
+                                stats.book_resync_failures_total =
+                                    stats.book_resync_failures_total.saturating_add(1);
+                            });
+                            warn!(
+                                asset_id = %asset_id,
+                                error = %e,
+                                retry_secs = socket.integrity.resync_retry_secs,
+                                "Order book resync failed"
+                            );
+                            socket.spawn_resync(
+                                asset_id,
+                                StdDuration::from_secs(socket.integrity.resync_retry_secs),
+                                resync_tx.clone(),
+                            );
+                        }
+                    }
+                }
                 _ = &mut read_deadline => {
                     // Read timeout actually fired — no data for read_timeout_secs
                     warn!(
@@ -580,14 +757,61 @@ impl ClobClient {
                         Some(msg) => msg,
                         None => {
                             warn!("WebSocket stream ended");
-                            return Ok(());
+                            return Ok(WsLoopExit::Closed);
                         }
                     };
 
                     match msg {
                         Ok(Message::Text(text)) => {
-                            let parsed = parse_ws_message(&text, &mut order_books_by_asset);
+                            let mut parsed = parse_ws_message(&text, &mut order_books_by_asset);
                             let now = chrono::Utc::now();
+                            let mut tombstones = Vec::new();
+                            if socket.integrity.enabled {
+                                for (asset_id, issue) in &parsed.issues {
+                                    if quarantined.contains(asset_id) || !assets.contains(asset_id) {
+                                        continue;
+                                    }
+                                    update_ws_runtime_stats(|stats| {
+                                        stats.book_integrity_failures_total =
+                                            stats.book_integrity_failures_total.saturating_add(1);
+                                        if matches!(issue, BookIssue::HashMismatch { .. }) {
+                                            stats.book_hash_mismatches_total =
+                                                stats.book_hash_mismatches_total.saturating_add(1);
+                                        }
+                                        stats.last_integrity_issue_kind =
+                                            Some(issue.as_str().to_string());
+                                        stats.last_integrity_issue_at = Some(now);
+                                    });
+                                    if !socket.integrity.quarantines(issue) {
+                                        debug!(asset_id = %asset_id, ?issue, "Order book integrity issue ignored");
+                                        continue;
+                                    }
+                                    warn!(asset_id = %asset_id, ?issue, "Order book failed integrity check, resyncing from REST");
+                                    // Consumers already hold the last good-looking
+                                    // book; tell them to drop it until the resync.
+                                    let market_id = order_books_by_asset
+                                        .get(asset_id)
+                                        .map(|book| book.market_id.clone())
+                                        .or_else(|| {
+                                            parsed
+                                                .updates
+                                                .iter()
+                                                .find(|update| &update.asset_id == asset_id)
+                                                .map(|update| update.market_id.clone())
+                                        })
+                                        .unwrap_or_default();
+                                    tombstones.push(OrderBookUpdate::invalidated(market_id, asset_id.clone()));
+                                    quarantined.insert(asset_id.clone());
+                                    socket.spawn_resync(asset_id.clone(), StdDuration::ZERO, resync_tx.clone());
+                                }
+                                if !quarantined.is_empty() {
+                                    for asset_id in &quarantined {
+                                        order_books_by_asset.remove(asset_id);
+                                    }
+                                    parsed.updates.retain(|update| !quarantined.contains(&update.asset_id));
+                                }
+                                socket_stats.set_quarantined(quarantined.len());
+                            }
                             update_ws_runtime_stats(|stats| {
                                 stats.text_messages_received_total =
                                     stats.text_messages_received_total.saturating_add(1);
@@ -615,6 +839,14 @@ impl ClobClient {
                                         stats.pong_messages_received_total =
                                             stats.pong_messages_received_total.saturating_add(1);
                                     }
+                                    WsParseKind::LastTradePrice => {
+                                        stats.last_trade_price_messages_total =
+                                            stats.last_trade_price_messages_total.saturating_add(1);
+                                    }
+                                    WsParseKind::TickSizeChange => {
+                                        stats.tick_size_change_messages_total =
+                                            stats.tick_size_change_messages_total.saturating_add(1);
+                                    }
                                     _ => {}
                                 }
                                 if parsed.kind.is_parse_miss() {
@@ -632,10 +864,24 @@ impl ClobClient {
                                 }
                             });
 
-                            for update in parsed.updates {
+                            for event in parsed.events {
+                                if let MarketChannelEvent::TickSizeChange(change) = &event {
+                                    info!(
+                                        asset_id = %change.asset_id,
+                                        old_tick_size = %change.old_tick_size,
+                                        new_tick_size = %change.new_tick_size,
+                                        "Tick size changed"
+                                    );
+                                    record_tick_size(&change.asset_id, change.new_tick_size);
+                                }
+                                // No receivers is fine; events are best-effort.
+                                let _ = socket.events.send(event);
+                            }
+
+                            for update in tombstones.into_iter().chain(parsed.updates) {
                                 if tx.send(update).await.is_err() {
                                     warn!("Receiver dropped, closing WebSocket");
-                                    return Ok(());
+                                    return Ok(WsLoopExit::Stopped);
                                 }
                             }
                         }
@@ -665,7 +911,7 @@ impl ClobClient {
                                 stats.last_message_kind = Some("close_frame".to_string());
                             });
                             info!("WebSocket closed by server");
-                            return Ok(());
+                            return Ok(WsLoopExit::Closed);
                         }
                         Err(e) => {
                             warn!("WebSocket receive error: {}", e);
@@ -841,6 +1087,351 @@ pub struct OrderBookUpdate {
     pub timestamp: chrono::DateTime<chrono::Utc>,
     pub bids: Vec<PriceLevel>,
     pub asks: Vec<PriceLevel>,
+    /// Tombstone: the asset's book failed an integrity check and must be
+    /// dropped until the next (resynced) update for it. Levels are empty.
+    pub invalidated: bool,
+}
+
+impl OrderBookUpdate {
+    /// Tombstone for a quarantined asset.
+    pub fn invalidated(market_id: String, asset_id: String) -> Self {
+        Self {
+            market_id,
+            asset_id,
+            timestamp: chrono::Utc::now(),
+            bids: Vec::new(),
+            asks: Vec::new(),
+            invalidated: true,
+        }
+    }
+}
+
+/// Non-book event from the market channel.
+#[derive(Debug, Clone)]
+pub enum MarketChannelEvent {
+    Trade(TradePrint),
+    TickSizeChange(TickSizeChange),
+}
+
+/// A matched trade (`last_trade_price`). Carries no wallet or transaction
+/// hash; those only arrive later via the Data API.
+#[derive(Debug, Clone)]
+pub struct TradePrint {
+    /// Condition id.
+    pub market_id: String,
+    pub asset_id: String,
+    pub price: Decimal,
+    pub size: Decimal,
+    /// Taker side.
+    pub side: OrderSide,
+    pub fee_rate_bps: Option<u64>,
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+}
+
+/// The minimum price increment for a token changed (`tick_size_change`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TickSizeChange {
+    pub market_id: String,
+    pub asset_id: String,
+    pub old_tick_size: Decimal,
+    pub new_tick_size: Decimal,
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+}
+
+/// Live order book subscription returned by [`ClobClient::subscribe_orderbook`].
+///
+/// Assets are spread over sockets of at most `max_assets_per_socket`; all
+/// sockets feed one merged update stream. Adding or removing assets is sent
+/// on the existing sockets, so books for unchanged assets survive a
+/// selection change. Trade prints and tick-size changes are published on a
+/// separate broadcast stream (see [`OrderBookSubscription::subscribe_events`]).
+/// Dropping the handle closes every socket.
+pub struct OrderBookSubscription {
+    socket: Arc<SocketContext>,
+    max_assets_per_socket: usize,
+    tx: mpsc::Sender<OrderBookUpdate>,
+    rx: mpsc::Receiver<OrderBookUpdate>,
+    sockets: Vec<SocketHandle>,
+    /// Asset id -> index into `sockets`.
+    assignments: HashMap<String, usize>,
+}
+
+/// Settings shared by every socket of one subscription.
+struct SocketContext {
+    ws_url: String,
+    /// REST client used to resync quarantined books.
+    rest: ClobClient,
+    integrity: BookIntegrityConfig,
+    events: broadcast::Sender<MarketChannelEvent>,
+}
+
+impl SocketContext {
+    /// Fetch `asset_id`'s book from REST after `delay` and report it on `resync_tx`.
+    fn spawn_resync(
+        self: &Arc<Self>,
+        asset_id: String,
+        delay: StdDuration,
+        resync_tx: mpsc::UnboundedSender<(String, Result<OrderBook>)>,
+    ) {
+        let socket = Arc::clone(self);
+        tokio::spawn(async move {
+            tokio::time::sleep(delay).await;
+            let result = socket.rest.get_order_book(&asset_id).await;
+            let _ = resync_tx.send((asset_id, result));
+        });
+    }
+}
+
+struct SocketHandle {
+    control: mpsc::UnboundedSender<SubscriptionCommand>,
+    assets: HashSet<String>,
+}
+
+/// Net change applied by [`OrderBookSubscription::set_assets`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SubscriptionDelta {
+    pub added: usize,
+    pub removed: usize,
+}
+
+impl OrderBookSubscription {
+    /// Buffered market-channel events per lagging receiver.
+    const EVENT_CHANNEL_CAPACITY: usize = 4096;
+
+    fn new(
+        client: &ClobClient,
+        max_assets_per_socket: usize,
+        integrity: BookIntegrityConfig,
+    ) -> Self {
+        let (tx, rx) = mpsc::channel(1000);
+        Self {
+            socket: Arc::new(SocketContext {
+                ws_url: client.ws_url.clone(),
+                rest: ClobClient::new(Some(client.base_url.clone()), Some(client.ws_url.clone())),
+                integrity,
+                events: broadcast::channel(Self::EVENT_CHANNEL_CAPACITY).0,
+            }),
+            max_assets_per_socket: max_assets_per_socket.max(1),
+            tx,
+            rx,
+            sockets: Vec::new(),
+            assignments: HashMap::new(),
+        }
+    }
+
+    /// Receive the next update from any socket.
+    pub async fn recv(&mut self) -> Option<OrderBookUpdate> {
+        self.rx.recv().await
+    }
+
+    /// Receive trade prints and tick-size changes from every socket.
+    ///
+    /// Events are dropped when nobody is subscribed; a receiver that falls
+    /// more than the channel capacity behind gets `RecvError::Lagged`.
+    pub fn subscribe_events(&self) -> broadcast::Receiver<MarketChannelEvent> {
+        self.socket.events.subscribe()
+    }
+
+    /// Currently subscribed asset ids.
+    pub fn asset_ids(&self) -> Vec<String> {
+        self.assignments.keys().cloned().collect()
+    }
+
+    /// Number of sockets carrying this subscription.
+    pub fn socket_count(&self) -> usize {
+        self.sockets.len()
+    }
+
+    /// Subscribe to exactly `asset_ids`, adding and removing the difference
+    /// on the live sockets.
+    pub fn set_assets(&mut self, asset_ids: Vec<String>) -> SubscriptionDelta {
+        let target: HashSet<String> = asset_ids.into_iter().collect();
+        let removed: Vec<String> = self
+            .assignments
+            .keys()
+            .filter(|asset_id| !target.contains(*asset_id))
+            .cloned()
+            .collect();
+        let added: Vec<String> = target
+            .into_iter()
+            .filter(|asset_id| !self.assignments.contains_key(asset_id))
+            .collect();
+        let delta = SubscriptionDelta {
+            added: added.len(),
+            removed: removed.len(),
+        };
+        self.remove_assets(removed);
+        self.add_assets(added);
+        delta
+    }
+
+    /// Add assets, filling the least-loaded sockets before opening new ones.
+    pub fn add_assets(&mut self, asset_ids: Vec<String>) {
+        let mut batches: HashMap<usize, Vec<String>> = HashMap::new();
+        for asset_id in asset_ids {
+            if self.assignments.contains_key(&asset_id) {
+                continue;
+            }
+            let socket_idx = match self
+                .sockets
+                .iter()
+                .enumerate()
+                .filter(|(_, socket)| socket.assets.len() < self.max_assets_per_socket)
+                .min_by_key(|(_, socket)| socket.assets.len())
+            {
+                Some((idx, _)) => idx,
+                None => {
+                    self.sockets.push(self.spawn_socket());
+                    self.sockets.len() - 1
+                }
+            };
+            self.sockets[socket_idx].assets.insert(asset_id.clone());
+            self.assignments.insert(asset_id.clone(), socket_idx);
+            batches.entry(socket_idx).or_default().push(asset_id);
+        }
+        for (socket_idx, batch) in batches {
+            let _ = self.sockets[socket_idx]
+                .control
+                .send(SubscriptionCommand::Add(batch));
+        }
+    }
+
+    /// Remove assets and close sockets left empty.
+    pub fn remove_assets(&mut self, asset_ids: Vec<String>) {
+        let mut batches: HashMap<usize, Vec<String>> = HashMap::new();
+        for asset_id in asset_ids {
+            if let Some(socket_idx) = self.assignments.remove(&asset_id) {
+                self.sockets[socket_idx].assets.remove(&asset_id);
+                batches.entry(socket_idx).or_default().push(asset_id);
+            }
+        }
+        for (socket_idx, batch) in batches {
+            let _ = self.sockets[socket_idx]
+                .control
+                .send(SubscriptionCommand::Remove(batch));
+        }
+
+        if self.sockets.iter().any(|socket| socket.assets.is_empty()) {
+            // Dropping a socket's control sender stops its task.
+            self.sockets.retain(|socket| !socket.assets.is_empty());
+            self.assignments.clear();
+            for (idx, socket) in self.sockets.iter().enumerate() {
+                for asset_id in &socket.assets {
+                    self.assignments.insert(asset_id.clone(), idx);
+                }
+            }
+        }
+    }
+
+    fn spawn_socket(&self) -> SocketHandle {
+        let (control, control_rx) = mpsc::unbounded_channel();
+        let socket = Arc::clone(&self.socket);
+        let tx = self.tx.clone();
+        tokio::spawn(async move {
+            ClobClient::ws_loop_with_reconnect(socket, HashSet::new(), control_rx, tx).await;
+        });
+        SocketHandle {
+            control,
+            assets: HashSet::new(),
+        }
+    }
+}
+
+/// Change to one socket's asset set.
+enum SubscriptionCommand {
+    Add(Vec<String>),
+    Remove(Vec<String>),
+}
+
+impl SubscriptionCommand {
+    /// Apply while disconnected: the next connect subscribes the full set.
+    fn apply(self, assets: &mut HashSet<String>) {
+        match self {
+            Self::Add(ids) => assets.extend(ids),
+            Self::Remove(ids) => {
+                for id in ids {
+                    assets.remove(&id);
+                }
+            }
+        }
+    }
+
+    /// Apply on a live socket; returns the wire operation and the assets
+    /// that actually changed.
+    fn apply_live(self, assets: &mut HashSet<String>) -> (&'static str, Vec<String>) {
+        match self {
+            Self::Add(ids) => (
+                "subscribe",
+                ids.into_iter()
+                    .filter(|id| assets.insert(id.clone()))
+                    .collect(),
+            ),
+            Self::Remove(ids) => (
+                "unsubscribe",
+                ids.into_iter().filter(|id| assets.remove(id)).collect(),
+            ),
+        }
+    }
+}
+
+/// Why a socket's read loop returned without error.
+enum WsLoopExit {
+    /// Server closed the connection; reconnect.
+    Closed,
+    /// The subscription handle or update receiver is gone; stop.
+    Stopped,
+}
+
+/// Keeps `subscribed_assets` / `active_sockets` / `quarantined_assets` in the
+/// runtime stats in step with one open socket.
+struct SocketStatsGuard {
+    assets: usize,
+    quarantined: usize,
+}
+
+impl SocketStatsGuard {
+    fn open(assets: usize) -> Self {
+        update_ws_runtime_stats(|stats| {
+            stats.active_sockets = stats.active_sockets.saturating_add(1);
+            stats.subscribed_assets = stats.subscribed_assets.saturating_add(assets);
+        });
+        Self {
+            assets,
+            quarantined: 0,
+        }
+    }
+
+    fn set_assets(&mut self, assets: usize) {
+        let previous = self.assets;
+        self.assets = assets;
+        update_ws_runtime_stats(|stats| {
+            stats.subscribed_assets = stats.subscribed_assets.saturating_sub(previous) + assets;
+        });
+    }
+
+    fn set_quarantined(&mut self, quarantined: usize) {
+        if quarantined == self.quarantined {
+            return;
+        }
+        let previous = self.quarantined;
+        self.quarantined = quarantined;
+        update_ws_runtime_stats(|stats| {
+            stats.quarantined_assets =
+                stats.quarantined_assets.saturating_sub(previous) + quarantined;
+        });
+    }
+}
+
+impl Drop for SocketStatsGuard {
+    fn drop(&mut self) {
+        let assets = self.assets;
+        let quarantined = self.quarantined;
+        update_ws_runtime_stats(|stats| {
+            stats.active_sockets = stats.active_sockets.saturating_sub(1);
+            stats.subscribed_assets = stats.subscribed_assets.saturating_sub(assets);
+            stats.quarantined_assets = stats.quarantined_assets.saturating_sub(quarantined);
+        });
+    }
 }
 
 // Internal API response types
@@ -970,6 +1561,65 @@ struct WsBook {
     #[serde(default, alias = "sells")]
     asks: Vec<ClobPriceLevel>,
     timestamp: String,
+    #[serde(default)]
+    hash: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct WsLastTradePrice {
+    market: String,
+    asset_id: String,
+    price: String,
+    size: String,
+    side: String,
+    #[serde(default)]
+    fee_rate_bps: Option<String>,
+    timestamp: String,
+}
+
+impl WsLastTradePrice {
+    fn into_trade_print(self) -> Option<TradePrint> {
+        let side = match self.side.to_ascii_uppercase().as_str() {
+            "BUY" => OrderSide::Buy,
+            "SELL" => OrderSide::Sell,
+            _ => return None,
+        };
+        Some(TradePrint {
+            market_id: self.market,
+            asset_id: self.asset_id,
+            price: self.price.parse().ok()?,
+            size: self.size.parse().ok()?,
+            side,
+            fee_rate_bps: self.fee_rate_bps.and_then(|fee| fee.parse().ok()),
+            timestamp: parse_ws_timestamp(&self.timestamp),
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct WsTickSizeChange {
+    market: String,
+    asset_id: String,
+    old_tick_size: String,
+    new_tick_size: String,
+    #[serde(default)]
+    timestamp: Option<String>,
+}
+
+impl WsTickSizeChange {
+    fn into_tick_size_change(self) -> Option<TickSizeChange> {
+        Some(TickSizeChange {
+            market_id: self.market,
+            asset_id: self.asset_id,
+            old_tick_size: self.old_tick_size.parse().ok()?,
+            new_tick_size: self.new_tick_size.parse().ok()?,
+            timestamp: self
+                .timestamp
+                .as_deref()
+                .map(parse_ws_timestamp)
+                .unwrap_or_else(chrono::Utc::now),
+        })
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -987,6 +1637,11 @@ struct WsPriceChange {
     price: String,
     size: String,
     side: String,
+    /// Server's top of book after this change, when provided.
+    #[serde(default)]
+    best_bid: Option<String>,
+    #[serde(default)]
+    best_ask: Option<String>,
 }
 
 fn parse_ws_message(text: &str, book_state: &mut HashMap<String, OrderBook>) -> ParsedWsMessage {
@@ -995,6 +1650,8 @@ fn parse_ws_message(text: &str, book_state: &mut HashMap<String, OrderBook>) ->
     if trimmed.eq_ignore_ascii_case("PONG") || trimmed.eq_ignore_ascii_case("PING") {
         return ParsedWsMessage {
             updates: Vec::new(),
+            issues: Vec::new(),
+            events: Vec::new(),
             kind: if trimmed.eq_ignore_ascii_case("PING") {
                 WsParseKind::ControlPing
             } else {
@@ -1007,6 +1664,8 @@ fn parse_ws_message(text: &str, book_state: &mut HashMap<String, OrderBook>) ->
         return ParsedWsMessage {
             updates: Vec::new(),
             kind: WsParseKind::InvalidOperation,
+            issues: Vec::new(),
+            events: Vec::new(),
         };
     }
 
@@ -1020,17 +1679,23 @@ fn parse_ws_message(text: &str, book_state: &mut HashMap<String, OrderBook>) ->
             return ParsedWsMessage {
                 updates: Vec::new(),
                 kind: WsParseKind::InvalidJson,
+                issues: Vec::new(),
+                events: Vec::new(),
             };
         }
     };
 
     match value {
         serde_json::Value::Array(items) => {
+            let mut issues = Vec::new();
             let updates: Vec<OrderBookUpdate> = items
                 .into_iter()
                 .filter_map(parse_ws_book_from_value)
-                .inspect(|update| {
-                    book_state.insert(update.asset_id.clone(), update_to_orderbook(update));
+                .map(|(update, hash_issue)| {
+                    let book = update_to_orderbook(&update);
+                    issues.extend(snapshot_issues(&book, hash_issue));
+                    book_state.insert(update.asset_id.clone(), book);
+                    update
                 })
                 .collect();
             let kind = if updates.is_empty() {
@@ -1038,43 +1703,142 @@ fn parse_ws_message(text: &str, book_state: &mut HashMap<String, OrderBook>) ->
             } else {
                 WsParseKind::Snapshot
             };
-            ParsedWsMessage { updates, kind }
+            ParsedWsMessage {
+                updates,
+                kind,
+                issues,
+                events: Vec::new(),
+            }
         }
         serde_json::Value::Object(_) => {
-            if let Some(update) = parse_ws_book_from_value(value.clone()) {
-                book_state.insert(update.asset_id.clone(), update_to_orderbook(&update));
+            match value.get("event_type").and_then(|v| v.as_str()) {
+                Some("last_trade_price") => {
+                    let events: Vec<MarketChannelEvent> =
+                        serde_json::from_value::<WsLastTradePrice>(value)
+                            .ok()
+                            .and_then(WsLastTradePrice::into_trade_print)
+                            .map(MarketChannelEvent::Trade)
+                            .into_iter()
+                            .collect();
+                    return ParsedWsMessage {
+                        updates: Vec::new(),
+                        kind: if events.is_empty() {
+                            WsParseKind::UnsupportedObject
+                        } else {
+                            WsParseKind::LastTradePrice
+                        },
+                        issues: Vec::new(),
+                        events,
+                    };
+                }
+                Some("tick_size_change") => {
+                    let events: Vec<MarketChannelEvent> =
+                        serde_json::from_value::<WsTickSizeChange>(value)
+                            .ok()
+                            .and_then(WsTickSizeChange::into_tick_size_change)
+                            .map(MarketChannelEvent::TickSizeChange)
+                            .into_iter()
+                            .collect();
+                    return ParsedWsMessage {
+                        updates: Vec::new(),
+                        kind: if events.is_empty() {
+                            WsParseKind::UnsupportedObject
+                        } else {
+                            WsParseKind::TickSizeChange
+                        },
+                        issues: Vec::new(),
+                        events,
+                    };
+                }
+                _ => {}
+            }
+
+            if let Some((update, hash_issue)) = parse_ws_book_from_value(value.clone()) {
+                let book = update_to_orderbook(&update);
+                let issues = snapshot_issues(&book, hash_issue);
+                book_state.insert(update.asset_id.clone(), book);
                 return ParsedWsMessage {
                     updates: vec![update],
                     kind: WsParseKind::Snapshot,
+                    issues,
+                    events: Vec::new(),
                 };
             }
 
             if let Ok(event) = serde_json::from_value::<WsPriceChangeEvent>(value) {
-                let updates = apply_price_changes(event, book_state);
+                let mut issues = Vec::new();
+                let updates = apply_price_changes(event, book_state, &mut issues);
                 let kind = if updates.is_empty() {
                     WsParseKind::EmptyPriceChange
                 } else {
                     WsParseKind::PriceChange
                 };
-                return ParsedWsMessage { updates, kind };
+                return ParsedWsMessage {
+                    updates,
+                    kind,
+                    issues,
+                    events: Vec::new(),
+                };
             }
 
             ParsedWsMessage {
                 updates: Vec::new(),
                 kind: WsParseKind::UnsupportedObject,
+                issues: Vec::new(),
+                events: Vec::new(),
             }
         }
         _ => ParsedWsMessage {
             updates: Vec::new(),
             kind: WsParseKind::UnsupportedValue,
+            issues: Vec::new(),
+            events: Vec::new(),
         },
     }
 }
 
-fn parse_ws_book_from_value(value: serde_json::Value) -> Option<OrderBookUpdate> {
+/// Crossed/locked and hash checks for a freshly applied snapshot.
+fn snapshot_issues(book: &OrderBook, hash_issue: Option<BookIssue>) -> Vec<(String, BookIssue)> {
+    hash_issue
+        .into_iter()
+        .chain(book_integrity::check_crossed(book))
+        .map(|issue| (book.outcome_id.clone(), issue))
+        .collect()
+}
+
+fn parse_ws_book_from_value(
+    value: serde_json::Value,
+) -> Option<(OrderBookUpdate, Option<BookIssue>)> {
     let ws_book = serde_json::from_value::<WsBook>(value).ok()?;
 
-    Some(OrderBookUpdate {
+    let hash_issue = ws_book
+        .hash
+        .as_deref()
+        .filter(|h| !h.is_empty())
+        .and_then(|expected| {
+            fn raw(levels: &[ClobPriceLevel]) -> Vec<RawLevel<'_>> {
+                levels
+                    .iter()
+                    .map(|l| RawLevel {
+                        price: &l.price,
+                        size: &l.size,
+                    })
+                    .collect()
+            }
+            let computed = book_integrity::summary_hash(
+                &ws_book.market,
+                &ws_book.asset_id,
+                &ws_book.timestamp,
+                &raw(&ws_book.bids),
+                &raw(&ws_book.asks),
+            );
+            (computed != expected).then(|| BookIssue::HashMismatch {
+                expected: expected.to_string(),
+                computed,
+            })
+        });
+
+    let update = OrderBookUpdate {
         market_id: ws_book.market,
         asset_id: ws_book.asset_id,
         timestamp: parse_ws_timestamp(&ws_book.timestamp),
@@ -1094,12 +1858,15 @@ fn parse_ws_book_from_value(value: serde_json::Value) -> Option<OrderBookUpdate>
                 size: l.size.parse().unwrap_or_default(),
             })
             .collect(),
-    })
+        invalidated: false,
+    };
+    Some((update, hash_issue))
 }
 
 fn apply_price_changes(
     event: WsPriceChangeEvent,
     book_state: &mut HashMap<String, OrderBook>,
+    issues: &mut Vec<(String, BookIssue)>,
 ) -> Vec<OrderBookUpdate> {
     let timestamp = event
         .timestamp
@@ -1118,15 +1885,30 @@ fn apply_price_changes(
             Err(_) => continue,
         };
 
-        let mut book = book_state
-            .remove(&change.asset_id)
-            .unwrap_or_else(|| OrderBook {
-                market_id: event.market.clone(),
-                outcome_id: change.asset_id.clone(),
-                timestamp,
-                bids: Vec::new(),
-                asks: Vec::new(),
-            });
+        let mut book = match book_state.remove(&change.asset_id) {
+            Some(book) => {
+                if timestamp < book.timestamp {
+                    issues.push((
+                        change.asset_id.clone(),
+                        BookIssue::OutOfOrder {
+                            book_at: book.timestamp,
+                            delta_at: timestamp,
+                        },
+                    ));
+                }
+                book
+            }
+            None => {
+                issues.push((change.asset_id.clone(), BookIssue::MissingSnapshot));
+                OrderBook {
+                    market_id: event.market.clone(),
+                    outcome_id: change.asset_id.clone(),
+                    timestamp,
+                    bids: Vec::new(),
+                    asks: Vec::new(),
+                }
+            }
+        };
 
         book.market_id = event.market.clone();
         book.timestamp = timestamp;
@@ -1140,12 +1922,21 @@ fn apply_price_changes(
             continue;
         }
 
+        let expected_bid = change.best_bid.as_deref().and_then(|p| p.parse().ok());
+        let expected_ask = change.best_ask.as_deref().and_then(|p| p.parse().ok());
+        if let Some(issue) = book_integrity::check_top_of_book(&book, expected_bid, expected_ask)
+            .or_else(|| book_integrity::check_crossed(&book))
+        {
+            issues.push((change.asset_id.clone(), issue));
+        }
+
         let update = OrderBookUpdate {
             market_id: book.market_id.clone(),
             asset_id: book.outcome_id.clone(),
             timestamp: book.timestamp,
             bids: book.bids.clone(),
             asks: book.asks.clone(),
+            invalidated: false,
         };
         book_state.insert(change.asset_id, book);
         updates.push(update);
@@ -1229,15 +2020,20 @@ impl ApiCredentials {
     /// Load from environment variables.
     #[allow(clippy::result_large_err)]
     pub fn from_env() -> Result<Self> {
-        let api_key = std::env::var("POLY_API_KEY").map_err(|_| Error::Config {
-            message: "POLY_API_KEY environment variable not set".to_string(),
-        })?;
-        let api_secret = std::env::var("POLY_API_SECRET").map_err(|_| Error::Config {
-            message: "POLY_API_SECRET environment variable not set".to_string(),
-        })?;
-        let api_passphrase = std::env::var("POLY_API_PASSPHRASE").map_err(|_| Error::Config {
-            message: "POLY_API_PASSPHRASE environment variable not set".to_string(),
-        })?;
+        let api_key =
+            crate::settings::get_opt::<String>("POLY_API_KEY").ok_or_else(|| Error::Config {
+                message: "POLY_API_KEY environment variable not set".to_string(),
+            })?;
+        let api_secret =
+            crate::settings::get_opt::<String>("POLY_API_SECRET").ok_or_else(|| Error::Config {
+                message: "POLY_API_SECRET environment variable not set".to_string(),
+            })?;
+        let api_passphrase =
+            crate::settings::get_opt::<String>("POLY_API_PASSPHRASE").ok_or_else(|| {
+                Error::Config {
+                    message: "POLY_API_PASSPHRASE environment variable not set".to_string(),
+                }
+            })?;
 
         Ok(Self {
             api_key,
@@ -1313,18 +2109,39 @@ impl PostOrderResponse {
 }
 
 /// Open order information.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OpenOrder {
     pub id: String,
     pub asset_id: String,
     pub market: String,
     pub side: String,
     pub price: String,
+    #[serde(alias = "original_size")]
     pub size: String,
+    /// Quantity already filled, when the CLOB reports it.
+    #[serde(default)]
+    pub size_matched: Option<String>,
     pub status: String,
     pub created_at: Option<String>,
 }
 
+impl OpenOrder {
+    /// Quantity filled so far; zero when the CLOB leaves it out.
+    pub fn matched_quantity(&self) -> Decimal {
+        self.size_matched
+            .as_deref()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(Decimal::ZERO)
+    }
+
+    /// Whether the order is still resting and can fill further. Matched,
+    /// cancelled and expired orders are final.
+    pub fn is_open(&self) -> bool {
+        let s = self.status.to_lowercase();
+        s == "live" || s == "delayed"
+    }
+}
+
 /// Response from deriving API credentials.
 #[derive(Debug, Clone, Deserialize)]
 pub struct DeriveApiKeyResponse {
@@ -1556,8 +2373,54 @@ impl AuthenticatedClobClient {
         Ok(result.neg_risk)
     }
 
+    /// Current tick size for a token.
+    ///
+    /// Prefers a value seen within `CLOB_TICK_SIZE_CACHE_SECS` (from a
+    /// `tick_size_change` event, one relayed on [`TICK_SIZE_CHANNEL`] or an
+    /// earlier lookup), else asks the CLOB API.
+    #[instrument(name = "clob.get_tick_size", skip_all, fields(otel.kind = "client", token_id = %token_id))]
+    async fn get_tick_size(&self, token_id: &str) -> Result<Decimal> {
+        let max_age_secs = crate::settings::get("CLOB_TICK_SIZE_CACHE_SECS");
+        if let Some(cached) = cached_tick_size(token_id, StdDuration::from_secs(max_age_secs)) {
+            return Ok(cached);
+        }
+
+        let url = format!("{}/tick-size?token_id={}", self.client.base_url, token_id);
+        let response = self.client.http_client.get(&url).send().await?;
+        if !response.status().is_success() {
+            return Err(Error::Api {
+                message: format!("Failed to query tick size for {}", token_id),
+                status: Some(response.status().as_u16()),
+            });
+        }
+
+        #[derive(Deserialize)]
+        struct TickSizeResponse {
+            minimum_tick_size: serde_json::Value,
+        }
+
+        let result: TickSizeResponse = response.json().await?;
+        let tick_size = match &result.minimum_tick_size {
+            serde_json::Value::String(raw) => raw.parse::<Decimal>().ok(),
+            other => other.to_string().parse::<Decimal>().ok(),
+        }
+        .filter(|tick| *tick > Decimal::ZERO)
+        .ok_or_else(|| Error::Api {
+            message: format!(
+                "Invalid tick size for {}: {}",
+                token_id, result.minimum_tick_size
+            ),
+            status: None,
+        })?;
+
+        record_tick_size(token_id, tick_size);
+        debug!(token_id = token_id, tick_size = %tick_size, "Queried and cached tick size");
+        Ok(tick_size)
+    }
+
     /// Query the CLOB API for the taker fee rate for a token.
     /// Results are cached per token_id since fee rates rarely change.
+    #[instrument(name = "clob.get_fee_rate", skip_all, fields(otel.kind = "client", token_id = %token_id))]
     async fn get_fee_rate_bps(&self, token_id: &str) -> Result<u64> {
         // Check cache first
         if let Some(&cached) = self.fee_rate_cache.lock().unwrap().get(token_id) {
@@ -1613,6 +2476,7 @@ impl AuthenticatedClobClient {
     ///
     /// This checks what the CLOB server sees (on-chain) for the maker address.
     /// Useful for diagnosing "not enough balance / allowance" errors.
+    #[instrument(name = "clob.get_balance_allowance", skip_all, fields(otel.kind = "client", asset_type = %asset_type))]
     pub async fn get_balance_allowance(
         &self,
         token_id: Option<&str>,
@@ -1666,6 +2530,7 @@ impl AuthenticatedClobClient {
     /// Must be called after setting on-chain approvals so the CLOB picks up the
     /// new allowance values. `signature_type` is 0 for EOA wallets. Conditional
     /// asset refreshes require a token id.
+    #[instrument(name = "clob.update_balance_allowance", skip_all, fields(otel.kind = "client", asset_type = %asset_type))]
     pub async fn update_balance_allowance_for_token(
         &self,
         asset_type: &str,
@@ -1727,6 +2592,7 @@ impl AuthenticatedClobClient {
     ///
     /// For GTC/FOK orders, expiration is forced to 0 per the CLOB API contract.
     /// Only GTD orders use a real expiration timestamp.
+    #[instrument(name = "clob.create_order", skip_all, fields(token_id = %token_id, side = ?side))]
     pub async fn create_order(
         &self,
         token_id: &str,
@@ -1748,6 +2614,39 @@ impl AuthenticatedClobClient {
         // Fetch the market's required fee rate
         let fee_rate = self.get_fee_rate_bps(token_id).await.unwrap_or(0);
 
+        // Off-grid prices are rejected by the CLOB; snap to the current tick.
+        let price = match self.get_tick_size(token_id).await {
+            Ok(tick_size) => {
+                let rounded = round_to_tick(
+                    price,
+                    tick_size,
+                    match side {
+                        crate::signing::OrderSide::Buy => OrderSide::Buy,
+                        crate::signing::OrderSide::Sell => OrderSide::Sell,
+                    },
+                )
+                .ok_or_else(|| Error::Order {
+                    message: format!(
+                        "price {price} is outside the tradable range for tick size {tick_size}"
+                    ),
+                })?;
+                if rounded != price {
+                    debug!(
+                        token_id = token_id,
+                        requested = %price,
+                        rounded = %rounded,
+                        tick_size = %tick_size,
+                        "Rounded order price to tick size"
+                    );
+                }
+                rounded
+            }
+            Err(e) => {
+                warn!(token_id = token_id, error = %e, "Tick size unavailable, submitting price unrounded");
+                price
+            }
+        };
+
         info!(
             token_id = token_id,
             side = ?side,
@@ -1798,6 +2697,7 @@ impl AuthenticatedClobClient {
     ///
     /// For BUY orders, `amount` is the USDC notional to spend.
     /// For SELL orders, `amount` is the token quantity to sell.
+    #[instrument(name = "clob.create_market_order", skip_all, fields(token_id = %token_id, side = ?side))]
     pub async fn create_market_order(
         &self,
         token_id: &str,
@@ -1915,6 +2815,26 @@ impl AuthenticatedClobClient {
         &self,
         signed_order: SignedOrder,
         order_type: OrderType,
+    ) -> Result<PostOrderResponse> {
+        self.submit_order(signed_order, order_type, None).await
+    }
+
+    /// Post a signed GTC order that may only add liquidity. The CLOB rejects
+    /// it instead of matching when it would cross the book.
+    pub async fn post_order_post_only(
+        &self,
+        signed_order: SignedOrder,
+    ) -> Result<PostOrderResponse> {
+        self.submit_order(signed_order, OrderType::Gtc, Some(true))
+            .await
+    }
+
+    #[instrument(name = "clob.post_order", skip_all, fields(otel.kind = "client", order_type = ?order_type, order_id = tracing::field::Empty))]
+    async fn submit_order(
+        &self,
+        signed_order: SignedOrder,
+        order_type: OrderType,
+        post_only: Option<bool>,
     ) -> Result<PostOrderResponse> {
         let credentials = self.credentials.as_ref().ok_or_else(|| Error::Auth {
             message: "API credentials not set - call derive_api_key() first".to_string(),
@@ -1929,7 +2849,7 @@ impl AuthenticatedClobClient {
             order: signed_order,
             order_type,
             owner: credentials.api_key.clone(),
-            post_only: None,
+            post_only,
         };
 
         let body = serde_json::to_string(&request)?;
@@ -1963,12 +2883,14 @@ impl AuthenticatedClobClient {
         }
 
         let result: PostOrderResponse = response.json().await?;
+        tracing::Span::current().record("order_id", result.order_id.as_str());
         info!(order_id = %result.order_id, "Order posted successfully");
 
         Ok(result)
     }
 
     /// Cancel an order by ID.
+    #[instrument(name = "clob.cancel_order", skip_all, fields(otel.kind = "client", order_id = %order_id))]
     pub async fn cancel_order(&self, order_id: &str) -> Result<()> {
         let credentials = self.credentials.as_ref().ok_or_else(|| Error::Auth {
             message: "API credentials not set - call derive_api_key() first".to_string(),
@@ -2007,6 +2929,7 @@ impl AuthenticatedClobClient {
     }
 
     /// Get open orders for the authenticated user.
+    #[instrument(name = "clob.get_open_orders", skip_all, fields(otel.kind = "client", market = ?market))]
     pub async fn get_open_orders(&self, market: Option<&str>) -> Result<Vec<OpenOrder>> {
         let credentials = self.credentials.as_ref().ok_or_else(|| Error::Auth {
             message: "API credentials not set - call derive_api_key() first".to_string(),
@@ -2048,7 +2971,46 @@ impl AuthenticatedClobClient {
         Ok(orders)
     }
 
+    /// Get one order by ID, whatever its status.
+    #[instrument(name = "clob.get_order", skip_all, fields(otel.kind = "client", order_id = %order_id))]
+    pub async fn get_order(&self, order_id: &str) -> Result<OpenOrder> {
+        let credentials = self.credentials.as_ref().ok_or_else(|| Error::Auth {
+            message: "API credentials not set - call derive_api_key() first".to_string(),
+        })?;
+
+        let path = format!("/data/order/{}", order_id);
+        let url = format!("{}{}", self.client.base_url, path);
+        let timestamp = current_timestamp().to_string();
+
+        let signature = sign_l2_request(credentials, "GET", &path, &timestamp, None)?;
+
+        let response = self
+            .client
+            .http_client
+            .get(&url)
+            .header("POLY_ADDRESS", self.address())
+            .header("POLY_SIGNATURE", signature)
+            .header("POLY_TIMESTAMP", &timestamp)
+            .header("POLY_API_KEY", &credentials.api_key)
+            .header("POLY_PASSPHRASE", &credentials.api_passphrase)
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let text = response.text().await.unwrap_or_default();
+            return Err(Error::Api {
+                message: format!("Failed to get order: {} - {}", status, text),
+                status: Some(status),
+            });
+        }
+
+        let order: OpenOrder = response.json().await?;
+        Ok(order)
+    }
+
     /// Cancel all open orders.
+    #[instrument(name = "clob.cancel_all_orders", skip_all, fields(otel.kind = "client"))]
     pub async fn cancel_all_orders(&self) -> Result<()> {
         let credentials = self.credentials.as_ref().ok_or_else(|| Error::Auth {
             message: "API credentials not set - call derive_api_key() first".to_string(),
@@ -2243,6 +3205,33 @@ mod authenticated_tests {
         AuthenticatedClobClient::new(client, order_signer)
     }
 
+    #[test]
+    fn test_open_order_fill_state() {
+        let order: OpenOrder = serde_json::from_value(serde_json::json!({
+            "id": "0x1",
+            "asset_id": "123",
+            "market": "0xabc",
+            "side": "BUY",
+            "price": "0.45",
+            "original_size": "100",
+            "size_matched": "40",
+            "status": "CANCELED",
+            "created_at": null
+        }))
+        .unwrap();
+        assert_eq!(order.size, "100");
+        assert_eq!(order.matched_quantity(), Decimal::new(40, 0));
+        assert!(!order.is_open());
+
+        let live = OpenOrder {
+            status: "LIVE".to_string(),
+            size_matched: None,
+            ..order
+        };
+        assert_eq!(live.matched_quantity(), Decimal::ZERO);
+        assert!(live.is_open());
+    }
+
     #[test]
     fn test_authenticated_client_creation() {
         let auth_client = test_auth_client();
@@ -2429,4 +3418,154 @@ mod authenticated_tests {
         let converted: Market = market.into();
         assert!(converted.resolved);
     }
+
+    #[tokio::test]
+    async fn test_subscription_spreads_assets_and_updates_in_place() {
+        // Sockets never connect here; only the handle's bookkeeping is checked.
+        let client = ClobClient::new(None, Some("ws://127.0.0.1:9".to_string()));
+        let mut subscription =
+            OrderBookSubscription::new(&client, 2, BookIntegrityConfig::default());
+        let ids = |range: std::ops::Range<u32>| -> Vec<String> {
+            range.map(|i| format!("asset-{i}")).collect()
+        };
+
+        let delta = subscription.set_assets(ids(0..5));
+        assert_eq!(
+            delta,
+            SubscriptionDelta {
+                added: 5,
+                removed: 0
+            }
+        );
+        assert_eq!(subscription.socket_count(), 3);
+
+        // Overlapping selection: only the difference moves.
+        let delta = subscription.set_assets(ids(2..6));
+        assert_eq!(
+            delta,
+            SubscriptionDelta {
+                added: 1,
+                removed: 2
+            }
+        );
+        let mut assets = subscription.asset_ids();
+        assets.sort();
+        assert_eq!(assets, ids(2..6));
+        assert!(subscription.socket_count() <= 3);
+        assert!(subscription
+            .sockets
+            .iter()
+            .all(|socket| !socket.assets.is_empty() && socket.assets.len() <= 2));
+        for (asset_id, idx) in &subscription.assignments {
+            assert!(subscription.sockets[*idx].assets.contains(asset_id));
+        }
+
+        subscription.set_assets(Vec::new());
+        assert_eq!(subscription.socket_count(), 0);
+    }
+
+    #[test]
+    fn test_ws_deltas_report_integrity_issues() {
+        let mut books = HashMap::new();
+
+        // Delta before any snapshot.
+        let orphan = r#"{"market":"0xm","timestamp":"1700000000000","price_changes":[
+            {"asset_id":"a","price":"0.40","size":"10","side":"BUY"}]}"#;
+        let parsed = parse_ws_message(orphan, &mut books);
+        assert_eq!(
+            parsed.issues,
+            vec![("a".to_string(), BookIssue::MissingSnapshot)]
+        );
+
+        let snapshot = r#"{"market":"0xm","asset_id":"b","timestamp":"1700000000000",
+            "bids":[{"price":"0.40","size":"10"}],"asks":[{"price":"0.45","size":"10"}]}"#;
+        let parsed = parse_ws_message(snapshot, &mut books);
+        assert!(parsed.issues.is_empty());
+
+        // Server says the best bid is 0.42 after this change; we computed 0.41.
+        let drifted = r#"{"market":"0xm","timestamp":"1700000001000","price_changes":[
+            {"asset_id":"b","price":"0.41","size":"5","side":"BUY","best_bid":"0.42","best_ask":"0.45"}]}"#;
+        let parsed = parse_ws_message(drifted, &mut books);
+        assert_eq!(parsed.issues.len(), 1);
+        assert_eq!(parsed.issues[0].1.as_str(), "top_of_book_mismatch");
+
+        // A bid through the ask crosses the book.
+        let crossing = r#"{"market":"0xm","timestamp":"1700000002000","price_changes":[
+            {"asset_id":"b","price":"0.50","size":"5","side":"BUY"}]}"#;
+        let parsed = parse_ws_message(crossing, &mut books);
+        assert_eq!(parsed.issues[0].1.as_str(), "crossed");
+    }
+
+    #[test]
+    fn test_ws_trade_and_tick_size_events_are_typed() {
+        let mut books = HashMap::new();
+
+        let trade = r#"{"event_type":"last_trade_price","market":"0xm","asset_id":"a",
+            "price":"0.456","size":"219.21","side":"BUY","fee_rate_bps":"0","timestamp":"1700000000000"}"#;
+        let parsed = parse_ws_message(trade, &mut books);
+        assert!(matches!(parsed.kind, WsParseKind::LastTradePrice));
+        assert!(parsed.updates.is_empty());
+        match &parsed.events[..] {
+            [MarketChannelEvent::Trade(print)] => {
+                assert_eq!(print.side, OrderSide::Buy);
+                assert_eq!(print.price, "0.456".parse::<Decimal>().unwrap());
+                assert_eq!(print.fee_rate_bps, Some(0));
+            }
+            other => panic!("unexpected events: {other:?}"),
+        }
+
+        let tick = r#"{"event_type":"tick_size_change","market":"0xm","asset_id":"a",
+            "old_tick_size":"0.01","new_tick_size":"0.001","timestamp":"1700000000000"}"#;
+        let parsed = parse_ws_message(tick, &mut books);
+        assert!(matches!(parsed.kind, WsParseKind::TickSizeChange));
+        assert!(matches!(
+            &parsed.events[..],
+            [MarketChannelEvent::TickSizeChange(change)]
+                if change.new_tick_size == "0.001".parse::<Decimal>().unwrap()
+        ));
+    }
+
+    #[test]
+    fn test_round_to_tick_never_improves_aggressiveness() {
+        let d = |v: &str| v.parse::<Decimal>().unwrap();
+        assert_eq!(
+            round_to_tick(d("0.4567"), d("0.01"), OrderSide::Buy),
+            Some(d("0.45"))
+        );
+        assert_eq!(
+            round_to_tick(d("0.4567"), d("0.01"), OrderSide::Sell),
+            Some(d("0.46"))
+        );
+        assert_eq!(
+            round_to_tick(d("0.4567"), d("0.001"), OrderSide::Buy),
+            Some(d("0.456"))
+        );
+        assert_eq!(
+            round_to_tick(d("0.45"), d("0.01"), OrderSide::Sell),
+            Some(d("0.45"))
+        );
+        assert_eq!(
+            round_to_tick(d("0.99"), d("0.01"), OrderSide::Sell),
+            Some(d("0.99"))
+        );
+        // Off-range prices are rejected rather than clamped toward the book.
+        assert_eq!(round_to_tick(d("0.995"), d("0.01"), OrderSide::Sell), None);
+        assert_eq!(round_to_tick(d("0.004"), d("0.01"), OrderSide::Buy), None);
+    }
+
+    #[test]
+    fn test_live_subscription_command_reports_only_changed_assets() {
+        let mut assets: HashSet<String> = ["a".to_string(), "b".to_string()].into();
+        let (operation, changed) = SubscriptionCommand::Add(vec!["b".to_string(), "c".to_string()])
+            .apply_live(&mut assets);
+        assert_eq!(operation, "subscribe");
+        assert_eq!(changed, vec!["c".to_string()]);
+
+        let (operation, changed) =
+            SubscriptionCommand::Remove(vec!["a".to_string(), "z".to_string()])
+                .apply_live(&mut assets);
+        assert_eq!(operation, "unsubscribe");
+        assert_eq!(changed, vec!["a".to_string()]);
+        assert_eq!(assets.len(), 2);
+    }
 }
@@ -14,8 +14,6 @@ use tracing::{debug, warn};
 
 /// Gamma API base URL.
 const DEFAULT_BASE_URL: &str = "https://gamma-api.polymarket.com";
-const DEFAULT_MAX_PAGE_SIZE: u32 = 100;
-const DEFAULT_PAGE_DELAY_MS: u64 = 125;
 
 /// Market metadata from the Gamma API.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -55,6 +53,9 @@ pub struct GammaMarket {
     /// Whether the market is archived.
     #[serde(default)]
     pub archived: bool,
+    /// When the market closed, once it has.
+    #[serde(default, alias = "closedTime")]
+    pub closed_time: Option<String>,
     /// Whether the market accepts orders right now.
     #[serde(default, alias = "acceptingOrders")]
     pub accepting_orders: bool,
@@ -126,6 +127,15 @@ impl GammaMarket {
             && self.enable_order_book
     }
 
+    /// Parsed close time; Gamma sends either RFC 3339 or `2024-11-06 12:00:00+00`.
+    pub fn closed_at(&self) -> Option<DateTime<Utc>> {
+        let raw = self.closed_time.as_deref()?.trim();
+        DateTime::parse_from_rfc3339(raw)
+            .or_else(|_| DateTime::parse_from_str(raw, "%Y-%m-%d %H:%M:%S%.f%#z"))
+            .ok()
+            .map(|at| at.with_timezone(&Utc))
+    }
+
     fn parse_string_array(raw: Option<&str>) -> Option<Vec<String>> {
         let raw = raw?;
         serde_json::from_str::<Vec<String>>(raw).ok()
@@ -229,22 +239,13 @@ impl GammaClient {
     }
 
     fn capped_page_size(page_size: u32) -> u32 {
-        page_size.max(1).min(
-            std::env::var("GAMMA_MAX_PAGE_SIZE")
-                .ok()
-                .and_then(|value| value.parse::<u32>().ok())
-                .unwrap_or(DEFAULT_MAX_PAGE_SIZE)
-                .max(1),
-        )
+        page_size
+            .max(1)
+            .min(crate::settings::get("GAMMA_MAX_PAGE_SIZE"))
     }
 
     fn page_delay() -> StdDuration {
-        StdDuration::from_millis(
-            std::env::var("GAMMA_PAGE_DELAY_MS")
-                .ok()
-                .and_then(|value| value.parse::<u64>().ok())
-                .unwrap_or(DEFAULT_PAGE_DELAY_MS),
-        )
+        StdDuration::from_millis(crate::settings::get("GAMMA_PAGE_DELAY_MS"))
     }
 
     /// Execute an HTTP GET with retry and exponential backoff.
@@ -465,6 +466,25 @@ mod tests {
         assert!(market.active); // defaults to true
     }
 
+    #[test]
+    fn test_gamma_market_closed_at_formats() {
+        let json = r#"{
+            "conditionId": "0x1234",
+            "question": "Test market",
+            "closed": true,
+            "closedTime": "2024-11-06 12:30:00+00"
+        }"#;
+        let mut market: GammaMarket = serde_json::from_str(json).unwrap();
+        let expected = "2024-11-06T12:30:00Z".parse::<DateTime<Utc>>().unwrap();
+        assert_eq!(market.closed_at(), Some(expected));
+
+        market.closed_time = Some("2024-11-06T12:30:00Z".to_string());
+        assert_eq!(market.closed_at(), Some(expected));
+
+        market.closed_time = Some("soon".to_string());
+        assert_eq!(market.closed_at(), None);
+    }
+
     #[test]
     fn test_parsed_gamma_market_conversion() {
         let gamma = GammaMarket {
@@ -480,6 +500,7 @@ mod tests {
             active: true,
             closed: false,
             archived: false,
+            closed_time: None,
             accepting_orders: true,
             enable_order_book: true,
             description: None,
@@ -510,6 +531,7 @@ mod tests {
             active: true,
             closed: false,
             archived: false,
+            closed_time: None,
             accepting_orders: true,
             enable_order_book: true,
             description: Some("Resolves YES if...".to_string()),
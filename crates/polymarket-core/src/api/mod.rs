@@ -1,6 +1,7 @@
 //! API clients for external services.
 
 pub mod approvals;
+pub mod book_integrity;
 pub mod clob;
 pub mod gamma;
 pub mod polygon;
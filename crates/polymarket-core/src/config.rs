@@ -1,8 +1,8 @@
 //! Configuration management for the Polymarket Scanner system.
 
+use crate::settings;
 use crate::{Error, Result};
 use serde::Deserialize;
-use std::env;
 
 /// Application configuration.
 #[derive(Debug, Clone, Deserialize)]
@@ -56,6 +56,28 @@ pub struct AlertsConfig {
     pub telegram_bot_token: Option<String>,
     pub telegram_chat_id: Option<String>,
     pub discord_webhook_url: Option<String>,
+    /// Slack-compatible incoming webhook.
+    #[serde(default)]
+    pub slack_webhook_url: Option<String>,
+    /// Generic JSON webhook receiving structured alerts.
+    #[serde(default)]
+    pub webhook_url: Option<String>,
+    #[serde(default)]
+    pub webhook_bearer_token: Option<String>,
+}
+
+impl AlertsConfig {
+    /// Load alert sink credentials from environment variables.
+    pub fn from_env() -> Self {
+        Self {
+            telegram_bot_token: settings::get_opt("TELEGRAM_BOT_TOKEN"),
+            telegram_chat_id: settings::get_opt("TELEGRAM_CHAT_ID"),
+            discord_webhook_url: settings::get_opt("DISCORD_WEBHOOK_URL"),
+            slack_webhook_url: settings::get_opt("SLACK_WEBHOOK_URL"),
+            webhook_url: settings::get_opt("ALERT_WEBHOOK_URL"),
+            webhook_bearer_token: settings::get_opt("ALERT_WEBHOOK_TOKEN"),
+        }
+    }
 }
 
 impl Config {
@@ -66,45 +88,27 @@ impl Config {
 
         Ok(Self {
             database: DatabaseConfig {
-                url: env::var("DATABASE_URL").map_err(|_| Error::Config {
+                url: settings::get_opt("DATABASE_URL").ok_or_else(|| Error::Config {
                     message: "DATABASE_URL environment variable not set".to_string(),
                 })?,
-                max_connections: env::var("DATABASE_MAX_CONNECTIONS")
-                    .ok()
-                    .and_then(|s| s.parse().ok())
-                    .unwrap_or(5),
-                max_retries: env::var("DB_RETRY_MAX_ATTEMPTS")
-                    .ok()
-                    .and_then(|s| s.parse().ok())
-                    .unwrap_or(5),
-                retry_base_delay_ms: env::var("DB_RETRY_BASE_DELAY_MS")
-                    .ok()
-                    .and_then(|s| s.parse().ok())
-                    .unwrap_or(1000),
-                retry_max_delay_ms: env::var("DB_RETRY_MAX_DELAY_MS")
-                    .ok()
-                    .and_then(|s| s.parse().ok())
-                    .unwrap_or(30000),
-                acquire_timeout_secs: env::var("DB_ACQUIRE_TIMEOUT_SECS")
-                    .ok()
-                    .and_then(|s| s.parse().ok()),
+                max_connections: settings::get("DATABASE_MAX_CONNECTIONS"),
+                max_retries: settings::get("DB_RETRY_MAX_ATTEMPTS"),
+                retry_base_delay_ms: settings::get("DB_RETRY_BASE_DELAY_MS"),
+                retry_max_delay_ms: settings::get("DB_RETRY_MAX_DELAY_MS"),
+                acquire_timeout_secs: Some(settings::get("DB_ACQUIRE_TIMEOUT_SECS")),
             },
             redis: RedisConfig {
-                url: env::var("REDIS_URL").unwrap_or_else(|_| "redis://127.0.0.1:6379".to_string()),
+                url: settings::get("REDIS_URL"),
             },
             polygon: PolygonConfig {
-                rpc_url: env::var("POLYGON_RPC_URL").ok(),
-                alchemy_api_key: env::var("ALCHEMY_API_KEY").ok(),
+                rpc_url: settings::get_opt("POLYGON_RPC_URL"),
+                alchemy_api_key: settings::get_opt("ALCHEMY_API_KEY"),
             },
             polymarket: PolymarketConfig {
-                clob_url: env::var("POLYMARKET_CLOB_URL").ok(),
-                ws_url: env::var("POLYMARKET_WS_URL").ok(),
-            },
-            alerts: AlertsConfig {
-                telegram_bot_token: env::var("TELEGRAM_BOT_TOKEN").ok(),
-                telegram_chat_id: env::var("TELEGRAM_CHAT_ID").ok(),
-                discord_webhook_url: env::var("DISCORD_WEBHOOK_URL").ok(),
+                clob_url: settings::get_opt("POLYMARKET_CLOB_URL"),
+                ws_url: settings::get_opt("POLYMARKET_WS_URL"),
             },
+            alerts: AlertsConfig::from_env(),
         })
     }
 
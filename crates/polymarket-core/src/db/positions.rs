@@ -639,15 +639,26 @@ impl PositionRepository {
             .collect())
     }
 
-    /// Total cost-basis exposure across all open positions (states: Pending, Open, ExitReady, Closing).
+    /// Total cost-basis exposure across all open positions (states: Pending, Open, ExitReady, Closing)
+    /// plus market-maker inventory.
     pub async fn get_total_active_exposure(&self) -> Result<Decimal> {
         let value: Option<Decimal> = sqlx::query_scalar(
             r#"
-            SELECT COALESCE(SUM(
-                quantity * COALESCE(entry_price, yes_entry_price + no_entry_price, 0)
-            ), 0)
-            FROM positions
-            WHERE state IN (0, 1, 2, 3)
+            SELECT
+                COALESCE((
+                    SELECT SUM(
+                        quantity * COALESCE(entry_price, yes_entry_price + no_entry_price, 0)
+                    )
+                    FROM positions
+                    WHERE state IN (0, 1, 2, 3)
+                ), 0)
+                + COALESCE((
+                    SELECT SUM(
+                        CASE WHEN position >= 0 THEN position * avg_price
+                             ELSE -position * (1 - avg_price) END
+                    )::numeric
+                    FROM mm_inventory
+                ), 0)
             "#,
         )
         .fetch_one(&self.pool)
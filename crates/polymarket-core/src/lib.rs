@@ -2,13 +2,18 @@
 //!
 //! Shared types, API clients, and database models for the Polymarket Scanner system.
 
+pub mod alerting;
 pub mod api;
 pub mod config;
 pub mod db;
 pub mod error;
 pub mod feature_extractor;
+pub mod metrics;
+pub mod settings;
+pub mod signal_stream;
 pub mod signing;
 pub mod sizing;
+pub mod telemetry;
 pub mod types;
 
 pub use error::{Error, Result};
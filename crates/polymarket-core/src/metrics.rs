@@ -0,0 +1,476 @@
+//! Prometheus metrics.
+//!
+//! Every service records into one process-wide registry ([`global`]) and
+//! exposes it in the text format: api-server on its `/metrics` route, the
+//! worker binaries through [`spawn_metrics_server`]. WebSocket counters are
+//! [`MirroredCounterVec`]s set from the CLOB client's runtime stats on each
+//! scrape, so the hot path only ever updates that snapshot.
+
+use crate::api::clob::websocket_runtime_stats_snapshot;
+use prometheus::core::{Collector, Desc};
+use prometheus::proto::MetricFamily;
+use prometheus::{
+    Encoder, Gauge, Histogram, HistogramOpts, HistogramVec, IntCounterVec, IntGauge, IntGaugeVec,
+    Opts, Registry, TextEncoder,
+};
+use sqlx::PgPool;
+use std::collections::BTreeMap;
+use std::net::SocketAddr;
+use std::sync::{Arc, LazyLock, Mutex};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tracing::{info, warn};
+
+const NAMESPACE: &str = "abbot";
+
+/// Order round-trip buckets, from a fast paper fill to a slow live retry.
+const ORDER_LATENCY_BUCKETS: &[f64] = &[
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0,
+];
+
+/// All metrics shared across services.
+pub struct Metrics {
+    pub registry: Registry,
+    /// Order execution time, labelled by `order_type` and `mode` (live/paper).
+    pub order_latency_seconds: HistogramVec,
+    /// Orders by `order_type`, `mode` and `outcome` (filled/rejected).
+    pub orders_total: IntCounterVec,
+    /// Signals by `strategy` and `signal` (entry, exit, classification, ...).
+    pub signals_total: IntCounterVec,
+    /// 1 while the circuit breaker halts trading.
+    pub circuit_breaker_tripped: IntGauge,
+    /// Capital committed to non-closed positions, in USD.
+    pub open_exposure_usd: Gauge,
+    /// Database pool connections by `state` (idle, in_use, max).
+    pub db_pool_connections: IntGaugeVec,
+    /// Market-channel messages by `kind`.
+    pub ws_messages_total: MirroredCounterVec,
+    pub ws_orderbook_updates_total: MirroredCounterVec,
+    pub ws_book_integrity_failures_total: MirroredCounterVec,
+    /// REST resyncs of quarantined books by `outcome` (ok/failed).
+    pub ws_book_resyncs_total: MirroredCounterVec,
+    pub ws_active_sockets: IntGauge,
+    pub ws_subscribed_assets: IntGauge,
+    pub ws_quarantined_assets: IntGauge,
+    /// Signal stream entries by `outcome` (received, acked, reclaimed,
+    /// dead_lettered, left_pending).
+    pub signal_stream_entries_total: IntCounterVec,
+    /// Publish-to-consume latency of signal stream entries.
+    pub signal_stream_latency_seconds: Histogram,
+    /// Signal stream entries delivered but not yet acknowledged.
+    pub signal_stream_pending: IntGauge,
+    /// Signal stream entries not yet delivered to the consumer group.
+    pub signal_stream_lag: IntGauge,
+}
+
+impl Metrics {
+    fn new() -> prometheus::Result<Self> {
+        let registry = Registry::new();
+        let opts = |name: &str, help: &str| Opts::new(name, help).namespace(NAMESPACE);
+
+        let metrics = Self {
+            order_latency_seconds: HistogramVec::new(
+                HistogramOpts::new("order_latency_seconds", "Order execution latency")
+                    .namespace(NAMESPACE)
+                    .buckets(ORDER_LATENCY_BUCKETS.to_vec()),
+                &["order_type", "mode"],
+            )?,
+            orders_total: IntCounterVec::new(
+                opts("orders_total", "Orders executed by outcome"),
+                &["order_type", "mode", "outcome"],
+            )?,
+            signals_total: IntCounterVec::new(
+                opts("signals_total", "Signals produced or received per strategy"),
+                &["strategy", "signal"],
+            )?,
+            circuit_breaker_tripped: IntGauge::with_opts(opts(
+                "circuit_breaker_tripped",
+                "Whether the circuit breaker is halting trading",
+            ))?,
+            open_exposure_usd: Gauge::with_opts(opts(
+                "open_exposure_usd",
+                "Capital committed to open positions",
+            ))?,
+            db_pool_connections: IntGaugeVec::new(
+                opts("db_pool_connections", "Database pool connections"),
+                &["state"],
+            )?,
+            ws_messages_total: MirroredCounterVec::new(
+                opts("ws_messages_total", "Market-channel WebSocket messages"),
+                &["kind"],
+            )?,
+            ws_orderbook_updates_total: MirroredCounterVec::new(
+                opts(
+                    "ws_orderbook_updates_total",
+                    "Order book updates emitted from the market channel",
+                ),
+                &[],
+            )?,
+            ws_book_integrity_failures_total: MirroredCounterVec::new(
+                opts(
+                    "ws_book_integrity_failures_total",
+                    "Order book integrity checks that failed",
+                ),
+                &[],
+            )?,
+            ws_book_resyncs_total: MirroredCounterVec::new(
+                opts("ws_book_resyncs_total", "REST resyncs of quarantined books"),
+                &["outcome"],
+            )?,
+            ws_active_sockets: IntGauge::with_opts(opts(
+                "ws_active_sockets",
+                "Open market-channel sockets",
+            ))?,
+            ws_subscribed_assets: IntGauge::with_opts(opts(
+                "ws_subscribed_assets",
+                "Assets subscribed on the market channel",
+            ))?,
+            ws_quarantined_assets: IntGauge::with_opts(opts(
+                "ws_quarantined_assets",
+                "Assets quarantined pending a REST resync",
+            ))?,
+            signal_stream_entries_total: IntCounterVec::new(
+                opts(
+                    "signal_stream_entries_total",
+                    "Signal stream entries by outcome",
+                ),
+                &["outcome"],
+            )?,
+            signal_stream_latency_seconds: Histogram::with_opts(
+                HistogramOpts::new(
+                    "signal_stream_latency_seconds",
+                    "Signal stream publish-to-consume latency",
+                )
+                .namespace(NAMESPACE)
+                .buckets(ORDER_LATENCY_BUCKETS.to_vec()),
+            )?,
+            signal_stream_pending: IntGauge::with_opts(opts(
+                "signal_stream_pending",
+                "Signal stream entries delivered but not acknowledged",
+            ))?,
+            signal_stream_lag: IntGauge::with_opts(opts(
+                "signal_stream_lag",
+                "Signal stream entries not yet delivered to the consumer group",
+            ))?,
+            registry,
+        };
+
+        let collectors: Vec<Box<dyn Collector>> = vec![
+            Box::new(metrics.order_latency_seconds.clone()),
+            Box::new(metrics.orders_total.clone()),
+            Box::new(metrics.signals_total.clone()),
+            Box::new(metrics.circuit_breaker_tripped.clone()),
+            Box::new(metrics.open_exposure_usd.clone()),
+            Box::new(metrics.db_pool_connections.clone()),
+            Box::new(metrics.ws_messages_total.clone()),
+            Box::new(metrics.ws_orderbook_updates_total.clone()),
+            Box::new(metrics.ws_book_integrity_failures_total.clone()),
+            Box::new(metrics.ws_book_resyncs_total.clone()),
+            Box::new(metrics.ws_active_sockets.clone()),
+            Box::new(metrics.ws_subscribed_assets.clone()),
+            Box::new(metrics.ws_quarantined_assets.clone()),
+            Box::new(metrics.signal_stream_entries_total.clone()),
+            Box::new(metrics.signal_stream_latency_seconds.clone()),
+            Box::new(metrics.signal_stream_pending.clone()),
+            Box::new(metrics.signal_stream_lag.clone()),
+        ];
+        for collector in collectors {
+            metrics.registry.register(collector)?;
+        }
+        Ok(metrics)
+    }
+}
+
+static METRICS: LazyLock<Metrics> =
+    LazyLock::new(|| Metrics::new().expect("metric definitions are valid"));
+
+/// The process-wide metrics.
+pub fn global() -> &'static Metrics {
+    &METRICS
+}
+
+/// Record one executed order.
+pub fn observe_order(order_type: &str, live: bool, filled: bool, elapsed: Duration) {
+    let mode = if live { "live" } else { "paper" };
+    let outcome = if filled { "filled" } else { "rejected" };
+    METRICS
+        .order_latency_seconds
+        .with_label_values(&[order_type, mode])
+        .observe(elapsed.as_secs_f64());
+    METRICS
+        .orders_total
+        .with_label_values(&[order_type, mode, outcome])
+        .inc();
+}
+
+/// Count a signal for `strategy`.
+pub fn record_signal(strategy: &str, signal: &str) {
+    METRICS
+        .signals_total
+        .with_label_values(&[strategy, signal])
+        .inc();
+}
+
+/// Snapshot pool usage into `db_pool_connections`.
+pub fn observe_db_pool(pool: &PgPool) {
+    let size = i64::from(pool.size());
+    let idle = pool.num_idle() as i64;
+    let gauge = &METRICS.db_pool_connections;
+    gauge.with_label_values(&["idle"]).set(idle);
+    gauge
+        .with_label_values(&["in_use"])
+        .set((size - idle).max(0));
+    gauge
+        .with_label_values(&["max"])
+        .set(i64::from(pool.options().get_max_connections()));
+}
+
+/// Counter family whose monotonic totals are kept elsewhere, e.g. in atomics
+/// on a hot path. Scrapes report the last totals [`set`](Self::set) as they
+/// are, so concurrent scrapes cannot double count the way copying the
+/// difference into an ordinary counter can.
+#[derive(Clone)]
+pub struct MirroredCounterVec {
+    /// Never incremented; holds the descriptor and builds each scrape's family.
+    template: IntCounterVec,
+    opts: Opts,
+    labels: Vec<String>,
+    totals: Arc<Mutex<BTreeMap<Vec<String>, u64>>>,
+}
+
+impl MirroredCounterVec {
+    pub fn new(opts: Opts, labels: &[&str]) -> prometheus::Result<Self> {
+        Ok(Self {
+            template: IntCounterVec::new(opts.clone(), labels)?,
+            opts,
+            labels: labels.iter().map(|l| l.to_string()).collect(),
+            totals: Arc::default(),
+        })
+    }
+
+    /// Set the current total for one label set.
+    pub fn set(&self, label_values: &[&str], total: u64) {
+        let key = label_values.iter().map(|v| v.to_string()).collect();
+        self.totals
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(key, total);
+    }
+}
+
+impl Collector for MirroredCounterVec {
+    fn desc(&self) -> Vec<&Desc> {
+        self.template.desc()
+    }
+
+    fn collect(&self) -> Vec<MetricFamily> {
+        let labels: Vec<&str> = self.labels.iter().map(String::as_str).collect();
+        let Ok(family) = IntCounterVec::new(self.opts.clone(), &labels) else {
+            return Vec::new();
+        };
+        let totals = self.totals.lock().unwrap_or_else(|e| e.into_inner());
+        for (values, total) in totals.iter() {
+            let values: Vec<&str> = values.iter().map(String::as_str).collect();
+            family.with_label_values(&values).inc_by(*total);
+        }
+        family.collect()
+    }
+}
+
+fn sync_ws_metrics(metrics: &Metrics) {
+    let stats = websocket_runtime_stats_snapshot();
+    for (kind, total) in [
+        ("text", stats.text_messages_received_total),
+        ("snapshot", stats.snapshot_messages_total),
+        ("price_change", stats.price_change_messages_total),
+        ("last_trade_price", stats.last_trade_price_messages_total),
+        ("tick_size_change", stats.tick_size_change_messages_total),
+        ("invalid_operation", stats.invalid_operation_messages_total),
+        ("parse_miss", stats.parse_misses_total),
+        ("ping", stats.ping_messages_received_total),
+        ("pong", stats.pong_messages_received_total),
+    ] {
+        metrics.ws_messages_total.set(&[kind], total);
+    }
+    metrics
+        .ws_orderbook_updates_total
+        .set(&[], stats.orderbook_updates_emitted_total);
+    metrics
+        .ws_book_integrity_failures_total
+        .set(&[], stats.book_integrity_failures_total);
+    metrics
+        .ws_book_resyncs_total
+        .set(&["ok"], stats.book_resyncs_total);
+    metrics
+        .ws_book_resyncs_total
+        .set(&["failed"], stats.book_resync_failures_total);
+    metrics.ws_active_sockets.set(stats.active_sockets as i64);
+    metrics
+        .ws_subscribed_assets
+        .set(stats.subscribed_assets as i64);
+    metrics
+        .ws_quarantined_assets
+        .set(stats.quarantined_assets as i64);
+}
+
+/// Content type of [`render`]'s output.
+pub fn content_type() -> &'static str {
+    prometheus::TEXT_FORMAT
+}
+
+/// Encode every metric in the Prometheus text format.
+pub fn render() -> String {
+    sync_ws_metrics(&METRICS);
+    let mut buffer = Vec::new();
+    if let Err(e) = TextEncoder::new().encode(&METRICS.registry.gather(), &mut buffer) {
+        warn!(error = %e, "Failed to encode metrics");
+    }
+    String::from_utf8(buffer).unwrap_or_default()
+}
+
+/// Standalone metrics listener configuration for the worker binaries.
+#[derive(Debug, Clone)]
+pub struct MetricsServerConfig {
+    pub enabled: bool,
+    pub addr: SocketAddr,
+}
+
+impl MetricsServerConfig {
+    /// Create config from environment variables; `default_port` differs per
+    /// binary so they can share a host.
+    pub fn from_env(default_port: u16) -> Self {
+        let port = crate::settings::get_opt("METRICS_PORT").unwrap_or(default_port);
+        Self {
+            enabled: crate::settings::get("METRICS_ENABLED"),
+            addr: SocketAddr::from(([0, 0, 0, 0], port)),
+        }
+    }
+}
+
+/// Serve `GET /metrics` on `config.addr`. `refresh` runs before each scrape
+/// to update gauges that are sampled rather than recorded (e.g. pool usage).
+pub fn spawn_metrics_server<F>(config: MetricsServerConfig, refresh: F)
+where
+    F: Fn() + Send + Sync + 'static,
+{
+    if !config.enabled {
+        info!("Metrics listener disabled (METRICS_ENABLED=false)");
+        return;
+    }
+    tokio::spawn(async move {
+        match TcpListener::bind(config.addr).await {
+            Ok(listener) => {
+                info!(addr = %config.addr, "Serving Prometheus metrics");
+                serve(listener, refresh).await;
+            }
+            Err(e) => warn!(addr = %config.addr, error = %e, "Failed to bind metrics listener"),
+        }
+    });
+}
+
+async fn serve<F>(listener: TcpListener, refresh: F)
+where
+    F: Fn() + Send + Sync + 'static,
+{
+    let refresh = std::sync::Arc::new(refresh);
+    loop {
+        let Ok((mut stream, _)) = listener.accept().await else {
+            continue;
+        };
+        let refresh = refresh.clone();
+        tokio::spawn(async move {
+            let mut head = [0u8; 1024];
+            let Ok(Ok(n)) =
+                tokio::time::timeout(Duration::from_secs(5), stream.read(&mut head)).await
+            else {
+                return;
+            };
+            let request_line = String::from_utf8_lossy(&head[..n]);
+            let mut parts = request_line.split_whitespace();
+            let response = match (parts.next(), parts.next()) {
+                (Some("GET"), Some(path)) if path.split('?').next() == Some("/metrics") => {
+                    refresh();
+                    let body = render();
+                    format!(
+                        "HTTP/1.1 200 OK\r\ncontent-type: {}\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                        content_type(),
+                        body.len(),
+                        body
+                    )
+                }
+                _ => "HTTP/1.1 404 Not Found\r\ncontent-length: 0\r\nconnection: close\r\n\r\n"
+                    .to_string(),
+            };
+            let _ = stream.write_all(response.as_bytes()).await;
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::net::TcpStream;
+
+    #[test]
+    fn test_mirrored_counter_reports_stored_totals() {
+        let counter =
+            MirroredCounterVec::new(Opts::new("mirror_test_total", "test"), &["kind"]).unwrap();
+        counter.set(&["a"], 5);
+        counter.set(&["b"], 2);
+        counter.set(&["a"], 8);
+
+        // Repeated scrapes report the totals, never a running sum of them.
+        for _ in 0..2 {
+            let families = counter.collect();
+            let mut values: Vec<f64> = families[0]
+                .get_metric()
+                .iter()
+                .map(|m| m.get_counter().get_value())
+                .collect();
+            values.sort_by(f64::total_cmp);
+            assert_eq!(values, vec![2.0, 8.0]);
+        }
+
+        let plain = MirroredCounterVec::new(Opts::new("mirror_plain_total", "test"), &[]).unwrap();
+        plain.set(&[], 3);
+        assert_eq!(
+            plain.collect()[0].get_metric()[0].get_counter().get_value(),
+            3.0
+        );
+    }
+
+    #[tokio::test]
+    async fn test_listener_serves_text_format() {
+        observe_order("market", false, true, Duration::from_millis(20));
+        record_signal("arb", "entry");
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(serve(listener, || global().circuit_breaker_tripped.set(1)));
+
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(b"GET /metrics HTTP/1.1\r\nhost: test\r\n\r\n")
+            .await
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.contains("abbot_circuit_breaker_tripped 1"));
+        assert!(response.contains(r#"abbot_signals_total{signal="entry",strategy="arb"}"#));
+        assert!(response
+            .contains(r#"abbot_order_latency_seconds_bucket{mode="paper",order_type="market""#));
+        assert!(response.contains(r#"abbot_ws_messages_total{kind="text"}"#));
+
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        stream
+            .write_all(b"GET /other HTTP/1.1\r\n\r\n")
+            .await
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        assert!(response.starts_with("HTTP/1.1 404"));
+    }
+}
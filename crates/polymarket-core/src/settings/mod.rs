@@ -0,0 +1,605 @@
+//! Layered service configuration.
+//!
+//! Every setting is named by the environment variable the services have
+//! always read. Values are resolved in order of precedence from the process
+//! environment (including `.env`), then the TOML file passed with `--config`
+//! or named by `ABBOT_CONFIG`, then the default in the [`schema`]. Code reads
+//! typed values through [`get`] and [`get_opt`]; [`var`] is the raw string
+//! lookup for names outside the schema.
+//!
+//! Binaries call [`startup`] first thing so that a bad value stops the process
+//! with every problem listed, instead of silently falling back to a default
+//! inside whichever background task happens to parse it.
+
+pub mod schema;
+
+use crate::{Error, Result};
+use config::{FileFormat, ValueKind};
+use std::collections::BTreeMap;
+use std::env::VarError;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+pub use schema::{KeySpec, Kind, Min, SCHEMA};
+
+/// Environment variable naming the config file when `--config` is not given.
+pub const CONFIG_PATH_VAR: &str = "ABBOT_CONFIG";
+
+static SETTINGS: OnceLock<Settings> = OnceLock::new();
+
+/// Where a resolved value came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Source {
+    Env,
+    File,
+    /// The schema default.
+    Default,
+}
+
+impl Source {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Env => "env",
+            Self::File => "file",
+            Self::Default => "default",
+        }
+    }
+}
+
+/// A single problem found while validating settings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationIssue {
+    pub key: String,
+    pub message: String,
+}
+
+impl ValidationIssue {
+    pub fn new(key: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.key, self.message)
+    }
+}
+
+/// The file layer, flattened to environment-variable names.
+#[derive(Debug, Clone, Default)]
+pub struct Settings {
+    path: Option<PathBuf>,
+    file: BTreeMap<String, String>,
+    /// Keys the file sets more than once (e.g. both nested and top level).
+    duplicates: Vec<String>,
+}
+
+impl Settings {
+    /// Load a TOML config file.
+    #[allow(clippy::result_large_err)]
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let mut settings = Self::from_source(config::File::from(path).format(FileFormat::Toml))?;
+        settings.path = Some(path.to_path_buf());
+        Ok(settings)
+    }
+
+    /// Parse TOML config text.
+    #[allow(clippy::result_large_err)]
+    pub fn from_toml(toml: &str) -> Result<Self> {
+        Self::from_source(config::File::from_str(toml, FileFormat::Toml))
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn from_source<S>(source: S) -> Result<Self>
+    where
+        S: config::Source + Send + Sync + 'static,
+    {
+        let table = config::Config::builder()
+            .add_source(source)
+            .build()?
+            .try_deserialize::<config::Map<String, config::Value>>()?;
+        let mut settings = Self::default();
+        for (key, value) in table {
+            settings.flatten(&key, value);
+        }
+        Ok(settings)
+    }
+
+    fn flatten(&mut self, name: &str, value: config::Value) {
+        let name = name.to_ascii_uppercase();
+        let rendered = match value.kind {
+            ValueKind::Table(table) => {
+                for (key, value) in table {
+                    self.flatten(&format!("{name}_{key}"), value);
+                }
+                return;
+            }
+            ValueKind::Array(items) => items
+                .into_iter()
+                .map(|item| item.to_string())
+                .collect::<Vec<_>>()
+                .join(","),
+            ValueKind::Nil => return,
+            _ => value.to_string(),
+        };
+        if self.file.insert(name.clone(), rendered).is_some() {
+            self.duplicates.push(name);
+        }
+    }
+
+    /// Path of the loaded config file, if any.
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    /// Resolve `name`, environment first.
+    pub fn get(&self, name: &str) -> Option<(String, Source)> {
+        match std::env::var(name) {
+            Ok(value) => Some((value, Source::Env)),
+            Err(_) => self
+                .file
+                .get(name)
+                .map(|value| (value.clone(), Source::File)),
+        }
+    }
+
+    /// Resolve `name` to the value services run with: the environment or
+    /// file value if it is non-blank and passes the key's schema check, else
+    /// the schema default. Names outside the schema resolve to their raw value.
+    pub fn resolve(&self, name: &str) -> Option<(String, Source)> {
+        let Some(spec) = schema::lookup(name) else {
+            return self.get(name);
+        };
+        self.get(name)
+            .filter(|(value, _)| !value.trim().is_empty() && spec.check(value).is_ok())
+            .or_else(|| {
+                spec.default
+                    .map(|value| (value.to_string(), Source::Default))
+            })
+    }
+
+    /// Check every set key against the schema, plus cross-field rules.
+    pub fn validate(&self) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+        for name in &self.duplicates {
+            issues.push(ValidationIssue::new(name, "set more than once in the file"));
+        }
+        for name in self.file.keys() {
+            match schema::lookup(name) {
+                None => issues.push(ValidationIssue::new(name, "unknown key in config file")),
+                Some(spec) if spec.env_only => issues.push(ValidationIssue::new(
+                    name,
+                    "must be set in the environment; it is read before the config file is loaded",
+                )),
+                Some(_) => {}
+            }
+        }
+        for spec in SCHEMA.iter().flat_map(|(_, keys)| keys.iter()) {
+            if let Some((value, source)) = self.get(spec.name) {
+                if let Err(message) = spec.check(&value) {
+                    issues.push(ValidationIssue::new(
+                        spec.name,
+                        format!("{message} (from {})", source.as_str()),
+                    ));
+                }
+            }
+        }
+        issues.extend(schema::cross_field_issues(|name| {
+            let (value, _) = self.get(name)?;
+            let spec = schema::lookup(name)?;
+            spec.check(&value).is_ok().then_some(value)
+        }));
+        issues
+    }
+
+    /// Effective configuration as TOML, secrets redacted and each value
+    /// annotated with its source. Unset keys show their schema default;
+    /// keys with no fixed default are listed commented out.
+    pub fn render(&self) -> String {
+        let mut out = String::from("# Effective configuration");
+        match &self.path {
+            Some(path) => out.push_str(&format!(" (file: {})\n", path.display())),
+            None => out.push_str(" (no config file)\n"),
+        }
+        out.push_str("# Environment values override the file, which overrides the defaults.\n");
+        for (group, keys) in SCHEMA {
+            out.push_str(&format!("\n# {group}\n"));
+            for spec in keys.iter() {
+                match self.get(spec.name).or_else(|| self.resolve(spec.name)) {
+                    Some((value, source)) => out.push_str(&format!(
+                        "{} = {}  # {}\n",
+                        spec.name,
+                        render_value(spec, &value),
+                        source.as_str()
+                    )),
+                    None => out.push_str(&format!("# {} =\n", spec.name)),
+                }
+            }
+        }
+        out
+    }
+}
+
+fn render_value(spec: &KeySpec, value: &str) -> String {
+    if spec.secret {
+        return "\"<redacted>\"".to_string();
+    }
+    let bare = matches!(
+        spec.kind,
+        Kind::Bool | Kind::Int | Kind::UInt | Kind::Number | Kind::Fraction
+    );
+    if bare && spec.check(value).is_ok() {
+        return value.trim().to_ascii_lowercase();
+    }
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+/// Load the process-wide settings from `path`, or from `ABBOT_CONFIG` when
+/// no path is given. Fails if settings were already loaded.
+#[allow(clippy::result_large_err)]
+pub fn init(path: Option<&Path>) -> Result<&'static Settings> {
+    let path = path
+        .map(Path::to_path_buf)
+        .or_else(|| std::env::var_os(CONFIG_PATH_VAR).map(PathBuf::from));
+    let settings = match path {
+        Some(path) => Settings::from_file(&path)?,
+        None => Settings::default(),
+    };
+    SETTINGS.set(settings).map_err(|_| Error::Config {
+        message: "settings were already loaded".to_string(),
+    })?;
+    Ok(global())
+}
+
+/// The process-wide settings. Loaded from `ABBOT_CONFIG` on first use when
+/// [`init`] was not called (tools, examples, tests).
+pub fn global() -> &'static Settings {
+    SETTINGS.get_or_init(|| {
+        let Some(path) = std::env::var_os(CONFIG_PATH_VAR) else {
+            return Settings::default();
+        };
+        Settings::from_file(Path::new(&path)).unwrap_or_else(|e| {
+            eprintln!("Ignoring config file {}: {e}", Path::new(&path).display());
+            Settings::default()
+        })
+    })
+}
+
+/// Read a setting: the environment variable if set, else the config file.
+pub fn var(name: &str) -> std::result::Result<String, VarError> {
+    match std::env::var(name) {
+        Err(VarError::NotPresent) => global().file.get(name).cloned().ok_or(VarError::NotPresent),
+        other => other,
+    }
+}
+
+/// Parse a boolean setting: `true` / `false` or `1` / `0`, in any case.
+/// The same values [`Kind::Bool`] validation accepts.
+pub fn parse_bool(value: &str) -> Option<bool> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "true" | "1" => Some(true),
+        "false" | "0" => Some(false),
+        _ => None,
+    }
+}
+
+/// A type a setting can be read as.
+pub trait FromSetting: Sized {
+    fn from_setting(value: &str) -> Option<Self>;
+}
+
+impl FromSetting for bool {
+    fn from_setting(value: &str) -> Option<Self> {
+        parse_bool(value)
+    }
+}
+
+impl FromSetting for String {
+    fn from_setting(value: &str) -> Option<Self> {
+        Some(value.to_string())
+    }
+}
+
+/// Comma-separated, trimmed, empty items dropped.
+impl FromSetting for Vec<String> {
+    fn from_setting(value: &str) -> Option<Self> {
+        Some(
+            value
+                .split(',')
+                .map(str::trim)
+                .filter(|item| !item.is_empty())
+                .map(String::from)
+                .collect(),
+        )
+    }
+}
+
+macro_rules! from_setting_via_parse {
+    ($($ty:ty),*) => {
+        $(impl FromSetting for $ty {
+            fn from_setting(value: &str) -> Option<Self> {
+                value.trim().parse().ok()
+            }
+        })*
+    };
+}
+
+from_setting_via_parse!(u16, u32, u64, usize, i32, i64, f64, rust_decimal::Decimal);
+
+/// Read a typed setting that may have no value: the environment, then the
+/// config file, then the schema default. A value that fails its schema check
+/// or does not parse as `T` falls back to the default.
+pub fn get_opt<T: FromSetting>(name: &str) -> Option<T> {
+    global()
+        .resolve(name)
+        .and_then(|(value, _)| T::from_setting(&value))
+        .or_else(|| schema::lookup(name)?.default.and_then(T::from_setting))
+}
+
+/// Read a typed setting only when it is set in the environment or the file,
+/// ignoring the schema default; for keys that fall back to another key.
+pub fn explicit<T: FromSetting>(name: &str) -> Option<T> {
+    match global().resolve(name)? {
+        (_, Source::Default) => None,
+        (value, _) => T::from_setting(&value),
+    }
+}
+
+/// Read a typed setting with a schema default.
+///
+/// # Panics
+///
+/// If `name` has no default in the schema that parses as `T`; that is a
+/// programming error, not a configuration one.
+pub fn get<T: FromSetting>(name: &str) -> T {
+    get_opt(name)
+        .unwrap_or_else(|| panic!("setting {name} has no default of this type in the schema"))
+}
+
+/// Configuration flags every service accepts, split from its own arguments.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ServiceArgs {
+    /// `--config <path>`
+    pub config: Option<PathBuf>,
+    /// `--print-config`
+    pub print_config: bool,
+    /// Remaining arguments, in order.
+    pub rest: Vec<String>,
+}
+
+impl ServiceArgs {
+    /// Parse arguments, excluding the program name.
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Self {
+        let mut parsed = Self::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            if arg == "--print-config" {
+                parsed.print_config = true;
+            } else if arg == "--config" {
+                parsed.config = args.next().map(PathBuf::from);
+            } else if let Some(path) = arg.strip_prefix("--config=") {
+                parsed.config = Some(PathBuf::from(path));
+            } else {
+                parsed.rest.push(arg);
+            }
+        }
+        parsed
+    }
+
+    /// Parse the current process arguments.
+    pub fn from_env() -> Self {
+        Self::parse(std::env::args().skip(1))
+    }
+}
+
+/// Load `.env` and the config file, then validate. With `print_config` the
+/// effective configuration is written to stdout and the process exits.
+pub fn startup(config: Option<&Path>, print_config: bool) -> anyhow::Result<&'static Settings> {
+    dotenvy::dotenv().ok();
+    let settings = init(config)?;
+    if print_config {
+        print!("{}", settings.render());
+    }
+    let issues = settings.validate();
+    if !issues.is_empty() {
+        let list = issues
+            .iter()
+            .map(|issue| format!("  - {issue}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        anyhow::bail!("invalid configuration ({} problems):\n{list}", issues.len());
+    }
+    if print_config {
+        std::process::exit(0);
+    }
+    Ok(settings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_nested_tables_flatten_to_env_names() {
+        let settings = Settings::from_toml(
+            r#"
+            LIVE_TRADING = false
+            alert_routes = ["telegram:critical", "slack"]
+
+            [arb]
+            min_net_profit = 0.01
+            signal_transport = "stream"
+
+            [arb.monitor]
+            max_markets = 150
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(settings.file["LIVE_TRADING"], "false");
+        assert_eq!(settings.file["ALERT_ROUTES"], "telegram:critical,slack");
+        assert_eq!(settings.file["ARB_MIN_NET_PROFIT"], "0.01");
+        assert_eq!(settings.file["ARB_SIGNAL_TRANSPORT"], "stream");
+        assert_eq!(settings.file["ARB_MONITOR_MAX_MARKETS"], "150");
+        assert!(settings.validate().is_empty());
+    }
+
+    #[test]
+    fn test_environment_overrides_file() {
+        let settings = Settings::from_toml("[settings_test]\nprecedence = \"file\"").unwrap();
+        assert_eq!(
+            settings.get("SETTINGS_TEST_PRECEDENCE"),
+            Some(("file".to_string(), Source::File))
+        );
+
+        std::env::set_var("SETTINGS_TEST_PRECEDENCE", "env");
+        assert_eq!(
+            settings.get("SETTINGS_TEST_PRECEDENCE"),
+            Some(("env".to_string(), Source::Env))
+        );
+        std::env::remove_var("SETTINGS_TEST_PRECEDENCE");
+    }
+
+    #[test]
+    fn test_validation_reports_every_problem() {
+        let settings = Settings::from_toml(
+            r#"
+            ARB_MIN_NET_PROFT = 0.01
+            VAULT_MASTER_KEY = "in-a-file"
+            CB_MAX_DAILY_LOSS = "lots"
+            OTEL_TRACES_SAMPLER_ARG = 1.5
+            ARB_MONITOR_AGGRESSIVENESS = "yolo"
+
+            [latency_arb]
+            min_yes_price = 0.9
+            max_yes_price = 0.1
+
+            [quant]
+            flow_allocation_pct = 0.6
+            cross_market_allocation_pct = 0.6
+            "#,
+        )
+        .unwrap();
+
+        let keys: HashSet<String> = settings
+            .validate()
+            .into_iter()
+            .map(|issue| issue.key)
+            .collect();
+        let expected: HashSet<String> = [
+            "ARB_MIN_NET_PROFT",
+            "VAULT_MASTER_KEY",
+            "CB_MAX_DAILY_LOSS",
+            "OTEL_TRACES_SAMPLER_ARG",
+            "ARB_MONITOR_AGGRESSIVENESS",
+            "LATENCY_ARB_MIN_YES_PRICE",
+            "QUANT_*_ALLOCATION_PCT",
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect();
+        assert_eq!(keys, expected);
+    }
+
+    #[test]
+    fn test_parse_bool_matches_validation() {
+        let spec = SCHEMA
+            .iter()
+            .flat_map(|(_, keys)| keys.iter())
+            .find(|spec| spec.kind == Kind::Bool)
+            .unwrap();
+        for value in ["true", "FALSE", " 1 ", "0", "True", "yes", "", "off"] {
+            assert_eq!(
+                parse_bool(value).is_some(),
+                spec.check(value).is_ok(),
+                "{value:?}"
+            );
+        }
+        assert_eq!(parse_bool("TRUE"), Some(true));
+        assert_eq!(parse_bool("1"), Some(true));
+        assert_eq!(parse_bool("0"), Some(false));
+    }
+
+    #[test]
+    fn test_render_redacts_secrets() {
+        let settings = Settings::from_toml(
+            r#"
+            JWT_SECRET = "hunter2"
+            DATABASE_URL = "postgres://abbot:hunter2@db/abbot"
+            ARB_POSITION_SIZE = 50
+            ARB_SIGNAL_STREAM_GROUP = "arb-executors"
+            "#,
+        )
+        .unwrap();
+
+        let rendered = settings.render();
+        assert!(!rendered.contains("hunter2"));
+        assert!(rendered.contains("JWT_SECRET = \"<redacted>\"  # file"));
+        assert!(rendered.contains("ARB_POSITION_SIZE = 50  # file"));
+        assert!(rendered.contains("ARB_SIGNAL_STREAM_GROUP = \"arb-executors\"  # file"));
+        assert!(rendered.contains("# TELEGRAM_BOT_TOKEN =\n"));
+        assert!(Settings::from_toml(&rendered).is_ok());
+    }
+
+    #[test]
+    fn test_render_prints_schema_defaults() {
+        let rendered = Settings::from_toml("ALERT_DEDUP_WINDOW_SECS = 60")
+            .unwrap()
+            .render();
+        assert!(rendered.contains("ALERT_DEDUP_WINDOW_SECS = 60  # file"));
+        assert!(rendered.contains("ALERT_ESCALATION_AFTER_SECS = 900  # default"));
+        assert!(rendered.contains("ORDER_ROUTER_ALGO = \"market\"  # default"));
+    }
+
+    #[test]
+    fn test_schema_defaults_pass_their_checks() {
+        for spec in SCHEMA.iter().flat_map(|(_, keys)| keys.iter()) {
+            if let Some(default) = spec.default {
+                assert_eq!(spec.check(default), Ok(()), "{}", spec.name);
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_value_resolves_to_default() {
+        let settings = Settings::from_toml(
+            r#"
+            GAMMA_MAX_PAGE_SIZE = 0
+            ALERT_ESCALATION_MAX = "often"
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            settings.resolve("GAMMA_MAX_PAGE_SIZE"),
+            Some(("100".to_string(), Source::Default))
+        );
+        assert_eq!(
+            settings.resolve("ALERT_ESCALATION_MAX"),
+            Some(("2".to_string(), Source::Default))
+        );
+        assert_eq!(settings.resolve("TELEGRAM_BOT_TOKEN"), None);
+    }
+
+    #[test]
+    fn test_schema_names_are_unique() {
+        let mut seen = HashSet::new();
+        for spec in SCHEMA.iter().flat_map(|(_, keys)| keys.iter()) {
+            assert!(seen.insert(spec.name), "{} listed twice", spec.name);
+        }
+    }
+
+    #[test]
+    fn test_service_args_keep_positional_arguments() {
+        let args = ServiceArgs::parse(
+            ["--config", "abbot.toml", "0xabc", "--print-config"].map(String::from),
+        );
+        assert_eq!(args.config, Some(PathBuf::from("abbot.toml")));
+        assert!(args.print_config);
+        assert_eq!(args.rest, vec!["0xabc".to_string()]);
+    }
+}
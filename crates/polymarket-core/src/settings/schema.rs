@@ -0,0 +1,704 @@
+//! The typed schema every configuration key is checked against.
+//!
+//! Keys keep their environment-variable names; a TOML file may set them
+//! either at the top level or nested under tables (`[arb] min_net_profit`
+//! is `ARB_MIN_NET_PROFIT`). A key's built-in default lives here too, so
+//! services read it through [`super::get`] rather than repeating it.
+
+use super::ValidationIssue;
+use Kind::{Bool, Fraction, Int, List, Number, OneOf, Text, UInt, Url};
+
+pub(crate) const SEVERITIES: &[&str] = &["info", "warning", "warn", "critical", "crit"];
+const ORDER_ROUTER_ALGOS: &[&str] = &["market", "twap", "pov", "passive_aggressive"];
+pub(crate) const AGGRESSIVENESS: &[&str] = &[
+    "stable",
+    "conservative",
+    "balanced",
+    "discovery",
+    "aggressive",
+];
+pub(crate) const SIGNAL_TRANSPORTS: &[&str] = &["pubsub", "stream", "streams"];
+pub(crate) const VAULT_PROVIDERS: &[&str] = &["env", "environment", "aws", "file"];
+
+/// Value type of a configuration key.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Kind {
+    /// `true` / `false` (also `1` / `0`).
+    Bool,
+    Int,
+    /// Non-negative integer: counts, ports, durations.
+    UInt,
+    /// Finite decimal number.
+    Number,
+    /// Decimal number in `[0, 1]`.
+    Fraction,
+    /// Absolute URL with a scheme.
+    Url,
+    Text,
+    /// Comma-separated list.
+    List,
+    /// One of a fixed set of names, compared case-insensitively.
+    OneOf(&'static [&'static str]),
+}
+
+/// Lower bound on a numeric key.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Min {
+    /// `>= 0`
+    NonNegative,
+    /// `> 0`
+    Positive,
+}
+
+/// One configuration key.
+#[derive(Debug, Clone, Copy)]
+pub struct KeySpec {
+    pub name: &'static str,
+    pub kind: Kind,
+    /// Value used when the key is unset or invalid; `None` when there is no
+    /// fixed default (optional keys, and defaults derived from other keys).
+    pub default: Option<&'static str>,
+    pub min: Option<Min>,
+    /// Value is replaced with `<redacted>` in `--print-config`.
+    pub secret: bool,
+    /// Read before settings are loaded (by the auth crate's key vault), so a
+    /// file value would be silently ignored; must come from the environment.
+    pub env_only: bool,
+}
+
+const fn key(name: &'static str, kind: Kind) -> KeySpec {
+    KeySpec {
+        name,
+        kind,
+        default: None,
+        min: None,
+        secret: false,
+        env_only: false,
+    }
+}
+
+impl KeySpec {
+    const fn default(mut self, value: &'static str) -> Self {
+        self.default = Some(value);
+        self
+    }
+
+    const fn positive(mut self) -> Self {
+        self.min = Some(Min::Positive);
+        self
+    }
+
+    const fn non_negative(mut self) -> Self {
+        self.min = Some(Min::NonNegative);
+        self
+    }
+
+    const fn secret(mut self) -> Self {
+        self.secret = true;
+        self
+    }
+
+    const fn env_only(mut self) -> Self {
+        self.env_only = true;
+        self
+    }
+
+    /// Check a raw value against this key's type and bound.
+    pub fn check(&self, value: &str) -> Result<(), String> {
+        self.check_kind(value)?;
+        match self.min {
+            Some(Min::Positive) if parse_number(value.trim())? <= 0.0 => {
+                Err(format!("expected a value above 0, got {}", value.trim()))
+            }
+            Some(Min::NonNegative) if parse_number(value.trim())? < 0.0 => Err(format!(
+                "expected a value of at least 0, got {}",
+                value.trim()
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    fn check_kind(&self, value: &str) -> Result<(), String> {
+        let value = value.trim();
+        match self.kind {
+            Kind::Bool => super::parse_bool(value)
+                .map(|_| ())
+                .ok_or_else(|| format!("expected true or false, got {value:?}")),
+            Kind::Int => value
+                .parse::<i64>()
+                .map(|_| ())
+                .map_err(|_| format!("expected an integer, got {value:?}")),
+            Kind::UInt => value
+                .parse::<u64>()
+                .map(|_| ())
+                .map_err(|_| format!("expected a non-negative integer, got {value:?}")),
+            Kind::Number => parse_number(value).map(|_| ()),
+            Kind::Fraction => match parse_number(value)? {
+                n if (0.0..=1.0).contains(&n) => Ok(()),
+                n => Err(format!("expected a fraction between 0 and 1, got {n}")),
+            },
+            Kind::Url => match value.split_once("://") {
+                Some((scheme, rest)) if !scheme.is_empty() && !rest.is_empty() => Ok(()),
+                _ => Err(format!("expected an absolute URL, got {value:?}")),
+            },
+            Kind::Text | Kind::List => Ok(()),
+            Kind::OneOf(allowed) => {
+                if allowed.iter().any(|a| a.eq_ignore_ascii_case(value)) {
+                    Ok(())
+                } else {
+                    Err(format!(
+                        "expected one of {}, got {value:?}",
+                        allowed.join(", ")
+                    ))
+                }
+            }
+        }
+    }
+}
+
+fn parse_number(value: &str) -> Result<f64, String> {
+    value
+        .parse::<f64>()
+        .ok()
+        .filter(|n| n.is_finite())
+        .ok_or_else(|| format!("expected a number, got {value:?}"))
+}
+
+/// Every key the services read, grouped as printed by `--print-config`.
+pub const SCHEMA: &[(&str, &[KeySpec])] = &[
+    (
+        "Database and Redis",
+        &[
+            key("DATABASE_MAX_CONNECTIONS", UInt).default("5"),
+            key("DATABASE_URL", Url).secret(),
+            key("DB_ACQUIRE_TIMEOUT_SECS", UInt).default("5"),
+            key("DB_RETRY_BASE_DELAY_MS", UInt).default("1000"),
+            key("DB_RETRY_MAX_ATTEMPTS", UInt).default("5"),
+            key("DB_RETRY_MAX_DELAY_MS", UInt).default("30000"),
+            key("REDIS_RECONNECT_DELAY", UInt).default("5"),
+            key("REDIS_SUBSCRIBE_ARB", Bool).default("true"),
+            key("REDIS_SUBSCRIBE_ORDERBOOK", Bool).default("true"),
+            key("REDIS_URL", Url).default("redis://127.0.0.1:6379").secret(),
+            key("SKIP_MIGRATIONS", Bool).default("false"),
+        ],
+    ),
+    (
+        "API server",
+        &[
+            key("API_HOST", Text).default("0.0.0.0"),
+            key("API_PORT", UInt).default("3000"),
+            key("APP_DOMAIN", Text).default("localhost"),
+            key("APP_URI", Url).default("http://localhost:3002"),
+            key("APP_URL", Url).default("http://localhost:3002"),
+            key("CORS_PERMISSIVE", Bool).default("true"),
+            key("DASHBOARD_URL", Url).default("http://localhost:3002"),
+            key("ENCRYPTION_KEY", Text).secret(),
+            key("ENVIRONMENT", Text),
+            key("JWT_SECRET", Text).default("development-secret-change-in-production").secret(),
+            key("PORT", UInt),
+            key("SEED_ADMIN_EMAIL", Text),
+            key("SEED_ADMIN_PASSWORD", Text).secret(),
+            key("WS_CHANNEL_CAPACITY", UInt).default("1000"),
+        ],
+    ),
+    (
+        "Polymarket and chain access",
+        &[
+            key("ALCHEMY_API_KEY", Text).secret(),
+            key("BINANCE_WS_URL", Url).default("wss://stream.binance.com:9443"),
+            key("CLOB_BOOK_ENFORCE_HASH", Bool).default("false"),
+            key("CLOB_BOOK_INTEGRITY_ENABLED", Bool).default("true"),
+            key("CLOB_BOOK_LOCKED_IS_CORRUPT", Bool).default("true"),
+            key("CLOB_BOOK_RESYNC_RETRY_SECS", UInt).default("5"),
+            key("CLOB_MARKET_LIMIT", UInt).default("200000"),
+            key("CLOB_TICK_SIZE_CACHE_SECS", UInt).default("300"),
+            key("CLOB_WS_MAX_ASSETS_PER_SOCKET", UInt).default("400").positive(),
+            key("CLOB_WS_PING_INTERVAL_SECS", UInt).default("10"),
+            key("CLOB_WS_READ_TIMEOUT_SECS", UInt).default("120"),
+            key("COINBASE_WS_URL", Url).default("wss://ws-feed.exchange.coinbase.com"),
+            key("GAMMA_ARB_MARKET_PAGE_SIZE", UInt).default("200"),
+            key("GAMMA_MAX_PAGE_SIZE", UInt).default("100").positive(),
+            key("GAMMA_PAGE_DELAY_MS", UInt).default("125"),
+            key("GAMMA_SYNCER_ENABLED", Bool).default("true"),
+            key("GAMMA_SYNCER_INTERVAL_SECS", UInt).default("3600"),
+            key("GAMMA_SYNCER_PAGE_SIZE", UInt).default("100"),
+            key("KRAKEN_WS_URL", Url).default("wss://ws.kraken.com/v2"),
+            key("LIVE_TRADING", Bool).default("false"),
+            key("OKX_WS_URL", Url).default("wss://ws.okx.com:8443/ws/v5/public"),
+            key("POLYGON_RPC_URL", Url),
+            key("POLYMARKET_CLOB_URL", Url),
+            key("POLYMARKET_WS_URL", Url),
+            key("POLY_API_KEY", Text).secret(),
+            key("POLY_API_PASSPHRASE", Text).secret(),
+            key("POLY_API_SECRET", Text).secret(),
+            key("TRADING_WALLET_ADDRESS", Text),
+            key("WALLET_PRIVATE_KEY", Text).secret().env_only(),
+        ],
+    ),
+    (
+        "Email",
+        &[
+            key("ALERT_EMAIL_TO", List),
+            key("RESEND_API_KEY", Text).secret(),
+            key("RESEND_API_URL", Url).default("https://api.resend.com/emails"),
+            key("RESEND_FROM", Text),
+            key("RESEND_FROM_NAME", Text),
+            key("SMTP_FROM", Text),
+            key("SMTP_FROM_NAME", Text).default("Polymarket Scanner"),
+        ],
+    ),
+    (
+        "Alerting",
+        &[
+            key("ALERT_DEDUP_WINDOW_SECS", UInt).default("300"),
+            key("ALERT_ESCALATION_AFTER_SECS", UInt).default("900"),
+            key("ALERT_ESCALATION_MAX", UInt).default("2"),
+            key("ALERT_ESCALATION_MIN_SEVERITY", OneOf(SEVERITIES)).default("critical"),
+            key("ALERT_ESCALATION_SINKS", List),
+            key("ALERT_QUIET_HOURS", Text),
+            key("ALERT_QUIET_HOURS_MIN_SEVERITY", OneOf(SEVERITIES)).default("critical"),
+            key("ALERT_RATE_LIMIT_PER_MINUTE", UInt).default("20"),
+            key("ALERT_ROUTES", List),
+            key("ALERT_WEBHOOK_TOKEN", Text).secret(),
+            key("ALERT_WEBHOOK_URL", Url),
+            key("DISCORD_WEBHOOK_URL", Url).secret(),
+            key("SLACK_WEBHOOK_URL", Url).secret(),
+            key("TELEGRAM_BOT_TOKEN", Text).secret(),
+            key("TELEGRAM_CHAT_ID", Text).secret(),
+        ],
+    ),
+    (
+        "Arb monitor",
+        &[
+            key("ARB_EXIT_THRESHOLD", Number).default("0.005"),
+            key("ARB_EXPLORATION_HOLD_SECS", Int).default("600").positive(),
+            key("ARB_EXPLORATION_SWAP_MIN_SCORE_DELTA", Number).default("0.75").non_negative(),
+            key("ARB_MIN_PROFIT_THRESHOLD", Number).default("0.005"),
+            key("ARB_MONITOR_AGGRESSIVENESS", OneOf(AGGRESSIVENESS)).default("balanced"),
+            key("ARB_MONITOR_EXPLORATION_SLOTS", UInt),
+            key("ARB_MONITOR_INSTANCE_ID", Text),
+            key("ARB_MONITOR_MAX_MARKETS", UInt),
+            key("ARB_MONITOR_MIN_BOOK_DEPTH", Number).default("100"),
+            key("ARB_MONITOR_SHARDING_ENABLED", Bool).default("false"),
+            key("ARB_MONITOR_SHARD_HEARTBEAT_SECS", UInt).default("5").positive(),
+            key("ARB_MONITOR_SHARD_LEASE_TTL_SECS", UInt),
+            key("ARB_MONITOR_WIDE_SPREAD_THRESHOLD", Number).default("0.10").positive(),
+            key("ARB_SELECTION_FORCE_REFRESH_SECS", Int).default("600").positive(),
+            key("ARB_SELECTION_RESUBSCRIBE_MIN_DELTA_RATIO", Number).default("0.08").non_negative(),
+            key("ARB_SELECTION_RESUBSCRIBE_MIN_MARKET_DELTA", UInt).default("8"),
+            key("ARB_UPDATE_TIMEOUT_SECS", UInt).default("120"),
+            key("TRADE_PRINTS_ENABLED", Bool).default("true"),
+            key("TRADE_PRINTS_FLUSH_MS", UInt).default("2000"),
+            key("TRADE_PRINTS_MAX_BATCH", UInt).default("500").positive(),
+        ],
+    ),
+    (
+        "Arb signal transport",
+        &[
+            key("ARB_SIGNAL_STREAM_BATCH_SIZE", UInt).default("32"),
+            key("ARB_SIGNAL_STREAM_BLOCK_MS", UInt).default("2000"),
+            key("ARB_SIGNAL_STREAM_CONSUMER", Text),
+            key("ARB_SIGNAL_STREAM_GROUP", Text).default("arb-executor"),
+            key("ARB_SIGNAL_STREAM_MAX_DELIVERIES", UInt).default("5"),
+            key("ARB_SIGNAL_STREAM_MAX_LEN", UInt).default("10000"),
+            key("ARB_SIGNAL_STREAM_RECLAIM_IDLE_MS", UInt).default("30000"),
+            key("ARB_SIGNAL_TRANSPORT", OneOf(SIGNAL_TRANSPORTS)).default("pubsub"),
+        ],
+    ),
+    (
+        "Arb executor",
+        &[
+            key("ARB_ALLOW_LIVE_EXECUTION", Bool).default("false"),
+            key("ARB_AUTO_EXECUTE", Bool).default("false"),
+            key("ARB_CACHE_REFRESH_SECS", UInt).default("300"),
+            key("ARB_DYNAMIC_SIZING", Bool).default("true"),
+            key("ARB_MAX_POSITION_SIZE", Number).default("25"),
+            key("ARB_MAX_SIGNAL_AGE_SECS", UInt).default("30"),
+            key("ARB_MAX_TOTAL_EXPOSURE", Number).default("25000"),
+            key("ARB_MIN_BOOK_DEPTH", Number).default("25"),
+            key("ARB_MIN_NET_PROFIT", Number).default("0.001"),
+            key("ARB_MIN_POSITION_SIZE", Number).default("5"),
+            key("ARB_POSITION_SIZE", Number).default("50"),
+            key("ARB_SIGNAL_COOLDOWN_SECS", Int).default("60"),
+            key("ARB_TRADING_FEE_PCT", Fraction).default("0.0"),
+            key("EXECUTOR_MIN_BOOK_DEPTH", Number).default("100"),
+        ],
+    ),
+    (
+        "Circuit breaker and market conditions",
+        &[
+            key("CB_COOLDOWN_MINUTES", Int).default("30"),
+            key("CB_HARD_KILL_DRAWDOWN_PCT", Fraction).default("0.40"),
+            key("CB_MAX_CONSECUTIVE_LOSSES", UInt).default("8"),
+            key("CB_MAX_DAILY_LOSS", Number).default("2500"),
+            key("CB_MAX_DRAWDOWN_PCT", Fraction).default("0.20"),
+            key("DEAD_MANS_SWITCH_ENABLED", Bool).default("true"),
+            key("DEAD_MANS_SWITCH_EXECUTOR_TIMEOUT_SECS", UInt).default("180"),
+            key("DEAD_MANS_SWITCH_HEARTBEAT_INTERVAL_SECS", UInt).default("5"),
+            key("DEAD_MANS_SWITCH_HEARTBEAT_TIMEOUT_SECS", UInt).default("30"),
+            key("MARKET_CONDITIONS_APPLY", Bool).default("true"),
+            key("MARKET_CONDITIONS_ENABLED", Bool).default("true"),
+            key("MARKET_CONDITIONS_INTERVAL_SECS", UInt).default("60"),
+            key("MARKET_CONDITIONS_MIN_MARKETS", UInt).default("20"),
+            key("MARKET_CONDITIONS_MIN_ORDERS", UInt).default("5"),
+            key("MARKET_CONDITIONS_RECOVERY_SAMPLES", UInt).default("3"),
+            key("MARKET_CONDITIONS_THROTTLE_BOOK_STALE_SECS", UInt).default("120"),
+            key("MARKET_CONDITIONS_THROTTLE_CAPACITY", Fraction).default("0.50"),
+            key("MARKET_CONDITIONS_THROTTLE_ONE_LEGGED_FILLS", UInt).default("2"),
+            key("MARKET_CONDITIONS_THROTTLE_REJECT_RATE", Fraction).default("0.30"),
+            key("MARKET_CONDITIONS_THROTTLE_WIDE_SPREAD_FRACTION", Fraction).default("0.35"),
+            key("MARKET_CONDITIONS_TRIP_BOOK_STALE_SECS", UInt).default("300"),
+            key("MARKET_CONDITIONS_TRIP_ONE_LEGGED_FILLS", UInt).default("4"),
+            key("MARKET_CONDITIONS_TRIP_REJECT_RATE", Fraction).default("0.60"),
+            key("MARKET_CONDITIONS_TRIP_WIDE_SPREAD_FRACTION", Fraction).default("0.60"),
+            key("MARKET_CONDITIONS_WINDOW_MINUTES", UInt).default("15"),
+        ],
+    ),
+    (
+        "Quant signals and execution",
+        &[
+            key("CROSS_MARKET_INTERVAL_SECS", UInt).default("900"),
+            key("CROSS_MARKET_MAX_LAG_MOVE", Number).default("0.02"),
+            key("CROSS_MARKET_MIN_CORRELATION", Fraction).default("0.70"),
+            key("CROSS_MARKET_MIN_LEAD_MOVE", Number).default("0.05"),
+            key("CROSS_MARKET_SIGNAL_ENABLED", Bool).default("false"),
+            key("EVENT_SIGNAL_ENABLED", Bool).default("false"),
+            key("EVENT_SIGNAL_INTERVAL_SECS", UInt).default("60"),
+            key("EVENT_SIGNAL_MAX_AGE_MINUTES", UInt).default("60"),
+            key("EVENT_SIGNAL_MAX_REACTION", Fraction).default("0.02"),
+            key("EVENT_SIGNAL_MIN_IMPACT", Fraction).default("0.35"),
+            key("EVENT_SIGNAL_MIN_LAG_SECS", UInt).default("120"),
+            key("EVENT_SIGNAL_TARGET_MOVE", Fraction).default("0.05"),
+            key("FLOW_CALIBRATION_LOOKBACK_DAYS", UInt).default("14"),
+            key("FLOW_CALIBRATION_MIN_CLOSED_TRADES", UInt).default("8"),
+            key("FLOW_FEATURE_ENABLED", Bool).default("true"),
+            key("FLOW_FEATURE_INTERVAL_SECS", UInt).default("300"),
+            key("FLOW_MAX_SIGNALS_PER_CYCLE", UInt).default("15"),
+            key("FLOW_MIN_BOT_SCORE_COVERAGE", Fraction).default("0.50"),
+            key("FLOW_MIN_EXPECTED_EDGE_BPS", Number).default("35.0"),
+            key("FLOW_MIN_IMBALANCE", Number).default("0.25"),
+            key("FLOW_MIN_SCORE", Fraction).default("0.58"),
+            key("FLOW_MIN_SMART_MONEY_SHARE", Fraction).default("0.35"),
+            key("FLOW_MIN_SMART_MONEY_USD", Number).default("500"),
+            key("FLOW_MIN_TRADE_COUNT", UInt).default("5"),
+            key("FLOW_REQUIRE_CALIBRATION", Bool).default("true"),
+            key("FLOW_REQUIRE_YES_PRICE", Bool).default("true"),
+            key("FLOW_SIGNAL_ENABLED", Bool).default("true"),
+            key("FLOW_SIGNAL_INTERVAL_SECS", UInt).default("300"),
+            key("FLOW_SIGNAL_WINDOW_MINUTES", UInt).default("60"),
+            key("FLOW_STREAM_MIN_VOLUME_USD", Number).default("2000.0"),
+            key("MEAN_REVERSION_INTERVAL_SECS", UInt).default("600"),
+            key("MEAN_REVERSION_SIGNAL_ENABLED", Bool).default("true"),
+            key("MEAN_REV_MIN_MOVE_PCT", Number).default("0.10"),
+            key("ORDER_ROUTER_ALGO", OneOf(ORDER_ROUTER_ALGOS)).default("market"),
+            key("ORDER_ROUTER_HORIZON_SECS", UInt).default("120"),
+            key("ORDER_ROUTER_LIMIT_SLIPPAGE", Fraction).default("0.02"),
+            key("ORDER_ROUTER_MIN_CHILD_QTY", Number).default("5"),
+            key("ORDER_ROUTER_MIN_NOTIONAL", Number).default("50"),
+            key("ORDER_ROUTER_PARTICIPATION", Fraction).default("0.10"),
+            key("ORDER_ROUTER_PASSIVE_FRACTION", Fraction).default("0.5"),
+            key("ORDER_ROUTER_POLL_MS", UInt).default("2000"),
+            key("ORDER_ROUTER_TWAP_SLICES", UInt).default("6"),
+            key("PORTFOLIO_KELLY_CACHE_TTL_SECS", UInt).default("10"),
+            key("PORTFOLIO_KELLY_FALLBACK_EQUITY", Number).default("1000"),
+            key("PORTFOLIO_KELLY_MAX_EQUITY_AGE_SECS", UInt).default("3600"),
+            key("PORTFOLIO_KELLY_MAX_PORTFOLIO_FRACTION", Fraction).default("0.50"),
+            key("QUANT_BASE_POSITION_SIZE", Number).default("30"),
+            key("QUANT_CACHE_REFRESH_SECS", UInt).default("300"),
+            key("QUANT_CROSS_MARKET_ALLOCATION_PCT", Fraction).default("0.30"),
+            key("QUANT_EVENT_ALLOCATION_PCT", Fraction).default("0.0"),
+            key("QUANT_EXECUTOR_ENABLED", Bool).default("false"),
+            key("QUANT_FLOW_ALLOCATION_PCT", Fraction).default("0.40"),
+            key("QUANT_KELLY_FRACTION", Fraction).default("0.25"),
+            key("QUANT_MAX_HOLD_HOURS", UInt).default("24"),
+            key("QUANT_MAX_POSITIONS", UInt).default("20"),
+            key("QUANT_MAX_POSITION_SIZE", Number).default("200"),
+            key("QUANT_MAX_SIGNAL_AGE_SECS", UInt).default("120"),
+            key("QUANT_MEAN_REVERSION_ALLOCATION_PCT", Fraction).default("0.20"),
+            key("QUANT_MIN_BOOK_DEPTH", Number).default("50"),
+            key("QUANT_MIN_CONFIDENCE", Fraction).default("0.65"),
+            key("QUANT_RESOLUTION_ALLOCATION_PCT", Fraction).default("0.10"),
+            key("QUANT_STOP_LOSS_PCT", Fraction).default("0.10"),
+            key("QUANT_STRATEGY_HALT_COOLDOWN_SECS", UInt).default("3600"),
+            key("QUANT_STRATEGY_MAX_CONSECUTIVE_LOSSES", UInt).default("5"),
+            key("QUANT_STRATEGY_MAX_DAILY_LOSS", Number).default("200"),
+            key("QUANT_TAKE_PROFIT_PCT", Fraction).default("0.15"),
+            key("RESOLUTION_MIN_VOLUME_USD", Number).default("1000"),
+            key("RESOLUTION_RISK_CAPITAL_COST_APR", Number).default("0.10"),
+            key("RESOLUTION_RISK_DELAY_WEIGHT", Fraction).default("0.5"),
+            key("RESOLUTION_RISK_ENABLED", Bool).default("true"),
+            key("RESOLUTION_RISK_GRACE_HOURS", Number).default("24.0"),
+            key("RESOLUTION_RISK_INTERVAL_SECS", UInt).default("1800"),
+            key("RESOLUTION_RISK_LOOKBACK_DAYS", UInt).default("180"),
+            key("RESOLUTION_RISK_MAX_CHECKS", UInt).default("50"),
+            key("RESOLUTION_RISK_PRIOR_STRENGTH", Number).default("20.0"),
+            key("RESOLUTION_SIGNAL_ENABLED", Bool).default("true"),
+            key("RESOLUTION_SIGNAL_INTERVAL_SECS", UInt).default("900"),
+            key("RISK_MAX_ENTRY_PRICE", Fraction).default("1"),
+            key("RISK_MIN_ENTRY_PRICE", Fraction).default("0"),
+            key("SMART_MONEY_BOT_THRESHOLD", UInt).default("30"),
+            key("STREAMING_SIGNALS_COOLDOWN_SECS", UInt).default("900"),
+            key("STREAMING_SIGNALS_ENABLED", Bool).default("false"),
+            key("STREAMING_SIGNALS_MAX_MARKETS", UInt).default("100"),
+            key("STREAMING_SIGNALS_MIN_EVAL_MS", UInt).default("1000"),
+            key("STREAMING_SIGNALS_UNIVERSE_REFRESH_SECS", UInt).default("1800"),
+            key("STREAMING_SIGNALS_WINDOW_SECS", UInt).default("900"),
+        ],
+    ),
+    (
+        "External events",
+        &[
+            key("EVENT_IMPACT_HALF_LIFE_MINS", UInt).default("30"),
+            key("EVENT_INGEST_DROP_DIR", Text),
+            key("EVENT_INGEST_ENABLED", Bool).default("false"),
+            key("EVENT_INGEST_FEEDS", List),
+            key("EVENT_INGEST_INTERVAL_SECS", UInt).default("120"),
+            key("EVENT_INGEST_MAX_AGE_HOURS", UInt).default("24"),
+            key("EVENT_INGEST_MAX_LINKS", UInt).default("5"),
+            key("EVENT_INGEST_MIN_MATCH_SCORE", Fraction).default("0.5"),
+            key("EVENT_WEBHOOK_SECRET", Text).secret(),
+        ],
+    ),
+    (
+        "Exits and unwinds",
+        &[
+            key("EXIT_CAPITAL_COST_APR", Number).default("0.10"),
+            key("EXIT_FAILED_RETRY_BACKOFF_SECS", UInt).default("300"),
+            key("EXIT_HANDLER_ENABLED", Bool).default("false"),
+            key("EXIT_HOLD_MAX_DAYS", Number).default("14.0"),
+            key("EXIT_OPTIMIZER_ENABLED", Bool).default("false"),
+            key("EXIT_PASSIVE_ADVERSE_MOVE", Number).default("0.01"),
+            key("EXIT_PASSIVE_FILL_PROB", Fraction).default("0.5"),
+            key("EXIT_PASSIVE_WINDOW_SECS", UInt).default("300"),
+            key("EXIT_POLL_INTERVAL_SECS", UInt).default("30"),
+            key("EXIT_RESOLUTION_CHECK_SECS", UInt).default("300"),
+            key("POSITION_RECONCILER_ENABLED", Bool).default("true"),
+            key("POSITION_RECONCILER_INTERVAL_SECS", UInt).default("300"),
+            key("POSITION_RECONCILER_STARTUP_DELAY_SECS", UInt).default("0"),
+            key("UNWIND_HOLD_MAX_HOURS_TO_RESOLUTION", Number).default("24.0"),
+            key("UNWIND_HOLD_RISK_AVERSION", Number).default("0.5"),
+            key("UNWIND_MAX_COMPLETION_ATTEMPTS", UInt).default("3"),
+            key("UNWIND_MAX_PAIR_COST", Number).default("1"),
+            key("UNWIND_POLICY_ENABLED", Bool).default("true"),
+        ],
+    ),
+    (
+        "Latency arb",
+        &[
+            key("CEX_FEED_MIN_VENUES", UInt).default("1"),
+            key("CEX_FEED_STALE_MS", UInt).default("5000"),
+            key("CEX_FEED_SYMBOLS", List).default("btc,eth,sol,xrp"),
+            key("CEX_FEED_VENUES", List).default("binance,coinbase,kraken,okx"),
+            key("LATENCY_ARB_COOLDOWN_MS", UInt).default("30000"),
+            key("LATENCY_ARB_DIVERGENCE_THRESHOLD", Number).default("0.003"),
+            key("LATENCY_ARB_EMA_ALPHA", Fraction).default("0.3"),
+            key("LATENCY_ARB_ENABLED", Bool).default("false"),
+            key("LATENCY_ARB_FEE_RATE", Fraction).default("0.02"),
+            key("LATENCY_ARB_KELLY_BANKROLL", Number).default("1000"),
+            key("LATENCY_ARB_KELLY_FRACTION", Fraction).default("0.10"),
+            key("LATENCY_ARB_MAX_POSITION_SIZE", Number).default("100"),
+            key("LATENCY_ARB_MAX_SIGNAL_AGE_MS", UInt).default("1500"),
+            key("LATENCY_ARB_MAX_YES_PRICE", Fraction).default("0.95"),
+            key("LATENCY_ARB_MIN_EDGE", Number).default("0.02"),
+            key("LATENCY_ARB_MIN_POSITION_SIZE", Number).default("5"),
+            key("LATENCY_ARB_MIN_VOL_SAMPLES", UInt).default("60"),
+            key("LATENCY_ARB_MIN_YES_PRICE", Fraction).default("0.05"),
+            key("LATENCY_ARB_VOL_BUFFER", Number).default("1.0"),
+            key("LATENCY_ARB_VOL_BUFFER_HORIZON_SECS", Number).default("5.0"),
+            key("LATENCY_ARB_VOL_SAMPLE_MS", UInt).default("1000"),
+            key("LATENCY_ARB_VOL_WINDOW_SECS", UInt).default("900"),
+            key("LATENCY_ARB_WARMUP_TICKS", UInt).default("10"),
+        ],
+    ),
+    (
+        "Market making",
+        &[
+            key("MARKET_MAKER_ENABLED", Bool).default("false"),
+            key("MARKET_MAKER_FILL_POLL_SECS", UInt).default("5"),
+            key("MARKET_MAKER_HALF_SPREAD", Fraction).default("0.02"),
+            key("MARKET_MAKER_INVENTORY_SKEW", Fraction).default("0.02"),
+            key("MARKET_MAKER_MARKETS", List),
+            key("MARKET_MAKER_MARKOUT_SECS", UInt).default("60"),
+            key("MARKET_MAKER_MAX_INVENTORY", Number).default("500.0"),
+            key("MARKET_MAKER_MIN_REQUOTE_MS", UInt).default("500"),
+            key("MARKET_MAKER_PAUSE_SECS", UInt).default("120"),
+            key("MARKET_MAKER_QUOTE_SIZE", Number).default("50.0"),
+            key("MARKET_MAKER_STALE_BOOK_SECS", UInt).default("30"),
+            key("MARKET_MAKER_VOL_SPIKE", Fraction).default("0.05"),
+            key("MARKET_MAKER_VOL_WINDOW_SECS", UInt).default("60"),
+        ],
+    ),
+    (
+        "Dynamic tuning and learning",
+        &[
+            key("BACKTEST_AUTOMATION_CLAIM_LIMIT", UInt).default("4"),
+            key("BACKTEST_AUTOMATION_ENABLED", Bool).default("true"),
+            key("BACKTEST_AUTOMATION_INTERVAL_SECS", UInt).default("300"),
+            key("BACKTEST_AUTOMATION_STARTUP_DELAY_SECS", UInt).default("180"),
+            key("DYNAMIC_CONFIG_ALLOWED_SOURCES", List).default("dynamic_tuner,dynamic_tuner_rollback,dynamic_tuner_sync,workspace_manual,risk_limits,operator_cli"),
+            key("DYNAMIC_CONFIG_REDIS_URL", Url).secret(),
+            key("DYNAMIC_TUNER_APPLY", Bool).default("true"),
+            key("DYNAMIC_TUNER_BOOTSTRAP_ENABLED", Bool).default("true"),
+            key("DYNAMIC_TUNER_BOOTSTRAP_MAX_ATTEMPTS", UInt).default("100"),
+            key("DYNAMIC_TUNER_ENABLED", Bool).default("true"),
+            key("DYNAMIC_TUNER_EVAL_DELAY_MINUTES", UInt).default("10"),
+            key("DYNAMIC_TUNER_FILL_DEGRADE_DELTA", Number).default("0.08"),
+            key("DYNAMIC_TUNER_FREEZE_DRAWDOWN", Number).default("0.20"),
+            key("DYNAMIC_TUNER_INTERVAL_SECS", UInt).default("300"),
+            key("DYNAMIC_TUNER_NO_TRADE_MIN_ATTEMPTS", UInt).default("20"),
+            key("DYNAMIC_TUNER_NO_TRADE_WINDOW_MINUTES", UInt).default("120"),
+            key("DYNAMIC_TUNER_PNL_DEGRADE_DELTA", Number).default("75.0"),
+            key("DYNAMIC_TUNER_REDIS_URL", Url).secret(),
+            key("DYNAMIC_TUNER_REGIME_STREAK", UInt).default("2"),
+            key("LEARNING_EVALUATOR_ENABLED", Bool).default("true"),
+            key("LEARNING_EVALUATOR_INTERVAL_SECS", UInt).default("1800"),
+            key("LEARNING_EVALUATOR_LOOKBACK_HOURS", UInt).default("168"),
+            key("LEARNING_EVALUATOR_MAX_MODELS", UInt).default("24"),
+            key("LEARNING_EVALUATOR_STARTUP_DELAY_SECS", UInt).default("240"),
+            key("LEARNING_MODEL_REFRESH_SECS", UInt).default("30"),
+            key("LEARNING_ROLLOUT_OBSERVER_ENABLED", Bool).default("true"),
+            key("LEARNING_ROLLOUT_OBSERVER_INTERVAL_SECS", UInt).default("900"),
+            key("LEARNING_ROLLOUT_OBSERVER_STARTUP_DELAY_SECS", UInt).default("300"),
+            key("LEARNING_ROLLOUT_REFRESH_SECS", UInt).default("30"),
+            key("LEARNING_SHADOW_ENABLED", Bool).default("true"),
+        ],
+    ),
+    (
+        "Background jobs",
+        &[
+            key("ACCOUNT_SNAPSHOT_ENABLED", Bool).default("true"),
+            key("ACCOUNT_SNAPSHOT_INTERVAL_SECS", UInt).default("300"),
+            key("HARVESTER_ENABLED", Bool).default("true"),
+            key("HARVESTER_INTERVAL_SECS", UInt).default("120"),
+            key("HARVESTER_MAX_NEW_PER_CYCLE", UInt).default("1500"),
+            key("HARVESTER_TRADES_PER_FETCH", UInt).default("1000"),
+            key("METRICS_CALCULATOR_BATCH_SIZE", UInt).default("50"),
+            key("METRICS_CALCULATOR_ENABLED", Bool).default("true"),
+            key("METRICS_CALCULATOR_INTERVAL_SECS", UInt).default("900"),
+            key("METRICS_RECALC_AFTER_HOURS", UInt).default("6"),
+            key("STRATEGY_HEALTH_ENABLED", Bool).default("true"),
+            key("STRATEGY_HEALTH_INTERVAL_SECS", UInt).default("21600"),
+            key("STRATEGY_PNL_ENABLED", Bool).default("true"),
+            key("STRATEGY_PNL_INTERVAL_SECS", UInt).default("21600"),
+            key("WALLET_INVENTORY_ENABLED", Bool).default("true"),
+            key("WALLET_INVENTORY_INITIAL_LOOKBACK_BLOCKS", UInt).default("2000000"),
+            key("WALLET_INVENTORY_INTERVAL_SECS", UInt).default("180"),
+            key("WALLET_INVENTORY_PHASE2_BACKFILL_CHUNKS_PER_REFRESH", UInt).default("4"),
+            key("WALLET_INVENTORY_PHASE2_BACKFILL_START_BLOCK", UInt).default("0"),
+            key("WALLET_INVENTORY_PHASE2_CHUNK_BLOCKS", UInt).default("2000000"),
+        ],
+    ),
+    (
+        "Observability",
+        &[
+            key("METRICS_ENABLED", Bool).default("true"),
+            key("METRICS_PORT", UInt),
+            key("METRICS_TOKEN", Text).secret(),
+            key("OTEL_EXPORTER_OTLP_ENDPOINT", Url),
+            key("OTEL_EXPORTER_OTLP_TRACES_ENDPOINT", Url),
+            key("OTEL_SDK_DISABLED", Bool).default("false"),
+            key("OTEL_SERVICE_NAME", Text),
+            // Spans exported: the workspace crates, not their dependencies.
+            key("OTEL_TRACES_FILTER", Text).default(
+                "warn,api_server=info,arb_monitor=info,bot_scanner=info,\
+                 polymarket_core=info,trading_engine=info,risk_manager=info",
+            ),
+            key("OTEL_TRACES_SAMPLER_ARG", Fraction).default("1.0"),
+        ],
+    ),
+    (
+        "Key vault (environment only)",
+        &[
+            key("AWS_REGION", Text).env_only(),
+            key("VAULT_FILE_PATH", Text).env_only(),
+            key("VAULT_MASTER_KEY", Text).secret().env_only(),
+            key("VAULT_PROVIDER", OneOf(VAULT_PROVIDERS)).env_only(),
+        ],
+    ),
+];
+
+/// Look up a key by its environment-variable name.
+pub fn lookup(name: &str) -> Option<&'static KeySpec> {
+    SCHEMA
+        .iter()
+        .flat_map(|(_, keys)| keys.iter())
+        .find(|spec| spec.name == name)
+}
+
+/// Lower/upper bound pairs; checked when both ends are set.
+const ORDERED_PAIRS: &[(&str, &str)] = &[
+    ("ARB_MIN_POSITION_SIZE", "ARB_MAX_POSITION_SIZE"),
+    (
+        "LATENCY_ARB_MIN_POSITION_SIZE",
+        "LATENCY_ARB_MAX_POSITION_SIZE",
+    ),
+    ("LATENCY_ARB_MIN_YES_PRICE", "LATENCY_ARB_MAX_YES_PRICE"),
+    ("RISK_MIN_ENTRY_PRICE", "RISK_MAX_ENTRY_PRICE"),
+    ("DB_RETRY_BASE_DELAY_MS", "DB_RETRY_MAX_DELAY_MS"),
+    ("CB_MAX_DRAWDOWN_PCT", "CB_HARD_KILL_DRAWDOWN_PCT"),
+    (
+        "MARKET_CONDITIONS_THROTTLE_REJECT_RATE",
+        "MARKET_CONDITIONS_TRIP_REJECT_RATE",
+    ),
+    (
+        "MARKET_CONDITIONS_THROTTLE_WIDE_SPREAD_FRACTION",
+        "MARKET_CONDITIONS_TRIP_WIDE_SPREAD_FRACTION",
+    ),
+];
+
+/// Intervals that must be strictly shorter than the timeout they feed.
+const SHORTER_THAN: &[(&str, &str)] = &[
+    (
+        "DEAD_MANS_SWITCH_HEARTBEAT_INTERVAL_SECS",
+        "DEAD_MANS_SWITCH_HEARTBEAT_TIMEOUT_SECS",
+    ),
+    ("CLOB_WS_PING_INTERVAL_SECS", "CLOB_WS_READ_TIMEOUT_SECS"),
+    (
+        "ARB_MONITOR_SHARD_HEARTBEAT_SECS",
+        "ARB_MONITOR_SHARD_LEASE_TTL_SECS",
+    ),
+];
+
+/// Per-strategy capital shares; together they cannot exceed the whole.
+const QUANT_ALLOCATIONS: &[&str] = &[
+    "QUANT_FLOW_ALLOCATION_PCT",
+    "QUANT_CROSS_MARKET_ALLOCATION_PCT",
+    "QUANT_MEAN_REVERSION_ALLOCATION_PCT",
+    "QUANT_RESOLUTION_ALLOCATION_PCT",
+];
+
+/// Rules spanning several keys. Values that fail their own type check are
+/// skipped here; they are already reported individually.
+pub(crate) fn cross_field_issues(get: impl Fn(&str) -> Option<String>) -> Vec<ValidationIssue> {
+    let number = |name: &str| get(name).and_then(|v| v.trim().parse::<f64>().ok());
+    let mut issues = Vec::new();
+
+    for &(low, high) in ORDERED_PAIRS {
+        if let (Some(l), Some(h)) = (number(low), number(high)) {
+            if l > h {
+                issues.push(ValidationIssue::new(
+                    low,
+                    format!("{l} is greater than {high} ({h})"),
+                ));
+            }
+        }
+    }
+    for &(interval, timeout) in SHORTER_THAN {
+        if let (Some(i), Some(t)) = (number(interval), number(timeout)) {
+            if i >= t {
+                issues.push(ValidationIssue::new(
+                    interval,
+                    format!("{i} must be shorter than {timeout} ({t})"),
+                ));
+            }
+        }
+    }
+    let allocated: f64 = QUANT_ALLOCATIONS.iter().filter_map(|k| number(k)).sum();
+    if allocated > 1.0 + 1e-9 {
+        issues.push(ValidationIssue::new(
+            "QUANT_*_ALLOCATION_PCT",
+            format!("strategy allocations sum to {allocated}, more than 1"),
+        ));
+    }
+    issues
+}
@@ -0,0 +1,539 @@
+//! Durable signal transport over Redis Streams.
+//!
+//! Pub/sub drops every message published while no subscriber is connected, so
+//! an api-server restart silently loses arb entry signals. In `stream` mode the
+//! publisher appends to a capped stream (`XADD MAXLEN ~`) and consumers read
+//! through a consumer group (`XREADGROUP`), acknowledging each entry once it
+//! has been handed off (`XACK`). Entries left pending, by a consumer that died
+//! or because nothing in-process took the hand-off, are reclaimed once idle
+//! (`XPENDING` + `XCLAIM`); entries that keep failing are acknowledged and
+//! counted as dead-lettered. Delivery counters, pending entries and group lag
+//! are exported to Prometheus.
+//!
+//! Pub/sub remains the default transport and the fallback mode.
+
+use redis::aio::ConnectionManager;
+use redis::streams::{
+    StreamClaimReply, StreamId, StreamMaxlen, StreamPendingCountReply, StreamPendingReply,
+    StreamReadOptions, StreamReadReply,
+};
+use redis::AsyncCommands;
+use serde::Serialize;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tracing::{debug, warn};
+
+/// Stream carrying arb entry signals (the durable twin of `arb:entry`).
+pub const ENTRY_STREAM: &str = "arb:stream:entry";
+/// Field holding the JSON-encoded signal.
+pub const FIELD_PAYLOAD: &str = "payload";
+/// Field holding the publish time in epoch milliseconds.
+pub const FIELD_PUBLISHED_AT_MS: &str = "published_at_ms";
+
+/// How signals travel between services.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SignalTransport {
+    /// Fire-and-forget Redis pub/sub.
+    #[default]
+    PubSub,
+    /// Redis Streams with consumer-group acknowledgement.
+    Stream,
+}
+
+impl SignalTransport {
+    /// Parse a transport name; unknown values fall back to pub/sub.
+    pub fn parse(value: &str) -> Self {
+        match value.trim().to_lowercase().as_str() {
+            "stream" | "streams" => Self::Stream,
+            _ => Self::PubSub,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::PubSub => "pubsub",
+            Self::Stream => "stream",
+        }
+    }
+}
+
+/// Configuration shared by stream publishers and consumers.
+#[derive(Debug, Clone)]
+pub struct SignalStreamConfig {
+    /// Transport in use; publishers and consumers must agree.
+    pub transport: SignalTransport,
+    /// Stream key.
+    pub stream_key: String,
+    /// Approximate cap on stream length (`XADD MAXLEN ~`).
+    pub max_len: usize,
+    /// Consumer group name.
+    pub group: String,
+    /// Consumer name within the group; must be unique per process.
+    pub consumer: String,
+    /// Maximum entries per `XREADGROUP` call.
+    pub batch_size: usize,
+    /// How long `XREADGROUP` blocks waiting for new entries.
+    pub block_ms: usize,
+    /// Pending entries idle for at least this long are reclaimed from other consumers.
+    pub reclaim_idle_ms: usize,
+    /// Entries delivered more than this many times are acknowledged and dropped.
+    pub max_deliveries: usize,
+}
+
+impl Default for SignalStreamConfig {
+    fn default() -> Self {
+        Self {
+            transport: SignalTransport::PubSub,
+            stream_key: ENTRY_STREAM.to_string(),
+            max_len: 10_000,
+            group: "arb-executor".to_string(),
+            consumer: "api-server".to_string(),
+            batch_size: 32,
+            block_ms: 2_000,
+            reclaim_idle_ms: 30_000,
+            max_deliveries: 5,
+        }
+    }
+}
+
+impl SignalStreamConfig {
+    /// Create config from environment variables.
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        let consumer = crate::settings::get_opt("ARB_SIGNAL_STREAM_CONSUMER")
+            .or_else(|| {
+                crate::settings::var("HOSTNAME")
+                    .ok()
+                    .map(|host| format!("api-server-{host}"))
+            })
+            .unwrap_or(defaults.consumer);
+        Self {
+            transport: SignalTransport::parse(&crate::settings::get::<String>(
+                "ARB_SIGNAL_TRANSPORT",
+            )),
+            stream_key: defaults.stream_key,
+            max_len: crate::settings::get("ARB_SIGNAL_STREAM_MAX_LEN"),
+            group: crate::settings::get("ARB_SIGNAL_STREAM_GROUP"),
+            consumer,
+            batch_size: crate::settings::get("ARB_SIGNAL_STREAM_BATCH_SIZE"),
+            block_ms: crate::settings::get("ARB_SIGNAL_STREAM_BLOCK_MS"),
+            reclaim_idle_ms: crate::settings::get("ARB_SIGNAL_STREAM_RECLAIM_IDLE_MS"),
+            max_deliveries: crate::settings::get("ARB_SIGNAL_STREAM_MAX_DELIVERIES"),
+        }
+    }
+}
+
+/// Append a signal to the stream, trimming it to roughly `max_len` entries.
+pub async fn publish(
+    conn: &mut ConnectionManager,
+    config: &SignalStreamConfig,
+    payload: &str,
+) -> redis::RedisResult<String> {
+    let published_at_ms = chrono::Utc::now().timestamp_millis().to_string();
+    conn.xadd_maxlen(
+        &config.stream_key,
+        StreamMaxlen::Approx(config.max_len),
+        "*",
+        &[
+            (FIELD_PAYLOAD, payload),
+            (FIELD_PUBLISHED_AT_MS, published_at_ms.as_str()),
+        ],
+    )
+    .await
+}
+
+/// One entry read from the stream.
+#[derive(Debug, Clone)]
+pub struct StreamEntry {
+    pub id: String,
+    pub payload: Option<String>,
+    pub published_at_ms: Option<i64>,
+    /// Set when the entry was reclaimed from another consumer.
+    pub reclaimed: bool,
+}
+
+impl StreamEntry {
+    fn from_stream_id(entry: &StreamId, reclaimed: bool) -> Self {
+        Self {
+            id: entry.id.clone(),
+            payload: entry.get(FIELD_PAYLOAD),
+            published_at_ms: entry
+                .get::<String>(FIELD_PUBLISHED_AT_MS)
+                .and_then(|ms| ms.parse().ok()),
+            reclaimed,
+        }
+    }
+
+    /// Publish-to-now latency; falls back to the millisecond part of the entry ID.
+    pub fn latency_ms(&self, now_ms: i64) -> Option<u64> {
+        let published_at_ms = self.published_at_ms.or_else(|| {
+            self.id
+                .split_once('-')
+                .and_then(|(ms, _)| ms.parse::<i64>().ok())
+        })?;
+        Some(now_ms.saturating_sub(published_at_ms).max(0) as u64)
+    }
+}
+
+/// Delivery counters and latency for a stream consumer. Every update is
+/// mirrored into the process-wide Prometheus registry as it happens.
+#[derive(Debug, Default)]
+pub struct SignalStreamMetrics {
+    received: AtomicU64,
+    acked: AtomicU64,
+    reclaimed: AtomicU64,
+    dead_lettered: AtomicU64,
+    left_pending: AtomicU64,
+    pending: AtomicU64,
+    lag: AtomicU64,
+    latency_samples: AtomicU64,
+    latency_sum_ms: AtomicU64,
+    latency_max_ms: AtomicU64,
+    last_latency_ms: AtomicU64,
+}
+
+/// Point-in-time view of [`SignalStreamMetrics`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct SignalStreamMetricsSnapshot {
+    pub received: u64,
+    pub acked: u64,
+    pub reclaimed: u64,
+    pub dead_lettered: u64,
+    /// Entries deliberately left unacknowledged for a later retry.
+    pub left_pending: u64,
+    /// Entries delivered to the group but not yet acknowledged.
+    pub pending: u64,
+    /// Entries in the stream not yet delivered to the group.
+    pub lag: u64,
+    pub avg_latency_ms: f64,
+    pub max_latency_ms: u64,
+    pub last_latency_ms: u64,
+}
+
+impl SignalStreamMetrics {
+    fn count(&self, counter: &AtomicU64, outcome: &str, n: u64) {
+        counter.fetch_add(n, Ordering::Relaxed);
+        crate::metrics::global()
+            .signal_stream_entries_total
+            .with_label_values(&[outcome])
+            .inc_by(n);
+    }
+
+    pub fn record_latency(&self, latency_ms: u64) {
+        self.latency_samples.fetch_add(1, Ordering::Relaxed);
+        self.latency_sum_ms.fetch_add(latency_ms, Ordering::Relaxed);
+        self.latency_max_ms.fetch_max(latency_ms, Ordering::Relaxed);
+        self.last_latency_ms.store(latency_ms, Ordering::Relaxed);
+        crate::metrics::global()
+            .signal_stream_latency_seconds
+            .observe(latency_ms as f64 / 1_000.0);
+    }
+
+    /// An entry was left unacknowledged so it is redelivered later.
+    pub fn record_left_pending(&self) {
+        self.count(&self.left_pending, "left_pending", 1);
+    }
+
+    fn record_backlog(&self, pending: u64, lag: Option<u64>) {
+        let metrics = crate::metrics::global();
+        self.pending.store(pending, Ordering::Relaxed);
+        metrics.signal_stream_pending.set(pending as i64);
+        if let Some(lag) = lag {
+            self.lag.store(lag, Ordering::Relaxed);
+            metrics.signal_stream_lag.set(lag as i64);
+        }
+    }
+
+    pub fn snapshot(&self) -> SignalStreamMetricsSnapshot {
+        let samples = self.latency_samples.load(Ordering::Relaxed);
+        let sum = self.latency_sum_ms.load(Ordering::Relaxed);
+        SignalStreamMetricsSnapshot {
+            received: self.received.load(Ordering::Relaxed),
+            acked: self.acked.load(Ordering::Relaxed),
+            reclaimed: self.reclaimed.load(Ordering::Relaxed),
+            dead_lettered: self.dead_lettered.load(Ordering::Relaxed),
+            left_pending: self.left_pending.load(Ordering::Relaxed),
+            pending: self.pending.load(Ordering::Relaxed),
+            lag: self.lag.load(Ordering::Relaxed),
+            avg_latency_ms: if samples == 0 {
+                0.0
+            } else {
+                sum as f64 / samples as f64
+            },
+            max_latency_ms: self.latency_max_ms.load(Ordering::Relaxed),
+            last_latency_ms: self.last_latency_ms.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Consumer-group reader for a signal stream.
+pub struct SignalStreamConsumer {
+    conn: ConnectionManager,
+    config: SignalStreamConfig,
+    metrics: Arc<SignalStreamMetrics>,
+}
+
+impl SignalStreamConsumer {
+    /// Connect and make sure the consumer group exists.
+    ///
+    /// A new group starts at the stream tail: signals published before the
+    /// group existed are stale by the time anyone could act on them.
+    pub async fn connect(
+        client: redis::Client,
+        config: SignalStreamConfig,
+        metrics: Arc<SignalStreamMetrics>,
+    ) -> redis::RedisResult<Self> {
+        let mut conn = ConnectionManager::new(client).await?;
+        let created: redis::RedisResult<()> = conn
+            .xgroup_create_mkstream(&config.stream_key, &config.group, "$")
+            .await;
+        match created {
+            Ok(()) => debug!(
+                stream = %config.stream_key,
+                group = %config.group,
+                "Created signal stream consumer group"
+            ),
+            Err(e) if e.code() == Some("BUSYGROUP") => {}
+            Err(e) => return Err(e),
+        }
+        Ok(Self {
+            conn,
+            config,
+            metrics,
+        })
+    }
+
+    pub fn config(&self) -> &SignalStreamConfig {
+        &self.config
+    }
+
+    pub fn metrics(&self) -> &Arc<SignalStreamMetrics> {
+        &self.metrics
+    }
+
+    /// Entries already delivered to this consumer but never acknowledged,
+    /// e.g. because the process restarted mid-batch.
+    pub async fn read_own_pending(&mut self) -> redis::RedisResult<Vec<StreamEntry>> {
+        self.read_group("0", None).await
+    }
+
+    /// Block for up to `block_ms` waiting for new entries.
+    pub async fn read_new(&mut self) -> redis::RedisResult<Vec<StreamEntry>> {
+        self.read_group(">", Some(self.config.block_ms)).await
+    }
+
+    async fn read_group(
+        &mut self,
+        id: &str,
+        block_ms: Option<usize>,
+    ) -> redis::RedisResult<Vec<StreamEntry>> {
+        let mut options = StreamReadOptions::default()
+            .group(&self.config.group, &self.config.consumer)
+            .count(self.config.batch_size);
+        if let Some(ms) = block_ms {
+            options = options.block(ms);
+        }
+        let reply: Option<StreamReadReply> = self
+            .conn
+            .xread_options(&[&self.config.stream_key], &[id], &options)
+            .await?;
+        let entries: Vec<StreamEntry> = reply
+            .map(|reply| {
+                reply
+                    .keys
+                    .iter()
+                    .flat_map(|key| key.ids.iter())
+                    .map(|entry| StreamEntry::from_stream_id(entry, false))
+                    .collect()
+            })
+            .unwrap_or_default();
+        self.metrics
+            .count(&self.metrics.received, "received", entries.len() as u64);
+        Ok(entries)
+    }
+
+    /// Claim up to `batch_size` entries left pending for longer than
+    /// `reclaim_idle_ms`, including ones this consumer left unacknowledged.
+    /// The whole pending list is paged through, so a long run of fresh
+    /// entries at its head cannot hide stale ones behind it. Entries over
+    /// `max_deliveries` are acknowledged and dropped instead of being handed
+    /// back.
+    pub async fn reclaim_stale(&mut self) -> redis::RedisResult<Vec<StreamEntry>> {
+        let mut claim_ids = Vec::new();
+        let mut start = "-".to_string();
+        while claim_ids.len() < self.config.batch_size {
+            let pending: StreamPendingCountReply = self
+                .conn
+                .xpending_count(
+                    &self.config.stream_key,
+                    &self.config.group,
+                    &start,
+                    "+",
+                    self.config.batch_size,
+                )
+                .await?;
+            let page_len = pending.ids.len();
+            let Some(next) = pending
+                .ids
+                .last()
+                .and_then(|entry| next_stream_id(&entry.id))
+            else {
+                break;
+            };
+
+            for entry in pending.ids {
+                if entry.last_delivered_ms < self.config.reclaim_idle_ms {
+                    continue;
+                }
+                if entry.times_delivered > self.config.max_deliveries {
+                    warn!(
+                        stream = %self.config.stream_key,
+                        id = %entry.id,
+                        consumer = %entry.consumer,
+                        times_delivered = entry.times_delivered,
+                        "Dropping signal stream entry after too many deliveries"
+                    );
+                    self.ack(&entry.id).await?;
+                    self.metrics
+                        .count(&self.metrics.dead_lettered, "dead_lettered", 1);
+                    continue;
+                }
+                if claim_ids.len() < self.config.batch_size {
+                    claim_ids.push(entry.id);
+                }
+            }
+            if page_len < self.config.batch_size {
+                break;
+            }
+            start = next;
+        }
+        if claim_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let claimed: StreamClaimReply = self
+            .conn
+            .xclaim(
+                &self.config.stream_key,
+                &self.config.group,
+                &self.config.consumer,
+                self.config.reclaim_idle_ms,
+                &claim_ids,
+            )
+            .await?;
+        let entries: Vec<StreamEntry> = claimed
+            .ids
+            .iter()
+            .map(|entry| StreamEntry::from_stream_id(entry, true))
+            .collect();
+        self.metrics
+            .count(&self.metrics.reclaimed, "reclaimed", entries.len() as u64);
+        Ok(entries)
+    }
+
+    /// Sample the group's pending count and lag into the metrics. Lag needs
+    /// Redis 7; on older servers only the pending count is updated.
+    pub async fn refresh_backlog(&mut self) -> redis::RedisResult<()> {
+        let pending: StreamPendingReply = self
+            .conn
+            .xpending(&self.config.stream_key, &self.config.group)
+            .await?;
+        let groups: Vec<std::collections::HashMap<String, redis::Value>> = redis::cmd("XINFO")
+            .arg("GROUPS")
+            .arg(&self.config.stream_key)
+            .query_async(&mut self.conn)
+            .await?;
+        let lag = groups
+            .iter()
+            .find(|group| {
+                group
+                    .get("name")
+                    .and_then(|name| redis::from_redis_value::<String>(name).ok())
+                    .as_deref()
+                    == Some(self.config.group.as_str())
+            })
+            .and_then(|group| group.get("lag"))
+            .and_then(|lag| redis::from_redis_value::<u64>(lag).ok());
+        self.metrics.record_backlog(pending.count() as u64, lag);
+        Ok(())
+    }
+
+    /// Acknowledge an entry so it leaves the group's pending list.
+    pub async fn ack(&mut self, id: &str) -> redis::RedisResult<()> {
+        let _: u64 = self
+            .conn
+            .xack(&self.config.stream_key, &self.config.group, &[id])
+            .await?;
+        self.metrics.count(&self.metrics.acked, "acked", 1);
+        Ok(())
+    }
+}
+
+/// Smallest stream ID after `id`, for paging through `XPENDING` without the
+/// exclusive-range syntax older servers lack.
+fn next_stream_id(id: &str) -> Option<String> {
+    let (ms, seq) = id.split_once('-')?;
+    let ms: u64 = ms.parse().ok()?;
+    let seq: u64 = seq.parse().ok()?;
+    Some(match seq.checked_add(1) {
+        Some(seq) => format!("{ms}-{seq}"),
+        None => format!("{}-0", ms.checked_add(1)?),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transport_parse_defaults_to_pubsub() {
+        assert_eq!(SignalTransport::parse("stream"), SignalTransport::Stream);
+        assert_eq!(SignalTransport::parse(" Streams "), SignalTransport::Stream);
+        assert_eq!(SignalTransport::parse("pubsub"), SignalTransport::PubSub);
+        assert_eq!(SignalTransport::parse("kafka"), SignalTransport::PubSub);
+    }
+
+    #[test]
+    fn test_latency_falls_back_to_entry_id() {
+        let mut entry = StreamEntry {
+            id: "1700000000000-0".to_string(),
+            payload: None,
+            published_at_ms: Some(1_700_000_000_250),
+            reclaimed: false,
+        };
+        assert_eq!(entry.latency_ms(1_700_000_000_400), Some(150));
+
+        entry.published_at_ms = None;
+        assert_eq!(entry.latency_ms(1_700_000_000_400), Some(400));
+
+        // Clock skew never yields a negative latency.
+        assert_eq!(entry.latency_ms(1_699_999_999_000), Some(0));
+    }
+
+    #[test]
+    fn test_next_stream_id_steps_past_entry() {
+        assert_eq!(
+            next_stream_id("1700000000000-0").as_deref(),
+            Some("1700000000000-1")
+        );
+        assert_eq!(
+            next_stream_id(&format!("5-{}", u64::MAX)).as_deref(),
+            Some("6-0")
+        );
+        assert_eq!(next_stream_id("garbage"), None);
+    }
+
+    #[test]
+    fn test_metrics_snapshot_averages_latency() {
+        let metrics = SignalStreamMetrics::default();
+        assert_eq!(metrics.snapshot().avg_latency_ms, 0.0);
+
+        metrics.record_latency(10);
+        metrics.record_latency(30);
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.avg_latency_ms, 20.0);
+        assert_eq!(snapshot.max_latency_ms, 30);
+        assert_eq!(snapshot.last_latency_ms, 30);
+    }
+}
@@ -3,7 +3,12 @@
 //! Provides mathematically-grounded position sizing for binary prediction
 //! markets. The Kelly Criterion maximizes long-term growth rate by sizing
 //! proportional to edge.
+//!
+//! `portfolio_kelly_sizes` extends single-bet Kelly to a set of simultaneous
+//! bets, so concurrent positions share one bankroll instead of each taking a
+//! full Kelly stake of it.
 
+use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
 
 /// Configuration for Kelly-based position sizing.
@@ -110,6 +115,257 @@ pub fn linear_position_size(
     min_position + size_range * t
 }
 
+/// Correlations are clamped to this magnitude so the system stays solvable
+/// when two legs are (nearly) the same bet.
+const MAX_ABS_CORRELATION: f64 = 0.95;
+
+/// A candidate bet for simultaneous Kelly sizing.
+#[derive(Debug, Clone)]
+pub struct PortfolioBet {
+    /// Market (condition ID) the bet is on.
+    pub market_id: String,
+    /// `true` when buying YES, `false` when buying NO.
+    pub buys_yes: bool,
+    /// Estimated probability that the purchased outcome wins.
+    pub p_win: f64,
+    /// Price paid for the purchased outcome (0.0–1.0).
+    pub price: f64,
+}
+
+/// Capital already committed to one leg of an open position.
+#[derive(Debug, Clone)]
+pub struct OpenExposure {
+    pub market_id: String,
+    /// `true` for a YES leg, `false` for a NO leg.
+    pub holds_yes: bool,
+    /// Cost basis of the leg in USD.
+    pub stake: Decimal,
+    /// Price of the held outcome (entry or current mark).
+    pub price: f64,
+}
+
+/// Configuration for portfolio-level Kelly sizing.
+#[derive(Debug, Clone)]
+pub struct PortfolioKellyConfig {
+    /// Fractional Kelly multiplier applied to every allocation.
+    pub fraction: f64,
+    /// Hard upper cap on a single position.
+    pub max_position: Decimal,
+    /// Allocations below this are dropped.
+    pub min_position: Decimal,
+    /// Cap on total committed capital (open + new) as a fraction of equity.
+    pub max_portfolio_fraction: f64,
+}
+
+impl Default for PortfolioKellyConfig {
+    fn default() -> Self {
+        Self {
+            fraction: 0.25,
+            max_position: Decimal::new(200, 0),
+            min_position: Decimal::new(5, 0),
+            max_portfolio_fraction: 0.50,
+        }
+    }
+}
+
+/// Compute simultaneous Kelly allocations for a set of candidate bets.
+///
+/// Each bet is first sized with fractional single-bet Kelly. Those sizes are
+/// then shrunk for correlation with the other candidates and with open
+/// positions by solving the quadratic (mean-variance) approximation of the
+/// fractional Kelly objective in standardized units:
+/// `R · z = c·s − R_open · z_open`, where `c` is `config.fraction`, `s` each
+/// bet's Sharpe ratio per dollar staked, `R` the correlation matrix and
+/// `z_open` the open stakes as actually held. Open stakes are already sized,
+/// so they are not scaled by `c` again. A bet keeps `z / (c·s)` of its
+/// fractional Kelly size (never more). Bets whose solution goes non-positive
+/// are dropped and the system is re-solved.
+///
+/// Finally the new stakes are scaled down pro rata so open plus new capital
+/// stays within `max_portfolio_fraction` of `equity`.
+///
+/// `correlation(a, b)` returns the price correlation between two different
+/// markets (0.0 when unknown). Direction is handled here: a YES bet and a
+/// NO bet on positively correlated markets are negatively correlated.
+///
+/// Returns one entry per candidate, in order; `None` means "do not trade".
+pub fn portfolio_kelly_sizes<F>(
+    candidates: &[PortfolioBet],
+    open_positions: &[OpenExposure],
+    equity: Decimal,
+    config: &PortfolioKellyConfig,
+    correlation: F,
+) -> Vec<Option<Decimal>>
+where
+    F: Fn(&str, &str) -> f64,
+{
+    let mut sizes = vec![None; candidates.len()];
+    let equity_f64 = equity.to_f64().unwrap_or(0.0);
+    let fraction = config.fraction;
+    if equity_f64 <= 0.0 || fraction <= 0.0 || candidates.is_empty() {
+        return sizes;
+    }
+
+    let leg_correlation = |market_a: &str, yes_a: bool, market_b: &str, yes_b: bool| {
+        let rho = if market_a == market_b {
+            1.0
+        } else {
+            correlation(market_a, market_b)
+        };
+        let signed = if yes_a == yes_b { rho } else { -rho };
+        signed.clamp(-MAX_ABS_CORRELATION, MAX_ABS_CORRELATION)
+    };
+
+    // Open legs are treated as fairly priced (no edge); only their risk matters.
+    let open_z: Vec<f64> = open_positions
+        .iter()
+        .map(|open| {
+            let stake = open.stake.to_f64().unwrap_or(0.0);
+            (stake / equity_f64) * bet_volatility(open.price, open.price)
+        })
+        .collect();
+
+    let mut active: Vec<usize> = (0..candidates.len())
+        .filter(|&i| kelly_fraction(candidates[i].p_win, candidates[i].price) > 0.0)
+        .collect();
+    let mut adjusted = vec![0.0_f64; candidates.len()];
+
+    while !active.is_empty() {
+        let matrix: Vec<Vec<f64>> = active
+            .iter()
+            .map(|&i| {
+                active
+                    .iter()
+                    .map(|&j| {
+                        if i == j {
+                            1.0
+                        } else {
+                            let (a, b) = (&candidates[i], &candidates[j]);
+                            leg_correlation(&a.market_id, a.buys_yes, &b.market_id, b.buys_yes)
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        let rhs: Vec<f64> = active
+            .iter()
+            .map(|&i| {
+                let bet = &candidates[i];
+                let hedge: f64 = open_positions
+                    .iter()
+                    .zip(&open_z)
+                    .map(|(open, z)| {
+                        leg_correlation(
+                            &bet.market_id,
+                            bet.buys_yes,
+                            &open.market_id,
+                            open.holds_yes,
+                        ) * z
+                    })
+                    .sum();
+                fraction * bet_sharpe(bet.p_win, bet.price) - hedge
+            })
+            .collect();
+
+        let Some(z) = solve_linear_system(matrix, rhs) else {
+            // Degenerate correlation input: fall back to standalone Kelly.
+            for &i in &active {
+                adjusted[i] = fraction * kelly_fraction(candidates[i].p_win, candidates[i].price);
+            }
+            break;
+        };
+
+        let before = active.len();
+        let mut next = Vec::with_capacity(before);
+        for (&i, &z_i) in active.iter().zip(&z) {
+            if z_i > 0.0 {
+                let bet = &candidates[i];
+                let shrink = (z_i / (fraction * bet_sharpe(bet.p_win, bet.price))).min(1.0);
+                adjusted[i] = fraction * kelly_fraction(bet.p_win, bet.price) * shrink;
+                next.push(i);
+            } else {
+                adjusted[i] = 0.0;
+            }
+        }
+        if next.len() == before {
+            break;
+        }
+        active = next;
+    }
+
+    let mut raw: Vec<f64> = adjusted
+        .iter()
+        .map(|&f| {
+            let size = f * equity_f64;
+            size.min(config.max_position.to_f64().unwrap_or(f64::MAX))
+        })
+        .collect();
+
+    let committed: f64 = open_positions
+        .iter()
+        .map(|open| open.stake.to_f64().unwrap_or(0.0))
+        .sum();
+    let budget = (config.max_portfolio_fraction * equity_f64 - committed).max(0.0);
+    let requested: f64 = raw.iter().sum();
+    if requested > budget && requested > 0.0 {
+        let scale = budget / requested;
+        raw.iter_mut().for_each(|size| *size *= scale);
+    }
+
+    for (slot, size) in sizes.iter_mut().zip(raw) {
+        if size <= 0.0 {
+            continue;
+        }
+        *slot = Decimal::from_f64_retain(size).filter(|d| *d >= config.min_position);
+    }
+    sizes
+}
+
+/// Expected return per dollar staked divided by its standard deviation.
+fn bet_sharpe(p_win: f64, price: f64) -> f64 {
+    let vol = bet_volatility(p_win, price);
+    if vol <= 0.0 {
+        return 0.0;
+    }
+    (p_win / price - 1.0) / vol
+}
+
+/// Standard deviation of the return per dollar staked on a binary outcome.
+fn bet_volatility(p_win: f64, price: f64) -> f64 {
+    if price <= 0.0 || price >= 1.0 || p_win <= 0.0 || p_win >= 1.0 {
+        return 0.0;
+    }
+    (p_win * (1.0 - p_win)).sqrt() / price
+}
+
+/// Solve `a · x = b` by Gaussian elimination with partial pivoting.
+fn solve_linear_system(mut a: Vec<Vec<f64>>, mut b: Vec<f64>) -> Option<Vec<f64>> {
+    let n = b.len();
+    for col in 0..n {
+        let pivot = (col..n).max_by(|&x, &y| a[x][col].abs().total_cmp(&a[y][col].abs()))?;
+        if a[pivot][col].abs() < 1e-9 {
+            return None;
+        }
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+        let pivot_row = a[col].clone();
+        for row in col + 1..n {
+            let factor = a[row][col] / pivot_row[col];
+            for (dst, src) in a[row][col..].iter_mut().zip(&pivot_row[col..]) {
+                *dst -= factor * src;
+            }
+            b[row] -= factor * b[col];
+        }
+    }
+
+    let mut x = vec![0.0; n];
+    for row in (0..n).rev() {
+        let tail: f64 = (row + 1..n).map(|k| a[row][k] * x[k]).sum();
+        x[row] = (b[row] - tail) / a[row][row];
+    }
+    Some(x)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -234,4 +490,174 @@ mod tests {
         let size = linear_position_size(Decimal::ZERO, min_edge, max_edge, min_pos, max_pos);
         assert_eq!(size, min_pos);
     }
+
+    fn bet(market: &str, buys_yes: bool, p_win: f64, price: f64) -> PortfolioBet {
+        PortfolioBet {
+            market_id: market.to_string(),
+            buys_yes,
+            p_win,
+            price,
+        }
+    }
+
+    fn wide_portfolio_config() -> PortfolioKellyConfig {
+        PortfolioKellyConfig {
+            fraction: 0.25,
+            max_position: Decimal::new(10_000, 0),
+            min_position: Decimal::new(1, 0),
+            max_portfolio_fraction: 1.0,
+        }
+    }
+
+    #[test]
+    fn test_portfolio_single_bet_matches_standalone_kelly() {
+        let config = wide_portfolio_config();
+        let sizes = portfolio_kelly_sizes(
+            &[bet("a", true, 0.85, 0.55)],
+            &[],
+            Decimal::new(1000, 0),
+            &config,
+            |_, _| 0.0,
+        );
+        let standalone = kelly_position_size(
+            0.85,
+            0.55,
+            &KellyConfig {
+                fraction: 0.25,
+                bankroll: Decimal::new(1000, 0),
+                max_position: Decimal::new(10_000, 0),
+                min_position: Decimal::new(1, 0),
+            },
+        )
+        .unwrap();
+        let diff = (sizes[0].unwrap() - standalone).abs();
+        assert!(diff < Decimal::new(1, 2), "diff {diff}");
+    }
+
+    #[test]
+    fn test_portfolio_correlated_bets_shrink() {
+        let config = wide_portfolio_config();
+        let bets = [bet("a", true, 0.70, 0.55), bet("b", true, 0.70, 0.55)];
+        let equity = Decimal::new(1000, 0);
+
+        let independent = portfolio_kelly_sizes(&bets, &[], equity, &config, |_, _| 0.0);
+        let correlated = portfolio_kelly_sizes(&bets, &[], equity, &config, |_, _| 0.8);
+
+        assert!(correlated[0].unwrap() < independent[0].unwrap());
+        assert!(correlated[1].unwrap() < independent[1].unwrap());
+    }
+
+    #[test]
+    fn test_portfolio_open_position_reduces_same_direction_bet() {
+        let config = wide_portfolio_config();
+        let equity = Decimal::new(1000, 0);
+        let candidate = [bet("a", true, 0.70, 0.55)];
+        let open = [OpenExposure {
+            market_id: "b".to_string(),
+            holds_yes: true,
+            stake: Decimal::new(100, 0),
+            price: 0.55,
+        }];
+
+        let alone = portfolio_kelly_sizes(&candidate, &[], equity, &config, |_, _| 0.8);
+        let with_open = portfolio_kelly_sizes(&candidate, &open, equity, &config, |_, _| 0.8);
+        assert!(with_open[0].unwrap() < alone[0].unwrap());
+
+        // Same market, opposite side: the open leg hedges, so no shrink below standalone.
+        let hedge = [OpenExposure {
+            market_id: "a".to_string(),
+            holds_yes: false,
+            stake: Decimal::new(100, 0),
+            price: 0.45,
+        }];
+        let hedged = portfolio_kelly_sizes(&candidate, &hedge, equity, &config, |_, _| 0.0);
+        assert_eq!(hedged[0], alone[0]);
+    }
+
+    #[test]
+    fn test_portfolio_open_position_hedge_is_not_scaled_by_fraction() {
+        let config = wide_portfolio_config();
+        let equity = Decimal::new(1000, 0);
+        let rho = 0.5;
+        let candidate = [bet("a", true, 0.70, 0.55)];
+        let open = [OpenExposure {
+            market_id: "b".to_string(),
+            holds_yes: true,
+            stake: Decimal::new(100, 0),
+            price: 0.55,
+        }];
+
+        // One bet: z = c·s − ρ·z_open, and it keeps z / (c·s) of c·f*.
+        let s = bet_sharpe(0.70, 0.55);
+        let z_open = 0.1 * bet_volatility(0.55, 0.55);
+        let z = config.fraction * s - rho * z_open;
+        let expected = kelly_fraction(0.70, 0.55) * z / s * 1000.0;
+
+        let sizes = portfolio_kelly_sizes(&candidate, &open, equity, &config, |_, _| rho);
+        let size = sizes[0].unwrap().to_f64().unwrap();
+        assert!(
+            (size - expected).abs() < 1e-6,
+            "size {size}, expected {expected}"
+        );
+
+        // The open stake weighs the same whatever the fraction, so halving
+        // the fraction more than halves the new bet.
+        let half = PortfolioKellyConfig {
+            fraction: config.fraction / 2.0,
+            ..config.clone()
+        };
+        let halved = portfolio_kelly_sizes(&candidate, &open, equity, &half, |_, _| rho)[0]
+            .map_or(0.0, |size| size.to_f64().unwrap());
+        assert!(halved < size / 2.0, "halved {halved}, size {size}");
+    }
+
+    #[test]
+    fn test_portfolio_budget_caps_total_allocation() {
+        let config = PortfolioKellyConfig {
+            max_portfolio_fraction: 0.20,
+            ..wide_portfolio_config()
+        };
+        let bets: Vec<PortfolioBet> = (0..10)
+            .map(|i| bet(&format!("m{i}"), true, 0.85, 0.55))
+            .collect();
+        let equity = Decimal::new(1000, 0);
+
+        let sizes = portfolio_kelly_sizes(&bets, &[], equity, &config, |_, _| 0.0);
+        let total: Decimal = sizes.iter().flatten().sum();
+        assert!(total <= Decimal::new(20001, 2), "total {total}");
+        assert!(sizes.iter().all(|s| s.is_some()));
+
+        // Open exposure consumes the budget first.
+        let open = [OpenExposure {
+            market_id: "other".to_string(),
+            holds_yes: true,
+            stake: Decimal::new(200, 0),
+            price: 0.50,
+        }];
+        let sizes = portfolio_kelly_sizes(&bets, &open, equity, &config, |_, _| 0.0);
+        assert!(sizes.iter().all(|s| s.is_none()));
+    }
+
+    #[test]
+    fn test_portfolio_no_edge_or_no_equity() {
+        let config = wide_portfolio_config();
+        let sizes = portfolio_kelly_sizes(
+            &[bet("a", true, 0.40, 0.60), bet("b", true, 0.85, 0.55)],
+            &[],
+            Decimal::new(1000, 0),
+            &config,
+            |_, _| 0.0,
+        );
+        assert!(sizes[0].is_none());
+        assert!(sizes[1].is_some());
+
+        let sizes = portfolio_kelly_sizes(
+            &[bet("a", true, 0.85, 0.55)],
+            &[],
+            Decimal::ZERO,
+            &config,
+            |_, _| 0.0,
+        );
+        assert!(sizes[0].is_none());
+    }
 }
@@ -0,0 +1,240 @@
+//! Logging and distributed tracing setup.
+//!
+//! Every binary calls [`init_tracing`] once at startup. Logs always go to
+//! stdout; when an OTLP endpoint is configured, spans are also exported so a
+//! collector can stitch one trade together across services. Signals that
+//! cross Redis carry a W3C trace context ([`TraceContext`]) produced by
+//! [`current_context`] and re-attached on the other side with [`set_parent`].
+
+use opentelemetry::propagation::TextMapPropagator;
+use opentelemetry::trace::{TraceContextExt, TracerProvider as _};
+use opentelemetry_otlp::WithExportConfig;
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use opentelemetry_sdk::trace::{Sampler, SdkTracerProvider};
+use opentelemetry_sdk::Resource;
+use std::collections::HashMap;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::{EnvFilter, Layer};
+
+/// W3C `traceparent` / `tracestate` headers carried inside signal payloads.
+pub type TraceContext = HashMap<String, String>;
+
+/// Trace export configuration, read from the standard `OTEL_*` variables.
+#[derive(Debug, Clone)]
+pub struct TelemetryConfig {
+    pub service_name: String,
+    /// Full OTLP/HTTP traces URL; export is off when unset.
+    pub traces_endpoint: Option<String>,
+    /// Fraction of root traces kept; child spans follow their parent.
+    pub sample_ratio: f64,
+    /// Which spans are exported (`EnvFilter` syntax), independent of `RUST_LOG`.
+    pub traces_filter: String,
+}
+
+impl TelemetryConfig {
+    /// Create config from environment variables.
+    pub fn from_env(default_service_name: &str) -> Self {
+        let disabled: bool = crate::settings::get("OTEL_SDK_DISABLED");
+        let traces_endpoint = crate::settings::get_opt("OTEL_EXPORTER_OTLP_TRACES_ENDPOINT")
+            .or_else(|| {
+                crate::settings::get_opt::<String>("OTEL_EXPORTER_OTLP_ENDPOINT")
+                    .map(|base| format!("{}/v1/traces", base.trim_end_matches('/')))
+            })
+            .filter(|_| !disabled);
+        Self {
+            service_name: crate::settings::get_opt("OTEL_SERVICE_NAME")
+                .unwrap_or_else(|| default_service_name.to_string()),
+            traces_endpoint,
+            sample_ratio: crate::settings::get("OTEL_TRACES_SAMPLER_ARG"),
+            traces_filter: crate::settings::get("OTEL_TRACES_FILTER"),
+        }
+    }
+}
+
+/// Flushes and stops trace export when dropped; keep it alive for the
+/// lifetime of `main`.
+#[must_use = "dropping the guard stops trace export"]
+pub struct TelemetryGuard {
+    provider: Option<SdkTracerProvider>,
+}
+
+impl Drop for TelemetryGuard {
+    fn drop(&mut self) {
+        if let Some(provider) = self.provider.take() {
+            if let Err(e) = provider.shutdown() {
+                eprintln!("Failed to flush traces on shutdown: {e}");
+            }
+        }
+    }
+}
+
+/// Build the OTLP exporter pipeline, or `None` when export is not configured.
+pub fn build_tracer_provider(
+    config: &TelemetryConfig,
+) -> anyhow::Result<Option<SdkTracerProvider>> {
+    let Some(endpoint) = config.traces_endpoint.as_deref() else {
+        return Ok(None);
+    };
+    let exporter = opentelemetry_otlp::SpanExporter::builder()
+        .with_http()
+        .with_endpoint(endpoint)
+        .build()?;
+    Ok(Some(
+        SdkTracerProvider::builder()
+            .with_batch_exporter(exporter)
+            .with_sampler(Sampler::ParentBased(Box::new(Sampler::TraceIdRatioBased(
+                config.sample_ratio,
+            ))))
+            .with_resource(
+                Resource::builder()
+                    .with_service_name(config.service_name.clone())
+                    .build(),
+            )
+            .build(),
+    ))
+}
+
+/// Install the global subscriber: stdout logs filtered by `RUST_LOG` (falling
+/// back to `default_log_filter`), plus OTLP span export when configured.
+pub fn init_tracing(service_name: &str, default_log_filter: &str) -> TelemetryGuard {
+    let config = TelemetryConfig::from_env(service_name);
+    let provider = build_tracer_provider(&config).unwrap_or_else(|e| {
+        eprintln!("Trace export disabled, failed to build OTLP exporter: {e}");
+        None
+    });
+
+    let log_filter =
+        EnvFilter::try_from_default_env().unwrap_or_else(|_| default_log_filter.into());
+    let otel_layer = provider.as_ref().map(|provider| {
+        tracing_opentelemetry::layer()
+            .with_tracer(provider.tracer(config.service_name.clone()))
+            .with_filter(EnvFilter::new(&config.traces_filter))
+    });
+    tracing_subscriber::registry()
+        .with(tracing_subscriber::fmt::layer().with_filter(log_filter))
+        .with(otel_layer)
+        .init();
+
+    if let Some(endpoint) = &config.traces_endpoint {
+        tracing::info!(
+            service = %config.service_name,
+            endpoint = %endpoint,
+            sample_ratio = config.sample_ratio,
+            "Exporting traces over OTLP"
+        );
+    }
+    TelemetryGuard { provider }
+}
+
+/// Trace context of the current span, for embedding in an outgoing payload.
+/// `None` when the span is not being exported.
+pub fn current_context() -> Option<TraceContext> {
+    let cx = tracing::Span::current().context();
+    if !cx.span().span_context().is_valid() {
+        return None;
+    }
+    let mut carrier = TraceContext::new();
+    TraceContextPropagator::new().inject_context(&cx, &mut carrier);
+    Some(carrier)
+}
+
+/// Trace id of the current span, for correlating stored records with traces.
+pub fn current_trace_id() -> Option<String> {
+    let cx = tracing::Span::current().context();
+    let span_context = cx.span().span_context().clone();
+    span_context
+        .is_valid()
+        .then(|| span_context.trace_id().to_string())
+}
+
+/// Make `span` a child of the trace carried in an incoming payload.
+pub fn set_parent(span: &tracing::Span, carrier: Option<&TraceContext>) {
+    let Some(carrier) = carrier else {
+        return;
+    };
+    let cx = TraceContextPropagator::new().extract(carrier);
+    if cx.span().span_context().is_valid() {
+        let _ = span.set_parent(cx);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    const TRACE_ID: &str = "4bf92f3577b34da6a3ce929d0e0e4736";
+
+    fn carrier() -> TraceContext {
+        TraceContext::from([(
+            "traceparent".to_string(),
+            format!("00-{TRACE_ID}-00f067aa0ba902b7-01"),
+        )])
+    }
+
+    #[test]
+    fn test_context_round_trips_through_payload() {
+        assert!(current_context().is_none());
+
+        let provider = SdkTracerProvider::builder().build();
+        let subscriber = tracing_subscriber::registry()
+            .with(tracing_opentelemetry::layer().with_tracer(provider.tracer("test")));
+        tracing::subscriber::with_default(subscriber, || {
+            let span = tracing::info_span!("arb.execute");
+            set_parent(&span, Some(&carrier()));
+            let _entered = span.enter();
+            let child = tracing::info_span!("order.execute");
+            let _child = child.enter();
+
+            assert_eq!(current_trace_id().as_deref(), Some(TRACE_ID));
+            let propagated = current_context().unwrap();
+            let traceparent = &propagated["traceparent"];
+            assert!(traceparent.starts_with(&format!("00-{TRACE_ID}-")));
+            assert!(!traceparent.contains("00f067aa0ba902b7"));
+        });
+    }
+
+    #[tokio::test]
+    async fn test_spans_are_exported_over_otlp_http() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = format!("http://{}/v1/traces", listener.local_addr().unwrap());
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let mut chunk = [0u8; 8192];
+                let n = stream.read(&mut chunk).await.unwrap_or(0);
+                let head = String::from_utf8_lossy(&chunk[..n]).to_string();
+                let _ = tx.send(head.lines().next().unwrap_or_default().to_string());
+                let _ = stream
+                    .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 0\r\nconnection: close\r\n\r\n")
+                    .await;
+            }
+        });
+
+        let config = TelemetryConfig {
+            service_name: "test".into(),
+            traces_endpoint: Some(endpoint),
+            sample_ratio: 1.0,
+            traces_filter: "info".into(),
+        };
+        let provider = tokio::task::spawn_blocking(move || build_tracer_provider(&config))
+            .await
+            .unwrap()
+            .unwrap()
+            .unwrap();
+        let subscriber = tracing_subscriber::registry()
+            .with(tracing_opentelemetry::layer().with_tracer(provider.tracer("test")));
+        tracing::subscriber::with_default(subscriber, || {
+            let _span = tracing::info_span!("arb.signal.publish").entered();
+        });
+        tokio::task::spawn_blocking(move || provider.shutdown().unwrap())
+            .await
+            .unwrap();
+
+        let request_line = rx.recv().await.unwrap();
+        assert_eq!(request_line, "POST /v1/traces HTTP/1.1");
+    }
+}
@@ -141,6 +141,10 @@ pub struct ArbOpportunity {
     pub yes_fee_shares: Decimal,
     #[serde(default)]
     pub no_fee_shares: Decimal,
+    /// Trace context of the detecting span, so executors can continue the
+    /// trace after the hop through Redis.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trace_context: Option<crate::telemetry::TraceContext>,
 }
 
 impl ArbOpportunity {
@@ -178,6 +182,7 @@ impl ArbOpportunity {
             worst_case_payout: Decimal::ONE - (total_cost * fee),
             yes_fee_shares: Decimal::ZERO,
             no_fee_shares: Decimal::ZERO,
+            trace_context: None,
         })
     }
 
@@ -214,6 +219,7 @@ impl ArbOpportunity {
             worst_case_payout,
             yes_fee_shares,
             no_fee_shares,
+            trace_context: None,
         })
     }
 
@@ -712,6 +712,35 @@ impl Position {
         }
     }
 
+    /// Held leg ("yes"/"no") of a one-legged arb queued for exit, if any.
+    pub fn stranded_leg(&self) -> Option<&str> {
+        if self.state != PositionState::ExitReady {
+            return None;
+        }
+        match &self.failure_reason {
+            Some(FailureReason::OneLeggedEntry { held_leg, .. }) => Some(held_leg.as_str()),
+            _ => None,
+        }
+    }
+
+    /// Return a one-legged ExitReady position to Open once the missing leg
+    /// has been bought, so it is held to resolution as a normal pair.
+    pub fn reopen_completed_one_legged(&mut self) -> std::result::Result<(), String> {
+        if self.stranded_leg().is_none() {
+            return Err(format!(
+                "Cannot reopen {:?} position (expected one-legged ExitReady)",
+                self.state
+            ));
+        }
+        if !self.has_full_pair_exposure() {
+            return Err("Both legs must be held to reopen a one-legged position".to_string());
+        }
+        self.state = PositionState::Open;
+        self.failure_reason = None;
+        self.last_updated = Utc::now();
+        Ok(())
+    }
+
     /// Recover a one-legged entry-failed position back to Open state.
     /// Should only be called after the missing NO leg has been successfully placed.
     pub fn recover_one_legged_to_open(&mut self) -> std::result::Result<(), String> {
@@ -906,6 +935,7 @@ mod tests {
             worst_case_payout: Decimal::new(98, 2),
             yes_fee_shares: Decimal::new(1, 2),
             no_fee_shares: Decimal::new(2, 2),
+            trace_context: None,
         };
         pos.apply_arb_fee_model(&arb);
         pos.mark_open().unwrap();
@@ -998,6 +1028,7 @@ mod tests {
             worst_case_payout: Decimal::new(98, 2),
             yes_fee_shares: Decimal::new(1, 2),
             no_fee_shares: Decimal::new(2, 2),
+            trace_context: None,
         };
         pos.apply_arb_fee_model(&arb);
         pos.mark_open().unwrap();
@@ -1041,6 +1072,33 @@ mod tests {
         assert!(pos.failure_reason.is_some());
     }
 
+    #[test]
+    fn test_reopen_completed_one_legged_requires_both_legs() {
+        let mut pos = Position::new(
+            "market123".to_string(),
+            Decimal::new(48, 2),
+            Decimal::ZERO,
+            Decimal::new(100, 0),
+            ExitStrategy::HoldToResolution,
+        );
+        pos.mark_open().unwrap();
+        pos.apply_yes_entry_fill(Decimal::new(100, 0));
+        pos.mark_exit_ready().unwrap();
+        pos.failure_reason = Some(FailureReason::OneLeggedEntry {
+            held_leg: "yes".to_string(),
+            message: "NO order rejected".to_string(),
+        });
+        assert_eq!(pos.stranded_leg(), Some("yes"));
+        assert!(pos.reopen_completed_one_legged().is_err());
+
+        pos.no_entry_price = Decimal::new(50, 2);
+        pos.apply_no_entry_fill(Decimal::new(100, 0));
+        pos.reopen_completed_one_legged().unwrap();
+        assert_eq!(pos.state, PositionState::Open);
+        assert_eq!(pos.stranded_leg(), None);
+        assert!(pos.failure_reason.is_none());
+    }
+
     #[test]
     fn test_exit_failure_and_recovery() {
         let mut pos = Position::new(
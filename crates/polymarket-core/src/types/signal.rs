@@ -21,9 +21,20 @@ pub enum QuantSignalKind {
     MeanReversion,
     /// Resolution proximity time-decay signal.
     ResolutionProximity,
+    /// Matched external event the market has not yet priced.
+    EventReaction,
 }
 
 impl QuantSignalKind {
+    /// Every kind, in registration order.
+    pub const ALL: [Self; 5] = [
+        Self::Flow,
+        Self::CrossMarket,
+        Self::MeanReversion,
+        Self::ResolutionProximity,
+        Self::EventReaction,
+    ];
+
     /// Human-readable label for logging and DB storage.
     pub fn as_str(&self) -> &'static str {
         match self {
@@ -31,8 +42,14 @@ impl QuantSignalKind {
             Self::CrossMarket => "cross_market",
             Self::MeanReversion => "mean_reversion",
             Self::ResolutionProximity => "resolution_proximity",
+            Self::EventReaction => "event_reaction",
         }
     }
+
+    /// Inverse of [`as_str`](Self::as_str).
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|kind| kind.as_str() == s)
+    }
 }
 
 impl std::fmt::Display for QuantSignalKind {
@@ -58,6 +75,15 @@ impl SignalDirection {
             Self::BuyNo => "buy_no",
         }
     }
+
+    /// Inverse of [`as_str`](Self::as_str).
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "buy_yes" => Some(Self::BuyYes),
+            "buy_no" => Some(Self::BuyNo),
+            _ => None,
+        }
+    }
 }
 
 impl std::fmt::Display for SignalDirection {
@@ -245,5 +271,14 @@ mod tests {
             QuantSignalKind::ResolutionProximity.to_string(),
             "resolution_proximity"
         );
+        assert_eq!(QuantSignalKind::EventReaction.to_string(), "event_reaction");
+    }
+
+    #[test]
+    fn test_signal_kind_parse_round_trips() {
+        for kind in QuantSignalKind::ALL {
+            assert_eq!(QuantSignalKind::parse(kind.as_str()), Some(kind));
+        }
+        assert_eq!(QuantSignalKind::parse("arb"), None);
     }
 }
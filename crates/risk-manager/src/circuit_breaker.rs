@@ -108,6 +108,9 @@ pub struct CircuitBreakerState {
     /// mixing delta-based P&L tracking with absolute equity snapshots.
     #[serde(default)]
     pub portfolio_value_seeded: bool,
+    /// Capacity cap applied while market conditions are degraded (None = no throttle).
+    #[serde(default)]
+    pub market_throttle: Option<Decimal>,
 }
 
 fn today_naive() -> chrono::NaiveDate {
@@ -177,6 +180,7 @@ impl Default for CircuitBreakerState {
             recovery_state: None,
             last_reset_date: Utc::now().date_naive(),
             portfolio_value_seeded: false,
+            market_throttle: None,
         }
     }
 }
@@ -312,12 +316,16 @@ impl CircuitBreaker {
     }
 
     /// Get current trading capacity (0.0 to 1.0).
-    /// Returns 1.0 when not in recovery mode or when recovery is disabled.
+    /// Returns 1.0 when not in recovery mode and no market-conditions throttle is set.
     pub async fn trading_capacity(&self) -> Decimal {
         let state = self.state.read().await;
-        match &state.recovery_state {
+        let recovery_capacity = match &state.recovery_state {
             Some(recovery) => recovery.capacity_pct(),
             None => Decimal::ONE,
+        };
+        match state.market_throttle {
+            Some(throttle) => recovery_capacity.min(throttle),
+            None => recovery_capacity,
         }
     }
 
@@ -650,6 +658,68 @@ impl CircuitBreaker {
         self.persist_state(&state).await;
     }
 
+    /// Trip due to abnormal market conditions.
+    ///
+    /// Unlike loss-based trips there is no cooldown: trading stays halted until
+    /// `clear_market_conditions` is called once conditions normalize. Returns
+    /// false (and leaves state untouched) if the breaker is already tripped for
+    /// another reason, so a market-conditions trip never masks a hard kill.
+    pub async fn trip_market_conditions(&self, detail: &str) -> bool {
+        let config = self.config.read().await;
+        if !config.enabled {
+            return false;
+        }
+        let mut state = self.state.write().await;
+        if state.tripped {
+            return false;
+        }
+
+        warn!(detail = %detail, "Circuit breaker tripped due to market conditions");
+        state.recovery_state = None;
+        self.trip_internal(&mut state, TripReason::MarketConditions, &config)
+            .await;
+
+        // Persist state to database
+        self.persist_state(&state).await;
+        true
+    }
+
+    /// Release a market-conditions trip.
+    ///
+    /// Enters gradual recovery when enabled, otherwise resets immediately.
+    /// Returns false if the breaker is not currently tripped for market conditions.
+    pub async fn clear_market_conditions(&self) -> bool {
+        let gradual = self.config.read().await.gradual_recovery_enabled;
+        {
+            let state = self.state.read().await;
+            if !state.tripped || state.trip_reason != Some(TripReason::MarketConditions) {
+                return false;
+            }
+        }
+
+        info!("Market conditions normalized, releasing circuit breaker");
+        if gradual {
+            self.start_recovery().await;
+        } else {
+            self.reset().await;
+        }
+        true
+    }
+
+    /// Set or clear the market-conditions capacity throttle.
+    pub async fn set_market_throttle(&self, capacity: Option<Decimal>) {
+        let mut state = self.state.write().await;
+        let capacity = capacity.map(|c| c.max(Decimal::ZERO).min(Decimal::ONE));
+        if state.market_throttle == capacity {
+            return;
+        }
+        match capacity {
+            Some(c) => warn!(capacity_pct = %c, "Market-conditions throttle applied"),
+            None => info!("Market-conditions throttle lifted"),
+        }
+        state.market_throttle = capacity;
+    }
+
     /// Reset the circuit breaker.
     pub async fn reset(&self) {
         let mut state = self.state.write().await;
@@ -767,6 +837,13 @@ impl CircuitBreaker {
                 current_value = %state.current_value,
                 "HARD KILL SWITCH ACTIVATED - trading permanently halted until manual reset"
             );
+        } else if reason == TripReason::MarketConditions {
+            // Released by the market-conditions detector, not by a cooldown timer.
+            state.resume_at = None;
+            error!(
+                reason = ?reason,
+                "Circuit breaker TRIPPED - trading halted until market conditions normalize"
+            );
         } else {
             let resume_at = now + Duration::minutes(config.cooldown_minutes);
             state.resume_at = Some(resume_at);
@@ -1113,6 +1190,49 @@ mod tests {
         assert!(!breaker.is_in_recovery().await);
     }
 
+    #[tokio::test]
+    async fn test_market_conditions_trip_and_clear() {
+        let config = CircuitBreakerConfig {
+            cooldown_minutes: 0,
+            gradual_recovery_enabled: false,
+            ..Default::default()
+        };
+        let breaker = CircuitBreaker::new(config);
+
+        assert!(breaker.trip_market_conditions("stale book").await);
+        assert!(breaker.is_tripped());
+        // No cooldown-based resume for market-conditions trips.
+        assert!(breaker.state().await.resume_at.is_none());
+        assert!(!breaker.can_trade().await);
+
+        assert!(breaker.clear_market_conditions().await);
+        assert!(!breaker.is_tripped());
+        assert!(breaker.can_trade().await);
+        assert!(!breaker.clear_market_conditions().await);
+    }
+
+    #[tokio::test]
+    async fn test_market_conditions_does_not_override_other_trip() {
+        let breaker = CircuitBreaker::new(CircuitBreakerConfig::default());
+        breaker.manual_trip(None).await;
+
+        assert!(!breaker.trip_market_conditions("wide spreads").await);
+        assert!(!breaker.clear_market_conditions().await);
+        assert_eq!(breaker.state().await.trip_reason, Some(TripReason::Manual));
+    }
+
+    #[tokio::test]
+    async fn test_market_throttle_caps_capacity() {
+        let breaker = CircuitBreaker::new(CircuitBreakerConfig::default());
+        assert_eq!(breaker.trading_capacity().await, Decimal::ONE);
+
+        breaker.set_market_throttle(Some(Decimal::new(50, 2))).await;
+        assert_eq!(breaker.trading_capacity().await, Decimal::new(50, 2));
+
+        breaker.set_market_throttle(None).await;
+        assert_eq!(breaker.trading_capacity().await, Decimal::ONE);
+    }
+
     #[tokio::test]
     async fn test_exit_recovery_early() {
         let config = CircuitBreakerConfig {
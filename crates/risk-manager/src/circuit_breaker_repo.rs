@@ -57,6 +57,8 @@ impl CircuitBreakerRepository {
                 // so record_trade doesn't corrupt current_value with deltas.
                 portfolio_value_seeded: r.get::<rust_decimal::Decimal, _>("peak_value")
                     > rust_decimal::Decimal::ZERO,
+                // Throttles are re-applied by the market-conditions monitor.
+                market_throttle: None,
             }
         });
 
@@ -5,6 +5,7 @@
 pub mod advanced_stops;
 pub mod circuit_breaker;
 pub mod circuit_breaker_repo;
+pub mod market_conditions;
 pub mod stop_loss;
 pub mod stop_loss_repo;
 
@@ -16,6 +17,11 @@ pub use circuit_breaker::{
     CircuitBreaker, CircuitBreakerConfig, CircuitBreakerState, RecoveryState, TripReason,
 };
 pub use circuit_breaker_repo::CircuitBreakerRepository;
+pub use market_conditions::{
+    MarketConditionTrigger, MarketConditionsAssessment, MarketConditionsConfig,
+    MarketConditionsDetector, MarketConditionsLevel, MarketConditionsSample,
+    MarketConditionsTransition,
+};
 pub use stop_loss::{
     CheckSkipReason, CheckTriggersSummary, RuleCheckOutcome, RuleCheckResult, StopLossManager,
     StopLossRule, StopLossStats, StopType, TriggeredStop,
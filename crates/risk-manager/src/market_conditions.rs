@@ -0,0 +1,485 @@
+//! Market-conditions detector for throttling or halting trading.
+//!
+//! The detector is fed periodic samples describing the health of the market
+//! data stream and execution quality (spread blowouts, stale books, one-legged
+//! arb fills, order rejects). It escalates immediately when a threshold is
+//! crossed and only steps back down after several consecutive healthy samples,
+//! so a single calm reading cannot flap the circuit breaker.
+
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Thresholds for the market-conditions detector.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarketConditionsConfig {
+    /// Minimum number of markets with a two-sided book before spread checks apply.
+    pub min_markets_sampled: u32,
+    /// Fraction of sampled markets with a wide spread that throttles trading.
+    pub throttle_wide_spread_fraction: f64,
+    /// Fraction of sampled markets with a wide spread that halts trading.
+    pub trip_wide_spread_fraction: f64,
+    /// Age of the latest order book update (seconds) that throttles trading.
+    pub throttle_book_stale_secs: i64,
+    /// Age of the latest order book update (seconds) that halts trading.
+    pub trip_book_stale_secs: i64,
+    /// One-legged arb fills within the sample window that throttle trading.
+    pub throttle_one_legged_fills: u32,
+    /// One-legged arb fills within the sample window that halt trading.
+    pub trip_one_legged_fills: u32,
+    /// Order reject rate (0.0 to 1.0) that throttles trading.
+    pub throttle_reject_rate: f64,
+    /// Order reject rate (0.0 to 1.0) that halts trading.
+    pub trip_reject_rate: f64,
+    /// Minimum order attempts in the window before the reject rate is evaluated.
+    pub min_orders_for_reject_rate: u32,
+    /// Trading capacity applied while throttled (e.g. 0.50 = half size).
+    pub throttle_capacity: Decimal,
+    /// Consecutive calmer samples required before stepping down a level.
+    pub recovery_samples: u32,
+}
+
+impl Default for MarketConditionsConfig {
+    fn default() -> Self {
+        Self {
+            min_markets_sampled: 20,
+            throttle_wide_spread_fraction: 0.35,
+            trip_wide_spread_fraction: 0.60,
+            throttle_book_stale_secs: 120,
+            trip_book_stale_secs: 300,
+            throttle_one_legged_fills: 2,
+            trip_one_legged_fills: 4,
+            throttle_reject_rate: 0.30,
+            trip_reject_rate: 0.60,
+            min_orders_for_reject_rate: 5,
+            throttle_capacity: Decimal::new(50, 2), // 50% size while throttled
+            recovery_samples: 3,
+        }
+    }
+}
+
+/// A single observation of market and execution health.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MarketConditionsSample {
+    /// When the sample was taken.
+    pub observed_at: DateTime<Utc>,
+    /// Markets with a two-sided book at sample time.
+    pub markets_sampled: u32,
+    /// Markets whose spread exceeded the wide-spread threshold.
+    pub wide_spread_markets: u32,
+    /// Timestamp of the most recent order book update, if any was seen.
+    pub last_book_update_at: Option<DateTime<Utc>>,
+    /// One-legged arb fills within the sample window.
+    pub one_legged_fills: u32,
+    /// Order attempts within the sample window.
+    pub order_attempts: u32,
+    /// Rejected or failed orders within the sample window.
+    pub order_rejects: u32,
+}
+
+impl MarketConditionsSample {
+    /// Fraction of sampled markets with a wide spread.
+    pub fn wide_spread_fraction(&self) -> f64 {
+        if self.markets_sampled == 0 {
+            return 0.0;
+        }
+        self.wide_spread_markets as f64 / self.markets_sampled as f64
+    }
+
+    /// Seconds since the last order book update, if one was seen.
+    pub fn book_age_secs(&self) -> Option<i64> {
+        self.last_book_update_at
+            .map(|at| (self.observed_at - at).num_seconds().max(0))
+    }
+
+    /// Share of order attempts that were rejected.
+    pub fn reject_rate(&self) -> f64 {
+        if self.order_attempts == 0 {
+            return 0.0;
+        }
+        (self.order_rejects as f64 / self.order_attempts as f64).min(1.0)
+    }
+}
+
+/// Severity level assigned by the detector.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MarketConditionsLevel {
+    /// Conditions are normal; full capacity.
+    Normal,
+    /// Conditions are degraded; trade at reduced capacity.
+    Throttled,
+    /// Conditions are abnormal; trading halted.
+    Tripped,
+}
+
+impl MarketConditionsLevel {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Normal => "normal",
+            Self::Throttled => "throttled",
+            Self::Tripped => "tripped",
+        }
+    }
+}
+
+/// A single condition that pushed the assessment above normal.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum MarketConditionTrigger {
+    /// Too many markets with blown-out spreads.
+    WideSpreads {
+        fraction: f64,
+        level: MarketConditionsLevel,
+    },
+    /// No fresh order book data.
+    StaleBook {
+        age_secs: i64,
+        level: MarketConditionsLevel,
+    },
+    /// Burst of one-legged arb fills.
+    OneLeggedFills {
+        count: u32,
+        level: MarketConditionsLevel,
+    },
+    /// Order reject rate spiked.
+    RejectRate {
+        rate: f64,
+        level: MarketConditionsLevel,
+    },
+}
+
+impl MarketConditionTrigger {
+    /// Severity contributed by this trigger.
+    pub fn level(&self) -> MarketConditionsLevel {
+        match self {
+            Self::WideSpreads { level, .. }
+            | Self::StaleBook { level, .. }
+            | Self::OneLeggedFills { level, .. }
+            | Self::RejectRate { level, .. } => *level,
+        }
+    }
+
+    /// Human-readable summary for logs and audit entries.
+    pub fn describe(&self) -> String {
+        match self {
+            Self::WideSpreads { fraction, .. } => {
+                format!("{:.0}% of markets have wide spreads", fraction * 100.0)
+            }
+            Self::StaleBook { age_secs, .. } => {
+                format!("no order book update for {age_secs}s")
+            }
+            Self::OneLeggedFills { count, .. } => format!("{count} one-legged arb fills"),
+            Self::RejectRate { rate, .. } => {
+                format!("{:.0}% order reject rate", rate * 100.0)
+            }
+        }
+    }
+}
+
+/// Result of evaluating a sample against the configured thresholds.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MarketConditionsAssessment {
+    /// Highest level reached by any trigger.
+    pub level: MarketConditionsLevel,
+    /// Conditions that fired.
+    pub triggers: Vec<MarketConditionTrigger>,
+}
+
+/// A change in the detector's effective level.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarketConditionsTransition {
+    pub from: MarketConditionsLevel,
+    pub to: MarketConditionsLevel,
+    pub triggers: Vec<MarketConditionTrigger>,
+    pub at: DateTime<Utc>,
+}
+
+impl MarketConditionsTransition {
+    /// Joined trigger descriptions, or a recovery note when none fired.
+    pub fn summary(&self) -> String {
+        if self.triggers.is_empty() {
+            return "market conditions normalized".to_string();
+        }
+        self.triggers
+            .iter()
+            .map(MarketConditionTrigger::describe)
+            .collect::<Vec<_>>()
+            .join("; ")
+    }
+}
+
+/// Stateful detector with escalation and hysteresis.
+pub struct MarketConditionsDetector {
+    config: MarketConditionsConfig,
+    level: MarketConditionsLevel,
+    /// Consecutive samples assessed below the current level.
+    calm_streak: u32,
+}
+
+impl MarketConditionsDetector {
+    /// Create a detector starting at `Normal`.
+    pub fn new(config: MarketConditionsConfig) -> Self {
+        Self {
+            config,
+            level: MarketConditionsLevel::Normal,
+            calm_streak: 0,
+        }
+    }
+
+    /// Create a detector resuming from a previously applied level (e.g. after restart).
+    pub fn with_level(config: MarketConditionsConfig, level: MarketConditionsLevel) -> Self {
+        Self {
+            config,
+            level,
+            calm_streak: 0,
+        }
+    }
+
+    /// Current effective level.
+    pub fn level(&self) -> MarketConditionsLevel {
+        self.level
+    }
+
+    /// Current configuration.
+    pub fn config(&self) -> &MarketConditionsConfig {
+        &self.config
+    }
+
+    /// Evaluate a sample without changing detector state.
+    pub fn assess(&self, sample: &MarketConditionsSample) -> MarketConditionsAssessment {
+        let cfg = &self.config;
+        let mut triggers = Vec::new();
+
+        if sample.markets_sampled >= cfg.min_markets_sampled {
+            let fraction = sample.wide_spread_fraction();
+            if let Some(level) = grade(
+                fraction,
+                cfg.throttle_wide_spread_fraction,
+                cfg.trip_wide_spread_fraction,
+            ) {
+                triggers.push(MarketConditionTrigger::WideSpreads { fraction, level });
+            }
+        }
+
+        if let Some(age_secs) = sample.book_age_secs() {
+            if let Some(level) = grade(
+                age_secs as f64,
+                cfg.throttle_book_stale_secs as f64,
+                cfg.trip_book_stale_secs as f64,
+            ) {
+                triggers.push(MarketConditionTrigger::StaleBook { age_secs, level });
+            }
+        }
+
+        if let Some(level) = grade(
+            sample.one_legged_fills as f64,
+            cfg.throttle_one_legged_fills as f64,
+            cfg.trip_one_legged_fills as f64,
+        ) {
+            triggers.push(MarketConditionTrigger::OneLeggedFills {
+                count: sample.one_legged_fills,
+                level,
+            });
+        }
+
+        if sample.order_attempts >= cfg.min_orders_for_reject_rate {
+            let rate = sample.reject_rate();
+            if let Some(level) = grade(rate, cfg.throttle_reject_rate, cfg.trip_reject_rate) {
+                triggers.push(MarketConditionTrigger::RejectRate { rate, level });
+            }
+        }
+
+        let level = triggers
+            .iter()
+            .map(MarketConditionTrigger::level)
+            .max()
+            .unwrap_or(MarketConditionsLevel::Normal);
+
+        MarketConditionsAssessment { level, triggers }
+    }
+
+    /// Feed a sample and return a transition if the effective level changed.
+    ///
+    /// Escalation is immediate. De-escalation requires `recovery_samples`
+    /// consecutive samples assessed below the current level, and then moves
+    /// to the level of the latest sample.
+    pub fn observe(
+        &mut self,
+        sample: &MarketConditionsSample,
+    ) -> Option<MarketConditionsTransition> {
+        let assessment = self.assess(sample);
+
+        if assessment.level > self.level {
+            return Some(self.transition_to(assessment, sample.observed_at));
+        }
+
+        if assessment.level == self.level {
+            self.calm_streak = 0;
+            return None;
+        }
+
+        self.calm_streak = self.calm_streak.saturating_add(1);
+        if self.calm_streak < self.config.recovery_samples.max(1) {
+            return None;
+        }
+
+        Some(self.transition_to(assessment, sample.observed_at))
+    }
+
+    fn transition_to(
+        &mut self,
+        assessment: MarketConditionsAssessment,
+        at: DateTime<Utc>,
+    ) -> MarketConditionsTransition {
+        let from = self.level;
+        self.level = assessment.level;
+        self.calm_streak = 0;
+        MarketConditionsTransition {
+            from,
+            to: assessment.level,
+            triggers: assessment.triggers,
+            at,
+        }
+    }
+}
+
+/// Grade a metric against throttle/trip thresholds. Non-positive thresholds disable that tier.
+fn grade(value: f64, throttle_at: f64, trip_at: f64) -> Option<MarketConditionsLevel> {
+    if trip_at > 0.0 && value >= trip_at {
+        Some(MarketConditionsLevel::Tripped)
+    } else if throttle_at > 0.0 && value >= throttle_at {
+        Some(MarketConditionsLevel::Throttled)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn healthy_sample() -> MarketConditionsSample {
+        let now = Utc::now();
+        MarketConditionsSample {
+            observed_at: now,
+            markets_sampled: 100,
+            wide_spread_markets: 5,
+            last_book_update_at: Some(now - Duration::seconds(2)),
+            one_legged_fills: 0,
+            order_attempts: 20,
+            order_rejects: 1,
+        }
+    }
+
+    #[test]
+    fn test_healthy_sample_is_normal() {
+        let detector = MarketConditionsDetector::new(MarketConditionsConfig::default());
+        let assessment = detector.assess(&healthy_sample());
+        assert_eq!(assessment.level, MarketConditionsLevel::Normal);
+        assert!(assessment.triggers.is_empty());
+    }
+
+    #[test]
+    fn test_wide_spreads_throttle_then_trip() {
+        let detector = MarketConditionsDetector::new(MarketConditionsConfig::default());
+
+        let mut sample = healthy_sample();
+        sample.wide_spread_markets = 40;
+        assert_eq!(
+            detector.assess(&sample).level,
+            MarketConditionsLevel::Throttled
+        );
+
+        sample.wide_spread_markets = 70;
+        assert_eq!(
+            detector.assess(&sample).level,
+            MarketConditionsLevel::Tripped
+        );
+    }
+
+    #[test]
+    fn test_spread_check_needs_minimum_sample() {
+        let detector = MarketConditionsDetector::new(MarketConditionsConfig::default());
+        let mut sample = healthy_sample();
+        sample.markets_sampled = 5;
+        sample.wide_spread_markets = 5;
+        assert_eq!(
+            detector.assess(&sample).level,
+            MarketConditionsLevel::Normal
+        );
+    }
+
+    #[test]
+    fn test_stale_book_trips() {
+        let detector = MarketConditionsDetector::new(MarketConditionsConfig::default());
+        let mut sample = healthy_sample();
+        sample.last_book_update_at = Some(sample.observed_at - Duration::seconds(600));
+        let assessment = detector.assess(&sample);
+        assert_eq!(assessment.level, MarketConditionsLevel::Tripped);
+        assert!(matches!(
+            assessment.triggers[0],
+            MarketConditionTrigger::StaleBook { age_secs: 600, .. }
+        ));
+    }
+
+    #[test]
+    fn test_reject_rate_ignored_below_min_attempts() {
+        let detector = MarketConditionsDetector::new(MarketConditionsConfig::default());
+        let mut sample = healthy_sample();
+        sample.order_attempts = 2;
+        sample.order_rejects = 2;
+        assert_eq!(
+            detector.assess(&sample).level,
+            MarketConditionsLevel::Normal
+        );
+
+        sample.order_attempts = 10;
+        sample.order_rejects = 7;
+        assert_eq!(
+            detector.assess(&sample).level,
+            MarketConditionsLevel::Tripped
+        );
+    }
+
+    #[test]
+    fn test_escalation_is_immediate_recovery_needs_streak() {
+        let mut detector = MarketConditionsDetector::new(MarketConditionsConfig {
+            recovery_samples: 2,
+            ..Default::default()
+        });
+
+        let mut bad = healthy_sample();
+        bad.one_legged_fills = 5;
+        let transition = detector.observe(&bad).expect("should trip");
+        assert_eq!(transition.from, MarketConditionsLevel::Normal);
+        assert_eq!(transition.to, MarketConditionsLevel::Tripped);
+
+        // One calm sample is not enough.
+        assert!(detector.observe(&healthy_sample()).is_none());
+        assert_eq!(detector.level(), MarketConditionsLevel::Tripped);
+
+        let transition = detector.observe(&healthy_sample()).expect("should recover");
+        assert_eq!(transition.to, MarketConditionsLevel::Normal);
+        assert!(transition.triggers.is_empty());
+    }
+
+    #[test]
+    fn test_relapse_resets_calm_streak() {
+        let mut detector = MarketConditionsDetector::new(MarketConditionsConfig {
+            recovery_samples: 2,
+            ..Default::default()
+        });
+
+        let mut bad = healthy_sample();
+        bad.one_legged_fills = 2;
+        assert!(detector.observe(&bad).is_some());
+        assert_eq!(detector.level(), MarketConditionsLevel::Throttled);
+
+        assert!(detector.observe(&healthy_sample()).is_none());
+        assert!(detector.observe(&bad).is_none());
+        assert!(detector.observe(&healthy_sample()).is_none());
+        assert_eq!(detector.level(), MarketConditionsLevel::Throttled);
+    }
+}
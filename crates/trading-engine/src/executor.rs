@@ -3,7 +3,9 @@
 use anyhow::Result;
 use auth::TradingWallet;
 use dashmap::DashMap;
-use polymarket_core::api::clob::{AuthenticatedClobClient, BalanceAllowanceResponse, OrderType};
+use polymarket_core::api::clob::{
+    AuthenticatedClobClient, BalanceAllowanceResponse, OpenOrder, OrderType,
+};
 use polymarket_core::api::ClobClient;
 use polymarket_core::signing::{OrderSide as SigningOrderSide, OrderSigner};
 use polymarket_core::types::{
@@ -13,7 +15,7 @@ use rust_decimal::Decimal;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use tokio::sync::{mpsc, RwLock};
-use tracing::{debug, error, info, warn};
+use tracing::{debug, error, info, instrument, warn};
 use uuid::Uuid;
 
 const BALANCE_ALLOWANCE_RETRY_MARKER: &str = "refreshable_balance_allowance";
@@ -29,6 +31,15 @@ pub struct ExecutionMetrics {
     pub avg_latency_us: u64,
 }
 
+/// Fill state of one live order, read back from the CLOB by id.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LiveOrderFill {
+    /// Quantity matched so far.
+    pub matched: Decimal,
+    /// Whether the order is still resting and can fill further.
+    pub open: bool,
+}
+
 /// Configuration for the order executor.
 #[derive(Debug, Clone)]
 pub struct ExecutorConfig {
@@ -287,6 +298,7 @@ impl OrderExecutor {
     }
 
     /// Execute a market order with timeout and retry logic.
+    #[instrument(name = "order.execute", skip_all, fields(order_type = "market", order_id = %order.id, market_id = %order.market_id, side = ?order.side))]
     pub async fn execute_market_order(&self, order: MarketOrder) -> Result<ExecutionReport> {
         let start = std::time::Instant::now();
 
@@ -344,6 +356,12 @@ impl OrderExecutor {
                 metrics.orders_rejected += 1;
             }
             let latency_us = start.elapsed().as_micros() as u64;
+            polymarket_core::metrics::observe_order(
+                "market",
+                self.is_live(),
+                report.is_success(),
+                start.elapsed(),
+            );
             metrics.avg_latency_us = (metrics.avg_latency_us * (metrics.orders_submitted - 1)
                 + latency_us)
                 / metrics.orders_submitted;
@@ -477,7 +495,10 @@ impl OrderExecutor {
     }
 
     /// Execute a limit order with timeout and retry logic.
+    #[instrument(name = "order.execute", skip_all, fields(order_type = "limit", order_id = %order.id, market_id = %order.market_id, side = ?order.side))]
     pub async fn execute_limit_order(&self, order: LimitOrder) -> Result<ExecutionReport> {
+        let start = std::time::Instant::now();
+
         // Validate order
         if order.quantity > self.config.max_order_size {
             let report = ExecutionReport::rejected(
@@ -521,6 +542,13 @@ impl OrderExecutor {
             )
             .await;
 
+        polymarket_core::metrics::observe_order(
+            "limit",
+            self.is_live(),
+            report.is_success(),
+            start.elapsed(),
+        );
+
         self.pending_orders.remove(&order.id);
         self.send_report(report.clone()).await;
         Ok(report)
@@ -537,6 +565,139 @@ impl OrderExecutor {
         }
     }
 
+    /// Cancel every resting order for the live wallet on the CLOB.
+    ///
+    /// Unlike `cancel_order`, this goes to the exchange and also removes
+    /// orders this process did not place (e.g. GTC orders left over from
+    /// a previous run).
+    pub async fn cancel_all_live_orders(&self) -> Result<()> {
+        let slot = self.auth_client.read().await;
+        let client = slot
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("No authenticated client"))?;
+        client
+            .cancel_all_orders()
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to cancel all orders: {}", e))?;
+        self.pending_orders.clear();
+        Ok(())
+    }
+
+    /// Rest a post-only GTC order on the CLOB and return its exchange order id.
+    ///
+    /// Unlike `execute_limit_order`, this does not wait for or simulate a
+    /// fill: the caller owns the resting order and tracks it through
+    /// `live_open_orders`. Live mode only.
+    pub async fn place_post_only_order(
+        &self,
+        token_id: &str,
+        side: OrderSide,
+        price: Decimal,
+        quantity: Decimal,
+    ) -> Result<String> {
+        if quantity > self.config.max_order_size {
+            return Err(anyhow::anyhow!(
+                "Order size {} exceeds maximum {}",
+                quantity,
+                self.config.max_order_size
+            ));
+        }
+        let slot = self.auth_client.read().await;
+        let client = slot
+            .as_ref()
+            .filter(|client| client.has_credentials())
+            .ok_or_else(|| anyhow::anyhow!("Live trading wallet is not initialized"))?;
+
+        let signing_side = match side {
+            OrderSide::Buy => SigningOrderSide::Buy,
+            OrderSide::Sell => SigningOrderSide::Sell,
+        };
+        let required_amount = match side {
+            OrderSide::Buy => quantity * price,
+            OrderSide::Sell => quantity,
+        };
+        Self::ensure_live_order_capacity(client, Uuid::new_v4(), side, token_id, required_amount)
+            .await?;
+
+        let signed_order = client
+            .create_order(token_id, signing_side, price, quantity, OrderType::Gtc)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to create order: {}", e))?;
+        let response = client
+            .post_order_post_only(signed_order)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to post order: {}", e))?;
+        if response.is_unfilled() {
+            return Err(anyhow::anyhow!(
+                "Post-only order rejected with status {}",
+                response.status
+            ));
+        }
+        Ok(response.order_id)
+    }
+
+    /// Cancel one resting order on the CLOB by its exchange order id.
+    pub async fn cancel_live_order(&self, clob_order_id: &str) -> Result<()> {
+        let slot = self.auth_client.read().await;
+        let client = slot
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("No authenticated client"))?;
+        client
+            .cancel_order(clob_order_id)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to cancel order: {}", e))
+    }
+
+    /// Matched quantity and status of one live order, whatever its status.
+    ///
+    /// Use this rather than inferring fills from `live_open_orders`: an
+    /// order missing from open orders may have been cancelled (by us, the
+    /// dead-man's switch, the CLOB or expiry) rather than filled.
+    pub async fn live_order_fill(&self, clob_order_id: &str) -> Result<LiveOrderFill> {
+        let slot = self.auth_client.read().await;
+        let client = slot
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("No authenticated client"))?;
+        let order = client
+            .get_order(clob_order_id)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to get order: {}", e))?;
+        Ok(LiveOrderFill {
+            matched: order.matched_quantity(),
+            open: order.is_open(),
+        })
+    }
+
+    /// Cancel a resting order and read back what it filled before the
+    /// cancel landed. A failed cancel is fine when the order reads back
+    /// closed (it filled or was cancelled elsewhere); an order still open
+    /// afterwards is an error and stays the caller's to track.
+    pub async fn cancel_live_order_and_read_fill(
+        &self,
+        clob_order_id: &str,
+    ) -> Result<LiveOrderFill> {
+        let cancelled = self.cancel_live_order(clob_order_id).await;
+        let fill = self.live_order_fill(clob_order_id).await?;
+        if fill.open {
+            return Err(cancelled.err().unwrap_or_else(|| {
+                anyhow::anyhow!("Order {} still open after cancel", clob_order_id)
+            }));
+        }
+        Ok(fill)
+    }
+
+    /// Resting orders for the live wallet in one market (condition id).
+    pub async fn live_open_orders(&self, market: &str) -> Result<Vec<OpenOrder>> {
+        let slot = self.auth_client.read().await;
+        let client = slot
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("No authenticated client"))?;
+        client
+            .get_open_orders(Some(market))
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to get open orders: {}", e))
+    }
+
     /// Get current execution metrics.
     pub fn metrics(&self) -> ExecutionMetrics {
         self.metrics.read().unwrap().clone()
@@ -6,7 +6,7 @@ pub mod executor;
 pub mod position_manager;
 pub mod recommendation;
 
-pub use executor::OrderExecutor;
+pub use executor::{LiveOrderFill, OrderExecutor};
 pub use position_manager::PositionManager;
 pub use recommendation::{
     Evidence, HoldingPeriod, Recommendation, RecommendationEngine, RecommendationType,
@@ -1344,7 +1344,7 @@ export interface RiskStatus {
 }
 
 // Quant Signal types
-export type QuantSignalKind = "flow" | "cross_market" | "mean_reversion" | "resolution_proximity" | "latency_arb";
+export type QuantSignalKind = "flow" | "cross_market" | "mean_reversion" | "resolution_proximity" | "event_reaction" | "latency_arb";
 export type SignalDirection = "BuyYes" | "BuyNo";
 export type SignalExecutionStatus = "pending" | "executed" | "skipped" | "expired";
 
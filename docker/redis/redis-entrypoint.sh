@@ -8,7 +8,7 @@ set -eu
 
 cat > /usr/local/etc/redis/users.acl <<EOF
 user default off
-user app on >${REDIS_APP_PASSWORD} ~arb:runtime:stats:latest &arb:* &copy:signals &orderbook:updates +@connection +@pubsub +get +set
+user app on >${REDIS_APP_PASSWORD} ~arb:runtime:stats:latest ~arb:runtime:stats:shard:* ~arb:shard:* ~arb:stream:* &arb:* &copy:signals &orderbook:updates +@connection +@pubsub +@stream +get +set +mget +zadd +zremrangebyscore +zrangebyscore
 user dynamic_tuner on >${REDIS_DYNAMIC_TUNER_PASSWORD} ~arb:runtime:stats:latest &dynamic:config:update +@connection +publish +get
 user dynamic_subscriber on >${REDIS_DYNAMIC_SUBSCRIBER_PASSWORD} &dynamic:config:update +@connection +subscribe +psubscribe +unsubscribe +punsubscribe
 EOF
@@ -0,0 +1,28 @@
+-- Versioned per-workspace risk limits.
+-- Each change writes a new version; at most one version per workspace is active.
+-- Loosening changes wait in pending_approval until a second owner/admin approves.
+
+CREATE TABLE IF NOT EXISTS workspace_risk_limits (
+    id UUID PRIMARY KEY,
+    workspace_id UUID NOT NULL REFERENCES workspaces(id) ON DELETE CASCADE,
+    version INTEGER NOT NULL,
+    -- Active version the change was proposed against (NULL = env defaults).
+    base_version INTEGER,
+    document JSONB NOT NULL,
+    status TEXT NOT NULL
+        CHECK (status IN ('active', 'pending_approval', 'superseded', 'rejected')),
+    loosened_fields TEXT[] NOT NULL DEFAULT '{}',
+    reason TEXT,
+    proposed_by UUID NOT NULL,
+    reviewed_by UUID,
+    reviewed_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (workspace_id, version)
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_workspace_risk_limits_active
+    ON workspace_risk_limits (workspace_id)
+    WHERE status = 'active';
+
+CREATE INDEX IF NOT EXISTS idx_workspace_risk_limits_workspace_version
+    ON workspace_risk_limits (workspace_id, version DESC);
@@ -0,0 +1,36 @@
+-- Trade prints from the CLOB market channel (`last_trade_price` events).
+--
+-- wallet_trades is filled by polling the Data API every few minutes, so flow
+-- features lag the market. arb-monitor records prints for the assets it
+-- watches as they happen; the flow feature calculator uses prints newer than
+-- the latest harvested wallet trade to fill that gap. Prints carry no wallet
+-- or transaction hash, so they never count towards buyers/sellers or smart
+-- money flow.
+
+CREATE TABLE IF NOT EXISTS market_trade_prints (
+    asset_id VARCHAR NOT NULL,
+    condition_id VARCHAR NOT NULL,
+    side VARCHAR NOT NULL CHECK (side IN ('BUY', 'SELL')),
+    price DECIMAL(20, 10) NOT NULL,
+    size DECIMAL(20, 10) NOT NULL,
+    value DECIMAL(20, 10) NOT NULL,  -- price * size
+    fee_rate_bps INTEGER,
+    timestamp TIMESTAMPTZ NOT NULL,
+    received_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    -- Prints have no id; replays after a reconnect are dropped on this key.
+    CONSTRAINT unique_market_trade_print UNIQUE (asset_id, timestamp, side, price, size)
+);
+
+SELECT create_hypertable(
+    'market_trade_prints',
+    'timestamp',
+    chunk_time_interval => INTERVAL '1 day',
+    if_not_exists => TRUE
+);
+
+-- Only the recent tail is read; Data API history lives in wallet_trades.
+SELECT add_retention_policy('market_trade_prints', INTERVAL '7 days', if_not_exists => TRUE);
+
+CREATE INDEX IF NOT EXISTS idx_market_trade_prints_flow_window
+    ON market_trade_prints (timestamp DESC, condition_id)
+    INCLUDE (side, value);
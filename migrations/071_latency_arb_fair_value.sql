@@ -0,0 +1,14 @@
+-- Digital-option pricing inputs for CEX latency arbitrage signals.
+--
+-- Signals are now priced as "above/below $K by T" digital options off the
+-- CEX spot and realized volatility, and only fire when the live Polymarket
+-- ask sits below fair value by more than fees plus a volatility buffer.
+-- These columns record each term so paper-mode results can be audited.
+
+ALTER TABLE latency_arb_signals
+    ADD COLUMN IF NOT EXISTS fair_value DOUBLE PRECISION,
+    ADD COLUMN IF NOT EXISTS ask_price DOUBLE PRECISION,
+    ADD COLUMN IF NOT EXISTS fee_estimate DOUBLE PRECISION,
+    ADD COLUMN IF NOT EXISTS vol_buffer DOUBLE PRECISION,
+    -- Log-return volatility per square-root second.
+    ADD COLUMN IF NOT EXISTS realized_vol DOUBLE PRECISION;
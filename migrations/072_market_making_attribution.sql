@@ -0,0 +1,12 @@
+-- P&L attribution for the market-making strategy.
+--
+-- Market-maker fills are recorded in trade_events as 'mm_fill' (carrying
+-- spread capture: how far inside the mid each quote was filled) and, one
+-- markout horizon later, 'mm_markout' (carrying adverse selection: how far
+-- the mid then moved against the fill). The strategy P&L calculator rolls
+-- both up into the market_making snapshot row; other strategies leave them
+-- NULL.
+
+ALTER TABLE strategy_pnl_snapshots
+    ADD COLUMN IF NOT EXISTS spread_capture DECIMAL(20, 10),
+    ADD COLUMN IF NOT EXISTS adverse_selection DECIMAL(20, 10);
@@ -0,0 +1,39 @@
+-- External events (news, announcements, operator notes) as a signal source.
+--
+-- Events arrive from RSS/Atom feeds, file drops and the JSON webhook and are
+-- deduplicated per source on the sender's id. Each new event is linked to the
+-- markets whose question keywords and tags it mentions; price_at_event is the
+-- market's YES mid when the link was made, so the event-reaction generator
+-- can tell whether the market has moved since.
+
+CREATE TABLE IF NOT EXISTS external_events (
+    id            UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    source        TEXT NOT NULL,            -- feed URL or path, drop directory, webhook sender
+    source_kind   TEXT NOT NULL,            -- feed, file_drop, webhook
+    external_id   TEXT NOT NULL,
+    title         TEXT NOT NULL,
+    summary       TEXT,
+    url           TEXT,
+    tags          TEXT[] NOT NULL DEFAULT '{}',
+    sentiment     DOUBLE PRECISION NOT NULL DEFAULT 0,  -- [-1, 1], positive leans YES
+    published_at  TIMESTAMPTZ NOT NULL,
+    received_at   TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (source, external_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_external_events_published
+    ON external_events (published_at DESC);
+
+CREATE TABLE IF NOT EXISTS external_event_market_links (
+    event_id       UUID NOT NULL REFERENCES external_events(id) ON DELETE CASCADE,
+    condition_id   TEXT NOT NULL,
+    match_score    DOUBLE PRECISION NOT NULL,   -- [0, 1]
+    matched_terms  TEXT[] NOT NULL DEFAULT '{}',
+    price_at_event DOUBLE PRECISION,
+    created_at     TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (event_id, condition_id)
+);
+
+-- Recent events for one market
+CREATE INDEX IF NOT EXISTS idx_external_event_links_market
+    ON external_event_market_links (condition_id, created_at DESC);
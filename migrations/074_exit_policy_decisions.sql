@@ -0,0 +1,38 @@
+-- Exit timing decisions for paired positions.
+--
+-- When the exit optimizer is enabled, every exit-ready YES+NO pair is valued
+-- three ways (sell into the bids now, rest passive sell orders for a window,
+-- hold to resolution) and the highest is chosen. A row is written each time
+-- the chosen policy for a position changes; when the position closes, the
+-- latest row gets the value the exit actually realized and how it got there,
+-- so expected and realized value can be compared per policy. The dynamic
+-- tuner reads realized passive rows to calibrate the passive fill probability.
+
+CREATE TABLE IF NOT EXISTS exit_policy_decisions (
+    id                        UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    position_id               UUID NOT NULL,
+    market_id                 TEXT NOT NULL,
+    policy                    TEXT NOT NULL,      -- sell_now, passive, hold
+    reason                    TEXT NOT NULL,
+    quantity                  DECIMAL(20, 10) NOT NULL,
+    -- Expected net proceeds in USD of each policy; NULL when not executable
+    sell_now_value            DECIMAL(20, 10),
+    passive_value             DECIMAL(20, 10),
+    hold_value                DECIMAL(20, 10),
+    expected_value            DECIMAL(20, 10),
+    passive_fill_probability  DOUBLE PRECISION,
+    hours_to_resolution       DOUBLE PRECISION,
+    decided_at                TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    -- Filled in when the position closes
+    realized_via              TEXT,               -- sell_now, passive, passive_fallback, resolution
+    realized_value            DECIMAL(20, 10),
+    realized_at               TIMESTAMPTZ
+);
+
+CREATE INDEX IF NOT EXISTS idx_exit_policy_decisions_position
+    ON exit_policy_decisions (position_id, decided_at DESC);
+
+-- Realized decisions by policy, for tuning
+CREATE INDEX IF NOT EXISTS idx_exit_policy_decisions_realized
+    ON exit_policy_decisions (policy, realized_at DESC)
+    WHERE realized_at IS NOT NULL;
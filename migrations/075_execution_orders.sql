@@ -0,0 +1,55 @@
+-- Parent and child orders worked by the order router.
+--
+-- Large entries are not sent as one market order: the router works a parent
+-- order (TWAP, participation-of-volume or passive-then-aggressive) up to a
+-- limit price and deadline, sending child orders as it goes. The parent row
+-- is written when work starts and completed with the fill, the stop reason
+-- and the implementation shortfall against the mid at arrival; each child
+-- order is logged underneath it.
+
+CREATE TABLE IF NOT EXISTS execution_parent_orders (
+    id                UUID PRIMARY KEY,
+    signal_id         UUID,
+    position_id       UUID,
+    market_id         TEXT NOT NULL,
+    token_id          TEXT NOT NULL,
+    side              TEXT NOT NULL CHECK (side IN ('BUY', 'SELL')),
+    algo              TEXT NOT NULL,      -- market, twap, pov, passive_aggressive
+    quantity          DECIMAL(20, 10) NOT NULL,
+    limit_price       DECIMAL(20, 10) NOT NULL,
+    deadline          TIMESTAMPTZ NOT NULL,
+    arrival_mid       DECIMAL(20, 10) NOT NULL,
+    started_at        TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    -- Filled in when work stops
+    finished_at       TIMESTAMPTZ,
+    stop_reason       TEXT,               -- filled, deadline, signal_expired
+    filled_quantity   DECIMAL(20, 10),
+    average_price     DECIMAL(20, 10),
+    fees              DECIMAL(20, 10),
+    -- Cost versus the arrival mid, positive when worse
+    shortfall_usd     DECIMAL(20, 10),
+    shortfall_bps     DOUBLE PRECISION
+);
+
+CREATE INDEX IF NOT EXISTS idx_execution_parent_orders_started
+    ON execution_parent_orders (started_at DESC);
+
+CREATE INDEX IF NOT EXISTS idx_execution_parent_orders_signal
+    ON execution_parent_orders (signal_id)
+    WHERE signal_id IS NOT NULL;
+
+CREATE TABLE IF NOT EXISTS execution_child_orders (
+    id                 UUID PRIMARY KEY,
+    parent_id          UUID NOT NULL REFERENCES execution_parent_orders (id) ON DELETE CASCADE,
+    kind               TEXT NOT NULL,     -- take, rest
+    exchange_order_id  TEXT,              -- resting live orders only
+    price              DECIMAL(20, 10) NOT NULL,
+    quantity           DECIMAL(20, 10) NOT NULL,
+    filled_quantity    DECIMAL(20, 10) NOT NULL DEFAULT 0,
+    fees               DECIMAL(20, 10) NOT NULL DEFAULT 0,
+    placed_at          TIMESTAMPTZ NOT NULL,
+    done_at            TIMESTAMPTZ
+);
+
+CREATE INDEX IF NOT EXISTS idx_execution_child_orders_parent
+    ON execution_child_orders (parent_id, placed_at);
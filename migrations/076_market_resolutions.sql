@@ -0,0 +1,27 @@
+-- Observed resolution timing and outcomes, for the resolution-risk model.
+--
+-- A row is seeded from market_metadata once a market is within a week of
+-- its end date, keeping the end date as first seen so a later reschedule
+-- still counts as a late resolution. The tracker polls the CLOB until the
+-- market reports resolved, then records when it saw the payout, which side
+-- won and the YES price a day before the end date (the favourite the
+-- resolution signal would have bought).
+
+CREATE TABLE IF NOT EXISTS market_resolutions (
+    condition_id         TEXT PRIMARY KEY,
+    category             TEXT,
+    end_date             TIMESTAMPTZ NOT NULL,
+    favourite_yes_price  DOUBLE PRECISION,
+    -- Filled in once the market reports resolved
+    resolved_at          TIMESTAMPTZ,
+    yes_won              BOOLEAN,
+    first_seen_at        TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    last_checked_at      TIMESTAMPTZ
+);
+
+CREATE INDEX IF NOT EXISTS idx_market_resolutions_end_date
+    ON market_resolutions (end_date DESC);
+
+CREATE INDEX IF NOT EXISTS idx_market_resolutions_pending
+    ON market_resolutions (last_checked_at NULLS FIRST)
+    WHERE resolved_at IS NULL;
@@ -0,0 +1,15 @@
+-- Market-maker inventory per market.
+--
+-- Written after every fill and markout so the inventory cap and skew
+-- survive a restart, and counted toward open exposure alongside positions.
+-- Prices are in YES terms; a negative position is net NO.
+
+CREATE TABLE IF NOT EXISTS mm_inventory (
+    market_id          TEXT PRIMARY KEY,
+    position           DOUBLE PRECISION NOT NULL DEFAULT 0,
+    avg_price          DOUBLE PRECISION NOT NULL DEFAULT 0,
+    realized_pnl       DOUBLE PRECISION NOT NULL DEFAULT 0,
+    spread_capture     DOUBLE PRECISION NOT NULL DEFAULT 0,
+    adverse_selection  DOUBLE PRECISION NOT NULL DEFAULT 0,
+    updated_at         TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
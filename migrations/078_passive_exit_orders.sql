@@ -0,0 +1,21 @@
+-- Passive exit sell orders resting for exit-ready pairs.
+--
+-- One row per leg, written when the orders are placed and whenever their
+-- fills change, and deleted once the exit settles or the orders are
+-- confirmed cancelled. On startup the exit handler reloads the rows so
+-- resting orders are polled, cancelled and settled instead of forgotten.
+
+CREATE TABLE IF NOT EXISTS passive_exit_orders (
+    position_id  UUID NOT NULL,
+    leg          TEXT NOT NULL CHECK (leg IN ('yes', 'no')),
+    token_id     TEXT NOT NULL,
+    price        DECIMAL(20, 10) NOT NULL,
+    quantity     DECIMAL(20, 10) NOT NULL,
+    order_id     TEXT,                 -- NULL for paper orders
+    filled       DECIMAL(20, 10) NOT NULL DEFAULT 0,
+    -- False once the order can no longer fill (filled, cancelled, expired)
+    open         BOOLEAN NOT NULL DEFAULT TRUE,
+    expires_at   TIMESTAMPTZ NOT NULL,
+    updated_at   TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (position_id, leg)
+);
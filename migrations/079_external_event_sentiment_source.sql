@@ -0,0 +1,9 @@
+-- Where an external event's sentiment came from.
+--
+-- 'sender' when the event arrived with a YES-leaning sentiment for the
+-- markets it concerns; 'lexicon' when it was scored from the headline, which
+-- says whether something happened, not whether that is YES for a given
+-- question. Only sender sentiment is traded by the event-reaction signal.
+
+ALTER TABLE external_events
+    ADD COLUMN IF NOT EXISTS sentiment_source TEXT NOT NULL DEFAULT 'lexicon';